@@ -0,0 +1,156 @@
+//! Derive macro for the `WinterPublicInputs` trait of the
+//! [winter-circom-prover](https://github.com/VictorColomb/stark-snark-recursive-proofs)
+//! crate.
+//!
+//! This crate should not be used directly, the macro is re-exported by
+//! `winter-circom-prover`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields, Index, Path};
+
+/// Derive the `WinterPublicInputs`, `Serialize` and `Serializable` traits for a
+/// public inputs struct.
+///
+/// Fields are flattened into a list of field elements, in declaration order.
+/// The number of public inputs, the Serde serialization (used for the Circom
+/// input file) and the Winterfell serialization (used to seed the public coin)
+/// are all generated from this list, and are therefore always consistent.
+///
+/// Supported field types are `BaseElement`, arrays of supported types and
+/// structs that themselves derive `WinterPublicInputs`.
+///
+/// The `#[winter_circom(options = PROOF_OPTIONS)]` attribute checks the number
+/// of public inputs against the `num_public_inputs` of the given proof options
+/// constant, and fails compilation if they differ.
+#[proc_macro_derive(WinterPublicInputs, attributes(winter_circom))]
+pub fn derive_winter_public_inputs(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new(
+                input.span(),
+                "WinterPublicInputs can only be derived for structs",
+            ))
+        }
+    };
+
+    // (type, accessor) of each field, in declaration order
+    let fields = match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|f| {
+                let ident = f.ident.as_ref().unwrap();
+                (&f.ty, quote!(#ident))
+            })
+            .collect::<Vec<_>>(),
+        Fields::Unnamed(fields) => fields
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, f)| {
+                let index = Index::from(i);
+                (&f.ty, quote!(#index))
+            })
+            .collect::<Vec<_>>(),
+        Fields::Unit => Vec::new(),
+    };
+
+    let private = quote!(::winter_circom_prover::__private);
+
+    // proof options the number of public inputs is checked against
+    let mut options: Option<Path> = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("winter_circom"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("options") {
+                options = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported winter_circom attribute, expected `options`"))
+            }
+        })?;
+    }
+    let layout_check = match options {
+        Some(options) if !input.generics.params.is_empty() => {
+            return Err(syn::Error::new(
+                options.span(),
+                "public inputs layout can only be checked for non-generic structs",
+            ))
+        }
+        Some(options) => quote_spanned!(options.span()=>
+            const _: usize = #private::check_public_inputs_layout(
+                &#options,
+                <#name as #private::PublicInputsElements>::NUM_ELEMENTS,
+            );
+        ),
+        None => quote!(),
+    };
+
+    let num_elements = fields.iter().map(|(ty, _)| {
+        quote_spanned!(ty.span()=> <#ty as #private::PublicInputsElements>::NUM_ELEMENTS)
+    });
+    let write_elements = fields.iter().map(|(ty, accessor)| {
+        quote_spanned!(ty.span()=>
+            #private::PublicInputsElements::write_elements(&self.#accessor, elements);
+        )
+    });
+
+    Ok(quote! {
+        impl #impl_generics #private::PublicInputsElements for #name #ty_generics #where_clause {
+            const NUM_ELEMENTS: usize = 0 #(+ #num_elements)*;
+
+            fn write_elements(&self, elements: &mut ::std::vec::Vec<#private::BaseElement>) {
+                #(#write_elements)*
+            }
+        }
+
+        impl #impl_generics ::winter_circom_prover::WinterPublicInputs for #name #ty_generics #where_clause {
+            const NUM_PUB_INPUTS: usize =
+                <Self as #private::PublicInputsElements>::NUM_ELEMENTS;
+        }
+
+        impl #impl_generics #private::serde::Serialize for #name #ty_generics #where_clause {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: #private::serde::Serializer,
+            {
+                use #private::serde::ser::SerializeTuple;
+
+                let elements = #private::PublicInputsElements::to_elements(self);
+                let mut state = serializer.serialize_tuple(elements.len())?;
+                for element in elements.iter() {
+                    state.serialize_element(element)?;
+                }
+                state.end()
+            }
+        }
+
+        impl #impl_generics #private::Serializable for #name #ty_generics #where_clause {
+            fn write_into<W: #private::ByteWriter>(&self, target: &mut W) {
+                for element in #private::PublicInputsElements::to_elements(self) {
+                    target.write(element);
+                }
+            }
+        }
+
+        #layout_check
+    })
+}
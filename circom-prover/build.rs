@@ -2,46 +2,74 @@ use std::{env, path::Path, process::Command};
 
 // TODO: switch to cargo binary dependency when available
 // see https://rust-lang.github.io/rfcs/3028-cargo-binary-dependencies.html
+
+/// Skips all steps of this build script.
+const SKIP_TOOLCHAIN: &str = "WINTER_CIRCOM_SKIP_TOOLCHAIN";
+/// Skips the initialization of the git submodules.
+const SKIP_SUBMODULES: &str = "WINTER_CIRCOM_SKIP_SUBMODULES";
+/// Skips the build of the Circom compiler.
+const SKIP_CIRCOM: &str = "WINTER_CIRCOM_SKIP_CIRCOM";
+/// Skips the installation of the SnarkJS dependencies.
+const SKIP_SNARKJS: &str = "WINTER_CIRCOM_SKIP_SNARKJS";
+
 pub fn main() {
     println!("cargo:rerun-if-changed=../iden3/circom/");
     println!("cargo:rerun-if-changed=../iden3/snarkjs/build/");
-    let cargo = env::var("CARGO").unwrap();
+    for variable in [SKIP_TOOLCHAIN, SKIP_SUBMODULES, SKIP_CIRCOM, SKIP_SNARKJS] {
+        println!("cargo:rerun-if-env-changed={}", variable);
+    }
+
+    // the toolchain is only built from the submodules with the toolchain
+    // feature, otherwise it is discovered at runtime
+    if env::var_os("CARGO_FEATURE_TOOLCHAIN").is_none() || skip(SKIP_TOOLCHAIN) {
+        return;
+    }
 
     // initialize and update git submodules
-    if !(Path::new("iden3/circom/.git").exists() && Path::new("iden3/snarkjs/.git").exists()) {
-        assert!(
-            Command::new("git")
-                .arg("submodule")
-                .arg("update")
-                .arg("--init")
-                .arg("--recursive")
-                .status()
-                .unwrap()
-                .success(),
-            "Git submodule initialization failed."
+    if !skip(SKIP_SUBMODULES)
+        && !(Path::new("../iden3/circom/.git").exists()
+            && Path::new("../iden3/snarkjs/.git").exists())
+    {
+        run(
+            Command::new("git").args(["submodule", "update", "--init", "--recursive"]),
+            "Git submodule initialization",
+            SKIP_SUBMODULES,
         );
     }
 
     // build circom
-    assert!(
-        Command::new(cargo)
-            .arg("build")
-            .arg("--release")
-            .current_dir("../iden3/circom")
-            .status()
-            .unwrap()
-            .success(),
-        "Circom build failed."
-    );
+    if !skip(SKIP_CIRCOM) {
+        run(
+            Command::new(env::var("CARGO").unwrap())
+                .args(["build", "--release"])
+                .current_dir("../iden3/circom"),
+            "Circom build",
+            SKIP_CIRCOM,
+        );
+    }
 
     // npm clean install
+    if !skip(SKIP_SNARKJS) {
+        run(
+            Command::new("npm")
+                .arg("ci")
+                .current_dir("../iden3/snarkjs"),
+            "Npm SnarkJS clean install",
+            SKIP_SNARKJS,
+        );
+    }
+}
+
+fn skip(variable: &str) -> bool {
+    env::var_os(variable).map_or(false, |value| !value.is_empty() && value != "0")
+}
+
+fn run(command: &mut Command, step: &str, variable: &str) {
+    let success = command.status().map_or(false, |status| status.success());
     assert!(
-        Command::new("npm")
-            .arg("ci")
-            .current_dir("../iden3/snarkjs")
-            .status()
-            .unwrap()
-            .success(),
-        "Npm SnarkJS clean install failed."
+        success,
+        "{} failed. Set {}=1 to skip this step, or {}=1 to skip the build of the \
+        toolchain, which is then looked up at runtime.",
+        step, variable, SKIP_TOOLCHAIN
     );
 }
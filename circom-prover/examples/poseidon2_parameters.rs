@@ -0,0 +1,52 @@
+//! Generate the Poseidon2 parameter files of the Rust hasher and of the Circom
+//! circuit.
+//!
+//! ```text
+//! poseidon2_parameters <modulus> <alpha> <R_F> <R_P> <rust_file> <circom_file>
+//! ```
+//!
+//! A file is not written if its parameters are not supported, e.g. the Rust
+//! hasher works over the f256 field only.
+
+use std::{env, fs, process};
+
+use winter_circom_prover::Poseidon2Parameters;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() != 6 {
+        eprintln!(
+            "Usage: poseidon2_parameters <modulus> <alpha> <R_F> <R_P> <rust_file> <circom_file>"
+        );
+        process::exit(1);
+    }
+
+    let parse = |arg: &str| -> usize {
+        arg.parse().unwrap_or_else(|_| {
+            eprintln!("Invalid integer argument: {}", arg);
+            process::exit(1);
+        })
+    };
+    let params = Poseidon2Parameters::generate(
+        &args[0],
+        parse(&args[1]) as u32,
+        parse(&args[2]),
+        parse(&args[3]),
+    )
+    .unwrap_or_else(exit);
+
+    write(&args[4], params.to_rust());
+    write(&args[5], params.to_circom());
+}
+
+fn write<E: std::fmt::Display>(file: &str, source: Result<String, E>) {
+    match source {
+        Ok(source) => fs::write(file, source).unwrap_or_else(exit),
+        Err(err) => eprintln!("{} not written: {}", file, err),
+    }
+}
+
+fn exit<E: std::fmt::Display, T>(err: E) -> T {
+    eprintln!("{}", err);
+    process::exit(1);
+}
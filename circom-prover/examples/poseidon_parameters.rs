@@ -0,0 +1,78 @@
+//! Generate the Poseidon parameter files of the Rust hasher and of the Circom
+//! circuit.
+//!
+//! ```text
+//! poseidon_parameters <modulus> <alpha> <security_level> <circom_file> (<width> <R_F> <R_P> <rust_file>)...
+//! ```
+//!
+//! A Rust file is written for every width, and the Circom file holds the
+//! parameters of all the widths, the first one being the width of the
+//! `Poseidon` template. The numbers of rounds of a width are computed from the
+//! security level when they are given as `auto`. A file is not written if its
+//! parameters are not supported, e.g. the Rust hasher works over the f256
+//! field only.
+
+use std::{env, fs, process};
+
+use winter_circom_prover::PoseidonParameters;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() < 8 || (args.len() - 4) % 4 != 0 {
+        eprintln!(
+            "Usage: poseidon_parameters <modulus> <alpha> <security_level> <circom_file> \
+            (<width> <R_F> <R_P> <rust_file>)..."
+        );
+        process::exit(1);
+    }
+
+    let parse = |arg: &str| -> usize {
+        arg.parse().unwrap_or_else(|_| {
+            eprintln!("Invalid integer argument: {}", arg);
+            process::exit(1);
+        })
+    };
+    let modulus = &args[0];
+    let alpha = parse(&args[1]) as u32;
+    let security_level = parse(&args[2]) as u32;
+
+    let mut parameters = vec![];
+    for group in args[4..].chunks(4) {
+        let width = parse(&group[0]);
+        let (num_full_rounds, num_partial_rounds) = if group[1] == "auto" || group[2] == "auto" {
+            PoseidonParameters::round_numbers(modulus, width, alpha, security_level)
+                .unwrap_or_else(exit)
+        } else {
+            (parse(&group[1]), parse(&group[2]))
+        };
+
+        let params = PoseidonParameters::generate(
+            modulus,
+            width,
+            alpha,
+            num_full_rounds,
+            num_partial_rounds,
+        )
+        .unwrap_or_else(exit);
+        println!(
+            "t = {}: R_F = {}, R_P = {}",
+            width, num_full_rounds, num_partial_rounds
+        );
+
+        write(&group[3], params.to_rust());
+        parameters.push(params);
+    }
+    write(&args[3], PoseidonParameters::to_circom(&parameters));
+}
+
+fn write<E: std::fmt::Display>(file: &str, source: Result<String, E>) {
+    match source {
+        Ok(source) => fs::write(file, source).unwrap_or_else(exit),
+        Err(err) => eprintln!("{} not written: {}", file, err),
+    }
+}
+
+fn exit<E: std::fmt::Display, T>(err: E) -> T {
+    eprintln!("{}", err);
+    process::exit(1);
+}
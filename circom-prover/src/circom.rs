@@ -1,63 +1,192 @@
 use std::{
     collections::HashMap,
-    fs::{create_dir_all, File},
+    fs::{create_dir_all, rename, File},
     io::Write,
+    time::Instant,
 };
 
 use colored::Colorize;
 use rug::{ops::Pow, Float};
+use serde_json::Value;
+use tracing::warn;
+use winter_rand_utils::rand_value;
 use winterfell::{
-    crypto::hashers::Poseidon,
+    crypto::hashers::{Poseidon, Poseidon2},
     math::{fields::f256::BaseElement, log2, StarkField},
-    Air, AirContext, HashFunction, Prover, TraceInfo,
+    Air, AirContext, HashFunction, Prover, Trace, TraceInfo,
 };
 
 use crate::{
-    json::proof_to_json,
+    compile::{parse_diagnostics, CircomDiagnostic, CompileOptions},
+    fri_layers, hash_function,
+    json::{pad_to_max_trace_length, proof_to_json},
+    proof::{
+        parse_elements, write_json, CommittedValues, Groth16Proof, PublicSignals,
+        VerificationReport,
+    },
+    solidity::generate_solidity_verifier,
     utils::{
-        canonicalize, check_file, command_execution, delete_directory, delete_file, Executable,
-        LoggingLevel, WinterCircomError,
+        canonicalize, check_file, command_execution, command_output, delete_directory, delete_file, in_stage,
+        read_json, stage_span, Executable, ExecutionControl, JobDirectory, LoggingLevel,
+        WinterCircomError,
     },
-    WinterCircomProofOptions, WinterPublicInputs,
+    verification::check_public_signals,
+    CircomAir, WinterCircomProofOptions, WinterPublicInputs,
 };
 
-/// Verify the Groth16 proof of the verification of the Winterfell proof.
+/// Verify the Groth16 proof of the verification of the Winterfell proof, and
+/// check the consistency of its OOD trace frame and constraint evaluations.
+///
+/// The OOD consistency check is performed against the public inputs of the
+/// computation, which are known to the verifier (see
+/// [check_ood_frame](crate::check_ood_frame)). Together with the Groth16 proof
+/// verification, it attests of the validity of the original Winterfell proof.
 ///
-/// This function should be used alongside the
-/// [check_ood_frame](crate::check_ood_frame) function to really attest of the
-/// validity of the original Winterfell proof.
+/// An invalid proof is not an error: the outcome of each check is given by the
+/// returned [VerificationReport]. Errors are only returned when the checks could
+/// not be performed, for instance when SnarkJS fails on a malformed verification
+/// key instead of reporting an invalid proof.
 ///
 /// ## Requirements
 ///
-/// This function requires the `verification_key.json`, `proof.json` and
-/// `public.json` files to be present in the directory
-/// `target/circom/<circuit_name>`. These files can be generated by the
-/// [circom_compile] and [circom_prove] functions.
+/// This function requires the `verification_key.json` and `options.json` files
+/// to be present in the directory `target/circom/<circuit_name>`. These files
+/// are generated by the [circom_compile] function.
+///
+/// The Groth16 verification is subject to the `groth16_verify` timeout of the
+/// execution `control`.
 ///
 /// [Verbose](LoggingLevel::Verbose) logging level is *highly* recommended.
-pub fn circom_verify(
+pub fn circom_verify<AIR, const N: usize>(
+    proof: &Groth16Proof,
+    public_signals: &PublicSignals,
+    pub_inputs: AIR::PublicInputs,
+    control: &ExecutionControl,
+    logging_level: LoggingLevel,
+) -> Result<VerificationReport, WinterCircomError>
+where
+    AIR: CircomAir<N>,
+{
+    let circuit_name = AIR::CIRCUIT_NAME;
+
+    // CHECK OOD CONSISTENCY
+    // ===========================================================================
+
+    if logging_level.print_big_steps() {
+        println!("{}", "Checking OOD consistency...".green());
+    }
+
+    let options = read_json(
+        format!("target/circom/{}/options.json", circuit_name),
+        Some("generated by circom_compile"),
+    )?;
+
+    let now = Instant::now();
+    let check = in_stage(stage_span!("ood_check"), || {
+        check_public_signals::<AIR>(public_signals, &options, pub_inputs)
+    })?;
+    let ood_time = now.elapsed();
+
+    // VERIFY GROTH16 PROOF
+    // ===========================================================================
+
+    if logging_level.print_big_steps() {
+        println!("{}", "Verifying SNARK proof...".green());
+    }
+
+    let job = JobDirectory::new(circuit_name)?;
+    let now = Instant::now();
+    let groth16_valid = in_stage(stage_span!("groth16_verify"), || {
+        groth16_verify(
+            circuit_name,
+            job.path(),
+            proof,
+            public_signals,
+            control,
+            logging_level,
+        )
+    })?;
+    let groth16_time = now.elapsed();
+
+    let report = VerificationReport {
+        groth16_valid,
+        ood_valid: check.ood_valid,
+        public_inputs_match: check.public_inputs_match,
+        groth16_time,
+        ood_time,
+    };
+
+    if logging_level.print_big_steps() {
+        if report.is_valid() {
+            println!("{}", "Proof verified successfully!".green());
+        } else {
+            println!("{}", "Proof is invalid!".yellow());
+        }
+    }
+
+    Ok(report)
+}
+
+/// Verify the Groth16 proof, without checking the OOD consistency.
+///
+/// The proof and its public signals are written to the `job_directory`, for
+/// SnarkJS to read them.
+pub(crate) fn groth16_verify(
     circuit_name: &str,
+    job_directory: &str,
+    proof: &Groth16Proof,
+    public_signals: &PublicSignals,
+    control: &ExecutionControl,
     logging_level: LoggingLevel,
-) -> Result<(), WinterCircomError> {
+) -> Result<bool, WinterCircomError> {
     check_file(
         format!("target/circom/{}/verification_key.json", circuit_name),
         Some("needed for verification"),
     )?;
-    check_file(
-        format!("target/circom/{}/public.json", circuit_name),
-        Some("needed for verification"),
-    )?;
-    check_file(
-        format!("target/circom/{}/proof.json", circuit_name),
-        Some("needed for verification"),
+
+    write_json(format!("{}/proof.json", job_directory), &proof.to_json())?;
+    write_json(
+        format!("{}/public.json", job_directory),
+        &public_signals.to_json(),
     )?;
 
-    command_execution(
+    let result = command_execution(
         Executable::SnarkJS,
-        &["g16v", "verification_key.json", "public.json", "proof.json"],
-        Some(&format!("target/circom/{}", circuit_name)),
+        &[
+            "g16v",
+            "../../verification_key.json",
+            "public.json",
+            "proof.json",
+        ],
+        Some(job_directory),
+        control.timeouts.groth16_verify,
+        &control.cancellation,
         &logging_level,
-    )
+    );
+
+    // SnarkJS exits with a non-zero code when the proof is invalid, but also
+    // when it fails to verify it, in which case it does not report the proof
+    match result {
+        Ok(()) => Ok(true),
+        Err(WinterCircomError::ExitCodeError {
+            ref stdout,
+            ref stderr,
+            ..
+        }) if reports_invalid_proof(stdout) || reports_invalid_proof(stderr) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Whether the output of `snarkjs g16v` reports an invalid proof, rather than
+/// a failure to verify it.
+fn reports_invalid_proof(output: &str) -> bool {
+    [
+        "Invalid proof",
+        "Public inputs are not valid",
+        "Proof commitments are not valid",
+    ]
+    .iter()
+    .any(|message| output.contains(message))
 }
 
 /// Generate a Groth16 proof that the Winterfell proof is correct.
@@ -86,13 +215,64 @@ pub fn circom_verify(
 /// This additional check, along with the Groth16 proof verification, is performed
 /// by the [circom_verify] function.
 ///
+/// The prover must be instantiated with the proof options of its AIR (see
+/// [CircomAir::PROOF_OPTIONS]).
+///
+/// The proof and its public signals are returned, and can be written to disk
+/// with the [save_proof](crate::save_proof) function.
+///
+/// The witness computation and Groth16 proof generation are subject to the
+/// timeouts of the execution `control`. Its cancellation is checked between
+/// stages, and kills the running command.
+///
 /// See [crate documentation](crate) for more information.
-pub fn circom_prove<P>(
+pub fn circom_prove<P, const N: usize>(
+    prover: P,
+    trace: <P as Prover>::Trace,
+    control: &ExecutionControl,
+    logging_level: LoggingLevel,
+) -> Result<(Groth16Proof, PublicSignals), WinterCircomError>
+where
+    P: Prover<BaseField = BaseElement>,
+    P::Air: CircomAir<N>,
+{
+    let job = JobDirectory::new(P::Air::CIRCUIT_NAME)?;
+    let committed_values = circom_witness(
+        prover,
+        trace,
+        P::Air::CIRCUIT_NAME,
+        job.path(),
+        control,
+        logging_level,
+    )?;
+    let (proof, signals) =
+        groth16_prove(P::Air::CIRCUIT_NAME, job.path(), control, logging_level)?;
+
+    Ok((
+        proof,
+        PublicSignals {
+            signals,
+            committed_values,
+        },
+    ))
+}
+
+/// Generate the Winterfell proof, parse it into the `input.json` file of the
+/// circuit and compute the execution witness of the circuit.
+///
+/// Both files are written to the `job_directory`, the shared artifacts of the
+/// circuit are only read.
+///
+/// The values behind the public signals commitment are returned if the circuit
+/// commits to its public signals.
+pub(crate) fn circom_witness<P>(
     prover: P,
     trace: <P as Prover>::Trace,
     circuit_name: &str,
+    job_directory: &str,
+    control: &ExecutionControl,
     logging_level: LoggingLevel,
-) -> Result<(), WinterCircomError>
+) -> Result<Option<CommittedValues>, WinterCircomError>
 where
     P: Prover<BaseField = BaseElement>,
     <<P as Prover>::Air as Air>::PublicInputs: WinterPublicInputs,
@@ -104,12 +284,72 @@ where
         println!("{}", "Building STARK proof...".green());
     }
 
-    assert_eq!(prover.options().hash_fn(), HashFunction::Poseidon);
+    control.cancellation.check()?;
+
+    // the circuit only supports the trace lengths, the Merkle arity, the hash
+    // function, the FRI remainder format and the FRI partitions it was
+    // compiled for
+    let options = read_json(
+        format!("target/circom/{}/options.json", circuit_name),
+        Some("generated by circom_compile"),
+    )?;
+    check_trace_length(&options, trace.length())?;
+
+    let merkle_arity = options["merkle_arity"]
+        .as_u64()
+        .map_or(2, |arity| arity as usize);
+    let poseidon2 = options["poseidon2"].as_bool().unwrap_or(false);
+    let hash_fn = hash_function(poseidon2, merkle_arity);
+    if prover.options().hash_fn() != hash_fn {
+        return Err(WinterCircomError::UnsupportedProof(format!(
+            "the hash function must be {}, with Merkle trees of arity {}",
+            if poseidon2 { "Poseidon2" } else { "Poseidon" },
+            merkle_arity
+        )));
+    }
+    let fri_remainder_coefficients = options["fri_remainder_coefficients"]
+        .as_bool()
+        .unwrap_or(false);
+    if prover.options().fri_remainder_coefficients() != fri_remainder_coefficients {
+        return Err(WinterCircomError::UnsupportedProof(format!(
+            "the FRI remainder must be sent as {}",
+            if fri_remainder_coefficients {
+                "coefficients"
+            } else {
+                "evaluations"
+            }
+        )));
+    }
+    let fri_num_partitions = options["fri_num_partitions"]
+        .as_u64()
+        .map_or(1, |num_partitions| num_partitions as usize);
+    if prover.options().fri_num_partitions() != fri_num_partitions {
+        return Err(WinterCircomError::UnsupportedProof(format!(
+            "the FRI layers must be committed to in {} partitions",
+            fri_num_partitions
+        )));
+    }
 
     let pub_inputs = prover.get_pub_inputs(&trace);
-    let proof = prover
-        .prove(trace)
-        .map_err(|e| WinterCircomError::ProverError(e))?;
+
+    // the blinding rows of the zero-knowledge mode must be left unconstrained
+    let num_blinding_rows = options["num_blinding_rows"]
+        .as_u64()
+        .map_or(0, |num_blinding_rows| num_blinding_rows as usize);
+    if num_blinding_rows > 0 {
+        let air = P::Air::new(
+            trace.get_info(),
+            pub_inputs.clone(),
+            prover.options().clone(),
+        );
+        check_blinding_rows(&air, num_blinding_rows)?;
+    }
+
+    let proof = in_stage(stage_span!("stark_prove"), || {
+        prover
+            .prove(trace)
+            .map_err(|e| WinterCircomError::ProverError(e))
+    })?;
 
     // VERIFY PROOF
     // ===========================================================================
@@ -120,8 +360,10 @@ where
             println!("{}", "Verifying STARK proof...".green());
         }
 
-        winterfell::verify::<P::Air>(proof.clone(), pub_inputs.clone())
-            .map_err(|err| WinterCircomError::InvalidProof(Some(err)))?;
+        in_stage(stage_span!("stark_verify"), || {
+            winterfell::verify::<P::Air>(proof.clone(), pub_inputs.clone())
+                .map_err(|err| WinterCircomError::InvalidProof(Some(err)))
+        })?;
     }
 
     // BUILD JSON OUTPUTS
@@ -131,42 +373,88 @@ where
         println!("{}", "Parsing proof to JSON...".green());
     }
 
-    // retrieve air and proof options
-    let air = P::Air::new(
-        proof.get_trace_info(),
-        pub_inputs.clone(),
-        proof.options().clone(),
-    );
+    let json = in_stage(stage_span!("json_export"), || {
+        // retrieve air and proof options
+        let air = P::Air::new(
+            proof.get_trace_info(),
+            pub_inputs.clone(),
+            proof.options().clone(),
+        );
 
-    // convert proof to json object
-    let mut fri_tree_depths = Vec::new();
-    let json = proof_to_json::<P::Air, Poseidon<BaseElement>>(
-        proof,
-        &air,
-        pub_inputs.clone(),
-        &mut fri_tree_depths,
-    );
+        // convert proof to json object
+        let trace_length = proof.get_trace_info().length();
+        let mut fri_tree_depths = Vec::new();
+        // the top levels of the Merkle trees given once are those of the
+        // smallest trace length supported by the circuit
+        let read = |key: &str| options[key].as_u64().map(|value| value as usize);
+        let min_trace_length = read("min_trace_length").unwrap_or(trace_length);
+        let max_trace_length = read("max_trace_length").unwrap_or(trace_length);
+        let mut json = match hash_fn {
+            HashFunction::Poseidon2 => proof_to_json::<P::Air, Poseidon2<BaseElement>>(
+                proof,
+                &air,
+                pub_inputs.clone(),
+                &mut fri_tree_depths,
+                min_trace_length,
+            ),
+            HashFunction::Poseidon4 => proof_to_json::<P::Air, Poseidon<BaseElement, 4>>(
+                proof,
+                &air,
+                pub_inputs.clone(),
+                &mut fri_tree_depths,
+                min_trace_length,
+            ),
+            HashFunction::Poseidon8 => proof_to_json::<P::Air, Poseidon<BaseElement, 8>>(
+                proof,
+                &air,
+                pub_inputs.clone(),
+                &mut fri_tree_depths,
+                min_trace_length,
+            ),
+            _ => proof_to_json::<P::Air, Poseidon<BaseElement>>(
+                proof,
+                &air,
+                pub_inputs.clone(),
+                &mut fri_tree_depths,
+                min_trace_length,
+            ),
+        }?;
 
-    // print json to file
-    let json_string = format!("{}", json);
-    create_dir_all(format!("target/circom/{}", circuit_name)).map_err(|e| {
-        WinterCircomError::IoError {
-            io_error: e,
-            comment: Some(String::from("creating Circom output directory")),
-        }
-    })?;
-    let mut file =
-        File::create(format!("target/circom/{}/input.json", circuit_name)).map_err(|e| {
+        // the inputs of the circuit are sized for its maximum trace length
+        pad_to_max_trace_length(
+            &mut json,
+            air.options(),
+            trace_length,
+            min_trace_length,
+            max_trace_length,
+            merkle_arity,
+        );
+
+        // print json to file
+        let json_string = format!("{}", json);
+        let mut file = File::create(format!("{}/input.json", job_directory)).map_err(|e| {
             WinterCircomError::IoError {
                 io_error: e,
                 comment: Some(String::from("creating input.json")),
             }
         })?;
-    file.write(&json_string.into_bytes())
-        .map_err(|err| WinterCircomError::IoError {
-            io_error: err,
-            comment: Some(String::from("writing input.json")),
-        })?;
+        file.write(&json_string.into_bytes())
+            .map_err(|err| WinterCircomError::IoError {
+                io_error: err,
+                comment: Some(String::from("writing input.json")),
+            })?;
+
+        Ok::<_, WinterCircomError>(json)
+    })?;
+
+    // the values behind the public signals commitment are needed for
+    // verification when the circuit only exposes the commitment (they must
+    // remain private in zero-knowledge mode)
+    let committed_values = if options["public_signals_commitment"] == true {
+        Some(CommittedValues::from_json(&json)?)
+    } else {
+        None
+    };
 
     // CIRCOM MAIN
     // ===========================================================================
@@ -176,85 +464,100 @@ where
         println!("{}", "Generating witness...".green());
     }
 
-    command_execution(
-        Executable::Make,
-        &[],
-        Some(&format!("target/circom/{}/verifier_cpp", circuit_name)),
-        &logging_level,
-    )?;
     check_file(
         format!("target/circom/{}/verifier_cpp/verifier", circuit_name),
-        Some("make command must have failed"),
+        Some("generated by circom_compile"),
     )?;
 
-    delete_file(format!("target/circom/{}/witness.wtns", circuit_name));
-    command_execution(
-        Executable::Custom {
-            path: format!("target/circom/{}/verifier_cpp/verifier", circuit_name),
-            verbose_argument: None,
-        },
-        &["input.json", "witness.wtns"],
-        Some(&format!("target/circom/{}", circuit_name)),
-        &logging_level,
-    )?;
-    check_file(
-        format!("target/circom/{}/witness.wtns", circuit_name),
-        Some("witness generation must have failed"),
-    )?;
+    in_stage(stage_span!("witness_generation"), || {
+        delete_file(format!("{}/witness.wtns", job_directory));
+        command_execution(
+            Executable::Custom {
+                path: format!("target/circom/{}/verifier_cpp/verifier", circuit_name),
+                verbose_argument: None,
+            },
+            &["input.json", "witness.wtns"],
+            Some(job_directory),
+            control.timeouts.witness,
+            &control.cancellation,
+            &logging_level,
+        )?;
+        check_file(
+            format!("{}/witness.wtns", job_directory),
+            Some("witness generation must have failed"),
+        )
+    })?;
+
+    Ok(committed_values)
+}
+
+/// Generate the Groth16 proof from the execution witness computed by
+/// [circom_witness] in the `job_directory`, and return it along with its
+/// public signals.
+pub(crate) fn groth16_prove(
+    circuit_name: &str,
+    job_directory: &str,
+    control: &ExecutionControl,
+    logging_level: LoggingLevel,
+) -> Result<(Groth16Proof, Vec<BaseElement>), WinterCircomError> {
+    // GROTH16 PROOF
+    // ===========================================================================
 
-    // generate snark proof
     if logging_level.print_big_steps() {
         println!("{}", "Generating SNARK proof...".green());
     }
 
-    delete_file(format!("target/circom/{}/proof.json", circuit_name));
-    delete_file(format!("target/circom/{}/public.json", circuit_name));
-    command_execution(
-        Executable::SnarkJS,
-        &[
-            "g16p",
-            "verifier.zkey",
-            "witness.wtns",
-            "proof.json",
-            "public.json",
-        ],
-        Some(&format!("target/circom/{}", circuit_name)),
-        &logging_level,
-    )?;
-    check_file(
-        format!("target/circom/{}/public.json", circuit_name),
-        Some("proof must have failed"),
-    )?;
     check_file(
-        format!("target/circom/{}/proof.json", circuit_name),
-        Some("proof must have failed"),
+        format!("target/circom/{}/verifier.zkey", circuit_name),
+        Some("generated by circom_compile"),
     )?;
 
+    delete_file(format!("{}/proof.json", job_directory));
+    delete_file(format!("{}/public.json", job_directory));
+    in_stage(stage_span!("groth16_prove"), || {
+        command_execution(
+            Executable::SnarkJS,
+            &[
+                "g16p",
+                "../../verifier.zkey",
+                "witness.wtns",
+                "proof.json",
+                "public.json",
+            ],
+            Some(job_directory),
+            control.timeouts.groth16_prove,
+            &control.cancellation,
+            &logging_level,
+        )
+    })?;
+
+    read_groth16_proof(job_directory, logging_level)
+}
+
+/// Read back the Groth16 proof and its public signals, written by SnarkJS to
+/// the `proof.json` and `public.json` files of the `job_directory`.
+pub(crate) fn read_groth16_proof(
+    job_directory: &str,
+    logging_level: LoggingLevel,
+) -> Result<(Groth16Proof, Vec<BaseElement>), WinterCircomError> {
+    // the output files of SnarkJS are only read back, persisting the proof is
+    // left to the caller
+    let comment = Some("proof must have failed");
+    let proof = read_json(format!("{}/proof.json", job_directory), comment)?;
+    let public_signals = read_json(format!("{}/public.json", job_directory), comment)?;
+
+    let proof = Groth16Proof::from_json(&proof)?;
+    let public_signals = parse_elements(&public_signals, "public signals")?;
+
     if logging_level.print_big_steps() {
         println!("{}", "Proof generated successfully!".green());
-        println!(
-            "Proof file:        {}",
-            canonicalize(format!("target/circom/{}/proof.json", circuit_name))?.to_string_lossy()
-        );
-        println!(
-            "Verification key:  {}",
-            canonicalize(format!(
-                "target/circom/{}/verification_key.json",
-                circuit_name
-            ))?
-            .to_string_lossy()
-        );
-        println!(
-            "Public in/outputs: {}",
-            canonicalize(format!("target/circom/{}/public.json", circuit_name))?.to_string_lossy()
-        );
     }
 
-    Ok(())
+    Ok((proof, public_signals))
 }
 
-/// Generate and compile Circom code to verify a Winterfell proof with given
-/// parameters.
+/// Generate and compile Circom code to verify a Winterfell proof of the given
+/// [CircomAir], with its proof options.
 ///
 /// The execution of this function, especially the generation of the circuit-specific keys, can last several minutes.
 ///
@@ -266,11 +569,13 @@ where
 ///
 /// ## Transition constraints and assertions
 ///
-/// This function requires that a file named `<circuit_name>.circom` be placed in
-/// the `circuits/air/` directory. This file must contain two templates:
+/// The [AIR_TEMPLATES](CircomAir::AIR_TEMPLATES) of the AIR, usually the
+/// contents of the `circuits/air/<circuit_name>.circom` file, must contain two
+/// templates:
 ///
 /// - `AIRTransitions` returning the degree of all transition constraints.
-/// - `AIRAssertions` defining the assertions.
+/// - `AIRAssertions` defining the assertions, which takes the trace length as
+///   an input signal.
 ///
 /// These definition are similar to the ones defined in the class implementing
 /// the [Air] trait that is needed by the Winterfell prover and verifier.
@@ -280,30 +585,72 @@ where
 /// ## Steps
 ///
 /// - Generate Circom code to verify a Winterfell proof of given parameters.
-/// - Compile the generated code.
+/// - Compile the generated code with the given `compile_options`, and build its
+/// witness generator.
 /// - Generate circuit-specific keys from the powers of tau phase 1 transcript.
 /// - Export a verification key
+/// - (If `solidity_verifier` is set) Export a Solidity verifier contract, see
+/// [generate_solidity_verifier].
 ///
 /// Generated files are placed in the `target/circom/<circuit_name>/` directory.
-pub fn circom_compile<P, const N: usize>(
-    proof_options: WinterCircomProofOptions<N>,
-    circuit_name: &str,
+///
+/// The warnings of the Circom compiler, such as the signals reported as
+/// under-constrained by its `--inspect` analysis, are returned as
+/// [CircomDiagnostic]s.
+///
+/// The compilation and the generation of the circuit-specific keys are subject
+/// to the `compile` and `setup` timeouts of the execution `control`.
+pub fn circom_compile<AIR, const N: usize>(
+    solidity_verifier: bool,
+    compile_options: &CompileOptions,
+    control: &ExecutionControl,
     logging_level: LoggingLevel,
-) -> Result<(), WinterCircomError>
+) -> Result<Vec<CircomDiagnostic>, WinterCircomError>
 where
-    P: Prover<BaseField = BaseElement>,
-    <<P as Prover>::Air as Air>::PublicInputs: WinterPublicInputs,
+    AIR: CircomAir<N>,
 {
+    let circuit_name = AIR::CIRCUIT_NAME;
+
     // CHECK FOR REQUIRED FILES
 
     check_file(
         String::from("final.ptau"),
         Some("required for the generation of circuit-specific keys"),
     )?;
-    check_file(
-        format!("circuits/air/{}.circom", circuit_name),
-        Some("required for the compilation of Circom code"),
-    )?;
+
+    // GENERATE, COMPILE AND SETUP CIRCUIT
+    // ===========================================================================
+
+    generate_circuit::<AIR, N>(AIR::PROOF_OPTIONS, logging_level)?;
+    let diagnostics =
+        compile_circuit(circuit_name, compile_options, control, logging_level)?;
+    circom_setup(circuit_name, "final.ptau", control, logging_level)?;
+
+    // EXPORT SOLIDITY VERIFIER
+    // ===========================================================================
+
+    if solidity_verifier {
+        if logging_level.print_big_steps() {
+            println!("{}", "Exporting Solidity verifier...".green());
+        }
+
+        generate_solidity_verifier(circuit_name, control, logging_level)?;
+    }
+
+    Ok(diagnostics)
+}
+
+/// Generate the Circom main file of the circuit, write the AIR templates next
+/// to it and the options it was generated with to the `options.json` file.
+pub(crate) fn generate_circuit<AIR, const N: usize>(
+    proof_options: WinterCircomProofOptions<N>,
+    logging_level: LoggingLevel,
+) -> Result<(), WinterCircomError>
+where
+    AIR: CircomAir<N>,
+{
+    let circuit_name = AIR::CIRCUIT_NAME;
+    proof_options.validate()?;
 
     // CREATE OUTPUT DIRECTORY
 
@@ -321,8 +668,43 @@ where
         println!("{}", "Generating Circom code...".green());
     }
 
-    generate_circom_main::<P::BaseField, P::Air, N>(proof_options, circuit_name)?;
+    // the options are needed to verify the proof
+    let mut file = File::create(format!("target/circom/{}/options.json", circuit_name))
+        .map_err(|e| WinterCircomError::IoError {
+            io_error: e,
+            comment: Some(String::from("creating options.json")),
+        })?;
+    file.write(&proof_options.to_json().to_string().into_bytes())
+        .map_err(|err| WinterCircomError::IoError {
+            io_error: err,
+            comment: Some(String::from("writing options.json")),
+        })?;
+
+    // the main file includes the AIR templates from the same directory
+    let mut file = File::create(format!("target/circom/{}/air.circom", circuit_name))
+        .map_err(|e| WinterCircomError::IoError {
+            io_error: e,
+            comment: Some(String::from("creating air.circom")),
+        })?;
+    file.write(AIR::AIR_TEMPLATES.as_bytes())
+        .map_err(|err| WinterCircomError::IoError {
+            io_error: err,
+            comment: Some(String::from("writing air.circom")),
+        })?;
+
+    generate_circom_main::<AIR, N>(proof_options)
+}
 
+/// Compile the generated Circom main file into its R1CS and C++ witness
+/// generator, and the additional artifacts selected by the compile options.
+///
+/// Return the warnings of the Circom compiler.
+pub(crate) fn compile_circuit(
+    circuit_name: &str,
+    compile_options: &CompileOptions,
+    control: &ExecutionControl,
+    logging_level: LoggingLevel,
+) -> Result<Vec<CircomDiagnostic>, WinterCircomError> {
     // COMPILE CIRCOM
     // ===========================================================================
 
@@ -332,16 +714,83 @@ where
 
     delete_file(format!("target/circom/{}/verifier.r1cs", circuit_name));
     delete_directory(format!("target/circom/{}/verifier_cpp", circuit_name));
-    command_execution(
+    delete_file(format!("target/circom/{}/verifier.sym", circuit_name));
+    delete_directory(format!("target/circom/{}/verifier_js", circuit_name));
+    // the AIR templates are copied next to the main file, their includes are
+    // resolved from the circuits/air/ directory
+    let mut args = compile_options.arguments();
+    args.extend(["-l", "../../../circuits/air", "verifier.circom"]);
+    let output = command_output(
         Executable::Circom,
-        &["--r1cs", "--c", "verifier.circom"],
+        &args,
         Some(&format!("target/circom/{}", circuit_name)),
+        control.timeouts.compile,
+        &control.cancellation,
         &logging_level,
     )?;
     check_file(
         format!("target/circom/{}/verifier.r1cs", circuit_name),
         Some("circom command must have failed"),
     )?;
+    if compile_options.sym {
+        check_file(
+            format!("target/circom/{}/verifier.sym", circuit_name),
+            Some("circom command must have failed"),
+        )?;
+    }
+    if compile_options.wasm {
+        check_file(
+            format!("target/circom/{}/verifier_js/verifier.wasm", circuit_name),
+            Some("circom command must have failed"),
+        )?;
+    }
+
+    let mut diagnostics = parse_diagnostics(&output.stderr);
+    diagnostics.extend(parse_diagnostics(&output.stdout));
+    let under_constrained = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.is_under_constrained())
+        .count();
+    if under_constrained > 0 {
+        warn!(under_constrained, "circom reported under-constrained signals");
+    }
+
+    // BUILD WITNESS GENERATOR
+    // ===========================================================================
+
+    if logging_level.print_big_steps() {
+        println!("{}", "Building witness generator...".green());
+    }
+
+    // the witness generator is shared by all proving jobs
+    command_execution(
+        Executable::Make,
+        &[],
+        Some(&format!("target/circom/{}/verifier_cpp", circuit_name)),
+        control.timeouts.compile,
+        &control.cancellation,
+        &logging_level,
+    )?;
+    check_file(
+        format!("target/circom/{}/verifier_cpp/verifier", circuit_name),
+        Some("make command must have failed"),
+    )?;
+
+    Ok(diagnostics)
+}
+
+/// Generate the circuit-specific keys from the powers of tau phase 1 transcript
+/// at `ptau_path`, and export the verification key.
+pub(crate) fn circom_setup(
+    circuit_name: &str,
+    ptau_path: &str,
+    control: &ExecutionControl,
+    logging_level: LoggingLevel,
+) -> Result<(), WinterCircomError> {
+    check_file(
+        String::from(ptau_path),
+        Some("required for the generation of circuit-specific keys"),
+    )?;
 
     // GENERATE CIRCUIT KEY
     // ===========================================================================
@@ -350,16 +799,20 @@ where
         println!("{}", "Generating circuit-specific key...".green());
     }
 
+    let ptau = canonicalize(ptau_path)?;
+
     delete_file(format!("target/circom/{}/verifier.zkey", circuit_name));
     command_execution(
         Executable::SnarkJS,
         &[
             "g16s",
             "verifier.r1cs",
-            "../../../final.ptau",
+            &ptau.to_string_lossy(),
             "verifier.zkey",
         ],
         Some(&format!("target/circom/{}", circuit_name)),
+        control.timeouts.setup,
+        &control.cancellation,
         &logging_level,
     )?;
     check_file(
@@ -367,27 +820,68 @@ where
         Some("circuit-specific key generation must have failed"),
     )?;
 
-    /*
-    delete_file(format!("target/circom/{}/verifier_0001.zkey", circuit_name))?;
+    export_verification_key(circuit_name, control, logging_level)
+}
+
+/// Add a contribution to the circuit-specific keys, and export the new
+/// verification key.
+///
+/// The entropy of the contribution is randomly generated.
+pub(crate) fn circom_contribute(
+    circuit_name: &str,
+    contributor: &str,
+    control: &ExecutionControl,
+    logging_level: LoggingLevel,
+) -> Result<(), WinterCircomError> {
+    check_file(
+        format!("target/circom/{}/verifier.zkey", circuit_name),
+        Some("needed for the contribution to circuit-specific keys"),
+    )?;
+
+    if logging_level.print_big_steps() {
+        println!("{}", "Contributing to circuit-specific key...".green());
+    }
+
+    let entropy = (0..4)
+        .map(|_| format!("{:016x}", rand_value::<u64>()))
+        .collect::<String>();
+
+    delete_file(format!("target/circom/{}/verifier_new.zkey", circuit_name));
     command_execution(
-        canonicalize("iden3/snarkjs/build/cli.cjs")?,
+        Executable::SnarkJS,
         &[
             "zkc",
-            "verifier_0000.zkey",
-            "verifier_0001.zkey",
-            // 25 random alphanumeric characters
-            // TODO: make it work for Windows as well
-            "-e=$(head/dev/urandom | tr -dc a-zA-Z0-9 | head -c 25)",
+            "verifier.zkey",
+            "verifier_new.zkey",
+            &format!("--name={}", contributor),
+            &format!("-e={}", entropy),
         ],
         Some(&format!("target/circom/{}", circuit_name)),
+        control.timeouts.setup,
+        &control.cancellation,
+        &logging_level,
     )?;
     check_file(
-        format!("target/circom/{}/verifier_0001.zkey", circuit_name),
+        format!("target/circom/{}/verifier_new.zkey", circuit_name),
         Some("circuit-specific key contribution must have failed"),
     )?;
-    */
+    rename(
+        format!("target/circom/{}/verifier_new.zkey", circuit_name),
+        format!("target/circom/{}/verifier.zkey", circuit_name),
+    )
+    .map_err(|e| WinterCircomError::IoError {
+        io_error: e,
+        comment: Some(String::from("replacing verifier.zkey")),
+    })?;
+
+    export_verification_key(circuit_name, control, logging_level)
+}
 
-    // export verification key
+fn export_verification_key(
+    circuit_name: &str,
+    control: &ExecutionControl,
+    logging_level: LoggingLevel,
+) -> Result<(), WinterCircomError> {
     delete_file(format!(
         "target/circom/{}/verification_key.json",
         circuit_name
@@ -396,55 +890,32 @@ where
         Executable::SnarkJS,
         &["zkev", "verifier.zkey", "verification_key.json"],
         Some(&format!("target/circom/{}", circuit_name)),
+        control.timeouts.setup,
+        &control.cancellation,
         &logging_level,
     )?;
     check_file(
         format!("target/circom/{}/verification_key.json", circuit_name),
         Some("verification key export must have failed"),
-    )?;
-
-    Ok(())
+    )
 }
 
 /// Generate a circom main file that defines the parameters for verifying a proof.
 ///
 /// The main file is generated in the `target/circom/<circuit_name>/` directory,
-/// with the `verifier.circom` name.
-pub fn generate_circom_main<E, AIR, const N: usize>(
+/// with the `verifier.circom` name. It includes the AIR templates from the
+/// `air.circom` file of the same directory.
+pub fn generate_circom_main<AIR, const N: usize>(
     proof_options: WinterCircomProofOptions<N>,
-    circuit_name: &str,
 ) -> Result<(), WinterCircomError>
 where
-    E: StarkField,
-    AIR: Air,
-    AIR::PublicInputs: WinterPublicInputs,
+    AIR: CircomAir<N>,
 {
-    // FRI TREE DEPTHS
-    let mut fri_tree_depths = vec![];
-    let mut lde_domain_size = proof_options.trace_length * proof_options.lde_blowup_factor();
-    while lde_domain_size > proof_options.fri_max_remainder_size {
-        lde_domain_size /= proof_options.lde_blowup_factor();
-        fri_tree_depths.push(log2(lde_domain_size));
-    }
-
-    let num_fri_layers = fri_tree_depths.len();
-
-    let fri_tree_depths = if fri_tree_depths.len() == 0 {
-        String::from("[0]")
-    } else {
-        format!(
-            "[{}]",
-            fri_tree_depths
-                .iter()
-                .map(|x| format!("{}", x))
-                .collect::<Vec<_>>()
-                .join(", ")
-        )
-    };
+    let circuit_name = AIR::CIRCUIT_NAME;
 
     // AIR CONTEXT
 
-    let air_context = AirContext::<E>::new(
+    let air_context = AirContext::<BaseElement>::new(
         TraceInfo::new(proof_options.trace_width, proof_options.trace_length),
         proof_options.transition_constraint_degrees().to_vec(),
         proof_options.num_assertions(),
@@ -461,59 +932,105 @@ where
 
     // WRITE TO FILE
 
+    // a circuit for a single trace length is a variable-length circuit whose
+    // minimum and maximum trace lengths are equal
+    let max_trace_length = if proof_options.variable_trace_length() {
+        proof_options.max_trace_length()
+    } else {
+        proof_options.trace_length
+    };
     let arguments = format!(
         "{}, // addicity\n    \
             {}, // ce_blowup_factor\n    \
             {}, // domain_offset\n    \
             {}, // folding_factor\n    \
-            {}, // fri_tree_depth\n    \
+            {}, // fri_max_remainder_size\n    \
+            {}, // fri_num_partitions\n    \
+            {}, // fri_remainder_coefficients\n    \
             {}, // grinding_factor\n    \
             {}, // lde_blowup_factor\n    \
+            {}, // max_log_trace_length\n    \
+            {}, // merkle_arity\n    \
+            {}, // min_log_trace_length\n    \
             {}, // num_assertions\n    \
             {}, // num_draws\n    \
-            {}, // num_fri_layers\n    \
             {}, // num_pub_coin_seed\n    \
             {}, // num_public_inputs\n    \
             {}, // num_queries\n    \
             {}, // num_transition_constraints\n    \
-            {}, // trace_length\n    \
-            {}, // trace_width\n    \
-            {} // tree_depth",
-        E::TWO_ADICITY,
+            {}, // num_transition_exemptions\n    \
+            {} // trace_width",
+        BaseElement::TWO_ADICITY,
         air_context.ce_domain_size() / proof_options.trace_length,
-        E::GENERATOR,
+        BaseElement::GENERATOR,
         proof_options.fri_folding_factor(),
-        fri_tree_depths,
+        proof_options.fri_max_remainder_size(),
+        proof_options.fri_num_partitions(),
+        proof_options.fri_remainder_coefficients() as u8,
         proof_options.grinding_factor(),
         proof_options.lde_blowup_factor(),
+        log2(max_trace_length),
+        proof_options.merkle_arity(),
+        log2(proof_options.min_trace_length()),
         proof_options.num_assertions,
+        // the smallest LDE domain requires the most draws
         number_of_draws(
             proof_options.num_queries() as u128,
-            (proof_options.trace_length * proof_options.fri_folding_factor()) as u128,
+            (proof_options.min_trace_length() * proof_options.lde_blowup_factor()) as u128,
             128
         ),
-        num_fri_layers,
         // 2 is the size of the serialized context in f256 field elements
-        AIR::PublicInputs::NUM_PUB_INPUTS + 2,
-        AIR::PublicInputs::NUM_PUB_INPUTS,
+        AIR::NUM_PUBLIC_INPUTS + 2,
+        AIR::NUM_PUBLIC_INPUTS,
         proof_options.num_queries,
         air_context.num_transition_constraints(),
-        proof_options.trace_length,
+        proof_options.num_transition_exemptions(),
         proof_options.trace_width,
-        log2(proof_options.trace_length * proof_options.fri_folding_factor()),
     );
 
+    // the commitment to the public signals is an output of the main component,
+    // which makes it the only public signal of the circuit
+    // in zero-knowledge mode, the OOD consistency check is done in the circuit
+    // and the public inputs of the computation are the only public signals
+    // with a variable trace length, its log2 is the first public signal
+    let main_component = if proof_options.zero_knowledge() {
+        "{public [public_inputs]} = Verify"
+    } else if proof_options.public_signals_commitment() {
+        "= VerifyCommitted"
+    } else if proof_options.variable_trace_length() {
+        "{public [log_trace_length, ood_frame_constraint_evaluation, ood_trace_frame]} = Verify"
+    } else {
+        "{public [ood_frame_constraint_evaluation, ood_trace_frame]} = Verify"
+    };
+
+    // the OOD trace frame is only checked in the circuit in zero-knowledge mode
+    let ood_frame_file = if proof_options.zero_knowledge() {
+        "zero_knowledge"
+    } else {
+        "public"
+    };
+
+    // the hash templates of the circuits are those of the hash function of the
+    // proof
+    let hash_file = if proof_options.poseidon2() {
+        "poseidon2"
+    } else {
+        "poseidon"
+    };
+
     let file_contents = format!(
         "pragma circom 2.0.0;\n\
         \n\
+        include \"../../../circuits/hash/{}.circom\";\n\
+        include \"../../../circuits/ood_frame/{}.circom\";\n\
         include \"../../../circuits/verify.circom\";\n\
-        include \"../../../circuits/air/{}.circom\";\n\
+        include \"air.circom\";\n\
         \n\
-        component main {{public [ood_frame_constraint_evaluation, ood_trace_frame]}} = Verify(\n    \
+        component main {}(\n    \
             {}\n\
         );\n\
 ",
-        circuit_name, arguments
+        hash_file, ood_frame_file, main_component, arguments
     );
 
     file.write(file_contents.as_bytes())
@@ -528,7 +1045,86 @@ where
 // HELPER FUNCTIONS
 // ===========================================================================
 
-fn number_of_draws(num_queries: u128, lde_domain_size: u128, security: i32) -> u128 {
+/// Check that the circuit, compiled with the given `options`, supports the
+/// trace length of the proof.
+///
+/// A variable-length circuit supports the trace lengths between the minimum
+/// and maximum trace lengths of its options whose log2 differ from the one of
+/// the maximum by a multiple of log2(merkle_arity), other circuits only their
+/// trace length.
+fn check_trace_length(options: &Value, trace_length: usize) -> Result<(), WinterCircomError> {
+    let read = |key: &str| options[key].as_u64().map(|value| value as usize);
+    let merkle_arity = read("merkle_arity").unwrap_or(2);
+
+    let supported = match (read("min_trace_length"), read("max_trace_length")) {
+        (Some(min_trace_length), Some(max_trace_length)) => {
+            (min_trace_length..=max_trace_length).contains(&trace_length)
+                && trace_length.is_power_of_two()
+                && log2(max_trace_length / trace_length) % log2(merkle_arity) == 0
+        }
+        _ => read("trace_length") == Some(trace_length),
+    };
+
+    if supported {
+        Ok(())
+    } else {
+        Err(WinterCircomError::UnsupportedProof(format!(
+            "trace length {} is not supported by the circuit, see options.json",
+            trace_length
+        )))
+    }
+}
+
+/// Check that the AIR leaves the last `num_blinding_rows` rows of the trace
+/// unconstrained: the transition constraints must be exempted on them, and no
+/// assertion may be placed on them.
+fn check_blinding_rows<A: Air>(air: &A, num_blinding_rows: usize) -> Result<(), WinterCircomError> {
+    if air.context().num_transition_exemptions() <= num_blinding_rows {
+        return Err(WinterCircomError::UnsupportedProof(format!(
+            "the transition constraints must be exempted on the {} blinding rows and the last step",
+            num_blinding_rows
+        )));
+    }
+
+    let trace_length = air.trace_length();
+    let first_blinding_row = trace_length - num_blinding_rows;
+    for assertion in air.get_assertions() {
+        let last_step = assertion.first_step()
+            + assertion.stride() * (assertion.get_num_steps(trace_length) - 1);
+        if last_step >= first_blinding_row {
+            return Err(WinterCircomError::UnsupportedProof(format!(
+                "assertion on column {} constrains step {}, but the rows from step {} are blinding rows",
+                assertion.column(),
+                last_step,
+                first_blinding_row
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Depths of the Merkle trees of the FRI layers, in levels of `merkle_arity`
+/// children.
+pub(crate) fn fri_tree_depths<const N: usize>(
+    proof_options: &WinterCircomProofOptions<N>,
+) -> Vec<usize> {
+    let lde_domain_size = proof_options.trace_length * proof_options.lde_blowup_factor();
+    let folding_factor = proof_options.fri_folding_factor();
+    let (num_fri_layers, _) = fri_layers(
+        lde_domain_size,
+        folding_factor,
+        proof_options.fri_max_remainder_size,
+    );
+    (1..=num_fri_layers)
+        .map(|layer| {
+            let layer_domain_size = lde_domain_size / folding_factor.pow(layer as u32);
+            (log2(layer_domain_size) / log2(proof_options.merkle_arity())) as usize
+        })
+        .collect()
+}
+
+pub(crate) fn number_of_draws(num_queries: u128, lde_domain_size: u128, security: i32) -> u128 {
     let mut num_draws: u128 = 0;
     let precision: u32 = security as u32 + 2;
 
@@ -0,0 +1,762 @@
+//! Generic command-line tool, driven by a project config file.
+//!
+//! A crate implementing an algorithm to prove only has to implement the
+//! [CircomProject] trait and call the [run] function from its main:
+//!
+//! ```rust
+//! use winter_circom_prover::{cli, utils::WinterCircomError};
+//!
+//! fn main() -> Result<(), WinterCircomError> {
+//!     cli::run::<SumProject, 2>()
+//! }
+//! ```
+//!
+//! The resulting executable provides the following subcommands:
+//!
+//! - `compile` - generate and compile the Circom circuit.
+//! - `setup` - generate the circuit-specific keys from the powers of tau phase 1
+//! transcript (and the Solidity verifier, if enabled).
+//! - `contribute` - add a random contribution to the circuit-specific keys.
+//! - `witness` - generate the Winterfell proof and compute the circuit witness,
+//! in the `jobs/witness/` directory of the circuit.
+//! - `prove` - generate the Winterfell proof and its Groth16 proof.
+//! - `verify` - verify the Groth16 proof and check the OOD consistency.
+//! - `inspect` - print the generated artifacts, options, public signals and
+//! R1CS information of the circuit.
+//! - `clean` - delete the generated artifacts of the circuit.
+//! - `estimate` - print the parameters of the circuit, without compiling it.
+//! - `serve` - run the local proving service of the circuit (with the `server`
+//! feature), see the [server](crate::server) module. The JSON document of
+//! each job gives the inputs of the computation, in place of the `[inputs]`
+//! table.
+//!
+//! # Project config
+//!
+//! The project config is a TOML file, named `winter-circom.toml` by default
+//! (see the `--config` argument).
+//!
+//! ```toml
+//! # workspace root, relative to this file (default ".")
+//! root = "../.."
+//! # Groth16 backend (default "snarkjs", the only supported backend)
+//! backend = "snarkjs"
+//! # export a Solidity verifier along with the circuit-specific keys (default false)
+//! solidity_verifier = true
+//!
+//! [paths]
+//! # powers of tau phase 1 transcript, relative to the root (default "final.ptau")
+//! ptau = "final.ptau"
+//!
+//! # options of the Circom compiler (all optional)
+//! [compile]
+//! # simplification level of the constraints: "O0", "O1" or "O2" (default of
+//! # the compiler if unset)
+//! simplification = "O2"
+//! # static analysis of the circuit, reporting under-constrained signals
+//! inspect = true
+//! # export the signal names to verifier.sym
+//! sym = true
+//! # build the WebAssembly witness generator in verifier_js/
+//! wasm = false
+//!
+//! # timeouts of the underlying commands, in seconds (all optional, no timeout
+//! # by default)
+//! [timeouts]
+//! compile = 600
+//! setup = 3600
+//! witness = 60
+//! groth16_prove = 600
+//! groth16_verify = 60
+//!
+//! # overrides of the proof options of the AIR (all optional)
+//! [options]
+//! trace_length = 128
+//! num_queries = 32
+//! lde_blowup_factor = 8
+//! grinding_factor = 0
+//! fri_folding_factor = 8
+//! fri_max_remainder_size = 128
+//! public_signals_commitment = false
+//! # verify any trace length up to this one with a single circuit (0 for the
+//! # trace length only)
+//! max_trace_length = 1024
+//! # arity of the Merkle trees: 2, 4 or 8
+//! merkle_arity = 2
+//! # hash with Poseidon2 instead of Poseidon (binary Merkle trees only)
+//! poseidon2 = false
+//! # send the FRI remainder as polynomial coefficients
+//! fri_remainder_coefficients = false
+//! # number of partitions of the FRI layers, a power of 2
+//! fri_num_partitions = 1
+//!
+//! # inputs of the computation, given to the project
+//! [inputs]
+//! start = 1
+//! ```
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use clap::{ArgAction, Parser, Subcommand};
+use colored::Colorize;
+use serde::Deserialize;
+use serde_json::Value;
+use winterfell::{math::fields::f256::BaseElement, Air, ProofOptions, Prover};
+
+use crate::{
+    circom::{
+        circom_contribute, circom_setup, circom_witness, compile_circuit, fri_tree_depths,
+        generate_circuit, number_of_draws,
+    },
+    circom_prove, circom_verify, generate_solidity_verifier, load_proof, save_proof,
+    utils::{
+        command_execution, delete_directory, read_json, Executable, ExecutionControl, LoggingLevel,
+        StageTimeouts, WinterCircomError,
+    },
+    CircomAir, CircomDiagnostic, CompileOptions, Simplification, VerificationReport,
+    WinterCircomProofOptions, WinterPublicInputs,
+};
+#[cfg(feature = "server")]
+use crate::{server::ProvingServer, CircuitHandle};
+
+/// AIR of the computation proven by a [CircomProject].
+pub type ProjectAir<P, const N: usize> = <<P as CircomProject<N>>::Prover as Prover>::Air;
+
+/// Public inputs of the computation proven by a [CircomProject].
+pub type ProjectPublicInputs<P, const N: usize> = <ProjectAir<P, N> as Air>::PublicInputs;
+
+/// An algorithm to prove, registered with the command-line tool.
+///
+/// The circuit name, proof options and Circom templates are those of the
+/// [CircomAir] of the prover. The proof options can be partially overridden by
+/// the `[options]` table of the project config.
+pub trait CircomProject<const N: usize> {
+    /// Winterfell prover of the computation.
+    type Prover: Prover<BaseField = BaseElement>;
+
+    /// Instantiate the prover.
+    fn prover(options: ProofOptions) -> Self::Prover;
+
+    /// Build the execution trace of the computation, from the `[inputs]` table
+    /// of the project config.
+    ///
+    /// In zero-knowledge mode, the trace must be blinded here (see
+    /// [blind_trace](WinterCircomProofOptions::blind_trace)).
+    fn build_trace(
+        prover: &Self::Prover,
+        trace_length: usize,
+        inputs: &Value,
+    ) -> Result<<Self::Prover as Prover>::Trace, WinterCircomError>;
+
+    /// Public inputs of the statement to verify, from the `[inputs]` table of
+    /// the project config.
+    fn public_inputs(
+        trace_length: usize,
+        inputs: &Value,
+    ) -> Result<ProjectPublicInputs<Self, N>, WinterCircomError>;
+}
+
+// PROJECT CONFIG
+// ===========================================================================
+
+/// Project config file, see [module documentation](self).
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectConfig {
+    #[serde(default = "default_root")]
+    pub root: PathBuf,
+    #[serde(default)]
+    pub backend: Backend,
+    #[serde(default)]
+    pub solidity_verifier: bool,
+    #[serde(default)]
+    pub paths: PathsConfig,
+    #[serde(default)]
+    pub compile: CompileConfig,
+    #[serde(default)]
+    pub timeouts: TimeoutsConfig,
+    #[serde(default)]
+    pub options: OptionsConfig,
+    #[serde(default)]
+    pub inputs: toml::Table,
+}
+
+/// Paths of the project config, relative to its root directory.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PathsConfig {
+    #[serde(default = "default_ptau")]
+    pub ptau: String,
+}
+
+/// Options of the Circom compiler (see [CompileOptions]).
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CompileConfig {
+    pub simplification: Option<Simplification>,
+    #[serde(default)]
+    pub inspect: bool,
+    #[serde(default)]
+    pub sym: bool,
+    #[serde(default)]
+    pub wasm: bool,
+}
+
+/// Timeouts of the underlying commands, in seconds, per stage of the pipeline
+/// (see [StageTimeouts]).
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TimeoutsConfig {
+    pub compile: Option<u64>,
+    pub setup: Option<u64>,
+    pub witness: Option<u64>,
+    pub groth16_prove: Option<u64>,
+    pub groth16_verify: Option<u64>,
+}
+
+/// Overrides of the proof options of the AIR of the project.
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OptionsConfig {
+    pub trace_length: Option<usize>,
+    pub num_queries: Option<usize>,
+    pub lde_blowup_factor: Option<usize>,
+    pub grinding_factor: Option<u32>,
+    pub fri_folding_factor: Option<usize>,
+    pub fri_max_remainder_size: Option<usize>,
+    pub public_signals_commitment: Option<bool>,
+    pub max_trace_length: Option<usize>,
+    pub merkle_arity: Option<usize>,
+    pub poseidon2: Option<bool>,
+    pub fri_remainder_coefficients: Option<bool>,
+    pub fri_num_partitions: Option<usize>,
+}
+
+/// Groth16 proving backend.
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    /// SnarkJS, with the C++ witness generator of Circom.
+    #[default]
+    SnarkJS,
+}
+
+impl ProjectConfig {
+    /// Read a project config file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, WinterCircomError> {
+        let path = path.as_ref();
+        let data = fs::read_to_string(path).map_err(|e| WinterCircomError::IoError {
+            io_error: e,
+            comment: Some(format!("reading {}", path.to_string_lossy())),
+        })?;
+
+        toml::from_str(&data).map_err(|e| WinterCircomError::ParsingError {
+            file: path.to_string_lossy().into_owned(),
+            comment: Some(e.to_string()),
+        })
+    }
+}
+
+impl OptionsConfig {
+    /// Override the given proof options.
+    pub fn apply<const N: usize>(
+        &self,
+        mut options: WinterCircomProofOptions<N>,
+    ) -> Result<WinterCircomProofOptions<N>, WinterCircomError> {
+        if let Some(trace_length) = self.trace_length {
+            options.trace_length = trace_length;
+        }
+        if let Some(num_queries) = self.num_queries {
+            options.num_queries = num_queries;
+        }
+        if let Some(lde_blowup_factor) = self.lde_blowup_factor {
+            options.lde_blowup_factor = lde_blowup_factor;
+        }
+        if let Some(grinding_factor) = self.grinding_factor {
+            options.grinding_factor = grinding_factor;
+        }
+        if let Some(fri_folding_factor) = self.fri_folding_factor {
+            options.fri_folding_factor = fri_folding_factor;
+        }
+        if let Some(fri_max_remainder_size) = self.fri_max_remainder_size {
+            options.fri_max_remainder_size = fri_max_remainder_size;
+        }
+        if let Some(public_signals_commitment) = self.public_signals_commitment {
+            if public_signals_commitment && options.zero_knowledge() {
+                return Err(WinterCircomError::ParsingError {
+                    file: String::from("project config"),
+                    comment: Some(String::from(
+                        "public signals commitment and zero-knowledge modes are mutually exclusive",
+                    )),
+                });
+            }
+            options.public_signals_commitment = public_signals_commitment;
+        }
+        if let Some(max_trace_length) = self.max_trace_length {
+            if max_trace_length > 0
+                && (options.zero_knowledge() || options.public_signals_commitment())
+            {
+                return Err(WinterCircomError::ParsingError {
+                    file: String::from("project config"),
+                    comment: Some(String::from(
+                        "variable trace length mode is exclusive with the zero-knowledge and public signals commitment modes",
+                    )),
+                });
+            }
+            options.max_trace_length = max_trace_length;
+        }
+        if let Some(merkle_arity) = self.merkle_arity {
+            options.merkle_arity = merkle_arity;
+        }
+        if let Some(poseidon2) = self.poseidon2 {
+            options.poseidon2 = poseidon2;
+        }
+        if let Some(fri_remainder_coefficients) = self.fri_remainder_coefficients {
+            options.fri_remainder_coefficients = fri_remainder_coefficients;
+        }
+        if let Some(fri_num_partitions) = self.fri_num_partitions {
+            options.fri_num_partitions = fri_num_partitions;
+        }
+
+        Ok(options)
+    }
+}
+
+impl CompileConfig {
+    /// Convert the config into compile options.
+    pub fn compile_options(&self) -> CompileOptions {
+        CompileOptions {
+            simplification: self.simplification,
+            inspect: self.inspect,
+            sym: self.sym,
+            wasm: self.wasm,
+        }
+    }
+}
+
+impl TimeoutsConfig {
+    /// Convert the timeouts into the stage timeouts of an execution control.
+    pub fn stage_timeouts(&self) -> StageTimeouts {
+        StageTimeouts {
+            compile: self.compile.map(Duration::from_secs),
+            setup: self.setup.map(Duration::from_secs),
+            witness: self.witness.map(Duration::from_secs),
+            groth16_prove: self.groth16_prove.map(Duration::from_secs),
+            groth16_verify: self.groth16_verify.map(Duration::from_secs),
+        }
+    }
+}
+
+impl Default for PathsConfig {
+    fn default() -> Self {
+        Self {
+            ptau: default_ptau(),
+        }
+    }
+}
+
+fn default_root() -> PathBuf {
+    PathBuf::from(".")
+}
+
+fn default_ptau() -> String {
+    String::from("final.ptau")
+}
+
+// COMMAND-LINE INTERFACE
+// ===========================================================================
+
+/// Command-line arguments of the tool, parsed by [run].
+#[derive(Parser)]
+#[command(about = "Generate STARK - SNARK recursive proofs")]
+pub struct Cli {
+    /// Path to the project config file
+    #[arg(short, long, default_value = "winter-circom.toml")]
+    pub config: PathBuf,
+
+    /// Print the output of underlying executables (twice to set them to verbose mode)
+    #[arg(short, long, action = ArgAction::Count, conflicts_with = "quiet")]
+    pub verbose: u8,
+
+    /// Do not print anything to stdout
+    #[arg(short, long)]
+    pub quiet: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// Subcommands of the tool, see [module documentation](self).
+#[derive(Debug, PartialEq, Eq, Subcommand)]
+pub enum Command {
+    /// Generate and compile the Circom circuit
+    Compile,
+    /// Generate the circuit-specific keys
+    Setup,
+    /// Add a random contribution to the circuit-specific keys
+    Contribute {
+        /// Name of the contributor
+        #[arg(long, default_value = "winter-circom")]
+        name: String,
+    },
+    /// Generate the Winterfell proof and compute the circuit witness
+    Witness,
+    /// Generate the Winterfell proof and its Groth16 proof
+    Prove,
+    /// Verify the Groth16 proof and check the OOD consistency
+    Verify,
+    /// Print the generated artifacts of the circuit
+    Inspect,
+    /// Delete the generated artifacts of the circuit
+    Clean,
+    /// Print the parameters of the circuit, without compiling it
+    Estimate,
+    /// Run the local proving service of the circuit
+    #[cfg(feature = "server")]
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        address: String,
+        /// Number of jobs proven concurrently
+        #[arg(long, default_value_t = 1)]
+        workers: usize,
+    },
+}
+
+/// Parse the command-line arguments and run the requested subcommand for the
+/// given project.
+pub fn run<P, const N: usize>() -> Result<(), WinterCircomError>
+where
+    P: CircomProject<N> + 'static,
+    ProjectAir<P, N>: CircomAir<N>,
+{
+    let cli = Cli::parse();
+    let logging_level = match (cli.quiet, cli.verbose) {
+        (true, _) => LoggingLevel::Quiet,
+        (false, 0) => LoggingLevel::Default,
+        (false, 1) => LoggingLevel::Verbose,
+        (false, _) => LoggingLevel::VeryVerbose,
+    };
+
+    let config = ProjectConfig::load(&cli.config)?;
+
+    // all paths are relative to the root of the project
+    let root = cli
+        .config
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(&config.root);
+    env::set_current_dir(&root).map_err(|e| WinterCircomError::IoError {
+        io_error: e,
+        comment: Some(format!("changing directory to {}", root.to_string_lossy())),
+    })?;
+
+    let options = config.options.apply(ProjectAir::<P, N>::PROOF_OPTIONS)?;
+    options.validate()?;
+    let control = ExecutionControl {
+        timeouts: config.timeouts.stage_timeouts(),
+        ..ExecutionControl::default()
+    };
+    let circuit_name = ProjectAir::<P, N>::CIRCUIT_NAME;
+    let inputs =
+        serde_json::to_value(&config.inputs).map_err(|e| WinterCircomError::JsonError {
+            json_error: e,
+            comment: Some(String::from("converting the inputs of the project config")),
+        })?;
+
+    match cli.command {
+        Command::Compile => {
+            generate_circuit::<ProjectAir<P, N>, N>(options, logging_level)?;
+            let diagnostics = compile_circuit(
+                circuit_name,
+                &config.compile.compile_options(),
+                &control,
+                logging_level,
+            )?;
+            print_diagnostics(&diagnostics, logging_level);
+            Ok(())
+        }
+        Command::Setup => {
+            circom_setup(circuit_name, &config.paths.ptau, &control, logging_level)?;
+            if config.solidity_verifier {
+                generate_solidity_verifier(circuit_name, &control, logging_level)?;
+            }
+            Ok(())
+        }
+        Command::Contribute { name } => {
+            circom_contribute(circuit_name, &name, &control, logging_level)?;
+            if config.solidity_verifier {
+                generate_solidity_verifier(circuit_name, &control, logging_level)?;
+            }
+            Ok(())
+        }
+        Command::Witness => {
+            let prover = P::prover(options.get_proof_options());
+            let trace = P::build_trace(&prover, options.trace_length, &inputs)?;
+
+            // unlike proving jobs, the witness is kept for inspection
+            let directory = format!("target/circom/{}/jobs/witness", circuit_name);
+            fs::create_dir_all(&directory).map_err(|e| WinterCircomError::IoError {
+                io_error: e,
+                comment: Some(format!("creating {}", directory)),
+            })?;
+            circom_witness(
+                prover,
+                trace,
+                circuit_name,
+                &directory,
+                &control,
+                logging_level,
+            )?;
+            Ok(())
+        }
+        Command::Prove => {
+            let prover = P::prover(options.get_proof_options());
+            let trace = P::build_trace(&prover, options.trace_length, &inputs)?;
+            let (proof, public_signals) =
+                circom_prove::<P::Prover, N>(prover, trace, &control, logging_level)?;
+            save_proof(circuit_name, &proof, &public_signals)
+        }
+        Command::Verify => {
+            let pub_inputs = P::public_inputs(options.trace_length, &inputs)?;
+            let (proof, public_signals) = load_proof(circuit_name)?;
+            let report = circom_verify::<ProjectAir<P, N>, N>(
+                &proof,
+                &public_signals,
+                pub_inputs,
+                &control,
+                logging_level,
+            )?;
+            print_report(&report, logging_level);
+
+            if report.is_valid() {
+                Ok(())
+            } else {
+                Err(WinterCircomError::InvalidProof(None))
+            }
+        }
+        Command::Inspect => inspect(circuit_name, logging_level),
+        Command::Clean => {
+            delete_directory(format!("target/circom/{}", circuit_name));
+            Ok(())
+        }
+        Command::Estimate => {
+            estimate::<ProjectPublicInputs<P, N>, N>(&options);
+            Ok(())
+        }
+        #[cfg(feature = "server")]
+        Command::Serve { address, workers } => {
+            serve::<P, N>(options, control.timeouts, &address, workers, logging_level)
+        }
+    }
+}
+
+// SUBCOMMANDS
+// ===========================================================================
+
+#[cfg(feature = "server")]
+fn serve<P, const N: usize>(
+    options: WinterCircomProofOptions<N>,
+    timeouts: StageTimeouts,
+    address: &str,
+    workers: usize,
+    logging_level: LoggingLevel,
+) -> Result<(), WinterCircomError>
+where
+    P: CircomProject<N> + 'static,
+    ProjectAir<P, N>: CircomAir<N>,
+{
+    let circuit_name = ProjectAir::<P, N>::CIRCUIT_NAME;
+    let handle = CircuitHandle::<ProjectAir<P, N>, N>::new(logging_level)?;
+
+    let server = ProvingServer::start(
+        address,
+        PathBuf::from(format!("target/circom/{}/server", circuit_name)),
+        workers,
+        timeouts,
+        move |inputs, control| {
+            let prover = P::prover(options.get_proof_options());
+            let trace = P::build_trace(&prover, options.trace_length, inputs)?;
+            handle.prove(prover, trace, control)
+        },
+    )?;
+
+    if logging_level.print_big_steps() {
+        println!(
+            "{}",
+            format!("Listening on http://{}", server.address()).green()
+        );
+    }
+    server.run();
+    Ok(())
+}
+
+fn print_report(report: &VerificationReport, logging_level: LoggingLevel) {
+    if !logging_level.print_big_steps() {
+        return;
+    }
+
+    let status = |valid: bool| {
+        if valid {
+            "valid".green()
+        } else {
+            "invalid".yellow()
+        }
+    };
+    println!("{}", "Verification report:".green());
+    println!("  Groth16 proof:         {}", status(report.groth16_valid));
+    println!("  OOD consistency:       {}", status(report.ood_valid));
+    println!(
+        "  public inputs:         {}",
+        status(report.public_inputs_match)
+    );
+    println!("  Groth16 verification:  {:?}", report.groth16_time);
+    println!("  OOD check:             {:?}", report.ood_time);
+}
+
+fn print_diagnostics(diagnostics: &[CircomDiagnostic], logging_level: LoggingLevel) {
+    if !logging_level.print_big_steps() || diagnostics.is_empty() {
+        return;
+    }
+
+    let under_constrained = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.is_under_constrained())
+        .count();
+    println!(
+        "{}",
+        format!(
+            "{} compiler warning(s), {} under-constrained signal(s):",
+            diagnostics.len(),
+            under_constrained
+        )
+        .yellow()
+    );
+    for diagnostic in diagnostics {
+        let code = diagnostic.code.as_deref().unwrap_or("warning");
+        println!("  [{}] {}", code, diagnostic.message);
+        if let Some(location) = &diagnostic.location {
+            println!("    at {}", location);
+        }
+    }
+}
+
+fn inspect(circuit_name: &str, logging_level: LoggingLevel) -> Result<(), WinterCircomError> {
+    if !logging_level.print_big_steps() {
+        return Ok(());
+    }
+
+    let directory = format!("target/circom/{}", circuit_name);
+    println!("{}", format!("Artifacts of {}:", directory).green());
+    for file in [
+        "options.json",
+        "verifier.circom",
+        "verifier.r1cs",
+        "verifier.sym",
+        "verifier.zkey",
+        "verification_key.json",
+        "verifier.sol",
+        "jobs/witness/input.json",
+        "jobs/witness/witness.wtns",
+        "proof.json",
+        "public.json",
+        "public_values.json",
+    ] {
+        match fs::metadata(format!("{}/{}", directory, file)) {
+            Ok(metadata) => println!("  {:<28}{} bytes", file, metadata.len()),
+            Err(_) => println!("  {:<28}{}", file, "missing".yellow()),
+        }
+    }
+
+    for file in ["options.json", "public.json"] {
+        if let Ok(json) = read_json(format!("{}/{}", directory, file), None) {
+            println!("{}", format!("Contents of {}:", file).green());
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&json).unwrap_or_default()
+            );
+        }
+    }
+
+    if Path::new(&format!("{}/verifier.r1cs", directory)).exists() {
+        println!("{}", "R1CS information:".green());
+        command_execution(
+            Executable::SnarkJS,
+            &["ri", "verifier.r1cs"],
+            Some(&directory),
+            None,
+            &Default::default(),
+            &LoggingLevel::Verbose,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn estimate<PI, const N: usize>(options: &WinterCircomProofOptions<N>)
+where
+    PI: WinterPublicInputs,
+{
+    let lde_domain_size = options.trace_length * options.lde_blowup_factor();
+    let fri_tree_depths = fri_tree_depths(options);
+    let num_public_signals = if options.zero_knowledge() {
+        PI::NUM_PUB_INPUTS
+    } else if options.public_signals_commitment() {
+        1
+    } else if options.variable_trace_length() {
+        3 * options.trace_width + 1
+    } else {
+        3 * options.trace_width
+    };
+
+    // conjectured soundness of the FRI protocol, see the Winterfell documentation
+    let security = options.num_queries() * options.lde_blowup_factor().trailing_zeros() as usize
+        + options.grinding_factor() as usize;
+
+    println!("{}", "Circuit parameters:".green());
+    println!("  trace length:          {}", options.trace_length);
+    if options.variable_trace_length() {
+        println!(
+            "  supported lengths:     {} to {}",
+            options.min_trace_length(),
+            options.max_trace_length()
+        );
+    }
+    println!("  trace width:           {}", options.trace_width);
+    println!("  LDE domain size:       {}", lde_domain_size);
+    println!("  number of queries:     {}", options.num_queries());
+    println!(
+        "  number of draws:       {}",
+        number_of_draws(
+            options.num_queries() as u128,
+            (options.trace_length * options.fri_folding_factor()) as u128,
+            128
+        )
+    );
+    println!("  FRI layers:            {}", fri_tree_depths.len());
+    println!("  FRI tree depths:       {:?}", fri_tree_depths);
+    println!("  Merkle arity:          {}", options.merkle_arity());
+    println!(
+        "  hash function:         {}",
+        if options.poseidon2() {
+            "Poseidon2"
+        } else {
+            "Poseidon"
+        }
+    );
+    println!(
+        "  FRI remainder:         {}",
+        if options.fri_remainder_coefficients() {
+            "coefficients"
+        } else {
+            "evaluations"
+        }
+    );
+    println!("  FRI partitions:        {}", options.fri_num_partitions());
+    println!("  public signals:        {}", num_public_signals);
+    println!("  conjectured security:  {} bits", security);
+    println!("Run the compile and inspect commands for the number of constraints of the circuit.");
+}
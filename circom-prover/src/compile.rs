@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+
+// COMPILE OPTIONS
+// ===========================================================================
+
+/// Options of the compilation of the generated Circom code, see
+/// [circom_compile](crate::circom_compile).
+///
+/// The default options compile the R1CS and the C++ witness generator only,
+/// with the default simplification level of the Circom compiler.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompileOptions {
+    /// Simplification level of the constraints (`--O0`, `--O1` or `--O2`), or
+    /// the default level of the compiler if `None`.
+    pub simplification: Option<Simplification>,
+    /// Run the static analysis of the compiler (`--inspect`), reporting
+    /// signals that may be under-constrained as [CircomDiagnostic]s.
+    pub inspect: bool,
+    /// Export the signal names of the circuit to `verifier.sym` (`--sym`).
+    pub sym: bool,
+    /// Build the WebAssembly witness generator in `verifier_js/` (`--wasm`),
+    /// in addition to the C++ one.
+    pub wasm: bool,
+}
+
+/// Simplification level of the constraints generated by the Circom compiler.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Simplification {
+    /// No simplification.
+    O0,
+    /// Signal-to-signal and signal-to-constant simplification.
+    O1,
+    /// Full constraint simplification.
+    O2,
+}
+
+impl CompileOptions {
+    /// Arguments of the Circom compiler selected by these options.
+    pub(crate) fn arguments(&self) -> Vec<&'static str> {
+        let mut arguments = vec!["--r1cs", "--c"];
+        if let Some(simplification) = self.simplification {
+            arguments.push(simplification.flag());
+        }
+        if self.inspect {
+            arguments.push("--inspect");
+        }
+        if self.sym {
+            arguments.push("--sym");
+        }
+        if self.wasm {
+            arguments.push("--wasm");
+        }
+        arguments
+    }
+}
+
+impl Simplification {
+    fn flag(&self) -> &'static str {
+        match self {
+            Self::O0 => "--O0",
+            Self::O1 => "--O1",
+            Self::O2 => "--O2",
+        }
+    }
+}
+
+// DIAGNOSTICS
+// ===========================================================================
+
+/// Warning emitted by the Circom compiler.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CircomDiagnostic {
+    /// Code of the warning, such as `CA01`.
+    pub code: Option<String>,
+    /// Message of the warning.
+    pub message: String,
+    /// Template the warning was emitted in.
+    pub template: Option<String>,
+    /// Signal (or array of signals) the warning is about.
+    pub signal: Option<String>,
+    /// Source location of the warning, as `"file":line:column`.
+    pub location: Option<String>,
+}
+
+impl CircomDiagnostic {
+    /// Whether this warning reports a signal that does not appear in any
+    /// constraint, as reported by the `--inspect` static analysis.
+    pub fn is_under_constrained(&self) -> bool {
+        self.message.contains("not appear in any constraint")
+    }
+}
+
+/// Parse the warnings from the output of the Circom compiler.
+///
+/// Each warning starts with a `warning[<code>]: <message>` line, optionally
+/// followed by its source location. Other lines are ignored.
+pub fn parse_diagnostics(output: &str) -> Vec<CircomDiagnostic> {
+    let mut diagnostics: Vec<CircomDiagnostic> = Vec::new();
+    // only the lines following a warning header may give its location
+    let mut in_warning = false;
+
+    for line in output.lines() {
+        let line = strip_ansi_codes(line);
+        let line = line.trim();
+
+        if let Some(rest) = line.strip_prefix("warning") {
+            let (code, message) = match rest.strip_prefix('[') {
+                Some(rest) => match rest.split_once("]:") {
+                    Some((code, message)) => (Some(code.to_string()), message),
+                    None => continue,
+                },
+                None => match rest.strip_prefix(':') {
+                    Some(message) => (None, message),
+                    None => continue,
+                },
+            };
+            let message = message.trim();
+
+            diagnostics.push(CircomDiagnostic {
+                code,
+                message: message.to_string(),
+                template: parse_template(message),
+                signal: parse_signal(message),
+                location: None,
+            });
+            in_warning = true;
+        } else if line.starts_with("error") {
+            in_warning = false;
+        } else if in_warning {
+            let location = line
+                .strip_prefix("┌─")
+                .or_else(|| line.strip_prefix("-->"))
+                .map(str::trim);
+            if let (Some(location), Some(diagnostic)) = (location, diagnostics.last_mut()) {
+                if diagnostic.location.is_none() {
+                    diagnostic.location = Some(location.to_string());
+                }
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// Template name of a message starting with `In template "<name>":`.
+fn parse_template(message: &str) -> Option<String> {
+    let rest = message.strip_prefix("In template ")?;
+    let (template, _) = rest.split_once(':')?;
+    Some(template.trim_matches('"').to_string())
+}
+
+/// Name following the first `signal` or `signals` word of a message.
+fn parse_signal(message: &str) -> Option<String> {
+    let mut words = message.split_whitespace();
+    words.find(|word| *word == "signal" || *word == "signals")?;
+    words
+        .next()
+        .map(|signal| signal.trim_matches(|c| c == '"' || c == '`').to_string())
+}
+
+/// Remove the terminal color codes from a line of output.
+fn strip_ansi_codes(line: &str) -> String {
+    let mut stripped = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // skip the escape sequence, up to its final letter
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
@@ -0,0 +1,339 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    marker::PhantomData,
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::{
+        mpsc::{channel, Receiver, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    thread,
+    time::Instant,
+};
+
+use colored::Colorize;
+use serde_json::{json, Value};
+use tracing::{debug, warn};
+use winterfell::{math::fields::f256::BaseElement, Prover};
+
+use crate::{
+    circom::{circom_witness, read_groth16_proof},
+    circom_verify,
+    utils::{
+        check_file, in_process_group, in_stage, kill_process_group, snarkjs_library, stage_span,
+        ExecutionControl, JobDirectory, LoggingLevel, WinterCircomError, POLL_INTERVAL,
+    },
+    CircomAir, Groth16Proof, PublicSignals, VerificationReport,
+};
+
+/// Thread-safe handle on the compiled circuit of a [CircomAir], for running
+/// several proving and verification jobs in parallel.
+///
+/// The shared artifacts of the circuit (R1CS, keys and witness generator),
+/// generated by [circom_compile](crate::circom_compile), are only read. Each job
+/// runs in its own scratch directory, in the
+/// `target/circom/<circuit_name>/jobs/` directory, which is deleted when the
+/// job completes.
+///
+/// The Groth16 proofs are generated by persistent SnarkJS processes, which keep
+/// the proving key of the circuit in memory across jobs, instead of one SnarkJS
+/// command per proof. A process is started for each concurrent proving job, and
+/// reused by the following ones. It is killed if its job fails, times out or
+/// is cancelled, and when the handle is dropped.
+///
+/// The handle can be shared between threads, for instance in an
+/// [Arc](std::sync::Arc).
+pub struct CircuitHandle<AIR, const N: usize> {
+    logging_level: LoggingLevel,
+    /// Persistent provers waiting for a job.
+    provers: Mutex<Vec<Groth16Prover>>,
+    _air: PhantomData<fn() -> AIR>,
+}
+
+impl<AIR, const N: usize> CircuitHandle<AIR, N>
+where
+    AIR: CircomAir<N>,
+{
+    /// Open a handle on the compiled circuit of the AIR, checking that its
+    /// shared artifacts have been generated.
+    pub fn new(logging_level: LoggingLevel) -> Result<Self, WinterCircomError> {
+        for file in [
+            "options.json",
+            "verifier.zkey",
+            "verification_key.json",
+            "verifier_cpp/verifier",
+        ] {
+            check_file(
+                format!("target/circom/{}/{}", AIR::CIRCUIT_NAME, file),
+                Some("generated by circom_compile"),
+            )?;
+        }
+
+        Ok(Self {
+            logging_level,
+            provers: Mutex::new(vec![]),
+            _air: PhantomData,
+        })
+    }
+
+    /// Generate a Groth16 proof that the Winterfell proof of the trace is
+    /// correct, like [circom_prove](crate::circom_prove), with a persistent
+    /// SnarkJS prover.
+    ///
+    /// Each job can be given its own execution `control`, to cancel it
+    /// independently of the others.
+    pub fn prove<P>(
+        &self,
+        prover: P,
+        trace: P::Trace,
+        control: &ExecutionControl,
+    ) -> Result<(Groth16Proof, PublicSignals), WinterCircomError>
+    where
+        P: Prover<BaseField = BaseElement, Air = AIR>,
+    {
+        let job = JobDirectory::new(AIR::CIRCUIT_NAME)?;
+        let committed_values = circom_witness(
+            prover,
+            trace,
+            AIR::CIRCUIT_NAME,
+            job.path(),
+            control,
+            self.logging_level,
+        )?;
+
+        if self.logging_level.print_big_steps() {
+            println!("{}", "Generating SNARK proof...".green());
+        }
+
+        let idle_prover = self.provers.lock().unwrap().pop();
+        let mut groth16_prover = match idle_prover {
+            Some(groth16_prover) => groth16_prover,
+            None => Groth16Prover::spawn(AIR::CIRCUIT_NAME, self.logging_level)?,
+        };
+        // a failed prover is dropped, which kills its process
+        in_stage(stage_span!("groth16_prove"), || {
+            groth16_prover.prove(job.path(), control)
+        })?;
+        self.provers.lock().unwrap().push(groth16_prover);
+
+        let (proof, signals) = read_groth16_proof(job.path(), self.logging_level)?;
+        Ok((
+            proof,
+            PublicSignals {
+                signals,
+                committed_values,
+            },
+        ))
+    }
+
+    /// Verify a Groth16 proof and check its OOD consistency, see
+    /// [circom_verify].
+    pub fn verify(
+        &self,
+        proof: &Groth16Proof,
+        public_signals: &PublicSignals,
+        pub_inputs: AIR::PublicInputs,
+        control: &ExecutionControl,
+    ) -> Result<VerificationReport, WinterCircomError> {
+        circom_verify::<AIR, N>(
+            proof,
+            public_signals,
+            pub_inputs,
+            control,
+            self.logging_level,
+        )
+    }
+}
+
+// PERSISTENT PROVER
+// ===========================================================================
+
+/// Node.js program proving the jobs it reads on its standard input, one JSON
+/// object per line, with the proving key loaded once. Each job is answered with
+/// a JSON line on its standard output.
+const GROTH16_PROVER: &str = r#"
+const fs = require("fs");
+const readline = require("readline");
+const snarkjs = require(process.argv[1]);
+const zkey = new Uint8Array(fs.readFileSync(process.argv[2]));
+const write = (path, value) => fs.writeFileSync(path, JSON.stringify(
+    value, (_, v) => (typeof v === "bigint" ? v.toString() : v), 1));
+const respond = (response) => process.stdout.write(JSON.stringify(response) + "\n");
+
+let jobs = Promise.resolve();
+const lines = readline.createInterface({ input: process.stdin });
+lines.on("line", (line) => {
+    jobs = jobs.then(async () => {
+        try {
+            const job = JSON.parse(line);
+            const { proof, publicSignals } = await snarkjs.groth16.prove(zkey, job.witness);
+            write(job.proof, proof);
+            write(job.public, publicSignals);
+            respond({ ok: true });
+        } catch (e) {
+            respond({ error: String((e && e.message) || e) });
+        }
+    });
+});
+// the worker threads of SnarkJS would otherwise keep the process alive
+lines.on("close", () => jobs.then(() => process.exit(0)));
+"#;
+
+/// Persistent SnarkJS process generating the Groth16 proofs of a circuit, see
+/// [CircuitHandle].
+struct Groth16Prover {
+    child: Child,
+    stdin: ChildStdin,
+    /// Lines of the standard output of the process.
+    responses: Receiver<String>,
+    /// Standard error of the process, since the start of the current job.
+    stderr: Arc<Mutex<String>>,
+}
+
+impl Groth16Prover {
+    /// Start a prover with the proving key of the circuit.
+    fn spawn(circuit_name: &str, logging_level: LoggingLevel) -> Result<Self, WinterCircomError> {
+        let library = snarkjs_library()?;
+        let zkey = format!("target/circom/{}/verifier.zkey", circuit_name);
+        check_file(zkey.clone(), Some("generated by circom_compile"))?;
+
+        let mut command = Command::new("node");
+        command
+            .arg("-e")
+            .arg(GROTH16_PROVER)
+            .arg(&library)
+            .arg(&zkey)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        in_process_group(&mut command);
+
+        let mut child = command
+            .spawn()
+            .map_err(|e| WinterCircomError::ToolchainError {
+                executable: String::from("node"),
+                comment: format!("could not be executed ({})", e),
+            })?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = child.stdout.take().expect("stdout is piped");
+        let stderr_pipe = child.stderr.take().expect("stderr is piped");
+
+        let (sender, responses) = channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    return;
+                }
+            }
+        });
+
+        let stderr = Arc::new(Mutex::new(String::new()));
+        let job_stderr = stderr.clone();
+        thread::spawn(move || {
+            for line in BufReader::new(stderr_pipe).lines().map_while(Result::ok) {
+                warn!(executable = "snarkjs", stream = "stderr", "{}", line);
+                if logging_level.print_command_output() {
+                    eprintln!("{}", line);
+                }
+                let mut job_stderr = job_stderr.lock().unwrap();
+                job_stderr.push_str(&line);
+                job_stderr.push('\n');
+            }
+        });
+
+        debug!(
+            executable = "snarkjs",
+            pid = child.id(),
+            "started persistent prover"
+        );
+        Ok(Self {
+            child,
+            stdin,
+            responses,
+            stderr,
+        })
+    }
+
+    /// Prove the witness of the `job_directory`, writing its `proof.json` and
+    /// `public.json` files.
+    ///
+    /// The process is killed if the job times out or is cancelled, the prover
+    /// must not be reused after an error.
+    fn prove(
+        &mut self,
+        job_directory: &str,
+        control: &ExecutionControl,
+    ) -> Result<(), WinterCircomError> {
+        control.cancellation.check()?;
+        self.stderr.lock().unwrap().clear();
+
+        let job = json!({
+            "witness": format!("{}/witness.wtns", job_directory),
+            "proof": format!("{}/proof.json", job_directory),
+            "public": format!("{}/public.json", job_directory),
+        });
+        writeln!(self.stdin, "{}", job).map_err(|e| WinterCircomError::IoError {
+            io_error: e,
+            comment: Some(String::from("sending a job to the SnarkJS prover")),
+        })?;
+
+        let start = Instant::now();
+        let response = loop {
+            match self.responses.recv_timeout(POLL_INTERVAL) {
+                Ok(line) => match serde_json::from_str::<Value>(&line) {
+                    Ok(response) if response.is_object() => break response,
+                    _ => debug!(executable = "snarkjs", stream = "stdout", "{}", line),
+                },
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => {
+                    let code = self
+                        .child
+                        .wait()
+                        .ok()
+                        .and_then(|status| status.code())
+                        .unwrap_or(-1);
+                    return Err(self.error(code, "the prover exited"));
+                }
+            }
+            if control.cancellation.is_cancelled() {
+                warn!(executable = "snarkjs", "command cancelled");
+                kill_process_group(&mut self.child);
+                return Err(WinterCircomError::Cancelled);
+            }
+            if let Some(timeout) = control.timeouts.groth16_prove {
+                if start.elapsed() >= timeout {
+                    warn!(executable = "snarkjs", ?timeout, "command timed out");
+                    kill_process_group(&mut self.child);
+                    return Err(WinterCircomError::Timeout {
+                        executable: String::from("snarkjs"),
+                        timeout,
+                        stdout: String::new(),
+                        stderr: self.stderr.lock().unwrap().clone(),
+                    });
+                }
+            }
+        };
+
+        match response["error"].as_str() {
+            // reported like a failure of the SnarkJS command line
+            Some(message) => Err(self.error(1, message)),
+            None => Ok(()),
+        }
+    }
+
+    /// Error of a failed job, with the standard error of the process followed
+    /// by the `message` of the failure.
+    fn error(&self, code: i32, message: &str) -> WinterCircomError {
+        WinterCircomError::ExitCodeError {
+            executable: String::from("snarkjs"),
+            code,
+            stdout: String::new(),
+            stderr: format!("{}{}", self.stderr.lock().unwrap(), message),
+        }
+    }
+}
+
+impl Drop for Groth16Prover {
+    fn drop(&mut self) {
+        kill_process_group(&mut self.child);
+    }
+}
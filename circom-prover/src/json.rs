@@ -1,25 +1,48 @@
+use std::fmt::Display;
+
 use serde::Serialize;
 use serde_json::{json, Value};
 use winterfell::{
-    crypto::{Digest, ElementHasher, RandomCoin},
+    crypto::{
+        hashers::PoseidonDigest, BatchMerkleProof, ElementHasher, MerkleTreeError, RandomCoin,
+    },
     math::{fields::f256::BaseElement, log2, FieldElement, StarkField},
-    Serializable, StarkProof, Air
+    ProofOptions, Serializable, StarkProof, Air
 };
 
+use crate::{fri_layers, utils::WinterCircomError};
+
 /// Parse a [StarkProof] into a Circom-usable JSON object.
 ///
 /// ## Padding
 ///
 /// To ensure constant size arrays and therefore Circom compatibility, elements
-/// of `fri_layer_proofs` and `fri_layer_queries` arrays are padded with zeroes.
+/// of `fri_layer_proofs`, `fri_layer_queries` and `fri_layer_top_nodes` arrays
+/// are padded with zeroes.
 ///
-/// The `fri_num_queries` and `fri_tree_depths` arguments are populated so that:
+/// The `fri_tree_depths` argument is populated with the depth of the Merkle
+/// tree of each FRI layer, in levels of `merkle_arity` children.
 ///
-/// ```text
-/// fri_layer_proofs[i].len() = fri_num_queries[i]
-/// fri_layer_proofs[i][j].len() = fri_tree_depths[i]
-/// fri_layer_queries[i].len() = fri_num_queries[i] * folding_factor
-/// ```
+/// ## Merkle proofs
+///
+/// The Merkle trees have arity `merkle_arity = H::MERKLE_ARITY` and depth
+/// `depth = log2(lde_domain_size) / log2(merkle_arity)` for the trace and
+/// constraint commitments. The batch proofs are laid out for the
+/// `MerkleBatchVerify` template of `circuits/merkle.circom`:
+///
+/// - the nodes of the `top_depth` levels below the root are given once, in
+///   `*_top_nodes` arrays of `num_top_nodes = merkle_arity + merkle_arity^2 +
+///   ... + merkle_arity^top_depth` elements, level by level, the nodes the
+///   proof does not reveal being zero;
+/// - the authentication paths only hold the `path_length = (depth -
+///   top_depth) * (merkle_arity - 1)` siblings of the levels below.
+///
+/// The number of top levels is the smallest `top_depth` such that
+/// `merkle_arity^top_depth >= num_queries`, up to the depth of the tree for
+/// the smallest trace length supported by the circuit, `min_trace_length`.
+/// The top levels of each FRI layer are those of its shallowest tree among
+/// the supported trace lengths that have this layer. The arrays of the FRI
+/// layers are sized for the trace tree, the deepest one.
 ///
 /// ## JSON structure
 ///
@@ -28,10 +51,12 @@ use winterfell::{
 ///     "addicity": _,
 ///     "constraint_commitment": _,
 ///     "constraint_evaluations": [[_; trace_width]; num_queries],
-///     "constraint_query_proofs": [[_; tree_depth]; num_queries],
+///     "constraint_query_proofs": [[_; path_length]; num_queries],
+///     "constraint_top_nodes": [_; num_top_nodes],
 ///     "fri_commitments": [num_fri_layers + 1],
-///     "fri_layer_proofs": [[[_; tree_depth]; num_queries]; num_fri_layers],
+///     "fri_layer_proofs": [[[_; path_length]; num_queries]; num_fri_layers],
 ///     "fri_layer_queries": [[_; num_queries * folding_factor]; num_fri_layers],
+///     "fri_layer_top_nodes": [[_; num_top_nodes]; num_fri_layers],
 ///     "fri_remainder": [_; lde_domain_size / (folding_factor ** num_fri_layers)],
 ///     "ood_constraint_evaluations": [_; ce_blowup_factor],
 ///     "ood_trace_frame": [[_; trace_width]; 2],
@@ -40,21 +65,33 @@ use winterfell::{
 ///     "public_inputs": [_; num_public_inputs],
 ///     "trace_commitment": _,
 ///     "trace_evaluations": [[_; trace_width]; num_queries],
-///     "trace_query_proofs": [[tree_depth]; num_queries],
+///     "trace_query_proofs": [[_; path_length]; num_queries],
+///     "trace_top_nodes": [_; num_top_nodes],
 /// }
 /// ```
 ///
-/// TODO: Return errors instead of panicking (`.map_err()` and `?` instead of `.unwrap()`)
+/// The FRI remainder holds `1 / lde_blowup_factor` as many elements when it is
+/// sent as the coefficients of its polynomial (see
+/// [with_fri_remainder_coefficients](crate::WinterCircomProofOptions::with_fri_remainder_coefficients)).
+///
+/// ## Errors
+///
+/// A [MalformedProof](WinterCircomError::MalformedProof) error is returned if
+/// the proof cannot be parsed with the parameters of the AIR, and an
+/// [UnsupportedProof](WinterCircomError::UnsupportedProof) error if it has
+/// several trace segments, or another number of FRI partitions than its
+/// options.
 pub fn proof_to_json<AIR, H>(
     proof: StarkProof,
     air: &AIR,
     pub_inputs: AIR::PublicInputs,
     fri_tree_depths: &mut Vec<usize>,
-) -> Value
+    min_trace_length: usize,
+) -> Result<Value, WinterCircomError>
 where
     AIR: Air<BaseField = BaseElement>,
     <AIR as Air>::PublicInputs: Serialize,
-    H: ElementHasher<BaseField = BaseElement>,
+    H: ElementHasher<BaseField = BaseElement, Digest = PoseidonDigest>,
 {
     let StarkProof {
         context,
@@ -73,9 +110,24 @@ where
     let fri_options = air.options().to_fri_options();
     let num_queries = air.options().num_queries();
     let folding_factor = fri_options.folding_factor();
+    let merkle_arity = H::MERKLE_ARITY;
+    let merkle_depth = (log2(lde_domain_size) / log2(merkle_arity)) as usize;
+    let min_lde_domain_size = min_trace_length * air.options().blowup_factor();
+    let top_depth = merkle_top_depth(
+        num_queries,
+        (log2(min_lde_domain_size) / log2(merkle_arity)) as usize,
+        merkle_arity,
+    );
+    let path_length = (merkle_depth - top_depth) * (merkle_arity - 1);
+    let num_top_nodes = merkle_num_top_nodes(top_depth, merkle_arity);
 
     // enforce only one trace segment to ensure compatibility with the Circom code
-    assert_eq!(num_trace_segments, 1);
+    if num_trace_segments != 1 {
+        return Err(WinterCircomError::UnsupportedProof(format!(
+            "{} trace segments, only 1 is supported",
+            num_trace_segments
+        )));
+    }
 
     // PUBLIC COIN SEED
     // ===========================================================================
@@ -106,7 +158,7 @@ where
             num_trace_segments,
             fri_options.num_fri_layers(lde_domain_size),
         )
-        .unwrap();
+        .map_err(|e| malformed("commitments", e))?;
 
     public_coin.reseed(trace_commitments[0]);
     public_coin.reseed(constraint_commitment);
@@ -114,11 +166,10 @@ where
     // map commitments to BaseElements
     let trace_commitment = trace_commitments
         .iter()
-        .map(|c| BaseElement::from_le_bytes(&c.as_bytes()))
+        .map(PoseidonDigest::as_element)
         .collect::<Vec<_>>()
         .remove(0);
-    let constraint_commitment: BaseElement =
-        BaseElement::from_le_bytes(&constraint_commitment.as_bytes());
+    let constraint_commitment = constraint_commitment.as_element();
 
     // OOD FRAME
     // ===========================================================================
@@ -126,7 +177,7 @@ where
     // parse ood_frame, ignoring the ood_aux_trace_frame
     let (ood_trace_frame, _, ood_constraint_evaluations) = ood_frame
         .parse::<BaseElement>(main_trace_width, aux_trace_width, air.ce_blowup_factor())
-        .unwrap();
+        .map_err(|e| malformed("OOD frame", e))?;
 
     public_coin.reseed(H::hash_elements(ood_trace_frame.current()));
     public_coin.reseed(H::hash_elements(ood_trace_frame.next()));
@@ -146,8 +197,15 @@ where
     // FRI PROOF PART 1
     // ===========================================================================
 
-    // only accept a fri proof with a single partition
-    assert_eq!(fri_proof.num_partitions(), 1);
+    // the circuit is compiled for the number of FRI partitions of the options
+    let num_partitions = fri_proof.num_partitions();
+    if num_partitions != fri_options.num_partitions() {
+        return Err(WinterCircomError::UnsupportedProof(format!(
+            "{} FRI partitions, {} expected",
+            num_partitions,
+            fri_options.num_partitions()
+        )));
+    }
 
     for root in fri_commitments.iter() {
         public_coin.reseed(*root);
@@ -157,7 +215,7 @@ where
     // of the commitment for the remainder
     let fri_commitments = fri_commitments
         .iter()
-        .map(|c| BaseElement::from_le_bytes(&c.as_bytes()))
+        .map(PoseidonDigest::as_element)
         .collect::<Vec<_>>();
 
     // QUERY POSITIONS
@@ -167,58 +225,63 @@ where
 
     let query_positions = public_coin
         .draw_integers(num_queries, lde_domain_size)
-        .unwrap();
+        .map_err(|e| malformed("query positions", e))?;
 
     // FRI PROOF PART 2
     // ===========================================================================
 
     // parse fri proof into Merkle proofs and queries for each layer
-    let fri_remainder = fri_proof.parse_remainder::<BaseElement>().unwrap();
+    let fri_remainder = fri_proof
+        .parse_remainder::<BaseElement>()
+        .map_err(|e| malformed("FRI remainder", e))?;
     let (mut fri_layer_queries, fri_layer_proofs) = fri_proof
         .parse_layers::<H, BaseElement>(lde_domain_size, folding_factor)
-        .unwrap();
+        .map_err(|e| malformed("FRI layers", e))?;
 
-    // convert batch merkle proofs into authentication paths
-    // and map digests to BaseElements
-    let mut indexes = query_positions.clone();
+    // convert batch merkle proofs into authentication paths and top nodes,
+    // and map digests to BaseElements; with several partitions, the leaves of
+    // the folded positions are reordered in the layer trees
+    let mut positions = query_positions.clone();
     let mut domain_size = lde_domain_size;
-    let mut fri_layer_proofs = fri_layer_proofs
+    let (mut fri_layer_proofs, mut fri_layer_top_nodes): (Vec<_>, Vec<_>) = fri_layer_proofs
         .iter()
-        .map(|merkle_proof| {
-            indexes = fold_positions(&indexes, domain_size, folding_factor);
+        .enumerate()
+        .map(|(layer, merkle_proof)| {
+            positions = fold_positions(&positions, domain_size, folding_factor);
             domain_size /= folding_factor;
 
-            merkle_proof
-                .to_paths(&indexes)
-                .unwrap()
-                .iter()
-                .map(|path| {
-                    path.iter()
-                        .map(|digest| BaseElement::from_le_bytes(&digest.as_bytes()))
-                        .collect::<Vec<_>>()
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
-
-    // pad fri_query_proofs with zeroes to ensure constant size arrays
-    let tree_depth = log2(lde_domain_size) as usize;
-    let fri_layer_proofs = fri_layer_proofs
-        .iter_mut()
-        .map(|paths| {
-            fri_tree_depths.push(paths[0].len());
-
-            for path in paths.iter_mut() {
-                while path.len() < tree_depth {
-                    path.push(BaseElement::ZERO);
-                }
-            }
-            while paths.len() < num_queries {
-                paths.push(vec![BaseElement::ZERO; tree_depth]);
-            }
-            paths
+            let indexes = map_positions_to_indexes(&positions, domain_size, num_partitions);
+            let layer_depth = (log2(domain_size) / log2(merkle_arity)) as usize;
+            let layer_top_depth = merkle_top_depth(
+                num_queries,
+                fri_layer_min_depth(
+                    layer,
+                    min_lde_domain_size,
+                    folding_factor,
+                    fri_options.max_remainder_size(),
+                    merkle_arity,
+                ),
+                merkle_arity,
+            );
+            fri_tree_depths.push(layer_depth);
+            merkle_openings(merkle_proof, &indexes, layer_depth, layer_top_depth)
+                .map_err(|e| malformed("FRI layer proofs", e))
         })
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .unzip();
+
+    // pad fri_query_proofs and fri_layer_top_nodes with zeroes to ensure
+    // constant size arrays
+    for paths in fri_layer_proofs.iter_mut() {
+        for path in paths.iter_mut() {
+            path.resize(path_length, BaseElement::ZERO);
+        }
+        paths.resize(num_queries, vec![BaseElement::ZERO; path_length]);
+    }
+    for top_nodes in fri_layer_top_nodes.iter_mut() {
+        top_nodes.resize(num_top_nodes, BaseElement::ZERO);
+    }
 
     // pad fri layer queries with zeroes to ensure constant size arrays
     for queries in fri_layer_queries.iter_mut() {
@@ -235,20 +298,17 @@ where
     let (trace_query_proofs, trace_evaluations) = trace_queries
         .remove(0)
         .parse::<H, BaseElement>(lde_domain_size, num_queries, main_trace_width)
-        .unwrap();
-
-    // convert the batch Merkle proof into authentication paths
-    // and map hash digests to BaseElements
-    let trace_query_proofs = trace_query_proofs
-        .to_paths(&query_positions)
-        .unwrap()
-        .iter()
-        .map(|path| {
-            path.iter()
-                .map(|digest| BaseElement::from_le_bytes(&digest.as_bytes()))
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<Vec<_>>>();
+        .map_err(|e| malformed("trace queries", e))?;
+
+    // convert the batch Merkle proof into authentication paths and top
+    // nodes, and map hash digests to BaseElements
+    let (trace_query_proofs, trace_top_nodes) = merkle_openings(
+        &trace_query_proofs,
+        &query_positions,
+        merkle_depth,
+        top_depth,
+    )
+    .map_err(|e| malformed("trace query proofs", e))?;
 
     // map constraint states table into a matrix of BaseElements
     let trace_evaluations = trace_evaluations.rows().fold(vec![], |mut e, row| {
@@ -262,20 +322,17 @@ where
     // parse constraint queries back into a Merkle proof and a vector of states
     let (constraint_query_proofs, constraint_evaluations) = constraint_queries
         .parse::<H, BaseElement>(lde_domain_size, num_queries, air.ce_blowup_factor())
-        .unwrap();
-
-    // convert the batch Merkle proof into authentication paths
-    // and map hash digests to BaseElements
-    let constraint_query_proofs = constraint_query_proofs
-        .to_paths(&query_positions)
-        .unwrap()
-        .iter()
-        .map(|path| {
-            path.iter()
-                .map(|digest| BaseElement::from_le_bytes(&digest.as_bytes()))
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
+        .map_err(|e| malformed("constraint queries", e))?;
+
+    // convert the batch Merkle proof into authentication paths and top
+    // nodes, and map hash digests to BaseElements
+    let (constraint_query_proofs, constraint_top_nodes) = merkle_openings(
+        &constraint_query_proofs,
+        &query_positions,
+        merkle_depth,
+        top_depth,
+    )
+    .map_err(|e| malformed("constraint query proofs", e))?;
 
     // map constraint states table into a matrix of BaseElements
     let constraint_evaluations = constraint_evaluations.rows().fold(vec![], |mut e, row| {
@@ -286,14 +343,16 @@ where
     // BUILD JSON OBJECT
     // ===========================================================================
 
-    json!({
+    let json = json!({
         "addicity_root": BaseElement::TWO_ADIC_ROOT_OF_UNITY,
         "constraint_commitment": constraint_commitment,
         "constraint_evaluations": constraint_evaluations,
         "constraint_query_proofs": constraint_query_proofs,
+        "constraint_top_nodes": constraint_top_nodes,
         "fri_commitments": fri_commitments,
         "fri_layer_proofs": fri_layer_proofs,
         "fri_layer_queries": fri_layer_queries,
+        "fri_layer_top_nodes": fri_layer_top_nodes,
         "fri_remainder": fri_remainder,
         "ood_constraint_evaluations": ood_constraint_evaluations,
         "ood_frame_constraint_evaluation" : ood_frame_constraint_evaluation,
@@ -304,12 +363,186 @@ where
         "trace_commitment": trace_commitment,
         "trace_evaluations": trace_evaluations,
         "trace_query_proofs": trace_query_proofs,
-    })
+        "trace_top_nodes": trace_top_nodes,
+    });
+
+    Ok(json)
+}
+
+/// Pad a JSON object returned by [proof_to_json] to the input sizes of the
+/// circuit, and add the log2 of the trace length of the proof.
+///
+/// The inputs of a circuit that verifies proofs of any trace length from
+/// `min_trace_length` to `max_trace_length` (see
+/// [with_max_trace_length](crate::WinterCircomProofOptions::with_max_trace_length))
+/// are sized for the maximum trace length: authentication paths, FRI layers
+/// and their commitments, and the FRI remainder are padded with zeroes. The
+/// sizes of the inputs of a circuit for a single trace length are left as is.
+pub(crate) fn pad_to_max_trace_length(
+    json: &mut Value,
+    options: &ProofOptions,
+    trace_length: usize,
+    min_trace_length: usize,
+    max_trace_length: usize,
+    merkle_arity: usize,
+) {
+    let zero = json!(BaseElement::ZERO);
+    let num_queries = options.num_queries();
+    let lde_blowup_factor = options.blowup_factor();
+    let folding_factor = options.to_fri_options().folding_factor();
+    let max_remainder_size = options.to_fri_options().max_remainder_size();
+    let log_arity = log2(merkle_arity) as usize;
+
+    // the top levels are those of the smallest trace length, the paths and FRI
+    // layers those of the largest one
+    let top_depth = merkle_top_depth(
+        num_queries,
+        log2(min_trace_length * lde_blowup_factor) as usize / log_arity,
+        merkle_arity,
+    );
+    let num_top_nodes = merkle_num_top_nodes(top_depth, merkle_arity);
+    let max_lde_domain_size = max_trace_length * lde_blowup_factor;
+    let path_length =
+        (log2(max_lde_domain_size) as usize / log_arity - top_depth) * (merkle_arity - 1);
+    let (num_fri_layers, _) = fri_layers(max_lde_domain_size, folding_factor, max_remainder_size);
+
+    // the remainder is sized for the largest one among the supported lengths
+    let remainder_length = (0..)
+        .map(|i| min_trace_length * merkle_arity.pow(i) * lde_blowup_factor)
+        .take_while(|&lde_domain_size| lde_domain_size <= max_lde_domain_size)
+        .map(|lde_domain_size| {
+            let (_, remainder_size) =
+                fri_layers(lde_domain_size, folding_factor, max_remainder_size);
+            if options.fri_remainder_coefficients() {
+                remainder_size / lde_blowup_factor
+            } else {
+                remainder_size
+            }
+        })
+        .max()
+        .unwrap_or(0);
+
+    for key in ["constraint_query_proofs", "trace_query_proofs"] {
+        for path in array_mut(&mut json[key]) {
+            pad(path, path_length, &zero);
+        }
+    }
+
+    let fri_layer_proofs = &mut json["fri_layer_proofs"];
+    for paths in array_mut(fri_layer_proofs) {
+        for path in array_mut(paths) {
+            pad(path, path_length, &zero);
+        }
+    }
+    pad(
+        fri_layer_proofs,
+        num_fri_layers,
+        &json!(vec![vec![BaseElement::ZERO; path_length]; num_queries]),
+    );
+    pad(
+        &mut json["fri_layer_queries"],
+        num_fri_layers,
+        &json!(vec![BaseElement::ZERO; num_queries * folding_factor]),
+    );
+    pad(
+        &mut json["fri_layer_top_nodes"],
+        num_fri_layers,
+        &json!(vec![BaseElement::ZERO; num_top_nodes]),
+    );
+    pad(&mut json["fri_commitments"], num_fri_layers + 1, &zero);
+    pad(&mut json["fri_remainder"], remainder_length, &zero);
+
+    json["log_trace_length"] = json!(log2(trace_length));
 }
 
 // HELPER FUNCTIONS
 // ===========================================================================
 
+/// Number of levels below the root of a Merkle tree whose nodes are given
+/// once in batch proofs, see `merkle_top_depth` in `circuits/merkle.circom`.
+fn merkle_top_depth(num_queries: usize, depth: usize, arity: usize) -> usize {
+    let mut top_depth = 0;
+    while top_depth < depth && arity.pow(top_depth as u32) < num_queries {
+        top_depth += 1;
+    }
+    top_depth
+}
+
+/// Smallest depth of the Merkle tree of a FRI layer, among the LDE domains
+/// from `min_lde_domain_size` which have this layer, growing by a factor of
+/// `merkle_arity`. See `FriVerifier` in `circuits/fri.circom`.
+fn fri_layer_min_depth(
+    layer: usize,
+    min_lde_domain_size: usize,
+    folding_factor: usize,
+    max_remainder_size: usize,
+    merkle_arity: usize,
+) -> usize {
+    let mut lde_domain_size = min_lde_domain_size;
+    while fri_layers(lde_domain_size, folding_factor, max_remainder_size).0 <= layer {
+        lde_domain_size *= merkle_arity;
+    }
+    let layer_domain_size = lde_domain_size / folding_factor.pow(layer as u32 + 1);
+    (log2(layer_domain_size) / log2(merkle_arity)) as usize
+}
+
+/// Number of nodes of the levels 1 to `top_depth` of a Merkle tree.
+fn merkle_num_top_nodes(top_depth: usize, arity: usize) -> usize {
+    (1..=top_depth).map(|level| arity.pow(level as u32)).sum()
+}
+
+/// Authentication paths of a batch Merkle proof of a tree of the given
+/// `depth` below its `top_depth` levels, and nodes of these top levels.
+///
+/// The digests are mapped to BaseElements, the nodes of the top levels which
+/// are not revealed by the proof being zero.
+fn merkle_openings<H: ElementHasher<BaseField = BaseElement, Digest = PoseidonDigest>>(
+    proof: &BatchMerkleProof<H>,
+    indexes: &[usize],
+    depth: usize,
+    top_depth: usize,
+) -> Result<(Vec<Vec<BaseElement>>, Vec<BaseElement>), MerkleTreeError> {
+    let top_depth = top_depth.min(depth);
+    let path_length = (depth - top_depth) * (H::MERKLE_ARITY - 1);
+
+    let paths = proof
+        .to_paths(indexes)?
+        .iter()
+        .map(|path| {
+            path[..path_length]
+                .iter()
+                .map(PoseidonDigest::as_element)
+                .collect()
+        })
+        .collect();
+    let top_nodes = proof
+        .to_top_levels(indexes, top_depth)?
+        .iter()
+        .flatten()
+        .map(|node| node.map_or(BaseElement::ZERO, |node| node.as_element()))
+        .collect();
+
+    Ok((paths, top_nodes))
+}
+
+/// Elements of a JSON array, or nothing if the value is not an array.
+fn array_mut(value: &mut Value) -> impl Iterator<Item = &mut Value> {
+    value.as_array_mut().into_iter().flatten()
+}
+
+/// Pad a JSON array with `filler` up to `len` elements.
+fn pad(value: &mut Value, len: usize, filler: &Value) {
+    if let Some(array) = value.as_array_mut() {
+        while array.len() < len {
+            array.push(filler.clone());
+        }
+    }
+}
+
+fn malformed<E: Display>(part: &str, error: E) -> WinterCircomError {
+    WinterCircomError::MalformedProof(format!("{}: {}", part, error))
+}
+
 fn fold_positions(
     positions: &[usize],
     source_domain_size: usize,
@@ -328,3 +561,17 @@ fn fold_positions(
 
     result
 }
+
+/// Indexes of the leaves of folded positions in the Merkle tree of a FRI layer
+/// over a domain of the given size, whose leaves are ordered by partition.
+fn map_positions_to_indexes(
+    positions: &[usize],
+    domain_size: usize,
+    num_partitions: usize,
+) -> Vec<usize> {
+    let partition_size = domain_size / num_partitions;
+    positions
+        .iter()
+        .map(|position| (position % num_partitions) * partition_size + position / num_partitions)
+        .collect()
+}
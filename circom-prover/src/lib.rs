@@ -7,8 +7,25 @@
 //! - `circom_compile` for generating a Circom circuit capable of verifying a
 //! Winterfell proof, compiling it and generating circuit-specific keys.
 //! - `circom_prove` for generating a SNARK Groth16 proof of the verification of
-//! the Winterfell proof.
+//! the Winterfell proof. The proof and its public signals are returned in memory,
+//! and can be written to disk with [save_proof].
 //! - `circom_verify` for verifying the proof generated by the previous function.
+//! It returns a [VerificationReport] with the outcome of each check.
+//! - `solidity_calldata` for encoding the proof generated by `circom_prove` into
+//! a call to the optional Solidity verifier contract.
+//!
+//! Proving and verification jobs run in their own scratch directories, and only
+//! read the shared artifacts of the compiled circuit. A [CircuitHandle] can
+//! therefore run several jobs in parallel against one compiled circuit, with
+//! persistent SnarkJS provers that keep the proving key in memory.
+//!
+//! With the `cli` feature, the [cli] module wraps these functions into a
+//! command-line tool driven by a project config file.
+//!
+//! With the `server` feature, the [server] module provides a local proving
+//! service, which runs the jobs submitted over HTTP on a fixed number of
+//! workers, and persists them across restarts. Each job can be cancelled on its
+//! own.
 //!
 //! # Powers of tau phase 1 transcript
 //!
@@ -19,6 +36,28 @@
 //! [here](https://www.dropbox.com/sh/mn47gnepqu88mzl/AACaJkBU7mmCq8uU8ml0-0fma?dl=0).
 //! Hopefully this link will not die.
 //!
+//! # Circom and SnarkJS toolchain
+//!
+//! With the `toolchain` feature (enabled by default), the build script
+//! initializes the `iden3/circom` and `iden3/snarkjs` git submodules, builds the
+//! Circom compiler and installs the SnarkJS dependencies with npm. Each step can
+//! be skipped by setting an environment variable:
+//!
+//! - `WINTER_CIRCOM_SKIP_SUBMODULES`: skip the initialization of the git
+//! submodules.
+//! - `WINTER_CIRCOM_SKIP_CIRCOM`: skip the build of the Circom compiler.
+//! - `WINTER_CIRCOM_SKIP_SNARKJS`: skip the installation of the SnarkJS
+//! dependencies (Node is then not needed at build time).
+//! - `WINTER_CIRCOM_SKIP_TOOLCHAIN`: skip all of the above, as does disabling
+//! the `toolchain` feature. This is needed for offline builds.
+//!
+//! At runtime, `circom` and `snarkjs` are looked up from the
+//! `WINTER_CIRCOM_CIRCOM` and `WINTER_CIRCOM_SNARKJS` environment variables,
+//! then in the submodules, then in the `PATH`. Their version is checked on first
+//! use: Circom 2.0.0 and SnarkJS 0.4.0 are the minimum versions. A missing or
+//! incompatible tool results in a
+//! [ToolchainError](utils::WinterCircomError::ToolchainError).
+//!
 //! # Completeness & Soundness
 //!
 //! The completeness and soundness of arguments of knowledge generated by this
@@ -33,7 +72,95 @@
 //!
 //! The generated proofs are composed of a Groth16 proof and a set of public
 //! inputs, which are the out-of-domain (OOD) trace frame and the OOD constraint
-//! evaluations.
+//! evaluations. Alternatively, these public inputs can be replaced by a single
+//! Poseidon commitment to them and to the public inputs of the computation (see
+//! [WinterCircomProofOptions::with_public_signals_commitment]).
+//!
+//! ## Zero-knowledge
+//!
+//! The OOD trace frame is made of evaluations of the trace polynomials, and
+//! therefore leaks information about the execution trace. In zero-knowledge
+//! mode (see [WinterCircomProofOptions::with_zero_knowledge]), the trace is
+//! blinded with random rows, the OOD trace frame and constraint evaluations are
+//! kept private and the OOD consistency check is performed by the circuit. The
+//! public inputs of the computation are then the only public signals of the
+//! Groth16 proof.
+//!
+//! The composition polynomial and the FRI layers are not blinded, so the
+//! Winterfell proof, a private input of the circuit, is not zero-knowledge and
+//! must not be shared. The AIR must leave the blinding rows unconstrained.
+//!
+//! ## Variable trace length
+//!
+//! By default, the circuit verifies proofs of a single trace length. With
+//! [WinterCircomProofOptions::with_max_trace_length], it verifies proofs of any
+//! supported power-of-2 trace length up to a maximum, so that a single
+//! circuit-specific setup serves them all. The supported trace lengths grow by
+//! a factor of the Merkle arity. The log2 of the trace length is then the first
+//! public signal of the Groth16 proof. The circuit is the same as for a single
+//! trace length, whose minimum and maximum trace lengths are equal: the
+//! `AIRAssertions` template of the AIR takes the trace length as an input
+//! signal, and the arrays of the proof are padded with zeroes up to the maximum
+//! trace length.
+//!
+//! ## Merkle arity
+//!
+//! The trace, the constraint evaluations and the FRI layers are committed to
+//! with binary Merkle trees by default. With
+//! [WinterCircomProofOptions::with_merkle_arity], they are committed to with
+//! 4-ary or 8-ary trees, whose nodes are merged by a single Poseidon
+//! permutation of width 6 or 10. The trees are two or three times shallower,
+//! which reduces the number of permutations the circuit evaluates per
+//! authentication path. The Winterfell proof then uses the
+//! [Poseidon4](winterfell::HashFunction::Poseidon4) or
+//! [Poseidon8](winterfell::HashFunction::Poseidon8) hash function.
+//!
+//! ## Batch Merkle proofs
+//!
+//! The openings of each Merkle tree are verified as a Winterfell batch proof,
+//! by the `MerkleBatchVerify` template of `circuits/merkle.circom`. The nodes
+//! of the top levels of the tree, which the queries share, are hashed once,
+//! and each query only hashes its authentication path below them. The number
+//! of top levels grows with the number of queries. In variable trace length
+//! mode, they are the top levels of the tree of the smallest supported trace
+//! length.
+//!
+//! This is not a full multiproof: the nodes below the top levels are hashed
+//! once per query, even when queries share them. The README compares the
+//! constraints of both approaches for common parameters.
+//!
+//! ## Poseidon2
+//!
+//! With [WinterCircomProofOptions::with_poseidon2], the Winterfell proof uses
+//! the [Poseidon2](winterfell::HashFunction::Poseidon2) hash function instead of
+//! Poseidon, for the Merkle trees and the public coin. Its permutation has a
+//! state of width 3 and cheaper linear layers, both natively and in the
+//! circuit, whose hash templates are then those of `circuits/hash/poseidon2.circom`.
+//! Poseidon2 builds binary Merkle trees only. The commitment to the public
+//! signals is a Poseidon hash in both cases.
+//!
+//! ## FRI remainder coefficients
+//!
+//! With [WinterCircomProofOptions::with_fri_remainder_coefficients], the
+//! Winterfell prover sends the coefficients of the FRI remainder polynomial
+//! instead of its evaluations, and commits to them with a single hash instead
+//! of a Merkle tree. The circuit then evaluates this polynomial at the queried
+//! points with Horner's method, rather than interpolating the remainder with
+//! an FFT, and the degree bound follows from the number of coefficients.
+//!
+//! The serialized proof options end with two bytes that earlier versions did
+//! not write, for the format of the remainder and for the number of
+//! [FRI partitions](#fri-partitions). Proofs serialized before these options
+//! were added can therefore no longer be deserialized.
+//!
+//! ## FRI partitions
+//!
+//! With [WinterCircomProofOptions::with_fri_partitions], the FRI layers are
+//! committed to in several partitions, the leaves of their Merkle trees being
+//! ordered by partition so that the tree of each partition can be built
+//! separately on large domains. The circuit maps the folded query positions to
+//! the indexes of their leaves before verifying the batch openings of each
+//! layer.
 //!
 //! ## Out-of-domain consistency check
 //!
@@ -45,9 +172,9 @@
 //!
 //! The fact that the out-of-domain trace frame and constraint evaluations are
 //! consistent is therefore not guaranteed by the Groth16 proof. This is why this
-//! crate provides a [check_ood_frame] function, that must be used alongside the
-//! [circom_verify] function and which takes the Groth16 public inputs and
-//! performs the OOD consistency check.
+//! crate provides a [check_ood_frame] function, which is called by the
+//! [circom_verify] function alongside the Groth16 verification, and which takes
+//! the Groth16 public inputs and performs the OOD consistency check.
 //!
 //! The [check_ood_frame] verifies that the the OOD trace frame and constraint
 //! evaluations correspond to one-another, using the transition constraints
@@ -72,58 +199,47 @@
 //!
 //! ```rust
 //! const PROOF_OPTIONS: WinterCircomProofOptions<2> =
-//!    WinterCircomProofOptions::new(128, 2, 3, [1, 1], 32, 8, 0, 8, 128);
+//!    WinterCircomProofOptions::new(128, 2, 2, 3, [1, 1], 32, 8, 0, 8, 128);
 //! ```
 //!
-//! 2. Implement [WinterPublicInputs].
+//! 2. Derive [WinterPublicInputs] for your public inputs struct.
+//!
+//! This also derives the [Serialize](serde::Serialize) and
+//! [Serializable](winterfell::Serializable) traits, from the fields of the
+//! struct, in declaration order. Fields can be `BaseElement`s, arrays of
+//! `BaseElement`s, or structs that themselves derive `WinterPublicInputs`. The
+//! `winter_circom` attribute checks at compile time that the fields flatten
+//! into the `num_public_inputs` declared by the proof options (the third
+//! argument of [new](WinterCircomProofOptions::new)).
 //!
 //! ```rust
-//! use serde::{ser::SerializeTuple, Serialize};
-//! use winter_circom_prover::winterfell::math::fields::f256::BaseElement;
+//! use winter_circom_prover::{winterfell::math::fields::f256::BaseElement, WinterPublicInputs};
 //!
-//! #[derive(Clone, Default)]
+//! #[derive(Clone, Default, WinterPublicInputs)]
+//! #[winter_circom(options = PROOF_OPTIONS)]
 //! pub struct PublicInputs {
 //!     pub start: BaseElement,
-//!     pub start: BaseElement,
-//! }
-//!
-//! impl WinterPublicInputs for PublicInputs {
-//!     const NUM_PUB_INPUTS: usize = 2;
-//! }
-//!
-//! impl Serialize for PublicInputs {
-//!     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-//!     where
-//!         S: serde::Serializer,
-//!     {
-//!         let mut state  = serializer.serialize_tuple(2)?;
-//!         state.serialize_element(&self.start)?;
-//!         state.serialize_element(&self.end)?;
-//!         state.end()
-//!     }
-//! }
-//!
-//! impl Serializable for PublicInputs {
-//!     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-//!         target.write(self.start);
-//!         target.write(self.result);
-//!     }
+//!     pub result: BaseElement,
 //! }
 //! ```
 //!
 //! 3. Implement Winterfell [Air](winterfell::Air) trait. See their
-//! [documentation](winterfell) for instructions. \
-//! While writing methods, make sure to use the
-//! [WinterCircomProofOptions] constant you previously defined, instead of hard
-//! coded values. \
-//! Also implement the [Default] trait for your [Air](winterfell::Air)
-//! implementation.
+//!    [documentation](winterfell) for instructions. \
+//!    While writing methods, make sure to use the
+//!    [WinterCircomProofOptions] constant you previously defined, instead of hard
+//!    coded values. \
+//!    The number of transition exemptions of the
+//!    [AirContext](winterfell::AirContext) must be set to
+//!    [num_transition_exemptions](WinterCircomProofOptions::num_transition_exemptions),
+//!    and assertions must not be placed after step `trace_length -
+//!    num_transition_exemptions`. In zero-knowledge mode, the rows after this
+//!    step are random blinding rows, which the AIR must leave unconstrained; the
+//!    prover rejects AIRs that constrain them.
 //!
 //! ```rust
 //! use winter_circom_prover::{winterfell::{
 //!     math::{fields::f256::BaseElement, FieldElement},
-//!     Air, AirContext, Assertion, EvaluationFrame, FieldExtension, HashFunction,
-//!     ProofOptions, TraceInfo}};
+//!     Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo}};
 //!
 //! pub struct WorkAir {
 //!     context: AirContext<BaseElement>,
@@ -141,7 +257,8 @@
 //!         let num_assertions = PROOF_OPTIONS.num_assertions();
 //!
 //!         WorkAir {
-//!             context: AirContext::new(trace_info, degrees, num_assertions, options),
+//!             context: AirContext::new(trace_info, degrees, num_assertions, options)
+//!                 .set_num_transition_exemptions(PROOF_OPTIONS.num_transition_exemptions()),
 //!             start: pub_inputs.start,
 //!             result: pub_inputs.result,
 //!         }
@@ -161,7 +278,8 @@
 //!     }
 //!
 //!     fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
-//!         let last_step = self.trace_length() - 1;
+//!         // the blinding rows of the zero-knowledge mode must be left unconstrained
+//!         let last_step = self.trace_length() - PROOF_OPTIONS.num_transition_exemptions();
 //!         vec![
 //!             Assertion::single(0, 0, self.start),
 //!             Assertion::single(1, 0, self.start),
@@ -173,24 +291,6 @@
 //!         &self.context
 //!     }
 //! }
-//!
-//! impl Default for WorkAir {
-//!     fn default() -> Self {
-//!         WorkAir::new(
-//!             TraceInfo::new(0, 0),
-//!             PublicInputs::default(),
-//!             ProofOptions::new(
-//!                 32,
-//!                 8,
-//!                 0,
-//!                 HashFunction::Poseidon,
-//!                 FieldExtension::None,
-//!                 8,
-//!                 128,
-//!             ),
-//!         )
-//!     }
-//! }
 //! ```
 //!
 //! 4. Implement the Winterfell [Prover](winterfell::Prover) trait. See their
@@ -237,7 +337,7 @@
 //!     type Trace = TraceTable<Self::BaseField>;
 //!
 //!     fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
-//!         let last_step = trace.length() - 1;
+//!         let last_step = trace.length() - PROOF_OPTIONS.num_transition_exemptions();
 //!         PublicInputs {
 //!             start: trace.get(0, 0),
 //!             result: trace.get(1, last_step),
@@ -262,7 +362,9 @@
 //! - **`AIRTransitions`** - template with a single array output. Hardcode the transition constrait degrees here.
 //!   In this example, we defined `PROOF_OPTIONS` with `[1, 1]` as transition constraint degrees. The template defined below therefore returns `[1, 1]` as well.
 //!
-//! - **`AIRAssertions`** - template that replicates the `get_assertions` method of the `Air` implementation for Winterfell.
+//! - **`AIRAssertions`** - template that replicates the `get_assertions` method of the `Air` implementation for Winterfell. Its `num_transition_exemptions` argument locates the last step before the blinding rows of the zero-knowledge mode.
+//!
+//! - **`AIRTransitionEvaluations`** - (zero-knowledge mode only) template that replicates the `evaluate_transition` method of the `Air` implementation for Winterfell.
 //!
 //! Copy the template below and replace the section between `/* HERE YOUR ASSERTIONS HERE */` and `/* -------------- */` with your own assertions.
 //!
@@ -283,12 +385,13 @@
 //! template AIRAssertions(
 //!     num_assertions,
 //!     num_public_inputs,
-//!     trace_length,
+//!     num_transition_exemptions,
 //!     trace_width
 //! ) {
 //!     signal input frame[2][trace_width];
 //!     signal input g_trace;
 //!     signal input public_inputs[num_public_inputs];
+//!     signal input trace_length;
 //!     signal input z;
 //!
 //!     signal output out[num_assertions];
@@ -310,7 +413,7 @@
 //!     register[1] <== 1;
 //!
 //!     value[2] <== public_inputs[1];
-//!     step[2] <== trace_length - 1;
+//!     step[2] <== trace_length - num_transition_exemptions;
 //!     register[2] <== 1;
 //!
 //!     /* ------------------------------------- */
@@ -329,9 +432,105 @@
 //!         divisor_degree[i] <== 1;
 //!     }
 //! }
+//!
+//! template AIRTransitionEvaluations(trace_width) {
+//!     signal input frame[2][trace_width];
+//!     signal output evaluations[trace_width];
+//!
+//!     evaluations[0] <== frame[1][0] - (frame[0][0] + 1);
+//!     evaluations[1] <== frame[1][1] - (frame[0][1] + frame[0][0] + 1);
+//! }
+//! ```
+//!
+//! 6. Implement the [CircomAir] trait for your AIR.
+//!
+//! It binds the circuit name, the proof options and the Circom templates to the
+//! AIR, so that the functions of this library only take the AIR type as
+//! parameter. The templates are included at compile time, from the file defined
+//! in the previous step.
+//!
+//! ```rust
+//! use winter_circom_prover::{CircomAir, WinterCircomProofOptions};
+//!
+//! impl CircomAir<2> for WorkAir {
+//!     const CIRCUIT_NAME: &'static str = "sum";
+//!
+//!     const PROOF_OPTIONS: WinterCircomProofOptions<2> = PROOF_OPTIONS;
+//!
+//!     const AIR_TEMPLATES: &'static str = include_str!("../../../circuits/air/sum.circom");
+//! }
+//! ```
+//!
+//! 7. Register the project with the command-line tool.
+//!
+//! Enable the `cli` feature of this crate, implement the
+//! [CircomProject](cli::CircomProject) trait and call [cli::run] from the main
+//! of your crate.
+//!
+//! ```rust
+//! use serde_json::Value;
+//! use winter_circom_prover::{
+//!     cli::{self, CircomProject},
+//!     utils::WinterCircomError,
+//!     winterfell::{math::fields::f256::BaseElement, ProofOptions, TraceTable},
+//! };
+//!
+//! struct Sum;
+//!
+//! impl CircomProject<2> for Sum {
+//!     type Prover = WorkProver;
+//!
+//!     fn prover(options: ProofOptions) -> WorkProver {
+//!         WorkProver::new(options)
+//!     }
+//!
+//!     fn build_trace(
+//!         prover: &WorkProver,
+//!         trace_length: usize,
+//!         inputs: &Value,
+//!     ) -> Result<TraceTable<BaseElement>, WinterCircomError> {
+//!         let start = BaseElement::from(inputs["start"].as_u64().unwrap());
+//!         Ok(prover.build_trace(start, trace_length))
+//!     }
+//!
+//!     fn public_inputs(trace_length: usize, inputs: &Value) -> Result<PublicInputs, WinterCircomError> {
+//!         // public inputs of the computation, which are known to the verifier
+//!         let start = BaseElement::from(inputs["start"].as_u64().unwrap());
+//!         Ok(PublicInputs { start, result: BaseElement::from(8256u64) })
+//!     }
+//! }
+//!
+//! fn main() -> Result<(), WinterCircomError> {
+//!     cli::run::<Sum, 2>()
+//! }
+//! ```
+//!
+//! Then write the project config, named `winter-circom.toml` (see the
+//! [cli] module documentation for all entries).
+//!
+//! ```toml
+//! root = "../.."
+//! solidity_verifier = true
+//!
+//! [inputs]
+//! start = 1
+//! ```
+//!
+//! With the `server` feature, the `serve` command runs a local proving service
+//! for the compiled circuit. Proving jobs are submitted over HTTP, with the
+//! inputs of the computation as JSON body in place of the `[inputs]` table (see
+//! the [server] module documentation for the endpoints).
+//!
+//! ```sh
+//! cargo run --release --features server -- serve --address 127.0.0.1:8080 --workers 2
+//! curl -X POST -d '{"start": 1}' http://127.0.0.1:8080/jobs
+//! curl http://127.0.0.1:8080/jobs/0
+//! curl http://127.0.0.1:8080/jobs/0/proof
+//! curl -X DELETE http://127.0.0.1:8080/jobs/0
 //! ```
 //!
-//! 6. Define executables for compilation, proving and verifying.
+//! Alternatively, define executables for compilation, proving and verifying,
+//! with the functions of the library.
 //!
 //! See [cargo documentation](https://doc.rust-lang.org/cargo/reference/cargo-targets.html#binaries)
 //! for how to define multiple binaries in a single cargo crate.
@@ -339,10 +538,26 @@
 //! **Compile executable**
 //!
 //! ```rust
-//! use winter_circom_prover::{circom_compile, utils::{LoggingLevel, WinterCircomError}};
+//! use winter_circom_prover::{
+//!     circom_compile, CompileOptions,
+//!     utils::{ExecutionControl, LoggingLevel, WinterCircomError},
+//! };
 //!
 //! fn main() -> Result<(), WinterCircomError> {
-//!     circom_compile::<WorkProver, 2>(PROOF_OPTIONS, "sum", LoggingLevel::Default)
+//!     let options = CompileOptions {
+//!         inspect: true,
+//!         ..CompileOptions::default()
+//!     };
+//!     let diagnostics = circom_compile::<WorkAir, 2>(
+//!         false,
+//!         &options,
+//!         &ExecutionControl::default(),
+//!         LoggingLevel::Default,
+//!     )?;
+//!     for diagnostic in diagnostics.iter().filter(|d| d.is_under_constrained()) {
+//!         println!("{}", diagnostic.message);
+//!     }
+//!     Ok(())
 //! }
 //! ```
 //!
@@ -350,10 +565,11 @@
 //!
 //! ```rust
 //! use winter_circom_prover::{
-//!     circom_prove,
-//!     utils::{LoggingLevel, WinterCircomError},
+//!     circom_prove, save_proof,
+//!     utils::{ExecutionControl, LoggingLevel, WinterCircomError},
 //!     winterfell::math::{fields::f256::BaseElement, FieldElement},
 //! };
+//! use std::time::Duration;
 //!
 //! fn main() -> Result<(), WinterCircomError> {
 //!     // parameters
@@ -362,9 +578,20 @@
 //!     // build proof
 //!     let options = PROOF_OPTIONS.get_proof_options();
 //!     let prover = WorkProver::new(options.clone());
-//!     let trace = prover.build_trace(start, PROOF_OPTIONS.trace_length);
+//!     let mut trace = prover.build_trace(start, PROOF_OPTIONS.trace_length);
+//!
+//!     // only needed in zero-knowledge mode
+//!     PROOF_OPTIONS.blind_trace(&mut trace)?;
+//!
+//!     // kill the witness generator if it hangs
+//!     let mut control = ExecutionControl::default();
+//!     control.timeouts.witness = Some(Duration::from_secs(60));
 //!
-//!     circom_prove(prover, trace, "sum", LoggingLevel::Default)
+//!     let (proof, public_signals) =
+//!         circom_prove::<WorkProver, 2>(prover, trace, &control, LoggingLevel::Default)?;
+//!
+//!     // write the proof to disk, for the verifier
+//!     save_proof("sum", &proof, &public_signals)
 //! }
 //! ```
 //!
@@ -372,13 +599,29 @@
 //!
 //! ```rust
 //! use winter_circom_prover::{
-//!     check_ood_frame, circom_verify,
-//!     utils::{LoggingLevel, WinterCircomError},
+//!     circom_verify, load_proof,
+//!     utils::{ExecutionControl, LoggingLevel, WinterCircomError},
+//!     winterfell::math::{fields::f256::BaseElement, FieldElement},
 //! };
 //!
 //! fn main() -> Result<(), WinterCircomError> {
-//!     check_ood_frame::<WorkAir>("sum");
-//!     circom_verify("sum", LoggingLevel::Verbose)?;
+//!     // public inputs of the computation, which are known to the verifier
+//!     let pub_inputs = PublicInputs {
+//!         start: BaseElement::ONE,
+//!         result: BaseElement::from(8256u64),
+//!     };
+//!
+//!     let (proof, public_signals) = load_proof("sum")?;
+//!
+//!     // also checks the OOD consistency
+//!     let report = circom_verify::<WorkAir, 2>(
+//!         &proof,
+//!         &public_signals,
+//!         pub_inputs,
+//!         &ExecutionControl::default(),
+//!         LoggingLevel::Verbose,
+//!     )?;
+//!     assert!(report.is_valid());
 //!
 //!     Ok(())
 //! }
@@ -386,51 +629,211 @@
 //!
 //! # Computing the proof
 //!
-//! - The `compile` executable will generate and compile the circom code. It must
-//! be run once before any other executable and every time the parameters of the
-//! proof of the Circom code are changed.
-//! - Run the `prove` executable to compute the Winterfell proof and verify it
+//! - The `compile` and `setup` commands will generate and compile the circom
+//! code, and generate the circuit-specific keys. They must be run once before
+//! any other command and every time the parameters of the proof of the Circom
+//! code are changed.
+//! - Run the `prove` command to compute the Winterfell proof and verify it
 //! using the generated Circom circuit.
-//! - Run the `verify` executable to verify the Groth16 proof and check the OOD
+//! - Run the `verify` command to verify the Groth16 proof and check the OOD
 //! consistency.
 //!
 //! In a real life scenario, the last step is of course executed by another
 //! party.
 //!
+//! # On-chain verification
+//!
+//! When its `solidity_verifier` argument is set, [circom_compile] also exports a
+//! Solidity contract verifying Groth16 proofs for the circuit, named
+//! `verifier.sol`. The [solidity_calldata] function then encodes the proof
+//! generated by [circom_prove] and its public inputs into the calldata of a call
+//! to the `verifyProof` method of this contract.
+//!
+//! Note that the OOD consistency check is not performed by the contract, and
+//! must still be done with [check_ood_frame].
+//!
+//! # Tracing
+//!
+//! The pipeline is instrumented with [tracing](https://docs.rs/tracing) spans:
+//! `stark_prove`, `stark_verify` (debug mode only), `json_export`,
+//! `witness_generation`, `groth16_prove`, `ood_check` and `groth16_verify`.
+//! Each span records its duration, the peak memory usage of the child
+//! processes it runs in `peak_child_memory_kb`, and the peak memory usage of
+//! the process since its start in `process_peak_memory_kb`. The output of the
+//! underlying executables is emitted as events, line by line, and is only
+//! printed as well from the [Verbose](utils::LoggingLevel::Verbose) logging
+//! level.
+//!
+//! Install a subscriber to route them. The progress printed to stdout is still
+//! controlled by the [LoggingLevel](utils::LoggingLevel) argument of the
+//! functions of this crate.
+//!
+//! # Timeouts and cancellation
+//!
+//! The compile, prove and verify functions also take an
+//! [ExecutionControl](utils::ExecutionControl), which sets per-stage timeouts on
+//! the underlying executables and carries a
+//! [CancellationToken](utils::CancellationToken). Cancelling the token from
+//! another thread kills the running executable.
+//!
+//! An executable that fails, times out or is cancelled results in an
+//! [ExitCodeError](utils::WinterCircomError::ExitCodeError),
+//! [Timeout](utils::WinterCircomError::Timeout) or
+//! [Cancelled](utils::WinterCircomError::Cancelled) error. The first two errors
+//! carry its captured stdout and stderr.
+//!
+//! # Compile options and diagnostics
+//!
+//! The compilation of the circuit is configured by [CompileOptions]: the
+//! simplification level of the constraints (`--O0`, `--O1` or `--O2`), the
+//! `--inspect` static analysis of the Circom compiler, and the optional
+//! `verifier.sym` (`--sym`) and WebAssembly witness generator (`--wasm`)
+//! artifacts.
+//!
+//! The warnings of the compiler are returned by [circom_compile] as
+//! [CircomDiagnostic]s. Those reporting signals that do not appear in any
+//! constraint are flagged by
+//! [is_under_constrained](CircomDiagnostic::is_under_constrained).
+//!
+//! # Poseidon parameters
+//!
+//! The round constants and MDS matrix of the Poseidon hash function, used by
+//! Winterfell and by the circuit, are generated by [PoseidonParameters] with
+//! the Grain LFSR of the reference implementation, for any prime field, state
+//! width, S-box exponent and security level. The `poseidon_parameters` example
+//! writes both the Rust and the Circom parameter files.
+//! The round constants of Poseidon2 are generated by [Poseidon2Parameters] in the
+//! same way, and written by the `poseidon2_parameters` example.
+//!
 //! # Disclaimer
 //!
 //! This library is a research project, has not been audited for safety and
 //! should not be used in production.
 //!
-//! The circuit-specific keys, generated by the `compile` executable, do not
-//! contain contributions and are therefore unsafe to use in production.
+//! The circuit-specific keys, generated by the `setup` command, do not contain
+//! contributions and are therefore unsafe to use in production. The
+//! `contribute` command adds a single random contribution.
 
 use serde::Serialize;
+use serde_json::{json, Value};
+use winter_rand_utils::rand_value;
 
 mod json;
 
 mod circom;
 pub use circom::{circom_compile, circom_prove, circom_verify};
 
+mod compile;
+pub use compile::{parse_diagnostics, CircomDiagnostic, CompileOptions, Simplification};
+
+mod proof;
+pub use proof::{
+    load_proof, save_proof, CommittedValues, Groth16Proof, PublicSignals, VerificationReport,
+};
+
+#[cfg(feature = "cli")]
+pub mod cli;
+
+mod handle;
+pub use handle::CircuitHandle;
+
+#[cfg(feature = "server")]
+pub mod server;
+
+mod solidity;
+pub use solidity::{generate_solidity_verifier, groth16_calldata, solidity_calldata};
+
 mod verification;
 pub use verification::check_ood_frame;
 
+mod poseidon;
+pub use poseidon::PoseidonParameters;
+
+mod poseidon2;
+pub use poseidon2::Poseidon2Parameters;
+
+mod public_inputs;
+pub use winter_circom_prover_derive::WinterPublicInputs;
+
+/// Items used by the code generated by the [WinterPublicInputs] derive macro.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::public_inputs::{check_public_inputs_layout, PublicInputsElements};
+    pub use serde;
+    pub use winterfell::{math::fields::f256::BaseElement, ByteWriter, Serializable};
+}
+
 pub mod utils;
 
 /// Re-export of a modified version of Winterfell, that has been adapted to suit
 /// the needs of this crate.
 pub use winterfell;
-use winterfell::{HashFunction, ProofOptions, TransitionConstraintDegree};
+use utils::WinterCircomError;
+use winterfell::{
+    math::{fields::f256::BaseElement, log2},
+    Air, HashFunction, ProofOptions, Trace, TraceInfo, TraceTable, TransitionConstraintDegree,
+};
 
 /// Trait for compatibility between implementations of [winterfell::Air::PublicInputs]
 /// and this crate.
 ///
 /// It simply requires that the number of public inputs be specified (through the
 /// [NUM_PUB_INPUTS](WinterPublicInputs::NUM_PUB_INPUTS) constant).
+///
+/// This trait, along with [Serialize] and
+/// [Serializable](winterfell::Serializable), can be derived with
+/// `#[derive(WinterPublicInputs)]`, which keeps the number of public inputs and
+/// both serializations consistent. Fields of the struct can be `BaseElement`s,
+/// arrays, or structs that themselves derive `WinterPublicInputs`.
 pub trait WinterPublicInputs: Serialize + Clone {
     const NUM_PUB_INPUTS: usize;
 }
 
+/// Extension of the [Air](winterfell::Air) trait, declaring everything this
+/// crate needs to generate, prove and verify the Circom circuit of an AIR.
+///
+/// The circuit name, the proof options and the Circom templates of the AIR are
+/// bound to the type, so that [circom_compile], [circom_prove] and
+/// [circom_verify] only take the type as parameter.
+///
+/// Generic parameter `N` is the number of transition constraints (see
+/// [WinterCircomProofOptions]).
+///
+/// The [Air::new](winterfell::Air::new) method of the implementation should
+/// build its context from [PROOF_OPTIONS](CircomAir::PROOF_OPTIONS), and its
+/// public inputs must implement [WinterPublicInputs].
+pub trait CircomAir<const N: usize>:
+    Air<BaseField = BaseElement, PublicInputs: WinterPublicInputs>
+{
+    /// Name of the circuit. Generated files are placed in the
+    /// `target/circom/<CIRCUIT_NAME>/` directory.
+    const CIRCUIT_NAME: &'static str;
+
+    /// Proof options of the circuit.
+    const PROOF_OPTIONS: WinterCircomProofOptions<N>;
+
+    /// Source of the Circom file defining the `AIRTransitions`,
+    /// `AIRAssertions` and (zero-knowledge mode only)
+    /// `AIRTransitionEvaluations` templates of the AIR, usually included with
+    /// `include_str!("<path>/circuits/air/<CIRCUIT_NAME>.circom")`. Its
+    /// includes are resolved from the `circuits/air/` directory.
+    const AIR_TEMPLATES: &'static str;
+
+    /// Number of public inputs of the AIR, given to its Circom templates.
+    ///
+    /// This is the number of field elements the public inputs are flattened
+    /// into, checked against the layout declared by
+    /// [PROOF_OPTIONS](CircomAir::PROOF_OPTIONS) when the circuit is generated.
+    /// A mismatch is a compile-time error, which the
+    /// `#[winter_circom(options = ...)]` attribute of the
+    /// [WinterPublicInputs] derive macro reports at the definition of the
+    /// public inputs.
+    const NUM_PUBLIC_INPUTS: usize = public_inputs::check_public_inputs_layout(
+        &Self::PROOF_OPTIONS,
+        <Self::PublicInputs as WinterPublicInputs>::NUM_PUB_INPUTS,
+    );
+}
+
 /// Proof options for a input-independant circuit.
 ///
 /// ## Transition constraints
@@ -439,9 +842,33 @@ pub trait WinterPublicInputs: Serialize + Clone {
 ///
 /// Element `transition_constraint_degree` is a usize array that will be mapped to
 /// an array of [TransitionConstraintDegree] through its `new()` method.
+///
+/// ## Public inputs
+///
+/// Element `num_public_inputs` is the number of field elements the public
+/// inputs of the AIR are flattened into (see [WinterPublicInputs]). It is
+/// checked against them at compile time (see
+/// [NUM_PUBLIC_INPUTS](CircomAir::NUM_PUBLIC_INPUTS)).
+///
+/// ## Public signals
+///
+/// By default, the OOD trace frame and the OOD constraint evaluations are the
+/// public signals of the Groth16 proof. The
+/// [with_public_signals_commitment](WinterCircomProofOptions::with_public_signals_commitment)
+/// method replaces them with a single commitment.
+///
+/// ## Zero-knowledge
+///
+/// The OOD trace frame is evaluations of the trace polynomials, and therefore
+/// leaks information about the execution trace when made public. The
+/// [with_zero_knowledge](WinterCircomProofOptions::with_zero_knowledge) method
+/// keeps it private, and blinds the trace with random rows (see
+/// [blind_trace](WinterCircomProofOptions::blind_trace)).
+#[derive(Clone)]
 pub struct WinterCircomProofOptions<const N: usize> {
     pub trace_length: usize,
     pub trace_width: usize,
+    pub num_public_inputs: usize,
     num_assertions: usize,
     transition_constraint_degrees: [usize; N],
     num_queries: usize,
@@ -449,12 +876,20 @@ pub struct WinterCircomProofOptions<const N: usize> {
     grinding_factor: u32,
     fri_folding_factor: usize,
     fri_max_remainder_size: usize,
+    public_signals_commitment: bool,
+    num_blinding_rows: usize,
+    max_trace_length: usize,
+    merkle_arity: usize,
+    poseidon2: bool,
+    fri_remainder_coefficients: bool,
+    fri_num_partitions: usize,
 }
 
 impl<const N: usize> WinterCircomProofOptions<N> {
     pub const fn new(
         trace_length: usize,
         trace_width: usize,
+        num_public_inputs: usize,
         num_assertions: usize,
         transition_constraint_degrees: [usize; N],
         num_queries: usize,
@@ -466,6 +901,7 @@ impl<const N: usize> WinterCircomProofOptions<N> {
         Self {
             trace_length,
             trace_width,
+            num_public_inputs,
             num_assertions,
             transition_constraint_degrees,
             num_queries,
@@ -473,28 +909,323 @@ impl<const N: usize> WinterCircomProofOptions<N> {
             grinding_factor,
             fri_folding_factor,
             fri_max_remainder_size,
+            public_signals_commitment: false,
+            num_blinding_rows: 0,
+            max_trace_length: 0,
+            merkle_arity: 2,
+            poseidon2: false,
+            fri_remainder_coefficients: false,
+            fri_num_partitions: 1,
+        }
+    }
+
+    /// Replace the public signals of the Groth16 proof with a single Poseidon
+    /// hash of the OOD trace frame, the OOD constraint evaluations and the
+    /// public inputs.
+    ///
+    /// The cost of verifying the Groth16 proof, on-chain or in batches, then no
+    /// longer depends on the trace width. The hashed values are written to
+    /// `public_values.json` by [circom_prove], and the commitment is checked
+    /// against them by [check_ood_frame].
+    pub const fn with_public_signals_commitment(mut self) -> Self {
+        assert!(
+            self.num_blinding_rows == 0,
+            "public signals commitment and zero-knowledge modes are mutually exclusive"
+        );
+        assert!(
+            self.max_trace_length == 0,
+            "public signals commitment and variable trace length modes are mutually exclusive"
+        );
+        self.public_signals_commitment = true;
+        self
+    }
+
+    /// Make the Groth16 proof zero-knowledge, by keeping the OOD trace frame and
+    /// the OOD constraint evaluations private.
+    ///
+    /// The transition constraints are then evaluated over the OOD trace frame in
+    /// the circuit, by the `AIRTransitionEvaluations` template of the AIR, and
+    /// the public inputs of the computation are the only public signals of the
+    /// Groth16 proof.
+    ///
+    /// The last `num_blinding_rows` rows of the trace are filled with random
+    /// values by [blind_trace](WinterCircomProofOptions::blind_trace), which
+    /// randomizes the trace polynomials. The AIR must leave these rows
+    /// unconstrained: the transition constraints must be exempted on them (see
+    /// [num_transition_exemptions](WinterCircomProofOptions::num_transition_exemptions)),
+    /// and assertions must not be placed on them. [circom_prove] rejects the
+    /// AIRs which constrain them.
+    ///
+    /// The composition polynomial and the FRI layers are not blinded, so the
+    /// Winterfell proof itself is not zero-knowledge: it is a private input of
+    /// the circuit, and must not be shared. Only the Groth16 proof is
+    /// zero-knowledge.
+    pub const fn with_zero_knowledge(mut self, num_blinding_rows: usize) -> Self {
+        assert!(
+            num_blinding_rows > 0,
+            "zero-knowledge mode requires at least one blinding row"
+        );
+        assert!(
+            !self.public_signals_commitment,
+            "public signals commitment and zero-knowledge modes are mutually exclusive"
+        );
+        assert!(
+            self.max_trace_length == 0,
+            "zero-knowledge and variable trace length modes are mutually exclusive"
+        );
+        self.num_blinding_rows = num_blinding_rows;
+        self
+    }
+
+    /// Compile a circuit that verifies proofs of any trace length up to
+    /// `max_trace_length`, instead of `trace_length` only.
+    ///
+    /// The log2 of the trace length is then the first public signal of the
+    /// Groth16 proof, and a single circuit-specific setup serves all the
+    /// supported trace lengths, from
+    /// [min_trace_length](WinterCircomProofOptions::min_trace_length) to
+    /// `max_trace_length`, growing by a factor of the Merkle arity. The
+    /// `trace_length` of the options must be one of them.
+    pub const fn with_max_trace_length(mut self, max_trace_length: usize) -> Self {
+        assert!(
+            max_trace_length > 0,
+            "variable trace length mode requires a positive maximum trace length"
+        );
+        assert!(
+            !self.public_signals_commitment,
+            "public signals commitment and variable trace length modes are mutually exclusive"
+        );
+        assert!(
+            self.num_blinding_rows == 0,
+            "zero-knowledge and variable trace length modes are mutually exclusive"
+        );
+        self.max_trace_length = max_trace_length;
+        self
+    }
+
+    /// Commit to the trace, the constraint evaluations and the FRI layers with
+    /// Merkle trees of the given arity (2, 4 or 8) instead of binary trees.
+    ///
+    /// The nodes of the trees are merged with a Poseidon permutation of width
+    /// `merkle_arity + 2`, and the trees are `log2(merkle_arity)` times
+    /// shallower. The authentication paths hold `merkle_arity - 1` nodes per
+    /// level, which makes them longer but cheaper to verify in the circuit.
+    ///
+    /// The log2 of the LDE domain size and of the FRI folding factor must be
+    /// multiples of `log2(merkle_arity)`, and the FRI remainder must hold at
+    /// least `merkle_arity` leaves.
+    pub const fn with_merkle_arity(mut self, merkle_arity: usize) -> Self {
+        assert!(
+            !self.poseidon2 || merkle_arity == 2,
+            "Poseidon2 requires binary Merkle trees"
+        );
+        self.merkle_arity = merkle_arity;
+        self
+    }
+
+    /// Hash with Poseidon2 instead of Poseidon, both in the Winterfell proof
+    /// and in the circuit.
+    ///
+    /// The Merkle trees and the public coin then use the
+    /// [Poseidon2](winterfell::HashFunction::Poseidon2) hasher, whose
+    /// permutation has a state of width 3. Only binary Merkle trees are
+    /// supported.
+    pub const fn with_poseidon2(mut self) -> Self {
+        assert!(
+            self.merkle_arity == 2,
+            "Poseidon2 requires binary Merkle trees"
+        );
+        self.poseidon2 = true;
+        self
+    }
+
+    /// Send the FRI remainder as the coefficients of its polynomial instead of
+    /// its evaluations.
+    ///
+    /// The remainder is then committed to with a single hash of its
+    /// coefficients, and the circuit evaluates it at the queried points with
+    /// Horner's method instead of interpolating it, which removes the FFT and
+    /// the remainder Merkle tree from the circuit.
+    pub const fn with_fri_remainder_coefficients(mut self) -> Self {
+        self.fri_remainder_coefficients = true;
+        self
+    }
+
+    /// Commit to the FRI layers in `num_partitions` partitions instead of one.
+    ///
+    /// The leaves of the Merkle tree of each FRI layer, except for the
+    /// remainder, are then ordered by partition, the partition of a folded
+    /// position being its remainder modulo `num_partitions`, and the circuit
+    /// maps the folded positions to the indexes of their leaves. The number of
+    /// partitions must be a power of 2, no larger than the FRI remainder.
+    pub const fn with_fri_partitions(mut self, num_partitions: usize) -> Self {
+        self.fri_num_partitions = num_partitions;
+        self
+    }
+
+    /// Fill the blinding rows of the trace with random values.
+    ///
+    /// This function does nothing when the zero-knowledge mode is not enabled
+    /// (see [with_zero_knowledge](WinterCircomProofOptions::with_zero_knowledge)),
+    /// and returns an [InvalidOptions](WinterCircomError::InvalidOptions) error
+    /// when the transition exemptions of the blinding rows do not fit in half
+    /// of the trace.
+    pub fn blind_trace(
+        &self,
+        trace: &mut TraceTable<BaseElement>,
+    ) -> Result<(), WinterCircomError> {
+        let trace_length = trace.length();
+        if self.zero_knowledge() {
+            ensure(
+                self.num_transition_exemptions() <= trace_length / 2,
+                "number of blinding rows must be smaller than half the trace length",
+            )?;
+        }
+        for step in trace_length - self.num_blinding_rows..trace_length {
+            for register in 0..trace.width() {
+                trace.set(register, step, rand_value());
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that the options are supported by Winterfell and by the Circom
+    /// circuit, returning an [InvalidOptions](WinterCircomError::InvalidOptions)
+    /// error otherwise.
+    ///
+    /// This check is performed by [circom_compile], and by the command-line tool
+    /// after applying its overrides.
+    pub fn validate(&self) -> Result<(), WinterCircomError> {
+        validate_options(
+            self.trace_length,
+            self.trace_width,
+            self.num_queries,
+            self.lde_blowup_factor,
+            self.grinding_factor,
+            self.fri_folding_factor,
+            self.fri_max_remainder_size,
+        )?;
+        validate_merkle_arity(
+            self.trace_length * self.lde_blowup_factor,
+            self.fri_folding_factor,
+            self.fri_max_remainder_size,
+            self.merkle_arity,
+        )?;
+        ensure(
+            !self.poseidon2 || self.merkle_arity == 2,
+            "Poseidon2 requires a Merkle arity of 2",
+        )?;
+        validate_fri_partitions(
+            self.trace_length * self.lde_blowup_factor,
+            self.fri_folding_factor,
+            self.fri_max_remainder_size,
+            self.fri_num_partitions,
+        )?;
+        ensure(
+            !self.zero_knowledge() || self.num_transition_exemptions() <= self.trace_length / 2,
+            "number of blinding rows must be smaller than half the trace length",
+        )?;
+
+        if self.variable_trace_length() {
+            ensure(
+                self.max_trace_length.is_power_of_two()
+                    && self.max_trace_length >= self.trace_length
+                    && log2(self.max_trace_length / self.trace_length) % log2(self.merkle_arity)
+                        == 0,
+                "maximum trace length must be a power of 2, at least the trace length, and differ from it by a power of the Merkle arity",
+            )?;
+            ensure(
+                self.supports_trace_length(self.max_trace_length),
+                "maximum trace length leads to a FRI remainder which is too small for the Circom circuit",
+            )?;
+            ensure(
+                self.trace_length >= self.min_trace_length(),
+                "trace length must be at least the smallest trace length supported by the circuit",
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Smallest trace length supported by the circuit.
+    ///
+    /// This is the trace length of the options, unless the circuit verifies
+    /// proofs of variable trace length (see
+    /// [with_max_trace_length](WinterCircomProofOptions::with_max_trace_length)).
+    /// The circuit then supports the powers of 2 from this length to the
+    /// maximum trace length, growing by a factor of the Merkle arity, with
+    /// which the options would be valid for a single trace length and the FRI
+    /// remainder is larger than the blowup factor.
+    pub fn min_trace_length(&self) -> usize {
+        if !self.variable_trace_length() {
+            return self.trace_length;
+        }
+
+        let mut min_trace_length = self.max_trace_length;
+        while min_trace_length / self.merkle_arity >= TraceInfo::MIN_TRACE_LENGTH
+            && self.supports_trace_length(min_trace_length / self.merkle_arity)
+        {
+            min_trace_length /= self.merkle_arity;
         }
+        min_trace_length
     }
 
+    /// Whether the variable-length circuit can verify proofs of the given
+    /// trace length, see [min_trace_length](WinterCircomProofOptions::min_trace_length).
+    fn supports_trace_length(&self, trace_length: usize) -> bool {
+        let options = Self {
+            trace_length,
+            max_trace_length: 0,
+            ..self.clone()
+        };
+        if options.validate().is_err() {
+            return false;
+        }
+
+        let (_, remainder_size) = fri_layers(
+            trace_length * self.lde_blowup_factor,
+            self.fri_folding_factor,
+            self.fri_max_remainder_size,
+        );
+        remainder_size > self.lde_blowup_factor
+    }
+
+    /// Winterfell proof options.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the options are invalid, see
+    /// [validate](WinterCircomProofOptions::validate).
     pub fn get_proof_options(&self) -> ProofOptions {
-        assert!(self.trace_length * self.lde_blowup_factor > self.fri_max_remainder_size,
-            "trace_length * lde_blowup_factor must be greater than fri_max_remainder_size for the Circom circuit to work");
+        if let Err(e) = self.validate() {
+            panic!("{}", e);
+        }
 
-        ProofOptions::new(
+        let options = ProofOptions::new(
             self.num_queries,
             self.lde_blowup_factor,
             self.grinding_factor,
-            HashFunction::Poseidon,
+            hash_function(self.poseidon2, self.merkle_arity),
             winterfell::FieldExtension::None,
             self.fri_folding_factor,
             self.fri_max_remainder_size,
         )
+        .with_fri_partitions(self.fri_num_partitions);
+        if self.fri_remainder_coefficients {
+            options.with_fri_remainder_coefficients()
+        } else {
+            options
+        }
     }
 
     pub(crate) fn fri_folding_factor(&self) -> usize {
         self.fri_folding_factor
     }
 
+    pub(crate) fn fri_max_remainder_size(&self) -> usize {
+        self.fri_max_remainder_size
+    }
+
     pub(crate) fn grinding_factor(&self) -> u32 {
         self.grinding_factor
     }
@@ -507,6 +1238,61 @@ impl<const N: usize> WinterCircomProofOptions<N> {
         self.num_queries
     }
 
+    pub(crate) fn public_signals_commitment(&self) -> bool {
+        self.public_signals_commitment
+    }
+
+    pub(crate) const fn zero_knowledge(&self) -> bool {
+        self.num_blinding_rows > 0
+    }
+
+    pub(crate) fn variable_trace_length(&self) -> bool {
+        self.max_trace_length > 0
+    }
+
+    pub(crate) fn max_trace_length(&self) -> usize {
+        self.max_trace_length
+    }
+
+    pub(crate) fn merkle_arity(&self) -> usize {
+        self.merkle_arity
+    }
+
+    pub(crate) fn poseidon2(&self) -> bool {
+        self.poseidon2
+    }
+
+    pub(crate) fn fri_remainder_coefficients(&self) -> bool {
+        self.fri_remainder_coefficients
+    }
+
+    pub(crate) fn fri_num_partitions(&self) -> usize {
+        self.fri_num_partitions
+    }
+
+    /// Options the proof was compiled with, which are needed for verification.
+    pub(crate) fn to_json(&self) -> Value {
+        json!({
+            "trace_length": self.trace_length,
+            "trace_width": self.trace_width,
+            "num_assertions": self.num_assertions,
+            "transition_constraint_degrees": self.transition_constraint_degrees.to_vec(),
+            "num_queries": self.num_queries,
+            "lde_blowup_factor": self.lde_blowup_factor,
+            "grinding_factor": self.grinding_factor,
+            "fri_folding_factor": self.fri_folding_factor,
+            "fri_max_remainder_size": self.fri_max_remainder_size,
+            "public_signals_commitment": self.public_signals_commitment,
+            "num_blinding_rows": self.num_blinding_rows,
+            "max_trace_length": self.variable_trace_length().then_some(self.max_trace_length),
+            "min_trace_length": self.variable_trace_length().then(|| self.min_trace_length()),
+            "merkle_arity": self.merkle_arity,
+            "poseidon2": self.poseidon2,
+            "fri_remainder_coefficients": self.fri_remainder_coefficients,
+            "fri_num_partitions": self.fri_num_partitions,
+        })
+    }
+
     pub fn transition_constraint_degrees(&self) -> Vec<TransitionConstraintDegree> {
         self.transition_constraint_degrees
             .iter()
@@ -517,4 +1303,138 @@ impl<const N: usize> WinterCircomProofOptions<N> {
     pub fn num_assertions(&self) -> usize {
         self.num_assertions
     }
+
+    /// Number of steps at the end of the trace on which the transition
+    /// constraints are not enforced, to be given to
+    /// [AirContext::set_num_transition_exemptions](winterfell::AirContext::set_num_transition_exemptions).
+    ///
+    /// This is the last step, plus the blinding rows in zero-knowledge mode.
+    pub fn num_transition_exemptions(&self) -> usize {
+        self.num_blinding_rows + 1
+    }
+}
+
+/// Check that proof options are supported by Winterfell and by the Circom
+/// circuit, see [WinterCircomProofOptions::validate].
+pub(crate) fn validate_options(
+    trace_length: usize,
+    trace_width: usize,
+    num_queries: usize,
+    lde_blowup_factor: usize,
+    grinding_factor: u32,
+    fri_folding_factor: usize,
+    fri_max_remainder_size: usize,
+) -> Result<(), WinterCircomError> {
+    // trace info
+    ensure(
+        trace_length >= TraceInfo::MIN_TRACE_LENGTH && trace_length.is_power_of_two(),
+        "trace length must be a power of 2, at least 8",
+    )?;
+    ensure(
+        (1..=TraceInfo::MAX_TRACE_WIDTH).contains(&trace_width),
+        "trace width must be between 1 and 255",
+    )?;
+
+    // Winterfell proof options
+    ensure(
+        (1..=128).contains(&num_queries),
+        "number of queries must be between 1 and 128",
+    )?;
+    ensure(
+        lde_blowup_factor.is_power_of_two()
+            && (ProofOptions::MIN_BLOWUP_FACTOR..=128).contains(&lde_blowup_factor),
+        "blowup factor must be a power of 2, between 2 and 128",
+    )?;
+    ensure(grinding_factor <= 32, "grinding factor cannot be greater than 32")?;
+    ensure(
+        fri_folding_factor.is_power_of_two() && (4..=16).contains(&fri_folding_factor),
+        "FRI folding factor must be a power of 2, between 4 and 16",
+    )?;
+    ensure(
+        fri_max_remainder_size.is_power_of_two() && (32..=1024).contains(&fri_max_remainder_size),
+        "FRI max remainder size must be a power of 2, between 32 and 1024",
+    )?;
+
+    // Circom circuit
+    ensure(
+        trace_length * lde_blowup_factor > fri_max_remainder_size,
+        "trace_length * lde_blowup_factor must be greater than fri_max_remainder_size for the Circom circuit to work",
+    )
+}
+
+/// Check that the Merkle trees of an LDE domain, of its FRI layers and of its
+/// FRI remainder can have the given arity, see
+/// [WinterCircomProofOptions::with_merkle_arity]. The other options must be
+/// valid.
+pub(crate) fn validate_merkle_arity(
+    lde_domain_size: usize,
+    fri_folding_factor: usize,
+    fri_max_remainder_size: usize,
+    merkle_arity: usize,
+) -> Result<(), WinterCircomError> {
+    ensure([2, 4, 8].contains(&merkle_arity), "Merkle arity must be 2, 4 or 8")?;
+    let log_arity = log2(merkle_arity);
+    ensure(
+        log2(lde_domain_size) % log_arity == 0 && log2(fri_folding_factor) % log_arity == 0,
+        "log2 of the LDE domain size and of the FRI folding factor must be multiples of log2 of the Merkle arity",
+    )?;
+    let (_, remainder_size) =
+        fri_layers(lde_domain_size, fri_folding_factor, fri_max_remainder_size);
+    ensure(
+        remainder_size >= fri_folding_factor * merkle_arity,
+        "the FRI remainder must hold at least fri_folding_factor * merkle_arity elements",
+    )
+}
+
+/// Check that the FRI layers of an LDE domain can be committed to in the given
+/// number of partitions, see [WinterCircomProofOptions::with_fri_partitions].
+/// The other options must be valid.
+pub(crate) fn validate_fri_partitions(
+    lde_domain_size: usize,
+    fri_folding_factor: usize,
+    fri_max_remainder_size: usize,
+    fri_num_partitions: usize,
+) -> Result<(), WinterCircomError> {
+    let (_, remainder_size) =
+        fri_layers(lde_domain_size, fri_folding_factor, fri_max_remainder_size);
+    ensure(
+        fri_num_partitions.is_power_of_two() && fri_num_partitions <= remainder_size,
+        "FRI number of partitions must be a power of 2, no larger than the FRI remainder",
+    )
+}
+
+/// Hash function of the Winterfell proof, Poseidon2 or Poseidon with Merkle
+/// trees of the given arity.
+pub(crate) fn hash_function(poseidon2: bool, merkle_arity: usize) -> HashFunction {
+    match (poseidon2, merkle_arity) {
+        (true, _) => HashFunction::Poseidon2,
+        (false, 4) => HashFunction::Poseidon4,
+        (false, 8) => HashFunction::Poseidon8,
+        _ => HashFunction::Poseidon,
+    }
+}
+
+/// Number of FRI layers of an LDE domain, and size of its remainder.
+///
+/// The domain is folded until it is no larger than the maximum remainder size.
+pub(crate) fn fri_layers(
+    lde_domain_size: usize,
+    fri_folding_factor: usize,
+    fri_max_remainder_size: usize,
+) -> (usize, usize) {
+    let mut num_fri_layers = 0;
+    let mut remainder_size = lde_domain_size;
+    while remainder_size > fri_max_remainder_size {
+        remainder_size /= fri_folding_factor;
+        num_fri_layers += 1;
+    }
+    (num_fri_layers, remainder_size)
+}
+
+fn ensure(condition: bool, comment: &str) -> Result<(), WinterCircomError> {
+    if condition {
+        Ok(())
+    } else {
+        Err(WinterCircomError::InvalidOptions(comment.to_owned()))
+    }
 }
@@ -0,0 +1,1092 @@
+use std::fmt::Write;
+
+use num_bigint::BigUint;
+use num_traits::{One, ToPrimitive, Zero};
+use winterfell::math::{fields::f256::BaseElement, StarkField};
+
+use crate::{ensure, utils::WinterCircomError};
+
+pub(crate) type Matrix = Vec<Vec<BigUint>>;
+
+// POSEIDON PARAMETERS
+// ===========================================================================
+
+/// Parameters of the Poseidon permutation over a prime field.
+///
+/// The round constants and the MDS matrix are sampled with the Grain LFSR,
+/// following the reference implementation of Poseidon
+/// (<https://extgit.iaik.tugraz.at/krypto/hadeshash>). MDS matrices which
+/// admit invariant subspace trails are rejected, as by the reference
+/// implementation.
+///
+/// The parameters are exported as a parameter file of the Poseidon hasher of
+/// winter-crypto (`param.rs` for width 4, `param_t6.rs` and `param_t10.rs`
+/// for the compressions of the 4-ary and 8-ary Merkle trees) with
+/// [to_rust](PoseidonParameters::to_rust), and the parameters of every width
+/// as the `circuits/poseidon/param.circom` file with
+/// [to_circom](PoseidonParameters::to_circom). Both include the sparse
+/// matrices and the round constants of the optimized partial rounds.
+///
+/// The `poseidon_parameters` example of this crate writes these files.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonParameters {
+    modulus: BigUint,
+    width: usize,
+    alpha: u32,
+    num_full_rounds: usize,
+    num_partial_rounds: usize,
+    round_constants: Matrix,
+    mds: Matrix,
+}
+
+impl PoseidonParameters {
+    /// Generate the parameters of the Poseidon permutation.
+    ///
+    /// - `modulus`: prime modulus of the field, in decimal or in hexadecimal
+    ///   with a `0x` prefix.
+    /// - `width`: number of field elements in the state, the rate of the
+    ///   sponge being `width - 1`.
+    /// - `alpha`: exponent of the S-box, which must be coprime with
+    ///   `modulus - 1`.
+    /// - `num_full_rounds`, `num_partial_rounds`: number of rounds, see
+    ///   [round_numbers](PoseidonParameters::round_numbers).
+    ///
+    /// An [InvalidOptions](WinterCircomError::InvalidOptions) error is
+    /// returned for unsupported parameters.
+    pub fn generate(
+        modulus: &str,
+        width: usize,
+        alpha: u32,
+        num_full_rounds: usize,
+        num_partial_rounds: usize,
+    ) -> Result<Self, WinterCircomError> {
+        let field = Field::new(modulus, width, alpha)?;
+        ensure(
+            num_full_rounds > 0 && num_full_rounds % 2 == 0 && num_full_rounds < 1024,
+            "number of full rounds must be an even number, between 2 and 1022",
+        )?;
+        ensure(
+            (1..1024).contains(&num_partial_rounds),
+            "number of partial rounds must be between 1 and 1023",
+        )?;
+
+        let mut grain = Grain::new(field.num_bits(), width, num_full_rounds, num_partial_rounds);
+
+        // the round constants are sampled first, then the MDS matrix
+        let round_constants = (0..num_full_rounds + num_partial_rounds)
+            .map(|_| {
+                (0..width)
+                    .map(|_| grain.field_element(&field.modulus))
+                    .collect()
+            })
+            .collect();
+
+        let mds = loop {
+            if let Some(mds) = cauchy_matrix(&field, &mut grain, width) {
+                if field.is_secure(&mds) {
+                    break mds;
+                }
+            }
+        };
+
+        Ok(Self {
+            modulus: field.modulus,
+            width,
+            alpha,
+            num_full_rounds,
+            num_partial_rounds,
+            round_constants,
+            mds,
+        })
+    }
+
+    /// Number of full and partial rounds providing `security_level` bits of
+    /// security, with the security margin of the reference implementation
+    /// (two more full rounds and 7.5% more partial rounds).
+    ///
+    /// The number of rounds minimizes the number of S-boxes, under the
+    /// statistical, interpolation and Gröbner basis attacks of the Poseidon
+    /// paper.
+    pub fn round_numbers(
+        modulus: &str,
+        width: usize,
+        alpha: u32,
+        security_level: u32,
+    ) -> Result<(usize, usize), WinterCircomError> {
+        let field = Field::new(modulus, width, alpha)?;
+        let log2_modulus = field.modulus.to_f64().unwrap_or(f64::INFINITY).ln() / 2f64.ln();
+
+        let mut round_numbers = (0, 0);
+        let mut min_cost = usize::MAX;
+        for num_partial_rounds in 1..500 {
+            // the number of partial rounds with security margin carries over
+            // to the next numbers of full rounds, as in the reference script
+            let mut num_partial_rounds = num_partial_rounds;
+            for num_full_rounds in (4..100).step_by(2) {
+                if !is_secure_against_attacks(
+                    log2_modulus,
+                    width,
+                    alpha,
+                    num_full_rounds,
+                    num_partial_rounds,
+                    security_level,
+                ) {
+                    continue;
+                }
+
+                let num_full_rounds = num_full_rounds + 2;
+                num_partial_rounds = (num_partial_rounds as f64 * 1.075).ceil() as usize;
+                let cost = width * num_full_rounds + num_partial_rounds;
+                if cost < min_cost || (cost == min_cost && num_full_rounds < round_numbers.0) {
+                    round_numbers = (num_full_rounds, num_partial_rounds);
+                    min_cost = cost;
+                }
+            }
+        }
+
+        Ok(round_numbers)
+    }
+
+    /// Number of full rounds of the permutation.
+    pub fn num_full_rounds(&self) -> usize {
+        self.num_full_rounds
+    }
+
+    /// Number of partial rounds of the permutation.
+    pub fn num_partial_rounds(&self) -> usize {
+        self.num_partial_rounds
+    }
+
+    /// Rust source of a parameter file of the Poseidon hasher of
+    /// winter-crypto.
+    ///
+    /// The hasher works over the f256 field of Winterfell only, an
+    /// [InvalidOptions](WinterCircomError::InvalidOptions) error is returned
+    /// for other fields.
+    pub fn to_rust(&self) -> Result<String, WinterCircomError> {
+        ensure(
+            self.modulus == BigUint::from_bytes_le(&BaseElement::get_modulus_le_bytes()),
+            "the Poseidon hasher of winter-crypto works over the f256 field only",
+        )?;
+
+        let field = self.field();
+        let (sparse_matrix, v_collection, w_hat_collection) = self.sparse_matrices(&field);
+        let t = self.width;
+
+        let mut source = String::from("use math::fields::f256::{BaseElement, U256};\n\n");
+        for (name, ty, value) in [
+            ("RATE", "usize", t - 1),
+            ("ALPHA", "u32", self.alpha as usize),
+            ("T", "usize", t),
+            ("R_F", "usize", self.num_full_rounds),
+            ("R_P", "usize", self.num_partial_rounds),
+        ] {
+            writeln!(source, "pub const {}: {} = {};\n", name, ty, value).unwrap();
+        }
+
+        write_rust_matrix(&mut source, "const", "MDS", &self.mds);
+        write_rust_matrix(&mut source, "const", "MP", &sparse_matrix);
+        // the hasher borrows the larger arrays, which are not copied at each use
+        write_rust_matrix(&mut source, "static", "V_COLLECTION", &v_collection);
+        write_rust_matrix(&mut source, "static", "W_HAT_COLLECTION", &w_hat_collection);
+        writeln!(
+            source,
+            "pub const M_0_0: BaseElement = {};\n",
+            rust_element(&self.mds[0][0])
+        )
+        .unwrap();
+        write_rust_matrix(
+            &mut source,
+            "static",
+            "ROUND_CONSTANTS_OPTI",
+            &self.optimized_round_constants(&field),
+        );
+
+        Ok(source)
+    }
+
+    /// Circom source of the `circuits/poseidon/param.circom` file, with the
+    /// parameters of every state width.
+    ///
+    /// The functions of the numbers of rounds, of the matrices and of the
+    /// round constants take the state width as argument, and `T()` returns
+    /// the width of the first parameters, which is the one of the `Poseidon`
+    /// template.
+    ///
+    /// The S-box of the Circom circuit is `x^5`, an
+    /// [InvalidOptions](WinterCircomError::InvalidOptions) error is returned
+    /// for other values of `alpha`, when no parameters are given, or when
+    /// several parameters have the same width or a different modulus.
+    pub fn to_circom(parameters: &[Self]) -> Result<String, WinterCircomError> {
+        ensure(
+            !parameters.is_empty(),
+            "at least one set of Poseidon parameters is needed",
+        )?;
+        for (i, params) in parameters.iter().enumerate() {
+            ensure(params.alpha == 5, "the S-box of the Circom circuit is x^5")?;
+            ensure(
+                params.modulus == parameters[0].modulus,
+                "the Poseidon parameters must have the same modulus",
+            )?;
+            ensure(
+                parameters[..i]
+                    .iter()
+                    .all(|other| other.width != params.width),
+                "the Poseidon parameters must have distinct widths",
+            )?;
+        }
+
+        let widths: Vec<usize> = parameters.iter().map(|params| params.width).collect();
+        let mut source = format!(
+            "pragma circom 2.0.0;\n\nfunction T() {{\n    return {};\n}}\n\n",
+            widths[0]
+        );
+
+        let mut num_full_rounds = vec![];
+        let mut num_partial_rounds = vec![];
+        let mut mds = vec![];
+        let mut sparse_matrices = vec![];
+        let mut sparse_rounds = vec![];
+        let mut round_constants = vec![];
+        for params in parameters {
+            let (m, p, s, c) = params.circom_matrices();
+            num_full_rounds.push(params.num_full_rounds.to_string());
+            num_partial_rounds.push(params.num_partial_rounds.to_string());
+            mds.push(circom_matrix(&m, true));
+            sparse_matrices.push(circom_matrix(&p, true));
+            sparse_rounds.push(circom_matrix(&s, false));
+            round_constants.push(circom_matrix(&c, false));
+        }
+
+        write_circom_function(&mut source, "R_F", &widths, &num_full_rounds, "0");
+        write_circom_function(&mut source, "R_P", &widths, &num_partial_rounds, "0");
+        write_circom_function(&mut source, "POSEIDON_M", &widths, &mds, "[[0]]");
+        write_circom_function(
+            &mut source,
+            "POSEIDON_P",
+            &widths,
+            &sparse_matrices,
+            "[[0]]",
+        );
+        write_circom_function(&mut source, "POSEIDON_S", &widths, &sparse_rounds, "[0]");
+        write_circom_function(&mut source, "POSEIDON_C", &widths, &round_constants, "[0]");
+
+        Ok(source)
+    }
+
+    // HELPERS
+    // -----------------------------------------------------------------------
+
+    fn field(&self) -> Field {
+        Field {
+            modulus: self.modulus.clone(),
+        }
+    }
+
+    /// Sparse matrices of the partial rounds: the matrix applied before the
+    /// first partial round, and the `v` and `w_hat` vectors of the sparse
+    /// matrices of every partial round, from the last one to the first one.
+    /// MDS matrix, transposed sparse matrix of the first partial round,
+    /// sparse matrices of the other partial rounds and round constants, as
+    /// expected by the Circom circuit.
+    fn circom_matrices(&self) -> (Matrix, Matrix, Matrix, Matrix) {
+        let field = self.field();
+        let (sparse_matrix, v_collection, w_hat_collection) = self.sparse_matrices(&field);
+        let half_full_rounds = self.num_full_rounds / 2;
+
+        // M[0][0], w_hat and v of every partial round
+        let sparse_rounds: Matrix = w_hat_collection
+            .into_iter()
+            .zip(v_collection)
+            .map(|(w_hat, v)| {
+                let mut round = vec![self.mds[0][0].clone()];
+                round.extend(w_hat);
+                round.extend(v);
+                round
+            })
+            .collect();
+
+        // only the first constant of the partial rounds following the first one
+        // is not zero
+        let round_constants: Matrix = self
+            .optimized_round_constants(&field)
+            .into_iter()
+            .enumerate()
+            .map(|(round, mut constants)| {
+                if round > half_full_rounds && round < half_full_rounds + self.num_partial_rounds {
+                    constants.truncate(1);
+                }
+                constants
+            })
+            .collect();
+
+        (
+            self.mds.clone(),
+            transpose(&sparse_matrix),
+            sparse_rounds,
+            round_constants,
+        )
+    }
+
+    fn sparse_matrices(&self, field: &Field) -> (Matrix, Matrix, Matrix) {
+        let t = self.width;
+        let mds_transpose = transpose(&self.mds);
+
+        let mut sparse_matrix = identity(t);
+        let mut v_collection = Vec::with_capacity(self.num_partial_rounds);
+        let mut w_hat_collection = Vec::with_capacity(self.num_partial_rounds);
+        let mut product = mds_transpose.clone();
+        for _ in 0..self.num_partial_rounds {
+            let m_hat: Matrix = product[1..].iter().map(|row| row[1..].to_vec()).collect();
+            let w: Vec<BigUint> = product[1..].iter().map(|row| row[0].clone()).collect();
+            v_collection.push(product[0][1..].to_vec());
+            w_hat_collection.push(field.mul_vector(&field.inverse(&m_hat), &w));
+
+            sparse_matrix = identity(t);
+            for (i, row) in m_hat.into_iter().enumerate() {
+                sparse_matrix[i + 1].splice(1.., row);
+            }
+            product = field.mul(&mds_transpose, &sparse_matrix);
+        }
+
+        (sparse_matrix, v_collection, w_hat_collection)
+    }
+
+    /// Round constants of the optimized partial rounds: the constants of each
+    /// partial round but the first one are moved to the previous round, except
+    /// the one added to the first element of the state.
+    fn optimized_round_constants(&self, field: &Field) -> Matrix {
+        let t = self.width;
+        let half_full_rounds = self.num_full_rounds / 2;
+        let mds_transpose_inverse = field.inverse(&transpose(&self.mds));
+
+        let mut round_constants = self.round_constants.clone();
+        for i in (half_full_rounds..half_full_rounds + self.num_partial_rounds - 1).rev() {
+            let next =
+                field.mul_vector(&transpose(&mds_transpose_inverse), &round_constants[i + 1]);
+            for j in 1..t {
+                round_constants[i][j] = field.add(&round_constants[i][j], &next[j]);
+            }
+            round_constants[i + 1] = vec![BigUint::zero(); t];
+            round_constants[i + 1][0] = next[0].clone();
+        }
+        round_constants
+    }
+}
+
+// GRAIN LFSR
+// ===========================================================================
+
+/// Grain LFSR in self-shrinking mode, seeded with the parameters of the
+/// permutation.
+pub(crate) struct Grain {
+    state: Vec<bool>,
+}
+
+impl Grain {
+    pub(crate) fn new(
+        num_bits: usize,
+        width: usize,
+        num_full_rounds: usize,
+        num_partial_rounds: usize,
+    ) -> Self {
+        let mut state = Vec::with_capacity(80);
+        // prime field, x^alpha S-box
+        for (value, size) in [
+            (1, 2),
+            (0, 4),
+            (num_bits, 12),
+            (width, 12),
+            (num_full_rounds, 10),
+            (num_partial_rounds, 10),
+        ] {
+            state.extend((0..size).rev().map(|i| (value >> i) & 1 == 1));
+        }
+        state.extend([true; 30]);
+
+        let mut grain = Self { state };
+        for _ in 0..160 {
+            grain.clock();
+        }
+        grain
+    }
+
+    fn clock(&mut self) -> bool {
+        let s = &self.state;
+        let bit = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+        self.state.remove(0);
+        self.state.push(bit);
+        bit
+    }
+
+    /// Next output bit: a pair of bits is output as its second bit if its
+    /// first bit is set, and discarded otherwise.
+    fn next_bit(&mut self) -> bool {
+        loop {
+            let keep = self.clock();
+            let bit = self.clock();
+            if keep {
+                return bit;
+            }
+        }
+    }
+
+    /// Integer made of the next `num_bits` output bits, most significant first.
+    fn next_integer(&mut self, num_bits: usize) -> BigUint {
+        let mut value = BigUint::zero();
+        for _ in 0..num_bits {
+            value <<= 1;
+            if self.next_bit() {
+                value += 1u32;
+            }
+        }
+        value
+    }
+
+    /// Next integer of the size of the modulus which is smaller than it.
+    pub(crate) fn field_element(&mut self, modulus: &BigUint) -> BigUint {
+        loop {
+            let value = self.next_integer(modulus.bits() as usize);
+            if &value < modulus {
+                return value;
+            }
+        }
+    }
+}
+
+/// Cauchy matrix `1 / (x_i + y_j)` of distinct `x_i` and `y_j` sampled with
+/// the Grain LFSR, or `None` if `x_i + y_j` is zero for some `i` and `j`.
+fn cauchy_matrix(field: &Field, grain: &mut Grain, width: usize) -> Option<Matrix> {
+    let num_bits = field.num_bits();
+    let values = loop {
+        let values: Vec<BigUint> = (0..2 * width)
+            .map(|_| grain.next_integer(num_bits) % &field.modulus)
+            .collect();
+        let mut sorted = values.clone();
+        sorted.sort();
+        sorted.dedup();
+        if sorted.len() == values.len() {
+            break values;
+        }
+    };
+    let (xs, ys) = values.split_at(width);
+
+    xs.iter()
+        .map(|x| {
+            ys.iter()
+                .map(|y| {
+                    let sum = field.add(x, y);
+                    (!sum.is_zero()).then(|| field.inv(&sum))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// ROUND NUMBERS
+// ===========================================================================
+
+/// Whether the numbers of rounds resist the statistical, interpolation and
+/// Gröbner basis attacks, as in the reference script.
+fn is_secure_against_attacks(
+    log2_modulus: f64,
+    width: usize,
+    alpha: u32,
+    num_full_rounds: usize,
+    num_partial_rounds: usize,
+    security_level: u32,
+) -> bool {
+    let t = width as f64;
+    let alpha = alpha as f64;
+    let r_p = num_partial_rounds as f64;
+    let m = security_level as f64;
+    let n = log2_modulus.ceil();
+    let log_alpha = |x: f64| x.ln() / alpha.ln();
+
+    let statistical = if m <= (log2_modulus - (alpha - 1.0) / 2.0).floor() * (t + 1.0) {
+        6.0
+    } else {
+        10.0
+    };
+    let interpolation = 1.0 + (log_alpha(2.0) * m.min(n)).ceil() + log_alpha(t).ceil() - r_p;
+    let groebner_1 = log_alpha(2.0) * m.min(log2_modulus) - r_p;
+    let groebner_2 = t - 1.0 + log_alpha(2.0) * (m / (t + 1.0)).min(log2_modulus / 2.0) - r_p;
+    let groebner_3 = (t - 2.0 + m / (2.0 * (alpha.ln() / 2f64.ln())) - r_p) / (t - 1.0);
+
+    let min_full_rounds = [
+        statistical,
+        interpolation,
+        groebner_1,
+        groebner_2,
+        groebner_3,
+    ]
+    .iter()
+    .map(|r_f| r_f.ceil())
+    .fold(f64::NEG_INFINITY, f64::max);
+    num_full_rounds as f64 >= min_full_rounds
+}
+
+// PRIME FIELD
+// ===========================================================================
+
+pub(crate) struct Field {
+    pub(crate) modulus: BigUint,
+}
+
+impl Field {
+    pub(crate) fn new(modulus: &str, width: usize, alpha: u32) -> Result<Self, WinterCircomError> {
+        let modulus = match modulus.strip_prefix("0x") {
+            Some(hex) => BigUint::parse_bytes(hex.as_bytes(), 16),
+            None => BigUint::parse_bytes(modulus.as_bytes(), 10),
+        };
+        let modulus = match modulus {
+            Some(modulus) => modulus,
+            None => {
+                return Err(WinterCircomError::InvalidOptions(
+                    "modulus must be an integer".to_owned(),
+                ))
+            }
+        };
+        ensure(
+            modulus.bits() > 2 && modulus.bits() < 4096 && is_probably_prime(&modulus),
+            "modulus must be an odd prime number, smaller than 2^4095",
+        )?;
+        ensure(
+            (2..4096).contains(&width),
+            "width must be between 2 and 4095",
+        )?;
+
+        let modulus_minus_one = &modulus - 1u32;
+        ensure(
+            alpha >= 3 && gcd(BigUint::from(alpha), modulus_minus_one.clone()).is_one(),
+            "alpha must be at least 3 and coprime with modulus - 1",
+        )?;
+
+        Ok(Self { modulus })
+    }
+
+    pub(crate) fn num_bits(&self) -> usize {
+        self.modulus.bits() as usize
+    }
+
+    // ELEMENT OPERATIONS
+    // -----------------------------------------------------------------------
+
+    fn add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + b) % &self.modulus
+    }
+
+    fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + &self.modulus - b) % &self.modulus
+    }
+
+    fn mul_elements(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        a * b % &self.modulus
+    }
+
+    fn neg(&self, a: &BigUint) -> BigUint {
+        self.sub(&BigUint::zero(), a)
+    }
+
+    fn inv(&self, a: &BigUint) -> BigUint {
+        a.modpow(&(&self.modulus - 2u32), &self.modulus)
+    }
+
+    // MATRIX OPERATIONS
+    // -----------------------------------------------------------------------
+
+    fn mul(&self, a: &Matrix, b: &Matrix) -> Matrix {
+        a.iter()
+            .map(|row| {
+                (0..b[0].len())
+                    .map(|j| {
+                        row.iter().zip(b).fold(BigUint::zero(), |sum, (x, b_row)| {
+                            self.add(&sum, &self.mul_elements(x, &b_row[j]))
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn mul_vector(&self, a: &Matrix, v: &[BigUint]) -> Vec<BigUint> {
+        a.iter()
+            .map(|row| {
+                row.iter().zip(v).fold(BigUint::zero(), |sum, (x, y)| {
+                    self.add(&sum, &self.mul_elements(x, y))
+                })
+            })
+            .collect()
+    }
+
+    fn pow(&self, a: &Matrix, exponent: usize) -> Matrix {
+        (0..exponent).fold(identity(a.len()), |product, _| self.mul(&product, a))
+    }
+
+    fn inverse(&self, a: &Matrix) -> Matrix {
+        let n = a.len();
+        let augmented: Matrix = a
+            .iter()
+            .zip(identity(n))
+            .map(|(row, identity_row)| row.iter().cloned().chain(identity_row).collect())
+            .collect();
+        let reduced = self.row_echelon_form(augmented);
+        reduced.into_iter().map(|row| row[n..].to_vec()).collect()
+    }
+
+    /// Reduced row echelon form of the matrix, without its zero rows.
+    fn row_echelon_form(&self, mut rows: Matrix) -> Matrix {
+        let num_columns = rows.first().map_or(0, |row| row.len());
+        let mut rank = 0;
+        for column in 0..num_columns {
+            let pivot = match (rank..rows.len()).find(|&i| !rows[i][column].is_zero()) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            rows.swap(rank, pivot);
+
+            let inverse = self.inv(&rows[rank][column]);
+            rows[rank] = rows[rank]
+                .iter()
+                .map(|x| self.mul_elements(x, &inverse))
+                .collect();
+            for i in 0..rows.len() {
+                if i != rank && !rows[i][column].is_zero() {
+                    let factor = rows[i][column].clone();
+                    rows[i] = rows[i]
+                        .iter()
+                        .zip(&rows[rank])
+                        .map(|(x, y)| self.sub(x, &self.mul_elements(&factor, y)))
+                        .collect();
+                }
+            }
+            rank += 1;
+        }
+        rows.truncate(rank);
+        rows
+    }
+
+    /// Basis of the right kernel of the matrix, of `num_columns` columns.
+    fn kernel(&self, rows: Matrix, num_columns: usize) -> Matrix {
+        let reduced = self.row_echelon_form(rows);
+        let pivots: Vec<usize> = reduced
+            .iter()
+            .map(|row| row.iter().position(|x| !x.is_zero()).unwrap())
+            .collect();
+
+        (0..num_columns)
+            .filter(|column| !pivots.contains(column))
+            .map(|free| {
+                let mut vector = vec![BigUint::zero(); num_columns];
+                vector[free] = BigUint::one();
+                for (row, &pivot) in reduced.iter().zip(&pivots) {
+                    vector[pivot] = self.neg(&row[free]);
+                }
+                vector
+            })
+            .collect()
+    }
+
+    /// Canonical basis of the subspace spanned by the vectors.
+    fn span(&self, vectors: Matrix) -> Matrix {
+        self.row_echelon_form(vectors)
+    }
+
+    /// Canonical basis of the intersection of two subspaces.
+    fn intersection(&self, a: &Matrix, b: &Matrix) -> Matrix {
+        if a.is_empty() || b.is_empty() {
+            return vec![];
+        }
+
+        // solve sum(x_i * a_i) = sum(y_j * b_j)
+        let dimension = a[0].len();
+        let rows: Matrix = (0..dimension)
+            .map(|k| {
+                a.iter()
+                    .map(|v| v[k].clone())
+                    .chain(b.iter().map(|v| self.neg(&v[k])))
+                    .collect()
+            })
+            .collect();
+        let solutions = self.kernel(rows, a.len() + b.len());
+
+        self.span(
+            solutions
+                .iter()
+                .map(|solution| self.mul_vector(&transpose(a), &solution[..a.len()]))
+                .collect(),
+        )
+    }
+
+    // SECURITY CHECKS
+    // -----------------------------------------------------------------------
+
+    /// Whether the MDS matrix admits no invariant subspace trail, following
+    /// the three algorithms of the reference implementation for a single
+    /// S-box in the partial rounds.
+    fn is_secure(&self, mds: &Matrix) -> bool {
+        let t = mds.len();
+        self.is_secure_algorithm_1(mds)
+            && self.is_secure_algorithm_2(mds)
+            && (2..=4 * t).all(|r| self.is_secure_algorithm_2(&self.pow(mds, r)))
+    }
+
+    fn is_secure_algorithm_1(&self, mds: &Matrix) -> bool {
+        let t = mds.len();
+        let powers: Vec<Matrix> = (1..=t).map(|i| self.pow(mds, i)).collect();
+
+        for i in 1..t {
+            let power = &powers[i - 1];
+            let scaled_identity: Matrix = identity(t)
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|x| self.mul_elements(x, &power[0][0]))
+                        .collect()
+                })
+                .collect();
+            if *power == scaled_identity {
+                return false;
+            }
+
+            // subspace of the inputs which are left unchanged by the S-box for
+            // i - 1 rounds
+            let subspace = if i == 1 {
+                identity(t)[1..].to_vec()
+            } else {
+                let rows = powers[..i - 1]
+                    .iter()
+                    .map(|power| power[0][1..].to_vec())
+                    .collect();
+                let kernel = self.kernel(rows, t - 1);
+                self.span(
+                    kernel
+                        .into_iter()
+                        .map(|v| std::iter::once(BigUint::zero()).chain(v).collect())
+                        .collect(),
+                )
+            };
+
+            // eigenvectors of the power in the subspace
+            let mut eigenvectors = Vec::new();
+            for eigenvalue in self.roots(&self.characteristic_polynomial(power)) {
+                let shifted: Matrix = power
+                    .iter()
+                    .enumerate()
+                    .map(|(k, row)| {
+                        let mut row = row.clone();
+                        row[k] = self.sub(&row[k], &eigenvalue);
+                        row
+                    })
+                    .collect();
+                let eigenspace = self.span(self.kernel(shifted, t));
+                eigenvectors.extend(self.intersection(&subspace, &eigenspace));
+            }
+            let invariant_subspace = self.span(eigenvectors);
+            if !invariant_subspace.is_empty() && invariant_subspace.len() != t {
+                return false;
+            }
+
+            for power in &powers[..i] {
+                let image = self.span(subspace.iter().map(|v| self.mul_vector(power, v)).collect());
+                if image == subspace {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Whether the smallest subspace containing the first unit vector and
+    /// invariant under the matrix is the whole space.
+    fn is_secure_algorithm_2(&self, matrix: &Matrix) -> bool {
+        let t = matrix.len();
+        let mut vector = identity(t).swap_remove(0);
+        let mut subspace = self.span(vec![vector.clone()]);
+        loop {
+            let dimension = subspace.len();
+            vector = self.mul_vector(matrix, &vector);
+            subspace.push(vector.clone());
+            subspace = self.span(subspace);
+            if subspace.len() == t {
+                return true;
+            }
+            if subspace.len() <= dimension {
+                return false;
+            }
+        }
+    }
+
+    // POLYNOMIALS
+    // -----------------------------------------------------------------------
+    // Polynomials are vectors of coefficients, lowest degree first, without
+    // leading zero coefficients.
+
+    /// Characteristic polynomial of the matrix, with the Faddeev-LeVerrier
+    /// algorithm.
+    fn characteristic_polynomial(&self, matrix: &Matrix) -> Vec<BigUint> {
+        let n = matrix.len();
+        let mut coefficients = vec![BigUint::zero(); n + 1];
+        coefficients[n] = BigUint::one();
+
+        let mut m = vec![vec![BigUint::zero(); n]; n];
+        for k in 1..=n {
+            m = self.mul(matrix, &m);
+            for (i, row) in m.iter_mut().enumerate() {
+                row[i] = self.add(&row[i], &coefficients[n - k + 1]);
+            }
+            let product = self.mul(matrix, &m);
+            let trace = (0..n).fold(BigUint::zero(), |sum, i| self.add(&sum, &product[i][i]));
+            coefficients[n - k] =
+                self.neg(&self.mul_elements(&trace, &self.inv(&BigUint::from(k))));
+        }
+        trim(coefficients)
+    }
+
+    /// Distinct roots of the polynomial in the field.
+    fn roots(&self, polynomial: &[BigUint]) -> Vec<BigUint> {
+        // the product of the linear factors is gcd(f, x^p - x)
+        let x = vec![BigUint::zero(), BigUint::one()];
+        let x_p = self.pow_mod(&x, &self.modulus, polynomial);
+        let linear_factors =
+            self.gcd_polynomials(polynomial.to_vec(), self.sub_polynomials(&x_p, &x));
+        self.split(linear_factors)
+    }
+
+    /// Roots of a product of distinct linear factors, with the
+    /// Cantor-Zassenhaus algorithm.
+    fn split(&self, polynomial: Vec<BigUint>) -> Vec<BigUint> {
+        match polynomial.len() {
+            0 | 1 => vec![],
+            2 => vec![self.neg(&self.mul_elements(&polynomial[0], &self.inv(&polynomial[1])))],
+            _ => {
+                let exponent = (&self.modulus - 1u32) >> 1;
+                let mut shift = BigUint::zero();
+                loop {
+                    // gcd(f, (x + shift)^((p - 1) / 2) - 1) is a proper factor of f
+                    // for half of the shifts
+                    let base = vec![shift.clone(), BigUint::one()];
+                    let power = self.pow_mod(&base, &exponent, &polynomial);
+                    let factor = self.gcd_polynomials(
+                        polynomial.clone(),
+                        self.sub_polynomials(&power, &[BigUint::one()]),
+                    );
+                    if factor.len() > 1 && factor.len() < polynomial.len() {
+                        let (quotient, _) = self.div_rem(&polynomial, &factor);
+                        let mut roots = self.split(factor);
+                        roots.extend(self.split(quotient));
+                        return roots;
+                    }
+                    shift += 1u32;
+                }
+            }
+        }
+    }
+
+    fn sub_polynomials(&self, a: &[BigUint], b: &[BigUint]) -> Vec<BigUint> {
+        let zero = BigUint::zero();
+        let difference = (0..a.len().max(b.len()))
+            .map(|i| self.sub(a.get(i).unwrap_or(&zero), b.get(i).unwrap_or(&zero)))
+            .collect();
+        trim(difference)
+    }
+
+    fn mul_polynomials(&self, a: &[BigUint], b: &[BigUint]) -> Vec<BigUint> {
+        if a.is_empty() || b.is_empty() {
+            return vec![];
+        }
+        let mut product = vec![BigUint::zero(); a.len() + b.len() - 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                product[i + j] = self.add(&product[i + j], &self.mul_elements(x, y));
+            }
+        }
+        trim(product)
+    }
+
+    fn div_rem(&self, a: &[BigUint], b: &[BigUint]) -> (Vec<BigUint>, Vec<BigUint>) {
+        let mut remainder = a.to_vec();
+        if remainder.len() < b.len() {
+            return (vec![], remainder);
+        }
+
+        let leading_inverse = self.inv(b.last().unwrap());
+        let mut quotient = vec![BigUint::zero(); a.len() - b.len() + 1];
+        while remainder.len() >= b.len() {
+            let shift = remainder.len() - b.len();
+            let factor = self.mul_elements(remainder.last().unwrap(), &leading_inverse);
+            for (i, y) in b.iter().enumerate() {
+                remainder[shift + i] =
+                    self.sub(&remainder[shift + i], &self.mul_elements(&factor, y));
+            }
+            quotient[shift] = factor;
+            remainder = trim(remainder);
+        }
+        (trim(quotient), remainder)
+    }
+
+    fn pow_mod(&self, base: &[BigUint], exponent: &BigUint, modulus: &[BigUint]) -> Vec<BigUint> {
+        let base = self.div_rem(base, modulus).1;
+        let mut result = vec![BigUint::one()];
+        for i in (0..exponent.bits()).rev() {
+            result = self
+                .div_rem(&self.mul_polynomials(&result, &result), modulus)
+                .1;
+            if exponent.bit(i) {
+                result = self
+                    .div_rem(&self.mul_polynomials(&result, &base), modulus)
+                    .1;
+            }
+        }
+        result
+    }
+
+    fn gcd_polynomials(&self, mut a: Vec<BigUint>, mut b: Vec<BigUint>) -> Vec<BigUint> {
+        while !b.is_empty() {
+            let remainder = self.div_rem(&a, &b).1;
+            a = b;
+            b = remainder;
+        }
+        a
+    }
+}
+
+// HELPER FUNCTIONS
+// ===========================================================================
+
+fn identity(n: usize) -> Matrix {
+    (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    if i == j {
+                        BigUint::one()
+                    } else {
+                        BigUint::zero()
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn transpose(a: &Matrix) -> Matrix {
+    (0..a[0].len())
+        .map(|j| a.iter().map(|row| row[j].clone()).collect())
+        .collect()
+}
+
+fn trim(mut polynomial: Vec<BigUint>) -> Vec<BigUint> {
+    while polynomial.last().is_some_and(|x| x.is_zero()) {
+        polynomial.pop();
+    }
+    polynomial
+}
+
+fn gcd(mut a: BigUint, mut b: BigUint) -> BigUint {
+    while !b.is_zero() {
+        let remainder = &a % &b;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+/// Miller-Rabin primality test with the first prime numbers as bases.
+fn is_probably_prime(n: &BigUint) -> bool {
+    let one = BigUint::one();
+    let n_minus_one = n - 1u32;
+    let trailing_zeros = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &n_minus_one >> trailing_zeros;
+
+    [2u32, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37]
+        .iter()
+        .all(|&base| {
+            let base = BigUint::from(base);
+            if &base >= n {
+                return true;
+            }
+            let mut x = base.modpow(&d, n);
+            if x == one || x == n_minus_one {
+                return true;
+            }
+            for _ in 1..trailing_zeros {
+                x = &x * &x % n;
+                if x == n_minus_one {
+                    return true;
+                }
+            }
+            false
+        })
+}
+
+pub(crate) fn rust_element(value: &BigUint) -> String {
+    let mut limbs = value.to_u64_digits();
+    limbs.resize(4, 0);
+    format!(
+        "BaseElement(U256([{}, {}, {}, {}]))",
+        limbs[0], limbs[1], limbs[2], limbs[3]
+    )
+}
+
+pub(crate) fn write_rust_matrix(source: &mut String, item: &str, name: &str, matrix: &Matrix) {
+    writeln!(
+        source,
+        "pub {} {}: [[BaseElement; {}]; {}] = [",
+        item,
+        name,
+        matrix[0].len(),
+        matrix.len()
+    )
+    .unwrap();
+    for row in matrix {
+        let row: Vec<String> = row.iter().map(rust_element).collect();
+        writeln!(source, "    [{}],", row.join(", ")).unwrap();
+    }
+    source.push_str("];\n\n");
+}
+
+/// Circom array of the matrix, or of the concatenation of its rows if `nested`
+/// is false, indented as the return value of a branch of a Circom function.
+pub(crate) fn circom_matrix(matrix: &[Vec<BigUint>], nested: bool) -> String {
+    let rows: Vec<String> = matrix
+        .iter()
+        .map(|row| {
+            let row: Vec<String> = row.iter().map(|x| x.to_string()).collect();
+            if nested {
+                format!("[{}]", row.join(", "))
+            } else {
+                row.join(", ")
+            }
+        })
+        .collect();
+    format!("[\n            {}\n        ]", rows.join(",\n            "))
+}
+
+/// Write a Circom function of the state width `t`, returning the value of the
+/// parameters of that width, or `default` for other widths.
+fn write_circom_function(
+    source: &mut String,
+    name: &str,
+    widths: &[usize],
+    values: &[String],
+    default: &str,
+) {
+    writeln!(source, "function {}(t) {{", name).unwrap();
+    for (i, (width, value)) in widths.iter().zip(values).enumerate() {
+        let keyword = if i == 0 { "    if" } else { " else if" };
+        write!(
+            source,
+            "{} (t == {}) {{\n        return {};\n    }}",
+            keyword, width, value
+        )
+        .unwrap();
+    }
+    writeln!(
+        source,
+        " else {{\n        return {};\n    }}\n}}\n",
+        default
+    )
+    .unwrap();
+}
@@ -0,0 +1,189 @@
+use std::fmt::Write;
+
+use num_bigint::BigUint;
+use winterfell::math::{fields::f256::BaseElement, StarkField};
+
+use crate::{
+    ensure,
+    poseidon::{circom_matrix, rust_element, write_rust_matrix, Field, Grain, Matrix},
+    utils::WinterCircomError,
+};
+
+/// Number of field elements in the state of the Poseidon2 permutation.
+const WIDTH: usize = 3;
+
+// POSEIDON2 PARAMETERS
+// ===========================================================================
+
+/// Parameters of the Poseidon2 permutation of width 3 over a prime field.
+///
+/// The round constants are sampled with the Grain LFSR, following the
+/// reference implementation of Poseidon2
+/// (<https://github.com/HorizenLabs/poseidon2>): the full rounds take `3`
+/// constants each and the partial rounds a single one. The linear layers of
+/// width 3 do not depend on the field: the external matrix is
+/// `circ(2, 1, 1)` and the internal matrix is `1 + diag(1, 1, 2)`, where `1`
+/// is the matrix of ones.
+///
+/// The parameters are exported as the `param.rs` file of the Poseidon2
+/// hasher of winter-crypto with [to_rust](Poseidon2Parameters::to_rust), and
+/// as the `circuits/poseidon2/param.circom` file with
+/// [to_circom](Poseidon2Parameters::to_circom).
+///
+/// The `poseidon2_parameters` example of this crate writes these files.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Poseidon2Parameters {
+    modulus: BigUint,
+    alpha: u32,
+    num_full_rounds: usize,
+    num_partial_rounds: usize,
+    external_round_constants: Matrix,
+    internal_round_constants: Vec<BigUint>,
+}
+
+impl Poseidon2Parameters {
+    /// Generate the parameters of the Poseidon2 permutation.
+    ///
+    /// - `modulus`: prime modulus of the field, in decimal or in hexadecimal
+    ///   with a `0x` prefix.
+    /// - `alpha`: exponent of the S-box, which must be coprime with
+    ///   `modulus - 1`.
+    /// - `num_full_rounds`, `num_partial_rounds`: number of rounds, which are
+    ///   those of Poseidon for the same width and security level.
+    ///
+    /// An [InvalidOptions](WinterCircomError::InvalidOptions) error is
+    /// returned for unsupported parameters.
+    pub fn generate(
+        modulus: &str,
+        alpha: u32,
+        num_full_rounds: usize,
+        num_partial_rounds: usize,
+    ) -> Result<Self, WinterCircomError> {
+        let field = Field::new(modulus, WIDTH, alpha)?;
+        ensure(
+            num_full_rounds > 0 && num_full_rounds % 2 == 0 && num_full_rounds < 1024,
+            "number of full rounds must be an even number, between 2 and 1022",
+        )?;
+        ensure(
+            (1..1024).contains(&num_partial_rounds),
+            "number of partial rounds must be between 1 and 1023",
+        )?;
+
+        let mut grain = Grain::new(field.num_bits(), WIDTH, num_full_rounds, num_partial_rounds);
+
+        // the constants are sampled in the order of the rounds
+        let half_full_rounds = num_full_rounds / 2;
+        let mut external_round_constants =
+            full_round_constants(&field, &mut grain, half_full_rounds);
+        let internal_round_constants = (0..num_partial_rounds)
+            .map(|_| grain.field_element(&field.modulus))
+            .collect();
+        external_round_constants.extend(full_round_constants(&field, &mut grain, half_full_rounds));
+
+        Ok(Self {
+            modulus: field.modulus,
+            alpha,
+            num_full_rounds,
+            num_partial_rounds,
+            external_round_constants,
+            internal_round_constants,
+        })
+    }
+
+    /// Number of full rounds of the permutation.
+    pub fn num_full_rounds(&self) -> usize {
+        self.num_full_rounds
+    }
+
+    /// Number of partial rounds of the permutation.
+    pub fn num_partial_rounds(&self) -> usize {
+        self.num_partial_rounds
+    }
+
+    /// Rust source of the parameter file of the Poseidon2 hasher of
+    /// winter-crypto.
+    ///
+    /// The hasher works over the f256 field of Winterfell only, an
+    /// [InvalidOptions](WinterCircomError::InvalidOptions) error is returned
+    /// for other fields.
+    pub fn to_rust(&self) -> Result<String, WinterCircomError> {
+        ensure(
+            self.modulus == BigUint::from_bytes_le(&BaseElement::get_modulus_le_bytes()),
+            "the Poseidon2 hasher of winter-crypto works over the f256 field only",
+        )?;
+
+        let mut source = String::from("use math::fields::f256::{BaseElement, U256};\n\n");
+        for (name, ty, value) in [
+            ("RATE", "usize", WIDTH - 1),
+            ("ALPHA", "u32", self.alpha as usize),
+            ("T", "usize", WIDTH),
+            ("R_F", "usize", self.num_full_rounds),
+            ("R_P", "usize", self.num_partial_rounds),
+        ] {
+            writeln!(source, "pub const {}: {} = {};\n", name, ty, value).unwrap();
+        }
+
+        write_rust_matrix(
+            &mut source,
+            "static",
+            "EXTERNAL_ROUND_CONSTANTS",
+            &self.external_round_constants,
+        );
+        writeln!(
+            source,
+            "pub static INTERNAL_ROUND_CONSTANTS: [BaseElement; {}] = [",
+            self.num_partial_rounds
+        )
+        .unwrap();
+        for constant in &self.internal_round_constants {
+            writeln!(source, "    {},", rust_element(constant)).unwrap();
+        }
+        source.push_str("];\n");
+
+        Ok(source)
+    }
+
+    /// Circom source of the `circuits/poseidon2/param.circom` file.
+    ///
+    /// The S-box of the Circom circuit is `x^5`, an
+    /// [InvalidOptions](WinterCircomError::InvalidOptions) error is returned
+    /// for other values of `alpha`.
+    pub fn to_circom(&self) -> Result<String, WinterCircomError> {
+        ensure(self.alpha == 5, "the S-box of the Circom circuit is x^5")?;
+
+        let mut source = String::from("pragma circom 2.0.0;\n\n");
+        for (name, value) in [
+            ("POSEIDON2_R_F", self.num_full_rounds.to_string()),
+            ("POSEIDON2_R_P", self.num_partial_rounds.to_string()),
+            (
+                "POSEIDON2_C_EXTERNAL",
+                circom_matrix(&self.external_round_constants, false),
+            ),
+            (
+                "POSEIDON2_C_INTERNAL",
+                circom_matrix(std::slice::from_ref(&self.internal_round_constants), false),
+            ),
+        ] {
+            writeln!(
+                source,
+                "function {}() {{\n    return {};\n}}\n",
+                name,
+                value.replace("\n    ", "\n")
+            )
+            .unwrap();
+        }
+
+        Ok(source)
+    }
+}
+
+/// Round constants of `num_rounds` full rounds, sampled with the Grain LFSR.
+fn full_round_constants(field: &Field, grain: &mut Grain, num_rounds: usize) -> Matrix {
+    (0..num_rounds)
+        .map(|_| {
+            (0..WIDTH)
+                .map(|_| grain.field_element(&field.modulus))
+                .collect()
+        })
+        .collect()
+}
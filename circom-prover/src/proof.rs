@@ -0,0 +1,252 @@
+use std::{fs::File, io::Write, time::Duration};
+
+use serde_json::{json, Value};
+use winterfell::math::fields::f256::{BaseElement, U256};
+
+use crate::utils::{delete_file, read_json, WinterCircomError};
+
+// GROTH16 PROOF
+// ===========================================================================
+
+/// Groth16 proof of the verification of a Winterfell proof, generated by
+/// [circom_prove](crate::circom_prove).
+///
+/// Points are given in affine coordinates, over the BN254 curve.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Groth16Proof {
+    pub pi_a: [U256; 2],
+    pub pi_b: [[U256; 2]; 2],
+    pub pi_c: [U256; 2],
+}
+
+impl Groth16Proof {
+    /// Parse a Groth16 proof from the JSON format output by SnarkJS.
+    pub fn from_json(json: &Value) -> Result<Self, WinterCircomError> {
+        Ok(Self {
+            pi_a: [parse_u256(&json["pi_a"][0])?, parse_u256(&json["pi_a"][1])?],
+            pi_b: [
+                [
+                    parse_u256(&json["pi_b"][0][0])?,
+                    parse_u256(&json["pi_b"][0][1])?,
+                ],
+                [
+                    parse_u256(&json["pi_b"][1][0])?,
+                    parse_u256(&json["pi_b"][1][1])?,
+                ],
+            ],
+            pi_c: [parse_u256(&json["pi_c"][0])?, parse_u256(&json["pi_c"][1])?],
+        })
+    }
+
+    /// Convert the Groth16 proof into the JSON format expected by SnarkJS.
+    pub fn to_json(&self) -> Value {
+        // SnarkJS expects projective coordinates
+        json!({
+            "pi_a": [self.pi_a[0].to_string(), self.pi_a[1].to_string(), "1"],
+            "pi_b": [
+                [self.pi_b[0][0].to_string(), self.pi_b[0][1].to_string()],
+                [self.pi_b[1][0].to_string(), self.pi_b[1][1].to_string()],
+                ["1", "0"],
+            ],
+            "pi_c": [self.pi_c[0].to_string(), self.pi_c[1].to_string(), "1"],
+            "protocol": "groth16",
+            "curve": "bn128",
+        })
+    }
+}
+
+// PUBLIC SIGNALS
+// ===========================================================================
+
+/// Public signals of a Groth16 proof, along with the values they commit to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicSignals {
+    /// Public signals of the circuit, in the order of the `public.json` file
+    /// output by SnarkJS.
+    pub signals: Vec<BaseElement>,
+
+    /// Values behind the public signals commitment, if the circuit commits to
+    /// its public signals (see
+    /// [with_public_signals_commitment](crate::WinterCircomProofOptions::with_public_signals_commitment)).
+    pub committed_values: Option<CommittedValues>,
+}
+
+/// Values behind the public signals commitment of a Groth16 proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommittedValues {
+    pub ood_frame_constraint_evaluation: Vec<BaseElement>,
+    pub ood_trace_frame: [Vec<BaseElement>; 2],
+    pub public_inputs: Vec<BaseElement>,
+}
+
+impl PublicSignals {
+    /// Parse the public signals from the JSON format output by SnarkJS, and the
+    /// committed values from the format of the `public_values.json` file.
+    pub fn from_json(
+        public_signals: &Value,
+        public_values: Option<&Value>,
+    ) -> Result<Self, WinterCircomError> {
+        Ok(Self {
+            signals: parse_elements(public_signals, "public signals")?,
+            committed_values: public_values.map(CommittedValues::from_json).transpose()?,
+        })
+    }
+
+    /// Convert the public signals into the JSON format expected by SnarkJS.
+    pub fn to_json(&self) -> Value {
+        json!(self.signals)
+    }
+}
+
+impl CommittedValues {
+    /// Read the committed values from the JSON input of the circuit, or from
+    /// the `public_values.json` file.
+    pub fn from_json(json: &Value) -> Result<Self, WinterCircomError> {
+        Ok(Self {
+            ood_frame_constraint_evaluation: parse_elements(
+                &json["ood_frame_constraint_evaluation"],
+                "public values",
+            )?,
+            ood_trace_frame: [
+                parse_elements(&json["ood_trace_frame"][0], "public values")?,
+                parse_elements(&json["ood_trace_frame"][1], "public values")?,
+            ],
+            public_inputs: parse_elements(&json["public_inputs"], "public values")?,
+        })
+    }
+
+    /// Convert the committed values into the format of the `public_values.json`
+    /// file.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "ood_frame_constraint_evaluation": self.ood_frame_constraint_evaluation,
+            "ood_trace_frame": self.ood_trace_frame,
+            "public_inputs": self.public_inputs,
+        })
+    }
+}
+
+// VERIFICATION REPORT
+// ===========================================================================
+
+/// Outcome of the verification of a Groth16 proof by
+/// [circom_verify](crate::circom_verify).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationReport {
+    /// Whether the Groth16 proof is valid for its public signals.
+    pub groth16_valid: bool,
+
+    /// Whether the OOD trace frame and constraint evaluations are consistent,
+    /// and match their commitment if any.
+    pub ood_valid: bool,
+
+    /// Whether the public signals match the public inputs of the computation.
+    pub public_inputs_match: bool,
+
+    /// Time spent verifying the Groth16 proof.
+    pub groth16_time: Duration,
+
+    /// Time spent checking the public signals.
+    pub ood_time: Duration,
+}
+
+impl VerificationReport {
+    /// Returns whether the original Winterfell proof is attested valid.
+    pub fn is_valid(&self) -> bool {
+        self.groth16_valid && self.ood_valid && self.public_inputs_match
+    }
+}
+
+// PERSISTENCE
+// ===========================================================================
+
+/// Write a Groth16 proof and its public signals to the `proof.json`,
+/// `public.json` and (if the circuit commits to its public signals)
+/// `public_values.json` files of the `target/circom/<circuit_name>/`
+/// directory.
+///
+/// These files are read back by [load_proof] and
+/// [solidity_calldata](crate::solidity_calldata).
+pub fn save_proof(
+    circuit_name: &str,
+    proof: &Groth16Proof,
+    public_signals: &PublicSignals,
+) -> Result<(), WinterCircomError> {
+    let directory = format!("target/circom/{}", circuit_name);
+
+    write_json(format!("{}/proof.json", directory), &proof.to_json())?;
+    write_json(format!("{}/public.json", directory), &public_signals.to_json())?;
+
+    delete_file(format!("{}/public_values.json", directory));
+    if let Some(committed_values) = &public_signals.committed_values {
+        write_json(
+            format!("{}/public_values.json", directory),
+            &committed_values.to_json(),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Read a Groth16 proof and its public signals from the files written by
+/// [save_proof].
+pub fn load_proof(circuit_name: &str) -> Result<(Groth16Proof, PublicSignals), WinterCircomError> {
+    let directory = format!("target/circom/{}", circuit_name);
+    let comment = Some("generated by save_proof");
+
+    let proof = read_json(format!("{}/proof.json", directory), comment)?;
+    let public_signals = read_json(format!("{}/public.json", directory), comment)?;
+    let public_values = read_json(format!("{}/public_values.json", directory), comment).ok();
+
+    Ok((
+        Groth16Proof::from_json(&proof)?,
+        PublicSignals::from_json(&public_signals, public_values.as_ref())?,
+    ))
+}
+
+// HELPER FUNCTIONS
+// ===========================================================================
+
+/// Write a JSON value to a file, returning an error on failure.
+pub(crate) fn write_json(path: String, json: &Value) -> Result<(), WinterCircomError> {
+    let mut file = File::create(&path).map_err(|e| WinterCircomError::IoError {
+        io_error: e,
+        comment: Some(format!("creating {}", path)),
+    })?;
+    file.write_all(json.to_string().as_bytes())
+        .map_err(|e| WinterCircomError::IoError {
+            io_error: e,
+            comment: Some(format!("writing {}", path)),
+        })
+}
+
+fn parse_u256(value: &Value) -> Result<U256, WinterCircomError> {
+    value
+        .as_str()
+        .and_then(|value| U256::from_dec_str(value).ok())
+        .ok_or_else(|| WinterCircomError::ParsingError {
+            file: String::from("proof"),
+            comment: Some(format!("invalid integer {}", value)),
+        })
+}
+
+pub(crate) fn parse_elements(value: &Value, file: &str) -> Result<Vec<BaseElement>, WinterCircomError> {
+    value
+        .as_array()
+        .ok_or_else(|| WinterCircomError::ParsingError {
+            file: file.to_owned(),
+            comment: Some(String::from("expected an array")),
+        })?
+        .iter()
+        .map(|element| {
+            element
+                .as_str()
+                .and_then(|element| U256::from_dec_str(element).ok())
+                .map(BaseElement::new)
+                .ok_or_else(|| WinterCircomError::ParsingError {
+                    file: file.to_owned(),
+                    comment: Some(format!("invalid element {}", element)),
+                })
+        })
+        .collect()
+}
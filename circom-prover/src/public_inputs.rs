@@ -0,0 +1,66 @@
+use winterfell::math::fields::f256::BaseElement;
+
+use crate::WinterCircomProofOptions;
+
+/// Types that can be flattened into a list of field elements, to be used as
+/// public inputs.
+///
+/// This trait is used by the code generated by the
+/// [WinterPublicInputs](crate::WinterPublicInputs) derive macro, and should not
+/// be implemented manually.
+pub trait PublicInputsElements {
+    /// Number of field elements this type is flattened into.
+    const NUM_ELEMENTS: usize;
+
+    /// Append the field elements of this value to the given list.
+    fn write_elements(&self, elements: &mut Vec<BaseElement>);
+
+    /// Flatten this value into a list of field elements.
+    fn to_elements(&self) -> Vec<BaseElement> {
+        let mut elements = Vec::with_capacity(Self::NUM_ELEMENTS);
+        self.write_elements(&mut elements);
+        elements
+    }
+}
+
+impl PublicInputsElements for BaseElement {
+    const NUM_ELEMENTS: usize = 1;
+
+    fn write_elements(&self, elements: &mut Vec<BaseElement>) {
+        elements.push(*self);
+    }
+}
+
+impl<T: PublicInputsElements, const N: usize> PublicInputsElements for [T; N] {
+    const NUM_ELEMENTS: usize = N * T::NUM_ELEMENTS;
+
+    fn write_elements(&self, elements: &mut Vec<BaseElement>) {
+        for value in self.iter() {
+            value.write_elements(elements);
+        }
+    }
+}
+
+/// Check the number of field elements the public inputs are flattened into
+/// against the layout declared by the proof options, and return it.
+///
+/// This function is evaluated at compile time, by the
+/// [NUM_PUBLIC_INPUTS](crate::CircomAir::NUM_PUBLIC_INPUTS) constant and the
+/// code generated by the `#[winter_circom(options = ...)]` attribute of the
+/// [WinterPublicInputs](crate::WinterPublicInputs) derive macro.
+pub const fn check_public_inputs_layout<const N: usize>(
+    options: &WinterCircomProofOptions<N>,
+    num_public_inputs: usize,
+) -> usize {
+    assert!(
+        num_public_inputs == options.num_public_inputs,
+        "public inputs do not match the number of public inputs of the proof options"
+    );
+    // in zero-knowledge mode, the public inputs are the only public signals of
+    // the Groth16 proof
+    assert!(
+        num_public_inputs > 0 || !options.zero_knowledge(),
+        "zero-knowledge mode requires at least one public input"
+    );
+    num_public_inputs
+}
@@ -0,0 +1,501 @@
+//! Local proving service, with a persistent job queue.
+//!
+//! The service accepts proving jobs over HTTP, and runs them on a fixed number
+//! of worker threads. Each job is described by a JSON document (typically, the
+//! inputs of the computation to prove), which is handed to the job runner of
+//! the server.
+//!
+//! The service exposes the following endpoints:
+//!
+//! - `POST /jobs` - submit a job, with its JSON document as body. Responds
+//! with the `id` of the job.
+//! - `GET /jobs/<id>` - poll the `status` of a job, one of `queued`,
+//! `running`, `done`, `failed` (along with an `error` message) or `cancelled`.
+//! - `DELETE /jobs/<id>` - cancel a queued or running job, and no other job.
+//! - `GET /jobs/<id>/proof` - fetch the proof bundle of a completed job: the
+//! Groth16 `proof`, its `public` signals and the committed `public_values`,
+//! in the JSON formats of [save_proof](crate::save_proof).
+//!
+//! Each job is run with its own [ExecutionControl], with the stage timeouts of
+//! the server and a fresh cancellation token.
+//!
+//! Jobs are persisted in the directory of the server, one subdirectory per job.
+//! On startup, the jobs that were queued or running when the server stopped are
+//! queued again. A job whose runner panics fails, and its worker carries on
+//! with the next jobs.
+//!
+//! With the `cli` feature, the `serve` command of the [cli](crate::cli) runs
+//! this service for the project, with a [CircuitHandle](crate::CircuitHandle)
+//! on its compiled circuit.
+
+use std::{
+    any::Any,
+    collections::HashMap,
+    fs,
+    net::SocketAddr,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::{
+        mpsc::{channel, Receiver, Sender},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+use tracing::{info, warn};
+
+use crate::{
+    proof::write_json,
+    utils::{read_json, CancellationToken, ExecutionControl, StageTimeouts, WinterCircomError},
+    CommittedValues, Groth16Proof, PublicSignals,
+};
+
+/// Function proving a job, from its JSON document and under its execution
+/// control.
+pub type JobRunner = dyn Fn(&Value, &ExecutionControl) -> Result<(Groth16Proof, PublicSignals), WinterCircomError>
+    + Send
+    + Sync;
+
+/// Status of a proving job.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Done,
+    Failed(String),
+    Cancelled,
+}
+
+impl JobStatus {
+    fn to_json(&self) -> Value {
+        match self {
+            JobStatus::Queued => json!({ "status": "queued" }),
+            JobStatus::Running => json!({ "status": "running" }),
+            JobStatus::Done => json!({ "status": "done" }),
+            JobStatus::Failed(error) => json!({ "status": "failed", "error": error }),
+            JobStatus::Cancelled => json!({ "status": "cancelled" }),
+        }
+    }
+
+    fn from_json(json: &Value) -> Result<Self, WinterCircomError> {
+        match json["status"].as_str() {
+            Some("queued") => Ok(JobStatus::Queued),
+            Some("running") => Ok(JobStatus::Running),
+            Some("done") => Ok(JobStatus::Done),
+            Some("failed") => Ok(JobStatus::Failed(
+                json["error"].as_str().unwrap_or_default().to_owned(),
+            )),
+            Some("cancelled") => Ok(JobStatus::Cancelled),
+            _ => Err(WinterCircomError::ParsingError {
+                file: String::from("status.json"),
+                comment: Some(format!("invalid status {}", json["status"])),
+            }),
+        }
+    }
+}
+
+// JOB STORE
+// ===========================================================================
+
+/// Persistent storage of the jobs, one subdirectory per job.
+struct JobStore {
+    directory: PathBuf,
+    next_id: Mutex<u64>,
+}
+
+impl JobStore {
+    fn open(directory: PathBuf) -> Result<Self, WinterCircomError> {
+        fs::create_dir_all(&directory).map_err(|e| WinterCircomError::IoError {
+            io_error: e,
+            comment: Some(format!("creating {}", directory.to_string_lossy())),
+        })?;
+
+        let next_id = Self::job_ids(&directory)?
+            .into_iter()
+            .max()
+            .map_or(0, |id| id + 1);
+
+        Ok(Self {
+            directory,
+            next_id: Mutex::new(next_id),
+        })
+    }
+
+    fn job_ids(directory: &Path) -> Result<Vec<u64>, WinterCircomError> {
+        let entries = fs::read_dir(directory).map_err(|e| WinterCircomError::IoError {
+            io_error: e,
+            comment: Some(format!("reading {}", directory.to_string_lossy())),
+        })?;
+
+        let mut ids = entries
+            .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+            .collect::<Vec<u64>>();
+        ids.sort_unstable();
+        Ok(ids)
+    }
+
+    fn path(&self, id: u64, file: &str) -> String {
+        format!("{}/{}/{}", self.directory.to_string_lossy(), id, file)
+    }
+
+    /// Persist a new job, and return its id.
+    fn create(&self, document: &Value) -> Result<u64, WinterCircomError> {
+        let mut next_id = self.next_id.lock().unwrap();
+        let id = *next_id;
+
+        let directory = self.directory.join(id.to_string());
+        fs::create_dir_all(&directory).map_err(|e| WinterCircomError::IoError {
+            io_error: e,
+            comment: Some(format!("creating {}", directory.to_string_lossy())),
+        })?;
+        write_json(self.path(id, "job.json"), document)?;
+        self.set_status(id, &JobStatus::Queued)?;
+
+        *next_id += 1;
+        Ok(id)
+    }
+
+    fn document(&self, id: u64) -> Result<Value, WinterCircomError> {
+        read_json(self.path(id, "job.json"), None)
+    }
+
+    /// Returns the status of a job, or `None` if the job does not exist.
+    fn status(&self, id: u64) -> Result<Option<JobStatus>, WinterCircomError> {
+        if !self.directory.join(id.to_string()).is_dir() {
+            return Ok(None);
+        }
+        JobStatus::from_json(&read_json(self.path(id, "status.json"), None)?).map(Some)
+    }
+
+    fn set_status(&self, id: u64, status: &JobStatus) -> Result<(), WinterCircomError> {
+        // write and rename, so that the status is never left half-written
+        write_json(self.path(id, "status.json.tmp"), &status.to_json())?;
+        fs::rename(
+            self.path(id, "status.json.tmp"),
+            self.path(id, "status.json"),
+        )
+        .map_err(|e| WinterCircomError::IoError {
+            io_error: e,
+            comment: Some(format!("writing {}", self.path(id, "status.json"))),
+        })
+    }
+
+    fn save_proof(
+        &self,
+        id: u64,
+        proof: &Groth16Proof,
+        public_signals: &PublicSignals,
+    ) -> Result<(), WinterCircomError> {
+        write_json(self.path(id, "proof.json"), &proof.to_json())?;
+        write_json(self.path(id, "public.json"), &public_signals.to_json())?;
+        if let Some(committed_values) = &public_signals.committed_values {
+            write_json(
+                self.path(id, "public_values.json"),
+                &committed_values.to_json(),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn proof_bundle(&self, id: u64) -> Result<Value, WinterCircomError> {
+        let proof = Groth16Proof::from_json(&read_json(self.path(id, "proof.json"), None)?)?;
+        let public_values = read_json(self.path(id, "public_values.json"), None)
+            .ok()
+            .map(|json| CommittedValues::from_json(&json))
+            .transpose()?;
+
+        Ok(json!({
+            "proof": proof.to_json(),
+            "public": read_json(self.path(id, "public.json"), None)?,
+            "public_values": public_values.map(|values| values.to_json()),
+        }))
+    }
+
+    /// Returns the ids of the jobs that are queued or were interrupted while
+    /// running, in submission order.
+    fn pending(&self) -> Result<Vec<u64>, WinterCircomError> {
+        let mut pending = vec![];
+        for id in Self::job_ids(&self.directory)? {
+            match self.status(id)? {
+                Some(JobStatus::Queued) | Some(JobStatus::Running) => pending.push(id),
+                _ => {}
+            }
+        }
+        Ok(pending)
+    }
+}
+
+// SERVER
+// ===========================================================================
+
+/// HTTP proving service, see [module documentation](self).
+pub struct ProvingServer {
+    server: Server,
+    store: Arc<JobStore>,
+    queue: Sender<u64>,
+    jobs: Arc<Mutex<HashMap<u64, CancellationToken>>>,
+}
+
+impl ProvingServer {
+    /// Start the service on the given address (e.g. `127.0.0.1:8080`),
+    /// persisting its jobs in the given directory, and running them on
+    /// `workers` threads with the given stage `timeouts`.
+    ///
+    /// Jobs left pending by a previous run in the same directory are queued
+    /// again. Requests are only handled once [run](Self::run) is called.
+    pub fn start<F>(
+        address: &str,
+        directory: PathBuf,
+        workers: usize,
+        timeouts: StageTimeouts,
+        runner: F,
+    ) -> Result<Self, WinterCircomError>
+    where
+        F: Fn(
+                &Value,
+                &ExecutionControl,
+            ) -> Result<(Groth16Proof, PublicSignals), WinterCircomError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        assert!(workers > 0, "the server needs at least one worker");
+
+        let store = Arc::new(JobStore::open(directory)?);
+        let runner: Arc<JobRunner> = Arc::new(runner);
+        let (queue, receiver) = channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let jobs = Arc::new(Mutex::new(HashMap::new()));
+
+        for _ in 0..workers {
+            let store = store.clone();
+            let runner = runner.clone();
+            let receiver = receiver.clone();
+            let jobs = jobs.clone();
+            let timeouts = timeouts.clone();
+            thread::spawn(move || worker(&store, runner.as_ref(), &receiver, &jobs, &timeouts));
+        }
+
+        for id in store.pending()? {
+            info!(job = id, "resuming job");
+            store.set_status(id, &JobStatus::Queued)?;
+            jobs.lock().unwrap().insert(id, CancellationToken::new());
+            queue.send(id).expect("workers are running");
+        }
+
+        let server = Server::http(address).map_err(|e| WinterCircomError::IoError {
+            io_error: std::io::Error::other(e),
+            comment: Some(format!("listening on {}", address)),
+        })?;
+
+        Ok(Self {
+            server,
+            store,
+            queue,
+            jobs,
+        })
+    }
+
+    /// Address the service is listening on.
+    pub fn address(&self) -> SocketAddr {
+        self.server
+            .server_addr()
+            .to_ip()
+            .expect("the server listens on an IP address")
+    }
+
+    /// Handle requests, until [shutdown](Self::shutdown) is called.
+    pub fn run(&self) {
+        for request in self.server.incoming_requests() {
+            self.handle(request);
+        }
+    }
+
+    /// Stop handling requests. Running jobs are resumed on the next start.
+    pub fn shutdown(&self) {
+        self.server.unblock();
+    }
+
+    fn handle(&self, mut request: Request) {
+        let url = request.url().trim_end_matches('/').to_owned();
+        let segments = url.split('/').skip(1).collect::<Vec<_>>();
+
+        let (code, body) = match (request.method(), segments.as_slice()) {
+            (Method::Post, ["jobs"]) => {
+                let mut body = String::new();
+                match request.as_reader().read_to_string(&mut body) {
+                    Ok(_) => self.submit(&body),
+                    Err(e) => (400, json!({ "error": e.to_string() })),
+                }
+            }
+            (Method::Delete, ["jobs", id]) => self.with_job(id, |id, _| self.cancel(id)),
+            (Method::Get, ["jobs", id]) => self.with_job(id, |_, status| {
+                let mut body = status.to_json();
+                body["id"] = json!(id);
+                (200, body)
+            }),
+            (Method::Get, ["jobs", id, "proof"]) => self.with_job(id, |id, status| match status {
+                JobStatus::Done => match self.store.proof_bundle(id) {
+                    Ok(bundle) => (200, bundle),
+                    Err(e) => (500, json!({ "error": e.message() })),
+                },
+                JobStatus::Failed(error) => (409, json!({ "error": error })),
+                JobStatus::Cancelled => (409, json!({ "error": "job was cancelled" })),
+                _ => (409, json!({ "error": "job is not completed" })),
+            }),
+            _ => (404, json!({ "error": "not found" })),
+        };
+
+        let response = Response::from_string(body.to_string())
+            .with_status_code(code)
+            .with_header(
+                Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+                    .expect("valid header"),
+            );
+        if let Err(e) = request.respond(response) {
+            warn!("failed to respond to request: {}", e);
+        }
+    }
+
+    fn submit(&self, body: &str) -> (u16, Value) {
+        let document = match serde_json::from_str::<Value>(body) {
+            Ok(document) => document,
+            Err(e) => return (400, json!({ "error": e.to_string() })),
+        };
+
+        // the job is registered before a worker can pick it up
+        let mut jobs = self.jobs.lock().unwrap();
+        match self.store.create(&document) {
+            Ok(id) => {
+                info!(job = id, "job submitted");
+                jobs.insert(id, CancellationToken::new());
+                self.queue.send(id).expect("workers are running");
+                (202, json!({ "id": id.to_string() }))
+            }
+            Err(e) => (500, json!({ "error": e.message() })),
+        }
+    }
+
+    fn cancel(&self, id: u64) -> (u16, Value) {
+        // the lock orders the cancellation with the start of the job by a
+        // worker, so that a queued job is never left running
+        let jobs = self.jobs.lock().unwrap();
+        let token = match jobs.get(&id) {
+            Some(token) => token,
+            None => return (409, json!({ "error": "job is completed" })),
+        };
+
+        token.cancel();
+        info!(job = id, "job cancelled");
+        match self.store.status(id) {
+            Ok(Some(JobStatus::Queued)) => match self.store.set_status(id, &JobStatus::Cancelled) {
+                Ok(()) => (200, JobStatus::Cancelled.to_json()),
+                Err(e) => (500, json!({ "error": e.message() })),
+            },
+            Ok(Some(JobStatus::Cancelled)) => (200, JobStatus::Cancelled.to_json()),
+            // a running job is marked as cancelled once its runner returns
+            Ok(_) => (202, JobStatus::Running.to_json()),
+            Err(e) => (500, json!({ "error": e.message() })),
+        }
+    }
+
+    fn with_job<F>(&self, id: &str, respond: F) -> (u16, Value)
+    where
+        F: FnOnce(u64, JobStatus) -> (u16, Value),
+    {
+        let id = match id.parse() {
+            Ok(id) => id,
+            Err(_) => return (404, json!({ "error": "unknown job" })),
+        };
+        match self.store.status(id) {
+            Ok(Some(status)) => respond(id, status),
+            Ok(None) => (404, json!({ "error": "unknown job" })),
+            Err(e) => (500, json!({ "error": e.message() })),
+        }
+    }
+}
+
+/// Run the queued jobs, until the server is dropped.
+///
+/// Each job is run with its own execution control, whose cancellation token is
+/// the one registered for the job.
+fn worker(
+    store: &JobStore,
+    runner: &JobRunner,
+    queue: &Mutex<Receiver<u64>>,
+    jobs: &Mutex<HashMap<u64, CancellationToken>>,
+    timeouts: &StageTimeouts,
+) {
+    loop {
+        // the lock is released before running the job
+        let id = match queue.lock().unwrap().recv() {
+            Ok(id) => id,
+            Err(_) => return,
+        };
+
+        let control = {
+            let mut jobs = jobs.lock().unwrap();
+            let cancellation = jobs.get(&id).cloned().unwrap_or_default();
+            if cancellation.is_cancelled() {
+                // cancelled while queued, its status is already persisted
+                jobs.remove(&id);
+                continue;
+            }
+            if let Err(e) = store.set_status(id, &JobStatus::Running) {
+                warn!(job = id, "failed to persist job status: {}", e.message());
+            }
+            ExecutionControl {
+                timeouts: timeouts.clone(),
+                cancellation,
+            }
+        };
+
+        // a panic of the runner only fails its job, the worker keeps running
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            store
+                .document(id)
+                .and_then(|document| runner(&document, &control))
+                .and_then(|(proof, public_signals)| store.save_proof(id, &proof, &public_signals))
+        }));
+
+        let status = match result {
+            Ok(Ok(())) => {
+                info!(job = id, "job done");
+                JobStatus::Done
+            }
+            Ok(Err(WinterCircomError::Cancelled)) => {
+                info!(job = id, "job cancelled");
+                JobStatus::Cancelled
+            }
+            Ok(Err(e)) => {
+                warn!(job = id, "job failed: {}", e.message());
+                JobStatus::Failed(e.message())
+            }
+            Err(payload) => {
+                let message = panic_message(payload.as_ref());
+                warn!(job = id, "job panicked: {}", message);
+                JobStatus::Failed(format!("Job panicked: {}.", message))
+            }
+        };
+
+        let mut jobs = jobs.lock().unwrap();
+        if let Err(e) = store.set_status(id, &status) {
+            warn!(job = id, "failed to persist job status: {}", e.message());
+        }
+        jobs.remove(&id);
+    }
+}
+
+/// Message of a panic payload, which is a string for the panics raised by the
+/// `panic!` macro.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
@@ -0,0 +1,102 @@
+use sha3::{Digest, Keccak256};
+use winterfell::math::{fields::f256::U256, StarkField};
+
+use crate::{
+    proof::{load_proof, Groth16Proof, PublicSignals},
+    utils::{
+        check_file, command_execution, delete_file, Executable, ExecutionControl, LoggingLevel,
+        WinterCircomError,
+    },
+};
+
+/// Export a Solidity verifier contract for the circuit-specific keys.
+///
+/// The contract is generated by SnarkJS from the `verifier.zkey` file in the
+/// `target/circom/<circuit_name>/` directory, and placed in the same directory
+/// with the `verifier.sol` name. Its `verifyProof` method takes the calldata
+/// produced by the [solidity_calldata] function.
+///
+/// This function is called by [circom_compile](crate::circom_compile) when the
+/// Solidity verifier is requested. The export is subject to the `setup` timeout
+/// of the execution `control`.
+pub fn generate_solidity_verifier(
+    circuit_name: &str,
+    control: &ExecutionControl,
+    logging_level: LoggingLevel,
+) -> Result<(), WinterCircomError> {
+    check_file(
+        format!("target/circom/{}/verifier.zkey", circuit_name),
+        Some("needed for the generation of the Solidity verifier"),
+    )?;
+
+    delete_file(format!("target/circom/{}/verifier.sol", circuit_name));
+    command_execution(
+        Executable::SnarkJS,
+        &["zkesv", "verifier.zkey", "verifier.sol"],
+        Some(&format!("target/circom/{}", circuit_name)),
+        control.timeouts.setup,
+        &control.cancellation,
+        &logging_level,
+    )?;
+    check_file(
+        format!("target/circom/{}/verifier.sol", circuit_name),
+        Some("Solidity verifier export must have failed"),
+    )
+}
+
+/// Build the ABI-encoded calldata of a call to the `verifyProof` method of the
+/// Solidity verifier, from the `proof.json` and `public.json` files in the
+/// `target/circom/<circuit_name>/` directory.
+///
+/// These files can be written by the [save_proof](crate::save_proof) function.
+pub fn solidity_calldata(circuit_name: &str) -> Result<Vec<u8>, WinterCircomError> {
+    let (proof, public_signals) = load_proof(circuit_name)?;
+
+    Ok(groth16_calldata(&proof, &public_signals))
+}
+
+/// Build the ABI-encoded calldata of a call to the `verifyProof` method of the
+/// Solidity verifier, from a Groth16 proof and its public signals, as returned
+/// by [circom_prove](crate::circom_prove).
+///
+/// The calldata is made of the 4-byte selector of
+/// `verifyProof(uint256[2],uint256[2][2],uint256[2],uint256[n])`, where `n` is
+/// the number of public signals, followed by the proof points and the public
+/// signals. All arguments are static arrays, which are encoded in place as
+/// 32-byte big-endian words.
+pub fn groth16_calldata(proof: &Groth16Proof, public_signals: &PublicSignals) -> Vec<u8> {
+    let signature = format!(
+        "verifyProof(uint256[2],uint256[2][2],uint256[2],uint256[{}])",
+        public_signals.signals.len()
+    );
+    let mut calldata = Keccak256::digest(signature.as_bytes())[..4].to_vec();
+
+    // the coordinates of the G2 point are given in reverse order to the
+    // verifier, to match the order expected by the pairing precompile
+    let words = [
+        proof.pi_a[0],
+        proof.pi_a[1],
+        proof.pi_b[0][1],
+        proof.pi_b[0][0],
+        proof.pi_b[1][1],
+        proof.pi_b[1][0],
+        proof.pi_c[0],
+        proof.pi_c[1],
+    ];
+    let signals = public_signals.signals.iter().map(|signal| signal.as_int());
+    for word in words.into_iter().chain(signals) {
+        calldata.extend_from_slice(&encode_word(word));
+    }
+
+    calldata
+}
+
+// HELPER FUNCTIONS
+// ===========================================================================
+
+/// Encode an integer into a 32-byte big-endian ABI word.
+fn encode_word(value: U256) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    value.to_big_endian(&mut word);
+    word
+}
@@ -1,11 +1,21 @@
 use std::{
+    cell::Cell,
     fmt::{Debug, Display},
-    io,
+    fs::create_dir_all,
+    io::{self, Read},
     path::{Path, PathBuf},
-    process::{Command, Stdio},
+    process::{Child, Command, ExitStatus, Stdio},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
 };
 
 use colored::Colorize;
+use serde_json::Value;
+use tracing::{debug, info, warn, Span};
 use winterfell::{ProverError, VerifierError};
 
 // ERRORS
@@ -29,22 +39,79 @@ pub enum WinterCircomError {
 
     /// This error type is triggered when an underlying command called by a
     /// function of this crate failed (returned a non-zero exit code).
+    ///
+    /// The captured output of the command is attached, for diagnosis.
     ExitCodeError {
         executable: String,
         code: i32,
+        stdout: String,
+        stderr: String,
     },
 
+    /// This error type is triggered when an underlying command called by a
+    /// function of this crate did not complete within the timeout of its stage
+    /// (see [StageTimeouts]), and was killed.
+    Timeout {
+        executable: String,
+        timeout: Duration,
+        stdout: String,
+        stderr: String,
+    },
+
+    /// This error type is triggered when the [CancellationToken] of a function
+    /// of this crate was cancelled. A running underlying command is killed.
+    Cancelled,
+
     /// This error is triggered, when the generated Winterfell proof could not
     /// be verified. This only happens in debug mode.
     InvalidProof(Option<VerifierError>),
 
     /// This error is triggered when the Winterfell proof generation failed.
     ProverError(ProverError),
+
+    /// This error is triggered when a file generated by an underlying command
+    /// could not be parsed.
+    ParsingError {
+        file: String,
+        comment: Option<String>,
+    },
+
+    /// This error is triggered when the out-of-domain (OOD) consistency check
+    /// failed, or when the public signals of the Groth16 proof do not match the
+    /// values they commit to or the public inputs.
+    InvalidOodFrame(String),
+
+    /// This error type is triggered when a Winterfell proof could not be
+    /// parsed, or is inconsistent with its AIR.
+    MalformedProof(String),
+
+    /// This error type is triggered when a Winterfell proof is valid, but its
+    /// shape is not supported by the Circom circuit (several trace segments or
+    /// FRI partitions, or a hash function other than Poseidon).
+    UnsupportedProof(String),
+
+    /// This error type is triggered when a value could not be serialized to or
+    /// deserialized from JSON.
+    JsonError {
+        json_error: serde_json::Error,
+        comment: Option<String>,
+    },
+
+    /// This error type is triggered when proof options are not supported by
+    /// Winterfell or by the Circom circuit, or when Poseidon parameters are
+    /// not supported.
+    InvalidOptions(String),
+
+    /// This error type is triggered when an external tool (Circom or SnarkJS)
+    /// could not be found, or its version is not supported.
+    ToolchainError { executable: String, comment: String },
 }
 
-impl Display for WinterCircomError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let error_string = match self {
+impl WinterCircomError {
+    /// Plain description of the error, without the colors of its [Display]
+    /// implementation, for logs and APIs that are not read on a terminal.
+    pub fn message(&self) -> String {
+        match self {
             WinterCircomError::IoError { io_error, comment } => {
                 if let Some(comment) = comment {
                     format!("IoError: {} ({}).", io_error, comment)
@@ -59,9 +126,31 @@ impl Display for WinterCircomError {
                     format!("File not found: {}.", file)
                 }
             }
-            WinterCircomError::ExitCodeError { executable, code } => {
-                format!("Executable {} exited with code {}.", executable, code)
+            WinterCircomError::ExitCodeError {
+                executable,
+                code,
+                stderr,
+                ..
+            } => {
+                if stderr.trim().is_empty() {
+                    format!("Executable {} exited with code {}.", executable, code)
+                } else {
+                    format!(
+                        "Executable {} exited with code {}:\n{}",
+                        executable,
+                        code,
+                        stderr.trim_end()
+                    )
+                }
+            }
+            WinterCircomError::Timeout {
+                executable,
+                timeout,
+                ..
+            } => {
+                format!("Executable {} timed out after {:?}.", executable, timeout)
             }
+            WinterCircomError::Cancelled => String::from("Cancelled."),
             WinterCircomError::InvalidProof(verifier_error) => {
                 if let Some(verifier_error) = verifier_error {
                     format!("Invalid proof: {}.", verifier_error)
@@ -72,9 +161,48 @@ impl Display for WinterCircomError {
             WinterCircomError::ProverError(prover_error) => {
                 format!("Prover error: {}.", prover_error)
             }
-        };
+            WinterCircomError::ParsingError { file, comment } => {
+                if let Some(comment) = comment {
+                    format!("Could not parse {} ({}).", file, comment)
+                } else {
+                    format!("Could not parse {}.", file)
+                }
+            }
+            WinterCircomError::InvalidOodFrame(comment) => {
+                format!("Invalid OOD frame: {}.", comment)
+            }
+            WinterCircomError::MalformedProof(comment) => {
+                format!("Malformed proof: {}.", comment)
+            }
+            WinterCircomError::UnsupportedProof(comment) => {
+                format!("Unsupported proof: {}.", comment)
+            }
+            WinterCircomError::JsonError {
+                json_error,
+                comment,
+            } => {
+                if let Some(comment) = comment {
+                    format!("JSON error: {} ({}).", json_error, comment)
+                } else {
+                    format!("JSON error: {}.", json_error)
+                }
+            }
+            WinterCircomError::InvalidOptions(comment) => {
+                format!("Invalid proof options: {}.", comment)
+            }
+            WinterCircomError::ToolchainError {
+                executable,
+                comment,
+            } => {
+                format!("Toolchain error for {}: {}.", executable, comment)
+            }
+        }
+    }
+}
 
-        write!(f, "{}", error_string.yellow())
+impl Display for WinterCircomError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message().yellow())
     }
 }
 
@@ -100,8 +228,8 @@ pub(crate) enum Executable {
 impl Executable {
     fn executable_path(&self) -> Result<PathBuf, WinterCircomError> {
         Ok(match self {
-            Self::Circom => canonicalize("iden3/circom/target/release/circom")?,
-            Self::SnarkJS => canonicalize("iden3/snarkjs/build/cli.cjs")?,
+            Self::Circom => CIRCOM.locate()?,
+            Self::SnarkJS => SNARKJS.locate()?,
             Self::Make => "make".into(),
             Self::Custom { path, .. } => canonicalize(path)?,
         })
@@ -122,6 +250,164 @@ impl Executable {
     }
 }
 
+// TOOLCHAIN DISCOVERY
+// ===========================================================================
+
+/// External tool of the iden3 toolchain, located at runtime.
+struct Tool {
+    name: &'static str,
+    /// Environment variable giving an explicit path to the tool.
+    path_variable: &'static str,
+    /// Path of the tool built from the git submodules, relative to the
+    /// workspace root.
+    submodule_path: &'static str,
+    version_args: &'static [&'static str],
+    /// Text preceding the version number in the output of the tool.
+    version_prefix: &'static str,
+    min_version: (u32, u32, u32),
+    /// Whether the version of the tool was already checked by this process.
+    checked: AtomicBool,
+}
+
+static CIRCOM: Tool = Tool {
+    name: "circom",
+    path_variable: "WINTER_CIRCOM_CIRCOM",
+    submodule_path: "iden3/circom/target/release/circom",
+    version_args: &["--version"],
+    version_prefix: "circom compiler ",
+    // pragma of the templates in the circuits/ directory
+    min_version: (2, 0, 0),
+    checked: AtomicBool::new(false),
+};
+
+static SNARKJS: Tool = Tool {
+    name: "snarkjs",
+    path_variable: "WINTER_CIRCOM_SNARKJS",
+    submodule_path: "iden3/snarkjs/build/cli.cjs",
+    // the version is printed along with the usage
+    version_args: &[],
+    version_prefix: "snarkjs@",
+    // first version with the export of Solidity verifiers
+    min_version: (0, 4, 0),
+    checked: AtomicBool::new(false),
+};
+
+impl Tool {
+    /// Locate the tool, from its path variable, the git submodules or the
+    /// `PATH`, in that order, and check its version on first use.
+    fn locate(&self) -> Result<PathBuf, WinterCircomError> {
+        let path = if let Some(path) = std::env::var_os(self.path_variable) {
+            canonicalize(path).map_err(|_| {
+                self.error(&format!(
+                    "{} does not point to an existing file",
+                    self.path_variable
+                ))
+            })?
+        } else if Path::new(self.submodule_path).exists() {
+            canonicalize(self.submodule_path)?
+        } else {
+            find_in_path(self.name).ok_or_else(|| {
+                self.error(&format!(
+                    "not found, set {}, build the iden3 submodules or install it in PATH",
+                    self.path_variable
+                ))
+            })?
+        };
+
+        if !self.checked.load(Ordering::Relaxed) {
+            self.check_version(&path)?;
+            self.checked.store(true, Ordering::Relaxed);
+        }
+
+        Ok(path)
+    }
+
+    fn check_version(&self, path: &Path) -> Result<(), WinterCircomError> {
+        let output = Command::new(path)
+            .args(self.version_args)
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|e| self.error(&format!("could not be executed ({})", e)))?;
+        let output = [output.stdout, output.stderr].concat();
+        let output = String::from_utf8_lossy(&output);
+
+        let version = output
+            .split(self.version_prefix)
+            .nth(1)
+            .and_then(parse_version);
+        match version {
+            Some(version) if version < self.min_version => Err(self.error(&format!(
+                "version {}.{}.{} is not supported, at least {}.{}.{} is required",
+                version.0,
+                version.1,
+                version.2,
+                self.min_version.0,
+                self.min_version.1,
+                self.min_version.2
+            ))),
+            Some(version) => {
+                debug!(executable = self.name, ?version, path = %path.display(), "found tool");
+                Ok(())
+            }
+            None => {
+                warn!(
+                    executable = self.name,
+                    path = %path.display(),
+                    "could not determine the tool version"
+                );
+                Ok(())
+            }
+        }
+    }
+
+    fn error(&self, comment: &str) -> WinterCircomError {
+        WinterCircomError::ToolchainError {
+            executable: self.name.to_owned(),
+            comment: comment.to_owned(),
+        }
+    }
+}
+
+/// Locate the JavaScript library of SnarkJS, next to its command line.
+pub(crate) fn snarkjs_library() -> Result<PathBuf, WinterCircomError> {
+    let cli = canonicalize(SNARKJS.locate()?)?;
+    let library = cli.with_file_name("main.cjs");
+    if library.is_file() {
+        Ok(library)
+    } else {
+        Err(SNARKJS.error(&format!(
+            "its library was not found next to {}",
+            cli.display()
+        )))
+    }
+}
+
+/// Parse a `major.minor.patch` version at the start of the text.
+fn parse_version(text: &str) -> Option<(u32, u32, u32)> {
+    let mut numbers = text
+        .split(|c: char| !c.is_ascii_digit())
+        .take(3)
+        .map(|number| number.parse().ok());
+    Some((numbers.next()??, numbers.next()??, numbers.next()??))
+}
+
+/// Look up an executable in the directories of the `PATH` environment variable.
+fn find_in_path(name: &str) -> Option<PathBuf> {
+    let extensions: &[&str] = if cfg!(windows) {
+        &["exe", "cmd"]
+    } else {
+        &[""]
+    };
+
+    std::env::split_paths(&std::env::var_os("PATH")?)
+        .flat_map(|directory| {
+            extensions
+                .iter()
+                .map(move |extension| directory.join(name).with_extension(extension))
+        })
+        .find(|path| path.is_file())
+}
+
 pub(crate) fn canonicalize<P: AsRef<Path>>(path: P) -> Result<PathBuf, WinterCircomError> {
     let path = path.as_ref();
     std::fs::canonicalize(path).map_err(|io_error| WinterCircomError::IoError {
@@ -133,13 +419,51 @@ pub(crate) fn canonicalize<P: AsRef<Path>>(path: P) -> Result<PathBuf, WinterCir
     })
 }
 
+/// Interval at which a running command is checked for completion, timeout and
+/// cancellation.
+pub(crate) const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
 /// Execute a system command, returning an error on failure.
+///
+/// The command is killed if it does not complete within `timeout`, or if the
+/// `cancellation` token is cancelled.
 pub(crate) fn command_execution(
     executable: Executable,
     args: &[&str],
     current_dir: Option<&str>,
+    timeout: Option<Duration>,
+    cancellation: &CancellationToken,
     logging_level: &LoggingLevel,
 ) -> Result<(), WinterCircomError> {
+    command_output(
+        executable,
+        args,
+        current_dir,
+        timeout,
+        cancellation,
+        logging_level,
+    )
+    .map(|_| ())
+}
+
+/// Output of a command executed by [command_output].
+pub(crate) struct CommandOutput {
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Execute a system command like [command_execution], returning its captured
+/// output on success.
+pub(crate) fn command_output(
+    executable: Executable,
+    args: &[&str],
+    current_dir: Option<&str>,
+    timeout: Option<Duration>,
+    cancellation: &CancellationToken,
+    logging_level: &LoggingLevel,
+) -> Result<CommandOutput, WinterCircomError> {
+    cancellation.check()?;
+
     let mut command = Command::new(executable.executable_path()?);
 
     // set arguments and current directory
@@ -171,32 +495,196 @@ pub(crate) fn command_execution(
         }
     };
 
-    // do not print command stdout if logging level is below verbose
-    if !logging_level.print_command_output() {
-        command.stdout(Stdio::null());
-    }
+    // capture the command output, to emit it as tracing events
+    command.stdin(Stdio::null());
+    command.stdout(Stdio::piped());
+    command.stderr(Stdio::piped());
+
+    // the command runs in its own process group, so that the processes it
+    // spawns (such as the compilers called by make) are killed along with it
+    in_process_group(&mut command);
 
-    match command.status() {
-        Ok(status) => {
-            if !status.success() {
-                return Err(WinterCircomError::ExitCodeError {
-                    executable: executable.executable_name(),
-                    code: status.code().unwrap_or(-1),
-                });
+    let mut child = command.spawn().map_err(|e| WinterCircomError::IoError {
+        io_error: e,
+        comment: Some(format!(
+            "during execution of: {}",
+            executable.executable_name()
+        )),
+    })?;
+
+    // the pipes are drained in the background, so that the command never
+    // blocks on a full pipe
+    let stdout_reader = read_pipe(child.stdout.take());
+    let stderr_reader = read_pipe(child.stderr.take());
+
+    let start = Instant::now();
+    let outcome = loop {
+        match try_wait_child(&mut child) {
+            Ok(Some((status, peak_memory_kb))) => {
+                if let Some(peak_memory_kb) = peak_memory_kb {
+                    record_child_memory(peak_memory_kb);
+                }
+                break CommandOutcome::Exited(status);
             }
+            Ok(None) => {}
+            Err(e) => break CommandOutcome::Failed(e),
         }
-        Err(e) => {
-            return Err(WinterCircomError::IoError {
-                io_error: e,
-                comment: Some(format!(
-                    "during execution of: {}",
-                    executable.executable_name()
-                )),
+        if cancellation.is_cancelled() {
+            break CommandOutcome::Cancelled;
+        }
+        if let Some(timeout) = timeout {
+            if start.elapsed() >= timeout {
+                break CommandOutcome::TimedOut(timeout);
+            }
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+    if !matches!(outcome, CommandOutcome::Exited(_)) {
+        kill_process_group(&mut child);
+    }
+
+    let executable_name = executable.executable_name();
+    let stdout = String::from_utf8_lossy(&stdout_reader.join().unwrap_or_default()).into_owned();
+    let stderr = String::from_utf8_lossy(&stderr_reader.join().unwrap_or_default()).into_owned();
+    for line in stdout.lines() {
+        debug!(executable = %executable_name, stream = "stdout", "{}", line);
+    }
+    for line in stderr.lines() {
+        warn!(executable = %executable_name, stream = "stderr", "{}", line);
+    }
+
+    // only print command output if logging level is verbose or above, it is
+    // otherwise only emitted as events
+    if logging_level.print_command_output() {
+        print!("{}", stdout);
+        eprint!("{}", stderr);
+    }
+
+    match outcome {
+        CommandOutcome::Exited(status) if status.success() => Ok(CommandOutput { stdout, stderr }),
+        CommandOutcome::Exited(status) => Err(WinterCircomError::ExitCodeError {
+            executable: executable_name,
+            code: status.code().unwrap_or(-1),
+            stdout,
+            stderr,
+        }),
+        CommandOutcome::TimedOut(timeout) => {
+            warn!(executable = %executable_name, ?timeout, "command timed out");
+            Err(WinterCircomError::Timeout {
+                executable: executable_name,
+                timeout,
+                stdout,
+                stderr,
             })
         }
+        CommandOutcome::Cancelled => {
+            warn!(executable = %executable_name, "command cancelled");
+            Err(WinterCircomError::Cancelled)
+        }
+        CommandOutcome::Failed(e) => Err(WinterCircomError::IoError {
+            io_error: e,
+            comment: Some(format!("waiting for: {}", executable_name)),
+        }),
     }
+}
 
-    Ok(())
+enum CommandOutcome {
+    Exited(ExitStatus),
+    TimedOut(Duration),
+    Cancelled,
+    Failed(io::Error),
+}
+
+/// Check whether a child process spawned by [command_execution] has exited,
+/// without blocking, and return its exit status along with its peak memory
+/// usage in kB.
+#[cfg(unix)]
+fn try_wait_child(child: &mut Child) -> io::Result<Option<(ExitStatus, Option<u64>)>> {
+    use std::os::unix::process::ExitStatusExt;
+
+    let mut status = 0;
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::uninit();
+    // SAFETY: wait4 only writes to the given status and struct
+    let pid = unsafe {
+        libc::wait4(
+            child.id() as libc::pid_t,
+            &mut status,
+            libc::WNOHANG,
+            usage.as_mut_ptr(),
+        )
+    };
+    match pid {
+        0 => Ok(None),
+        -1 => match io::Error::last_os_error() {
+            e if e.kind() == io::ErrorKind::Interrupted => Ok(None),
+            e => Err(e),
+        },
+        _ => {
+            // SAFETY: the struct was initialized by the successful call above
+            let usage = unsafe { usage.assume_init() };
+            Ok(Some((
+                ExitStatus::from_raw(status),
+                Some(max_rss_kb(&usage)),
+            )))
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn try_wait_child(child: &mut Child) -> io::Result<Option<(ExitStatus, Option<u64>)>> {
+    Ok(child.try_wait()?.map(|status| (status, None)))
+}
+
+/// Run a command in its own process group, so that it can be killed along with
+/// the processes it spawns by [kill_process_group].
+pub(crate) fn in_process_group(command: &mut Command) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+
+        // SAFETY: setpgid is async-signal-safe
+        unsafe {
+            command.pre_exec(|| {
+                if libc::setpgid(0, 0) == 0 {
+                    Ok(())
+                } else {
+                    Err(io::Error::last_os_error())
+                }
+            });
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = command;
+    }
+}
+
+/// Kill a child process spawned by [command_execution], along with the
+/// processes it spawned, and wait for it.
+pub(crate) fn kill_process_group(child: &mut Child) {
+    #[cfg(unix)]
+    {
+        // SAFETY: the child leads its own process group, see command_execution
+        unsafe {
+            libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL);
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = child.kill();
+    }
+    let _ = child.wait();
+}
+
+/// Read a pipe of a child process to its end, in a background thread.
+fn read_pipe<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buffer);
+        }
+        buffer
+    })
 }
 
 /// Verify that a file exists, returning an error on failure.
@@ -214,6 +702,20 @@ pub(crate) fn check_file(path: String, comment: Option<&str>) -> Result<(), Wint
     Ok(())
 }
 
+/// Read and parse a JSON file, returning an error on failure.
+pub fn read_json(path: String, comment: Option<&str>) -> Result<Value, WinterCircomError> {
+    check_file(path.clone(), comment)?;
+    let data = std::fs::read_to_string(&path).map_err(|e| WinterCircomError::IoError {
+        io_error: e,
+        comment: Some(format!("reading {}", path)),
+    })?;
+
+    serde_json::from_str(&data).map_err(|e| WinterCircomError::JsonError {
+        json_error: e,
+        comment: Some(format!("parsing {}", path)),
+    })
+}
+
 pub(crate) fn delete_file(path: String) {
     let _ = std::fs::remove_file(&path);
 }
@@ -222,10 +724,226 @@ pub(crate) fn delete_directory(path: String) {
     let _ = std::fs::remove_dir_all(&path);
 }
 
+// JOB DIRECTORIES
+// ===========================================================================
+
+/// Counter of the job directories created by this process.
+static NEXT_JOB: AtomicUsize = AtomicUsize::new(0);
+
+/// Scratch directory of a single proving or verification job, in the
+/// `target/circom/<circuit_name>/jobs/` directory.
+///
+/// The shared artifacts of the circuit (R1CS, keys and witness generator) are
+/// only read by jobs, so that several jobs can run concurrently against the
+/// same compiled circuit. The directory is deleted when dropped.
+pub(crate) struct JobDirectory {
+    path: String,
+}
+
+impl JobDirectory {
+    /// Create a new job directory, with a name that is unique across processes.
+    pub(crate) fn new(circuit_name: &str) -> Result<Self, WinterCircomError> {
+        let job_id = NEXT_JOB.fetch_add(1, Ordering::Relaxed);
+        let path = format!(
+            "target/circom/{}/jobs/{}-{}",
+            circuit_name,
+            std::process::id(),
+            job_id
+        );
+
+        create_dir_all(&path).map_err(|e| WinterCircomError::IoError {
+            io_error: e,
+            comment: Some(format!("creating job directory {}", path)),
+        })?;
+
+        Ok(Self { path })
+    }
+
+    /// Path of the job directory, relative to the workspace root.
+    pub(crate) fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl Drop for JobDirectory {
+    fn drop(&mut self) {
+        delete_directory(self.path.clone());
+    }
+}
+
+// TRACING
+// ===========================================================================
+
+/// Create a span for a stage of the pipeline, with empty `duration_ms`,
+/// `process_peak_memory_kb` and `peak_child_memory_kb` fields that are
+/// recorded by [in_stage].
+macro_rules! stage_span {
+    ($name:literal) => {
+        tracing::info_span!(
+            $name,
+            duration_ms = tracing::field::Empty,
+            process_peak_memory_kb = tracing::field::Empty,
+            peak_child_memory_kb = tracing::field::Empty,
+        )
+    };
+}
+pub(crate) use stage_span;
+
+thread_local! {
+    /// Peak memory usage of the child processes run by the current stage of
+    /// this thread, in kB.
+    static STAGE_CHILD_MEMORY_KB: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Record the peak memory usage of a child process that has exited, in the
+/// current stage of this thread.
+fn record_child_memory(peak_memory_kb: u64) {
+    STAGE_CHILD_MEMORY_KB.with(|memory| {
+        memory.set(Some(memory.get().unwrap_or(0).max(peak_memory_kb)));
+    });
+}
+
+/// Run a stage of the pipeline inside the given span (see [stage_span]), and
+/// record its duration and memory usage at its end.
+///
+/// The peak child memory usage is the maximum resident set size of the child
+/// processes run by the stage (such as the witness generator or SnarkJS), and
+/// is only recorded for stages that run one. The process peak memory usage is
+/// the maximum resident set size of the process since its start, which
+/// includes the previous stages and the concurrent jobs of the process. Both
+/// are only recorded on Unix platforms.
+pub(crate) fn in_stage<T, F: FnOnce() -> T>(span: Span, stage: F) -> T {
+    let _enter = span.enter();
+    let now = Instant::now();
+    let outer_child_memory = STAGE_CHILD_MEMORY_KB.with(|memory| memory.replace(None));
+
+    let result = stage();
+
+    let duration_ms = now.elapsed().as_millis() as u64;
+    span.record("duration_ms", duration_ms);
+    let process_peak_memory_kb = process_peak_memory_kb();
+    if let Some(memory) = process_peak_memory_kb {
+        span.record("process_peak_memory_kb", memory);
+    }
+
+    // the child processes of a nested stage are also run by the outer stage
+    let peak_child_memory_kb =
+        STAGE_CHILD_MEMORY_KB.with(|memory| memory.replace(outer_child_memory));
+    if let Some(memory) = peak_child_memory_kb {
+        span.record("peak_child_memory_kb", memory);
+        record_child_memory(memory);
+    }
+    info!(
+        duration_ms,
+        process_peak_memory_kb, peak_child_memory_kb, "stage completed"
+    );
+
+    result
+}
+
+/// Maximum resident set size of the process since its start, in kB.
+#[cfg(unix)]
+fn process_peak_memory_kb() -> Option<u64> {
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::uninit();
+    // SAFETY: getrusage only writes to the given struct
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr()) } != 0 {
+        return None;
+    }
+    // SAFETY: the struct was initialized by the successful call above
+    Some(max_rss_kb(&unsafe { usage.assume_init() }))
+}
+
+#[cfg(not(unix))]
+fn process_peak_memory_kb() -> Option<u64> {
+    None
+}
+
+/// Maximum resident set size of a resource usage, in kB.
+#[cfg(unix)]
+fn max_rss_kb(usage: &libc::rusage) -> u64 {
+    // macOS reports the resident set size in bytes
+    if cfg!(target_os = "macos") {
+        usage.ru_maxrss as u64 / 1024
+    } else {
+        usage.ru_maxrss as u64
+    }
+}
+
+// EXECUTION CONTROL
+// ===========================================================================
+
+/// Timeouts and cancellation of the underlying commands called by the
+/// functions of this crate.
+///
+/// The default control sets no timeout, and is never cancelled.
+#[derive(Clone, Debug, Default)]
+pub struct ExecutionControl {
+    pub timeouts: StageTimeouts,
+    pub cancellation: CancellationToken,
+}
+
+/// Timeouts of the underlying commands, per stage of the pipeline. Commands
+/// without timeout can run indefinitely.
+#[derive(Clone, Debug, Default)]
+pub struct StageTimeouts {
+    /// Compilation of the circuit and of its witness generator.
+    pub compile: Option<Duration>,
+
+    /// Generation of and contributions to the circuit-specific keys, and
+    /// export of the verification key and Solidity verifier.
+    pub setup: Option<Duration>,
+
+    /// Computation of the execution witness of the circuit.
+    pub witness: Option<Duration>,
+
+    /// Generation of the Groth16 proof.
+    pub groth16_prove: Option<Duration>,
+
+    /// Verification of the Groth16 proof.
+    pub groth16_verify: Option<Duration>,
+}
+
+/// Token for cancelling the execution of the functions of this crate from
+/// another thread.
+///
+/// Clones of a token share its state. Cancellation kills the running underlying
+/// command, and is otherwise checked between the stages of the pipeline.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the execution of the functions using this token.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns whether this token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Returns a [Cancelled](WinterCircomError::Cancelled) error if this token
+    /// was cancelled.
+    pub(crate) fn check(&self) -> Result<(), WinterCircomError> {
+        if self.is_cancelled() {
+            Err(WinterCircomError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
 // LOGGING
 // ===========================================================================
 
 /// Logging level selector for functions of this crate.
+#[derive(Clone, Copy)]
 pub enum LoggingLevel {
     /// Nothing is printed to stdout (errors are still printed to stderr)
     Quiet,
@@ -233,7 +951,7 @@ pub enum LoggingLevel {
     /// Minimal logging (only major steps are logged to stdout)
     Default,
 
-    /// Output of underlying executables is printed as well
+    /// Output of underlying executables, stdout and stderr, is printed as well
     Verbose,
 
     /// Underlying executables are set to verbose mode, and their output is printed as well
@@ -256,8 +974,8 @@ impl LoggingLevel {
     /// Returns whether the logging level is set to [Verbose](LoggingLevel::Verbose)
     /// or above.
     ///
-    /// This is used to trigger the printing of underlying commands stdout in the
-    /// functions of this crate.
+    /// This is used to trigger the printing of underlying commands stdout and
+    /// stderr in the functions of this crate.
     pub(crate) fn print_command_output(&self) -> bool {
         match self {
             Self::Quiet => false,
@@ -1,11 +1,15 @@
-use std::fs;
-
+use serde_json::Value;
 use winterfell::{
-    math::{
-        fields::f256::{BaseElement, U256},
-        FieldElement,
-    },
-    Air, EvaluationFrame,
+    crypto::{hashers::Poseidon, ElementHasher},
+    math::{fields::f256::BaseElement, log2, FieldElement},
+    Air, EvaluationFrame, FieldExtension, ProofOptions, TraceInfo,
+};
+
+use crate::{
+    ensure, hash_function,
+    proof::{parse_elements, CommittedValues, PublicSignals},
+    utils::WinterCircomError,
+    validate_fri_partitions, validate_merkle_arity, validate_options, WinterPublicInputs,
 };
 
 /// Check that the out-of-domain (OOD) trace frame corresponds to the given [Air]
@@ -16,63 +20,270 @@ use winterfell::{
 /// would be different and the Merkle commitment verifications would fail. This
 /// function therefore guarantees that the OOD constraint evaluations are correct.
 ///
-/// This function requires the `public.json` file in the
-/// `target/circom/<circuit_name>/` directory to contain `t` ood constraint
+/// The [Air] is built from the public inputs of the computation and the
+/// `options` the circuit was compiled with, which are written to the
+/// `options.json` file of the `target/circom/<circuit_name>/` directory by
+/// [circom_compile](crate::circom_compile).
+///
+/// The `public_signals` are the public signals of the Groth16 proof, as returned
+/// by [circom_prove](crate::circom_prove). They are `t` ood constraint
 /// evaluations and 2`t` ood trace frame elements, in that order, where `t` is
-/// the trace width. This should be correct if the Circom proof was generated
-/// with the [circom_prove](crate::circom_prove) function.
-pub fn check_ood_frame<AIR>(circuit_name: &str)
+/// the trace width.
+///
+/// If the circuit commits to its public signals (see
+/// [with_public_signals_commitment](crate::WinterCircomProofOptions::with_public_signals_commitment)),
+/// the public signals only contain the commitment. The OOD trace frame and
+/// constraint evaluations are then read from the
+/// [committed values](PublicSignals::committed_values), and their hash is
+/// checked against the commitment.
+///
+/// If the circuit verifies proofs of variable trace length (see
+/// [with_max_trace_length](crate::WinterCircomProofOptions::with_max_trace_length)),
+/// the public signals start with the log2 of the trace length, which is used to
+/// build the [Air].
+///
+/// In zero-knowledge mode (see
+/// [with_zero_knowledge](crate::WinterCircomProofOptions::with_zero_knowledge)),
+/// the OOD consistency check is done by the circuit, and this function only
+/// checks that the public signals are the public inputs.
+pub fn check_ood_frame<AIR>(
+    public_signals: &PublicSignals,
+    options: &Value,
+    pub_inputs: AIR::PublicInputs,
+) -> Result<(), WinterCircomError>
 where
-    AIR: Air<BaseField = BaseElement> + Default,
+    AIR: Air<BaseField = BaseElement>,
+    AIR::PublicInputs: WinterPublicInputs,
 {
-    // public.json parsing
-    let data = fs::read_to_string(format!("target/circom/{}/public.json", circuit_name))
-        .expect("Unable to read file");
-    let json: serde_json::Value =
-        serde_json::from_str(&data).expect("public.json format incorrect!");
+    let check = check_public_signals::<AIR>(public_signals, options, pub_inputs)?;
 
-    let pub_inputs = json.as_array().unwrap();
+    if !check.public_inputs_match {
+        return Err(WinterCircomError::InvalidOodFrame(String::from(
+            "public signals do not match the public inputs",
+        )));
+    }
+    if !check.ood_valid {
+        return Err(WinterCircomError::InvalidOodFrame(String::from(
+            "OOD constraint evaluations are not consistent with the OOD trace frame",
+        )));
+    }
 
-    // public.json contains 3 * trace_width elements :
-    //  - trace_width ood_constraint_evaluation
-    //  - 2 * trace_width elements for the OOD trace frame
-    let trace_width = pub_inputs.len() / 3;
+    Ok(())
+}
+
+/// Outcome of the checks performed on the public signals of a Groth16 proof.
+pub(crate) struct PublicSignalsCheck {
+    pub(crate) public_inputs_match: bool,
+    pub(crate) ood_valid: bool,
+}
+
+/// Check the public signals of a Groth16 proof against the public inputs and the
+/// transition constraints of the [Air], see [check_ood_frame].
+///
+/// Errors are only returned for malformed public signals or invalid options.
+pub(crate) fn check_public_signals<AIR>(
+    public_signals: &PublicSignals,
+    options: &Value,
+    pub_inputs: AIR::PublicInputs,
+) -> Result<PublicSignalsCheck, WinterCircomError>
+where
+    AIR: Air<BaseField = BaseElement>,
+    AIR::PublicInputs: WinterPublicInputs,
+{
+    let expected_public_inputs =
+        serde_json::to_value(pub_inputs.clone()).map_err(|e| WinterCircomError::JsonError {
+            json_error: e,
+            comment: Some(String::from("serializing public inputs")),
+        })?;
+    let expected_public_inputs = parse_elements(&expected_public_inputs, "public inputs")?;
+
+    if read_usize(options, "num_blinding_rows")? > 0 {
+        return Ok(PublicSignalsCheck {
+            public_inputs_match: public_signals.signals == expected_public_inputs,
+            ood_valid: true,
+        });
+    }
+
+    // the single public signal is the commitment to the actual public signals
+    let (public_inputs_match, ood_valid, public_signals) =
+        if options["public_signals_commitment"] == true {
+            let committed_values = public_signals.committed_values.as_ref().ok_or_else(|| {
+                parsing_error("public signals", "missing the committed values")
+            })?;
+            let commitment = public_signals.signals.first().ok_or_else(|| {
+                parsing_error("public signals", "missing the public signals commitment")
+            })?;
+            (
+                committed_values.public_inputs == expected_public_inputs,
+                commitment_matches(committed_values, *commitment),
+                [
+                    committed_values.ood_frame_constraint_evaluation.as_slice(),
+                    &committed_values.ood_trace_frame[0],
+                    &committed_values.ood_trace_frame[1],
+                ]
+                .concat(),
+            )
+        } else {
+            (true, true, public_signals.signals.clone())
+        };
 
-    let mut channel_ood_constraint_evaluation = Vec::<BaseElement>::with_capacity(trace_width);
+    // the log2 of the trace length comes first in variable-length circuits
+    let (trace_length, public_signals) = if options["max_trace_length"].is_u64() {
+        let (log_trace_length, public_signals) = public_signals.split_first().ok_or_else(|| {
+            parsing_error("public signals", "missing the log2 of the trace length")
+        })?;
+        (
+            trace_length_from_log(*log_trace_length, options)?,
+            public_signals.to_vec(),
+        )
+    } else {
+        (read_usize(options, "trace_length")?, public_signals)
+    };
 
-    for i in 0..trace_width {
-        channel_ood_constraint_evaluation.push(BaseElement::new(
-            U256::from_str_radix(pub_inputs[i].as_str().unwrap(), 10).unwrap(),
+    // public signals contain 3 * trace_width elements :
+    //  - trace_width ood_constraint_evaluation
+    //  - 2 * trace_width elements for the OOD trace frame
+    let trace_width = read_usize(options, "trace_width")?;
+    if public_signals.len() != 3 * trace_width {
+        return Err(parsing_error(
+            "public signals",
+            &format!("expected {} public signals", 3 * trace_width),
         ));
     }
 
+    let channel_ood_constraint_evaluation = &public_signals[..trace_width];
+
     let mut frame = EvaluationFrame::new(trace_width);
+    frame
+        .current_mut()
+        .copy_from_slice(&public_signals[trace_width..2 * trace_width]);
+    frame
+        .next_mut()
+        .copy_from_slice(&public_signals[2 * trace_width..]);
 
-    for i in 0..trace_width {
-        frame.current_mut()[i] = BaseElement::new(
-            U256::from_str_radix(pub_inputs[trace_width + i].as_str().unwrap(), 10).unwrap(),
-        );
-        frame.next_mut()[i] = BaseElement::new(
-            U256::from_str_radix(pub_inputs[2 * trace_width + i].as_str().unwrap(), 10).unwrap(),
-        );
-    }
+    // build the actual air from the public inputs and the circuit options,
+    // which are checked beforehand since Winterfell panics on invalid ones
+    let num_queries = read_usize(options, "num_queries")?;
+    let lde_blowup_factor = read_usize(options, "lde_blowup_factor")?;
+    let grinding_factor = read_usize(options, "grinding_factor")? as u32;
+    let fri_folding_factor = read_usize(options, "fri_folding_factor")?;
+    let fri_max_remainder_size = read_usize(options, "fri_max_remainder_size")?;
+    // options written before Merkle trees of higher arity were supported
+    let merkle_arity = options["merkle_arity"]
+        .as_u64()
+        .map_or(2, |arity| arity as usize);
+    let poseidon2 = options["poseidon2"].as_bool().unwrap_or(false);
+    let fri_remainder_coefficients = options["fri_remainder_coefficients"]
+        .as_bool()
+        .unwrap_or(false);
+    let fri_num_partitions = options["fri_num_partitions"]
+        .as_u64()
+        .map_or(1, |num_partitions| num_partitions as usize);
+    validate_options(
+        trace_length,
+        trace_width,
+        num_queries,
+        lde_blowup_factor,
+        grinding_factor,
+        fri_folding_factor,
+        fri_max_remainder_size,
+    )?;
+    validate_merkle_arity(
+        trace_length * lde_blowup_factor,
+        fri_folding_factor,
+        fri_max_remainder_size,
+        merkle_arity,
+    )?;
+    validate_fri_partitions(
+        trace_length * lde_blowup_factor,
+        fri_folding_factor,
+        fri_max_remainder_size,
+        fri_num_partitions,
+    )?;
+    ensure(
+        !poseidon2 || merkle_arity == 2,
+        "Poseidon2 requires a Merkle arity of 2",
+    )?;
+
+    let trace_info = TraceInfo::new(trace_width, trace_length);
+    let proof_options = ProofOptions::new(
+        num_queries,
+        lde_blowup_factor,
+        grinding_factor,
+        hash_function(poseidon2, merkle_arity),
+        FieldExtension::None,
+        fri_folding_factor,
+        fri_max_remainder_size,
+    )
+    .with_fri_partitions(fri_num_partitions);
+    let proof_options = if fri_remainder_coefficients {
+        proof_options.with_fri_remainder_coefficients()
+    } else {
+        proof_options
+    };
+    let air = AIR::new(trace_info, pub_inputs, proof_options);
 
-    // We only need to access the 'evaluate_constraints' method which doesn't depend on the air.
-    // A default implementation of a Workair is sufficient here.
-    let air = AIR::default();
     let mut ood_frame_constraint_evaluation = BaseElement::zeroed_vector(trace_width);
     air.evaluate_transition::<BaseElement>(&frame, &[], &mut ood_frame_constraint_evaluation);
 
-    for i in 0..trace_width {
-        assert!(
-            ood_frame_constraint_evaluation[i] == channel_ood_constraint_evaluation[i],
-            "\x1b[33m{}\x1b[0m",
-            "Proof invalid: OOD not correct!"
-        );
+    Ok(PublicSignalsCheck {
+        public_inputs_match,
+        ood_valid: ood_valid && ood_frame_constraint_evaluation == channel_ood_constraint_evaluation,
+    })
+}
+
+// HELPER FUNCTIONS
+// ===========================================================================
+
+/// Check the values behind the public signals commitment against the
+/// commitment.
+fn commitment_matches(committed_values: &CommittedValues, commitment: BaseElement) -> bool {
+    // the commitment is the hash of the OOD trace frame, the OOD constraint
+    // evaluations and the public inputs, in that order
+    let values = [
+        committed_values.ood_trace_frame[0].as_slice(),
+        &committed_values.ood_trace_frame[1],
+        &committed_values.ood_frame_constraint_evaluation,
+        &committed_values.public_inputs,
+    ]
+    .concat();
+    let digest = Poseidon::<BaseElement>::hash_elements(&values);
+
+    digest.as_element() == commitment
+}
+
+/// Trace length of a variable-length circuit, given the log2 public signal.
+fn trace_length_from_log(
+    log_trace_length: BaseElement,
+    options: &Value,
+) -> Result<usize, WinterCircomError> {
+    let min_trace_length = read_usize(options, "min_trace_length")?;
+    let max_trace_length = read_usize(options, "max_trace_length")?;
+    let merkle_arity = read_usize(options, "merkle_arity").unwrap_or(2);
+
+    // the supported trace lengths are the powers of 2 between both bounds,
+    // stepping by a factor of merkle_arity
+    let mut trace_length = min_trace_length;
+    while trace_length <= max_trace_length {
+        if BaseElement::from(log2(trace_length)) == log_trace_length {
+            return Ok(trace_length);
+        }
+        trace_length *= merkle_arity;
     }
 
-    println!(
-        "\x1b[32m{}\x1b[0m",
-        "OOD constraint evaluations are correct!"
-    );
+    Err(parsing_error("public signals", "trace length out of range"))
+}
+
+fn read_usize(options: &Value, key: &str) -> Result<usize, WinterCircomError> {
+    options[key]
+        .as_u64()
+        .map(|value| value as usize)
+        .ok_or_else(|| parsing_error("options.json", &format!("missing {}", key)))
+}
+
+fn parsing_error(file: &str, comment: &str) -> WinterCircomError {
+    WinterCircomError::ParsingError {
+        file: file.to_owned(),
+        comment: Some(comment.to_owned()),
+    }
 }
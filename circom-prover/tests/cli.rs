@@ -0,0 +1,312 @@
+//! Checks the project config and the command-line arguments of the
+//! command-line tool.
+
+#![cfg(feature = "cli")]
+
+use std::{path::PathBuf, time::Duration};
+
+use clap::Parser;
+use winter_circom_prover::{
+    cli::{Cli, Command, OptionsConfig, ProjectConfig},
+    utils::WinterCircomError,
+    winterfell::HashFunction,
+    Simplification, WinterCircomProofOptions,
+};
+
+fn air_options() -> WinterCircomProofOptions<2> {
+    WinterCircomProofOptions::new(128, 2, 2, 3, [1, 1], 32, 8, 0, 8, 128)
+}
+
+fn load(name: &str, config: &str) -> Result<ProjectConfig, WinterCircomError> {
+    let path = std::env::temp_dir().join(format!(
+        "winter-circom-{}-{}.toml",
+        name,
+        std::process::id()
+    ));
+    std::fs::write(&path, config).unwrap();
+    let config = ProjectConfig::load(&path);
+    let _ = std::fs::remove_file(&path);
+    config
+}
+
+fn overrides(name: &str, options: &str) -> OptionsConfig {
+    load(name, &format!("[options]\n{}", options))
+        .unwrap()
+        .options
+}
+
+// PROJECT CONFIG
+// ===========================================================================
+
+#[test]
+fn empty_config_has_defaults() {
+    let config = load("empty", "").unwrap();
+
+    assert_eq!(config.root, PathBuf::from("."));
+    assert!(!config.solidity_verifier);
+    assert_eq!(config.paths.ptau, "final.ptau");
+    assert_eq!(config.compile.simplification, None);
+    assert!(config.timeouts.stage_timeouts().groth16_prove.is_none());
+    assert!(config.options.trace_length.is_none());
+    assert!(config.inputs.is_empty());
+}
+
+#[test]
+fn config_tables_are_parsed() {
+    let config = load(
+        "tables",
+        r#"
+root = "../.."
+backend = "snarkjs"
+solidity_verifier = true
+
+[paths]
+ptau = "ptau/final.ptau"
+
+[compile]
+simplification = "O1"
+inspect = true
+
+[timeouts]
+compile = 600
+witness = 60
+
+[options]
+trace_length = 256
+max_trace_length = 1024
+
+[inputs]
+start = 3
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(config.root, PathBuf::from("../.."));
+    assert!(config.solidity_verifier);
+    assert_eq!(config.paths.ptau, "ptau/final.ptau");
+
+    let compile_options = config.compile.compile_options();
+    assert_eq!(compile_options.simplification, Some(Simplification::O1));
+    assert!(compile_options.inspect);
+    assert!(!compile_options.wasm);
+
+    let timeouts = config.timeouts.stage_timeouts();
+    assert_eq!(timeouts.compile, Some(Duration::from_secs(600)));
+    assert_eq!(timeouts.witness, Some(Duration::from_secs(60)));
+    assert_eq!(timeouts.setup, None);
+
+    assert_eq!(config.options.trace_length, Some(256));
+    assert_eq!(config.options.max_trace_length, Some(1024));
+    assert_eq!(config.inputs["start"].as_integer(), Some(3));
+}
+
+#[test]
+fn invalid_configs_are_rejected() {
+    for (name, config) in [
+        ("unknown-key", "unknown = 1"),
+        ("unknown-option", "[options]\nnum_querys = 32"),
+        ("unknown-backend", r#"backend = "rapidsnark""#),
+        ("wrong-type", "[options]\ntrace_length = \"128\""),
+        ("not-toml", "[options"),
+    ] {
+        assert!(
+            matches!(
+                load(name, config),
+                Err(WinterCircomError::ParsingError { .. })
+            ),
+            "{} should be rejected",
+            name
+        );
+    }
+
+    assert!(matches!(
+        ProjectConfig::load("missing/winter-circom.toml"),
+        Err(WinterCircomError::IoError { .. })
+    ));
+}
+
+// OPTIONS OVERRIDES
+// ===========================================================================
+
+#[test]
+fn options_are_overridden() {
+    let options = overrides(
+        "overrides",
+        r#"
+trace_length = 256
+num_queries = 16
+lde_blowup_factor = 16
+grinding_factor = 4
+fri_folding_factor = 4
+merkle_arity = 4
+fri_remainder_coefficients = true
+"#,
+    )
+    .apply(air_options())
+    .unwrap();
+    options.validate().unwrap();
+
+    assert_eq!(options.trace_length, 256);
+    let proof_options = options.get_proof_options();
+    assert_eq!(proof_options.num_queries(), 16);
+    assert_eq!(proof_options.blowup_factor(), 16);
+    assert_eq!(proof_options.grinding_factor(), 4);
+    assert_eq!(proof_options.hash_fn(), HashFunction::Poseidon4);
+    assert_eq!(proof_options.to_fri_options().folding_factor(), 4);
+    assert!(proof_options.fri_remainder_coefficients());
+
+    // unset overrides keep the options of the AIR
+    let options = overrides("no-overrides", "").apply(air_options()).unwrap();
+    assert_eq!(options.trace_length, 128);
+    assert_eq!(options.get_proof_options().num_queries(), 32);
+    assert_eq!(
+        options.get_proof_options().hash_fn(),
+        HashFunction::Poseidon
+    );
+}
+
+#[test]
+fn variable_trace_length_is_overridden() {
+    let options = overrides("variable", "max_trace_length = 1024")
+        .apply(air_options())
+        .unwrap();
+    options.validate().unwrap();
+    assert_eq!(options.min_trace_length(), 32);
+
+    // zero keeps the trace length only
+    let options = overrides("fixed", "max_trace_length = 0")
+        .apply(air_options())
+        .unwrap();
+    assert_eq!(options.min_trace_length(), 128);
+}
+
+#[test]
+fn exclusive_modes_are_rejected() {
+    let zero_knowledge = air_options().with_zero_knowledge(4);
+    let commitment = air_options().with_public_signals_commitment();
+
+    for (name, config, options) in [
+        ("zk-variable", "max_trace_length = 1024", &zero_knowledge),
+        (
+            "commitment-variable",
+            "max_trace_length = 1024",
+            &commitment,
+        ),
+        (
+            "zk-commitment",
+            "public_signals_commitment = true",
+            &zero_knowledge,
+        ),
+    ] {
+        assert!(
+            matches!(
+                overrides(name, config).apply(options.clone()),
+                Err(WinterCircomError::ParsingError { .. })
+            ),
+            "{} should be rejected",
+            name
+        );
+    }
+
+    // disabling a mode is always accepted
+    overrides("zk-no-commitment", "public_signals_commitment = false")
+        .apply(zero_knowledge.clone())
+        .unwrap();
+    overrides("zk-fixed", "max_trace_length = 0")
+        .apply(zero_knowledge)
+        .unwrap();
+}
+
+#[test]
+fn unsupported_overrides_fail_validation() {
+    for (name, config) in [
+        ("arity", "merkle_arity = 3"),
+        ("trace-length", "trace_length = 100"),
+        ("max-trace-length", "max_trace_length = 1000"),
+        ("partitions", "fri_num_partitions = 3"),
+    ] {
+        let options = overrides(name, config).apply(air_options()).unwrap();
+        assert!(
+            matches!(
+                options.validate(),
+                Err(WinterCircomError::InvalidOptions(_))
+            ),
+            "{} should be rejected",
+            name
+        );
+    }
+}
+
+// COMMAND-LINE ARGUMENTS
+// ===========================================================================
+
+#[test]
+fn arguments_are_parsed() {
+    let cli = Cli::try_parse_from(["winter-circom", "prove"]).unwrap();
+    assert_eq!(cli.config, PathBuf::from("winter-circom.toml"));
+    assert_eq!(cli.verbose, 0);
+    assert!(!cli.quiet);
+    assert_eq!(cli.command, Command::Prove);
+
+    let cli = Cli::try_parse_from([
+        "winter-circom",
+        "-vv",
+        "--config",
+        "project.toml",
+        "contribute",
+        "--name",
+        "alice",
+    ])
+    .unwrap();
+    assert_eq!(cli.config, PathBuf::from("project.toml"));
+    assert_eq!(cli.verbose, 2);
+    assert_eq!(
+        cli.command,
+        Command::Contribute {
+            name: String::from("alice")
+        }
+    );
+
+    let cli = Cli::try_parse_from(["winter-circom", "-q", "contribute"]).unwrap();
+    assert!(cli.quiet);
+    assert_eq!(
+        cli.command,
+        Command::Contribute {
+            name: String::from("winter-circom")
+        }
+    );
+}
+
+#[cfg(feature = "server")]
+#[test]
+fn serve_arguments_are_parsed() {
+    let cli = Cli::try_parse_from(["winter-circom", "serve"]).unwrap();
+    assert_eq!(
+        cli.command,
+        Command::Serve {
+            address: String::from("127.0.0.1:8080"),
+            workers: 1
+        }
+    );
+
+    let cli = Cli::try_parse_from(["winter-circom", "serve", "--workers", "4"]).unwrap();
+    assert!(matches!(cli.command, Command::Serve { workers: 4, .. }));
+    assert!(Cli::try_parse_from(["winter-circom", "serve", "--workers", "many"]).is_err());
+}
+
+#[test]
+fn invalid_arguments_are_rejected() {
+    for args in [
+        &["winter-circom"][..],
+        &["winter-circom", "deploy"],
+        &["winter-circom", "-v", "-q", "prove"],
+        &["winter-circom", "prove", "--name", "alice"],
+        &["winter-circom", "--config"],
+    ] {
+        assert!(
+            Cli::try_parse_from(args).is_err(),
+            "{:?} should be rejected",
+            args
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! Checks the parsing of the warnings of the Circom compiler into diagnostics.
+
+use winter_circom_prover::parse_diagnostics;
+
+const OUTPUT: &str = r#"template instances: 412
+warning[CA01]: In template "Verify(2)": Local signal unused does not appear in any constraint
+   ┌─ "../../../circuits/verify.circom":42:5
+   │
+42 │     signal unused;
+   │     ^^^^^^^^^^^^^ The value assigned to `unused` is not used in any constraint.
+
+warning[CA02]: In template "Main": Subcomponent input/output signal check.out does not appear in any constraint of the father component
+   = For details, execute circom with --inspect.
+
+warning: In template "Main": unused template argument
+non-linear constraints: 1024
+Written successfully: ./verifier.r1cs
+"#;
+
+#[test]
+fn under_constrained_signals() {
+    let diagnostics = parse_diagnostics(OUTPUT);
+    assert_eq!(diagnostics.len(), 3);
+
+    let local = &diagnostics[0];
+    assert_eq!(local.code.as_deref(), Some("CA01"));
+    assert_eq!(local.template.as_deref(), Some("Verify(2)"));
+    assert_eq!(local.signal.as_deref(), Some("unused"));
+    assert_eq!(
+        local.location.as_deref(),
+        Some("\"../../../circuits/verify.circom\":42:5")
+    );
+    assert!(local.is_under_constrained());
+
+    let subcomponent = &diagnostics[1];
+    assert_eq!(subcomponent.code.as_deref(), Some("CA02"));
+    assert_eq!(subcomponent.template.as_deref(), Some("Main"));
+    assert_eq!(subcomponent.signal.as_deref(), Some("check.out"));
+    assert_eq!(subcomponent.location, None);
+    assert!(subcomponent.is_under_constrained());
+
+    let other = &diagnostics[2];
+    assert_eq!(other.code, None);
+    assert_eq!(other.template.as_deref(), Some("Main"));
+    assert_eq!(other.signal, None);
+    assert!(!other.is_under_constrained());
+}
+
+#[test]
+fn colored_output() {
+    let output = "\u{1b}[1;33mwarning[CA01]\u{1b}[0m\u{1b}[1m: In template \"Main\": \
+        Local signal x does not appear in any constraint\u{1b}[0m\n";
+    let diagnostics = parse_diagnostics(output);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].signal.as_deref(), Some("x"));
+    assert!(diagnostics[0].is_under_constrained());
+}
+
+#[test]
+fn no_warnings() {
+    assert!(parse_diagnostics("Everything went okay\n").is_empty());
+}
@@ -0,0 +1,168 @@
+//! Checks the degree adjustment of the transition constraints in the OOD
+//! consistency check of the circuit against the one of Winterfell.
+//!
+//! The combination of the transition constraint evaluations computed by the
+//! `OodConsistencyCheck` template of `circuits/ood_consistency_check.circom` is
+//! mirrored here, and compared with the combination computed by Winterfell for
+//! the same evaluations and composition coefficients.
+
+use winter_circom_prover::winterfell::{
+    math::{fields::f256::BaseElement, FieldElement},
+    Air, AirContext, Assertion, EvaluationFrame, FieldExtension, HashFunction, ProofOptions,
+    TraceInfo, TransitionConstraintDegree,
+};
+
+const TRACE_LENGTH: usize = 128;
+
+#[test]
+fn transition_degree_adjustment() {
+    for num_transition_exemptions in [1, 2] {
+        let air = DegreeAir::with_degrees(TRACE_LENGTH, &[1, 2], num_transition_exemptions);
+        let (coefficients, evaluations, z) = random_values(2);
+        let expected = air
+            .get_transition_constraints(&coefficients)
+            .combine_evaluations::<BaseElement>(&evaluations, &[], z);
+
+        // composition degree + divisor degree - evaluation degree
+        let result = circuit_transition_result(&air, &coefficients, &evaluations, z, |degree| {
+            air.ce_domain_size() - 1 + TRACE_LENGTH
+                - num_transition_exemptions
+                - degree * (TRACE_LENGTH - 1)
+        });
+        assert_eq!(expected, result);
+
+        // the former exponent only matched for constraints of degree 1
+        let result = circuit_transition_result(&air, &coefficients, &evaluations, z, |degree| {
+            air.ce_domain_size() - degree
+        });
+        assert_ne!(expected, result);
+    }
+}
+
+// HELPER FUNCTIONS
+// ===========================================================================
+
+/// Combination of the transition constraint `evaluations` at `z`, divided by
+/// the transition divisor, as computed by the circuit with the degree
+/// adjustment exponent of each constraint given by `exponent`.
+fn circuit_transition_result(
+    air: &DegreeAir,
+    coefficients: &[(BaseElement, BaseElement)],
+    evaluations: &[BaseElement],
+    z: BaseElement,
+    exponent: impl Fn(usize) -> usize,
+) -> BaseElement {
+    let g_trace = air.trace_domain_generator();
+    let exemptions_product = (0..air.context().num_transition_exemptions())
+        .map(|i| z - g_trace.exp(((TRACE_LENGTH - 1 - i) as u64).into()))
+        .fold(BaseElement::ONE, |product, factor| product * factor);
+    let transition_divisor =
+        (z.exp((TRACE_LENGTH as u64).into()) - BaseElement::ONE) / exemptions_product;
+
+    let evaluation_result = air
+        .degrees
+        .iter()
+        .zip(coefficients)
+        .zip(evaluations)
+        .map(|((&degree, &(c0, c1)), &evaluation)| {
+            (c0 + c1 * z.exp((exponent(degree) as u64).into())) * evaluation
+        })
+        .fold(BaseElement::ZERO, |sum, term| sum + term);
+
+    evaluation_result / transition_divisor
+}
+
+/// Pseudo-random composition coefficients and constraint evaluations for `n`
+/// transition constraints, and an OOD point.
+fn random_values(
+    n: usize,
+) -> (
+    Vec<(BaseElement, BaseElement)>,
+    Vec<BaseElement>,
+    BaseElement,
+) {
+    let mut values = (1u64..).map(|i| BaseElement::from(i.wrapping_mul(0x9e37_79b9_7f4a_7c15)));
+    let coefficients = (0..n)
+        .map(|_| (values.next().unwrap(), values.next().unwrap()))
+        .collect();
+    let evaluations = (0..n).map(|_| values.next().unwrap()).collect();
+
+    (coefficients, evaluations, values.next().unwrap())
+}
+
+// TEST AIR
+// ===========================================================================
+
+/// AIR with transition constraints of the given degrees, whose evaluations are
+/// only combined by this test.
+struct DegreeAir {
+    context: AirContext<BaseElement>,
+    degrees: Vec<usize>,
+}
+
+impl DegreeAir {
+    fn with_degrees(
+        trace_length: usize,
+        degrees: &[usize],
+        num_transition_exemptions: usize,
+    ) -> Self {
+        let options = ProofOptions::new(
+            32,
+            8,
+            0,
+            HashFunction::Poseidon,
+            FieldExtension::None,
+            8,
+            128,
+        );
+        let context = AirContext::new(
+            TraceInfo::new(2, trace_length),
+            degrees
+                .iter()
+                .map(|&degree| TransitionConstraintDegree::new(degree))
+                .collect(),
+            1,
+            options,
+        )
+        .set_num_transition_exemptions(num_transition_exemptions);
+
+        Self {
+            context,
+            degrees: degrees.to_vec(),
+        }
+    }
+}
+
+impl Air for DegreeAir {
+    type BaseField = BaseElement;
+    type PublicInputs = ();
+
+    fn new(trace_info: TraceInfo, _pub_inputs: (), options: ProofOptions) -> Self {
+        let context = AirContext::new(
+            trace_info,
+            vec![TransitionConstraintDegree::new(1)],
+            1,
+            options,
+        );
+        Self {
+            context,
+            degrees: vec![1],
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        _frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        _result: &mut [E],
+    ) {
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        vec![Assertion::single(0, 0, BaseElement::ZERO)]
+    }
+}
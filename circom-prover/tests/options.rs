@@ -0,0 +1,203 @@
+//! Checks that unsupported proof options are reported as errors, before they
+//! reach the assertions of Winterfell.
+
+use winter_circom_prover::{
+    utils::WinterCircomError, winterfell::HashFunction, WinterCircomProofOptions,
+};
+
+fn options(trace_length: usize, fri_max_remainder_size: usize) -> WinterCircomProofOptions<2> {
+    folded_options(trace_length, 8, fri_max_remainder_size)
+}
+
+fn folded_options(
+    trace_length: usize,
+    fri_folding_factor: usize,
+    fri_max_remainder_size: usize,
+) -> WinterCircomProofOptions<2> {
+    WinterCircomProofOptions::new(
+        trace_length,
+        2,
+        2,
+        3,
+        [1, 1],
+        32,
+        8,
+        0,
+        fri_folding_factor,
+        fri_max_remainder_size,
+    )
+}
+
+#[test]
+fn valid_options() {
+    assert!(options(128, 128).validate().is_ok());
+    assert!(options(128, 256).with_zero_knowledge(34).validate().is_ok());
+}
+
+#[test]
+fn invalid_options() {
+    for invalid in [
+        // trace length is not a power of 2
+        options(100, 128),
+        // FRI max remainder size is too large for the circuit
+        options(16, 128),
+        // FRI max remainder size is not supported by Winterfell
+        options(128, 2048),
+        // blinding rows exceed half the trace
+        options(128, 128).with_zero_knowledge(64),
+    ] {
+        assert!(matches!(
+            invalid.validate(),
+            Err(WinterCircomError::InvalidOptions(_))
+        ));
+    }
+}
+
+#[test]
+fn variable_trace_length() {
+    let variable = options(128, 128).with_max_trace_length(1024);
+    assert!(variable.validate().is_ok());
+    assert_eq!(variable.min_trace_length(), 32);
+    assert_eq!(options(128, 128).min_trace_length(), 128);
+
+    for invalid in [
+        // maximum trace length is not a power of 2
+        options(128, 128).with_max_trace_length(1000),
+        // maximum trace length is smaller than the trace length
+        options(128, 128).with_max_trace_length(64),
+        // trace length is smaller than the minimum supported trace length
+        options(8, 32).with_max_trace_length(1024),
+    ] {
+        assert!(matches!(
+            invalid.validate(),
+            Err(WinterCircomError::InvalidOptions(_))
+        ));
+    }
+}
+
+#[test]
+#[should_panic(expected = "mutually exclusive")]
+fn variable_trace_length_zero_knowledge() {
+    let _ = options(128, 256)
+        .with_zero_knowledge(34)
+        .with_max_trace_length(1024);
+}
+
+#[test]
+fn merkle_arity() {
+    // LDE domain of 8^3 elements, folded once into a remainder of 64 elements
+    assert!(options(64, 128).with_merkle_arity(8).validate().is_ok());
+    // LDE domain of 4^5 elements, folded once into a remainder of 64 elements
+    assert!(folded_options(128, 16, 128)
+        .with_merkle_arity(4)
+        .validate()
+        .is_ok());
+
+    for invalid in [
+        // arity is not supported
+        options(128, 128).with_merkle_arity(3),
+        // FRI folding factor is not a power of the arity
+        options(128, 128).with_merkle_arity(4),
+        // LDE domain size is not a power of the arity
+        options(128, 128).with_merkle_arity(8),
+        // FRI remainder holds less than arity leaves
+        folded_options(128, 16, 32).with_merkle_arity(4),
+    ] {
+        assert!(matches!(
+            invalid.validate(),
+            Err(WinterCircomError::InvalidOptions(_))
+        ));
+    }
+}
+
+#[test]
+fn variable_trace_length_merkle_arity() {
+    // supported trace lengths of 128, 512 and 2048, whose LDE domains are
+    // powers of 4, the trace length of 32 leaving no FRI layer
+    let variable = folded_options(128, 16, 256)
+        .with_max_trace_length(2048)
+        .with_merkle_arity(4);
+    assert!(variable.validate().is_ok());
+    assert_eq!(variable.min_trace_length(), 128);
+
+    // the maximum trace length differs from the trace length by a factor of 8
+    assert!(matches!(
+        folded_options(128, 16, 256)
+            .with_max_trace_length(1024)
+            .with_merkle_arity(4)
+            .validate(),
+        Err(WinterCircomError::InvalidOptions(_))
+    ));
+}
+
+#[test]
+fn poseidon2() {
+    let options = options(128, 128).with_poseidon2();
+    assert!(options.validate().is_ok());
+    assert_eq!(
+        options.get_proof_options().hash_fn(),
+        HashFunction::Poseidon2
+    );
+    assert!(options.with_max_trace_length(1024).validate().is_ok());
+}
+
+#[test]
+#[should_panic(expected = "binary Merkle trees")]
+fn poseidon2_merkle_arity() {
+    let _ = options(64, 128).with_poseidon2().with_merkle_arity(8);
+}
+
+#[test]
+fn fri_remainder_coefficients() {
+    let options = options(128, 128).with_fri_remainder_coefficients();
+    assert!(options.validate().is_ok());
+    assert!(options
+        .get_proof_options()
+        .to_fri_options()
+        .remainder_coefficients());
+}
+
+#[test]
+fn variable_trace_length_fri_remainder_coefficients() {
+    let variable = options(128, 128)
+        .with_max_trace_length(1024)
+        .with_fri_remainder_coefficients();
+    assert!(variable.validate().is_ok());
+    assert_eq!(variable.min_trace_length(), 32);
+}
+
+#[test]
+fn fri_partitions() {
+    let partitioned = options(128, 128).with_fri_partitions(4);
+    assert!(partitioned.validate().is_ok());
+    assert_eq!(partitioned.get_proof_options().fri_num_partitions(), 4);
+
+    // not a power of 2, larger than the FRI remainder
+    for num_partitions in [3, 256] {
+        assert!(matches!(
+            options(128, 128)
+                .with_fri_partitions(num_partitions)
+                .validate(),
+            Err(WinterCircomError::InvalidOptions(_))
+        ));
+    }
+}
+
+#[test]
+fn variable_trace_length_fri_partitions() {
+    // the FRI remainders of the supported trace lengths hold at least 32 elements
+    let variable = options(128, 128)
+        .with_max_trace_length(1024)
+        .with_fri_partitions(32);
+    assert!(variable.validate().is_ok());
+    assert_eq!(variable.min_trace_length(), 32);
+
+    // the FRI remainder of a trace length of 256 holds 32 elements
+    assert!(matches!(
+        options(128, 128)
+            .with_max_trace_length(1024)
+            .with_fri_partitions(64)
+            .validate(),
+        Err(WinterCircomError::InvalidOptions(_))
+    ));
+}
@@ -0,0 +1,92 @@
+//! Checks that the Poseidon and Poseidon2 parameter generators reproduce the
+//! parameter files of the Rust hashers and of the Circom circuits, and the
+//! numbers of rounds of the reference implementation.
+
+use winter_circom_prover::{utils::WinterCircomError, Poseidon2Parameters, PoseidonParameters};
+
+const BN_254: &str =
+    "21888242871839275222246405745257275088548364400416034343698204186575808495617";
+
+#[test]
+fn parameter_files() {
+    // the numbers of rounds of the widths of the Merkle tree compressions are
+    // those of the security level
+    for width in [6, 10] {
+        assert_eq!(
+            PoseidonParameters::round_numbers(BN_254, width, 5, 128).unwrap(),
+            (8, 57)
+        );
+    }
+    let parameters = [
+        PoseidonParameters::generate(BN_254, 4, 5, 8, 58).unwrap(),
+        PoseidonParameters::generate(BN_254, 6, 5, 8, 57).unwrap(),
+        PoseidonParameters::generate(BN_254, 10, 5, 8, 57).unwrap(),
+    ];
+    let rust_files = [
+        include_str!("../../winterfell/crypto/src/hash/poseidon/param.rs"),
+        include_str!("../../winterfell/crypto/src/hash/poseidon/param_t6.rs"),
+        include_str!("../../winterfell/crypto/src/hash/poseidon/param_t10.rs"),
+    ];
+    for (params, rust_file) in parameters.iter().zip(rust_files) {
+        assert_eq!(params.to_rust().unwrap(), rust_file);
+    }
+    assert_eq!(
+        PoseidonParameters::to_circom(&parameters).unwrap(),
+        include_str!("../../circuits/poseidon/param.circom")
+    );
+}
+
+#[test]
+fn poseidon2_parameter_files() {
+    let parameters = Poseidon2Parameters::generate(BN_254, 5, 8, 56).unwrap();
+    assert_eq!(
+        parameters.to_rust().unwrap(),
+        include_str!("../../winterfell/crypto/src/hash/poseidon2/param.rs")
+    );
+    assert_eq!(
+        parameters.to_circom().unwrap(),
+        include_str!("../../circuits/poseidon2/param.circom")
+    );
+}
+
+#[test]
+fn round_numbers() {
+    // numbers of partial rounds of circomlib, rounded up to a multiple of the
+    // width
+    let partial_rounds = [
+        56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65, 70, 60, 64, 68,
+    ];
+    for (width, expected) in (2..).zip(partial_rounds) {
+        let (num_full_rounds, num_partial_rounds) =
+            PoseidonParameters::round_numbers(BN_254, width, 5, 128).unwrap();
+        assert_eq!(num_full_rounds, 8);
+        assert_eq!(num_partial_rounds.div_ceil(width) * width, expected);
+    }
+}
+
+#[test]
+fn invalid_parameters() {
+    let invalid = |result: Result<PoseidonParameters, WinterCircomError>| {
+        assert!(matches!(result, Err(WinterCircomError::InvalidOptions(_))))
+    };
+
+    // not a prime number
+    invalid(PoseidonParameters::generate("0x100", 4, 5, 8, 58));
+    invalid(PoseidonParameters::generate("abc", 4, 5, 8, 58));
+    // alpha is not coprime with p - 1
+    invalid(PoseidonParameters::generate(BN_254, 4, 3, 8, 58));
+    invalid(PoseidonParameters::generate(BN_254, 1, 5, 8, 58));
+    invalid(PoseidonParameters::generate(BN_254, 4, 5, 7, 58));
+    invalid(PoseidonParameters::generate(BN_254, 4, 5, 8, 0));
+
+    // the Rust hasher works over the f256 field only
+    let parameters = PoseidonParameters::generate("18446744073709551359", 3, 5, 8, 22).unwrap();
+    assert!(parameters.to_rust().is_err());
+    assert!(PoseidonParameters::to_circom(std::slice::from_ref(&parameters)).is_ok());
+
+    // the Circom parameters must have distinct widths and the same modulus
+    assert!(PoseidonParameters::to_circom(&[]).is_err());
+    let bn_254 = PoseidonParameters::generate(BN_254, 3, 5, 8, 57).unwrap();
+    assert!(PoseidonParameters::to_circom(&[bn_254.clone(), bn_254.clone()]).is_err());
+    assert!(PoseidonParameters::to_circom(&[bn_254, parameters]).is_err());
+}
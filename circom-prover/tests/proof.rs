@@ -0,0 +1,69 @@
+//! Checks that the typed proof and public signals round-trip through the JSON
+//! formats of SnarkJS, and that they are encoded into the Solidity calldata.
+
+use serde_json::json;
+use winter_circom_prover::{
+    groth16_calldata,
+    winterfell::math::fields::f256::{BaseElement, U256},
+    CommittedValues, Groth16Proof, PublicSignals,
+};
+
+fn proof() -> Groth16Proof {
+    let words = (1..=8u64).map(U256::from).collect::<Vec<_>>();
+    Groth16Proof {
+        pi_a: [words[0], words[1]],
+        pi_b: [[words[2], words[3]], [words[4], words[5]]],
+        pi_c: [words[6], words[7]],
+    }
+}
+
+fn elements(range: std::ops::Range<u64>) -> Vec<BaseElement> {
+    range.map(BaseElement::from).collect()
+}
+
+#[test]
+fn proof_json_round_trip() {
+    let json = proof().to_json();
+
+    assert_eq!(json["pi_a"], json!(["1", "2", "1"]));
+    assert_eq!(json["pi_b"], json!([["3", "4"], ["5", "6"], ["1", "0"]]));
+    assert_eq!(json["protocol"], "groth16");
+    assert_eq!(Groth16Proof::from_json(&json).unwrap(), proof());
+}
+
+#[test]
+fn public_signals_json_round_trip() {
+    let public_signals = PublicSignals {
+        signals: elements(0..1),
+        committed_values: Some(CommittedValues {
+            ood_frame_constraint_evaluation: elements(1..3),
+            ood_trace_frame: [elements(3..5), elements(5..7)],
+            public_inputs: elements(7..9),
+        }),
+    };
+
+    let signals = public_signals.to_json();
+    let values = public_signals.committed_values.as_ref().unwrap().to_json();
+
+    assert_eq!(signals, json!(["0"]));
+    assert_eq!(
+        PublicSignals::from_json(&signals, Some(&values)).unwrap(),
+        public_signals
+    );
+}
+
+#[test]
+fn calldata_encodes_proof_and_public_signals() {
+    let public_signals = PublicSignals {
+        signals: elements(9..12),
+        committed_values: None,
+    };
+    let calldata = groth16_calldata(&proof(), &public_signals);
+
+    // selector, 8 proof words and 3 public signals
+    assert_eq!(calldata.len(), 4 + 11 * 32);
+    // the coordinates of the G2 point are swapped
+    assert_eq!(calldata[4 + 3 * 32 - 1], 4);
+    assert_eq!(calldata[4 + 4 * 32 - 1], 3);
+    assert_eq!(calldata[calldata.len() - 1], 11);
+}
@@ -0,0 +1,100 @@
+//! Checks that the code generated by the `WinterPublicInputs` derive macro
+//! flattens fields consistently across the number of public inputs and both
+//! serializations, and checks them against the layout of the proof options.
+
+use winter_circom_prover::{
+    __private::check_public_inputs_layout,
+    winterfell::{
+        math::{fields::f256::BaseElement, FieldElement},
+        Serializable,
+    },
+    WinterCircomProofOptions, WinterPublicInputs,
+};
+
+const PROOF_OPTIONS: WinterCircomProofOptions<2> =
+    WinterCircomProofOptions::new(128, 2, 8, 3, [1, 1], 32, 8, 0, 8, 128);
+
+#[derive(Clone, Default, WinterPublicInputs)]
+struct Bounds {
+    start: BaseElement,
+    end: BaseElement,
+}
+
+#[derive(Clone, Default, WinterPublicInputs)]
+#[winter_circom(options = PROOF_OPTIONS)]
+struct PublicInputs {
+    seed: BaseElement,
+    bounds: [Bounds; 2],
+    digest: [BaseElement; 3],
+}
+
+fn public_inputs() -> PublicInputs {
+    let elements = (1..=8u64).map(BaseElement::from).collect::<Vec<_>>();
+    PublicInputs {
+        seed: elements[0],
+        bounds: [
+            Bounds {
+                start: elements[1],
+                end: elements[2],
+            },
+            Bounds {
+                start: elements[3],
+                end: elements[4],
+            },
+        ],
+        digest: [elements[5], elements[6], elements[7]],
+    }
+}
+
+#[test]
+fn num_pub_inputs() {
+    assert_eq!(Bounds::NUM_PUB_INPUTS, 2);
+    assert_eq!(PublicInputs::NUM_PUB_INPUTS, 8);
+}
+
+#[test]
+fn serialize_flattens_fields_in_order() {
+    let json = serde_json::to_value(public_inputs()).unwrap();
+    let expected = (1..=8u64)
+        .map(|i| serde_json::to_value(BaseElement::from(i)).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(json.as_array().unwrap(), &expected);
+}
+
+#[test]
+fn write_into_matches_serialize() {
+    let bytes = public_inputs().to_bytes();
+    let expected = (1..=8u64)
+        .flat_map(|i| BaseElement::from(i).to_bytes())
+        .collect::<Vec<_>>();
+
+    assert_eq!(bytes.len(), PublicInputs::NUM_PUB_INPUTS * BaseElement::ELEMENT_BYTES);
+    assert_eq!(bytes, expected);
+}
+
+#[test]
+fn layout_matches_proof_options() {
+    assert_eq!(
+        check_public_inputs_layout(&PROOF_OPTIONS, PublicInputs::NUM_PUB_INPUTS),
+        8
+    );
+    assert_eq!(
+        check_public_inputs_layout(&PROOF_OPTIONS.with_zero_knowledge(34), 8),
+        8
+    );
+}
+
+#[test]
+#[should_panic(expected = "do not match the number of public inputs")]
+fn layout_mismatch_is_rejected() {
+    check_public_inputs_layout(&PROOF_OPTIONS, Bounds::NUM_PUB_INPUTS);
+}
+
+#[test]
+#[should_panic(expected = "requires at least one public input")]
+fn zero_knowledge_without_public_inputs_is_rejected() {
+    let mut options = PROOF_OPTIONS.with_zero_knowledge(34);
+    options.num_public_inputs = 0;
+    check_public_inputs_layout(&options, 0);
+}
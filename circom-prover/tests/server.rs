@@ -0,0 +1,248 @@
+//! Checks the job queue of the proving service on localhost, with a stub job
+//! runner in place of the Groth16 toolchain.
+
+#![cfg(feature = "server")]
+
+use std::{
+    io::{Read, Write},
+    net::{SocketAddr, TcpStream},
+    path::{Path, PathBuf},
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+
+use serde_json::{json, Value};
+use winter_circom_prover::{
+    server::ProvingServer,
+    utils::{ExecutionControl, StageTimeouts, WinterCircomError},
+    winterfell::math::fields::f256::{BaseElement, U256},
+    Groth16Proof, PublicSignals,
+};
+
+fn runner(
+    document: &Value,
+    control: &ExecutionControl,
+) -> Result<(Groth16Proof, PublicSignals), WinterCircomError> {
+    // blocking jobs only complete once cancelled
+    if document["panic"] == true {
+        panic!("runner panicked");
+    }
+    if document["block"] == true {
+        while !control.cancellation.is_cancelled() {
+            thread::sleep(Duration::from_millis(1));
+        }
+        return Err(WinterCircomError::Cancelled);
+    }
+
+    let start = document["start"]
+        .as_u64()
+        .ok_or(WinterCircomError::ParsingError {
+            file: String::from("job"),
+            comment: Some(String::from("missing start")),
+        })?;
+
+    let word = U256::from(start);
+    Ok((
+        Groth16Proof {
+            pi_a: [word; 2],
+            pi_b: [[word; 2]; 2],
+            pi_c: [word; 2],
+        },
+        PublicSignals {
+            signals: vec![BaseElement::from(start)],
+            committed_values: None,
+        },
+    ))
+}
+
+fn start(directory: &Path) -> SocketAddr {
+    let server = Arc::new(
+        ProvingServer::start(
+            "127.0.0.1:0",
+            directory.to_path_buf(),
+            2,
+            StageTimeouts::default(),
+            runner,
+        )
+        .unwrap(),
+    );
+    let address = server.address();
+    thread::spawn(move || server.run());
+    address
+}
+
+fn request(address: SocketAddr, method: &str, path: &str, body: &str) -> (u16, Value) {
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+        method,
+        path,
+        body.len(),
+        body
+    )
+    .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let code = response[9..12].parse().unwrap();
+    let body = response.split("\r\n\r\n").nth(1).unwrap();
+    (code, serde_json::from_str(body).unwrap())
+}
+
+fn wait(address: SocketAddr, id: &str) -> Value {
+    for _ in 0..100 {
+        let (_, status) = request(address, "GET", &format!("/jobs/{}", id), "");
+        if ["done", "failed", "cancelled"].contains(&status["status"].as_str().unwrap()) {
+            return status;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    panic!("job {} did not complete", id);
+}
+
+fn directory(name: &str) -> PathBuf {
+    let directory =
+        std::env::temp_dir().join(format!("winter-circom-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&directory);
+    directory
+}
+
+#[test]
+fn jobs_are_proven_and_failures_reported() {
+    let address = start(&directory("server"));
+
+    let (code, submitted) = request(address, "POST", "/jobs", r#"{"start": 3}"#);
+    assert_eq!(code, 202);
+    let id = submitted["id"].as_str().unwrap();
+    assert_eq!(wait(address, id)["status"], "done");
+
+    let (code, bundle) = request(address, "GET", &format!("/jobs/{}/proof", id), "");
+    assert_eq!(code, 200);
+    assert_eq!(bundle["proof"]["pi_a"], json!(["3", "3", "1"]));
+    assert_eq!(bundle["public"], json!(["3"]));
+    assert_eq!(bundle["public_values"], Value::Null);
+
+    let (_, submitted) = request(address, "POST", "/jobs", "{}");
+    let id = submitted["id"].as_str().unwrap();
+    let status = wait(address, id);
+    assert_eq!(status["status"], "failed");
+    let error = status["error"].as_str().unwrap();
+    assert!(error.contains("missing start"));
+    // the error is not colored for the terminal
+    assert!(!error.contains('\u{1b}'));
+    assert_eq!(
+        request(address, "GET", &format!("/jobs/{}/proof", id), "").0,
+        409
+    );
+
+    assert_eq!(request(address, "GET", "/jobs/42", "").0, 404);
+    assert_eq!(request(address, "POST", "/jobs", "not json").0, 400);
+}
+
+#[test]
+fn jobs_are_cancelled_independently() {
+    let address = start(&directory("cancel"));
+    let submit = |body: &str| {
+        let (_, submitted) = request(address, "POST", "/jobs", body);
+        submitted["id"].as_str().unwrap().to_owned()
+    };
+    let status =
+        |id: &str| request(address, "GET", &format!("/jobs/{}", id), "").1["status"].clone();
+    let cancel = |id: &str| request(address, "DELETE", &format!("/jobs/{}", id), "").0;
+
+    // both workers are busy with blocking jobs, the last job stays queued
+    let first = submit(r#"{"block": true}"#);
+    let second = submit(r#"{"block": true}"#);
+    let queued = submit(r#"{"start": 4}"#);
+    loop {
+        if status(&first) == "running" && status(&second) == "running" {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    assert_eq!(cancel(&queued), 200);
+    assert_eq!(status(&queued), "cancelled");
+
+    assert_eq!(cancel(&first), 202);
+    assert_eq!(wait(address, &first)["status"], "cancelled");
+    assert_eq!(
+        request(address, "GET", &format!("/jobs/{}/proof", first), "").0,
+        409
+    );
+
+    // the other jobs are not cancelled along with the first one
+    let next = submit(r#"{"start": 6}"#);
+    assert_eq!(wait(address, &next)["status"], "done");
+    assert_eq!(status(&second), "running");
+    assert_eq!(status(&queued), "cancelled");
+
+    assert_eq!(cancel(&second), 202);
+    assert_eq!(wait(address, &second)["status"], "cancelled");
+
+    // completed jobs can no longer be cancelled
+    assert_eq!(cancel(&next), 409);
+    assert_eq!(cancel("42"), 404);
+}
+
+#[test]
+fn panicking_jobs_fail_without_stopping_workers() {
+    let address = start(&directory("panic"));
+
+    // more panicking jobs than workers
+    let panicked = (0..3)
+        .map(|_| {
+            let (_, submitted) = request(address, "POST", "/jobs", r#"{"panic": true}"#);
+            submitted["id"].as_str().unwrap().to_owned()
+        })
+        .collect::<Vec<_>>();
+    for id in &panicked {
+        let status = wait(address, id);
+        assert_eq!(status["status"], "failed");
+        assert!(status["error"]
+            .as_str()
+            .unwrap()
+            .contains("runner panicked"));
+    }
+
+    // both workers are still running
+    let (_, first) = request(address, "POST", "/jobs", r#"{"block": true}"#);
+    let (_, second) = request(address, "POST", "/jobs", r#"{"start": 2}"#);
+    let second = second["id"].as_str().unwrap();
+    assert_eq!(wait(address, second)["status"], "done");
+    let first = first["id"].as_str().unwrap();
+    loop {
+        let (_, status) = request(address, "GET", &format!("/jobs/{}", first), "");
+        if status["status"] == "running" {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    request(address, "DELETE", &format!("/jobs/{}", first), "");
+}
+
+#[test]
+fn jobs_survive_restarts() {
+    let directory = directory("restart");
+
+    let address = start(&directory);
+    let (_, submitted) = request(address, "POST", "/jobs", r#"{"start": 5}"#);
+    let done = submitted["id"].as_str().unwrap().to_owned();
+    wait(address, &done);
+
+    // a job interrupted while running is resumed on restart
+    std::fs::create_dir_all(directory.join("1")).unwrap();
+    std::fs::write(directory.join("1/job.json"), r#"{"start": 7}"#).unwrap();
+    std::fs::write(directory.join("1/status.json"), r#"{"status": "running"}"#).unwrap();
+
+    let address = start(&directory);
+    let (_, bundle) = request(address, "GET", &format!("/jobs/{}/proof", done), "");
+    assert_eq!(bundle["public"], json!(["5"]));
+    assert_eq!(wait(address, "1")["status"], "done");
+
+    // new jobs do not overwrite persisted ones
+    let (_, submitted) = request(address, "POST", "/jobs", r#"{"start": 9}"#);
+    assert_eq!(submitted["id"], "2");
+}
@@ -0,0 +1,122 @@
+//! Deploys the Solidity verifier exported by `circom_compile` in an embedded
+//! EVM, and calls it with the proof generated by `circom_prove`.
+//!
+//! This test requires the `solc` compiler to be installed, and the `compile`,
+//! `setup` and `prove` commands of the `sum` example to have been run
+//! beforehand:
+//!
+//! ```bash
+//! cargo run --release -p example-sum -- -c examples/sum/winter-circom.toml compile
+//! cargo run --release -p example-sum -- -c examples/sum/winter-circom.toml setup
+//! cargo run --release -p example-sum -- -c examples/sum/winter-circom.toml prove
+//! cargo test --release -p winter-circom-prover --test solidity -- --ignored --nocapture
+//! ```
+
+use std::{env, path::Path, process::Command};
+
+use revm::{
+    primitives::{hex, Address, ExecutionResult, Output, TxKind},
+    Evm, InMemoryDB,
+};
+use serde_json::Value;
+use winter_circom_prover::solidity_calldata;
+
+const CIRCUIT_NAME: &str = "sum";
+const GAS_LIMIT: u64 = 30_000_000;
+
+#[test]
+#[ignore]
+fn verify_proof_on_chain() {
+    // functions of the crate use paths relative to the workspace root
+    env::set_current_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap();
+
+    let bytecode = compile_verifier(&format!("target/circom/{}/verifier.sol", CIRCUIT_NAME));
+    let mut calldata = solidity_calldata(CIRCUIT_NAME).unwrap();
+
+    let mut evm = Evm::builder()
+        .with_db(InMemoryDB::default())
+        .modify_tx_env(|tx| {
+            tx.caller = Address::with_last_byte(1);
+            tx.gas_limit = GAS_LIMIT;
+        })
+        .build();
+
+    // deploy the verifier contract
+    evm.tx_mut().transact_to = TxKind::Create;
+    evm.tx_mut().data = bytecode.into();
+    let contract = match evm.transact_commit().unwrap() {
+        ExecutionResult::Success {
+            output: Output::Create(_, Some(address)),
+            gas_used,
+            ..
+        } => {
+            println!("Deployment gas:   {}", gas_used);
+            address
+        }
+        result => panic!("verifier deployment failed: {:?}", result),
+    };
+
+    // the valid proof is accepted
+    evm.tx_mut().transact_to = TxKind::Call(contract);
+    evm.tx_mut().data = calldata.clone().into();
+    let (accepted, gas_used) = call_verifier(&mut evm);
+    println!("Verification gas: {}", gas_used);
+    assert!(accepted, "valid proof rejected by the Solidity verifier");
+
+    // tampering with the last public signal makes the proof invalid
+    let last = calldata.len() - 1;
+    calldata[last] ^= 1;
+    evm.tx_mut().data = calldata.into();
+    let (accepted, _) = call_verifier(&mut evm);
+    assert!(!accepted, "invalid proof accepted by the Solidity verifier");
+}
+
+// HELPER FUNCTIONS
+// ===========================================================================
+
+/// Compile a Solidity file with `solc` and return the creation bytecode of its
+/// verifier contract.
+fn compile_verifier(path: &str) -> Vec<u8> {
+    assert!(
+        Path::new(path).exists(),
+        "{} not found, run the compile, setup and prove commands of the {} example first",
+        path,
+        CIRCUIT_NAME
+    );
+
+    let output = Command::new("solc")
+        .args(["--optimize", "--combined-json", "bin", path])
+        .output()
+        .expect("solc must be installed to run this test");
+    assert!(
+        output.status.success(),
+        "solc failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // the contract is named `Verifier` or `Groth16Verifier` depending on the
+    // version of SnarkJS
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let (_, contract) = json["contracts"]
+        .as_object()
+        .unwrap()
+        .iter()
+        .find(|(name, _)| name.ends_with("Verifier"))
+        .expect("no verifier contract found in the Solidity file");
+
+    hex::decode(contract["bin"].as_str().unwrap()).unwrap()
+}
+
+/// Execute the current transaction, returning whether the verifier accepted the
+/// proof and the gas used.
+fn call_verifier(evm: &mut Evm<'_, (), InMemoryDB>) -> (bool, u64) {
+    match evm.transact().unwrap().result {
+        ExecutionResult::Success {
+            output: Output::Call(output),
+            gas_used,
+            ..
+        } => (output.last() == Some(&1), gas_used),
+        ExecutionResult::Revert { gas_used, .. } => (false, gas_used),
+        result => panic!("verifier call failed: {:?}", result),
+    }
+}
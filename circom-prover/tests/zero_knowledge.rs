@@ -0,0 +1,288 @@
+//! Proves a blinded execution of the `sum` AIR in zero-knowledge mode, and
+//! checks that the AIRs which constrain the blinding rows are rejected.
+//!
+//! The `groth16_proof` test also compiles the zero-knowledge circuit, proves and
+//! verifies it end to end. It requires Circom and SnarkJS to be installed, and
+//! a `final.ptau` file at the workspace root:
+//!
+//! ```bash
+//! cargo test --release -p winter-circom-prover --test zero_knowledge -- --ignored --nocapture
+//! ```
+
+use std::{env, fs, path::Path, sync::Once};
+
+use serde_json::json;
+use winter_circom_prover::{
+    circom_compile, circom_prove, circom_verify,
+    utils::{ExecutionControl, LoggingLevel, WinterCircomError},
+    winterfell::{
+        self,
+        math::{fields::f256::BaseElement, FieldElement},
+        Air, AirContext, Assertion, EvaluationFrame, ProofOptions, Prover, Serializable,
+        StarkProof, Trace, TraceInfo, TraceTable,
+    },
+    CircomAir, CompileOptions, WinterCircomProofOptions, WinterPublicInputs,
+};
+
+const CIRCUIT_NAME: &str = "sum_zk";
+
+const PROOF_OPTIONS: WinterCircomProofOptions<2> =
+    WinterCircomProofOptions::new(128, 2, 2, 3, [1, 1], 32, 8, 0, 8, 128).with_zero_knowledge(4);
+
+/// AIR which leaves the blinding rows unconstrained.
+type SumAir = BlindedSumAir<5, 5>;
+
+#[test]
+fn blinded_proof_is_valid() {
+    let (proof, pub_inputs) = stark_prove::<5, 5>(3);
+    assert_eq!(pub_inputs.result, sum(3, 123));
+    winterfell::verify::<SumAir>(proof, pub_inputs).unwrap();
+}
+
+#[test]
+fn blinded_proofs_differ() {
+    // proofs of the same computation only share the public inputs
+    let (first, first_inputs) = stark_prove::<5, 5>(3);
+    let (second, second_inputs) = stark_prove::<5, 5>(3);
+    assert_eq!(first_inputs.result, second_inputs.result);
+    assert_ne!(first.commitments.to_bytes(), second.commitments.to_bytes());
+    assert_ne!(first.ood_frame.to_bytes(), second.ood_frame.to_bytes());
+}
+
+#[test]
+fn constrained_blinding_rows_are_rejected() {
+    env::set_current_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap();
+    write_options();
+
+    // transition constraints enforced on the blinding rows
+    assert!(matches!(
+        witness::<1, 5>(),
+        Err(WinterCircomError::UnsupportedProof(_))
+    ));
+
+    // assertion on the last row
+    assert!(matches!(
+        witness::<5, 1>(),
+        Err(WinterCircomError::UnsupportedProof(_))
+    ));
+}
+
+#[test]
+fn short_trace_is_rejected() {
+    let prover = SumProver::<5, 5>::new();
+    let mut trace = prover.build_trace(BaseElement::ONE, 8);
+    assert!(matches!(
+        PROOF_OPTIONS.blind_trace(&mut trace),
+        Err(WinterCircomError::InvalidOptions(_))
+    ));
+
+    // the trace is left untouched
+    assert_eq!(trace.get(1, 7), sum(1, 7));
+}
+
+#[test]
+#[ignore]
+fn groth16_proof() {
+    env::set_current_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap();
+    let control = ExecutionControl::default();
+
+    circom_compile::<SumAir, 2>(
+        false,
+        &CompileOptions::default(),
+        &control,
+        LoggingLevel::Default,
+    )
+    .unwrap();
+
+    let prover = SumProver::<5, 5>::new();
+    let trace = blinded_trace(&prover, 3);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let (proof, public_signals) =
+        circom_prove(prover, trace, &control, LoggingLevel::Default).unwrap();
+
+    // the public inputs are the only public signals
+    assert_eq!(
+        public_signals.signals,
+        vec![pub_inputs.start, pub_inputs.result]
+    );
+
+    let report = circom_verify::<SumAir, 2>(
+        &proof,
+        &public_signals,
+        pub_inputs,
+        &control,
+        LoggingLevel::Default,
+    )
+    .unwrap();
+    assert!(report.is_valid(), "{:?}", report);
+}
+
+// HELPER FUNCTIONS
+// ===========================================================================
+
+/// Sum of the integers from `start` to `start + last_step`, plus `start`.
+fn sum(start: u64, last_step: u64) -> BaseElement {
+    let (start, last_step) = (BaseElement::from(start), BaseElement::from(last_step));
+    start + last_step * start + last_step * (last_step + BaseElement::ONE) / BaseElement::from(2u64)
+}
+
+fn blinded_trace<const E: usize, const S: usize>(
+    prover: &SumProver<E, S>,
+    start: u64,
+) -> TraceTable<BaseElement> {
+    let mut trace = prover.build_trace(BaseElement::from(start), PROOF_OPTIONS.trace_length);
+    PROOF_OPTIONS.blind_trace(&mut trace).unwrap();
+    trace
+}
+
+fn stark_prove<const E: usize, const S: usize>(start: u64) -> (StarkProof, PublicInputs) {
+    let prover = SumProver::<E, S>::new();
+    let trace = blinded_trace(&prover, start);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    (prover.prove(trace).unwrap(), pub_inputs)
+}
+
+/// Run `circom_prove` up to the checks of the circuit options, which reject
+/// the AIR before any Circom or SnarkJS command is run.
+fn witness<const E: usize, const S: usize>() -> Result<(), WinterCircomError> {
+    let prover = SumProver::<E, S>::new();
+    let trace = blinded_trace(&prover, 3);
+    circom_prove(
+        prover,
+        trace,
+        &ExecutionControl::default(),
+        LoggingLevel::Quiet,
+    )
+    .map(|_| ())
+}
+
+/// Write the `options.json` file read by `circom_prove`, as `circom_compile`
+/// would.
+fn write_options() {
+    static WRITE: Once = Once::new();
+    WRITE.call_once(|| {
+        let directory = format!("target/circom/{}", CIRCUIT_NAME);
+        fs::create_dir_all(&directory).unwrap();
+        let options = json!({
+            "trace_length": PROOF_OPTIONS.trace_length,
+            "num_blinding_rows": 4,
+        });
+        fs::write(format!("{}/options.json", directory), options.to_string()).unwrap();
+    });
+}
+
+// SUM AIR
+// ===========================================================================
+
+#[derive(Clone, Default, WinterPublicInputs)]
+#[winter_circom(options = PROOF_OPTIONS)]
+struct PublicInputs {
+    start: BaseElement,
+    result: BaseElement,
+}
+
+/// `sum` AIR with `E` transition exemptions, and the result asserted at step
+/// `trace_length - S`.
+struct BlindedSumAir<const E: usize, const S: usize> {
+    context: AirContext<BaseElement>,
+    start: BaseElement,
+    result: BaseElement,
+}
+
+impl<const E: usize, const S: usize> CircomAir<2> for BlindedSumAir<E, S> {
+    const CIRCUIT_NAME: &'static str = CIRCUIT_NAME;
+
+    const PROOF_OPTIONS: WinterCircomProofOptions<2> = PROOF_OPTIONS;
+
+    const AIR_TEMPLATES: &'static str = include_str!("../../circuits/air/sum.circom");
+}
+
+impl<const E: usize, const S: usize> Air for BlindedSumAir<E, S> {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        let context = AirContext::new(
+            trace_info,
+            PROOF_OPTIONS.transition_constraint_degrees(),
+            PROOF_OPTIONS.num_assertions(),
+            options,
+        )
+        .set_num_transition_exemptions(E);
+
+        Self {
+            context,
+            start: pub_inputs.start,
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<F: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<F>,
+        _periodic_values: &[F],
+        result: &mut [F],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+
+        result[0] = next[0] - (current[0] + F::ONE);
+        result[1] = next[1] - (current[1] + current[0] + F::ONE);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        vec![
+            Assertion::single(0, 0, self.start),
+            Assertion::single(1, 0, self.start),
+            Assertion::single(1, self.trace_length() - S, self.result),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
+
+struct SumProver<const E: usize, const S: usize> {
+    options: ProofOptions,
+}
+
+impl<const E: usize, const S: usize> SumProver<E, S> {
+    fn new() -> Self {
+        Self {
+            options: PROOF_OPTIONS.get_proof_options(),
+        }
+    }
+
+    fn build_trace(&self, start: BaseElement, n: usize) -> TraceTable<BaseElement> {
+        let mut trace = TraceTable::new(PROOF_OPTIONS.trace_width, n);
+        trace.fill(
+            |state| {
+                state[0] = start;
+                state[1] = start;
+            },
+            |_, state| {
+                state[0] += BaseElement::ONE;
+                state[1] += state[0];
+            },
+        );
+        trace
+    }
+}
+
+impl<const E: usize, const S: usize> Prover for SumProver<E, S> {
+    type BaseField = BaseElement;
+    type Air = BlindedSumAir<E, S>;
+    type Trace = TraceTable<BaseElement>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
+        PublicInputs {
+            start: trace.get(0, 0),
+            result: trace.get(1, trace.length() - S),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -24,6 +24,29 @@ template AIRTransitions(trace_width) {
     transition_degree[1] <== 1;
 }
 
+/**
+ * Evaluate the transition constraints over an evaluation frame. This template
+ * is only needed by the zero-knowledge verifier (see
+ * ood_frame/zero_knowledge.circom), and replicates the evaluate_transition
+ * method of the Air implementation for Winterfell.
+ *
+ * INPUTS:
+ * - frame: current and next states of the trace
+ *
+ * OUTPUTS:
+ * - evaluations: evaluation of each transition constraint over the frame
+ */
+template AIRTransitionEvaluations(trace_width) {
+    signal input frame[2][trace_width];
+    signal output evaluations[trace_width];
+
+    // transition 0
+    evaluations[0] <== frame[1][0] - (frame[0][0] + 1);
+
+    // transition 1
+    evaluations[1] <== frame[1][1] - (frame[0][1] + frame[0][0] + 1);
+}
+
 /**
  * Define the assertions that will tie your public inputs to the calculation.
  * These assertions will then be transformed into boundray constraints.
@@ -33,6 +56,7 @@ template AIRTransitions(trace_width) {
  * INPUTS:
  * - public_inputs: inputs used for the calculation
  * - frame: Out Of Domain evaluation frame
+ * - trace_length: number of steps in the proven calculation
  *
  * OUTPUTS:
  * - out: evaluation of the boundary constraints against each trace column
@@ -48,12 +72,13 @@ template AIRTransitions(trace_width) {
 template AIRAssertions(
     num_assertions,
     num_public_inputs,
-    trace_length,
+    num_transition_exemptions,
     trace_width
 ) {
     signal input frame[2][trace_width];
     signal input g_trace;
     signal input public_inputs[num_public_inputs];
+    signal input trace_length;
     signal input z;
 
     signal output out[num_assertions];
@@ -75,7 +100,7 @@ template AIRAssertions(
     register[1] <== 1;
 
     value[2] <== public_inputs[1];
-    step[2] <== trace_length - 1;
+    step[2] <== trace_length - num_transition_exemptions;
     register[2] <== 1;
 
     /* ------------------------------------- */
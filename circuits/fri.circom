@@ -4,210 +4,344 @@ include "utils.circom";
 include "merkle.circom";
 
 
+/**
+ * Verify the FRI low-degree proof of the DEEP composition polynomial.
+ *
+ * The trace length is selected by length_selector among the supported trace
+ * lengths (see Verify). The arrays of the FRI proof are sized for the largest
+ * one, and padded with zeroes for shorter traces. The layers beyond the actual
+ * number of FRI layers are ignored, and the remainder is checked for each of
+ * the remainder sizes the supported trace lengths can lead to.
+ *
+ * ARGUMENTS:
+ * - See verify.circom
+ *
+ * INPUTS:
+ * - addicity_root: 2**addicity root of unity
+ * - deep_evaluations: DEEP composition polynomial evaluations at the query positions
+ * - fri_commitments: the root of the evaluations merkle tree for each FRI layer,
+     followed by the remainder commitment and zero padding
+ * - fri_layer_proofs: authentication paths of the FRI layers below the top
+     levels, padded with zeroes, at the indexes of their leaves in the
+     partitioned tree
+ * - fri_layer_queries: folded DEEP polynomial evaluations at the folded query
+     positions for each FRI layer
+ * - fri_layer_top_nodes: nodes of the top levels of the merkle tree of each FRI
+     layer, padded with zeroes
+ * - fri_remainder: evaluations of the FRI remainder over its domain, or the
+     coefficients of its polynomial, padded with zeroes
+ * - g_lde: generator of the LDE domain
+ * - layer_alphas: Fiat-Shamir coefficients of the FRI layers
+ * - length_selector: binary array with a single 1, at index
+     log2(trace_length) - min_log_trace_length
+ * - query_positions: query positions over the LDE domain
+ */
 template FriVerifier(
     addicity,
     domain_offset,
     folding_factor,
-    fri_tree_depths,
+    fri_max_remainder_size,
+    fri_num_partitions,
+    fri_remainder_coefficients,
     lde_blowup_factor,
-    num_fri_layers,
-    num_queries,
-    trace_length,
-    tree_depth
+    max_log_trace_length,
+    merkle_arity,
+    min_log_trace_length,
+    num_queries
 ) {
-    var remainder_size = (trace_length * lde_blowup_factor) \ (folding_factor ** num_fri_layers);
-    var lde_domain_size = trace_length * lde_blowup_factor;
+    var log_blowup = numbits(lde_blowup_factor) - 1;
+    var log_folding_factor = numbits(folding_factor) - 1;
+    var log_max_remainder_size = numbits(fri_max_remainder_size) - 1;
+    var log_merkle_arity = numbits(merkle_arity) - 1;
+    var max_tree_depth = max_log_trace_length + log_blowup;
+    var num_fri_layers = fri_num_layers(max_tree_depth, log_folding_factor, log_max_remainder_size);
+    var num_lengths = max_log_trace_length - min_log_trace_length + 1;
+    var remainder_length = fri_max_remainder_length(
+        folding_factor,
+        fri_max_remainder_size,
+        fri_remainder_coefficients,
+        lde_blowup_factor,
+        max_log_trace_length,
+        merkle_arity,
+        min_log_trace_length
+    );
+
+    // the inputs are sized for the trees of the trace and constraint
+    // commitments, which are the deepest ones
+    var merkle_top_levels = merkle_top_depth(num_queries, (min_log_trace_length + log_blowup) \ log_merkle_arity, merkle_arity);
+    var merkle_path_length = (max_tree_depth \ log_merkle_arity - merkle_top_levels) * (merkle_arity - 1);
+    var num_merkle_top_nodes = merkle_num_top_nodes(merkle_top_levels, merkle_arity);
+
+    // the remainder is folded until it is no larger than fri_max_remainder_size,
+    // its size is therefore one of the folding_factor largest sizes up to it
+    var min_log_remainder_size = log_max_remainder_size - log_folding_factor + 1;
+    var num_remainder_sizes = log_folding_factor;
+
+    // LDE domain size, number of FRI layers and remainder size of each
+    // supported length, whose log2 differ by multiples of log2(merkle_arity)
+    var log_lde_domain_sizes[num_lengths];
+    var layer_counts[num_lengths];
+    var log_remainder_sizes[num_lengths];
+    for (var j = 0; j < num_lengths; j += log_merkle_arity) {
+        log_lde_domain_sizes[j] = min_log_trace_length + j + log_blowup;
+        layer_counts[j] = fri_num_layers(log_lde_domain_sizes[j], log_folding_factor, log_max_remainder_size);
+        log_remainder_sizes[j] = log_lde_domain_sizes[j] - log_folding_factor * layer_counts[j];
+        assert(layer_counts[j] > 0);
+    }
 
     signal input addicity_root;
     signal input deep_evaluations[num_queries];
     signal input fri_commitments[num_fri_layers + 1];
-    signal input fri_layer_proofs[num_fri_layers][num_queries][tree_depth];
+    signal input fri_layer_proofs[num_fri_layers][num_queries][merkle_path_length];
     signal input fri_layer_queries[num_fri_layers][num_queries * folding_factor];
-    signal input fri_remainder[remainder_size];
+    signal input fri_layer_top_nodes[num_fri_layers][num_merkle_top_nodes];
+    signal input fri_remainder[remainder_length];
     signal input g_lde;
     signal input layer_alphas[num_fri_layers];
+    signal input length_selector[num_lengths];
     signal input query_positions[num_queries];
 
     signal coordinates_xe[num_fri_layers][num_queries];
-    signal max_degree_plus_1[num_fri_layers + 1];
-    signal query_values[num_fri_layers][num_queries];
-    signal t1[folding_factor];
-    signal t2[folding_factor];
-    signal x_pow[lde_domain_size];
+    signal folding_roots[folding_factor];
+    signal last_evaluation_terms[num_fri_layers][num_queries];
+    signal last_evaluations[num_queries];
+    signal last_mask_terms[num_fri_layers][num_queries];
+    signal last_masks[num_queries];
+    signal last_point_terms[num_fri_layers][num_queries];
+    signal last_points[num_queries];
+    signal layer_active[num_fri_layers];
+    signal layer_check_masks[num_fri_layers][num_queries];
+    signal layer_generators[num_fri_layers];
+    signal layer_is_last[num_fri_layers];
+    signal layer_masks[num_fri_layers][num_queries];
+    signal layer_position_masks[num_fri_layers][max_tree_depth];
+    signal layer_quotients[num_fri_layers][num_queries];
+    signal layer_shift_inverses[num_fri_layers];
+    signal remainder_active[num_remainder_sizes];
+    signal remainder_checks[num_remainder_sizes][num_queries];
+    signal remainder_commitment;
+    signal remainder_commitment_terms[num_fri_layers];
 
-    component coordinate_pow_selectors[num_fri_layers];
     component coordinate_interpolators[num_fri_layers];
+    component coordinate_pows[num_fri_layers][num_queries];
     component evaluations[num_fri_layers][num_queries];
     component folded_positions[num_fri_layers];
     component folded_position_modulos[num_fri_layers][num_queries];
-    component folding_roots;
+    component folding_root;
     component layer_commitment_verifiers[num_fri_layers];
-    component layer_queries_divisions[num_fri_layers][num_queries];
+    component layer_generator_pows[num_fri_layers];
+    component layer_partition_divisions[num_fri_layers][num_queries];
     component layer_queries_lookups[num_fri_layers];
     component layer_query_selectors[num_fri_layers];
-    component remainder_degree;
-    component remainder_length_lt;
-    component remainder_degree_lt;
-    component remainder_hashers[remainder_size];
-    component remainder_interpolation;
-    component remainder_merkle_tree;
-    component remainder_selectors;
-
-
-    // PRE-COMPUTE ROOTS OF UNITY
+    component remainder_commitments[num_remainder_sizes];
+    component remainder_coordinate_pows[num_queries];
+    component remainder_degrees[num_remainder_sizes];
+    component remainder_degree_lts[num_remainder_sizes];
+    component remainder_evaluations[num_queries];
+    component remainder_hashers[num_remainder_sizes][fri_max_remainder_size \ folding_factor];
+    component remainder_interpolations[num_remainder_sizes];
+    component remainder_merkle_trees[num_remainder_sizes];
+    component remainder_selectors[num_remainder_sizes];
+
+
+    // SELECT THE PARAMETERS OF THE FRI LAYERS
     // ==========================================================================
 
-    // calculate all powers of g_lde
-    x_pow[0] <== 1;
-    for (var i = 1; i < lde_domain_size; i++) {
-        x_pow[i] <== x_pow[i - 1] * g_lde;
-    }
+    // a layer is active if it is one of the actual FRI layers of the selected
+    // length, its positions are folded over a domain of size 2**depth, whose
+    // Merkle tree has depth / log2(merkle_arity) levels
+    var layer_depths[num_fri_layers][num_lengths];
+    var layer_min_merkle_depths[num_fri_layers];
+    var layer_max_merkle_depths[num_fri_layers];
+    var layer_partition_sizes[num_fri_layers];
+    for (var k = 0; k < num_fri_layers; k++) {
+        var active = 0;
+        var is_last = 0;
+        var shift_inverse = 0;
+        layer_min_merkle_depths[k] = max_tree_depth;
+        layer_max_merkle_depths[k] = 0;
+        for (var j = 0; j < num_lengths; j += log_merkle_arity) {
+            if (layer_counts[j] > k) {
+                layer_depths[k][j] = log_lde_domain_sizes[j] - log_folding_factor * (k + 1);
+                active += length_selector[j];
+
+                var merkle_depth = layer_depths[k][j] \ log_merkle_arity;
+                if (merkle_depth < layer_min_merkle_depths[k]) {
+                    layer_min_merkle_depths[k] = merkle_depth;
+                }
+                if (merkle_depth > layer_max_merkle_depths[k]) {
+                    layer_max_merkle_depths[k] = merkle_depth;
+                }
+            }
+            if (layer_counts[j] == k + 1) {
+                is_last += length_selector[j];
+            }
+        }
 
-    folding_roots = MultiSelector(lde_domain_size, folding_factor);
-    for (var i = 0; i < lde_domain_size; i++) {
-        folding_roots.in[i] <== x_pow[i];
+        // inactive layers are not checked, their depth is the smallest one
+        var partition_size = 0;
+        for (var j = 0; j < num_lengths; j += log_merkle_arity) {
+            if (layer_counts[j] <= k) {
+                layer_depths[k][j] = layer_min_merkle_depths[k] * log_merkle_arity;
+            }
+            shift_inverse += length_selector[j] * (1 / 2 ** layer_depths[k][j]);
+            partition_size += length_selector[j] * (2 ** layer_depths[k][j] \ fri_num_partitions);
+        }
+        layer_active[k] <== active;
+        layer_is_last[k] <== is_last;
+        layer_shift_inverses[k] <== shift_inverse;
+        layer_partition_sizes[k] = partition_size;
+
+        for (var d = 0; d < max_tree_depth; d++) {
+            var position_mask = 0;
+            for (var j = 0; j < num_lengths; j += log_merkle_arity) {
+                if (d < layer_depths[k][j]) {
+                    position_mask += length_selector[j];
+                }
+            }
+            layer_position_masks[k][d] <== position_mask;
+        }
     }
-    for (var i = 0; i < folding_factor; i++) {
-        // t2 = lde_domain_size / N * i
-        t1[i] <-- lde_domain_size \ folding_factor;
-        t1[i] * folding_factor === lde_domain_size;
-        t2[i] <== t1[i] * i;
-
-        // folding_roots[i] = g_lde ** t2;
-        folding_roots.indexes[i] <== t2[i];
+
+    // folding_roots[i] are the powers of the folding_factor-th root of unity
+    folding_root = Pow(2 ** (addicity - log_folding_factor));
+    folding_root.in <== addicity_root;
+
+    folding_roots[0] <== 1;
+    for (var i = 1; i < folding_factor; i++) {
+        folding_roots[i] <== folding_roots[i - 1] * folding_root.out;
     }
 
     // 1 - VERIFY RECURSIVE COMPONENTS OF THE FRI PROOF
     // ==========================================================================
 
-    var domain_size = lde_domain_size;
-    var domain_generator_offset = 1;
-    var extended_num_queries[num_fri_layers + 1];
-    max_degree_plus_1[0] <== trace_length;
-
     for (var depth = 0; depth < num_fri_layers; depth++) {
         // CALCULATE FOLDED POSITIONS
-        var target_domain_size = domain_size \ folding_factor;
-        if (depth == 0) {
-            // for the first FRI layers, fold query_positions
-            folded_positions[0] = RemoveDuplicatesUnknown(num_queries);
-
-            for (var j = 0; j < num_queries; j++) {
-                folded_position_modulos[0][j] = IntegerDivision(target_domain_size, tree_depth);
-                folded_position_modulos[0][j].in <== query_positions[j];
+        // the folded positions are the lower bits of the positions of the
+        // previous layer
+        folded_positions[depth] = RemoveDuplicatesUnknown(num_queries);
 
-                folded_positions[0].in[j] <== folded_position_modulos[0][j].remainder;
-                folded_positions[0].in_mask[j] <== 1;
+        for (var i = 0; i < num_queries; i++) {
+            folded_position_modulos[depth][i] = MaskBits(max_tree_depth);
+            if (depth == 0) {
+                folded_position_modulos[0][i].in <== query_positions[i];
+                folded_positions[0].in_mask[i] <== 1;
+            } else {
+                folded_position_modulos[depth][i].in <== folded_positions[depth - 1].out[i];
+                folded_positions[depth].in_mask[i] <== folded_positions[depth - 1].out_mask[i];
             }
-        } else {
-            // for all consequent FRI layers, fold previous folded_positions
-            folded_positions[depth] = RemoveDuplicatesUnknown(num_queries);
-
-            for (var j = 0; j < num_queries; j++) {
-                folded_position_modulos[depth][j] = IntegerDivision(target_domain_size, tree_depth);
-                folded_position_modulos[depth][j].in <== folded_positions[depth - 1].out[j];
-
-                folded_positions[depth].in[j] <== folded_position_modulos[depth][j].remainder;
-                folded_positions[depth].in_mask[j] <== folded_positions[depth - 1].out_mask[j];
+            for (var d = 0; d < max_tree_depth; d++) {
+                folded_position_modulos[depth][i].mask[d] <== layer_position_masks[depth][d];
             }
+
+            folded_positions[depth].in[i] <== folded_position_modulos[depth][i].out;
         }
 
         // VERIFY FRI LAYER COMMITMENT
-        layer_commitment_verifiers[depth] = MerkleOpeningsVerifyMasked(num_queries, fri_tree_depths[depth], folding_factor);
+        var min_merkle_depth = layer_min_merkle_depths[depth];
+        var max_merkle_depth = layer_max_merkle_depths[depth];
+        var layer_top_levels = merkle_top_depth(num_queries, min_merkle_depth, merkle_arity);
+        layer_commitment_verifiers[depth] = MerkleBatchVerifyMasked(num_queries, min_merkle_depth, max_merkle_depth, folding_factor, merkle_arity);
         layer_commitment_verifiers[depth].root <== fri_commitments[depth];
+        for (var d = 0; d <= max_merkle_depth - min_merkle_depth; d++) {
+            var depth_selector = 0;
+            for (var j = 0; j < num_lengths; j += log_merkle_arity) {
+                if (layer_depths[depth][j] == (min_merkle_depth + d) * log_merkle_arity) {
+                    depth_selector += length_selector[j];
+                }
+            }
+            layer_commitment_verifiers[depth].depth_selector[d] <== depth_selector;
+        }
         for (var i = 0; i < num_queries; i++) {
-            layer_commitment_verifiers[depth].indexes[i] <== folded_positions[depth].out[i];
-            layer_commitment_verifiers[depth].mask[i] <== folded_positions[depth].out_mask[i];
+            layer_masks[depth][i] <== folded_positions[depth].out_mask[i] * layer_active[depth];
+
+            if (fri_num_partitions == 1) {
+                layer_commitment_verifiers[depth].indexes[i] <== folded_positions[depth].out[i];
+            } else {
+                // the leaves of the layer tree are ordered by partition, the
+                // partition of a position being its remainder modulo
+                // fri_num_partitions, followed by its index in the partition
+                layer_partition_divisions[depth][i] = IntegerDivision(fri_num_partitions, max_tree_depth);
+                layer_partition_divisions[depth][i].in <== folded_positions[depth].out[i];
+                layer_commitment_verifiers[depth].indexes[i] <== layer_partition_divisions[depth][i].remainder * layer_partition_sizes[depth] + layer_partition_divisions[depth][i].quotient;
+            }
+            layer_commitment_verifiers[depth].mask[i] <== layer_masks[depth][i];
             for (var j = 0; j < folding_factor; j++) {
                 layer_commitment_verifiers[depth].leaves[i][j] <== fri_layer_queries[depth][i * folding_factor + j];
             }
-            for (var j = 0; j < fri_tree_depths[depth]; j++) {
+            for (var j = 0; j < (max_merkle_depth - layer_top_levels) * (merkle_arity - 1); j++) {
                 layer_commitment_verifiers[depth].openings[i][j] <== fri_layer_proofs[depth][i][j];
             }
         }
+        for (var j = 0; j < merkle_num_top_nodes(layer_top_levels, merkle_arity); j++) {
+            layer_commitment_verifiers[depth].top_nodes[j] <== fri_layer_top_nodes[depth][j];
+        }
 
         // VERIFY LAYER QUERIES
-        var row_length = domain_size \ folding_factor;
-        if (depth == 0) {
-            // handle the first FRI layer with query_values
-            layer_queries_lookups[0] = MultiIndexLookup(num_queries, num_queries);
-            for (var j = 0; j < num_queries; j++) {
-                layer_queries_lookups[0].in[j] <== folded_positions[0].out[j];
-                layer_queries_lookups[0].mask[j] <== folded_positions[0].out_mask[j];
-            }
-
-            layer_query_selectors[0] = MultiSelector(num_queries * folding_factor, num_queries);
-            for (var i = 0; i < num_queries * folding_factor; i++) {
-                layer_query_selectors[0].in[i] <== fri_layer_queries[0][i];
-            }
-
-            for (var i = 0; i < num_queries; i++) {
-                // integer division of position (query_positions[i]) by row_length
-                layer_queries_divisions[0][i] = IntegerDivision(row_length, tree_depth);
-                layer_queries_divisions[0][i].in <== query_positions[i];
+        layer_queries_lookups[depth] = MultiIndexLookup(num_queries, num_queries);
+        for (var j = 0; j < num_queries; j++) {
+            layer_queries_lookups[depth].in[j] <== folded_positions[depth].out[j];
+            layer_queries_lookups[depth].mask[j] <== folded_positions[depth].out_mask[j];
+        }
 
-                // find index of position % row_length in query_positions
-                layer_queries_lookups[0].lookup[i] <== layer_queries_divisions[0][i].remainder;
-            }
-            for (var i = 0; i < num_queries; i++) {
-                // pick fri_layer_queries[depth][idx * folding_factor + position \ row_length]
-                // (where idx = layer_queries_lookups[depth][i].out)
-                layer_query_selectors[0].indexes[i] <== layer_queries_lookups[0].out[i] * folding_factor + layer_queries_divisions[0][i].quotient;
-            }
-            for (var i = 0; i < num_queries; i++) {
-                // verify that query_values == deep_evaluations
-                // (where query_values = layer_query_selectors[depth].out)
-                layer_query_selectors[0].out[i] === deep_evaluations[i];
-            }
-        } else {
-            // handle subsequent layers
-            layer_queries_lookups[depth] = MultiIndexLookup(num_queries, num_queries);
-            for (var j = 0; j < num_queries; j++) {
-                layer_queries_lookups[depth].in[j] <== folded_positions[depth].out[j];
-                layer_queries_lookups[depth].mask[j] <== folded_positions[depth].out_mask[j];
-            }
+        layer_query_selectors[depth] = MultiSelector(num_queries * folding_factor, num_queries);
+        for (var i = 0; i < num_queries * folding_factor; i++) {
+            layer_query_selectors[depth].in[i] <== fri_layer_queries[depth][i];
+        }
 
-            layer_query_selectors[depth] = MultiSelector(num_queries * folding_factor, num_queries);
-            for (var i = 0; i < num_queries * folding_factor; i++) {
-                layer_query_selectors[depth].in[i] <== fri_layer_queries[depth][i];
+        for (var i = 0; i < num_queries; i++) {
+            // find index of the folded position in folded_positions[depth]
+            layer_queries_lookups[depth].lookup[i] <== folded_position_modulos[depth][i].out;
+
+            // quotient of the position by the size of the folded domain
+            if (depth == 0) {
+                layer_quotients[0][i] <== (query_positions[i] - folded_position_modulos[0][i].out) * layer_shift_inverses[0];
+            } else {
+                layer_quotients[depth][i] <== (folded_positions[depth - 1].out[i] - folded_position_modulos[depth][i].out) * layer_shift_inverses[depth];
             }
 
-            for (var i = 0; i < num_queries; i++) {
-                // integer division of position (folded_positions[depth - 1].out[i]) by row_length
-                layer_queries_divisions[depth][i] = IntegerDivision(row_length, fri_tree_depths[depth - 1]);
-                layer_queries_divisions[depth][i].in <== folded_positions[depth - 1].out[i];
-
-                // find index of position % row_length in folded_positions[depth]
-                layer_queries_lookups[depth].lookup[i] <== layer_queries_divisions[depth][i].remainder;
-            }
-            for (var i = 0; i < num_queries; i++) {
-                // pick fri_layer_queries[depth][idx * folding_factor + position \ row_length]
-                // (where idx = layer_queries_lookups[depth][i].out)
-                layer_query_selectors[depth].indexes[i] <== layer_queries_lookups[depth].out[i] * folding_factor + layer_queries_divisions[depth][i].quotient;
-            }
-            for (var i = 0; i < num_queries; i++) {
-                // verify that query_values == deep_evaluations
-                // (where query_values = layer_query_selectors[depth].out)
-                // the mask is used to rule out values beyond the actual folded positions
-                (layer_query_selectors[depth].out[i] - evaluations[depth - 1][i].out) * folded_positions[depth - 1].out_mask[i] === 0;
+            // pick fri_layer_queries[depth][idx * folding_factor + quotient]
+            // (where idx = layer_queries_lookups[depth][i].out)
+            layer_query_selectors[depth].indexes[i] <== layer_queries_lookups[depth].out[i] * folding_factor + layer_quotients[depth][i];
+        }
+        for (var i = 0; i < num_queries; i++) {
+            // verify that query_values == evaluations of the previous layer
+            // the mask is used to rule out values beyond the actual folded
+            // positions and layers
+            if (depth == 0) {
+                layer_check_masks[0][i] <== layer_active[0];
+                (layer_query_selectors[0].out[i] - deep_evaluations[i]) * layer_check_masks[0][i] === 0;
+            } else {
+                layer_check_masks[depth][i] <== folded_positions[depth - 1].out_mask[i] * layer_active[depth];
+                (layer_query_selectors[depth].out[i] - evaluations[depth - 1][i].out) * layer_check_masks[depth][i] === 0;
             }
         }
 
         // BUILD A SET OF COORDINATES FOR EACH ROW POLYNOMIAL
         // AND INTERPOLATE INTO ROW POLYNOMIALS
         coordinate_interpolators[depth] = BatchInterpolate(num_queries, folding_factor);
-        coordinate_pow_selectors[depth] = MultiSelector(lde_domain_size, num_queries);
-        for (var i = 0; i < lde_domain_size; i++) {
-            coordinate_pow_selectors[depth].in[i] <== x_pow[i];
+
+        // generator of the domain of the layer, g_lde ** (folding_factor ** depth)
+        if (depth == 0) {
+            layer_generators[0] <== g_lde;
+        } else {
+            layer_generator_pows[depth] = Pow(folding_factor);
+            layer_generator_pows[depth].in <== layer_generators[depth - 1];
+            layer_generators[depth] <== layer_generator_pows[depth].out;
         }
+
+        // folded positions are smaller than the size of the folded domain,
+        // their x coordinates are computed by square-and-multiply over their bits
         for (var i = 0; i < num_queries; i++) {
-            coordinate_pow_selectors[depth].indexes[i] <== folded_positions[depth].out[i] * domain_generator_offset;
+            coordinate_pows[depth][i] = Pow_signal(max_tree_depth - log_folding_factor * (depth + 1));
+            coordinate_pows[depth][i].in <== layer_generators[depth];
+            coordinate_pows[depth][i].exp <== folded_positions[depth].out[i];
         }
         for (var i = 0; i < num_queries; i++) {
-            coordinates_xe[depth][i] <== coordinate_pow_selectors[depth].out[i] * domain_offset;
+            coordinates_xe[depth][i] <== coordinate_pows[depth][i].out * domain_offset;
 
             for (var j = 0; j < folding_factor; j++) {
-                coordinate_interpolators[depth].xs[i][j] <== coordinates_xe[depth][i] * folding_roots.out[j];
+                coordinate_interpolators[depth].xs[i][j] <== coordinates_xe[depth][i] * folding_roots[j];
                 coordinate_interpolators[depth].ys[i][j] <== fri_layer_queries[depth][i * folding_factor + j];
             }
         }
@@ -219,76 +353,199 @@ template FriVerifier(
                 evaluations[depth][i].p[j] <== coordinate_interpolators[depth].out[i][j];
             }
         }
-
-        // make sure the next degree reduction does not result in truncation
-        max_degree_plus_1[depth + 1] <-- max_degree_plus_1[depth] \ folding_factor;
-        max_degree_plus_1[depth + 1] * folding_factor === max_degree_plus_1[depth];
-
-        // prepare next layer
-        domain_size = target_domain_size;
-        domain_generator_offset *= folding_factor;
     }
 
     // 2 - VERIFY THE REMAINDER OF THE FRI PROOF
     // ==========================================================================
 
-    // check remainder values against last level evaluations
-    remainder_selectors = MultiSelector(remainder_size, num_queries);
-    for (var i = 0; i < remainder_size; i++) {
-        remainder_selectors.in[i] <== fri_remainder[i];
-    }
-    for (var i = 0; i < num_queries; i++) {
-        remainder_selectors.indexes[i] <== folded_positions[num_fri_layers - 1].out[i];
+    // select the evaluations of the last active layer, with its folded
+    // positions if the remainder is sent as evaluations, or the powers of the
+    // layer generator at these positions if it is sent as coefficients, and
+    // the remainder commitment which follows its commitment
+    var remainder_commitment_lc = 0;
+    for (var k = 0; k < num_fri_layers; k++) {
+        remainder_commitment_terms[k] <== layer_is_last[k] * fri_commitments[k + 1];
+        remainder_commitment_lc += remainder_commitment_terms[k];
     }
+    remainder_commitment <== remainder_commitment_lc;
+
     for (var i = 0; i < num_queries; i++) {
-        (remainder_selectors.out[i] - evaluations[num_fri_layers - 1][i].out) * folded_positions[num_fri_layers - 1].out_mask[i] === 0;
+        var last_evaluation = 0;
+        var last_mask = 0;
+        var last_point = 0;
+        for (var k = 0; k < num_fri_layers; k++) {
+            last_evaluation_terms[k][i] <== layer_is_last[k] * evaluations[k][i].out;
+            last_mask_terms[k][i] <== layer_is_last[k] * folded_positions[k].out_mask[i];
+            if (fri_remainder_coefficients == 1) {
+                last_point_terms[k][i] <== layer_is_last[k] * coordinate_pows[k][i].out;
+            } else {
+                last_point_terms[k][i] <== layer_is_last[k] * folded_positions[k].out[i];
+            }
+            last_evaluation += last_evaluation_terms[k][i];
+            last_mask += last_mask_terms[k][i];
+            last_point += last_point_terms[k][i];
+        }
+        last_evaluations[i] <== last_evaluation;
+        last_masks[i] <== last_mask;
+        last_points[i] <== last_point;
     }
 
-    // transpose remainder into a matrix of width folding_factor and hash each line
-    var remainder_leaves_size = remainder_size \ folding_factor;
-    for (var i = 0; i < remainder_leaves_size; i++) {
-        remainder_hashers[i] = Poseidon(folding_factor);
-    }
-    for (var i = 0; i < remainder_leaves_size; i++) {
-        for (var j = 0; j < folding_factor; j++) {
-            remainder_hashers[i].in[j] <== fri_remainder[i + j * remainder_leaves_size];
+    if (fri_remainder_coefficients == 1) {
+        // evaluate the remainder polynomial at the x coordinates of the last
+        // folded positions, domain_offset * (layer_generator ** folding_factor) ** position,
+        // and check the evaluations against last level evaluations; the
+        // coefficients beyond the remainder length are zero
+        for (var i = 0; i < num_queries; i++) {
+            remainder_coordinate_pows[i] = Pow(folding_factor);
+            remainder_coordinate_pows[i].in <== last_points[i];
+
+            remainder_evaluations[i] = Evaluate(remainder_length);
+            remainder_evaluations[i].x <== remainder_coordinate_pows[i].out * domain_offset;
+            for (var j = 0; j < remainder_length; j++) {
+                remainder_evaluations[i].p[j] <== fri_remainder[j];
+            }
+        }
+        for (var i = 0; i < num_queries; i++) {
+            (remainder_evaluations[i].out - last_evaluations[i]) * last_masks[i] === 0;
         }
     }
 
-    // verify remainder commitment
-    remainder_merkle_tree = MerkleTree(remainder_leaves_size);
-    for (var i = 0; i < remainder_leaves_size; i++) {
-        remainder_merkle_tree.leaves[i] <== remainder_hashers[i].out;
-    }
-    remainder_merkle_tree.root === fri_commitments[num_fri_layers];
-
-    // VERIFY REMAINDER DEGREE
-
-    // make sure that remainder max degree < remainder length - 1
-    // remainder max degree is max_degree_plus_1[num_fri_layers] - 1
-    remainder_length_lt = LessThan(tree_depth);
-    remainder_length_lt.in[0] <== max_degree_plus_1[num_fri_layers];
-    remainder_length_lt.in[1] <== remainder_size;
-    remainder_length_lt.out === 1;
-
-    // interpolate fri_remainder
-    remainder_interpolation = FFTInterpolate(remainder_size, addicity);
-    remainder_interpolation.addicity_root <== addicity_root;
-    for (var i = 0; i < remainder_size; i++) {
-        remainder_interpolation.ys[i] <== fri_remainder[i];
+    // check the remainder for each of its possible sizes, the checks of the
+    // sizes that are not the actual one are ignored
+    for (var m = 0; m < num_remainder_sizes; m++) {
+        var log_remainder_size = min_log_remainder_size + m;
+        var remainder_size = 2 ** log_remainder_size;
+
+        var active = 0;
+        var used = 0;
+        for (var j = 0; j < num_lengths; j += log_merkle_arity) {
+            if (log_remainder_sizes[j] == log_remainder_size) {
+                active += length_selector[j];
+                used = 1;
+            }
+        }
+        remainder_active[m] <== active;
+
+        if (used == 1 && fri_remainder_coefficients == 1) {
+            // the remainder is the coefficients of a polynomial whose degree
+            // is bounded by their number
+            var size_remainder_length = fri_remainder_length(1, lde_blowup_factor, remainder_size);
+            assert(size_remainder_length > 0);
+
+            // verify remainder commitment, the hash of all the coefficients
+            remainder_commitments[m] = HashElements(size_remainder_length);
+            for (var i = 0; i < size_remainder_length; i++) {
+                remainder_commitments[m].in[i] <== fri_remainder[i];
+            }
+            (remainder_commitments[m].out - remainder_commitment) * remainder_active[m] === 0;
+
+            for (var i = size_remainder_length; i < remainder_length; i++) {
+                fri_remainder[i] * remainder_active[m] === 0;
+            }
+        } else if (used == 1) {
+            assert(log_remainder_size > log_folding_factor);
+
+            for (var i = 0; i < num_queries; i++) {
+                remainder_checks[m][i] <== last_masks[i] * remainder_active[m];
+            }
+
+            // check remainder values against last level evaluations
+            remainder_selectors[m] = MultiSelector(remainder_size, num_queries);
+            for (var i = 0; i < remainder_size; i++) {
+                remainder_selectors[m].in[i] <== fri_remainder[i];
+            }
+            for (var i = 0; i < num_queries; i++) {
+                remainder_selectors[m].indexes[i] <== last_points[i];
+            }
+            for (var i = 0; i < num_queries; i++) {
+                (remainder_selectors[m].out[i] - last_evaluations[i]) * remainder_checks[m][i] === 0;
+            }
+
+            // transpose remainder into a matrix of width folding_factor and hash each line
+            var remainder_leaves_size = remainder_size \ folding_factor;
+            for (var i = 0; i < remainder_leaves_size; i++) {
+                remainder_hashers[m][i] = HashElements(folding_factor);
+                for (var j = 0; j < folding_factor; j++) {
+                    remainder_hashers[m][i].in[j] <== fri_remainder[i + j * remainder_leaves_size];
+                }
+            }
+
+            // verify remainder commitment
+            remainder_merkle_trees[m] = MerkleTree(remainder_leaves_size, merkle_arity);
+            for (var i = 0; i < remainder_leaves_size; i++) {
+                remainder_merkle_trees[m].leaves[i] <== remainder_hashers[m][i].out;
+            }
+            (remainder_merkle_trees[m].root - remainder_commitment) * remainder_active[m] === 0;
+
+            // VERIFY REMAINDER DEGREE
+
+            // interpolate fri_remainder
+            remainder_interpolations[m] = FFTInterpolate(remainder_size, addicity);
+            remainder_interpolations[m].addicity_root <== addicity_root;
+            for (var i = 0; i < remainder_size; i++) {
+                remainder_interpolations[m].ys[i] <== fri_remainder[i];
+            }
+
+            // calculate the degree of the remainder
+            remainder_degrees[m] = PolynomialDegree(remainder_size);
+            for (var i = 0; i < remainder_size; i++) {
+                remainder_degrees[m].in[i] <== remainder_interpolations[m].out[i];
+            }
+
+            // make sure that remainder degree < remainder_size / lde_blowup_factor
+            remainder_degree_lts[m] = LessThan(log_remainder_size + 1);
+            remainder_degree_lts[m].in[0] <== remainder_degrees[m].out;
+            remainder_degree_lts[m].in[1] <== remainder_size \ lde_blowup_factor;
+            (remainder_degree_lts[m].out - 1) * remainder_active[m] === 0;
+        }
     }
+}
 
-    // calculate the degree of the remainder
-    remainder_degree = PolynomialDegree(remainder_size);
-    for (var i = 0; i < remainder_size; i++) {
-        remainder_degree.in[i] <== remainder_interpolation.out[i];
+/**
+ * Length of the FRI remainder: the number of coefficients of the remainder
+ * polynomial if it is sent as coefficients, its number of evaluations otherwise.
+ *
+ * ARGUMENTS:
+ * - fri_remainder_coefficients: 1 if the remainder is sent as coefficients, 0 otherwise
+ * - lde_blowup_factor: LDE blowup factor
+ * - remainder_size: size of the domain of the remainder
+ */
+function fri_remainder_length(fri_remainder_coefficients, lde_blowup_factor, remainder_size) {
+    if (fri_remainder_coefficients == 1) {
+        return remainder_size \ lde_blowup_factor;
     }
+    return remainder_size;
+}
 
-    // make sure that remainder degree <= max degree (max_degree_plus_1[num_fri_layers] - 1)
-    remainder_degree_lt = LessThan(tree_depth);
-    remainder_degree_lt.in[0] <== remainder_degree.out;
-    remainder_degree_lt.in[1] <== max_degree_plus_1[num_fri_layers];
-    remainder_degree_lt.out === 1;
+/**
+ * Largest length of the FRI remainder over the supported trace lengths, see
+ * fri_remainder_length and Verify.
+ */
+function fri_max_remainder_length(
+    folding_factor,
+    fri_max_remainder_size,
+    fri_remainder_coefficients,
+    lde_blowup_factor,
+    max_log_trace_length,
+    merkle_arity,
+    min_log_trace_length
+) {
+    var log_blowup = numbits(lde_blowup_factor) - 1;
+    var log_folding_factor = numbits(folding_factor) - 1;
+    var log_max_remainder_size = numbits(fri_max_remainder_size) - 1;
+    var log_merkle_arity = numbits(merkle_arity) - 1;
+
+    var max_length = 0;
+    for (var log_trace_length = min_log_trace_length; log_trace_length <= max_log_trace_length; log_trace_length += log_merkle_arity) {
+        var log_lde_domain_size = log_trace_length + log_blowup;
+        var num_layers = fri_num_layers(log_lde_domain_size, log_folding_factor, log_max_remainder_size);
+        var remainder_size = 2 ** (log_lde_domain_size - log_folding_factor * num_layers);
+        var length = fri_remainder_length(fri_remainder_coefficients, lde_blowup_factor, remainder_size);
+        if (length > max_length) {
+            max_length = length;
+        }
+    }
+    return max_length;
 }
 
 /**
@@ -0,0 +1,47 @@
+pragma circom 2.0.0;
+
+include "../poseidon/poseidon.circom";
+
+/**
+ * Hash templates of the verifier for proofs generated with the Poseidon
+ * hasher of winter-crypto. The main file of the verifier includes either this
+ * file or hash/poseidon2.circom, which define the same templates.
+ */
+
+/**
+ * Hash a sequence of field elements, as the hash_elements method of the
+ * hasher. This is also the merge of two digests and the draw of the public
+ * coin.
+ *
+ * ARGUMENTS:
+ * - l: length of the input sequence.
+ */
+template HashElements(l) {
+    signal input in[l];
+    signal output out;
+
+    component hash = Poseidon(l);
+    for (var i = 0; i < l; i++) {
+        hash.in[i] <== in[i];
+    }
+    out <== hash.out;
+}
+
+/**
+ * Hash the children of a node of the Merkle tree. This is a single
+ * permutation of width arity + 2, which matches the merge_many method of the
+ * hasher.
+ *
+ * ARGUMENTS:
+ * - arity: number of children of each node of the tree (2, 4 or 8)
+ */
+template HashMerge(arity) {
+    signal input in[arity];
+    signal output out;
+
+    component hash = PoseidonHash(arity, arity + 2, 1);
+    for (var i = 0; i < arity; i++) {
+        hash.in[i] <== in[i];
+    }
+    out <== hash.out[0];
+}
@@ -0,0 +1,47 @@
+pragma circom 2.0.0;
+
+include "../poseidon2/poseidon2.circom";
+
+/**
+ * Hash templates of the verifier for proofs generated with the Poseidon2
+ * hasher of winter-crypto. The main file of the verifier includes either this
+ * file or hash/poseidon.circom, which define the same templates.
+ */
+
+/**
+ * Hash a sequence of field elements, as the hash_elements method of the
+ * hasher. This is also the merge of two digests and the draw of the public
+ * coin.
+ *
+ * ARGUMENTS:
+ * - l: length of the input sequence.
+ */
+template HashElements(l) {
+    signal input in[l];
+    signal output out;
+
+    component hash = Poseidon2(l);
+    for (var i = 0; i < l; i++) {
+        hash.in[i] <== in[i];
+    }
+    out <== hash.out;
+}
+
+/**
+ * Hash the children of a node of the Merkle tree, which matches the merge
+ * method of the hasher. Only binary Merkle trees are supported.
+ *
+ * ARGUMENTS:
+ * - arity: number of children of each node of the tree, which must be 2
+ */
+template HashMerge(arity) {
+    signal input in[arity];
+    signal output out;
+
+    assert(arity == 2);
+
+    component hash = Poseidon2(2);
+    hash.in[0] <== in[0];
+    hash.in[1] <== in[1];
+    out <== hash.out;
+}
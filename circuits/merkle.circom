@@ -1,146 +1,406 @@
 pragma circom 2.0.0;
 
-include "poseidon/poseidon.circom";
 include "utils.circom";
 
+// the HashElements and HashMerge templates are defined by the file of the
+// circuits/hash directory included by the main file of the verifier
+
 
 /**
- * Verify the validity of a Merkle opening, given the leaves,
- * the missing nodes and the expected root.
+ * Place a node among its siblings, in the order of the children of their
+ * parent in the Merkle tree.
  *
- * INPUTS
- * - index: the index of the leaf to check in the original tree
- * - opening[depth + 1]: the authentication path to verify. the first element being the leaf
- * - root: the expected root of the tree
+ * ARGUMENTS:
+ * - arity: number of children of each node of the tree
+ *
+ * INPUTS:
+ * - position_bits[log2(arity)]: LE bits of the position of the node among
+ *                               the children of its parent
+ * - node
+ * - siblings[arity - 1]: the other children, in order
+ *
+ * OUTPUTS: children[arity]
  */
-template MerkleOpeningRoot(depth, leaf_size) {
-    signal input index;
-    signal input leaf[leaf_size];
-    signal input opening[depth];
-    signal output root;
+template MerkleChildren(arity) {
+    var log_arity = numbits(arity) - 1;
+    assert(arity == 2 ** log_arity && log_arity > 0);
+
+    signal input position_bits[log_arity];
+    signal input node;
+    signal input siblings[arity - 1];
+    signal output children[arity];
+
+    // one-hot decompositions of the lowest b + 1 position bits, the one of
+    // b + 1 bits taking 2 ** (b + 1) elements from index 2 ** (b + 1) - 2
+    signal onehot[2 * arity - 2];
+    signal shifted[arity - 1];
+
+    onehot[0] <== 1 - position_bits[0];
+    onehot[1] <== position_bits[0];
+    for (var b = 1; b < log_arity; b++) {
+        var half = 2 ** b;
+        var previous = half - 2;
+        var current = 2 * half - 2;
+        for (var k = 0; k < half; k++) {
+            onehot[current + k + half] <== onehot[previous + k] * position_bits[b];
+            onehot[current + k] <== onehot[previous + k] - onehot[current + k + half];
+        }
+    }
 
-    component index_bits = Num2Bits(depth);
-    component node_index_bits = Num2Bits(depth + 1);
-    component switch[depth];
-    component P[depth];
-    component P_leaf = Poseidon(leaf_size);
+    // children before the node are the siblings of the same index, and
+    // children after it the siblings of the previous index
+    var before = 0;
+    var last = node;
+    for (var k = 0; k < arity - 1; k++) {
+        var is_node = onehot[arity - 2 + k];
+        if (k == 0) {
+            shifted[k] <== siblings[k];
+        } else {
+            shifted[k] <== siblings[k] + before * (siblings[k - 1] - siblings[k]);
+        }
+        children[k] <== shifted[k] + is_node * (node - shifted[k]);
 
-    var n = 2 ** depth;
+        before += is_node;
+        last += siblings[k] - children[k];
+    }
 
-    // hash leaf
-    for (var i = 0; i < leaf_size; i++) {
-        P_leaf.in[i] <== leaf[i];
+    // the children are a permutation of the node and its siblings
+    children[arity - 1] <== last;
+}
+
+
+/**
+ * Number of levels below the root of a Merkle tree whose nodes are hashed
+ * once by MerkleBatchVerify, rather than once per opening. Levels are added
+ * while the deepest one has fewer nodes than there are openings, hashing the
+ * parents of its nodes being then cheaper than hashing a node per opening.
+ */
+function merkle_top_depth(amount, depth, arity) {
+    var top_depth = 0;
+    while (top_depth < depth && arity ** top_depth < amount) {
+        top_depth += 1;
     }
+    return top_depth;
+}
 
-    // turn index into LE bits
-    index_bits.in <== index;
-    node_index_bits.in <== index + n;
 
-    // switch over index parity (1st bit)
-    switch[0] = Switcher();
-    switch[0].sel <== index_bits.out[0];
-    switch[0].L <== P_leaf.out;
-    switch[0].R <== opening[0];
+/**
+ * Number of nodes of the levels 1 to top_depth of a Merkle tree.
+ */
+function merkle_num_top_nodes(top_depth, arity) {
+    return (arity ** (top_depth + 1) - 1) \ (arity - 1) - 1;
+}
 
-    // hash leaves
-    P[0] = Poseidon(2);
-    P[0].in[0] <== switch[0].outL;
-    P[0].in[1] <== switch[0].outR;
 
-    for (var i = 1; i < depth; i++) {
-        // switch over (index >> i) parity (i-th bit)
-        switch[i] = Switcher();
-        switch[i].sel <== index_bits.out[i];
-        switch[i].L <== P[i - 1].out;
-        switch[i].R <== opening[i];
+/**
+ * Compute the nodes of a Merkle tree above a leaf, given the leaf and the
+ * authentication path up to the highest node.
+ *
+ * ARGUMENTS:
+ * - depth: number of levels between the leaf and the highest node
+ * - leaf_size
+ * - arity: number of children of each node of the tree (2, 4 or 8)
+ *
+ * INPUTS:
+ * - position_bits[depth * log2(arity)]: LE bits of the position of the leaf
+ *                                       in the subtree of the highest node
+ * - leaf[leaf_size]
+ * - opening[depth * (arity - 1)]: the authentication path, the arity - 1
+ *                                 siblings of each level in order
+ *
+ * OUTPUTS:
+ * - nodes[depth + 1]: the node of each level above the leaf, from the hash of
+ *                     the leaf to the highest node
+ */
+template MerklePathNodes(depth, leaf_size, arity) {
+    var log_arity = numbits(arity) - 1;
+
+    signal input position_bits[depth * log_arity];
+    signal input leaf[leaf_size];
+    signal input opening[depth * (arity - 1)];
+    signal output nodes[depth + 1];
+
+    component children[depth];
+    component P[depth];
+    component P_leaf = HashElements(leaf_size);
 
-        // hash previous hash and node
-        P[i] = Poseidon(2);
-        P[i].in[0] <== switch[i].outL;
-        P[i].in[1] <== switch[i].outR;
+    // hash leaf
+    for (var i = 0; i < leaf_size; i++) {
+        P_leaf.in[i] <== leaf[i];
     }
+    nodes[0] <== P_leaf.out;
+
+    for (var i = 0; i < depth; i++) {
+        // place the previous hash among its siblings, at the position given
+        // by the i-th base arity digit of the position
+        children[i] = MerkleChildren(arity);
+        for (var b = 0; b < log_arity; b++) {
+            children[i].position_bits[b] <== position_bits[i * log_arity + b];
+        }
+        children[i].node <== nodes[i];
+        for (var j = 0; j < arity - 1; j++) {
+            children[i].siblings[j] <== opening[i * (arity - 1) + j];
+        }
 
-    root <== P[depth - 1].out;
+        // hash the children
+        P[i] = HashMerge(arity);
+        for (var j = 0; j < arity; j++) {
+            P[i].in[j] <== children[i].children[j];
+        }
+        nodes[i + 1] <== P[i].out;
+    }
 }
 
 
 /**
- * Verify the validity of a number of Merkle openings, against a given root.
+ * Verify the validity of a batch of Merkle openings against a given root,
+ * hashing each node of the top levels of the tree once.
+ *
+ * The depth of the tree is a signal, between min_depth and max_depth. The
+ * top_depth = merkle_top_depth(amount, min_depth, arity) levels below the root
+ * are given by top_nodes, level l holding the arity ** l nodes of depth l from
+ * index (arity ** l - 1) / (arity - 1) - 1. Each opening is a path from its
+ * leaf to a node of depth top_depth, which must match the given node. The
+ * nodes above are computed from their children when they are ancestors of an
+ * opening, and taken from top_nodes otherwise: they are the siblings of the
+ * Winterfell batch proof, the other values of top_nodes being ignored.
+ *
+ * This is not a full multiproof: the nodes below the top levels are hashed
+ * once per opening, even when openings share them. Random query positions
+ * rarely do, and the circuit is sized for the case where they do not, so only
+ * the top levels, which the openings always share, are deduplicated.
+ *
+ * The openings of the mask are ignored, and so is the root if none of them
+ * are verified.
+ *
+ * ARGUMENTS:
+ * - amount: number of openings
+ * - min_depth: smallest number of levels of the tree
+ * - max_depth: largest number of levels of the tree
+ * - leaf_size
+ * - arity: number of children of each node of the tree (2, 4 or 8)
  *
  * INPUTS:
+ * - depth_selector[max_depth - min_depth + 1]: binary array with a single 1,
+ *                                             at index depth - min_depth
+ * - indexes[amount]: the indexes of the leaves in the tree
+ * - leaves[amount][leaf_size]
+ * - mask[amount]: binary array, dictating which openings to verify
+ * - openings[amount][(max_depth - top_depth) * (arity - 1)]: the
+ *                                                           authentication
+ *                                                           paths below the
+ *                                                           top levels,
+ *                                                           padded with
+ *                                                           zeroes
  * - root: the expected root of the tree
- * - openings[amount][depth + 1]: the authentication paths to verify, the
-                                  first element of each being the leaf
- * - indexes[amount]: the indexes of the authentication paths
+ * - top_nodes[merkle_num_top_nodes(top_depth, arity)]: the nodes of the top
+ *                                                      levels
  */
-template MerkleOpeningsVerify(amount, depth, leaf_size) {
+template MerkleBatchVerifyMasked(amount, min_depth, max_depth, leaf_size, arity) {
+    var log_arity = numbits(arity) - 1;
+    var num_depths = max_depth - min_depth + 1;
+    var top_depth = merkle_top_depth(amount, min_depth, arity);
+    var lower_depth = max_depth - top_depth;
+    var num_top_nodes = merkle_num_top_nodes(top_depth, arity);
+    var width = arity ** top_depth;
+    // index of the first node of depth top_depth in top_nodes
+    var last_offset = num_top_nodes - width;
+
+    signal input depth_selector[num_depths];
     signal input indexes[amount];
     signal input leaves[amount][leaf_size];
-    signal input openings[amount][depth];
+    signal input mask[amount];
+    signal input openings[amount][lower_depth * (arity - 1)];
     signal input root;
-
-    component V[amount];
+    signal input top_nodes[num_top_nodes];
+
+    // nodes of the top levels, laid out as top_nodes, and whether they are
+    // ancestors of a verified opening
+    signal covered[num_top_nodes];
+    signal nodes[num_top_nodes];
+    signal node_terms[amount][num_depths];
+    signal path_nodes[amount];
+    signal position_terms[amount][num_depths];
+    signal selected[amount][width];
+    signal selected_nodes[amount][width];
+
+    component coverage_checks[width];
+    component coverage_ors[num_top_nodes];
+    component hashers[num_top_nodes + 1 - width];
+    component index_bits[amount];
+    component paths[amount];
+    component positions[amount];
+    component verified = OrN(amount);
+
+    // LOWER LEVELS
+    // ==========================================================================
 
     for (var i = 0; i < amount; i++) {
-        V[i] = MerkleOpeningRoot(depth, leaf_size);
-        V[i].index <== indexes[i];
+        // turn index into LE bits, the lower levels using the low bits
+        index_bits[i] = Num2Bits(max_depth * log_arity);
+        index_bits[i].in <== indexes[i];
+
+        paths[i] = MerklePathNodes(lower_depth, leaf_size, arity);
+        for (var b = 0; b < lower_depth * log_arity; b++) {
+            paths[i].position_bits[b] <== index_bits[i].out[b];
+        }
         for (var j = 0; j < leaf_size; j++) {
-            V[i].leaf[j] <== leaves[i][j];
+            paths[i].leaf[j] <== leaves[i][j];
+        }
+        for (var j = 0; j < lower_depth * (arity - 1); j++) {
+            paths[i].opening[j] <== openings[i][j];
         }
-        for (var j = 0; j < depth; j++) {
-            V[i].opening[j] <== openings[i][j];
+
+        // node of depth top_depth above the leaf, and its position among the
+        // nodes of depth top_depth, for the selected depth of the tree
+        var node = 0;
+        var position = 0;
+        for (var d = 0; d < num_depths; d++) {
+            var path_depth = min_depth + d - top_depth;
+            var depth_position = 0;
+            for (var b = 0; b < top_depth * log_arity; b++) {
+                depth_position += index_bits[i].out[path_depth * log_arity + b] * 2 ** b;
+            }
+
+            if (num_depths == 1) {
+                node_terms[i][d] <== paths[i].nodes[path_depth];
+                position_terms[i][d] <== depth_position;
+            } else {
+                node_terms[i][d] <== depth_selector[d] * paths[i].nodes[path_depth];
+                position_terms[i][d] <== depth_selector[d] * depth_position;
+            }
+            node += node_terms[i][d];
+            position += position_terms[i][d];
         }
+        path_nodes[i] <== node;
 
-        V[i].root === root;
+        positions[i] = OneHot(width);
+        positions[i].in <== position;
+        for (var j = 0; j < width; j++) {
+            selected[i][j] <== positions[i].out[j] * mask[i];
+        }
+
+        verified.in[i] <== mask[i];
+    }
+
+    // TOP LEVELS
+    // ==========================================================================
+
+    // the computed nodes must match the given nodes of depth top_depth, or
+    // the root if there are no top levels
+    if (top_depth > 0) {
+        for (var j = 0; j < width; j++) {
+            var count = 0;
+            for (var i = 0; i < amount; i++) {
+                count += selected[i][j];
+            }
+            coverage_checks[j] = IsZero();
+            coverage_checks[j].in <== count;
+            covered[last_offset + j] <== 1 - coverage_checks[j].out;
+            nodes[last_offset + j] <== top_nodes[last_offset + j];
+        }
+    }
+    for (var i = 0; i < amount; i++) {
+        var lc = 0;
+        for (var j = 0; j < width; j++) {
+            if (top_depth > 0) {
+                selected_nodes[i][j] <== selected[i][j] * nodes[last_offset + j];
+            } else {
+                selected_nodes[i][j] <== selected[i][j] * root;
+            }
+            lc += selected_nodes[i][j];
+        }
+        path_nodes[i] * mask[i] === lc;
+    }
+
+    // hash the ancestors of the computed nodes, up to the root
+    for (var k = 0; k < top_depth; k++) {
+        var l = top_depth - 1 - k;
+        // number of nodes above the levels l and l + 1
+        var first = (arity ** l - 1) \ (arity - 1);
+        var first_child = (arity ** (l + 1) - 1) \ (arity - 1);
+        for (var j = 0; j < arity ** l; j++) {
+            hashers[first + j] = HashMerge(arity);
+            for (var c = 0; c < arity; c++) {
+                hashers[first + j].in[c] <== nodes[first_child - 1 + arity * j + c];
+            }
+
+            if (l == 0) {
+                (hashers[0].out - root) * verified.out === 0;
+            } else {
+                var index = first - 1 + j;
+                coverage_ors[index] = OrN(arity);
+                for (var c = 0; c < arity; c++) {
+                    coverage_ors[index].in[c] <== covered[first_child - 1 + arity * j + c];
+                }
+                covered[index] <== coverage_ors[index].out;
+                nodes[index] <== top_nodes[index] + covered[index] * (hashers[first + j].out - top_nodes[index]);
+            }
+        }
     }
 }
 
 
-template MerkleOpeningsVerifyMasked(amount, depth, leaf_size) {
+/**
+ * Verify the validity of a batch of Merkle openings against a given root. See
+ * MerkleBatchVerifyMasked.
+ */
+template MerkleBatchVerify(amount, min_depth, max_depth, leaf_size, arity) {
+    var num_depths = max_depth - min_depth + 1;
+    var top_depth = merkle_top_depth(amount, min_depth, arity);
+
+    signal input depth_selector[num_depths];
     signal input indexes[amount];
     signal input leaves[amount][leaf_size];
-    signal input mask[amount];
-    signal input openings[amount][depth];
+    signal input openings[amount][(max_depth - top_depth) * (arity - 1)];
     signal input root;
+    signal input top_nodes[merkle_num_top_nodes(top_depth, arity)];
 
-    component V[amount];
+    component V = MerkleBatchVerifyMasked(amount, min_depth, max_depth, leaf_size, arity);
 
+    V.root <== root;
+    for (var d = 0; d < num_depths; d++) {
+        V.depth_selector[d] <== depth_selector[d];
+    }
     for (var i = 0; i < amount; i++) {
-        V[i] = MerkleOpeningRoot(depth, leaf_size);
-        V[i].index <== indexes[i];
+        V.indexes[i] <== indexes[i];
+        V.mask[i] <== 1;
         for (var j = 0; j < leaf_size; j++) {
-            V[i].leaf[j] <== leaves[i][j];
+            V.leaves[i][j] <== leaves[i][j];
         }
-        for (var j = 0; j < depth; j++) {
-            V[i].opening[j] <== openings[i][j];
+        for (var j = 0; j < (max_depth - top_depth) * (arity - 1); j++) {
+            V.openings[i][j] <== openings[i][j];
         }
-
-        (V[i].root - root) * mask[i] === 0;
+    }
+    for (var j = 0; j < merkle_num_top_nodes(top_depth, arity); j++) {
+        V.top_nodes[j] <== top_nodes[j];
     }
 }
 
 
 /**
- * Compute the layer of a Poseidon-based Merkle tree.
+ * Compute the layer of a Merkle tree.
  *
  * ARGUMENTS:
- * - N: number of children nodes (must be even)
+ * - N: number of children nodes (must be a multiple of arity)
+ * - arity: number of children of each node of the tree
  *
  * INPUTS: children[N]
- * OUTPUTS: parents[N \ 2]
+ * OUTPUTS: parents[N \ arity]
  */
-template MerkleTreeLayer(N) {
+template MerkleTreeLayer(N, arity) {
     signal input children[N];
-    signal output parents[N \ 2];
+    signal output parents[N \ arity];
 
-    component hash[N \ 2];
+    component hash[N \ arity];
 
-    assert(N & 1 == 0);
+    assert(N % arity == 0);
 
-    for (var i = 0; i < N\2; i++) {
-        hash[i] = Poseidon(2);
-        hash[i].in[0] <== children[2 * i];
-        hash[i].in[1] <== children[2 * i + 1];
+    for (var i = 0; i < N \ arity; i++) {
+        hash[i] = HashMerge(arity);
+        for (var j = 0; j < arity; j++) {
+            hash[i].in[j] <== children[arity * i + j];
+        }
         parents[i] <== hash[i].out;
     }
 }
@@ -150,37 +410,38 @@ template MerkleTreeLayer(N) {
  * Compute a Merkle tree root.
  *
  * ARGUMENTS:
- * - N: number of leaves. It must be a power of two.
+ * - N: number of leaves. It must be a power of arity.
+ * - arity: number of children of each node of the tree
  *
  * INPUTS: leaves[N]
  * OUTPUTS: root
  */
-template MerkleTree(N) {
+template MerkleTree(N, arity) {
     signal input leaves[N];
     signal output root;
 
     var size = N;
     var depth = 0;
-    while (2 ** depth < N) {
+    while (arity ** depth < N) {
         depth += 1;
     }
 
     component layer[depth];
 
     // build first layer
-    layer[0] = MerkleTreeLayer(N);
+    layer[0] = MerkleTreeLayer(N, arity);
     for (var j = 0; j < N; j++) {
         layer[0].children[j] <== leaves[j];
     }
-    size \= 2;
+    size \= arity;
 
     // build all subsequent layers
     for (var i = 1; i < depth; i++) {
-        layer[i] = MerkleTreeLayer(size);
+        layer[i] = MerkleTreeLayer(size, arity);
         for (var j = 0; j < size; j++) {
             layer[i].children[j] <== layer[i - 1].parents[j];
         }
-        size \= 2;
+        size \= arity;
     }
 
     root <== layer[depth - 1].parents[0];
@@ -7,6 +7,9 @@ pragma circom 2.0.0;
  * out-of-domain frame. This template does not return any signal, its purpose is
  * just to create the 'channel_result === evaluation_result' constraint
  *
+ * The boundary constraints are evaluated by the AIRAssertions template of the
+ * AIR, which takes the trace length as an input signal.
+ *
  * ARGUMENTS:
  * - See verify.circom
  *
@@ -14,7 +17,11 @@ pragma circom 2.0.0;
  * - boundary_coeffs: Fiat-Shamir coefficients for the boundary constraints.
  * - channel_ood_evaluations: Out Of Domain evaluations given in the proof.
  * - frame: the Out Of Domain frame over which the constraints will be evaluated.
+ * - g_trace: generator of the trace domain
+ * - length_selector: binary array with a single 1, at index
+     log2(trace_length) - min_log_trace_length
  * - public_inputs: inputs used for the calculation
+ * - trace_length: number of steps in the proven calculation
  * - transition_coeffs: Fiat-Shamir coefficients for the transition constraints.
  * - z: Out Of Domain point of evaluation, generated in the public coin.
  *
@@ -25,17 +32,24 @@ pragma circom 2.0.0;
  */
 template OodConsistencyCheck(
     ce_blowup_factor,
+    max_log_trace_length,
+    min_log_trace_length,
     num_assertions,
     num_public_inputs,
-    trace_length,
+    num_transition_exemptions,
     trace_width
 ) {
+    var max_trace_length = 2 ** max_log_trace_length;
+    var num_lengths = max_log_trace_length - min_log_trace_length + 1;
+
     signal input boundary_coeffs[num_assertions][2];
     signal input channel_ood_evaluations[trace_width];
     signal input frame[2][trace_width];
     signal input ood_frame_constraint_evaluation[trace_width];
     signal input g_trace;
+    signal input length_selector[num_lengths];
     signal input public_inputs[num_public_inputs];
+    signal input trace_length;
     signal input transition_coeffs[trace_width][2];
     signal input z;
 
@@ -43,6 +57,7 @@ template OodConsistencyCheck(
     signal boundary_temp_2[num_assertions];
     signal channel_ood_pow[trace_width];
     signal evaluation_result[trace_width + num_assertions];
+    signal exemptions_product[num_transition_exemptions];
     signal transition_divisor;
     signal transition_result;
     signal transition_temp[trace_width];
@@ -50,31 +65,49 @@ template OodConsistencyCheck(
     component AIR;
     component boundary_deg_adjustment[num_assertions];
     component evaluate_boundary_constraints;
+    component exemption_pow[num_transition_exemptions];
     component gpstep[num_assertions];
-    component gp_trace_len;
     component transition_deg_adjustment[trace_width];
     component xpn;
 
 
 
     // BUILDING TRANSITION DIVISOR
-    // for transition constraints, it is always the same : div(x) = (x**n)/(product i : 1 --> k : (x - g ** (n - i)))
+    // for transition constraints, it is always the same : div(x) = (x**n - 1)/(product i : 1 --> k : (x - g ** (n - i)))
     // The above divisor specifies that transition constraints must hold on all steps of the execution trace except for the last k steps.
-    // The default value for k is 1, n represent the trace length
+    // k is the number of transition exemptions (1 by default), n represent the trace length
 
-    gp_trace_len = Pow(trace_length - 1);
-    gp_trace_len.in <== g_trace;
+    for (var i = 0; i < num_transition_exemptions; i++) {
+        exemption_pow[i] = Pow_signal(max_log_trace_length);
+        exemption_pow[i].in <== g_trace;
+        exemption_pow[i].exp <== trace_length - 1 - i;
 
-    xpn = Pow(trace_length);
+        if (i == 0) {
+            exemptions_product[i] <== z - exemption_pow[i].out;
+        } else {
+            exemptions_product[i] <== exemptions_product[i - 1] * (z - exemption_pow[i].out);
+        }
+    }
+
+    // z ** trace_length, with trace_length = 2 ** (min_log_trace_length + i)
+    // for the selected length i
+    xpn = SelectSquare(max_log_trace_length + 1);
     xpn.in <== z;
-    transition_divisor <-- (xpn.out - 1) / (z - gp_trace_len.out);
-    transition_divisor * (z - gp_trace_len.out) === xpn.out - 1;
+    for (var i = 0; i < min_log_trace_length; i++) {
+        xpn.selector[i] <== 0;
+    }
+    for (var i = 0; i < num_lengths; i++) {
+        xpn.selector[min_log_trace_length + i] <== length_selector[i];
+    }
+    transition_divisor <-- (xpn.out - 1) / exemptions_product[num_transition_exemptions - 1];
+    transition_divisor * exemptions_product[num_transition_exemptions - 1] === xpn.out - 1;
 
     AIR = AIRTransitions(trace_width);
     for (var i = 0; i < trace_width; i++) {
-        transition_deg_adjustment[i] = Pow_signal(numbits(trace_length * ce_blowup_factor - 1));
+        transition_deg_adjustment[i] = Pow_signal(numbits(max_trace_length * ce_blowup_factor - 1));
         transition_deg_adjustment[i].in <== z;
-        transition_deg_adjustment[i].exp <== trace_length * ce_blowup_factor - AIR.transition_degree[i];
+        // composition degree + divisor degree - evaluation degree
+        transition_deg_adjustment[i].exp <== trace_length * ce_blowup_factor - 1 + trace_length - num_transition_exemptions - AIR.transition_degree[i] * (trace_length - 1);
         transition_temp[i] <== transition_coeffs[i][0] + transition_coeffs[i][1] * transition_deg_adjustment[i].out;
 
         if (i == 0) {
@@ -94,11 +127,12 @@ template OodConsistencyCheck(
     evaluate_boundary_constraints = AIRAssertions(
         num_assertions,
         num_public_inputs,
-        trace_length,
+        num_transition_exemptions,
         trace_width
     );
 
     evaluate_boundary_constraints.g_trace <== g_trace;
+    evaluate_boundary_constraints.trace_length <== trace_length;
     evaluate_boundary_constraints.z <== z;
 
     for (var i = 0; i < num_public_inputs; i++) {
@@ -119,7 +153,7 @@ template OodConsistencyCheck(
 
         // divisor for boundary constraints
         // for single constraints the divisor is always x - g**step
-        gpstep[i] = Pow_signal(numbits(trace_length));
+        gpstep[i] = Pow_signal(numbits(max_trace_length));
         gpstep[i].in <== g_trace;
         gpstep[i].exp <== evaluate_boundary_constraints.step[i];
 
@@ -148,4 +182,4 @@ template OodConsistencyCheck(
     }
 
     channel_result === evaluation_result[trace_width + num_assertions - 1];
-}
\ No newline at end of file
+}
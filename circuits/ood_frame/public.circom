@@ -0,0 +1,23 @@
+pragma circom 2.0.0;
+
+/**
+ * OOD frame check of the verifier for proofs whose OOD trace frame and OOD
+ * frame constraint evaluations are public signals. The main file of the
+ * verifier includes either this file or ood_frame/zero_knowledge.circom, which
+ * define the same template.
+ */
+
+/**
+ * Nothing is checked in the circuit: the transition constraints are evaluated
+ * over the public OOD trace frame alongside the Groth16 verification, and
+ * compared with the public OOD frame constraint evaluations.
+ *
+ * INPUTS:
+ * - frame: out of domain trace frame
+ * - ood_frame_constraint_evaluation: transition constraint evaluations over
+ *   the frame
+ */
+template OodFrameCheck(trace_width) {
+    signal input frame[2][trace_width];
+    signal input ood_frame_constraint_evaluation[trace_width];
+}
@@ -0,0 +1,33 @@
+pragma circom 2.0.0;
+
+/**
+ * OOD frame check of the zero-knowledge verifier. The OOD trace frame and the
+ * OOD frame constraint evaluations are private, and the public inputs of the
+ * computation are therefore the only values revealed by the proof. The main
+ * file of the verifier includes either this file or ood_frame/public.circom,
+ * which define the same template.
+ */
+
+/**
+ * Evaluate the transition constraints over the OOD trace frame, using the
+ * AIRTransitionEvaluations template of the AIR, and check them against the OOD
+ * frame constraint evaluations.
+ *
+ * INPUTS:
+ * - frame: out of domain trace frame
+ * - ood_frame_constraint_evaluation: transition constraint evaluations over
+ *   the frame
+ */
+template OodFrameCheck(trace_width) {
+    signal input frame[2][trace_width];
+    signal input ood_frame_constraint_evaluation[trace_width];
+
+    component transitions = AIRTransitionEvaluations(trace_width);
+    for (var i = 0; i < trace_width; i++) {
+        transitions.frame[0][i] <== frame[0][i];
+        transitions.frame[1][i] <== frame[1][i];
+    }
+    for (var i = 0; i < trace_width; i++) {
+        transitions.evaluations[i] === ood_frame_constraint_evaluation[i];
+    }
+}
@@ -1,26 +1,498 @@
 pragma circom 2.0.0;
+
 function T() {
-return 4 ;}
+    return 4;
+}
 
-function R_F() {
-return 8 ;}
+function R_F(t) {
+    if (t == 4) {
+        return 8;
+    } else if (t == 6) {
+        return 8;
+    } else if (t == 10) {
+        return 8;
+    } else {
+        return 0;
+    }
+}
 
-function R_P() {
-return 58 ;}
+function R_P(t) {
+    if (t == 4) {
+        return 58;
+    } else if (t == 6) {
+        return 57;
+    } else if (t == 10) {
+        return 57;
+    } else {
+        return 0;
+    }
+}
 
-function  POSEIDON_M(t)  {
- return  [[13682032250801179187570186285176733763308291836613942991479520590256716961232, 19807656384719591425791552925292162125472531523842931528010588787546530670933, 8480858688710537284044155618254509512044353645728868286672343037476095514727, 12214991638150566385351300014811456752648014435654858529043329724953935400908],[932609354172568758621898802162548910750085747674861943621300469229428996293, 11975570439724300049427624059185735298466199651049665650528367284730050080061, 2496991100123035306946304087156754650943712752477134966408347869013799258614, 15203151410367761253872155638116635593972103817310453963894412905622580538624],[15769083491408695821293953432311849404675481052199955565246542172728344652638, 11172925168439976744262347703667110058577952721340171328243610295065712584680, 823775840048352895109063666787603553255555875879064484340739496936638354115, 14873589042569363018733567413594526490824580536169546361376239438852440570232],[1993254594848538629647767427467154242266341087089807051511845988684169892843, 538948912143034996708681690851566753393255887231536901372242074252209202461, 18572153612839353128807930582057656558611992624574973691395192701502449938484, 14532782355590045100198562172324991242037056455059852801364228131654218898265]] ;
+function POSEIDON_M(t) {
+    if (t == 4) {
+        return [
+            [13682032250801179187570186285176733763308291836613942991479520590256716961232, 19807656384719591425791552925292162125472531523842931528010588787546530670933, 8480858688710537284044155618254509512044353645728868286672343037476095514727, 12214991638150566385351300014811456752648014435654858529043329724953935400908],
+            [932609354172568758621898802162548910750085747674861943621300469229428996293, 11975570439724300049427624059185735298466199651049665650528367284730050080061, 2496991100123035306946304087156754650943712752477134966408347869013799258614, 15203151410367761253872155638116635593972103817310453963894412905622580538624],
+            [15769083491408695821293953432311849404675481052199955565246542172728344652638, 11172925168439976744262347703667110058577952721340171328243610295065712584680, 823775840048352895109063666787603553255555875879064484340739496936638354115, 14873589042569363018733567413594526490824580536169546361376239438852440570232],
+            [1993254594848538629647767427467154242266341087089807051511845988684169892843, 538948912143034996708681690851566753393255887231536901372242074252209202461, 18572153612839353128807930582057656558611992624574973691395192701502449938484, 14532782355590045100198562172324991242037056455059852801364228131654218898265]
+        ];
+    } else if (t == 6) {
+        return [
+            [18604790440163502403509907022708665294255623333833911441550601634319890759920, 13969481149561469393473072941811176942192838800125595169358590051747772383598, 5070200533275752423171838363158687836032633193228211955335667334313706043691, 5788760339159456949577610727426882541477855549634102328924627198793627313671, 4935089264840789942516811090203374022113311689486010562878684594723681301129, 9888245597518120997841365408306680327530790763564967034250989838713031188590],
+            [16221252769449621891778558514519119337301360649181930116591506728533479210164, 5811089755710128401877380623469203700688015927427576738232503566067826197093, 18698871638527318600914522147354686107323161334458516997865277210176933886625, 21295408385020331350461118403579650617479302176184953680131804613317349496890, 18491044217164968879288917252261678185598760319935430996104644563694813261961, 1699967620829893808481772747347243951109522792042485335929667993179203479181],
+            [9367928370515928807785004577344387478982346613598115025100917804851557397383, 21851427942272104393356971084668622464301557844609490603290349346500950164544, 17291366434244128916794428179493241158269692041133886280956094955576313899607, 6187116679247239961561459577146210687772831867817725261002842431557660916351, 9153105040829754057848063488250533598518682046435467782368979214342840907484, 418537200302488853883001375858261327586879525204774188886487976673935396825],
+            [20616114076522717593501521651270635940743909372239873918281246571805428947449, 2737722775418917629043655724796526712182790924859781452975061131103747117556, 8624959476914091818571706568475477560878462239216129434087889756311604976298, 7680315184329016963314722358045384378317949364926237926427977135288771405295, 600268992709867111654714077696004426861608483130918875853755413835878958911, 18726049826448967200674820348787067782562547961131701168248800285380706189317],
+            [14809208476841095579222349344089057756098354064739757844761315534872929666858, 11257767420631475692528732478611780833779248623554173576380820828364691592736, 20247750274699778132504942790627962010429002067067027315778215237950838501146, 6279473341016676174640543846572140155407234929240881268361316234575530203358, 5240942208523620581737728536533949327756723009330171501389675195334168548849, 14842604119775736815590288049490311243981375083341869649766182195502962693897],
+            [12061067608669655151609145929815133516911631574489323823207510204094146677628, 2643726646160051410968378028028892737239233381547645461806506225492461103273, 20604138380277141232338686714070820069973320480070159148547445754318648776079, 21797362640042362768969424598955870386113929073378589660589569339181573252982, 8336443777715035528087957615781969000711079713495066347797404722039803328096, 5732634470417168638399434359393040700226091329037746569800623334412013720027]
+        ];
+    } else if (t == 10) {
+        return [
+            [2375326280684828385181441823935580489283181336736930855154573908611495388988, 19700111285403845224095612787058447861629983137329541455250737408696855455199, 1562339597589966262743389739803074590418740347058802208344712491268763762462, 20139631010248617334199012199746206703607534938431951350328252256049355578491, 18731433623428013448897208197252721999982094751204868623033965429125714843271, 369357642779322124758066349192095630258213277128965954549186479215928788535, 610466094683450237827086790630559123200785924178739576815945706851405787987, 3946551562923872160682227070171959375523716887521541249849801857246474717216, 13087341774083119712454419135892147736390218981258310271669991825194090031448, 1341862552378723945970813130450040355026249536976848177629589976366848524611],
+            [9325093535377664218911050065820297849718836536671958037364183073245510621083, 7090781664847354039459379061865969715301268456974677542129642543297506141647, 5152065666008213951556864171491136238244244779046546119983915897614488201115, 15376513775134297169279138982594293256817583287594591757536375415783000131198, 15983817531552164303630430156184359323836449811430249196561555763801987591838, 3821079322295578667071582620528950563078929157458698862165380529316304664723, 3510205893530414510047581695850095993662713226613549332837326208828558337877, 19411445063068255868527764768333296754899745104124930509212557648708634602665, 11674217884261815123778374913740046995697303685740981642231150822096253424927, 8905815167565281495227950135912077317609238135972471101162497215006021736680],
+            [16265319644996158547188028325302464266974899365751029012568691443275346542722, 7648515083936951807678596932010909166497505182874140138526297399247062888354, 7544962929895280489649338886271865437345701756834384013407598371396385826953, 15102587114791311706346947823090463716850965841533043542185726425454935291060, 15304233735326439322328345459842618517510868874679142073224968577880782783645, 1425111095781706733195700245747649023285769265748925505976591706577818199102, 21186092832179334669647393928931591050620663273045484437980057890382294611813, 18458875245397921524047571211063584912185624125481432501526556718438256327296, 14656871925148543554075242681714795139612276297050225675813917153979760847832, 4470666859553349098664975113439091550589104221709205046882680544502263302037],
+            [1148426937708791668377619457791611238431348630934472853382594059629507406291, 1549788918203816433639311457863404109017141390814895206932631973941013415182, 17955114752813479724221335296359538655250307126264833831032895558685255868378, 14755557794651749425151722848847625197351080027144350366352822334736886196429, 21160324078620097599092988692171163417613494298599176276087144707870761010725, 16908496194345570905938235081985734377524818772937405483661543224826137869368, 12561012795599449319072349565147894679224804429997401959655906737172751370336, 2259090353201173374438428371158178282045999122066065407693385293936076577773, 16176698622887380500583265700600868401338303448365378051081469555956046709434, 4259228589235598393886050722991961711224637837588877360983430698052814523712],
+            [15760206325563895894781668033982912992425737426265269547487114642614432763867, 14789901022853392467479501523668118763046139979351218844063323785978387675753, 4058454106132853370815896020609045987667458142328162181680551103406876845163, 7932760980856949206219453187565599684512842476914714238658998602778410341487, 20331506365459794637045551421722482800247028089864683039137080309464296611478, 5397512863464112924581283709554478394447228977609444331537069167288407687169, 12242779789835637222082652617608204579854258133010555673988247971880882779611, 6460708980672734890014142227004626770400823976583534421769047172578616422801, 2458679362473982525603401674056708637903753198066588420681316276533057434748, 21809029656057345603975391341426746124125030995144146696512538296201118212601],
+            [6280879893263761290992718227937872987126222027743578792550769796663965483097, 21781661005105675375371060239865938859358818307229213623212808724278892862695, 12218805153792234965265652457224963047739997589130000753882969109842395427376, 14175863057224896077562309949031443970522565448499941380029714907685154354368, 18713044545045765774440160758734075036525019074144088192911587140344439091833, 871862384691776984077351035638013756533735021806334375683313807796500024269, 1502839477291513077945126323905785773870760508304103171140961373988295154779, 8261104870027826927153482334237790400652546380102622838610690104209817005897, 19114471765340638236230908342744168016803065625044196455441979640601650659374, 19019817642327124430192079783638494688217569290842402842956368219318543325610],
+            [21104471323156074605656489170933219673575207173444288308664189932798373332192, 13487516686309964825337613756005693093628877420566394768272084460830899674073, 20923891180154323287343076578683812588421417256689806513293662673928067248905, 9208907368933719480343035717737298240342112955717644997876650999701034922511, 8759124893054947350824353959628946739191252370515030820665941763277633516423, 552479635200089809629944571207754163982882993453107617642431805678017795632, 11076919627854785327945122527942097617018417909794245111403691710092882041394, 13376851011951664078770039614383941403867698686885045018798273570102981695587, 7297153347641803594524552959486821257974767840762375389605794896695728757656, 15701080397032376300575428837718062955231946065504386418063249697536923869239],
+            [12802170385451591237712204995006285383925578544159142261676941898824917235220, 14907114985207001255764610781035354255372442263397615492677846904891260107317, 12687964410181221925959474791387804634690949835289963846997608009050802795461, 16442854744551302640718953845291230895568128650167334506295555787581255249839, 19860599061353713361417233465489069535533478047002000758678734400026464324076, 7644026247097012602062360363752382716272479612529050441411121993982530058280, 14466239242483561190140487713940916990839409282708470749941913785269805488878, 3972489987993477778113235309747094403076401013163374797277894415653205509413, 17408651360484985276058702725882470191511895393745201506720010012315594727704, 2893349533196610144798644198919832827549349809101277140581516556653096834845],
+            [8075095927871321024825674865855532896325988070329769886143854338310661263801, 4994603180568410839864567901950996576684317607568015848628764761264475983735, 10042577151349874697347870615645437815273767829190871242730117222556913940333, 12960616692542614154879595596284351666696773697514683044265737372341356484030, 14654681957787078146319137804838344818091142009586487432498965228380315422823, 18650686249082486990158347786191095578507485692738092271600602824105441003777, 8370094883899057877572819582052988533483327555252968177252078038473806691054, 20603670646681768601810978159386299002855391498160837215055792519449041300668, 7411129693762458045972207997663827423166960035866510752511293714623571462878, 21107581320845238471493982735064980278488748039185323937346939869502629488193],
+            [12794818599095047529257170526193918847448273305731746348496221006743311976292, 6223021759135697878779880710604395130954111022397848213906171840861622622805, 2020323331503387520180756499021043014771224613892712079675424070416132616220, 19702366988912673202766583781972654896463913258926649603813665243543060451837, 9046342774706068086400156124138716158896463602435884290097948610807635093611, 17441445481102482587454913588846173976203385943568077667955971319307689255579, 20037362854758249066105347949911315159015660769362548664131754085571517746044, 21647269349888360917332710747368355585453093091887557791278695278444429920692, 19773743567870862563698283317337732869865883972326111408125110478359409350374, 7563817978322404546119994684936870493422641234258302315251569150398518842242]
+        ];
+    } else {
+        return [[0]];
+    }
 }
 
-function  POSEIDON_P(t)  {
- return  [[1, 0, 0, 0],[0, 6567110472842852103562307208729730979822219223623901027818583814067854838328, 20470061474680650345578714531473094379400340476560997496715326719712871926142, 19028294503297846228875421263551770433446059297477930627722423155307211447339],[0, 15710992196131133545860512667382886707203438488863937336602968135980036288911, 16064254529437669764209280466420859688095708919674294313521931353202253295256, 20723410942482182944937257372274938760667975826432238664052409431780404650593],[0, 4740553294484845208755879370775021352129931932572907641850170631151805266519, 13834075607074563986415073421261958021876061867946085905597802974514937214306, 5359496710656701796743513897878954806381462397593624630585008853060034915129]] ;
+function POSEIDON_P(t) {
+    if (t == 4) {
+        return [
+            [1, 0, 0, 0],
+            [0, 6567110472842852103562307208729730979822219223623901027818583814067854838328, 20470061474680650345578714531473094379400340476560997496715326719712871926142, 19028294503297846228875421263551770433446059297477930627722423155307211447339],
+            [0, 15710992196131133545860512667382886707203438488863937336602968135980036288911, 16064254529437669764209280466420859688095708919674294313521931353202253295256, 20723410942482182944937257372274938760667975826432238664052409431780404650593],
+            [0, 4740553294484845208755879370775021352129931932572907641850170631151805266519, 13834075607074563986415073421261958021876061867946085905597802974514937214306, 5359496710656701796743513897878954806381462397593624630585008853060034915129]
+        ];
+    } else if (t == 6) {
+        return [
+            [1, 0, 0, 0, 0, 0],
+            [0, 11134286428293723102852565763264592996862344363196976456977938955678032509756, 20688865344088605431773382606362686994090677022030997232612665057795116551127, 5306875699660238259602815588135455718329056067277240797174901872543865050746, 11761575360864366609162793704703888352705944094003322827148016569203985456430, 4607089342232846157445666948042153700299193682917381922738126835187800159584],
+            [0, 3852134778840300895225732504587029188031932685412961470621089413024496008542, 12493781805571836368231951016226205160592288988759410001233610853372193940474, 11185893699598791622312511348755355872546585109321365794114433117046208887640, 10429926096623855352486881833507784018601676869316403787014088597866757471066, 6679161810345905549522668212621049371600338953960296622545109789730589574270],
+            [0, 18834854809770826753728769221953949284590946141112194016526429908454901541159, 14659683910457486436039470750124156696997849144472259966118516330145591501383, 1966337500702217019913291428440055006348973746952475483766631424433908082140, 15898528092210769732505898902737519128768721136452279530301347956399968788658, 2528400935406696596474789858360176613590974397758878911293128996689066816533],
+            [0, 9195067043592332135991207926630196127481707161010009200678505818850557563815, 21305589615386009158514549700080674574987207505914163999646872986864486586543, 20894368859059687879410138999565426545767608014142654592385132403542244509697, 2686954758212756571861590338215615267018655914139119949772021423787127555582, 4616564953898379030284389168386981816423957120166933561503012671243375852281],
+            [0, 11144187192013087963067768609564056759569432334520505415647662233259403525402, 10177737119235731776493722290819804393773868026138977608541610211827197767917, 20604200536633292179226842929066981111225848360498055923426124220302413293627, 14667926716598210594509024127201768625550171185634575497470711681086324305691, 12642125799833701879108981778265794242100518064062749574984804295048876628191]
+        ];
+    } else if (t == 10) {
+        return [
+            [1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            [0, 4164601276895196770451742123075912865670455982433356995004856225531967705123, 16869052913382903132905065631914409854593165742468130930465194925624016479349, 15436628000822945432750693686178502465738917211354160402921625400855972791149, 11778304236311614020883518005461019360564943822086928146529891405852055759148, 13688598942375250046508897839788010699088477520871965460621952355869916853752, 5241869634109646413763380823522574077961529088842697616523890384741484485075, 6136081110578236352070351743435002624615213090636510215142142590872991870843, 21795288182670769655409912588005089831635359353754167829211122645313830095113, 18388625890994773595112660730442256664560186945028186678330891685617134518956],
+            [0, 11829230837905995146163240406308949136864356114304856955070983422361560374954, 7408065666540908407717214522265635685471823759343217573568034952934810648776, 11727653097782440145757073884769921492422864832360171521604419231859990845691, 13578530967682134059862108816370890133335854293333178378154864327568239874642, 38252409812828256607248290791308002182406774890244623128651140014457618274, 5475564738698017986132518397943674291353951493755839189699052276353607712146, 9661824145051035771301508928626124622832183448221181923934648219896414089915, 1437344855522163053358510285449133991765251213249051971183765364915492462197, 19438821198894657586659766028659146389939446352477673030455975319666488792821],
+            [0, 18246314188154184543381938953991624225710586511297907762816140055989106002657, 8821221387782420901656495072601556419490250155002602387899377041349499672191, 10450895517302394274473311697276671913371112954443466804550581892750674803147, 7645910435853662031206435060166721900174189367061574270530699769550522893585, 3915526735850096530854221167711889270044614847908182614979728281814031669884, 11925969296565289843952887850448492380126076225189854070793417207775086761833, 4193163336796998381162027980307856365821092585855671918818293201145416657142, 19269632049963641186195554609865877732757189388314784655777554945837369987951, 18055297155546730453583813403677961221151461370807933347665975605846855986394],
+            [0, 16252593338513578416560377859775069764682706274168119802676809260547354200955, 17228764048011478653030349969613681770874832800152866615538895538926824480048, 15959795691840915215805808000019389031466523251524251547841673193404778221279, 11081150254463051373036418011867255864745259732784143365242351014585394719074, 20455227798528512521025486415869443721782645330549266614136419198450043673430, 1373156650433787722719155207555570118808696527874872851978979167596897273943, 14470151580086891884682392022529117336467081085801383511253590730891413235445, 20449123037965572776011539052457218110815449684906631080298707862036399727620, 18242841371298500623920779973380449888163226531135934150800916245819774691166],
+            [0, 2459995321033809175733399859325930279365815641499846271898012379426385173493, 6522410873941366713091821325856191770287040438746049074536712896657544852028, 10475601234342738748911926299039725587393788373247438746474636511762854051785, 4882891084903475773811928097104202847830144107336044617707311552615904866681, 21163663574290399842364759438447428955750910169881947571024244513653433897427, 15069405790276134380870872405505640854362949010197192504441646233733093041636, 8877487528985664691148395016447256100991177762642370116592646230051824479161, 2351937165731775858683024914137743255014270874447622844573001312197642137696, 3928789567338542864550629554781720528410914635603638006816057732783922252599],
+            [0, 21398074249400835444429880303292512518122278347517937601736659057766820894398, 4174579871238720898470163698641378510270534984833703897973986586702740715835, 18512130333822364228611771571788102383299902101785904913073572362730659761709, 16119604645858770487022445983920127804515136142891005339841118347315277307999, 4586447065804244201074973891244625528086568417891728196705438212355301343923, 3109233247576303354670554018745257741794100038415069789727210449135619276715, 3366895758450696796462532838573530617649872515560917377306880180202971748034, 3473950581696088774125544593260919542363516743148392706976223633372550309818, 1738920889085353489552296072879157063619105536045925704008499406212108462398],
+            [0, 137236649031991359622441174771566871431170092602584730301802501202609345909, 12311012137792729738854025828270308242460160210236911407888735494253484039859, 11131863074157670935162591349171198371136417760899454217863359849764681391083, 13539607204302409164399558602264711284094285124763816319408492858853249333531, 19227407947823491752298926422619022611506399849041258389612005979065942491509, 13775784596602288407166788467872091606618773344666844282913010755199954617583, 1713594936372320577570642641614285524215130030440911369181856791528885712927, 17148146485977435570975030674454570112232556012611475737337088583109469238357, 4228875351452995202548936803090852692501969943419694780705171954313128009289],
+            [0, 21307710721812382533232914970385914277021015192171369658706662484602043412007, 8289428531410068947775839971067725150168911354424388195205515562377082668265, 19698010965800404943450511654380903315662659265826699219631008246159532564766, 18221126036671117849772761351474545466584764773483815261848540973578567910317, 10336173645086171304714039881917139796282112417306765546452454087388846524040, 5729392074828188575137859806084730035404935553705597050692142731403843673115, 18865197911243271340122782491343143494863294201599333748874501109874766818142, 4336999457214814070195329637781128184724075708501131666345621850741476740214, 14902789510007656151083604803575657938498090793009228438986801183357042838071],
+            [0, 1878300197394764752738934014280612768127071463414322272910068596875778720750, 18326600420670002794124587188305350224622594526219882596262076492686551055285, 17111668960836874719921226826437350134019971176611606560526443157833801157554, 15654316953075848530962432550465818350378983310851953388197869764266461374729, 17416509385854860084981610136027144023478778812046841634661455592434167942308, 1845659474386632780606074276616090910848425677526563344401942186604580642522, 17319755413194508054236115868673353174828028158649243818266919295580857038000, 11407617673021521743827915081767550222395306812058663180520161481627796602206, 18952387662880079168406688905211104853440367201458655016975174668231624464779]
+        ];
+    } else {
+        return [[0]];
+    }
 }
 
-function  POSEIDON_S(t)  {
- return  [13682032250801179187570186285176733763308291836613942991479520590256716961232, 14646596344068401085767621273228985003167459682857410456146371642845129842448, 17565752605069278975076247288950209634442464901681167015469063126910488603771, 21005069460037993933657916705096053296776591758275427002901582100634324851278, 932609354172568758621898802162548910750085747674861943621300469229428996293, 15769083491408695821293953432311849404675481052199955565246542172728344652638, 1993254594848538629647767427467154242266341087089807051511845988684169892843, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 12601399289167534805953581425497576700821948179741142475327459720723884399011, 16171174184255179202968842059620512460553497931970079279073138550590137995034, 9876052112206235594362607173620568917971704360019339362942243432549463247720, 16833059093685337815102511013040934538531054981930473769764662770293594563343, 19868869248141071185884092328944756523994354163187600853331268761992949645044, 9742987914059605775150950486640537764282216628088102138440741615111706485011, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 19308235776033348432191583231696552639331092572872720553661244319097484414080, 12144836420681058698010609302959798632676312206995881288236332300122201322671, 19202709194685791555381392805685577633764249957228923161871473747118788663879, 13794007918702301261492100596917288371383857713079603131518682185863441864894, 12065356245022438263581000284034977341952371294958480368695985244426632619530, 20574629023838675388879947404723443826292879606411566517954034718619460994653, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 17319418304013637781204080400030372663868757033773406443509055759487007363387, 19995475711078419300761445783987646069747117163489249339149362607770644442753, 20315928643532444402026984920106569898830894833840198930731962883427992525196, 5637306692482723391507724736785480406767493860947728444738505535404442035235, 14037145804305210897104878383770092288312130871745259406518545414019601417704, 4913059599638446320767768126447193255600382165178538440901557448166398810607, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 16506739437794296892042925479823188810146395154828810461370242298855040141721, 16530409767929433504392337269328864452633162870256410247068272943323684556074, 17337105904044292748591636913077375201507518632127871361980131643707774544246, 2642961755324466861720199428381591525941297003883072875258319328803996176017, 4092577786554822734185605799315435715541779467285929827151520514439277551496, 5918950876470172599324476767395080721276965078535565512152004844683187439003, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 4534736306984684416199139145971522920904063970099411511550721503740417591698, 10553889045209304121930060059678100837837858014401728543831892352342840159629, 6992087986393609885111174814772457932811423820801632490253418428548062982905, 8328024804158287056472332680940577481479960990382618132324672527006663605061, 19409532272079329167168120162535538173390778725195142897135932621969530726874, 20085797933182814631376908050320924377311995756305421490407165146536818962776, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 6958711654808721933235805075425876377530845917575722511474973515056515537953, 719371423267805883637910812700442406165245150573117407740589967073289655859, 13244769949856933764992275718967849010819825447672129810819099890588633724410, 13711295815536500324565192896838181258804194022179500284963560223444255205092, 10549324733844452535350748214846817923105161523272377338217851412584722878512, 21850042478868153633044126570277518393463842058252326146996423361272750244317, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 8232225712188241908594900756788388980717706525703800987785020466697811076205, 6582237112732757185374280895956404883557578423444830296130830820970292726195, 2573551098010641100873069592095230025554325505281750448453636294853379207189, 17481893258630187124726190522221139693232665938873889170050102142928916672647, 10062117020758751822699250314218233740879587886830375828173722603308875428128, 11737608334782838908496360373266668537276579745604966565199650588076858021252, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 3557239961908842493008998292053237565325955805267633739000664365339666002270, 16922216395239514736499309416077746029918124845004839378229361134372224664211, 4592596909855368848809076987660672834339260720837251621387584916704590579026, 15406830353971977084976859949548223630104800922650456685792355118563333551163, 16112181133183439318088626134708289567073117093447661217913901761330294624400, 8592612248094232911107815926177540150078115756446204813581127074831645007037, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 313017092132611630951055999984412746020819832939294926880863328933422773505, 2169224732590391764615158064387423069796389809242093637210577904515295269132, 10084333009737814678124996010458238105332561619168738374635170520766200148580, 2432595924360156940615333371659820235875891256702669364248191264242701031032, 11950335326554970165696509566774996962811551427018759804081504614113523573085, 20543101303451342936470974017975332047140136905352044411103198294515156556849, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 21311436592755551651076120254848521025433787601759768089379335838162976095456, 5612717095311242869497179016272355664158998432420423012301023678616401713990, 19692038117789262391792775347818491095453823405760917740713792883957016242883, 15595281680672285722368311298187932492718582944131359618592419688770321748683, 14403241415212675649369752441218835877262869728016013138747842376780743318960, 20074431788271705368875999686316661785413598193947149768957177413646826516900, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 9251283941217063673846200893203506952399539735887269951498404591617453842983, 18951935078088007913224545913401674464092273605560684785942130642084288024251, 7645019518611944724559996824569333259157073289222310793610548804210730808507, 9131640583503117385109188039045670633280701297131204666064774778343306271065, 12730742734697439194675041733749835290190561550191055593090767110352087178101, 21261049683668172193054767789202853794180220816511434720790201467655893942498, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 17508857951800816671411182628059923396384038694055599581794533937118617525191, 10093940660337563809238709326305063684392147692287310929826754990805793361303, 14286796126240288219815515151150656233335880345514765530256404455415670397861, 20609141191701088252275837438775054558755875953974839579749030242797139466625, 7156151258377065319145379427784562232454112818606364502667982639241088889405, 14862254528341457208503839849180876656529911986558357700734130548703121222605, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 12578844973831896095069316763587499076416841457583443130571256559432171508344, 7580330195308744851797350352868983159568044798477836181288201733236097362093, 12055280365497939963482919515075381949282205502809758845856790682554358027769, 21566821926027857131502734504052464501876763110856978268672056729935798638380, 11696393231302412206735112811799605698604765343438662177197232688702927545883, 13434553466304504123729335220380289868969524403629132437833504525913963677208, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 1043855872726780148391610545054159727132355944751974884494421338741499419258, 567673602486159149337531749368888713326060299071032668374886456281936458921, 430728494804027296741999027826220274422996320999247785435701887997018489552, 15623863290210667475203016306602014114941475611853587630460132375138791484970, 16808157163897247353140965489863977433424547674561960909307881499234063196178, 5492272638299437261046624704968851693006884346080931722389550937117570851173, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 17270558279662194946571360631444484870723125221624216970047847686354000309390, 3872516645146144747219872045417840086028268728501610874028730555801186024540, 4988103526429636624885021810183539248919006415256154421874757891060558905151, 10105391338087160653266185861407220966129724732348486284255160055194250906551, 548140075303151058115671037242269504869682243693248539762883536928947908751, 5877405046039136069458487439729854110414158469952214217538390626200606164139, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 18129329296375204358198778592729036435258554232108861239120574684787481339896, 6110589327735930778500121988425783719712452708579857700175752794823839601447, 1370004376460454031591135041541429060190986749912409807755049838134456290178, 13521139607267034124901511043475300720492518137873030218932481835612768871801, 17763410076509998147634972931131158865241124978549756583400328182588210454820, 18734416289961982950464051240822303520084594112628573749124024735229171005690, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 19960286251658621765068307635258597501107187093684777935566075033896395348852, 19997500087239989755599837818714406539842745183254678136253946152034267884340, 4218513967091867456671478350335878594769199662896889657649826872145473855877, 21174750804866364273268932080734421672118470163394564563922705091759362780212, 19371894041145044087405242124153564730730065239455546098687446974180088273395, 4563833033076926743270662660905744677703867765500232877422601377200489424717, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 18115242095443186114418307299314667855138992548743377560161188293881872199479, 9094482206194611942880691816332738031879056926491881156996367291241568933776, 8195931841666892376110906415326699715910242460149012094428037253074186305432, 3483963571910615702545968736529140171940151302797473903947300245515087510770, 2392544082536473720119754656692526189305376464573486036648011315920049681785, 17990623071933869646564548650616145517411521003082816182477914326472381356220, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 18887202111695917072984853831220608932956937737607990089177594895204556525155, 18789479730296295291822125310981490068627852094685156388682965165272730517922, 12837726699831133963158390565258697534468856568735940196917966380652997523010, 3174605038550000844055338040294442949723981381023282545545771361379838907389, 8053792238662063839095792431456284452630767624342861780166105263818890303497, 13232630494402717167672668218962621621534183245542342787083443013449166057465, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 12965536663375549790989270617848870117914604790047882154754780015790598168695, 14374818862426658421043727733240684018078937477189866104467437511443314481481, 5549479501930039038426552881557403101844144430617240586826938303220945759045, 20210557537866294575150570916114048208297655700625417701181069283076010692985, 20341777106930979917984673605940505962448675459182890463930935243176498669535, 9044685446818477378705967105129843900658097264869545358634534849694895340024, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 13182052968517059276294050718662606155589944202444716180390590210911242952083, 18433435764631534458301126301808437159755917601552949436465647610375190520170, 6500167921991561925079013752856020848686209980766384134993565619351467954084, 8755390015113093864265056922423878269859817847588962692358540383407762487755, 13880500282098868248867520377855308148932056473080352362012849206931075010596, 13431174285996662654955814025077873470726252471218137761056526042417589289566, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 20154005571715091107733493660361643006285070530180560571401890485239131530547, 9460880755784285908824764500484851317960026343124957011868551434978223019034, 15309227959804598927213986628357865485359080076142445957244117125659840071599, 558538532301397062567430316565308749375706344634647990206648746581830592977, 7318851421179892086948671155572330873644552108834507826742770102341492007564, 17318764488356822153629705360653937722427658780616465220180314420562243673470, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 6877450660486444430146226108717836360707176191808557923063731607026894162362, 15122183340506909656408014993957621077894052950571828828187198305484853308961, 21585811659565797873051616726953579419097383670206299281081921613267733602478, 17951923408034952965894549453870430259076031382846645522316948006926034573721, 10482885469825780597984347209709741696777617601450659247250165929141849655139, 20387915744051715531193807770689679441034694764821876359675117491048231458367, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 11434287657895735566134339953780286396244560440090923748436858367426860772337, 9176259137643390583576540386108758478535363224145048898877592376007433459825, 9997997893185156092979025118718133301556867309068804774885903584027247053407, 7853985416613220906356029972202305330225746407566707076888290678930809269211, 21245586073454269637819832328912332757428410419465524959522446767517743463284, 19616007815947465496576174079957847300658013567435914258255881486046332487437, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 6932000177284708782234096321372184897542211796543334563662062785343863073481, 20610918980964828784669770001052123771433836689389724633870055436893498983656, 5852240746589390383874921620487574519108624000684699842010603046267743689167, 9165504979853044490623980906307150188451153739208013471126874248434248945044, 926804177086985399544557042519060436490239926053175844575956973872247509006, 7070416918650174705839593391233457515000055233947289847610611384199115771743, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 12314212483923127223585764593630958556143316679400202783633384234136883328900, 14247426655827770578350000961875076561853066206685747221908484739930344228761, 14289212625419786873981959548910733855540650447891942371174149916151564771830, 323735384385759014771222922831781369480028035165223801806933395832042883159, 8177205469293263931301191548311560483436392599541306677111580257298094091958, 5659727362690176110606285256361616977644661869748538446829340752734094525108, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 5394478856196283383948692937661048321733837314858059468022345844834072810956, 16538571192308776816140795530848182248555424443890893025242604546162717581082, 16891338779968674556480141297113108195772445228041432803854753754290538811816, 21054072045122041834525794827158966987109556933203082867287710629816885896144, 1871180099577121356960485077457187432458962337874774631329754403539659093840, 19381554759242919624651238266133224998314111110531651721152654918048169801558, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 19947005863180337742939665197773878471028708275538279543377740774752595498294, 11777422489193019183064482134617810233425079768578104627206136539471636658466, 14026050418493166618493543892382807348884642897167320582022850747239025559989, 16783714117464671006149304853070095627310555529212018798259932394692947975105, 5963152515951922972169904827076487577504734842566431713852972442281649669076, 5190777135239186129252046139118539638414790392122218134028935878868397094175, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 10391144068435933178306025235593902786323571235682250724096482441412796923873, 5460590061735980710270375795961037019392219837232733914315868042560389367456, 289198696085866878794992595261024239315881874447841152007785034811649657156, 16260089518820019581825668056827136844762996588258176150882027512516543964522, 17621425440342655020012079819172285873781943778949747997598624842754801899196, 2300678003235837082005630857775818517703109873388418992213236303715635892508, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 2330217254853057880101322374092906661729213235480835029283041676583200972286, 12524880246552963799223112071328722384179230428933908504335379685037434928488, 19837223878311626889129799510271261308689817893472058283739698727311770248287, 12511236370012848030143420620646378394123237719980266481711868258685361638144, 7796295321457545229378239435881656888493480786834374023021147014692551896356, 4080025695481039555506409488881482791569752746533710149504048926572996685140, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 2561415951346773095599622075100527281398378385416019137188498113589309110169, 19751068829983362325882232858514256865801836973059031934502641679817463086673, 418196707666910482640997048620118970089128451677399482119344910057055149277, 9668268991254835234325759707128352607089102135361760937169008384586072759772, 13817778735485412388851271390025419517148543131878667251222567182721729044209, 15888886307507144349546911521339831280303587520354669062214932041170625379969, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 21692798665202191466906307834919591954607708049078970080218332825166869504372, 16334946145194499528408096267739195497432651524479269382900334523614293172967, 21879095739322604911162213563730947223593642911992169998253852283966396162145, 1242962364670140374850624727809544914938651497266208923206920327728486740085, 2327114018561264497039635894876597759616798698255404791689992062664898360727, 13005204159039732686029302394555221807153350354471068967378489681519588823902, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 1979336425219871502489362870703705411114798574291940456492862688163607953207, 17213148002484643596937431159400343096953673328799099363460542286939838987137, 18527707935679787348892391632997375171905194478940487517927577529223862378810, 1102258666051967252345334230913517733530655169999560730207232087923129124837, 3257933293299164338384556046815024050420634397939223669502658819799376625180, 9775984440706465484994820294993911512675971730913081556076463187624552425398, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 18716617009963132083472931695272782782410209400666873559371539000335406060651, 1313643661012380099221929648449940592190572439025375722956819907422119350708, 7312840906839001778398398743270342390376355247419995653629482475274334745213, 3817618890230731926492687819220347740909008913522812587337983345696394658563, 21683112830541892655634388022102407646995528156404143832505918153017429261601, 19731211038090394053041567164994511137553171647211498180856862711752525403006, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 21506789181278859894615937573633421888808021071394738183721207835923074832861, 5017694874047705915993380005886965809587001723826415984800169451214369206735, 18358309092415353681367505021747782399174882714188162153412205535623088146149, 19298773364977877771096377727021868470348580061666447331718453016991034268616, 18597394128284950492359879336004568073448462232806737516938741409242041477208, 16926453550040190220594348699250045813221576091179033933997463580035678954191, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 8184594875275389149021159061247313436979460935556053189374848952709229690455, 11139383147364752447239827397298935720021220510858241870536356643950236646027, 20191840507932457935820607286132508917106294493540085627912640514226402949037, 12688376645352917363387482424742518529003587159470921422508356315546595585347, 40981352692902866815258185041052582219111497541201899306926701869878612002, 18486491881053962148814718400407465634581750260400068487544864132342152016221, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 1695729472381710733928352878387988951133162210628024109318134294380897472462, 9037300260570459667840887015250606321894041574928878218087719305840715128602, 5312927042044538648186733797382745400074437483710630716231463282839255623847, 17095097482638449820504723510882623580401661624620335691517744080954229451056, 20453643868557095310811261032643796590566309700451223509687892252936389086265, 1240958257168848844684922475926702318677587477911954417075939364855854057968, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 17530157544095875498191024654467069830499073183076170028142690250680987840093, 7318767322529504544809735560064835974605894025165737718845155297390340824037, 1489575092339150645422178308196130013472305868388267729091740110696160053262, 19274993411302834750063559423095795120056650210496306635722225707204473012004, 5582537638760175844598868852084519660546841148757783372272372227930063597358, 19029777290083864247894753901873723839864386781808455321577411889752921342041, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 10619219649069560750843884548744767898133928692833392516632196675051651090051, 9680908865011573872699641497821060473900615135982869166291863897267712272299, 6480216166501410628622384982397460259009546257881251139713887687555847631839, 10104732764124077118692976857972510604412620452321292134152074509955283797809, 14731326658236267062124533409559182807961122326117543200355702108800961103136, 21392751567056565127259030698306417203484494863181430076189347003983671019462, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 4846344476531410018388598296146478747388160968950583616917218006551364364329, 19815743109232903270697777957966069110182260614580100865703509589922442534760, 18267776670499200172834507380211281227366776704494746424651720642317926068606, 6759335722491250508464699083653873643825831236502810823267687654112019663355, 961538675643144280012476646795096856817225985060376192007725912310182640388, 10242171353624102329030496673791423841744261148424686873787675698938398495090, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 14918221363651457754217922357729905394730799653192947649306879946519134687534, 15676605793547446905990194639746809646814533263123892381669118538018514303044, 4856496721534361181148422877490849709703131262869112745575831266596904659427, 3767845911570401251823554665235083004652593864634372401617838986658905920397, 13538981514645716414277342525960634627087318751199161382477530247611996375670, 12852780683606999604379932679876286034764446634700991278791707549920120528347, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 16717227160001626594341201071344897183913337008692437955230779657366455400448, 11771230011107666293190431771784625831589390812914280950028717100599165044527, 12287565177019103250374631962870182672319368772828721105247043255933506481280, 2134865819994191857326457714775040034260052733485406062060357839373406379292, 4238080858201975312121536900854850676590410292532671920935523108768201653651, 15623653705002662022541090572277128455543984200048210294922566959430527064622, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 13486491875435813283676913530280027990756819068320413260269996085146838602859, 4547005103516336650573981868663377657305340086691237300320767253604104456504, 7629026853883693463866989265641807627970876817416801369247869943256910195053, 16001808933692422802969910993192798285613745652567549009747180231630508968190, 4896783546612808366932596448676401756274464201023898242474719486802237296518, 10237803222866065612339994997098353312878953009734835822717566496027053984434, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 20153124778018782545650790229074363116756201833687307849635167706284747148636, 14754169472037101498892707983591363049576756003171486361008642988333821153755, 6021985424395931073937951900900805904576698149584895298967435419478934956089, 19482188103947807529843419844202873812386262347513410799437620593373502691548, 13234776977283228197143109929514976907933077463086729232551679719606809487693, 3970166139043633327832288505829698210555445910737338913563821301393114170810, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 8530665317088477016240024755699749979247950136683013721103108979308618402972, 14070054049083044928252933690036915173210706650363912693941509807988302685719, 21264865604325507301054390252401628519693207314288118895794082734566852168416, 5296142234732069852698116286994877445128300970719755246958655884725655624932, 12172240519659464688589522189924826126089235690804501261064608204266403569064, 14362952668167590978712495747968137821660136885447400032803621704384127391641, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 7859140558855954413262352901749760669566507867329479637484095225080220452700, 8890288114868312217157147447436750880340258089183111876567117173781838551239, 1653362290729157907363935656580829095179886609634413659279405634586616444856, 2020304730654579826920764391506160711916736026852468311690195935254476273338, 11872594883219050953223566870743155264255610543071566066716529596438011475222, 4379275800617083543905661209867412772335326080015045457486911112815815063555, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 12474301448083848802158862503321461398799838736690243840295835562119535046184, 8713676646162840923485427399054964528520634052379873893016868799819379492926, 15468595191829212466032610147018193387629977360744252685386102613325894056497, 18830350159598798106500790490355061359312939324062290409710677592529869394065, 5129593290475875399028805765424925212431920856628220150492573303545242635969, 14596902578188418898889506708552656308967537926953845374042388268804860521743, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 17867103339597134965210777255889140889288313356392990926657852111653100109746, 7864008311637143347670802407698099103428700205398105898645721223625509251938, 7528972241373663278808913146072244928295873461360584571322668126628365009083, 1674728902877985098547107483451511031196308524279288815810841267554135807721, 2487358425049418196271881780118378016272845339089282008127855999393659567125, 19779502236670535268762269708698291077530925623276245886104886507331658612743, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 3551490932415592542734712491810154110249560651042774033731999960288237570800, 21449056658492609552426508270976037450931015359017002693407090113563722464434, 5046490280800409640249143755617878645269874239797065407595919715173851229464, 21856383746075542799905037334589414464014469332501464222250271832810036183716, 14687683381887256857023695499547416065625807594034033814592062520160423395534, 21273743789360455365669996269334924340538053982130977599083446708525011347823, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 14361793024018319140933510121104979802440412566286166839560365719488636220156, 4861480116568332074738051978929535334079576385737590168433994227611964377929, 7954314210823104667087878531723291913857375245513336337595228843108555604503, 18587035511215166692588389203278350715588656356101027698868677725274014389973, 19076193071866672696054762512391153034072190601839680723079560111988031081597, 6396347070808677682456918186534093740555152850458917771790593757817439770913, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 7641692137827981633128501328189759654518185143966990367762616100460984516820, 9032424759863109130056528877205221374408870204005199746339007918828143977078, 15199479818850470303330109202152600642791806745334305568145167125798177151198, 10485784884882559892864476007020787887394367544531326378687559979687784473279, 11385956744102802510499958630061906203687023354127850139377071986193117464096, 12750093175479612081123864563968411506809937268423721345021098614296481762733, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 3194172349023611851901290798634544728844593862195664545530288289131015836535, 14766193335527184725024985107907545895455417103638886618364694335614434196550, 11442246190058590381714989540736821026760155617318890118094124183559516925278, 15619440690591944291013221810207854724444562118699466092372666107408011558735, 21488771682957880870842801840313685880680959393360057078188900327662379719795, 17636608415190583341943819672808808590902162357782982968532785097198972525258, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 5013222984294415928602856962967098974310783246274815445037852203595946879233, 21158395162605619129358229140955894454902361123225264326487387672370555363622, 7453747463446714256080263914825452618389873375189682491650823421501183843212, 20449794490016964559618519981420518161156851432652654446606103419075452086911, 18060133715051592396774192197749913730379330790360148100388331112504967691967, 3545370445909551618315870889015107373768816803356875927688749901340667382444, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 6971080866272149975012041211205458661607627285149112607961489477560385955153, 3161431268353199803153150868988752986245162968829696556680683812329552465959, 20579446473524632036307060231198232624580040284973532785683553830246774657903, 12310479747205430391823872955762714022206899927116339617725168431863848824474, 18864213300014703219134551157058124077986812838158809255181563376434330238816, 17136894167404661644929547366701558035593338444023086341864860401323068721598, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 13978045254845087255087036823282005001375904089567769972961200273053956111567, 1027960459650241314691567745675264523047333850574331306705836643917447244735, 4599419493799460632004039591752385806038975398681998570456900530927300924520, 20930047787246668412848154073859033252573896150757244678019836550048351093287, 2231321823453154951007292111614971952156891413519105588353723795482887293392, 19722247923766344328195710932145319041394671578230172186500232344640228380791, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 17364854775855888172333280472592558883990330876257123871942883596991734419170, 17429111180694199918840831771537415521661251719635525482052816318361779381920, 10744830880597153644446978566394951124538566482618671878028856712048275676385, 12170237088530259857498551009903979295023539264064095585170647743870052410798, 20111261580881041910327214721783267815941938832830987452738622687039183857300, 2842416151120918656553986879608432866329590374719567335530221019530599203277, 13682032250801179187570186285176733763308291836613942991479520590256716961232, 19079396717075822980580423681703205267655764248867893707055220593027669327561, 12862600542072705130143494019079103092663657221084116733281487970023921431108, 11865179123044371566242148217926804570554369588633767928846142703514550163849, 1326719870080884510778634888767927698588407826733802711711254511060225838132, 4575367705100374660637380380668662838129321056006504402254440439023797025537, 13334298007405504999069862790397548992179150538755114555876181469206872233877] ;
+function POSEIDON_S(t) {
+    if (t == 4) {
+        return [
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 14646596344068401085767621273228985003167459682857410456146371642845129842448, 17565752605069278975076247288950209634442464901681167015469063126910488603771, 21005069460037993933657916705096053296776591758275427002901582100634324851278, 932609354172568758621898802162548910750085747674861943621300469229428996293, 15769083491408695821293953432311849404675481052199955565246542172728344652638, 1993254594848538629647767427467154242266341087089807051511845988684169892843,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 12601399289167534805953581425497576700821948179741142475327459720723884399011, 16171174184255179202968842059620512460553497931970079279073138550590137995034, 9876052112206235594362607173620568917971704360019339362942243432549463247720, 16833059093685337815102511013040934538531054981930473769764662770293594563343, 19868869248141071185884092328944756523994354163187600853331268761992949645044, 9742987914059605775150950486640537764282216628088102138440741615111706485011,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 19308235776033348432191583231696552639331092572872720553661244319097484414080, 12144836420681058698010609302959798632676312206995881288236332300122201322671, 19202709194685791555381392805685577633764249957228923161871473747118788663879, 13794007918702301261492100596917288371383857713079603131518682185863441864894, 12065356245022438263581000284034977341952371294958480368695985244426632619530, 20574629023838675388879947404723443826292879606411566517954034718619460994653,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 17319418304013637781204080400030372663868757033773406443509055759487007363387, 19995475711078419300761445783987646069747117163489249339149362607770644442753, 20315928643532444402026984920106569898830894833840198930731962883427992525196, 5637306692482723391507724736785480406767493860947728444738505535404442035235, 14037145804305210897104878383770092288312130871745259406518545414019601417704, 4913059599638446320767768126447193255600382165178538440901557448166398810607,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 16506739437794296892042925479823188810146395154828810461370242298855040141721, 16530409767929433504392337269328864452633162870256410247068272943323684556074, 17337105904044292748591636913077375201507518632127871361980131643707774544246, 2642961755324466861720199428381591525941297003883072875258319328803996176017, 4092577786554822734185605799315435715541779467285929827151520514439277551496, 5918950876470172599324476767395080721276965078535565512152004844683187439003,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 4534736306984684416199139145971522920904063970099411511550721503740417591698, 10553889045209304121930060059678100837837858014401728543831892352342840159629, 6992087986393609885111174814772457932811423820801632490253418428548062982905, 8328024804158287056472332680940577481479960990382618132324672527006663605061, 19409532272079329167168120162535538173390778725195142897135932621969530726874, 20085797933182814631376908050320924377311995756305421490407165146536818962776,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 6958711654808721933235805075425876377530845917575722511474973515056515537953, 719371423267805883637910812700442406165245150573117407740589967073289655859, 13244769949856933764992275718967849010819825447672129810819099890588633724410, 13711295815536500324565192896838181258804194022179500284963560223444255205092, 10549324733844452535350748214846817923105161523272377338217851412584722878512, 21850042478868153633044126570277518393463842058252326146996423361272750244317,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 8232225712188241908594900756788388980717706525703800987785020466697811076205, 6582237112732757185374280895956404883557578423444830296130830820970292726195, 2573551098010641100873069592095230025554325505281750448453636294853379207189, 17481893258630187124726190522221139693232665938873889170050102142928916672647, 10062117020758751822699250314218233740879587886830375828173722603308875428128, 11737608334782838908496360373266668537276579745604966565199650588076858021252,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 3557239961908842493008998292053237565325955805267633739000664365339666002270, 16922216395239514736499309416077746029918124845004839378229361134372224664211, 4592596909855368848809076987660672834339260720837251621387584916704590579026, 15406830353971977084976859949548223630104800922650456685792355118563333551163, 16112181133183439318088626134708289567073117093447661217913901761330294624400, 8592612248094232911107815926177540150078115756446204813581127074831645007037,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 313017092132611630951055999984412746020819832939294926880863328933422773505, 2169224732590391764615158064387423069796389809242093637210577904515295269132, 10084333009737814678124996010458238105332561619168738374635170520766200148580, 2432595924360156940615333371659820235875891256702669364248191264242701031032, 11950335326554970165696509566774996962811551427018759804081504614113523573085, 20543101303451342936470974017975332047140136905352044411103198294515156556849,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 21311436592755551651076120254848521025433787601759768089379335838162976095456, 5612717095311242869497179016272355664158998432420423012301023678616401713990, 19692038117789262391792775347818491095453823405760917740713792883957016242883, 15595281680672285722368311298187932492718582944131359618592419688770321748683, 14403241415212675649369752441218835877262869728016013138747842376780743318960, 20074431788271705368875999686316661785413598193947149768957177413646826516900,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 9251283941217063673846200893203506952399539735887269951498404591617453842983, 18951935078088007913224545913401674464092273605560684785942130642084288024251, 7645019518611944724559996824569333259157073289222310793610548804210730808507, 9131640583503117385109188039045670633280701297131204666064774778343306271065, 12730742734697439194675041733749835290190561550191055593090767110352087178101, 21261049683668172193054767789202853794180220816511434720790201467655893942498,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 17508857951800816671411182628059923396384038694055599581794533937118617525191, 10093940660337563809238709326305063684392147692287310929826754990805793361303, 14286796126240288219815515151150656233335880345514765530256404455415670397861, 20609141191701088252275837438775054558755875953974839579749030242797139466625, 7156151258377065319145379427784562232454112818606364502667982639241088889405, 14862254528341457208503839849180876656529911986558357700734130548703121222605,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 12578844973831896095069316763587499076416841457583443130571256559432171508344, 7580330195308744851797350352868983159568044798477836181288201733236097362093, 12055280365497939963482919515075381949282205502809758845856790682554358027769, 21566821926027857131502734504052464501876763110856978268672056729935798638380, 11696393231302412206735112811799605698604765343438662177197232688702927545883, 13434553466304504123729335220380289868969524403629132437833504525913963677208,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 1043855872726780148391610545054159727132355944751974884494421338741499419258, 567673602486159149337531749368888713326060299071032668374886456281936458921, 430728494804027296741999027826220274422996320999247785435701887997018489552, 15623863290210667475203016306602014114941475611853587630460132375138791484970, 16808157163897247353140965489863977433424547674561960909307881499234063196178, 5492272638299437261046624704968851693006884346080931722389550937117570851173,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 17270558279662194946571360631444484870723125221624216970047847686354000309390, 3872516645146144747219872045417840086028268728501610874028730555801186024540, 4988103526429636624885021810183539248919006415256154421874757891060558905151, 10105391338087160653266185861407220966129724732348486284255160055194250906551, 548140075303151058115671037242269504869682243693248539762883536928947908751, 5877405046039136069458487439729854110414158469952214217538390626200606164139,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 18129329296375204358198778592729036435258554232108861239120574684787481339896, 6110589327735930778500121988425783719712452708579857700175752794823839601447, 1370004376460454031591135041541429060190986749912409807755049838134456290178, 13521139607267034124901511043475300720492518137873030218932481835612768871801, 17763410076509998147634972931131158865241124978549756583400328182588210454820, 18734416289961982950464051240822303520084594112628573749124024735229171005690,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 19960286251658621765068307635258597501107187093684777935566075033896395348852, 19997500087239989755599837818714406539842745183254678136253946152034267884340, 4218513967091867456671478350335878594769199662896889657649826872145473855877, 21174750804866364273268932080734421672118470163394564563922705091759362780212, 19371894041145044087405242124153564730730065239455546098687446974180088273395, 4563833033076926743270662660905744677703867765500232877422601377200489424717,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 18115242095443186114418307299314667855138992548743377560161188293881872199479, 9094482206194611942880691816332738031879056926491881156996367291241568933776, 8195931841666892376110906415326699715910242460149012094428037253074186305432, 3483963571910615702545968736529140171940151302797473903947300245515087510770, 2392544082536473720119754656692526189305376464573486036648011315920049681785, 17990623071933869646564548650616145517411521003082816182477914326472381356220,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 18887202111695917072984853831220608932956937737607990089177594895204556525155, 18789479730296295291822125310981490068627852094685156388682965165272730517922, 12837726699831133963158390565258697534468856568735940196917966380652997523010, 3174605038550000844055338040294442949723981381023282545545771361379838907389, 8053792238662063839095792431456284452630767624342861780166105263818890303497, 13232630494402717167672668218962621621534183245542342787083443013449166057465,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 12965536663375549790989270617848870117914604790047882154754780015790598168695, 14374818862426658421043727733240684018078937477189866104467437511443314481481, 5549479501930039038426552881557403101844144430617240586826938303220945759045, 20210557537866294575150570916114048208297655700625417701181069283076010692985, 20341777106930979917984673605940505962448675459182890463930935243176498669535, 9044685446818477378705967105129843900658097264869545358634534849694895340024,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 13182052968517059276294050718662606155589944202444716180390590210911242952083, 18433435764631534458301126301808437159755917601552949436465647610375190520170, 6500167921991561925079013752856020848686209980766384134993565619351467954084, 8755390015113093864265056922423878269859817847588962692358540383407762487755, 13880500282098868248867520377855308148932056473080352362012849206931075010596, 13431174285996662654955814025077873470726252471218137761056526042417589289566,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 20154005571715091107733493660361643006285070530180560571401890485239131530547, 9460880755784285908824764500484851317960026343124957011868551434978223019034, 15309227959804598927213986628357865485359080076142445957244117125659840071599, 558538532301397062567430316565308749375706344634647990206648746581830592977, 7318851421179892086948671155572330873644552108834507826742770102341492007564, 17318764488356822153629705360653937722427658780616465220180314420562243673470,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 6877450660486444430146226108717836360707176191808557923063731607026894162362, 15122183340506909656408014993957621077894052950571828828187198305484853308961, 21585811659565797873051616726953579419097383670206299281081921613267733602478, 17951923408034952965894549453870430259076031382846645522316948006926034573721, 10482885469825780597984347209709741696777617601450659247250165929141849655139, 20387915744051715531193807770689679441034694764821876359675117491048231458367,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 11434287657895735566134339953780286396244560440090923748436858367426860772337, 9176259137643390583576540386108758478535363224145048898877592376007433459825, 9997997893185156092979025118718133301556867309068804774885903584027247053407, 7853985416613220906356029972202305330225746407566707076888290678930809269211, 21245586073454269637819832328912332757428410419465524959522446767517743463284, 19616007815947465496576174079957847300658013567435914258255881486046332487437,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 6932000177284708782234096321372184897542211796543334563662062785343863073481, 20610918980964828784669770001052123771433836689389724633870055436893498983656, 5852240746589390383874921620487574519108624000684699842010603046267743689167, 9165504979853044490623980906307150188451153739208013471126874248434248945044, 926804177086985399544557042519060436490239926053175844575956973872247509006, 7070416918650174705839593391233457515000055233947289847610611384199115771743,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 12314212483923127223585764593630958556143316679400202783633384234136883328900, 14247426655827770578350000961875076561853066206685747221908484739930344228761, 14289212625419786873981959548910733855540650447891942371174149916151564771830, 323735384385759014771222922831781369480028035165223801806933395832042883159, 8177205469293263931301191548311560483436392599541306677111580257298094091958, 5659727362690176110606285256361616977644661869748538446829340752734094525108,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 5394478856196283383948692937661048321733837314858059468022345844834072810956, 16538571192308776816140795530848182248555424443890893025242604546162717581082, 16891338779968674556480141297113108195772445228041432803854753754290538811816, 21054072045122041834525794827158966987109556933203082867287710629816885896144, 1871180099577121356960485077457187432458962337874774631329754403539659093840, 19381554759242919624651238266133224998314111110531651721152654918048169801558,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 19947005863180337742939665197773878471028708275538279543377740774752595498294, 11777422489193019183064482134617810233425079768578104627206136539471636658466, 14026050418493166618493543892382807348884642897167320582022850747239025559989, 16783714117464671006149304853070095627310555529212018798259932394692947975105, 5963152515951922972169904827076487577504734842566431713852972442281649669076, 5190777135239186129252046139118539638414790392122218134028935878868397094175,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 10391144068435933178306025235593902786323571235682250724096482441412796923873, 5460590061735980710270375795961037019392219837232733914315868042560389367456, 289198696085866878794992595261024239315881874447841152007785034811649657156, 16260089518820019581825668056827136844762996588258176150882027512516543964522, 17621425440342655020012079819172285873781943778949747997598624842754801899196, 2300678003235837082005630857775818517703109873388418992213236303715635892508,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 2330217254853057880101322374092906661729213235480835029283041676583200972286, 12524880246552963799223112071328722384179230428933908504335379685037434928488, 19837223878311626889129799510271261308689817893472058283739698727311770248287, 12511236370012848030143420620646378394123237719980266481711868258685361638144, 7796295321457545229378239435881656888493480786834374023021147014692551896356, 4080025695481039555506409488881482791569752746533710149504048926572996685140,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 2561415951346773095599622075100527281398378385416019137188498113589309110169, 19751068829983362325882232858514256865801836973059031934502641679817463086673, 418196707666910482640997048620118970089128451677399482119344910057055149277, 9668268991254835234325759707128352607089102135361760937169008384586072759772, 13817778735485412388851271390025419517148543131878667251222567182721729044209, 15888886307507144349546911521339831280303587520354669062214932041170625379969,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 21692798665202191466906307834919591954607708049078970080218332825166869504372, 16334946145194499528408096267739195497432651524479269382900334523614293172967, 21879095739322604911162213563730947223593642911992169998253852283966396162145, 1242962364670140374850624727809544914938651497266208923206920327728486740085, 2327114018561264497039635894876597759616798698255404791689992062664898360727, 13005204159039732686029302394555221807153350354471068967378489681519588823902,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 1979336425219871502489362870703705411114798574291940456492862688163607953207, 17213148002484643596937431159400343096953673328799099363460542286939838987137, 18527707935679787348892391632997375171905194478940487517927577529223862378810, 1102258666051967252345334230913517733530655169999560730207232087923129124837, 3257933293299164338384556046815024050420634397939223669502658819799376625180, 9775984440706465484994820294993911512675971730913081556076463187624552425398,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 18716617009963132083472931695272782782410209400666873559371539000335406060651, 1313643661012380099221929648449940592190572439025375722956819907422119350708, 7312840906839001778398398743270342390376355247419995653629482475274334745213, 3817618890230731926492687819220347740909008913522812587337983345696394658563, 21683112830541892655634388022102407646995528156404143832505918153017429261601, 19731211038090394053041567164994511137553171647211498180856862711752525403006,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 21506789181278859894615937573633421888808021071394738183721207835923074832861, 5017694874047705915993380005886965809587001723826415984800169451214369206735, 18358309092415353681367505021747782399174882714188162153412205535623088146149, 19298773364977877771096377727021868470348580061666447331718453016991034268616, 18597394128284950492359879336004568073448462232806737516938741409242041477208, 16926453550040190220594348699250045813221576091179033933997463580035678954191,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 8184594875275389149021159061247313436979460935556053189374848952709229690455, 11139383147364752447239827397298935720021220510858241870536356643950236646027, 20191840507932457935820607286132508917106294493540085627912640514226402949037, 12688376645352917363387482424742518529003587159470921422508356315546595585347, 40981352692902866815258185041052582219111497541201899306926701869878612002, 18486491881053962148814718400407465634581750260400068487544864132342152016221,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 1695729472381710733928352878387988951133162210628024109318134294380897472462, 9037300260570459667840887015250606321894041574928878218087719305840715128602, 5312927042044538648186733797382745400074437483710630716231463282839255623847, 17095097482638449820504723510882623580401661624620335691517744080954229451056, 20453643868557095310811261032643796590566309700451223509687892252936389086265, 1240958257168848844684922475926702318677587477911954417075939364855854057968,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 17530157544095875498191024654467069830499073183076170028142690250680987840093, 7318767322529504544809735560064835974605894025165737718845155297390340824037, 1489575092339150645422178308196130013472305868388267729091740110696160053262, 19274993411302834750063559423095795120056650210496306635722225707204473012004, 5582537638760175844598868852084519660546841148757783372272372227930063597358, 19029777290083864247894753901873723839864386781808455321577411889752921342041,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 10619219649069560750843884548744767898133928692833392516632196675051651090051, 9680908865011573872699641497821060473900615135982869166291863897267712272299, 6480216166501410628622384982397460259009546257881251139713887687555847631839, 10104732764124077118692976857972510604412620452321292134152074509955283797809, 14731326658236267062124533409559182807961122326117543200355702108800961103136, 21392751567056565127259030698306417203484494863181430076189347003983671019462,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 4846344476531410018388598296146478747388160968950583616917218006551364364329, 19815743109232903270697777957966069110182260614580100865703509589922442534760, 18267776670499200172834507380211281227366776704494746424651720642317926068606, 6759335722491250508464699083653873643825831236502810823267687654112019663355, 961538675643144280012476646795096856817225985060376192007725912310182640388, 10242171353624102329030496673791423841744261148424686873787675698938398495090,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 14918221363651457754217922357729905394730799653192947649306879946519134687534, 15676605793547446905990194639746809646814533263123892381669118538018514303044, 4856496721534361181148422877490849709703131262869112745575831266596904659427, 3767845911570401251823554665235083004652593864634372401617838986658905920397, 13538981514645716414277342525960634627087318751199161382477530247611996375670, 12852780683606999604379932679876286034764446634700991278791707549920120528347,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 16717227160001626594341201071344897183913337008692437955230779657366455400448, 11771230011107666293190431771784625831589390812914280950028717100599165044527, 12287565177019103250374631962870182672319368772828721105247043255933506481280, 2134865819994191857326457714775040034260052733485406062060357839373406379292, 4238080858201975312121536900854850676590410292532671920935523108768201653651, 15623653705002662022541090572277128455543984200048210294922566959430527064622,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 13486491875435813283676913530280027990756819068320413260269996085146838602859, 4547005103516336650573981868663377657305340086691237300320767253604104456504, 7629026853883693463866989265641807627970876817416801369247869943256910195053, 16001808933692422802969910993192798285613745652567549009747180231630508968190, 4896783546612808366932596448676401756274464201023898242474719486802237296518, 10237803222866065612339994997098353312878953009734835822717566496027053984434,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 20153124778018782545650790229074363116756201833687307849635167706284747148636, 14754169472037101498892707983591363049576756003171486361008642988333821153755, 6021985424395931073937951900900805904576698149584895298967435419478934956089, 19482188103947807529843419844202873812386262347513410799437620593373502691548, 13234776977283228197143109929514976907933077463086729232551679719606809487693, 3970166139043633327832288505829698210555445910737338913563821301393114170810,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 8530665317088477016240024755699749979247950136683013721103108979308618402972, 14070054049083044928252933690036915173210706650363912693941509807988302685719, 21264865604325507301054390252401628519693207314288118895794082734566852168416, 5296142234732069852698116286994877445128300970719755246958655884725655624932, 12172240519659464688589522189924826126089235690804501261064608204266403569064, 14362952668167590978712495747968137821660136885447400032803621704384127391641,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 7859140558855954413262352901749760669566507867329479637484095225080220452700, 8890288114868312217157147447436750880340258089183111876567117173781838551239, 1653362290729157907363935656580829095179886609634413659279405634586616444856, 2020304730654579826920764391506160711916736026852468311690195935254476273338, 11872594883219050953223566870743155264255610543071566066716529596438011475222, 4379275800617083543905661209867412772335326080015045457486911112815815063555,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 12474301448083848802158862503321461398799838736690243840295835562119535046184, 8713676646162840923485427399054964528520634052379873893016868799819379492926, 15468595191829212466032610147018193387629977360744252685386102613325894056497, 18830350159598798106500790490355061359312939324062290409710677592529869394065, 5129593290475875399028805765424925212431920856628220150492573303545242635969, 14596902578188418898889506708552656308967537926953845374042388268804860521743,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 17867103339597134965210777255889140889288313356392990926657852111653100109746, 7864008311637143347670802407698099103428700205398105898645721223625509251938, 7528972241373663278808913146072244928295873461360584571322668126628365009083, 1674728902877985098547107483451511031196308524279288815810841267554135807721, 2487358425049418196271881780118378016272845339089282008127855999393659567125, 19779502236670535268762269708698291077530925623276245886104886507331658612743,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 3551490932415592542734712491810154110249560651042774033731999960288237570800, 21449056658492609552426508270976037450931015359017002693407090113563722464434, 5046490280800409640249143755617878645269874239797065407595919715173851229464, 21856383746075542799905037334589414464014469332501464222250271832810036183716, 14687683381887256857023695499547416065625807594034033814592062520160423395534, 21273743789360455365669996269334924340538053982130977599083446708525011347823,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 14361793024018319140933510121104979802440412566286166839560365719488636220156, 4861480116568332074738051978929535334079576385737590168433994227611964377929, 7954314210823104667087878531723291913857375245513336337595228843108555604503, 18587035511215166692588389203278350715588656356101027698868677725274014389973, 19076193071866672696054762512391153034072190601839680723079560111988031081597, 6396347070808677682456918186534093740555152850458917771790593757817439770913,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 7641692137827981633128501328189759654518185143966990367762616100460984516820, 9032424759863109130056528877205221374408870204005199746339007918828143977078, 15199479818850470303330109202152600642791806745334305568145167125798177151198, 10485784884882559892864476007020787887394367544531326378687559979687784473279, 11385956744102802510499958630061906203687023354127850139377071986193117464096, 12750093175479612081123864563968411506809937268423721345021098614296481762733,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 3194172349023611851901290798634544728844593862195664545530288289131015836535, 14766193335527184725024985107907545895455417103638886618364694335614434196550, 11442246190058590381714989540736821026760155617318890118094124183559516925278, 15619440690591944291013221810207854724444562118699466092372666107408011558735, 21488771682957880870842801840313685880680959393360057078188900327662379719795, 17636608415190583341943819672808808590902162357782982968532785097198972525258,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 5013222984294415928602856962967098974310783246274815445037852203595946879233, 21158395162605619129358229140955894454902361123225264326487387672370555363622, 7453747463446714256080263914825452618389873375189682491650823421501183843212, 20449794490016964559618519981420518161156851432652654446606103419075452086911, 18060133715051592396774192197749913730379330790360148100388331112504967691967, 3545370445909551618315870889015107373768816803356875927688749901340667382444,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 6971080866272149975012041211205458661607627285149112607961489477560385955153, 3161431268353199803153150868988752986245162968829696556680683812329552465959, 20579446473524632036307060231198232624580040284973532785683553830246774657903, 12310479747205430391823872955762714022206899927116339617725168431863848824474, 18864213300014703219134551157058124077986812838158809255181563376434330238816, 17136894167404661644929547366701558035593338444023086341864860401323068721598,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 13978045254845087255087036823282005001375904089567769972961200273053956111567, 1027960459650241314691567745675264523047333850574331306705836643917447244735, 4599419493799460632004039591752385806038975398681998570456900530927300924520, 20930047787246668412848154073859033252573896150757244678019836550048351093287, 2231321823453154951007292111614971952156891413519105588353723795482887293392, 19722247923766344328195710932145319041394671578230172186500232344640228380791,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 17364854775855888172333280472592558883990330876257123871942883596991734419170, 17429111180694199918840831771537415521661251719635525482052816318361779381920, 10744830880597153644446978566394951124538566482618671878028856712048275676385, 12170237088530259857498551009903979295023539264064095585170647743870052410798, 20111261580881041910327214721783267815941938832830987452738622687039183857300, 2842416151120918656553986879608432866329590374719567335530221019530599203277,
+            13682032250801179187570186285176733763308291836613942991479520590256716961232, 19079396717075822980580423681703205267655764248867893707055220593027669327561, 12862600542072705130143494019079103092663657221084116733281487970023921431108, 11865179123044371566242148217926804570554369588633767928846142703514550163849, 1326719870080884510778634888767927698588407826733802711711254511060225838132, 4575367705100374660637380380668662838129321056006504402254440439023797025537, 13334298007405504999069862790397548992179150538755114555876181469206872233877
+        ];
+    } else if (t == 6) {
+        return [
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 10495708614310567300075619027658275955653070244789596139586092898994624461815, 16060968810897855582745731432996817952898871523856997750245419753357368550997, 1519132569134366422894764255643025234182472872461136613659036181999495799816, 15603928204026984973081236187834450460886661920227035826472701672512042292135, 10448375857671758599843903144662704427921783228178015114376267011662965405538, 16221252769449621891778558514519119337301360649181930116591506728533479210164, 9367928370515928807785004577344387478982346613598115025100917804851557397383, 20616114076522717593501521651270635940743909372239873918281246571805428947449, 14809208476841095579222349344089057756098354064739757844761315534872929666858, 12061067608669655151609145929815133516911631574489323823207510204094146677628,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 12684578853209502555527828445047724254541375383471508291770770349448038756724, 7424223883647365552488335702389336571784837746273866619572778359754756769466, 3319400549944315717787728378955715528969003062705661695245347082065019627756, 4138370862714084999005860724878074095416467226199762989882190718329060576633, 15201536155204710962080668498221872771590272338680835206337265574287540541603, 17353044920786960492818521050555561508371466575954714944022863420256950026031, 7212039145931952897096877513993617335699649398674199625348605115219910043986, 2086782136369200317061451870061685889229082457091446163615954031264209286964, 18093484499126843127760921074770594077451146551380451415771676097118503085279, 15470433858385375711472157668278563468325537441259461094605807838585254071923,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 3443859806033400763588801073300567110075964032796065718464335910931875482035, 13392346584509892541346320042135857870448448804488709570806865107486689218481, 8729451684588464745753275285754032991596753931475400006442088625579372342210, 1926186976131855060382416576100732515025769531388798962290099277442990577128, 11607466840773129265327237938278677902553801119603095170210601164593936690646, 15105637717161652986827857720729909424700461317232914231500465521373776099973, 3035700775429739837041148797437260880528286756516207172255177823954780680092, 2311167436378014219083842322578322636826536846004341535184298949745361263633, 18830852314527861052876189326086245013836845931199046064379418588485088859157, 12311801557851179318039381020957032928743458703316437969562498089132185838466,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 214446024342040014128259148124156832787129682965206146537267618358973046862, 3852372433941308766000019612798640335808181470467934261766835513085863026124, 17422714399552532995554443388919004675969250611735000627173429545213373704117, 8650620247206420199761229351116748595585293749594527733019626063562423659108, 8342895841271591038483316204064053080636223991208124213566920929880183071754, 9256307404906545034682589067630094200553607785073756226867752292959041682992, 7137454534684388814312859863730043355202143292793910211353225679094845530149, 12380305402993490678705076972323475521920108901226768185350580194769363714753, 10351100059143823272810665883340495981055239188804686222975444488974517538448, 1957037452252433091056278045809030583262088816785268784240245189505705683138,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 19355840017839589112321467575671638080405381341989946226657887911030557059064, 9691677048531444380875083737702365557762556341135263768962090062771530502153, 5241811291534699834783056627716378797241803987065174783515109310248373796126, 5144702297173918651600884259100021695494060615450673116918199968051029261122, 21802883591668773139275273024426060225300419210957872278201820302067663006567, 19296792489247640082530544131378804465561398441911867142971614340075959137772, 4163725341047633737470881850768979343706870350193290986024641011655924582003, 18847796056665400004107135825681231948701928983081222630390182848541836184427, 5770125933452084168814844102642240544417900024981560328520942530081672306989, 16209608805023018735361126616066910336583658791303376371323801737617450197752,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 5276338467213020996629600099328295807229501462599894004265244316946658316109, 15446375580827892227710001529146290622975573284524225217963491010155818582464, 21333864369845549344749399327915085611706699713355553396099951281523751052328, 14992093287283193449686447800019899552759858903144858234151115532945082599348, 6093290138439145864464721838275168300862799299431238120091625281634071407970, 13929617094207511767311139515500694727142926274466836283341687438539511480699, 10229731590789100818924910918595647527328797763597668148547314446180579551017, 15038305641338219418305787970876608641343384767964083177975818610556953702049, 4516400598735634078839792109937124879720857159588473266146558350623036781907, 2928869091320203071667232048466173783229790478179834613492193517980623730264,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 2309203818082345173031060835767535698634876013670251103799506469496622927142, 8641558536543401095336786693165003187765018309009166755402882249451914175699, 1938158079408478261525688175207713621255522990189747832353459992579361969511, 18392331623574019489712688809946287269033151867299949894116322479403803959197, 782804821827184539829256989159721464692563867166704116240415155770339633125, 4747805883711143035738175484750240026746953049966339094271293689277470146693, 8469051884893681785887522122741684610820302288932321763744587155380770993720, 5274416514762992903431048874438749790677673853880083837840777558632421146899, 15302625043268291323037991153154402981220497545785812061821080713321460252877, 7401637642146707354834601568277759917336351348708535849602522826233707814419,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 14449534450723511411906141961848958177116540879322831054923998242201821855592, 4000072579100702780382161200593677267969195451413621088601161838377155943306, 10905365459358311850391453264035305995193895520539143253122363799946277095982, 4566009202821520573094031872146520946109470711431228141667219983497647228781, 3868731917430071881727669774680318404497810724114249174078161624276264334323, 6607563036853499404723750401005218217499505613314272257613630624577819552291, 1172766256194761294495533598915541801668686710309117664483063412580780740617, 9051326490835012453087712422066117740102027117387284273811547362683704878820, 15622062990566332747446914573730331476439529071432270619018356127433145607040, 9329417304520599468173462069329474643987714492890203860771303983811251516414,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 17000049508335621829474519498449352144422764387596625390800411428738662942249, 1058659111315924243069569089235218856231605956169744881577400933845364766672, 6053640463433130237750646320683971932211595546733800982708575155564351623526, 20163216505898517180722439482512277084697367504673262649750189164442106729689, 9007384067008505094668805032388873766148494145054840899885389767038740147559, 6831543090773865334715094219849600920252179627117926545537643131781907434238, 8138545169606842857523631126172669748537750774750224320647303266502050113895, 2034881542853620884289032397949760281808322009987202853286253848188955915338, 2626358324356853399091346781534563974918176905441424979328998124610910852802, 18826527543362639137060873584350063804455374572558391566038488015939927639119,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 11378691708117725215296610593231740316437012915361435428991153671118154044440, 3134776541229913594352410364495823594933222147189363767457704402819199739247, 6457497165705846989275963717384772119271172801670874605120936742033428288938, 20891017443031899541271367358226058335533775216801077024018805749108355984894, 1625710831286547487801825800057326639958404850424444578553261150481632810975, 226251863636724070155479335648318211792161070565276598075480819351873199995, 16086779541822962507625826137174430772716046508475746418800191799654775201038, 14067520248903717900750017291164517810427141829237825738888570798039718833710, 11222630151799652543748458006328420806104204793593424598357505923777839212602, 10922595742524186617998118941985291578583062736125689068251814211361965603369,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 2398655748444061585106054031255102250582273751933343516603185667895387844260, 18895377573884746767999439937135778092633317129103810329931922707542785348143, 10209576057635768581432511750355123260508543356341076555010735993445742991446, 20467182333824856129237815173216256015228535966559532715146733765183546723254, 10929556476076219203717682295960166591419862113157012137577113049860311298072, 18062773953987554644176698703121875603068531694912814148441955863241119327687, 5996605325915359348618735653962202152920044393475368560964087334832011806616, 14602164283734062946253676104443511358787246311529777190719925347764831078701, 20797466061050605715356335022635017091813224162441756613469551162192012589860, 12254933494251261642460515668327018189562743041602732163645253510324526783798,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 16039271070096994260819898660986927933965643545402938716527930229110271506800, 5781119165721165831620423464499437892186030861972183429621878721055484989549, 2310408212458554020093059706725482376277556067845562759790970089351306131027, 15940199244369877109873010415434182615409746740880055969734867830395941362085, 17368818242866227948919496778535933847214774702395119805039872970932124862434, 16943488485640451047140375725602154915166077388071263645318048193135292432422, 20924005390052432792725390113928925408010637232491812943446101173111544945490, 11626087005033234043806561845010687791814898381565011467245323174522404507462, 2255259534369376540150680875267340290309210077265840016234674716250195712716, 9924683477866612332618956409399123637349445251898570474049666916475204315124,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 384696226074080236774293917776407939296180000478969588606573947430862635833, 8567176433375442284998810553943101891538373951338649776348829431248249151087, 3337448081697126328653508987676761233964001481612185898658906294087934395320, 1808528134522843539990679652592739393587616360318512384493752889333944592546, 21791940575349059876480139647076055129048758436058789339370838494392397303304, 17517388675838647371538189342485326722167451991686171903994629629484077030604, 3137679240901689709557298854294567403123373288665648649143210724454310925808, 18631682921661398131534803878031150996539843326904736753392820466392081708874, 8601747040209087500855414964834434622069213310309443553409839087677591181226, 1040335774591893126640553414681951906558501348153218545346800900696496810630,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 8228871798382955371398748753990637603065017829953250174317447261335614370462, 3220720994138954690487850638370508339152887411449967468258283121974037787997, 20436339058492233202977806407983096467856666294959453358313264152641705125676, 11870161606191811143106861772813716207582932397620142275196687920504969152314, 21224747110663751019222539488629735850434611261524145762839728199071969877092, 3789872835719832545462487028663365068179339232808319363789325209370888471493, 7328890319753002703020393930419081022174162593624017204425795819093638213415, 5450360046199760251233028068494856349632974970523081042591015673681839710295, 17845068551164472608165028522025801374982109763907085802851813412432126406797, 15784255602224096977745506970466938961535062599781391611240562890346886694410,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 7624189440197340545991857880302934121924370976818366183414686466981803680116, 12174688816368462578579188612801076229774068624362400916057616268715393213009, 7032813216662003005978591956584697787084710495815688430414128466790982967865, 10141235776652188187693823220894368966931527491764649662855048619716849668569, 9192081111955954839031063706485817354465926247914758110374407465004900347162, 4154025230078923069648960795645692527223851546756013597348803002760635560607, 12142405865849948709848598467820393829916235741573361244692538801392453505596, 14372608525865110848754516645506594784022605855437715374837521083187449841218, 19160402977080810106502219372011647168336906284682985664739942484206631564345, 3936293174756909346147538365253540187233913238749982441928386984018514091135,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 4648195656421521908506483827217720655615905942369922848069493303295101938221, 14769479156656069987153070580015890157832222998010554471751151307969033207683, 2188242570795156233225145163544068804761737092066936284991968274087805188716, 4199006963670045005730254222115313723378276350096377689611841998995039977154, 4698691314174056040013517749208818292103275079892034773977776373504907403877, 294341647563524010382124173566342176508348709131457215686442140744389745630, 5646058388751141957808116769455086947627424712406246049765089917708062504835, 13096675025843507284336208377204978796532597168305672251669894000113174396779, 3701530987977145470974851103482520264655971547348277777518801456008095865686, 8454433077909402746750642916254613735717168267114015436619202462118859927139,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 5160572041635863099651007546820836795495598686028363052300411679842352367123, 4025769768770930558791030402992124739821800778943623157679977002199518846282, 14766796581429461845261815291924363132316306527101847647096475187739351014230, 14348800566068515095593003705823286082716661758276073430310227865116374553676, 13641423684833036804477183940649860048384738250991378508453124150576447956217, 15678893643803703531982396008297567999139527875511145428598466994575784154232, 376260800472301121481318469272273535728737981748584921792208599326900511579, 6761380203538566519321042334001577734603568484362700000270271680605573206092, 11980210147320246640174175722908201761040704930140465617658648335962438938702, 11649564830270081755724310997367885535305817438863121266801065120596043005869,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 1157203550599769900752679373217117775106274959939589941350162156603131109772, 7864546781379086324744592062343007378932168214197062954799779561823847435410, 238804068133211904459986204287549735551948431409194589396234129807541037231, 16360044615329410491123215640764193346672359803290676202130051247604487813432, 19453371554555803724880771521845315552581163200171929602233187018351826637283, 13441087936702206119525672082088346790226952417954077551184323629135239073797, 2189841632456018126915246620070602541093445419222533408397803966725133939428, 18535646340932431131607936538373074268594921417155878309704770267070965359879, 9381806911833344176854828840724448336764046564259444278569003899935189488842, 5789323819930311027481357267517130924920882791456313475450105670228107864186,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 10174426127654021699541975682719597061924899211284444654403212555515649661742, 19069180061614651984349998787675387973677763051323665887378727932336402621047, 11830864623356442537060776392266390567042789554214235086562043705701805594806, 16256123968350114128466501319943799717985986901827458341530453263935627985553, 5889119652200407535151407744832615830712526277184914782607719573519605274243, 3736763735020768978944651417895984572054385822876391324419382816241982551707, 18599729358823283758354886806159106190368912515171408203872944702710620863369, 12229934187234434140061884971359146205208834033522843508638721459849830328879, 10236259760233544749513337472333638061748778687435480779193971293283517494614, 21305994247699455167955965621664131465696741462856957849131593116899741984622,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 421240024987527248511648649309072989276323171186088558735587313556710135355, 9754576796382545879717843314826291144653961328342092132739320433416770565722, 5038283807097648308065514544603690658349248457027597517145675433291852771875, 2488604139526353310125880325072788580620011476148852939500342424169655257544, 4768493746444333287626197076192740139589105616767042924911751137712393046709, 412697022705729721188806525211012875548726739977688992698942172458592727002, 10911588313633193357964067390126282315319345564118082914272778144969404880767, 1333724836451852268902292524204648910522299485189555671427257110167601777041, 14364203568494236655056125738446076275307972798342578924223897699414133885324, 10738742192283157301554411781109974232929054129681277351301666294449095484742,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 10678524184693212425808455535809156504264604489480764056636898514802306294094, 20059147041350368339767483790116580689314582795379416295773328786219699133747, 5353038116577835094373304208019156073691180436605176793195239530527666648705, 20363730843933140536824534613328660777177932540685664366590917287055443490245, 14899458662081688305387626654005297001255375695188255616017627841647871776955, 20490690698857979421069999136628034521966098724198116313393014754907606225029, 6061792248781705035691487192810186474909288452167378328539207597082086823413, 14759975391082859099619412562234705196011853603532361508298927497999876675831, 6451157032485504722741452318618640668051780818266464693158927297745312311013, 7439397176362952222896727021616205663334501569274449607752476739867053454497,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 6922007995221477078838463235103811781840300616381874699972616013604035352120, 183588662273168708198705721013977322858648089948082799929120392506356893882, 13522891077233215656058941758125491501539569355059091150690447694862642410990, 16494364127806380326074180653632204636022313229506814350439665410061849307804, 17702493622075773150961032531894186529316731246144374911257669286605868569294, 13482012741807196366553034627793077315483553498102435134484969325851550969830, 4016538182299851992447209933274131103105582041406424893515915380994102431159, 5660393319208300335181996581142912737209745948919729035532666723913303009743, 11267065329219644987272823625925419672865211722057816443450647979054001106529, 21242125828551049579660949458651539423103631794694311629826967918398434365717,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 4936290291977253617277104158369382872972326139802433668589403748163699756944, 16342782840916469183487821615548251993573812637543561993664056338506634861659, 10149161618339127675185896119155453845404367900425170875972814499464678294852, 15937809842606180969876474750423730309207908062401481446550763069469193591627, 21049576569023360478771954610589748164389717663581985516882405887534362451379, 1800076159976487749643844585598962918677292085573915017460783860312658296369, 16509014379480127280815822009606569757788398812673123977788051144491017503762, 12477854277088051828967348527618241565106081120526550805959886658062306424820, 7018453327233396434551132148509774238771659708417456889955471379482804821986, 5309342214134315785193369295579164814528592519194255020656138762148645427570,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 13370857112782130108353673579183425296408413586636819958646260077958763679847, 17344018342054122545933602492955445627117645099165548672985529568831307588783, 9314037819734359236375053160146636177422690292998257365667997860933169487185, 6516908165089853203526259751367921618770245781412076395633967170946446616129, 3811587645047705196752510790688167242611800785080341015070529408264280015289, 6895496747036094739217073292331092325790658485476100647701344228579981126300, 9979633404561716391601837818338016913723091011223163343592850309796888288156, 1350312759182014368484214610532977146965197641980605645674630123739501646769, 1774064087915538961254890702279349279046557632919715245416790212630771553430, 18653491241306386313430819317314308864991392790211696949258832009453257609358,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 11448265266617243298353645194214967541149924182661430474539163479392794762198, 6241011991517590831092408573973044264978743578671530100024413996019075240433, 6523063847941365889306046060075546164009258012744724694255551877535054758911, 10189605335404707737465481802949499506253953003022226938410252648769106032272, 13447867751398176572597277050278545643933453161414967447570755676800410258322, 10634533249445774093883996206918714443960297348023797198865995762577044508498, 3088220355589619740377389204555841322053695467899679973975586288315755603997, 18691552469335752263835498891488769371485786643257594852458658942371294302574, 10065513208699479210067361110730780721932415785512876797378743577074755726996, 2669925599695691600927385589489973589566052104075349645624812986279892485594,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 3774266597345917370717778404085500385867296829154758838567285487803209712188, 9411820563227624389019764081724829423419635566493607112836832964314423955322, 11535402449003110413568427321653815994297936438592758720626396562636614974511, 15663036059795727094497906758316994517564251342510112518306905516968121801549, 18580990860407462375114020772740780440134234272760863010900636220539551473557, 8981098814198754981411429635670902863301072349074824001537086629076746411479, 11131717804544027800636357611560833950945991068595186288777747968427508392348, 21209978242338697292585348040960489081447409918456584730045052438834955509434, 17395609258013584216069280524437706298466237976808522654328467781736718973245, 7181907012172843070014283175826578720771818662863361238717157189895816427765,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 5166757346715602025821425404007770763066124300193804249574728925864774369358, 12567508836226106162199351310461407902410998661449939100950072598176514563085, 362947562254384292395785366554437503706710141449308815173099564848018215378, 7783880808992609592292175496557182838155734524120749057385643142368292969385, 15745848975567369708163051593839216557811341717081403349912056175900689412868, 13495270042386398963926749849266471070772981302154566394004259327890381818037, 9065892547238784844924437111742409043564111436965162986953284261171705275853, 11057922605682290649296280547594679644331350966980456154794709463922392731618, 12473262480802676496636515722946035941837595809816763496423606612940828544095, 5364708520518646429397130504513836023913477696501823804774113590809021272540,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 17563585077628136488688527979029565536903905896744117014501109597214445679408, 12631896219161439904212570480625138913288116434319595103187244913574397435677, 19092090019577921820028317168454236353104789247416884820638387900903777815371, 1047505586922089778590114018041839357323816544901851411619100687048638037640, 21499735981397469127010985364498886290420471540844610245042973867615633872541, 17779571771000742612909668581818081682089417145647097049504121938218734723114, 11776074057803657605831858648088105940836122019074723275317880070920443143031, 6024808987006541509128371350768534886764935675893124559929601209487234721176, 3967034046171233285156758228950989503222916802785061644643318143396429551165, 12000350430187494862935308142274756570526940285422608362786071556702389986470,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 7083670761726880109807843822354282057556410961615923045755908535432605287941, 8197479865256116561398033741310862061709318642076163616991217600621018965679, 3720643634033047250739428399366573218587898524926017530599828828926575251375, 11741922769774771384933856164595368121787369294385567868128064818794347576871, 17143230228133090114582551864657088457459756500100071805802277361604513036161, 9184874031577983475903025532222648814172420987865898401230588161339220929302, 15550480245900610039426798274499432613935811584972166274225332907238296465248, 6164150881890245653900310185997611949290434978001406623862546726520379635957, 8987333931270833553500666854611528124741070739267252642352125074304179921886, 18250090931965255981988644634535341858926168189312294345983441459216306327241,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 4236130384375923758079722175870340336900565583228825300155768409799804961176, 16789791062755499908656901235955468539391187858199975448804736986858122169514, 1119668257497007201398524798723371664070191916300573568888359186032186885245, 18667349696663942172574095998244379208151203436813852018619911147829336226279, 8396363047292315501574074439370552015611046628642097274964002671400064798495, 16164113891092027054870004479463185202273367973667191841979109390359397215907, 10694208195257483342666547308502717084443681705461135191560090769850017220673, 19509195843961589682250536585328145944080994899812687966976040027397362981552, 2159954646717076586481328415898596302767496727029922418087152332663979017559, 1933720934701065278551020094157950214259696916693424832623003505863285593219,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 316451801876390568172466353954275217818431244474887342675551143608627367718, 6604168314110637752858267241775623928735996792773494878778124882359345580839, 3886514470259607494869757233438915525009055145926106365108105582563666942828, 9716982850823910447842452190966222872007553331724542297022206220817385364059, 4838187147276075645648026197167893449918257228822257533176371562717686332981, 4495153638601417332612505065414378267551852954122915222723981736321446850856, 14511755139202776401223043585463709244794764319342224925112703443787773491931, 12699573094492307062687564453944370943770615715011559665672917570322527860371, 15746553475605517721881352462751722895347103855872202792525693907122456252556, 13378686387972991020105729125861491475600219750614441047792707227622994773820,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 21481890664173572654725713388510293301876846103851010640540287460594316856894, 17471805867277234507738991958885653095957268726511343839837234833483376472788, 1556417118627795195101197976219377369933043354467343738477933829805351052214, 12527005605016069520362204556217522472104680156429695765521919962275939494564, 1393771117452468509899085622996946244301918533042211269653157007527609950038, 9738842394353013246303723604807767299159080229149634553502830304635788037775, 9963896165017825337859779926419268326902162390246266965996291166742574518063, 9449187166219031895739980880166326355468633016131894249387140665338982591487, 18769299151984323031116013457913975150789435167005380049929155498778602783856, 8593311566552551567678606453399756234535791428457448042751840993532555154152,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 21283607503245560160155290406120555008471335413295002833724232287947873645758, 10979415357019398233664934212528339996135880975764759569130904197913095140944, 21011501512245468156964936817968763985017737195479565592636790147427660276060, 13231994714419408940516712965973108511948535739859151912833624578737642764342, 13694076516120087385552444769169366181536097464270349993156456441408528240548, 4386468140002736034909948125818852436024078897799178754169495548929118786518, 16679119542010930886833604270814822901001933647929793165335331533604613457291, 15723185584497064561967540806293541726479558788495021428278064478900383647279, 13516441631686747506788026996425096238300874188938179730616178773295028679349, 1302184965872348120705856404161165756940733779724923300773828368130979082831,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 19734028979374620114845637401258482481170272483813123961918717198505272012667, 8243160986196266571173511944287713371291814603358596587262701266944563955338, 19661765173227751526811164115703532545067066364431424342206376723499692659346, 3869759293361381237440316147548215007329312572996410387484309334419375910615, 12852748968948464678778979229083393845452477401957589788138836172100744692897, 5659477087783271422220313004448330281733003937549837745517232774297900947654, 15015647542591145334323598372755489659413417562876498530538760859233410543183, 20415427788250004139825617382441429385201394143087179081713920710456181275136, 21794535818698080682182893327206222130261618472863326416847128449064601884819, 17204094902572111985896875437756870210142392899748579478593051617255762146174,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 14004532712422870324629298854074819111948989032963843414924455175154633489154, 18517544250273901844667608189359699297981259260102282927938905484158116224106, 12634369839687517764162131644393905849418984130531798920757910275350856685187, 7395525430286895495761865333129713239945628315060253849142459420350702599240, 11535006063822279936341251864340667038061129320817765647171846738956668849962, 12051155804667805261763784898100460258637404749944649060291232139713383727378, 5296120971816027760639401296466323004366280616834936790972251373987177376891, 15356009586383668480084719784792487388825804611442778255668284953637212040958, 8147539854521690369759841754545321948879376953635592120625506660754877415022, 18176824687974074229989462457351929330547073845441518890623005152338866636610,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 10072525627956915159685917623844344665720143957316222908962317522529760718410, 6571340035040588491979973534885036234096821816067536097203520263226694699011, 20297851968226749697255049680961125337028464343713493611881986147483017805213, 1287227261559676912037091896970048815024921096621350396764326829188028867307, 14689382772464517265724611318239949147862017106065870097480621650740134755356, 10328878591764575961902685007164301518714753264933916064085820245933509502467, 12966735474937835864367052325880083266436903560571634676969473674780870599480, 20010753036498483871560692385402785559241696179210268021764509897864254478887, 13589629844482878689788342381880521256472767484970408832768884180442998862681, 926850344493517073018853781001572761104261195785462637114738788296272682956,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 15680037027664948041748267350282114958664225697487547271119491903490667510099, 14743262777319965041271523599788928385277491276135636204507879062604422217948, 13601375411373659478171512667973172704310350585414662713326651079193744773038, 11470194296724533376792480695723765976650057969231714840074518302628646500448, 13821924931486053367445158821973540318104729593976100517083473103332311600645, 12844683265738895955556376206064415938843131701916272938211724675548713296473, 6381440777128880698205040537039217888027675075603044381700184060666794954618, 9594892212395229002370549916530816611586138687690440233156591452439639173835, 21146686588297938964401599344289593098956239267049581224949721073885082795587, 5255602633995554620758276276407710587591845787225197072994980343218395104282,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 7049887870453736008022101630680179416141455695156463210595050959933233110286, 3357922706846910478981732865983002265521571591638479032346227320275123493905, 5403300242557320718686552571877457320849474015176732827526392846774138320992, 10042603700467857790230656075436499008338646984255385131859901430215307697453, 18809224482503375983937477190385820057522847362843412463466252356516342114802, 2245697496829738156133778471499558233970465486517896606432071245412535921172, 10358514045323499004985385267964731633366332613571848668152980886561037495926, 14376461625337966402208912435630413213561629347505235122085441314740446005203, 12002016841763328763435740078674181192997929490882278920708833550025470753845, 5850049932300074662708212394486033731667928399372792413693335262154523921809,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 19289820262457486988746793470679915581743005771054363041319634821104208987343, 1260511423641927543717981794211519328508744991187403916951187398482779152297, 8991876720906140017456117574104387808921652957814172229032055345101499002031, 14761923243947337026033048198102026424217015215309899110454390456681562615055, 9744606227660384675286729282035863091720471138885829507336335612352697904067, 8372577724597940867070841005610424871891076938606428900553533876428272480183, 9391467468083124275345114155144801897152177201896860785627580118184530375534, 572228462893664712169918822201218511824187459219338375280313177649138145131, 600725380783982404078594531897555587924097947166506779711015595266788180757, 8466212800721627335058614214101706565155619300481819922690625686321435340586,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 6590245746275295328541565645558154161994948588745659217161324479467822060421, 2035752346303271072783662373812647305277762409214220256515605313461658657774, 684292049195647817411087212143091104807957819339650345963252347085724468954, 3827036696142778344149767669584331868257873786298250365462799742875601197257, 7848403444678511609672598741556557354342503413325268238870785762069584936364, 5462694694088226079380122259076828438907628889064454147933194785193886359699, 18893235584432679149862443411140455200206736009192569210355550054013606762666, 14174787509296660448856052483888011968861698066240824316966953500173871410258, 4549627967804116367925504001722148929476537190037219999322802115256940596095, 14976755321081395420681549004504560863108598098398127740134331424262765355660,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 8205277805242704718191638220992122587422965766262750157133532039994863755169, 5504324238190270534772284571049405481057058025351457416398494523671711460875, 15261476641628230805701718312463396124709647027413781999120924052791991737659, 11742663643352475102751229542486731946752532529909350642465767804704404548504, 15262361117174484132849400455325460768264298912832902127234904021835253386708, 21054125379128919160586507809906577759413006319347333047658211803873659616646, 6120465627690454632808208390240136955822061663094363073411012833747510476153, 15371281406127106318647667982433434435417796176995796406438615743983613842774, 6003685061627524319785187512791287371269467484259183720436777848228142701357, 3691268746947971951775980647223607843681194810612895482341102858538502492986,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 148610740510494475891338621651294773492648530751820494056464882734708719642, 2014508974577238298506266859761937636856382317170950470860104575412454779884, 17537082875814500811375435606181401588025183224942297392684596160419951235601, 6813672930359801047223865222988349156520280747358250408148860727476410159663, 20986957154367627091484511394408025474155144491777867565356921667384514700275, 5718522632442420174430315580588903894590954846816192128909913991515019937717, 12509078009454686973424302608930056736004262883422914284915279158681771625830, 3254484617818853833066768454220511409912158355780092827399339944439008695556, 1170678310324987217777103410209172218594513661981068376893902314669983994415, 19452247828447941681094982987572399668646331009501461839611114422230891849022,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 8453479125073675770465861481462829295354025218253709177330929383527844732226, 19190519286509680251595146614261951703676963125174959002276696378834057638328, 3296916069217274914082600693578632050091967085311971422761086363935087595487, 16576686071118269173533208624885949290967941065867462855317244627846500315253, 4424605699847215374580738693390015675784265685964561595820495343150505183085, 19741885768728456139436899269100106668301239301980709700303189663746834436359, 126975495737768642207315645621726637374435674223792889106308456526703965211, 3300183416842596626111787129728156754790544825329686531056811545565688342642, 8678097882159466091727395696620933073853976504541123574398664699053156316578, 6326228366079844814909159517507953834898489009311351928169198361736279327387,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 6184140015540295674161707318013764646304095679948023106083388983242702578603, 3669988763913504197738373571365522767376787520684188575243036273983969510080, 18413396250380314170460935417929137745195462148246738790633766302278440750411, 15891055436091753039682589173566964069920952461733297020959641467802743138394, 10109930262933759609034878928508245419596673258274610187969050993818775341333, 17016878191992045047631468423680524797102799621806502337708390675906133237229, 11419115489648224166520671181921368463112187943947295583425362405242816726978, 12797500772171660100417547796420299633592209879568897013270722300915596625082, 18490270532149790866399299172478814962211895670546712498009029381193161616131, 15248999502602195294727882740345720629273603661629229250740138849979835589989,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 15258776515560538876522744379608186682377354730006575639186433666100706866137, 8643992549132079631975380624672896044225607108572974525784661954385107219248, 19057809230802056587048958970548319853904044803669353284718225695279966123988, 3656186187593325739093675894163746930440897812507828605901541477983120712863, 5478663765729600255585878074306164070452031489555343738915298322032605502618, 12219914526127896522095495130421581509741911371690330650358923350684858262595, 5571651868256532861956613311202434806330458142822610458110971833211289251447, 8658727651085529367891052482095106712447835954307223044933406507265424720944, 3620125616393343576019607961588726803522624312699079799668304978550061240625, 20948649516526145955948474656236851680261170746769983905787265649854495583336,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 5972147423489313437588510316511989928003895205865018032975461769491484575733, 15678443381826814973017178153839698484715113997272731861272255448055920860670, 6115042565344822214776080642767670387963260012270736899794135204849509509273, 15740483821236710475096937539535208282964977879804753455374525506588963101089, 8769425317561559386569912717169653699667753138814073381911245723948084647051, 1014982602830123838549423441799886253166220710501305645004532529785363188726, 1394292393183869284040998761067729478136051566931844159154956598063962257935, 9601391196345414776781179240075239938414673639003432553459060810826161055438, 21692414669365993871980627918425777816373501430540112469318989186394407980992, 19131118906127833362342988777334785783557518103093844413277005536987125030462,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 4387324117989313879516249574201695907225518472409952110503147894875836116161, 3033133637985531296689429287031984118522829692836058559619628089343964265596, 6405557188522812415190959660706570518773794133229763092453269134921761877532, 18663314622708768586045665583480479807301400479013741454214278908878617691069, 10247190487056639110577118496112595127793378698746983993580979661207641283911, 27410082064075811759274689756042703363285101543904528156740284339812955886, 21338987118027012543710411999653670139914472839719908176124830314147175291399, 5946753588491112982423717784505018943535514248769749373981797468525523066280, 6693147195931929366742367780855803600249283324521182736127153944439762090083, 17158419903283810020949553517011775487950389953860134913641777491910607793463,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 3368972499215512616977068626108344708342325554202422260485378185306159699332, 14199833614348626800069311328600695831981766829118246981087699991949814268332, 16821339375883333841605842197380551236777458956761638961029152546755501120965, 19478630582500307687804540063515677132502683370410558877882379849855812564876, 20884857203194193699046998629436036445253839729955173485341758392965212178927, 11739523560554039430290261646907379454642634288917159262064565208457880360607, 11109435340479461884221314546776721750474791500942448279977304172684116523121, 18965078171957288932300629345770195258968791867601348613009786076754859893382, 3624762471277837610510971260136059428154228996621635582672871500047885188588, 15463135050138776905293868648749554344242379666301304917666529671044028301753,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 13193051593326881872534820771399736442274215715133676631005398121266915414489, 12102905140198837374632613040653455595564338275737450694711322049040277014893, 16660095206010974599816606040384249338900428232781404405326721043292019833699, 20454038846739855315196095975336405517106165435959255209866820711853256220374, 12532502052434288167067169004167248877423547507681676320325172088563620038524, 7351247318269691483295780667885966033823517008632892066713658059299569243567, 2754834757193524992171653390839722873806395158493495758672237239756835864962, 12428944011001353830125530126778740079010934545832990935195957478207336385934, 14208476241821633755248549225395489018014102129394690537539457640720109384238, 6855821994291891092967235800848121943640521147345825649783936297665053310915,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 4951018313915466200475774699060099750138290909888611466090154974656177788941, 20607496240383276808886232325086950560669857691603749812981148885441417699642, 4244992742588513127792653166292273301856022368006153679014326059471949972426, 18896560081210841460615960620416342518728204971684831824444160149245417911342, 1272111762100431739382714859387077332804510443498026078894778305122962023773, 21404379410670863372424645185958759188094831816979338129020321205912634526633, 10575192560470706588577018619560436726609720765788850940387116836758809617496, 21507192943523372465156107851000771155952884966667140133273261707245030110117, 7348561269818276779095716922778030019976824075126691686148584171100162144693, 1839627258861335361423281215703636217485293172730427063468429992792812742645,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 5666779853226993292327271640542381216460732039600578833283005482769997877146, 21435973333238753191081448859775193773770817235279155990658247156582939515528, 13666604036444405323463431902756531068745908421851775850348048392891540142200, 6208395410833207942582119977413879194105463282238470498470873240922770692517, 11702239746134559695090850144387287013687270032490514349995537444138782746078, 21054517439575088128849689189037917735434550095506574610225714431619320535835, 14752371908451859213713792910681587854778332690341908426924261356538090820831, 18089321749974144927028003758272415761962016753124235456281127124950222316926, 15682659969726144172889101793301909028862256662101734685985271862784013209964, 12811931470803308394424931546331074918551869001519925842827822159857218872469,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 5771997913195620105745556603114659928662293352340227622518596605199694193425, 21583154009613531926361916663363304347273435142254781500483701181922369061721, 3157317543810005204680635420898501661881793028195393751033263182819946486516, 20985979348196739725821741081603627433175145478427087660002432875468018941095, 8295238812102664125283125026039830673643506647536295899571082047261540836183, 9537728324052122235816861958795367119939383300115080862110769373885838878830, 16449046523828507548291938097647785586570512084006218026712663203513760552313, 21315007741889959027825341553429440253488202374123664199133738286982410543575, 21748677059177283893646722823063832370469703093774621862787169633146256645754, 20239940383523148793165385527585210812480878967473907365998499908644743021936,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 972820744460884859644822245327994117234235710077081137963288313086887541755, 9898947961537642131235101679110398697777790444460481647643281293945341381179, 825468835797214472462927006300067375138678765826935194735526401834771841407, 14251539483297709305458840850651297611219708103634326564765429033148040641390, 11190762663891677808311481703151889325749859636257876412631650430161617224833, 12247870405219403376984233639350074224872719235371562091039796389638508122033, 14436395726079717846518265518438786322100229437859028606701231380003064020368, 2926645869502653475294379434062869547147848642055415352519240308220552379066, 11715065819850460374566394721184903642363391336918837421062974564454635631296, 8161981810267863094350215996644295067755252891280902398298615103936767416870,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 21190991609445627112011266467446967951818637142944970325541544822393971754770, 21304905306208756307467499685168722370155470836084200109659964897372509903489, 19453557408186053758158553060845022450589022743103346880565323414319402935696, 19220000625806340001668404093057946215416714057800222486487299852783421081176, 2284200483454878089108033958572180103468171931881996370411572539970044428488, 8923334871990913871110975903548029800667002717078832786840483330408888581910, 983718660954228565712004294880329296328257333177131010427033602669431270900, 17247842673017671076086928577673631686506933632974120863894329902190662185391, 12594835097477772119912812675749028649961059760653891753115646277746940220025, 4155747985020834652601057147774334185946550430171661769981840555271218406860,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 2755902970808257350353344299296197977603936281268797196490107279461214264606, 11584788294582910691639880906524260915578645241920599273584079398579727711570, 16804309815609877282114331261377134873582280270807806625765430588868149606335, 18798140785639923015209841419634432636523356240536263383239230449729576709387, 1712899193066955809981387635359077076368288134612347348568276022177938720318, 2710849434421617155210641707683210506428862433997392736803254164587447747922, 6777009342368738797688457589820057790872250383588516073555808021087391777736, 12147454538719657345084016148213240745998377791502016883511801918703435030417, 13083846506098109945960635576587357731626807942084270516004976362111823335873, 14466517374742556292962942927443964410494666826524177473448715825670712683745,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 18663690310946026839389054722417803564178404681617154260827522269970541595699, 4724839367532535894017632210081112687652238922940218863469649670161727818558, 14526072567259132017366712398980120195303922196984783745423429252378798085659, 1917459296466156460624639530592170715491540529354839842079098459513971530522, 15285651190010304175991246488579055464487144910853666896573802766412433114507, 4152986142867362812097412235017624498540833515608000837949333287713012774452, 12836567421876333103156044451360841547025251622295880139357433349262309580376, 994910162622931723143067509001571160129601999151194831933427426946488196177, 20996452909682223279372761908789064935027013949800170502177457723385432130956, 20174751734916523909738259734149537659370050083349492226163865065726329597672,
+            18604790440163502403509907022708665294255623333833911441550601634319890759920, 11462796216186464765265534473459302025543825819773102314082812501827360710925, 5979167289702877013371308652604498042746246145374557545228804311906103305508, 9245795521385289048887929292054524665340447362184637381990879703315750225522, 2619961245059413572201039362286009702321148185484539385563794048569047398921, 9982461885533791398569792440125882742786911565774062533116125682736500196718, 5437624978583293845646425486296238380970226285100522621945721956365139049434, 386511033489864501151392623400319814327834091417389207939477473480912919959, 13617510222008457676892945219351364891749814210778318647096207925024090876129, 8344046845383556046355572285832525884847157624492696345375734750362677161101, 3342580914149089374247705869012466512850676607139562373943161515483777247797
+        ];
+    } else if (t == 10) {
+        return [
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 9038470065427024156323248692714058857465219682159939048393785145172765628305, 21354533294881464188014624338711739918528115997078038133620418732417950108579, 3481270889343021389004761483965522382035852249779727396109474258696567109081, 2446439744677992996002167722347474216684730239822536816550045713930671103849, 7811770971600744471405135286257312230746579300963821285136037410706121997500, 9604719893662396396188653889798071590560232995460527445065306716780234429763, 2301291748237325964877933428464820139643043045547418019360034002644810588782, 18608965447253844539184783808180613516158753617895425472905534794789309776105, 12790161350801421248721683050665347479804763239461863517002546979653719663520, 9325093535377664218911050065820297849718836536671958037364183073245510621083, 16265319644996158547188028325302464266974899365751029012568691443275346542722, 1148426937708791668377619457791611238431348630934472853382594059629507406291, 15760206325563895894781668033982912992425737426265269547487114642614432763867, 6280879893263761290992718227937872987126222027743578792550769796663965483097, 21104471323156074605656489170933219673575207173444288308664189932798373332192, 12802170385451591237712204995006285383925578544159142261676941898824917235220, 8075095927871321024825674865855532896325988070329769886143854338310661263801, 12794818599095047529257170526193918847448273305731746348496221006743311976292,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 4717697811675423576455946022265346453054408836822213760268978432625302478420, 4585316806592609443513377465790053604269479987804911600292353770232474238170, 1357525256639115365447591432266568711287791713271912187536264865236602144748, 15107066856175520517178388438053369783542408400878484584044062607120454994624, 3410671303189181757579052783573764836013780480762878503772904989427828248848, 20690249784272152754499540482547330863448814618799993521521466575739842377148, 7033213140407542041850327818795454028311692176103088351754468859185241812569, 12164308930044269684258455504536629077860766145401198979092738395227384006611, 15474257674208148517702037861860332581537939975915314156836063090527420037582, 3623129319870890119224790411368569553603663667404282332119988366345041372625, 16747026950964693640739201463040351607524089541577828176689475626145751925443, 16635422831844038463261210705142617197515926286786193563164992271286818161797, 17055365675959093472982917873507179019058960658710702090984594366650107934989, 18962391541523621397173740599637642542423952706590828060542937921016220871224, 14603881065361098299148438407519090663372999859766098881481582261037372464051, 21837268585953672690544635051319045168098437885924422037600950188410329397581, 11908532113392631319706883726602193898667402483141338197905784935684293584575, 5032657501907884676256272246615411141680355273732913041090408411663187202012,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 858768806045538961060918166653485924583717753485461378938010781478702476770, 19240550101299595064316980768051408093613267163870732669507754570126642532841, 9039045895312736833180028829517893886039224799100586143061529041954229212070, 7641114452513372978983198981444224111503139392911778013603997057220565630596, 21777288947414019356537834013188816147059142227384363890243523575355218315588, 4987415826626932886021801864266248916694800475635255892009561719036289794806, 758001313092156079636344917425527731284945386344751908234874181429924656698, 13618922098999564984168742046830448659995433421449136837646419489987113473014, 2377514952411274863221733124356296644265878635993425268005580104323520361602, 15227547994859235610424976019108006425515736321203186071488435065978030416898, 19620132546668619678060189772322850885577346974698652485659400873322893126966, 20841113810147708598468468856327009384614248423032379688979817385657927687139, 3831819798459871897665500638640070612532028662992631993757449149774578942153, 10334749037856210866580493741034154577883390955807922349188699929360368927898, 20421843923388071804686389297863514879324119769850233252725868560840314141959, 8921741637849399229345504887867481856963795793217075318415314940690461113449, 9318066726157309658207905885165575029581729556760834731281347581161189508591, 2211152468726887102735731847898041856699022803670767550679352380548165933908,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 12728324856562025342105136179209385742482277446090597178079550813889253413298, 15368751266736948438109293776060700961584663696876235009954720906285023954607, 17852352828003289798558437864643465009432691248008830242319008845948380367542, 13777896133852134282998226076872608289430587894590316026963387616999551623659, 14672799207159874457448545359630633532965719119294445728713629447978426281609, 19686107365275904339142918357293585968701055553568928451796006378358560505207, 384867815366763583303304582810708959902062105410707515465545586960954100163, 13476495249381950680351260612029591674144345263658731628980506359750107439010, 12219617659807195147115663317760967703060976983960045651241123523375919862840, 10821957569594513158739367632076797981426846341731802174631898441025978676445, 9611755072849272454375997104585920245389248579722222323332032974619093685868, 18924481103672435803182891404657428497228290576469624137499428333444658342524, 1424941019625783318997945175906242910680054532288962910316691530782880143609, 3318491863746161923209502162420898503772183790148414466679296154969270836528, 19495015154380029625465889239479923097488022558450977762388335383891765768296, 14887908744842309497677635429684151225737760407439086144356700560403406718858, 5711197241064964530276743536548370669163813592870164179855035750766137316660, 8632126130040637421329143222631043640702530565621196140225164339938192397868,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 16057066906649818438782281339877005087985442605503719144991387465583938728760, 5567157401070061340163304123111438208992201587791901034398891864979879993309, 4896942747890370854672088902413237794432352574826433992789363389043458923849, 9713827847178658587445143571985545141060773693775947100223221184791607649040, 13389506312274597468407808642358871578689198342326848906618756309462735485465, 9792771355892980076636596618134107428073333804677707308037118992034924644252, 16980412754884155348839077575474385738498081679729649062984984472752369291823, 6366315500051494797858211281782631989059397460259109624957972275764499114426, 19424640005195973873550127600983978327931540474666422414880320861650130598481, 2413551403279336800072360472506052721044497677202335860530056291385309662943, 18634115560484823080453211228950325563701277119872664929966428442446198858633, 20128194255172169591887318915582998257678659189860660107079767888168717027393, 3903148825908351198701793283696380558258136406058551140643793819068885086613, 6596227979682048307781107723811733481331306064615913167619655558178651595967, 14746521483920369041056038204120836580246914962065385873512840646797633973065, 15370669279330610249950586149294085233999251185138436646956576558185714113352, 2767189917043923560620505574453565250377214603749085350595690909696008884284, 4867362973056198169719498683382388405390248227329297391958162738147047790756,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 2098503812546634183170991053893456314577395831330221749441368569988099916822, 17435859869561328173089993884965950460286481688792244495131353099062164482123, 16503733243421523316392259698091837117136556397892301591246709130393776638749, 21218705779444843478357980337028443808350329871485687651147734320075306712005, 7392661163748336560653190647337172587812398613115137703679867566616278432720, 2537049444891505781424502891951732519202049015859659068341730416632213170073, 5036375820924258600208201710886172833015773802550553655063341305276542590229, 8928933683186989867459344933307030289716443001086380099830691145883704724009, 3541863491781543563965897080674533497876177160829823605463295099147461965805, 16579280734517786455444438801112103518500195581874722668279326275496180516057, 7163730716657824950942279748150427749965685745706404732069094444959780460434, 6367756772930228649320386131200295269759798908535435308698872105425763685989, 12756470324604195026625247195699282900981027057424135365589404661575720486851, 11186128410251204946878138860598560597267897416007756559180655383377957332832, 8117890444851737566711306070611239588895758900003412475315851490097950870237, 12725714971292761882709122772694310524362150345595857049622115543732283575652, 3802492562554768359042864838075457827934712002010500540487893278004422841114, 7554462868412300551461984775252633022041284084643860716816664272857894545474,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 15240923088004479859787408559172002962166072588467954629831955244198800486569, 20708811816709416487966956290504096842987651394717496393103999164373759064411, 1317400515477859321233873867480765248102731624552127258447128166380346304695, 12246913327783656323041161913470202550106330922019400198101773530392768104682, 4054304566630318334596609853624561102070484580421504777330688123238164744833, 9917277612063640808776408124879178225361467101774417823667817229310985462951, 11047183976606015391153359108633144350948457383946247945000584940747793116173, 14133523672048797179272532129048705922840034814690394246793226341308928737599, 20678966670647619344704314862536690006519182184094453849693602188656941545533, 15086731607072270912358143153207287345201301994690563766930194600366761795985, 4194216179236782682603853801102119407671970526460071448078093259016860019431, 8147762381787471604409275110441560818236008906270582749641751824119670173090, 12802799788152235729792158877392631376483319749372859808825090086087191468391, 9520962268264394465546929796397209433665532576827258713748952204023957805995, 4353694037612522575955026009766793769733287882327952341439178926591412395974, 4317365248741859391123410564171502087117612377007922362588719344916252793790, 7486913908220536601257243186576638866470118800915605684787161746960528036764, 7015243485120062557699599600319765927414388143841594102478613383090798409002,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 15741906270049893181862931881553424130291569424331787183701925454895004019089, 12961528593570141772029460494328607392369227334724073576422297759583640621660, 13818209000855479195877567428834484721281567124785226905654928860730710671757, 18834635889920422203766496574226300673498890025707281881100439119590702268186, 18289456021708900738689334284963756615217894515865888924264165219946394406097, 20339936658876091756162841946098898654326330827999623639447879479405241534430, 7348525471429534938746995647478354545080102863915802367908894355871208492031, 5739812854132670092510305451659462524032062486668783468976169230139893360663, 14189766890230312801969020377035122396495459824932961186954888139275859520399, 16517101358819964637703047996140968444219843889616474312169811753040228942507, 994238661279689410019429336141531440418293402978554657218703216389589497241, 10369299371848033449776973606472414321537513131210074634953465541151015035210, 7137122957910380476099639810317725308254305216029668594002462595813427822392, 8826729162717669956850390936870128905581345533227234355122445762705382438529, 1649095020454913598930342205240685086228137229928278712953250712043159068342, 16261817752928522897836266862805896272882071357596370641773044836474163458093, 5006141810208728736794037607979919301850870707085964690232966351751246734645, 4380215519308287712330014858909423675377431170922588446015461958098781204128,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 10527770452537752389445724350339240864114719435456297848177770778051588027424, 8130568615810860868700426984735647341380737069747964194905987473404587344743, 5873095885856746173514118077051208552288609729031599258046966654474005310410, 17875017139932425551585334170095224354807281654191330992099049702798884469775, 13614747751455799163443745989421159463507734317106088134346809739603608952526, 4606970722214047665383867364668693473235421610939344830914690528113152635743, 14521185637661223204158109810059536337273040773998428533454695512107487101932, 1242784180627778389847114788752263250984665329372329911236561452969242028537, 18093567113977090386333787768271558181416961766985748647696188555929073975606, 21077801519877017071702788669709281803569986369053322253794324113157509961322, 2986640315143631589908998150131281852556768789949295796209688916604913737070, 14788756347123523578022625171126910507531514523604957206908209338519702937047, 6777737258125732160238022634285157401469284874539387286424475609758862065687, 4139330267469903394749120788169081004008006059344926433885722427572357828137, 6139230024560722444375617344812819470573678300867728162841574393761607589961, 4433438845869432800440601727932360288121754901670119786202422415672150650480, 1441055411153995623846039205702103775457824330960766021347444888129092059112, 16189990374754220227857615086825732001350139611967569168749503342114541844720,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 6929999714335053113807957734278179484299096386600325021169379340729341421471, 4589620386515613804655133503466031017118044627260623446342867686906737968951, 17466951206230434266776818372796520350221132153119522618675606738954906280280, 18431741730313445435124146683255176157809142810167284722095436603327023894504, 8729327964713543563505962608555286020270457802958179892150656121940936658437, 14184493780219852970891270161785483582971927448650924091539364543941008745576, 17818871770441047782831730339782753778207715107180739813994797681458375285290, 10839160606956260642446445257674911363369466438478972744168110641813111498987, 19602583317375815089744914904876668617963062368227762635090977822443432467603, 11726348888357032605915086162626687229715857828038311751668314386707428317041, 16134622747687751380846831913415424395229357162197304199544466722034632528391, 13132497893699399441523951020959435095135736755096524679891204083704692666805, 11926764499514478315782259375736736939792364993476230379317188839164895393814, 12716241354564319101023239291425952089040278631072095235951577247970337244476, 4407177636955819917774499593096348406312454506157817884584771943479076170667, 19365818436096105297999773865190412993848581853541186730658471856323869767784, 20888190570445033132292346899523651221563060153675140546709724205921511591970, 21471382350663638162020071940563686713076307615814675099256399780599006825858,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 1653591281782860363581073434012285039535546789727239159355319140687851876612, 18383438637530064828841303604118089756411643223722562265299345750380747536510, 11342295239448541533711520849122875293822955681423988235579392097353940723316, 20003608819478604957746844506453310475585827014525515265820622043632717409448, 6339238019365659619996203229865856800519596460043196845633038408722547334303, 4231868518392265188689151272063105878692433096340124120126369194993181409130, 973887808508840850268755864182452317952556594994972255933823977988266983410, 7317791848112091412440197369970375981350905821771250748439713529306591801065, 12358097522029791344763330330029713422963206611429791277738934486275581305666, 3127424675073063917237790627600862600457612598731308787944647711267772093973, 3465432842323883068537228940722764622655855221261018963486083083744710809962, 6547347660079695590440792907064604586703163479865145996956558247131728036451, 13357859876471337950224457250242690769009806382835102023112114650849755190966, 12470238432719458206760672826591318665926994340427367720899369005663505965346, 16849905834993124875921053971592854729115522918711641076643851784149804916572, 21291442628497467483597265885906339510278756436572462538873476022665263562000, 13291530515860375232506214285865041266493878183289921591431990457355709325843, 12485822322626457571980351182602903137061627129499862103047259300417341195338,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 13216590486308465754416753909771791073716106044437065968130523968229849075458, 11608862690205647563140730118908367013777455952827542381022926813553192830451, 11240553570935342452966716025526271955591445726508042680880906178540792002505, 13619702737527108559646507539859440390933570754272378017105930248298622756279, 13323461034419851326151060531701939136667194502315310880625167906297610202458, 1918766222450885566699446734659540218321060075283637320079070692613674324345, 4682900241494586628994932259755176508247212362564340816359548082133479487025, 13479035790875135688262952961198248351127182391684874186265067207928249035477, 13655801419392041964102270118219707677376787819617322934030695954163373031165, 17149296886910733173009494950324936354286629719235961323195443339860213503071, 10290095431913535075524678332965137633178817188061904139293552675022303740740, 9856272980434147717513746085411013593273859177766857349793125642427774491942, 5188731488470271604363865227712868722240775101292115309681518280474238476442, 7873646902128825197287231379004425837111297796231896985360931472739166561882, 9366990164570760643914715134347548306316900948152760646177146276793785790699, 9433333668942861502004975873824297425209105271935890973498035515400871646288, 2096289426100973334690341720047775887678997574702803707497408197463663311939, 1276177249878819160674655869111380080718890925031388823090912627381242119040,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 18604793341117489061032470819500296473042035473328060569673248389578906048066, 380497663978298674087571744621200801311513263450165939265893754162547904738, 7849413098145641998572560626895984826399443748215908170113349626604385332865, 16070343094865376594123037160564821287238956882830019411851448608131652378135, 9374573162519220649503393125350804575971936171080533342925444806615260167578, 7147343426397776366514847464975572223237803688609537766113317434733540293473, 7286162039332460305169041372507643002063838488352004486631782472024636322861, 7842354043265735680229618839796815415696422751762333323233651089056713778791, 5630071322531891289686314193803407480148471356397062088458164401335009677891, 18793031446434021260823766201770598286548438145420182182843846526516257630708, 14468084859843709781820734755767659581456941345959240984069314158144015605365, 17518100500363021127359742958211880147596527610654191766698909851140267485954, 250689037864270173644479735083955705004473600056582149022846862739500605313, 4328282731000283377696976081482162462387561355029284806890120288692475255954, 7247851871708987048211917837118522404032710907172511305599041462353867863423, 1338282196552696410068108839829728857005218736899357799810851762209204352639, 20332331770193908847398758584243152666328231746597828220532731716500919444432, 11912771689692044392682716238353884577349742118915006156612216624327821684947,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 9867260464423749884379597724816627101780656163374850389955399181776822230012, 20405709456025772486233420590640348531329512540443981966995751280517100248784, 13217913388485927462460274544712676563575381155830018401508633732582289755408, 15512582369682847342477192510899725662963411402860638531965084030750774474776, 6718937414076657859169068325174982127757703261678289355620788576734163384762, 20942558246305914355151795878257318044489875166990214368937425841384762468796, 20655319320702590947582222478458738057290979700536343512499418593475024224741, 3556493219658700290398037961527911713026598452178613445925139870873320480022, 10146153606528161258301651129655321284475053943421455706056791768592597452065, 16891204537408795831337512196558696132558757416324790943484637788050267897093, 347402726009854657311178508444541753943228271283614006215942881745129832806, 8100728038033792044248584869693284181861211741903019668510733047491240767477, 6898980730224490650822916325812637707158561488701976983847117731575269048584, 17798947648287155188788611639978839583142828206264654140970928033628063650191, 7845136198915454643420036110887871643995865908584617323726344095301475964892, 2467520754831866737152107132151637675100912390117816770429625690119412813165, 9344431924163090150585253424157849766571280176116364581895656229258921645213, 21388963933455729530695495161763616314095207876456122138549792407152710213563,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 4966113672835182004468808925672671847528437149033122078829987155690402916963, 4994380867433886464784948169920672787149402671514389864725841009576871161613, 4846903286512374864140301102378262966116733413816882974220650004899495033517, 8386533861163976476972279955052601929664739314424208036571581337121362384571, 76729482569623737075939877290231125566988088554163624742436514604006149415, 14476493602769143237132375466077713455035030321948620134858109642417576715295, 6852984770263021803081332013817666543474246805311045863196251580385835966219, 12971539690325075239465062872878435185312754400639122812350640101736654504225, 8729289744313764459181609368933265639860684292887423345031971497477514689194, 10590563758383531203635637818042875787823343902670400806858250126148140828350, 14977773946087670256616260507518314299802412602697816789325078617322107494815, 9308622070386088539632930433736417886301998752125897193579839322051986347472, 20574218861597076725313252250784848575928908367640338853108049970630104528435, 19190636634215053484076238966852547698743411876208264467095550182407881946091, 14833368499909314785438219456930897351284938251767441038445038618199944297438, 15864993723282660128152012067753072333720737611930025253054726510912672469864, 7976837892452718746482637843153670095419102566216612909538947692350076417155, 20498096403162537055191222637556404535985515942927950397874472801774985513467,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 18987384495049608927960974595585503070826767704464921732990799161430036657446, 9827262986114756460038425387723316443222024907371421889201491520820443214536, 10468382202457169996231447497114343937847505739431382792464658846265422437781, 2993609070011723102583414975188127623931419238432484023505139288229188376525, 20761295960588856167317807944050827607205192406884011741970741614822855113989, 5507800596551026726012061635091604020191966053478816892603866556934504633973, 376634031739749922783896702852230111681726405026742295328037814385641987503, 9680367531666375858454941874531048918758312988524672592318738820209987392185, 12364967212509569514508575136572688791659592597615372188019954288851839755974, 15034617821124766397845609585657498994478769296819123809851941539675087919485, 21241752042593307184012464386381257175204861343202268110917926526847964791847, 19151830518044157781192202045202202605175351238233009206411646731794168712579, 10085298347787527442077654231613765448398257683872708966852845082940464321900, 18027037958787409882501315447239688105720386055882759410814517224375500545895, 4942163651759430255475036308842570801992805130924490689728457551778949507340, 20524593503673250897525685385309179682014828043614136919494994208224369033496, 14641829653802010016756324095887251457379412099567328018474114092341707187923, 19306455335835086956760393576848859220926166336709394486319490270283936523230,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 7085136670491296890126811428333355274632513634146478629115570929123416036275, 12133015094367610471948072074990540191801255533233219617039937397400927337526, 9471583229594690740905228493279613100403798451606651254950320500754870271407, 13969969234074246004248137548061068385083910605227692446665681442760692026353, 20369494696297921537827067675145944387274077984341485995814771820037531759500, 1750089129437226515607292661001334420067017784185199188512321224165323012114, 13082737016731511584330326437912474154622649334525997532959400223715645648868, 3467716152397936319521716912902669529413644576870451677174469040200390026089, 2902273157577886298945392227049207323777543266417301148485186664102723407884, 995610651513060059347067441498458387685585832746892002226547796247389429577, 21627465329160736999488394980853740303047964068189803817594386761251738309440, 8276349772153701180171349407754304910554623186276465606720309400245957081874, 9422849790093409649657475280250977507277428209914932784793145222571624105718, 9792863607760140908980533690144337207076265040312999620851373587943694023921, 5709581797882168666143845903105767129896407608448585775586382599407734502437, 1593438664717058547319282434823356353873092550143329730105663985116525378879, 6587573329895870973558019923813493711924810083543265562124852238405252644562, 8136137972621151841392785650969723943763672038461822778004681778068987944818,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 16455301201954536322814390481177528521262140481041150032725162699766324892404, 18855835235527113511779542002323583904318739315902390181080192927026355112117, 2548803141207879102132482622597469548493309310827518940110409219278952879683, 18378107550565326661923492100508064146365731235082604183677038700371203849012, 15726640886822754678064851903779957631116766695964676383654143780454179253286, 2337601575954082643652019142674926125199952176365392446394863021010808219954, 15179410000166018414760880852749295218917235054397830610500795767149732496942, 20258772582713783778987221842259612244742217731693564482764697181342605931591, 6539933062422238693398939214029911337055898859102007730348558841452246548097, 769023191767997667503205620028421602108708183572507282223769941065583440290, 21200226780180995754076324992285759739501627020401718078405137745122419640037, 19329743419723065803734961444933412943666895931230671314279084325452297210503, 9377409931757987258134768852417142650550661097297288866660096304007509317215, 1095744806020565463327453855892857159205497114368767870511460403869736811341, 5914728977788494844931610862187588841092753847477621133362048934111226074859, 17209890895768838773887295401309712496148933871560246341907371201271460136393, 16515133944282091555063184105846212710238417588037840774821373848598829693538, 6105601806722266667409042683039862278980244520212608410187926447568872175722,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 12025522654127772624530040146754491497414075874151818854670287270992297337169, 8549081224348151105569480046839718572991870269604056919799570682186875798843, 18975890805265676209272956616292715845146960199731973947818566221671617868462, 16966221105060426893105688389726533463297875091270975370702664855931230728425, 15767026959551905327933978098294333195878995522489451001365041682060232420532, 16808942621094781252985464424451683213989484296723753881921849163328703636482, 18537213696870745871378213384165702173783152291124468470939129512101661232281, 14406048750675216833277112701300690927921986045194051759527783172552627403636, 15254230832924266333714080718966649413343986219434456351781653617667500780964, 19311949177966230125377546339166359539193857438199246326644253511939895674096, 214319051199529444035688400596825041619458322714894547265265695370815786886, 13968794337518203279171504699848247967671239177706526846513898079444784779708, 10288522299950857583783682335081345772524160562028247191448852720569371743852, 17874682352167553353668513349138234516107993822961699647638660772241661142848, 8924941136777611211365699307436087860615627278538378359141418659311273689595, 2468230406726498681942148902538019478860553296629832888551489616842303746344, 2138188940299772675126059780217317825040291504676968655892045717739519944336, 6958488793180266443418456172709218493050705224297493721328433903784099778367,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 20458260659008742874800087415974962440795527065819711302588750103485266978662, 18583046140174385357319882962082455498344992148238161081384774095866175198088, 10580625029960416158927672993598907337157795089989748405697603615381319232282, 21137669830858581033473593636995483845831220625112729557116436860048680051142, 3959437110542134152785898779533228192498683102106298890246596289091446005538, 19613330353792594898285438487745689341518175673054524453128121688600586555000, 19933104354551093180532839433835655464782543967623170530521709033563839848538, 7242431872012218245346749756070553874419862642851155533332602200077047158935, 9772448035653444799415144778671257647540960526465784644753373699839327707857, 7487210922512677479275783709592765985375661594528580658896804629937635380947, 14812628409420731384576552745075554324943665812024487723023004251296544216869, 18555592628580775963592117186613188130807054979341366960637822343747898979654, 9086181953319914081361514118201497491412191766348647017172606481907421470848, 7103125289931732485394939254776751759126557520325870054926719319536745194617, 16073107494999368477141636454161373596340796085748560595620327616804444097504, 19673145913448899002037149134953586816659081469248999294303308669157509889306, 12767296076919763903423014564809188604795323258389036368492435100122243877107, 16223834702583982834157389460780628371647886433801439447368486567539847337019,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 5607705827123418053268071499429507231942940889859232725322453601327028501625, 2895169570541018942692884657197974992441131928337622551016420901009873717253, 21153983575114457358638382332943383059912422919980502572085664228704126999580, 18488147423701320007672232155874187517784649480104057589527728872987853414212, 20199509967917329619908961339640880556926213087372869894647824076300799219765, 7085726207930237658825870752327773269700191331117496896833887474273724968603, 16109884051030955652152658652260687219916309278762825950396926835900944129418, 7780768724988479367970106177625522644242296695841070551015771513247654429013, 19161125470463735784274730908228067940647349192094786318212135260789405425074, 6137080185449139109935360086480396082233640874788762822388455337318295755670, 1525437478364148504676476610429831294429899720653135298135436767114618526395, 14995431707462170983450159391071596745938239079247531029344742028881605668532, 19204286586689711414975127376042763477243050305543824501489436502514087137786, 16531278262884823419961849122066347885237555235685879084275959325376684950033, 15327601040102423008486822588432603944095564470618967466749968696722677326894, 8944295541591277127200840540666520961232667284279106044844360645042802410361, 15556442065012271140047251578046116725174478665096469144802892454617258763872, 1750345176352332224925713173800578236606821814348105842409136384014805795025,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 10651711672413332134250255087846413590447878577687781626250149780114013254246, 18915966476784991648555818137455024386697315712313611818123689526640769499110, 4023946964980728422162670246570872824733181112271604070102236737239574769946, 14073900491205517913479259276832964364116030713313546469990300410123545442336, 10428545271725942582893578352446924235193390050170408270029320360445712609741, 12106580898170590792698727075974348442208545324863100089796717590828386403595, 11143162688099461647224427666239185725785357211429842968799978623268128501461, 12741093605076338245241654768649664050576775761533323841195596071050178699365, 20761992645953251147680846562706562873954313486561516366361227473568400809645, 16000001645136217216646211602241470941005256751267923129987675200077720505425, 4605572679459793365553874374673773252411180388454904274836401558330380559799, 16668363843980126681225423766902874126890409628635922240130386716246841623223, 10278357867626752754156723261263947363226240882526840588036266835084654035040, 5673339603077253774703901774094026913726445412229364282715855214568209018072, 10166989526160251375968411919268774847861359749805570728061343809117894534636, 2256344305450109925761067062932336653633666414821754774348520742229437204708, 4574070945546057856164800615065678556756599866314611626709142514624791475065, 1194788181450057969615030261316974858931108875240696167487112510473388835208,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 11071146211639297210418571467828403464300271443819047084985614877361501023740, 13629206545353764627787096393412121982981408157180703652000688559731761966928, 1133397768003005753410407606131753958608254609760292523204135354263285099088, 10189103807437957399292610199191749349288436346391132881296225222269292547703, 19213226059562127624026349397727502849645731899773358355044332339368651932051, 19293280436974840040026044474495123589506928439800211123233608882142898957408, 617404337167438095319926035236152821173358742870891724454864154558295784958, 15439586015745063883476690109249912362645557358252482387878657340690507060847, 1328018031539715679514651455435718461904244977262850309839195169539808478772, 15775898046512168264087627279637629685663656664633824008015126901946319009333, 19565693926916720972179911151145607673647799421671881335189629053175398745364, 12968994584991216427067707850171809017286293302756149654245397770349007060101, 4639513048544636002925991020291182481842346706330069075301231369641619963185, 12965421042936073901509873546517877286834723359401212539002810271778145693154, 15297262884901157143883612217580117477834607503692290205815900236688286626867, 21217324319837705783570854969962225014290554421700262632797540608171445279441, 4469588099935767886960188232553897859065595307053412028125975855255271673536, 10531049834339866817427076535050770672963025908006701669392364556184712161753,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 10502956826875473110676259388266430813441185254892426015555332509791117234702, 15006640269558928714710842603025475348656722152953485854130882141659220929572, 10009890823958939922183955764956688462465400466586485444722237479367617798483, 8185961242817948151468285904432618047911625559913806129031633664468753836462, 11949350864176674492011076528210194502332088790583102494061988978108553436850, 12787459067172763408967352428424519624549209319383561675754254647297381332018, 21319750268520378420789352031536323648655901497375713281187725049273679202168, 15753163947487041819003878627996781773987535247932859588082501800022611506226, 18710961975833836838233219625339859287395116701388701770255409069424223444074, 7362588680998575163619891289412785433381850190582536923858627273059159482018, 3621672805506856656639133373562222765482378360368623055849461840832285409199, 14171994673120922950442147387469744735376500026640132491945371435222014561085, 2680316839418507045023465483358168412428220439550100988563740308473158647352, 21887565525158052028827688245790254817726269773117793929177859262018937481839, 19282271785953027505061108573818055807263026883490892968155744482272708791621, 13847507742949070706046197966825460174740230309177146604200225780714234145746, 16193356454187773908300583988486538124445957846211112082542287435223503639504, 5813646741779780628310075527552499710654926349416899317907748895068021901415,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 204378117063380645194121904801042452047534217984244332055027463650929796171, 11124700923251449101256314625250031342416416564243139806042392646198710069085, 1547349629943810240381363064518478066300653020758144766847856214845890272899, 5210218503379010202418734190346502373798238727676287213827852838370368636428, 11698896196547918091788122761874726778652848720784320059730420824166481445509, 17249254157778954063574598910498194611282150168496305916685199122748416098156, 7405981945721396488180661762317917924136527569191577911891667859608465160694, 6010923120014652909268013202497144305130162345673350240427189650764461654067, 14542844748469564740758828507301978438013110943903488925904974056824530665889, 2486077193943338365037517328724121437419151514668997840442306472640963289100, 19567199660366919203549062376226879461873637102442276912195837494945335925007, 5214831918329700544927680571703477278921020943527027895361426321446103675806, 17051916466950768992256554856203411456094318556576523254356640067246331562845, 6842584375361651116484373090686751401952675129714402267222024746298152838389, 21737255196724890395713892591763126150051485689185709559210977643827610722960, 21032037184943240783230446729441931725244058338960018114738979643391099689534, 2703860112058188680872957238579179428747659090436207139782488124780470534069, 2039560961182177751346732775690946875102531118302107434014713242567855569000,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 17527069586825995134457084207301798522735424332821808653698688819415681631441, 14518154598202927826164906109550833192087956998727648253260449587712245690462, 766259643071840829291587867158144269265387288036590561306146258691790332086, 17784815737278519105386445908620053791916481997903805812298082303789545042879, 8227989349705066096102885239780377212531048352930288629315065166485239038127, 5702172329605303629799000034782049728838248313761204004007451527701343796667, 19372901416517232335077410775427338496715551012104851775328212646121568244820, 7934285502885382350640181903934263903793209772679148890144685538163833733262, 18637786833837493745845802334750936764248275461939885851293247586941358534947, 10085365104600910287704258423441437131254370718056896393725156053724294599652, 2900246341450869496203655358992455934608021066894521543335924076561171502450, 19056132888885792720870596197994351426353976418786020879819364492249188489539, 13054039469754720644173489081046033605943474984022877111554690915909070541745, 3578379291240864541786459154139605639703570446643335300997789608763662935371, 18916602571206188533425485260072825978401706395664624487198505363696888449437, 11207936861204708767130149121115520131450731522051855946268768498744386890006, 8553118040204754864140636377238034999603325866574520020325256611855536217476, 9604110129794444211921423928972500774435766615850582362299191604842745381475,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 15027523796917931503363603027497225510887530784828840182966031493769874383003, 10922409050343816782841459650923409276606337582616751112090751029494269346466, 11779698303048402493372412510531174156964177355617455637313033095292934872105, 18841940015140958774627787058720474217943759761856605373347616018483807006702, 540669296331235675091079364680786648451721132571742802916478422832820151432, 2590317257098179076769418769415944012639243732250658738819166878638467803989, 16081852368394388447698928094557049983552053859266953643360407587516451826056, 2552638430177068849455518514812265115637938979963989035319745182204383535443, 18395408520743771809414668114918334183057548253988984156029012620424835458532, 682371680157287020221962214749976373340996253909680381043145026129257107240, 13140453507149650277562752668287146194265681087568717997087258968255646808794, 21781145287248476932090859101427283453240025176654313378303272680626314408545, 407816292383918732654602212742136281079484523181597472213187110236576866867, 11267209352769670922069120014675242414324911462650280054584441718378230627993, 11433478910903664287195860885790753219128714083239435926323026137203139847333, 13408702503130709524315597960824556953853915155173886230051260511328756572170, 16899557977207807223972742248004592871475324126416689460421846319493859890195, 1471244088900403839909350798060767795761056830174634063756811750550717297822,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 21313137336874189630165556283840520913094314293715751049468194304602113289945, 2666199294936170057323904641105387418799061371551102017723373618868392406464, 20704354889156548354537255184582070071567792511984199330399298822756795449631, 19320235060674834911467757671007995500385969790529054791874042574792499630871, 16744365227904834731920161154971177763250031490666724748612764341205954950516, 17593150964102327350669708314901295348557039353884955798108547234768778769434, 5199345925211932568096355795311030765858963349578347159178918130729117524323, 17095396937223435597840436446097897690915228980387389174091916869930711804376, 6797833202735171079067711806500416585239048471459078764445772690600497030891, 2649694635367147548442934760637876715799990280022382442719844884947606230890, 14060714720299845020589298739345369354953398209544254231431516940551883280683, 16118385614789406126400976929399792597329409147289261199866070512904063198160, 6509085446598080279779882929745003933722809682896194219481097339434536996399, 8891895326570983665929671993100475460512417667198172393472061085756010218068, 12114521670225493664275066522763328967658683683884408184177666131142721870133, 12928273522981930191609517678587318357966317285775001802876721862819920522907, 7110507764400581694519591161969569397248891796434483002875780402665153796607, 8846524027089481454106991085453139772315880043015193867601935350237416233750,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 18253001296938598182602285207494746106053095722598413201247688498963406802741, 1685930549672565354717651569562712418960381721139623981978252486539499259514, 7154115576132715441383967033505807010702170533652628590021365330329562562934, 14505950142995171719520609206291256080513382018349507367971322042395464641210, 19477407745177574616664528256194768681791848386440507852590389724753286948357, 17184146704932762799521215610276259385582949723455064106742253272579450430774, 10471934195634983214778049860795479928701698220380299875437938464761660393662, 16685004542435071008397996777729852911769501525739964446161011854995855432676, 3895344692405708070485531233718813130893682507970554503378830445353842348870, 1547039731464298469923111078935383590163647707463094896583931543526450249011, 20676997834655755920781944313340883039900009410878469579308596842184111388907, 16366855099175332354560975781826585828362317088629359848854489699227661183367, 3398222627552998003339290293578193965111889770214684970836464701120371264457, 10089730792688456966511815720062281445600094336647274039589625298505777497620, 1023135779071254742757398752076802573892845869105109447599331887237105310891, 9316374104043119111828455685279161426476105266464727243974904210826077864455, 7942456583541722599325720602233220188161631978074079969638263665225201776410, 5396484386956194005443706679304052950142730116329471918293495857747508821112,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 17829780770682032865912185341284009585870514674992146496050031977754064249553, 1271679343867351369178271313000906115950609510496290648362813350429927288947, 21649647586886324442061257741562638238669882453343498463707926054711389190992, 9853483021024037640116108085014753144978333444729021377593520237247847351834, 17632999576333156481836112432371910386061959333301254297141393437280236057929, 6599491667515923253264163506614350914731291709273817314185850120715099316849, 9056881931798968045325908026508159416777032617790063328876918830910674998452, 19766904337762748349792866373791810112588174055312258174200685531236679904268, 9225041178963727476237084197279001718985194188132014831482985830995798216121, 16348016441178989387666954328677439014762358542755132186467153629260145209932, 21775437949999802216691434445082115365459022727668467904807920842753408126329, 12745850284177984584247458734742828606858757268867190169164683449024630018060, 23901029963774739904013505075798963145663757743289892711854397456442347720, 13862131014467325704554447035413617203031725841717251162460285027919713655232, 9620011631384350804699500588183053103419642553090713358948351695917892303761, 3691455328116314962478285099098900664434414725139614888245341702649487890017, 13294658660117893560809159530546143227578664062363566788674594492325859696133, 429949025305543583115191096957516407294908140295019270171442173702223563200,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 7276692609268346657020525472632735020925847969002131170979526784679020496758, 21460017309412534175877113248038340987777987198195464333715729631727509539155, 7559438173140094936305481644088355985250113977251171879084618598628738876394, 1976335095835910300788080053976503010570937046246572227718333103360731804161, 2658357568445378357285347448285035588972832745498630090719006366880535332551, 18476950387253125078633377906153163559628705886365718324288919675185409935886, 20424685323419523457046725173165154152272114612516601197311489086343643089660, 6520496422943524536511193947172919645507678533477960159439995025143403868362, 16550542103242608046276861965849322185754781158139822259960226428716512253395, 3272783472709168680425007971206521097108040162093962454749601395705295561621, 7653050164134667558168962832614611702984530532817044387407783874808927366824, 14400528550893615229632148441842873257833577491249667064392719959166178414124, 16291892235233584520718213976211289265266572438845145384985672676514320602114, 2564264205188540347193665221837263776606973590395136480038684355100501581830, 15193171591625836110589249071324310532148851702544334718925131963413526104446, 13403869343242027866241944089527880795236189882659096281396518793260602912076, 13156026671449340247673096096295525001118806511550484907220321216730201776948, 12631781482030403229674287329260285782498124554495305093223643336332634710123,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 13746347567541287423333869380120447537106665047908378730975164333467917231973, 14208592246000619734461012004327641578449951892382403808164382081133264240392, 15810369392049600147765626319227334227881125594274132953897855599930249564678, 13971147555564441638828278971166275876781202920022995070600052917673311320173, 6049753275211051510890385210212911110516397580069462917427935002069868875053, 1547847664039489324308866121940670122441477733635231280325246218903919170221, 19229931355975823860551833993637974210839002773848785593653485618311969283461, 10617708658580387557667189917026060388430240845137312835510236273194894199399, 1990194912135289505896717821764720938896209855486856549408853663389720651235, 11213847890633949053122328692489640753518461299962588919447002181709692264263, 1108251575367511744944611521153222788365711517890512322655328402710025010740, 16728783191541517774039258894193378979241716285751749481099042061125317863423, 2048606890595983964564318000454073765132777586399003697197796336472832685311, 585660294695575669603347540356690977317874914643774502023866507927137148959, 5711762582081188036617573410633462433186584020815131145339574922937618865119, 21462194522188150870491734241812534810409630028003973828843729555860314822094, 10063876929261863290303550886015227483292653023000292505575262236858296152099, 3614418010851065292613456968037902826108308581289121035760278979900459683214,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 6351655992618720729460114621510938148444527879070328859366663056989257163856, 2403597806786624385155879751015669680769151815118691254141015570511006195154, 1591754164246622005888809807951807019573313524124817656532012923899652595453, 17038250483411362313988092521855451389593961780250913794978306212391302784898, 10717505261081116148752806937543751763698754140228107546127864642726390100672, 14488269904231879423192114868250743437894229570320818771379068471279947319319, 15073088910573324915761009921690046104985751996313843813523313669395168900060, 12616290981498197413777335405511415233716603966166150274442947169873973329016, 9284212702854126831274439359070228482570427002433435300592742935295855177009, 6000341539328935118084059691666718824565327037363454506537078032114622090331, 6806230838185730928692873471783540716308281693222645101472477962031696079325, 4104823400013627176125461670721475649272666379240682514475034176627525143067, 4221817186284991341634314588277471756742976799331273458393882453919120812161, 18728021280204287959942214912224630855851284047866484863863497937721798179342, 6097746711927149977341648378975806870539827818937064979337997366090874716234, 14493425016312970427404009263589843984220459180826899305090157924412734498891, 5888149287092477974496865811151754563097067365657559059703998532398593117855, 13992989850811855148759970229608721429148581360116227369687443859834092303770,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 9590870854774223287452386935926490928387392141482684098639687042051729299952, 11800188094367001455897555722311697377792908542079278453031997484521907726987, 9870096216289627205687213366880147187025623155800045903614446537383660527259, 6910111600974608342559622994985637521885885897716351344661742075086486034114, 8147965273070303569475175217784101937850674866109270733100494749113971000968, 14944636080834354089191610206756044203351520286178962001345016725149491954818, 16599927790073846815025723450285197061237424939657777843916072354445015227808, 10954767786342032667353184491062758326455359582608557002971291155602325466200, 2805123002478517540392925586359771421791512252310319681339970804274801753032, 4766994058365785519050430719143415952615555930261291443460489327430014529096, 6306940600580370511800445726859531481921145539760663359870731454490198971462, 4860292772507607926322601892251495285018805796123834189120892186313899731622, 12566938597626735483082000019033910698536301162869849077171586688428219686969, 19226017849614601976235701331834369476195438171777979825239037980595492154027, 17322489003446517183857356858124272750844431513305956967800858735406123437156, 7227885743379102496486490539565041885927885704021872511999178652648281708391, 15555581838660312024311912794735835859240671983291088517428166333631766971500, 17724164621594033393033191635562917321838578220379913375892326695497278998693,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 12219394041467146655375803417483389251869395507808206075627933559579425934401, 12087506887895954205247948022085223584185288884443334692162176700178417556980, 19791802969402226204036741198661616081768955287029202745760842290526307159235, 17497391721573423868343191790096363966941742751339413521775519091450089761636, 3562869370866339866896379624022709675997775248688658500534706292336776806054, 19529153298845864037282690392049831984077099032096869534161710612903708140603, 10085582373954036154850120976897549963777021522763975116142153528843234930724, 12520783882632817905919212617326754716302706406417946488125721702751166008050, 599214288115548055927376668020696526917870144244551608901631793561221863534, 13385042448523774992626764223616742757915480302286685929007943065840019866934, 16053719188086245522390240345753511312835916953131413651684474565547559233021, 10492086639254139197826423494128855949958260084496652977936448206984342303644, 5526950778244853952621260986171546745740464931364524268801176902346774045013, 17431223596900485480229986143761683101543870231638407273272082715788261374696, 13196045571513040718854745226014541961866247777781681191674481420135595813723, 14321534192765982342086463026695724458793115395163739600758783722262415577757, 13746348917870279260491229537141967288067149256020291401617098385075574213043, 14234932672628649663767018466049463495539816513073549528379578064804833936736,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 6183143234556721027410822387534553624095175231379333075532159647821353826484, 15942625121640153470844452955760436839803105856892430227495138317457692300404, 15102086617389117244969800337557368969186201867503089611114461305525897691444, 19610694580630404035267292822372837976609061716448418164067984508347741273490, 17521233635283164227403271618810808212068624021966081584079125408982701765822, 12497783479335198138230779736133245466726627585062583187727078223110437629830, 7085347373931882829846523270866017351563239267125871237300292406184299951076, 5061023933071668458165966152615818296522390607820867190658196661915865936067, 13133427044524012395191119053471577448275302729623551577267325955802507721115, 1656906253677983817361156052331687953231257094268172264973243605184265947480, 4913122727838492450876259452875478628096321902949770326922287611009459510895, 14286186500659004848948165626109790933804084625494599694020471605076666338763, 18792229410058177212568402329770121684216849973942486200672722040818416254332, 461603284826675303530654299700069249052792760866149848919504907083823663976, 6260770622182763044041439202385215754882541074176997475175986386052022587519, 11031646115575396967640287301758261246726060883954191763037362791526108052813, 19646603531261923510716015033847643108937289820738780532782999779468412894946, 16086816255909874906920083554744084942168373536299529348775566397721033352444,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 2114214419531330639624285868317973854573149801588373906605122387487601847028, 15018831335613800767918611825634812078036981580303889047166897465907437958293, 2885191080016961284247871390067901738903093335743507818032354658743094999606, 6873258124503244162724207102945540106650352363156998989950866655280358253392, 15123256763595157118079432280696797045497364790712036609966901079563764670825, 2736190229086400274863481529761757046081953834500986808087439632576303425898, 14913398119436018262587682865365763695839013269263558376780008033265704366330, 7203385654052775092214028945520911757977101860274953212912090453038850194396, 3711643680437424477633671534516718569532347461470293162938043117032319209612, 12641824499210951525664543957878581102489744772436005436905319075118277591990, 3926554007007721856696394168569987773551897855740490934818685826647760120427, 21622238220550549108112617831820747791345962377085956670434929386479464217645, 14140339407442368403986463953720259595814962701664063909300526301032914746149, 17023143020443541701685131666121315284105302263013803218148425472074423325045, 9336101413590841502511659357821019946665092680784628953243456859097057354461, 18736140849773356773336602790359558851437721763929203361178733699537103723968, 9081665374281910899220287000467647340429968033953317528970422815347882247474, 7238616207253802945552957805938291918090371257771695762520739949151550479192,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 15191791745492122484389075982185182376737419121204668930361853598106229772680, 4025242122127696886723642426813331885586105157232927282114515206868756301818, 17680260186408230175621594974932951630152978381088819219860391683453271678058, 16786089467962851342353906404241424354068277283741897930652721511520313464389, 16656241114875081578970029587958012974774897361799989795143511088752370964988, 1411616664045533786013831038843699842829495367666309297651644731029211819122, 9929958356591943398269379573608173460161906747075082836366456251413530936056, 12088229654046659383035088442233187250809736982264994445318455631211977920934, 19618160052788467765874281262193299538347454873402358302691910606951252004829, 385769755947966183974298705936240485855491057598377360050732382470473260945, 19583202052678362603810192579927867822617899851678714245846711739047106152925, 11470998383873976051444296773670383924668280051941445112290267348345695758224, 4115963375492475555242149978195213205855647450894277027151072620649508636284, 6973116930625663660763656382249027685586229085483106574392114784951103920403, 8141789809982334593559016178160029908329929848037924987825150381685860184261, 12197589050580687677001404081808604694536013319890819617986704286306988450737, 20826120374963878963773827581867293406794335488517367798723667165413726648218, 10056416551855157437189330200716444168716649906435320091280645452391326309575,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 14139398136116168829297996142381891728345688024882917452599779128369529660030, 10809465527384200536589333441678441026938036578289639097799899526030087896341, 19235221785070847672373425028186267046322551512838476334994504930536050654028, 9667704270461021003536268448996307009401628647040688577465146040556776606483, 15385597529974272665176165457431124702420416549266595723968592755389693140880, 20877928975043320158102291622046895754854815899522922738513817489048313825658, 15303500018708180468518453877899312568481315534469183028885531341366311316996, 20025011763386705986977135716141751956324674424403223783870639717129888581252, 10656236026448740903646513607549808775225073896928183075399634156740409663029, 6441574380781876137163265398383926780370716954835267214708620959622247753608, 10985425860475495330957819769284619288160513572573479659300490514229877518025, 20232598588456508785130145309632118776247133023369941180556260834309889876271, 1786758489775259159597512108551632842380765904560624857495983006930322484791, 15313969264051533087550748360711846975702509445921720311264173214214672639231, 9946433055801458984081322113237189356507734159131876660744290954419866196831, 10621716222594209894130296811432864694705791470235018657674492161984489339249, 15775838818445673011095733070439219651364395465391072325121234746135407353897, 17520062277240992890826232982261326347541359981931544441492057455360993805380,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 7526651979687381534772729221517486518873000098036730302050574794123205526424, 18455410812305863700543787548224786875398334671396997077674367057903674752572, 5839208652039830682748592685010889204570177434966320806788196483660962796134, 7659851311522866918625994471035307050541680118383614031588162563065589975288, 20792316669227771364874742338658021866798470341131686743531503388593624750404, 3611048614111633301823662293611859329248190793439034247238588783595506015696, 5495626380271954651847065898905989345961834547663611552826394594298688702919, 9108550737791323263664140277140758460989864575197668672770850168485865386604, 13827719034995459709394021651248793262356777830236649612318127453974094939345, 19651521803978870323094819422013629553839973438220221856343806655101278913921, 17470307646373971903051220485732856466944789855288663212002876663066496330646, 11576678275148884974704317205180699478652467749341959793314323613349880720039, 20969157449957927089563689047733903190946022938101527578121978847846709146020, 13437444265495404663845949587016315117861756449838457069246203289842878563258, 3715397904246302537630400534990162899606452988536168965277607806341704581119, 2095297487166109481825348729494598893632079832141569504818512385894953453653, 4980261529991606667892313155434979144250590126976920636727717876961358048694, 2140364006340526944847013679786610562448808042323089739458185515278989035996,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 4986969170469131118902484765032816954191957152078225678486896873003641973723, 21202616752781888558814138304228608146281602004664151002646130670030978646348, 2746765780682739559760716011462644145530057447366323257431473272606288677745, 18351958775358660274116868713375638763104019713559736407365092686978674746011, 13689566468139719810551058757747144388002498721625743419381849775035444189123, 13027859075361157800930068848876610921259261426602386978901597843222068054675, 12392565622073693737165250758617081596682728536246101607444637554441087421006, 1690498119427706084201904892757811328739881784973917141246888967960398166600, 20819388352076450679126077048452009297256342062133774963779124802581571790983, 236952479601181831097718604295683623880877085864608357598740880345547721574, 19045546087727438818536018134714742409287234278428943044982930864373114710009, 534222936236647260103019652157525627239136612108117411853967314911437542442, 10399093625003806875471879427250114951963895141977205242404610188644625566311, 1760235953294500975249860112037708545055723717300221957801794155055417119501, 6651280672199747504203179320426788100777892776771493225425501720159075609055, 21345740855617667301551298560036155010919736529481542825227031611557100243591, 1305395162931986735928614601486080918986636262671776582510749431664731151511, 11481898488846059491453018933828184438492962408682571781494070734330270897771,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 7032101556663831045156424064386881774491778427883926021005735112323390440035, 19640372938772518741541098849213617157075184407459907415138096507905602867715, 5138421245238759758387625328267644465318972890080603296243655810559296198164, 5174134232156556395333820420433956773176417574968411971165219755508260664147, 13947299781942617312494786554803503955131393442923424502804169619865461078266, 12238039783046418161494849665906971413331969405440666349472629314120371129687, 10815573235511629452160837522447665979523396578716806722631664265391892292245, 14931127944106697925258785639900519764649270374684481838012472468724401296780, 6571903632788447966616545926752767181433618860664448980972034517169433535017, 10306536041684942968217662758337616767303895488529615472252885548203293245179, 13108482805249363099320490579134556547398002546286695972092364904240494869916, 12017135451996840729859757472523031449760164834650347950456471451735847022637, 16951897027127552858413210217019706574051002371704873666337215608532961465339, 8080612718197305884149226198183659281412629381852673915810683582480290978211, 4033687284877905589243936665876668503065521727909018399705110516407104096794, 15785797625976887012036089584801835167354478588139768432272803100941608083961, 763406975526039728107987468105543524770375019187602903219438542953430582042, 3795236504122785596180411280465154491755152861340174320208011813640078495473,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 109564357577637382043016644791823832813734585284919014595036465951168964202, 8517872720471396786101575144063421897884106652318956148215563324255990680022, 1200938952444454854818005594514739494017813199825809073964118858191628259151, 7138196569511445153969327746403538318155861507060780136536432912983929320225, 9644177045427517755417192033803901587217466878262326468692208979447358705208, 11733834133865555860890036092951084064903775620344101470506133388872543746764, 1065188482283073590930038182915578135479381316603093344368325873076639319902, 6636458811408245020946935260183373027941942404546994955011519555367234171588, 6344875715313719302652959935298691523861499427377536080648884261332743756675, 709082418219662572696054800664128324907193560509296248948673749765042800724, 20633417107739876925970164894160655541407830168095358492074152964758736436325, 1481158476963087272907447305458671737048178988726573288649812108919316233832, 1273932486963850176809871924920167429185518983112372066525521597560991789191, 2016609475170992390948451511047251473645808446655055083017468860297636932762, 15414043358972022662859920841414969506515712361269022113013041221989162367912, 1409979625429032067097484171584196385666215719248140006104131028156732648346, 13295851758520056961414690097598608870686849651240874678345102158098056118485, 4639451293268891100033144442974098300978957688514011254358574178300950399716,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 1987585042253448321089220622548521142045201329172100306926531731963975409321, 18686215998478032635058401734031411425884035450994949157100591541618546024561, 16523568095018626727277531467898897463245440540769700846633609017027051475820, 13971290954795695751539480119810676730137084380992581190355116464662656680931, 17276186462298018378610990429941370648282328740130381809573457393575784189748, 5947779224647764949701620434182606960537464923678253751859197854019182817008, 558337380989190648685343909093414883687696381681838478762739409073911996208, 1929613704822347698903081679235178917763611812431308534444744089736686384895, 9954330314311102290449131068736680301967522207821187806704222883310513183790, 12349001293066333656156539883366931131911567866504821846434720409140281577212, 4280974353645159076580398503037416350105381927818491085063117035926439091708, 6093998200051345323552785303550853123214535235662452570407076134649037158671, 6455689594721843442911571121091043098644391321317636937931283763185342211988, 10761908991609208690697602821122485962237874284066803375549415735040494991018, 21690512429512994441857241541262055551889283635578284506204588713212203939547, 1072636481224120192717694714289101763934175140883534577333353133134212788868, 2317749577228039961813066998687772487618036337381078328171318585317007270473, 19352459278105713470459050543864349672135861291974486339101866665471901492697,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 7311667995133604325076032040231154886907048627167632865693010393053374059166, 1868089648173312823764423054996197820540056701280806878923922816735630545416, 13355361585641278701379413153731665699286778095823344602649869841859261928463, 11343167905945417412786825681924007399435474909384439695421440181310650484331, 3435748048397733107216455147876622865090081384741049639728845070078204250648, 3261068010157030663023404633391717951420564630980983922695312297458937546236, 21717556798091699923266909235675876064681510709866698181246112318984982568927, 6187432198298596879721000507837816746877504507181643687937903574870851173823, 18338188516786491165321368339073163406835082467032479843223181479199270776735, 18704492315506551531777803888290799462492706595731345059002351550521875007791, 2669907404306752409738198583689290962067125692166502273074903121642722627775, 20977830853103582331580541561246172291161889788155609547222701087608659634929, 2714791058193284035284525561891851339417219876077352379029051013654342271004, 18375045429344469668999032640865838635932814505098902552145016039365498797626, 6066448328589341017508408596244100618690357066619869563869607425529802131133, 8136147398423667812553943957664960592822607541347780235695923816153639238619, 7618168661008690053741143125575034674423986271604170176112165005853243477513, 16992846829843422929805491439748361692855960667032723217000738783544640978848,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 2058011612783008581827425860912732294033115156375959049938225020930947664561, 14640530439537365125750700961649715638854964267625137560070211030414396363096, 6535453610840387135912397732997462106155497786940004085438900944587933573411, 15160535276818587716075952170010952380547459097951216490764210287473373487790, 18096197079995506819258168765387602911526462197762097738657363160694643655963, 4871363599458287223197271233685661540394424037792145798210295731209020127556, 178063877871200209239734611408780639262817787477686576210691624095530086654, 18396562666521512335339437078383553755136872857959477938276959701591566860145, 4677955110834277893459027261895533269378457756851359278409204664337147583971, 18248133086779508503512102588932001357938330847212666631671541239632204542718, 8218904460624471717624010557383777915295708066946157848657075693508606416296, 19655758014354952764080032196753872566998837866956108765880623959148921742663, 18526591981177727260836405411000669274506279542029668428871647875578495557383, 17652183504047619136784372027271933493585748132154181184258255047829396268525, 16299330709951099880974795276719126832305121971951711692342456110515915435697, 9082243309101909316393344369688797458147199458043654418291228284484054359945, 12039291287189674469174254816628449776445170563579576458917051881535911816354, 1083226477985836553844721977495493714365591779210935759491191456676877530610,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 765062179011675715452763697007996177426299401321612369680388134910038319193, 106730789067473347682551335313129457016069271768314827260171065773854304969, 10565535885285092890292692060979785859808794576192162053915442906277999282502, 20775721205427688122633944617427141271230939742967534158673312341569733125149, 12514606172804191935341131113209191817296989668020465907752626600332411458463, 18598784369537577903612555583295979526984115736667289199973878654976506808609, 5840209564743842118202941276965636175549692210606587479303487814683260372836, 8449250536787909791632546535033548590761292152498165251922266314428012226835, 4915155307682697256219329086760771378642588210588300693057152200973122204246, 14383996009634927613491158055222701511297053022768875813025862198789939928697, 18011123302337633404817077501959260357901421218763497541781827926446511542628, 10658235073627413487595354948383166965782429851959916501758328876321172954134, 19692158862703958274714414079760142122989708196905259975166818342010748429962, 4197756875728495707354267295093567688990924004615457495462317458380204335310, 9847563577072199062277421978044296007688678527705382036271140401931240160763, 7313754251137678868644724044246722795775631630451921752967681720901029752246, 7516900973411156230781560504485938709197565684855419990878097658395657368372, 3406386000053875740596279635806377046916174968987048951316738857300650972308,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 21134460062173058061922993338520134043944639508737080871397768700335557118298, 13766089484489240405240512193171630132455318567705211668171606421040890084704, 17373097755509804303699429815667708275177291797331946536514177612390873653986, 11376080738822749042234487703196644100773411246827942713768744620916916617167, 363597543464807766620044320091615247243904356227286504598145521978068165715, 1712410523158391410083434511047171606711689121615538486037233437034380425151, 20522372148388614189545698052351860468603906517965328184652180532825630059873, 1514187593470778934186692987710669117508572422316410964187935034074037603474, 2070860429378346811676790718673071572487689007865754239609581714547204988789, 7412081915168994866854233291069792103019255927349648301718813254394015633073, 5227345087549438418681011788492150117582648879024925637993088259519559648115, 8499218743391929667995451768477789283366923110540035004381913284730318298257, 18826273575417741044043106023714791912975230527088286929266991488477699585757, 15386619820579831658122279860560911739385085092322435831644026372921331426457, 1887013634207476162036445391703137597662958662391445678221957498033816514336, 19315711355391890208888947140854455755186022620367321913260718425486128202643, 14827833144400598199380348640335509280546199078671937956973006376756298307509, 10857792677835835205791368345128197189660874986207532033953442610400663853399,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 1645406189491189587827602605632268762782766756872299006114408168954417009907, 4766825193375680358711713298845070766132374887175772925112258578221143154306, 15266738657403190201926294298000632224045251408901280615157896618617476577627, 21116835686644731495496245753818173383189294891237186296254219512081073838620, 21781643473897800403712323479986240761918599425693630739707628188631732502823, 4650670137769794795455332363858976211683807686780730049710961528308522985509, 19998202621280451726064819789863597878688653114062603512693975488602393398072, 7938100856726592251472707933698883657217151733012323224061388628557899032509, 5068183206811878762717594591974472856852073178794961307971845852111756976106, 9120384471166276873796251691275414794642247190983841400201107201271271131513, 19465082404142490253629154653883139510171182267212474406412009395087762808898, 2791451501427398537204869283598020223482432793655681480259817984937091920999, 4038023752505444472511045065524392169060229366302644500574093491568579957651, 10270366649908201036528597176264693851170884363560646037306855911031989665681, 19951271735080288875816382831225611507625483009276317783687079779327453594324, 6872459180648003073032302141092475133881613777352419256594412267070396720704, 145436096785361354601958872813791379571429431430424998271915819304414957940, 13413958798746259491510182522360777061218129791908590348790869730961277022790,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 16243460934882896228879521328795112400373560021684666616912093125780867196874, 10118288790538836303476595921638610534688208785109117116440088832473174727794, 18307565808355729064471318770910585837709463638350940081296336981916522988476, 13988442503812063212805508063723165425046658020304245367621055752715219795643, 12084256316530391052963176423703273203085621289796482083303173931140260587002, 6118754751236531588448645892197563597685384239167801542846642720156235220360, 13945000709657803913970898462053590174560798972008973162880813378311498200437, 7989156307276288645331655231184726721907862636059221695950073104996607808108, 14937361816973734081319658038637777100092899342280569120357251176001481147781, 1685033020987461560508016924386398187060513656551297298483362265932953301846, 16939636836301210503599298592460979495018137037236231257595818869654924584304, 20545240624491256939929043499585672635034226769395357278126035930963520470527, 1201910033243774808308639213605107763801690833654813465549254162661226120016, 295323214328031592681330827166279612078232876447317836314541977178388361056, 10791564719624196196105043868636429154836216409668336294204010717780991458671, 5697971077562692339408442815595379754706692314729390583446492382421517705480, 18747848686392022565505190127374630433996325574818304910234778717125804751656, 16486691703898212419425673935537771060531971652476775390797331346430623425458,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 13254967441897403153557331581677331110501190178172821137475976563459487789499, 11955463712100298294989508078999546929196181172345048874618886903874508577269, 19691042404218229206724394992180365432880264143114796917912749972655049307387, 4513870191866322489791431927180619306413750235544521938269106310239019229851, 1171248985708621933379975041193386053445437996293446129736623943681462175007, 146740188068661552159052298575368598508106305964507924929810410072747996262, 14223954483350323254201393093575263215869854345958343547463813006106970787, 9331321222820841238381089635421868335387743828494189399307389734740419181827, 19428084050587322072476293061690371172844143831934353885609365346463195667369, 2713553854368008883909367246575154514530560325153303476619620187379504579927, 7436248298901116516014709228318280140036432232432280269402121790642774081198, 6749064815815010618012058866197154941990172114935686891361833735299902225082, 15705187966791330370726638597022375719260007452249662456510387968340670991605, 15630247584758697053883605184527893232949502783608637204156896188366564142635, 11847329000288884700469377634296945793374550430422368795635117024418007575762, 20188067292088515697753899359915463097631843935199506259975688598343010922911, 15645888060443529429939612535335891181460800333444936375501064590731064287070, 13059088011684084316820783654465399039455171784809015940694500528255912882503,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 20980253017173417952642769303243346345916997510293271200933402124993701669682, 15807512496234207608437868499269599213325847492567767660622402598357681326275, 16004607837233349932203423718781701408402690400401073373202272477819090499352, 14304192779274106909267198320321868254448906366563917359594388137639549373104, 34922665034348374461910420063848608373556885378653014695030115453672066541, 6848410464451450048080667589438827654452601859661636274887606818070030004879, 15444007151296460622017469146135826694788987158716705041254934565171025629997, 7578059907179996213813003351483493840112031279229493020199144096112589228806, 15947006937433353443769475817785185403787926372957999446556251723156309994647, 12327530906678177093218092176455794073751205722750676021683583816731674454295, 16965519251000672096943859715437526595084163602167530461792480902816549311236, 4225720397096221190633461919810201559876302344058634392209163372450524665031, 10942344469661964789889232751692402439839757439496730775568464496096758802911, 18673297286308438769873506657317883151730336947473588156022761598019937031300, 7292593327347131288017463469930720249513132607692157189347515265704906471042, 3359359530085024238792132751001307105738292572942451712399073806021801769806, 8304990876841842065112130763861662539836575067239539833997825860164172719732, 15916701075478851994467330678568452867698791992156733713699309831839878492858,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 4303204051109977198992962905963971239236268523148728420439754497637942843936, 14832114058447632448093295095226810210866252758493529770413151081902465518499, 7864232192514116338455845593282342517282645485478794450419203767403770197377, 15623042124555771987729645800803029160573930724762351965648685205776116179354, 4954146526020804628014476117092822298058181373778187611913963574229732924361, 21055733648676441344523730393285020885034941792886813067986792728572101756839, 15824956441036164689569422438932632191884648455881038625788987014334213212877, 9823666214413206837889619496294815520175117242361729866348609136667875602958, 14833240741475716961259819226589029626824161704136470670107720094332458018870, 671925903501720354122890335506402539723308979120491277011493371793301394414, 8199066052582218670642429925408509736286985765336466848886133829907854938365, 8234443536951865325269365089692792145784302283802893960694848446315862191623, 8390255419665850879306141646471827680958188488304820929220256041122747042920, 4036290685359991425112459608139160282365435835081300290464488074168848507208, 13647672871172326207938337512717222660821519508073474168794411591012437406636, 18662644602020598945245400603377924084283751741313540067819047977518401374090, 10791275534398152752313811859387591255587478628490865318327836100982351132073, 5152747449096619772133847142247479246283560720229765772815506128175040716209,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 10318791749144090466354946301455732275636719076427001289945854188200251874964, 18071067298353541185539977368568386341849701614863986521953414283445597425306, 1294919946489150674541331519460056684963613326760407135501427706534597417282, 13367220042694755381606341864863180785830256444444802349526729706615589998168, 1543636744954062587855779942699104512417814870033518521854332031503827475071, 19708538838375678429774493826821911421635523814600886974133004098101556569260, 15906237145602462192997412349374691153501558454970227241695460496508255237985, 8159514753793991815898298351539644332154259203784853002437793019322282119369, 21477722614300855870196260954920918019744775435608929548214061553446506370264, 14853398086056246419273962752696527377322127721038100845750518561851159508759, 11226532878071320342844888728407745226972279193411762966639697709244164915805, 4030531478030462281637675026531182260340208302627868598064805535760891899248, 17658932629529698592283085446726330082745537828314502687595272826650186079231, 2385691526253135526715993975475330219699425814004372048971193259025672506914, 4277316542989537026960827831419789404672288588391291637870840043544152923102, 13767613192999263802406551720888221882049076442357859267871140045146201682668, 1624163458777059735978660253891367854819312370158443091930802226833337829647, 89418860254063579463369905308826645980985185023146148403901468992715533801,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 8710478801280094619053651116956651138576052059930258726200550866827936991051, 11951512962296318192378703501316943964674635553961924862617871200566352386638, 17620231173441604363394081270889521469511669216490056616754893115297842844573, 12817244769685869180731386552827150307624297720865371136747136084466144381213, 7119827582297253425444233751905188763106308543719259697597651791827498286661, 291514273303178980808427172828156032982937289898370590198567825902216527097, 13899065995131221096608304854561610658197035063108948400203819053450485378977, 7620650430154617444716071185155071269180167610776817239443307302583557395577, 9194607138625874780497260051669679618212501019055539955091300610660134362396, 13651857333269661817096178180689903564266747466032819879946352574002730689098, 14026112340274220354545447500948754924974669299504381486704636990221315021145, 7967914849014180292336298177427903272035106663244384653006346901854743602875, 9193633419589510669573601361884848666699078900832612032924399462102629786773, 11238413234081134320851460795880624093706204482460268465478272095409788694847, 12604585689822743447440413853883887216507271243253712155002680176723033947625, 2745472558603056855460175030614941026614719125199782050314215642589549596031, 2080773116530796554998570566435524086881107549317652292710032010696478337175, 16008485668487835610350498858782182543408057990301475716836272290602561714770,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 12665793712054041695893750739370903239393913956842248220866951148834055294116, 1409709744917285252578125345471059705308669098720805043104885434380365022097, 4276843503613665070289228494496279936030038354579675664673526460877789643755, 19763376396137855663296436279567155768375007140348735742512359173835401925623, 3070211255998224745410141917530340982406625899059161005511050778879880118399, 18397829932527590255414404925883871580885254998026882135289517264724304117492, 2959183878526870533767190976394477274944912285401495151514390820068027158484, 1115599590268940682685882579970778083428738092295226493625095335592578693608, 20429022205898534818872957641015036363229464377988346504786710023094031346880, 8861660853934050568657602041546383800424507571765696862428207263345505141219, 4434765288394673088374594717017370897225359602583566969585430083540332522371, 11708350833318932317058845496010760805241125158669914444417287775509245610201, 14116603111691208626061705978617237365454438354453736641046072218331135639198, 9116342777803537989816532151426955777930405617404669574761709918111215295326, 7230387237997631087343688151735771220915245630414051850680807942291364973432, 20366366356543100046476363046834573860051619624311934772122161600052923209587, 15748952733056022555799097481032398309142402548386536233776155287677493401961, 11126568746900522118397484872178079544662341955718835448882141762175437686498,
+            2375326280684828385181441823935580489283181336736930855154573908611495388988, 4758266170300578620294145210341846982030401321690767513219165464008451359465, 6995459824676046247854546145291567117712928562860021707284278045882991085070, 13160993742003457794631880354468727533433518637628410688850558199790429691987, 12674675135343683132985281566713263072113286807364770167886035933103455696276, 19413838153466451358848549292390396508660628984154582990950761440201102727228, 16770018397146467071340467136125727821415355677101461558555229464680838940785, 10482315039283431402683860042092317933285094816203600019321905685688164761909, 17421176174184595345868971393420768778725580330412171717660899500317996389346, 18205380690962551944392555381982761060789293811517325476892322364104141720025, 14631298842369689804698699693605761470127449738173569696213939694199962660589, 9238135912173222737189969566541896722097879899972864439884878781835483441644, 13796095702781206444769252943157582180699676868238670905311454239085230432379, 20078463992388046831782079193060468275215834366028849964613155760920853182307, 3734512171963834158947054928310536318563813906140199157845407373452292740700, 8243502361533447055759755935869360224500301789398223755856053342331725049659, 584496540765509170950396596159328661085357145509324408817863975786104215468, 19760955019122292947746212339278995436138214203157828769803993630608985377786, 18323185973220383561112796160815342578071629652454665305975538406701818493131
+        ];
+    } else {
+        return [0];
+    }
 }
 
-function  POSEIDON_C(t)  {
- return  [9349661298342399812155975888769216971032306854753642878665919446294879656360, 20355483845293419182639799451281054460194762622258826357723348986791339666584, 3863924254320797838188096098429099056002921664247415692790885110053932478199, 1179615533324779426788185816518326392495151040698217115677996613301276682774, 17857817167154695836609045863125494616953942146896460807392682577567978661748, 598171348165321622852793933505075582400337822156992624972338044918725664853, 10288182281340317860498695241603468482410103834992200046713945227738753708698, 14033715015428758690964178846063326121907630185247031945740719057439149565993, 4403200580683260380496436725772333420155669721780700308273860885868494218346, 19596494125741654142983078963141005641888021246517341126259813204502802384067, 8564650104936121756307676523083173187701906303606385520973932800410096546403, 14645239752159699463621673219464989513053653395607183327172956630300632214161, 5306035170159303765381094919358101787761660740153826945252354930617409344545, 18879875135569478383581020380333204197826083956201656258109092867558319472011, 3327735463285264389547553322049591191687534162750551437554125229508114433905, 16842705392485332591186430147391106061494560395960911123266351758884473968040, 19116030705444699721811373764669364345407858022672453360681431146172949087255, 2556295993134190904194870012333867079241625910210556131806073737807670536195, 10644869965734901344443395387387045116760852031343528360919723232182003180734, 3146284219537929952513093370046724584557543194361496722163849248585270858229, 11799529262408724785365581933962772742794621397990395000863288804785129182910, 5038221304521166673710137477200929399954171929955649530746630250171144596841, 8147354433672769632117515633559418405264662965336752553587905022048019393513, 19805612709260883230258634930327591635602067839882100212275456926381669940777, 2828469452445477666662718382140792509524240931040336457791741134359005620522, 21619645947626002179335865882621506014445270080232469039597635148324917445113, 9657701437123232145208715343070779731809312827823196697094338797717108620204, 14405933840181041767886032250856357065005989540790394504607873676504442544388, 8050077752121024406680602930032054682176168814235137818747683602730993239723, 3634779791528718610963055486060763289258492797276529204378463126976652898282, 14289504638709009817514913366281924261412913531931461147916604063353169135875, 19808611561178210185907727485467381894653904658064972676596810398265291312682, 875540074392053829839167347384995806945141348348684357895337260650951537651, 149782703995897590663727251277218139898565432305899560520809768763459304960, 2325899818459332698188379133405601478486706187813182188831118886386900440108, 9387786297727168942262053600500939618384074909841011712360818706197854259398, 14643564796002952608138338041991975511099529001189658022198918898178065285942, 11943780670255343654264677736016536880932809474653319595135792386661428034208, 19315122676991448479562196612729578244657262735634343606300730864316514437916, 2033302238957093548694177680313132720823621784029539640386529595987205563817, 21017162213109765517720200069098681093558829680891994167227378764515260704254, 15695855131310769445331546608245731708535648553533244927667812629965807413055, 10254339763535511888801336395312728667111712467839664607963569642664046146286, 12857722948612458115918613587010186642024740635518051593859714580875022836625, 11689774519980874591423750398549403636243259886734402879736794015889248625159, 5630545930581933227491482765182178718417042751941115635783964030867302022650, 6462221634759901287717620648363892578196700535694051938397877848486937602072, 20135479848967449888663575942155760364168331277250987582105510663374436296566, 1506609375074084439295681604537488048634936232827287222840264656582617426048, 15518817407686653165310534550174395920063796796856306232577871664623816236828, 19697819040079558751184076210336177971945661040616356222508498827633936546670, 198086315849005460058478289563826903696525857046509272045516380446741022966, 15141038601089609410228264855564406411869089209269890544206326237870077595378, 6466856554501932373912212518662553404889806045647107724027129718774447529043, 5230847849349980515957799436151781327741685505034268701434156624912358604423, 16475741596232520401203151449043570574574867732271794733709752490143646037202, 314763228971272019180648001512868301904229610940230882078568074520372477746, 19767646071819927277983555204769055764719697844313037623984782365142010517086, 5308825563589825235677486073949278985017222520397253733198695890888836966715, 17943593604654137368386324814539877398547782685116620064383641773201233494038, 4628113511033300016757558493411844990857180580030830308444504563793196550163, 9371356098548153693737622866049255440158102944027526733410378436897021883337, 2804579237313176834853009873496592194069903569969278241019437419075571132917, 6533876484783851614881982093409170493352439625847144415920061509291002878591, 8878042080143090857128906068599732746700296709001248742680562287395322993167, 2006989282579054039586338264542167953557318975898350998842592859144181687395, 7563684410685667570425909108526076102246996303714795688488274903582481599048, 17793144260754017063441700509744399505776617550306096499101066213049294923253, 15461784754357440427982579711352836817521124094147349663739899144158640542547, 11637002508261932767706051620679867875917691470985288544482878345196253782210, 3290571548788211789568164891870609820925550339992234007493371295600160707518, 9543442816197392637689010322510777882517657449224330631027797262441272793338, 15741976579526785704909710434787538663849862333755029189819883708525363640271, 11587448147767191550122800504099103588134277946389363363918366540547969857939, 17089945160930463480299172005159435545966194950004664848006500408993865303936, 21076397116003169143237549196262170354823673854940100172549085297172654765965, 2936824540194315304637014031292877115056910692770295884310441315796362848520, 3781723304823238282041311984598203813092601326394273530089132695630358346612, 3877210817131422478583292089293035419531115006720513407976642521503917655828, 18288778785643334665607696349644110626670483121710897972939679969152733585582, 15495420713207559051343512003805682385572438645142210638759550864258402356610, 15119123750029189700711075787847466555341636481416216103240587119366240506150, 11315426816296385118998923842301936481836773040737464959688429922679983454982, 4554779067708368684206250019380631397702142865103060126290923342511791751189, 7518797529936055312687165446662466828525723598982165204297380098190600309745, 21274000271620242444761341207873898613663993729356429002456844224117451205099, 12758340679572462242513103350035983373783313600058349712999333199270358630357, 18075460348028450656833105975538495928217184307195200939777466004354274817647, 479287212619403249267464234107497337618767005546786937973104804424270076529, 15623099225915283184952112665460997295735843781600923635586690676724393971754, 7808119454227715457905211900490542186901857058806129577898073532801689565552, 17114888026887817799741290345291364699443741157849200190371808547402946059863, 18234374287120548493264565642741001584228393427091467694122765660247438751703] ;
+function POSEIDON_C(t) {
+    if (t == 4) {
+        return [
+            9349661298342399812155975888769216971032306854753642878665919446294879656360, 20355483845293419182639799451281054460194762622258826357723348986791339666584, 3863924254320797838188096098429099056002921664247415692790885110053932478199, 1179615533324779426788185816518326392495151040698217115677996613301276682774,
+            17857817167154695836609045863125494616953942146896460807392682577567978661748, 598171348165321622852793933505075582400337822156992624972338044918725664853, 10288182281340317860498695241603468482410103834992200046713945227738753708698, 14033715015428758690964178846063326121907630185247031945740719057439149565993,
+            4403200580683260380496436725772333420155669721780700308273860885868494218346, 19596494125741654142983078963141005641888021246517341126259813204502802384067, 8564650104936121756307676523083173187701906303606385520973932800410096546403, 14645239752159699463621673219464989513053653395607183327172956630300632214161,
+            5306035170159303765381094919358101787761660740153826945252354930617409344545, 18879875135569478383581020380333204197826083956201656258109092867558319472011, 3327735463285264389547553322049591191687534162750551437554125229508114433905, 16842705392485332591186430147391106061494560395960911123266351758884473968040,
+            19116030705444699721811373764669364345407858022672453360681431146172949087255, 2556295993134190904194870012333867079241625910210556131806073737807670536195, 10644869965734901344443395387387045116760852031343528360919723232182003180734, 3146284219537929952513093370046724584557543194361496722163849248585270858229,
+            11799529262408724785365581933962772742794621397990395000863288804785129182910,
+            5038221304521166673710137477200929399954171929955649530746630250171144596841,
+            8147354433672769632117515633559418405264662965336752553587905022048019393513,
+            19805612709260883230258634930327591635602067839882100212275456926381669940777,
+            2828469452445477666662718382140792509524240931040336457791741134359005620522,
+            21619645947626002179335865882621506014445270080232469039597635148324917445113,
+            9657701437123232145208715343070779731809312827823196697094338797717108620204,
+            14405933840181041767886032250856357065005989540790394504607873676504442544388,
+            8050077752121024406680602930032054682176168814235137818747683602730993239723,
+            3634779791528718610963055486060763289258492797276529204378463126976652898282,
+            14289504638709009817514913366281924261412913531931461147916604063353169135875,
+            19808611561178210185907727485467381894653904658064972676596810398265291312682,
+            875540074392053829839167347384995806945141348348684357895337260650951537651,
+            149782703995897590663727251277218139898565432305899560520809768763459304960,
+            2325899818459332698188379133405601478486706187813182188831118886386900440108,
+            9387786297727168942262053600500939618384074909841011712360818706197854259398,
+            14643564796002952608138338041991975511099529001189658022198918898178065285942,
+            11943780670255343654264677736016536880932809474653319595135792386661428034208,
+            19315122676991448479562196612729578244657262735634343606300730864316514437916,
+            2033302238957093548694177680313132720823621784029539640386529595987205563817,
+            21017162213109765517720200069098681093558829680891994167227378764515260704254,
+            15695855131310769445331546608245731708535648553533244927667812629965807413055,
+            10254339763535511888801336395312728667111712467839664607963569642664046146286,
+            12857722948612458115918613587010186642024740635518051593859714580875022836625,
+            11689774519980874591423750398549403636243259886734402879736794015889248625159,
+            5630545930581933227491482765182178718417042751941115635783964030867302022650,
+            6462221634759901287717620648363892578196700535694051938397877848486937602072,
+            20135479848967449888663575942155760364168331277250987582105510663374436296566,
+            1506609375074084439295681604537488048634936232827287222840264656582617426048,
+            15518817407686653165310534550174395920063796796856306232577871664623816236828,
+            19697819040079558751184076210336177971945661040616356222508498827633936546670,
+            198086315849005460058478289563826903696525857046509272045516380446741022966,
+            15141038601089609410228264855564406411869089209269890544206326237870077595378,
+            6466856554501932373912212518662553404889806045647107724027129718774447529043,
+            5230847849349980515957799436151781327741685505034268701434156624912358604423,
+            16475741596232520401203151449043570574574867732271794733709752490143646037202,
+            314763228971272019180648001512868301904229610940230882078568074520372477746,
+            19767646071819927277983555204769055764719697844313037623984782365142010517086,
+            5308825563589825235677486073949278985017222520397253733198695890888836966715,
+            17943593604654137368386324814539877398547782685116620064383641773201233494038,
+            4628113511033300016757558493411844990857180580030830308444504563793196550163,
+            9371356098548153693737622866049255440158102944027526733410378436897021883337,
+            2804579237313176834853009873496592194069903569969278241019437419075571132917,
+            6533876484783851614881982093409170493352439625847144415920061509291002878591,
+            8878042080143090857128906068599732746700296709001248742680562287395322993167,
+            2006989282579054039586338264542167953557318975898350998842592859144181687395,
+            7563684410685667570425909108526076102246996303714795688488274903582481599048,
+            17793144260754017063441700509744399505776617550306096499101066213049294923253,
+            15461784754357440427982579711352836817521124094147349663739899144158640542547,
+            11637002508261932767706051620679867875917691470985288544482878345196253782210,
+            3290571548788211789568164891870609820925550339992234007493371295600160707518,
+            9543442816197392637689010322510777882517657449224330631027797262441272793338,
+            15741976579526785704909710434787538663849862333755029189819883708525363640271,
+            11587448147767191550122800504099103588134277946389363363918366540547969857939,
+            17089945160930463480299172005159435545966194950004664848006500408993865303936,
+            21076397116003169143237549196262170354823673854940100172549085297172654765965,
+            2936824540194315304637014031292877115056910692770295884310441315796362848520,
+            3781723304823238282041311984598203813092601326394273530089132695630358346612, 3877210817131422478583292089293035419531115006720513407976642521503917655828, 18288778785643334665607696349644110626670483121710897972939679969152733585582, 15495420713207559051343512003805682385572438645142210638759550864258402356610,
+            15119123750029189700711075787847466555341636481416216103240587119366240506150, 11315426816296385118998923842301936481836773040737464959688429922679983454982, 4554779067708368684206250019380631397702142865103060126290923342511791751189, 7518797529936055312687165446662466828525723598982165204297380098190600309745,
+            21274000271620242444761341207873898613663993729356429002456844224117451205099, 12758340679572462242513103350035983373783313600058349712999333199270358630357, 18075460348028450656833105975538495928217184307195200939777466004354274817647, 479287212619403249267464234107497337618767005546786937973104804424270076529,
+            15623099225915283184952112665460997295735843781600923635586690676724393971754, 7808119454227715457905211900490542186901857058806129577898073532801689565552, 17114888026887817799741290345291364699443741157849200190371808547402946059863, 18234374287120548493264565642741001584228393427091467694122765660247438751703
+        ];
+    } else if (t == 6) {
+        return [
+            10771411641833261575254636475741074137574319884179345491181417245104054040911, 10226873576709941472494324767512681478073649868800242036043488942252410431040, 16356673103369473937177375571720101585427546808919823311507253620761444672321, 9616282310424820485577256001118962488516563308893034144841146433698006408352, 12296947903686538794694285989707264870391193194869522795493974604175445572933, 18339119639110636045257619600352812193777367635682309106180181471964334651840,
+            7407149302550331089377553816178329158210815939060597323282735444585247439009, 16485212976265194247029558451558044956215521901249218910684677902937575234442, 3153064683263993362456599251755017601371809044007098690887850635749217574972, 12283290507603130593465206472186169026337897271884899952084864804714308112928, 7521792392381020330682458013028540235250635419209348015743527421256161024756, 20350568348420313352398747328035077015262687758901727309050044983345006011444,
+            5334596163083853426186055469428367047659327259381412996066577795963266752732, 13939967881795738926064425768206140700409841262133213258843471398736606004661, 8583191482536805016912481827891064179653957130595309302798545882713805453865, 8652245542318505350943504443239431263419131585678522344821416200250623806789, 17155942160138262838969061676849029726812044564832900614237017795822939176884, 2059590631618111707670262803875828191580291671143467979996685599737024208651,
+            18816141741437833263843821999791872304341553014278967582201041088483047653941, 7875642198241538954134688673013630294728335040326180867210047636204084057130, 20547620274562030489560025958183764616454582170605248924041049654974621281109, 14682789618190287476173095907940228457113623318080003610004034325291192776199, 8339529458494999053608892336861966252831506314622425106000468267623378933549, 6724786315821763688424265480232193326828001229231865888933482787238137798225,
+            6930289561898234088840179487415723806053048987934963326996796649998106277155, 8206982136206291267715779950334889918543117838462717052749801004055906462780, 16267371734205478479226878078903076212272391934360115411748940001084534803349, 11480847734344171892807405320068073666080354959272619741336097724159269328509, 8947316812367351339325386595537442089824047917918449299826769378948980461376, 11147166950354900340479566980857893103847205178580621849012710222192896022126,
+            9841950325584152100457265700774893258112760871318856581631909526162256722758,
+            6288367059683625863122386533823295881921717817382808600312785860747414038075,
+            9027154318870854759577283631538807966861425544672646105340778519385679826819,
+            8464755165450052819253226394420965986314536642731657158115547322102308730380,
+            14149801072760294344224918828688200041491032789246177870283134792048739859115,
+            13004617072892945214599176516447735719479183026361948685704871782649724546350,
+            17541140593173095662383619431716677236251518629563963763620967749233268488980,
+            9501175564386714949064024122513115613020500397115515730551551836342057351530,
+            3101798198415297451892944247783288393754007819801768549107695916052139713356,
+            8912435958410445922625200770864682561664397109715391254889247685962905917515,
+            7646397691544642701351252933814407908839541122290403851739940472210710007549,
+            9441372941891963860734044666693653918605354332593207858640790368397251454359,
+            2221194337417643710192490352572845692461674409600688284848565458853812353036,
+            5244378889459630467423340198846321970418565244951454904113234250506953640354,
+            20416064234641726750938116818660784434264609001389764268626987481166477772169,
+            3120713659350380345914098234948827115151381120388369410414066935231017345833,
+            8057540452529438444690649054331418531743554365265706919318757117697520655248,
+            8395297341988424544494253718934825013420835743401644530761365247141524396292,
+            17060807479970696477279133545361766114326046547047673451638152335717666756684,
+            10622818584262662587480330134071031792418503216940759438535762353063585874393,
+            73122409486855089691689424672664854909528481916333274697076526470455776143,
+            869481738278593825995737464364679347213587314211562965714418985227671652704,
+            17760030957772301231729649852172293934159885379633357872069262642180806071339,
+            19791327143865150773375530586070166322990888332724951910954527198389509666984,
+            11962593577915776051075762884719746794972279105569785856044039557705311587010,
+            6366364484896579876762032894259379658994922608484366472508191726358547537154,
+            14343946185167630908312052328347151444474785108823273187870720022376233411233,
+            18490974443443242305059685062068353384772754264705452050399018013770421025041,
+            6958006263817125150919241854540769657732882008179271396037497721754041237732,
+            16107235249076711274700261149053243981471143095581186678661347349867840777811,
+            11716060226094611918859044332040484298352803123286553649232319154501350032095,
+            20219467126107051417900927327369113482182139335821449250286688422123579410053,
+            3077572610867804562472104609354465406627754400777931690010378120209088438595,
+            17658569922617315037890975573548627001991170013447804735360139542005458132410,
+            17384628776228084220155907584268227847862172368848836896321358869279239303502,
+            6863382617215604670051649216000730471832688419982663872817498369875823167119,
+            10539546858011885508089378346041639477524177768244255720574686868120642088356,
+            20450008043969422279459857990782995309853756279622596103596731867388465476103,
+            14948899820329680711083656459945223651892302571310389737662856298392802357720,
+            21524748209980266102620653457721906920302007108098707083691022872162851027837,
+            2254895460426147421047464122828995586918277797972572411489438473359698134722,
+            4651148376096839326443306431687383980567784287510951665903551194351975674799,
+            2464467792649940786131387904882922202759518801102186761919688037133801881661,
+            19179004614053038672732320400455097626299128400912660993237366721874457699350,
+            2588543307639662670022922173954196385338766966565373349240673523569250440810,
+            7513011599547442932914922888213149667122484285343921621591311034867649748846,
+            21721775758642559831918213701493654386075543770202140245428180254246143434478,
+            17190173369393891803529641415892779520604570428110064183061747664211741130168,
+            7543066919288247276280081432256847068326074359352759045120782920886910648751,
+            14683995909550516887320370149028634436210329610658106631249745656774690831000,
+            9116801917563738487560085807447257922813635977219698794742748987855226883379,
+            351562756541947304368617341623128325254853298067715853812360006842610820700,
+            14910295121214962251642250246675461059633867333904517509227094086602407780305,
+            11719481849469547097782247826926939629763628560678180457044234724560408083572,
+            5569030916268895328165218682360316728033608417385951711999745064755481672095,
+            14621614870814976921867151692416315921794175003847274902738570451607630641825,
+            8581659443651503025104883325700588699280993239467615702530519594782039101197, 1180673982891436414078594227422435621107447805249883162464339972304719495495, 14851545221345039685513785212223542180555958180620455391196327202361336047305, 1488398510858898837383229774707508837818005820192069532227710205841097885806, 21688189842006214806449010555517726101075935049890014972684695862011311084473, 10549703981799457324417366465630932424068855107018592266422221375820110896668,
+            16568689783894563267281947979662357670305402808018391957140497559861175435657, 20380407002397227402285084156667501765682175700406348824463164030051770512693, 4901239133662599112848228393242295689258916406811838143018850527928205365563, 18818063013502232594061175268054320031626319826461953905629537316701970746443, 19472580311948223203153975430130572461405154151864596346562498260487446609735, 4465903863274600701728559265587004742481117942835253757110948015018159448069,
+            17917213095008282463311987795802011108049228276807141742013365792541236265131, 5537674246119045879183441826586094956774149163987127834712809020721348583069, 8012207878046124244029192436510070594235839101953689137602910972660197531268, 8586355562156996636856290074536692000684168626565166308986712135447428563764, 14114242714219333183104995376264086729780360275029434589359118386902612410442, 15748995571185577590462889922495915277017835801924245529393478359348135637682,
+            624841338480068129167936665442068128987058588661856656202495937257847355766, 880605737008842766400183854946467713237463542482651803691198055577652117176, 19592012397316362872812153738814750030340808155097825160618752239781045751015, 19147606707299518743827265145839660281302787294707514680370655043019670577499, 21665200629024392993885111996789585310149986770579502943502388061042266727304, 5472366771636488845632901418525317412589791088378830134443822645867541270321
+        ];
+    } else if (t == 10) {
+        return [
+            5751829695852414429692457886575001206879611101748534025854412136438161815640, 1723210382569048553031933391736464421278917486713089628755478592481156829095, 12838970509645804847407995775863383844405107865736640323015630393287667265278, 4058863976198216501322591929223572444733480952911891850445340961742835501622, 5423960695020019514960605686358411236844924027336242600454586326510864385850, 9743636955364723979801980557195965166379569601816251540884679168811186178606, 13662687175219895084643105300336217998175184418310774769399810434440341609785, 11954151850292660102562418073883166733543580533920906362927465160941757494892, 13273852528111706416313525749316463677071547073485370686752703344574958047615, 7920977569631059740969363984665107894399237266911974283695086431057053681340,
+            20427148765200681557038189466545661610913308633023392184914436772592861479695, 16839358314718462457416241417638890725124406773221750051369559370494837897602, 18775932048706589773516343600090210997451702119076996301753707168700324258062, 17370825858093014404362901631307916393758538930890728724439665857719179221477, 10511048003876877096979117559270075636728373731891017356283676667892430970694, 12599058682722952322508940538781362721637082644764533627798132901316241601051, 469947586293766536443637206930820075320119762226824190453756598162878350325, 14559069358917717289279616209585177902632968444105000142987923755305531242187, 8115424869063657052247027461739937999610166311245619540082462646444891652078, 1067468468730194714447208033093612602878313739631450259612435225775627108360,
+            7201319233254635078091536404683020750550230782017444353639301947845183860119, 16914575153359121596176321873207840210847468985439525103345769326540493558534, 13348842567005585864349644250025236770347606972280503576898864275699176552610, 3380022560163555487504346833650609468060421565255880793623551340137850215569, 11303749138385422080610369373164649673866008215966246598090425484092079923170, 1278336445114109161467639603499431821124001450021006073763736669859834495487, 3097347176365271939605990533065475758182044243189156550052805604874095989979, 8470970172148550724478005683325549443806244768915061097988604858824007367762, 2604492351710682132847950897558208814784148203190650822987436319247125711906, 18760776907084895224741892567552264052274815264745325344509093560666709790474,
+            13059524858091028829337559903483046662622745344793570403141838333218080214405, 3428713292073890069926109350749508488613331482686492357081665321086047109983, 10321777607926945801985648998876017685720363466333152968311541724923426920357, 17486975828075946075808242633587146311786590657787587861691318597454091017322, 5372662872697972608496907575553230715023868377212809927512116585352552562264, 18125676967048350718344225375147775472456088959505157896317443684303760391909, 17989740805313840798761207494086564261075825758795966104987297374994591997392, 13196834053405516742645225903855334257370388378740867443714957022057052954835, 13811218284665713506079415135355141202563924289565468205367554940425126688810, 16770164608787089837181885396656327133876578417547385718549570907470455802441,
+            3596358357192034229411610642140699966142953614017371525418537884880527824144, 3612094932053826615357634690287400167040334110751054230410752807146869637034, 2171007463347168813443076770246791513868982059387615658768576282444370232582, 17862134777816920407080955702303039508265628759959332760286153761285453161938, 7593191092703194950250794918407231278444789524130043898604068592279960265194, 21129109060546757810723479681575799378936745575022778391830756047795455941789, 4468452396717735891427377160219303975138036200312374356230692001176352425999, 18809094857903611589198490588084684685104677105350098245872454478052661074383, 19592569261498731300270229035787512651842524030973337141830505320050300668741, 15231934370891202985541675458906911708137974251278301645861759318770005547373,
+            15667294850888786124889954578896042252786482138002281138353813618753992197988,
+            5652980483623368376100369783468893623862776929189899036961847986562181133545,
+            765846521020087689647742402093571021694124216481918878171056493443901240606,
+            17832832300118023840438428542183756309473856542732389002744268798952510878250,
+            7851972223799495419205126523398993927174838215532692378887119352352985417814,
+            2910007185128044012625017243554808946935588784458143542800993049869863211568,
+            17554331319485684360628490595344531609585091679878995209983444361496265934702,
+            10713401563540665707055565684648868469528485811343581864535888180385086447793,
+            7381502348963128767807630502671506873932188431242114065792162171809216826822,
+            1190850040491520835469600295993408908017876603174612344324465435500902611154,
+            19092826691322695799667679838446315322548192292977614640221689558931035046402,
+            15131714725741163021961117031617952825738120028258321787883612852418742952376,
+            14554103865159362427699694139450040262870424123627989690791393415420551937101,
+            5693708118732283108236986359922492768017738195126371385356055642820180865847,
+            21545040398690696844019241818849616769348642328937086319817156323430378953599,
+            16649000544327446198008189505932738488466799758557779156049222974703323678129,
+            4684340990222167315775311760317800047888458754086076126654127466813911283382,
+            18211540049152277552714877026437286773940954422616062618011184635827885881249,
+            16997330696938280732658542275883458586599999208959293603934768303382136832605,
+            7046298269651821173520767813872350062905238220865041380337848143815010511075,
+            21287128670789401615414607352631263382342924048815147286198135730937235624206,
+            6431029066726891057114546539334652299104298493396752334885160989967064057108,
+            16820006387354418804455484142766297422688271172655896314959870493769336355670,
+            15507664888392200192416089885622823175652195620214647414394843182834593749392,
+            5685805087079815231598497867792218891623325589839372477459964119727980205338,
+            7291351719125470465153435972433639087643468748280549560095717167871976355593,
+            13796323978562846331796566692891292556678947182114192376017604070882368994706,
+            5257841914172970868802435231998477996465606335985592298756336789330220603994,
+            5547840418837137563878539648766759070993343465023501088196532639462256206770,
+            1078388813306379609496159737852169665983005429300271494388450595175496598449,
+            11603517533512315762975356708819079267360462979227346091417986289687885220275,
+            11630302831940138519881757025755369470317149169972737450619199289988548193312,
+            862829151421249117876285787081789377759788054655161364623038866645931542967,
+            10765522875981743674516247558863665361080441219428891991960653905164720425092,
+            5766680422695256991729124816945979237306027221587137765846844527612017145430,
+            4229624286328860472018873901165591438415337481686185452326645434696595127180,
+            13698746431076197939820350639148428926835405980094793303888999554317770266443,
+            1102898783643415248091799797201276790214474329910480342413221489280064754343,
+            15512090229972516026002223948092557671172217517097513215931421278313111386768,
+            8041403187361583412188804414433427854418600765842986475033680807874378867640,
+            11488867982795374938222650634599583481799444635969084117750973031915911968862,
+            16139316920070881919173636560906008188586247859472562613806250881013312055351,
+            16097071680806144226677211198246162640659772136090405595414081527813365897444,
+            6174421658607635672911574519986938560764198521120711659453129629518834663774,
+            16697986768794528902594335467612906016774949346146743904610580699252873023680,
+            3916246702637623874315770723351375400091688139525746305206260714515766649781,
+            14921926430663663077866132482951581458893239700044052467580099215277315870575,
+            139905612272990886865214567676804518332040044569322577012478156608446216355,
+            2744615017438467589570986746165484076658605207792006379273371723234311580185,
+            12023240251605655321376842995431602549838761636950680142025950837583961164986,
+            21256636592637861456012334226782358322448547281314404526094587103846078566709,
+            16390181070577760820825189784738452583515348188888341836602515067776527617081,
+            20294165036345223111694912116086176648119909573786483187310620498603856916865,
+            11679093077534312570140266548422240905570367046424489795184100783323181076204,
+            6100518096754510582980907436367833583509241344877929776052786900069993474998,
+            4152516256219064931016080576527189256935569165699881322022103700200809629031,
+            8230352260819296482036583739931299744651996362977827644648872130455167775498, 14781150272394516050451460137902057820162975993356798508910874089967136446158, 4264132253933756625158046281911306813110222474175566765543402072750117634769, 13904517546899414419841731385805497972315933066405466559509500661793988348404, 1852845791721211950081746039508269090809353597889326505602360011643778357676, 5695405096514522937765123449110593332703686143512040484034537656352063185320, 21299512222509028907161319276929706473808609990466369012307907474264243879868, 13280139797501449166789653241857136840161514407971883850545435165509092431772, 18239103033282706514371598060667952618898694900553943368749630351318077151592, 6130320766579114500044986032128993924101206545232025204962245973847370642790,
+            4265458786720761248051411594062035719671378028973590147555760084218876953682, 2273637840836552343651553748299135697221954760636800998490497791632118489437, 7990660265931350687888532222676676815282547244699281580162023607887544691815, 16532692330239230501545431516963951036093458539665491415082804985089823050677, 7367497666008644952817336797683059531434394334138466505723538223596023364142, 1468778265036031376127436451904903608312462321894090083585754046213913178723, 5001837399383917842426684312102179036245463018035796901201357716072597465298, 21802621377654446375404964009689709054915320808928291259104030871011869870370, 204931987331241476645898671344975641621293908268485685000672241353519853526, 15148178560220106260790934742358251038602893355054199333909998780961780352503,
+            14493310192279310722767190392393971560624661517278447480489670806911562409778, 15814239943925856199636698356937927835116210909597402617686603871513362654963, 19830010163475737253191899170767193987589093994447435412133198635133635350607, 12082683957739496714190990964160473796843409872485876326400257365577537618415, 7231768500289601878948148230739084910352471111799853120100433887316142644046, 18641250324308684327309612824398749368199255770477183592023789683744651202806, 15107458951632112680647840764900613562911249733850631621001804944842937349903, 16540998432191047488593645605424618043975105205980565264038674242525898400094, 4368777933350366184323199458739413915261198602215815243840662490652017552929, 6324812721965743643778378316712522737040379613335524100796003945164649851257,
+            6952998529359652318112626198886451181087231382013250454550668799702477803349, 14466848253173278404445444268234120156135521230269261249068227681174896530525, 21727131820966073403186644320859350767920182709814146180128730147498830388880, 10942044644195259081718434005304725464200800162969912504560789620117167660848, 20114478128748582442111787661218142757797538555463272709952138883346656499708, 19478601758678805412004322112776385345883746853377544001667036799148565860978, 16839431275614984319915954843588837557592205197148172205241173514734851858438, 3732598974853665991983123258970912862889242270431180832792659201314901122701, 7816527699067524362185479226994548590921570251384178401599639547589725056035, 13955450170649144096154991662822874396923789976991649633745758681594617206872
+        ];
+    } else {
+        return [0];
+    }
 }
 
@@ -100,9 +100,9 @@ template MixS(t, S, r) {
 
 /**
  * Poseidon permutation. All of the parameters are set in param.circom
- * and are generated by generate_parameters_grain.sage.py a public poseidon
- * parameter generator that can be found at https://extgit.iaik.tugraz.at/krypto/hadeshash
- * It has been modified to directly output a circom file.
+ * and are generated by the poseidon_parameters example of winter-circom-prover,
+ * which follows the public poseidon parameter generator that can be found at
+ * https://extgit.iaik.tugraz.at/krypto/hadeshash
  * ARGUMENTS:
  * - t: state width.
  *
@@ -116,8 +116,8 @@ template PoseidonPerm(t) {
     signal input in[t];
     signal output out[t];
 
-    var n_f_rounds = R_F();
-    var n_p_rounds = R_P();
+    var n_f_rounds = R_F(t);
+    var n_p_rounds = R_P(t);
     var C[t*(n_f_rounds + 1) + n_p_rounds - 1] = POSEIDON_C(t);
     var S[n_p_rounds * (t*2-1)]  = POSEIDON_S(t);
     var M[t][t] = POSEIDON_M(t);
@@ -328,6 +328,9 @@ template Sponge(l, t, c) {
 
 /**
  * Poseidon boilerplate to simplify usage  and readability.
+ * The input is absorbed by a sponge of width T() and rate T() - 1, so any
+ * number of elements can be hashed with the same permutation. This matches
+ * the hash_elements method of the Poseidon hasher of winter-crypto.
  *
  * ARGUMENTS:
  * - l_inputs: length of the input sequence.
@@ -0,0 +1,199 @@
+pragma circom 2.0.0;
+
+include "param.circom";
+
+/**
+ * Poseidon2 S-Box.
+ * For the verifier the S-Box exponent is hardcoded as 5
+ *
+ * INPUTS:
+ * - in: x
+ *
+ * OUTPUTS:
+ * - out: x ** 5
+ */
+template Poseidon2Sigma() {
+    signal input in;
+    signal output out;
+
+    signal in2;
+    signal in4;
+
+    in2 <== in * in;
+    in4 <== in2 * in2;
+
+    out <== in4 * in;
+}
+
+/**
+ * Multiplication by the external matrix circ(2, 1, 1), which adds the sum of
+ * the state to each of its elements.
+ *
+ * INPUTS:
+ * - in: current state.
+ *
+ * OUTPUTS:
+ * - out: M_E * state
+ */
+template Poseidon2External() {
+    signal input in[3];
+    signal output out[3];
+
+    var sum = in[0] + in[1] + in[2];
+    for (var i = 0; i < 3; i++) {
+        out[i] <== in[i] + sum;
+    }
+}
+
+/**
+ * Multiplication by the internal matrix 1 + diag(1, 1, 2), where 1 is the
+ * matrix of ones.
+ *
+ * INPUTS:
+ * - in: current state.
+ *
+ * OUTPUTS:
+ * - out: M_I * state
+ */
+template Poseidon2Internal() {
+    signal input in[3];
+    signal output out[3];
+
+    var sum = in[0] + in[1] + in[2];
+    out[0] <== in[0] + sum;
+    out[1] <== in[1] + sum;
+    out[2] <== 2 * in[2] + sum;
+}
+
+/**
+ * Poseidon2 permutation of width 3. The round constants are set in
+ * param.circom and are generated by the poseidon2_parameters example of
+ * winter-circom-prover, which follows the reference implementation that can be
+ * found at https://github.com/HorizenLabs/poseidon2
+ *
+ * INPUTS:
+ * - in: current state.
+ *
+ * OUTPUTS:
+ * - out: permuted state
+ */
+template Poseidon2Perm() {
+    signal input in[3];
+    signal output out[3];
+
+    var n_f_rounds = POSEIDON2_R_F();
+    var n_p_rounds = POSEIDON2_R_P();
+    var C_E[n_f_rounds * 3] = POSEIDON2_C_EXTERNAL();
+    var C_I[n_p_rounds] = POSEIDON2_C_INTERNAL();
+
+    component external[n_f_rounds + 1];
+    component internal[n_p_rounds];
+    component sigmaF[n_f_rounds][3];
+    component sigmaP[n_p_rounds];
+
+    // initial linear layer
+    external[0] = Poseidon2External();
+    for (var j = 0; j < 3; j++) {
+        external[0].in[j] <== in[j];
+    }
+
+    // first full rounds
+    for (var r = 0; r < n_f_rounds / 2; r++) {
+        external[r + 1] = Poseidon2External();
+        for (var j = 0; j < 3; j++) {
+            sigmaF[r][j] = Poseidon2Sigma();
+            sigmaF[r][j].in <== external[r].out[j] + C_E[r * 3 + j];
+            external[r + 1].in[j] <== sigmaF[r][j].out;
+        }
+    }
+
+    // partial rounds, with a single round constant and S-box
+    for (var r = 0; r < n_p_rounds; r++) {
+        internal[r] = Poseidon2Internal();
+        sigmaP[r] = Poseidon2Sigma();
+        if (r == 0) {
+            sigmaP[r].in <== external[n_f_rounds / 2].out[0] + C_I[r];
+        } else {
+            sigmaP[r].in <== internal[r - 1].out[0] + C_I[r];
+        }
+        internal[r].in[0] <== sigmaP[r].out;
+        for (var j = 1; j < 3; j++) {
+            if (r == 0) {
+                internal[r].in[j] <== external[n_f_rounds / 2].out[j];
+            } else {
+                internal[r].in[j] <== internal[r - 1].out[j];
+            }
+        }
+    }
+
+    // last full rounds
+    for (var r = n_f_rounds / 2; r < n_f_rounds; r++) {
+        external[r + 1] = Poseidon2External();
+        for (var j = 0; j < 3; j++) {
+            sigmaF[r][j] = Poseidon2Sigma();
+            if (r == n_f_rounds / 2) {
+                sigmaF[r][j].in <== internal[n_p_rounds - 1].out[j] + C_E[r * 3 + j];
+            } else {
+                sigmaF[r][j].in <== external[r].out[j] + C_E[r * 3 + j];
+            }
+            external[r + 1].in[j] <== sigmaF[r][j].out;
+        }
+    }
+
+    for (var j = 0; j < 3; j++) {
+        out[j] <== external[n_f_rounds].out[j];
+    }
+}
+
+/**
+ * Poseidon2 hash function, which matches the hash_elements method of the
+ * Poseidon2 hasher of winter-crypto.
+ * The input is absorbed by a sponge of rate 2, whose capacity element is
+ * initialized to the length of the input, the last chunk of the input being
+ * padded with zeros. Two elements are therefore hashed with a single
+ * permutation.
+ *
+ * ARGUMENTS:
+ * - l_inputs: length of the input sequence.
+ *
+ * INPUTS:
+ * - in: input to be hashed.
+ *
+ * OUTPUTS:
+ * - out: hashed input.
+ */
+template Poseidon2(l_inputs) {
+    signal input in[l_inputs];
+    signal output out;
+
+    var rate = 2;
+    var num_chunks = (l_inputs + rate - 1) \ rate;
+    if (num_chunks == 0) {
+        num_chunks = 1;
+    }
+
+    component permutation[num_chunks];
+    for (var i = 0; i < num_chunks; i++) {
+        permutation[i] = Poseidon2Perm();
+        for (var j = 0; j < 3; j++) {
+            var k = i * rate + j;
+            if (i == 0) {
+                if (j == rate) {
+                    permutation[i].in[j] <== l_inputs;
+                } else if (k < l_inputs) {
+                    permutation[i].in[j] <== in[k];
+                } else {
+                    permutation[i].in[j] <== 0;
+                }
+            } else {
+                if (j < rate && k < l_inputs) {
+                    permutation[i].in[j] <== permutation[i - 1].out[j] + in[k];
+                } else {
+                    permutation[i].in[j] <== permutation[i - 1].out[j];
+                }
+            }
+        }
+    }
+
+    out <== permutation[num_chunks - 1].out[0];
+}
@@ -1,8 +1,10 @@
 pragma circom 2.0.0;
 
-include "poseidon/poseidon.circom";
 include "utils.circom";
 
+// the HashElements and HashMerge templates are defined by the file of the
+// circuits/hash directory included by the main file of the verifier
+
 
 /**
  * Pseudo-random generator used to create and verify a STARK. Usually, random
@@ -11,16 +13,24 @@ include "utils.circom";
  * we can create a component whose initialized at the start of the verification,
  * whose outputs will be accessed throughout the rest of the verification.
  *
+ * The FRI layers beyond the actual number of layers of the trace length are
+ * padding: the public coin is not reseeded with their commitments. Query
+ * positions are drawn over the actual LDE domain.
+ *
  * ARGUMENTS:
- * - See verify.circom
+ * - max_tree_depth: log2 of the largest LDE domain size
+ * - See verify.circom for the others
  *
  * INPUTS:
  * - constraint_commitment: merkle root commit for the constraints.
+ * - fri_commitment_mask: binary array, 1 for the commitments of the actual FRI
+     layers and remainder
  * - fri_commitments: merkle root commits for every layer of FRI.
  * - ood_constraint_evaluations: Constraint polynomials evaluated out of domain
  * - ood_trace_frame: Out Of domain trace frame.
  * - pub_coin_seed: serialized public inputs and context.
  * - pow_nonce: Proof of work nonce
+ * - query_bit_mask: binary array, 1 for the log2(lde_domain_size) lower bits
  * - trace_commitment: merkle root commit for the trace.
  *
  * OUTPUTS:
@@ -39,24 +49,25 @@ include "utils.circom";
 template PublicCoin(
     ce_blowup_factor,
     grinding_factor,
-    lde_blowup_factor,
+    max_tree_depth,
     num_assertions,
     num_draws,
     num_fri_layers,
     num_pub_coin_seed,
     num_queries,
     num_transition_constraints,
-    trace_length,
     trace_width
 ) {
     var num_seeds = 6 + num_fri_layers + 1;
 
     signal input constraint_commitment;
+    signal input fri_commitment_mask[num_fri_layers + 1];
     signal input fri_commitments[num_fri_layers + 1];
     signal input ood_constraint_evaluations[trace_width];
     signal input ood_trace_frame[2][trace_width];
     signal input pow_nonce;
     signal input pub_coin_seed[num_pub_coin_seed];
+    signal input query_bit_mask[max_tree_depth];
     signal input trace_commitment;
 
     signal output boundary_coeffs[num_assertions][2];
@@ -68,13 +79,14 @@ template PublicCoin(
     signal output transition_coeffs[num_transition_constraints][2];
     signal output z;
 
+    signal fri_seeds[num_fri_layers + 2];
+    signal query_bits[num_draws][max_tree_depth];
     signal query_draws[num_draws];
 
     component constraint_coin;
-    component bits2num[num_draws];
     component deep_coin[3 * trace_width + ce_blowup_factor + 2];
     component fri_coin[num_fri_layers + 1];
-    component init = Poseidon(num_pub_coin_seed);
+    component init = HashElements(num_pub_coin_seed);
     component num2bits[num_draws];
     component query_coin[num_draws];
     component remove_duplicates;
@@ -99,7 +111,7 @@ template PublicCoin(
     // drawing transition and constraint coefficients for OOD consistency check
     for (var i = 0; i < num_transition_constraints; i++) {
         for (var j = 0; j < 2; j++){
-            trace_coin[i][j] = Poseidon(2);
+            trace_coin[i][j] = HashElements(2);
             trace_coin[i][j].in[0] <== reseed[k].out;
             trace_coin[i][j].in[1] <== 2 * i + j + 1;
             transition_coeffs[i][j] <== trace_coin[i][j].out;
@@ -108,7 +120,7 @@ template PublicCoin(
 
     for (var i = 0; i < num_assertions; i++) {
         for (var j = 0; j < 2; j++){
-            trace_coin[i + num_transition_constraints][j] = Poseidon(2);
+            trace_coin[i + num_transition_constraints][j] = HashElements(2);
             trace_coin[i + num_transition_constraints][j].in[0] <== reseed[k].out;
             trace_coin[i + num_transition_constraints][j].in[1] <== 2 * (i + num_transition_constraints) + j + 1;
             boundary_coeffs[i][j] <== trace_coin[i + num_transition_constraints][j].out;
@@ -124,7 +136,7 @@ template PublicCoin(
     reseed[k].in[0] <== constraint_commitment;
 
     // OOD point for evaluations
-    constraint_coin = Poseidon(2);
+    constraint_coin = HashElements(2);
     constraint_coin.in[0] <== reseed[k].out;
     constraint_coin.in[1] <== 1;
     z <== constraint_coin.out;
@@ -159,21 +171,21 @@ template PublicCoin(
     // drawing all coefficient needed for the DEEP composition polynomial
     for (var i = 0; i < trace_width; i++){
         for (var j = 0; j < 3; j++){
-        deep_coin[3 * i + j] = Poseidon(2);
+        deep_coin[3 * i + j] = HashElements(2);
         deep_coin[3 * i + j].in[0] <== reseed[k].out;
         deep_coin[3 * i + j].in[1] <== 3 * i + j + 1;
         deep_trace_coefficients[i][j] <== deep_coin[3 * i + j].out;
         }
     }
     for (var i = 0; i < trace_width; i++){
-        deep_coin[i + 3 * trace_width] = Poseidon(2);
+        deep_coin[i + 3 * trace_width] = HashElements(2);
         deep_coin[i + 3 * trace_width].in[0] <== reseed[k].out;
         deep_coin[i + 3 * trace_width].in[1] <== i + 3 * trace_width + 1;
         deep_constraint_coefficients[i] <== deep_coin[i + 3 * trace_width].out ;
     }
 
     for (var i = 0; i < 2; i++){
-        deep_coin[i + 3 * trace_width + ce_blowup_factor] = Poseidon(2);
+        deep_coin[i + 3 * trace_width + ce_blowup_factor] = HashElements(2);
         deep_coin[i + 3 * trace_width + ce_blowup_factor].in[0] <== reseed[k].out;
         deep_coin[i + 3 * trace_width + ce_blowup_factor].in[1] <== i + 3 * trace_width + ce_blowup_factor + 1;
         degree_adjustment_coefficients[i] <== deep_coin[i + 3 * trace_width + ce_blowup_factor].out ;
@@ -182,14 +194,18 @@ template PublicCoin(
 
     // 5 - DRAW FRI ALPHAS + RESEED WITH FRI COMMITMENTS
 
+    // the seed is only updated with the commitments of the actual FRI layers
+    // and remainder, the commitments beyond them are padding
+    fri_seeds[0] <== reseed[k].out;
     for (var i = 0; i < num_fri_layers + 1; i++) {
         // reseeding with FRI commitments
         k += 1;
         reseed[k] = Reseed(1);
-        reseed[k].prev_seed <== reseed[k-1].out;
+        reseed[k].prev_seed <== fri_seeds[i];
         reseed[k].in[0] <== fri_commitments[i];
+        fri_seeds[i + 1] <== fri_seeds[i] + fri_commitment_mask[i] * (reseed[k].out - fri_seeds[i]);
 
-        fri_coin[i] = Poseidon(2);
+        fri_coin[i] = HashElements(2);
         fri_coin[i].in[0] <== reseed[k].out;
         fri_coin[i].in[1] <== 1;
         layer_alphas[i] <== fri_coin[i].out;
@@ -200,7 +216,7 @@ template PublicCoin(
 
     k += 1;
     reseed[k] = Reseed(1);
-    reseed[k].prev_seed <== reseed[k-1].out;
+    reseed[k].prev_seed <== fri_seeds[num_fri_layers + 1];
     reseed[k].in[0] <== pow_nonce;
 
     // check proof of work
@@ -216,25 +232,21 @@ template PublicCoin(
     // winterfell protocol has to be modified to match
     remove_duplicates = RemoveDuplicates(num_draws,num_queries);
 
-    // compute the size of the query elements in bits
-    var bit_mask = trace_length * lde_blowup_factor;
-    var mask_size = 0;
-    while(bit_mask != 1) {
-        bit_mask \= 2;
-        mask_size += 1;
-    }
-
+    // the query elements are the lower bits of the draws, given by the mask
     for (var i = 0; i < num_draws; i++) {
-        query_coin[i] = Poseidon(2);
+        query_coin[i] = HashElements(2);
         query_coin[i].in[0] <== reseed[k].out;
         query_coin[i].in[1] <== i + 1;
         num2bits[i] = Num2Bits(255);
         num2bits[i].in <== query_coin[i].out;
-        bits2num[i] = Bits2Num(mask_size);
-        for (var j = 0; j < mask_size; j++){
-            bits2num[i].in[j] <== num2bits[i].out[j];
+
+        var lc = 0;
+        for (var j = 0; j < max_tree_depth; j++) {
+            query_bits[i][j] <== num2bits[i].out[j] * query_bit_mask[j];
+            lc += query_bits[i][j] * 2 ** j;
         }
-        remove_duplicates.in[i] <== bits2num[i].out;
+        query_draws[i] <== lc;
+        remove_duplicates.in[i] <== query_draws[i];
     }
 
     for (var i = 0; i < num_queries; i++){
@@ -251,14 +263,14 @@ template Reseed(input_len) {
     signal input prev_seed;
     signal output out;
 
-    component hash = Poseidon(2);
+    component hash = HashMerge(2);
     component hash_data;
     hash.in[0] <== prev_seed;
 
     if (input_len == 1) {
         hash.in[1] <== in[0];
     } else {
-        hash_data = Poseidon(input_len);
+        hash_data = HashElements(input_len);
         for(var i = 0; i < input_len; i++) {
             hash_data.in[i] <== in[i];
         }
@@ -91,6 +91,103 @@ template Pow_signal(n) {
 }
 
 
+/**
+ * Repeated squaring, where the number of squarings is given by a one-hot
+ * selector signal.
+ *
+ * ARGUMENTS:
+ * - n: number of possible numbers of squarings
+ *
+ * INPUTS:
+ * - in: x
+ * - selector[n]: binary array with a single 1, at index k
+ *
+ * OUTPUTS:
+ * - out: x**(2**k)
+ */
+template SelectSquare(n) {
+    signal input in;
+    signal input selector[n];
+
+    signal output out;
+
+    signal squares[n];
+    signal terms[n];
+
+    squares[0] <== in;
+    for (var i = 1; i < n; i++) {
+        squares[i] <== squares[i - 1] * squares[i - 1];
+    }
+
+    var lc = 0;
+    for (var i = 0; i < n; i++) {
+        terms[i] <== squares[i] * selector[i];
+        lc += terms[i];
+    }
+
+    out <== lc;
+}
+
+
+/**
+ * One-hot encoding of the log2 of the trace length, among the trace lengths
+ * supported by the verifier.
+ *
+ * The supported trace lengths are the powers of 2 from
+ * 2**min_log_trace_length to 2**max_log_trace_length whose log2 differs from
+ * min_log_trace_length by a multiple of step. With a single supported trace
+ * length, the log2 of the trace length is checked against it and the
+ * selector is constant.
+ *
+ * ARGUMENTS:
+ * - min_log_trace_length: log2 of the smallest supported trace length
+ * - max_log_trace_length: log2 of the largest supported trace length
+ * - step: difference between the log2 of consecutive supported trace lengths
+ *
+ * INPUTS:
+ * - log_trace_length: log2 of a supported trace length
+ *
+ * OUTPUTS:
+ * - selector[max_log_trace_length - min_log_trace_length + 1]: binary array
+ *   with a single 1, at index log_trace_length - min_log_trace_length
+ * - trace_length: 2**log_trace_length
+ */
+template TraceLengthSelector(min_log_trace_length, max_log_trace_length, step) {
+    var num_lengths = max_log_trace_length - min_log_trace_length + 1;
+
+    assert((num_lengths - 1) % step == 0);
+
+    signal input log_trace_length;
+    signal output selector[num_lengths];
+    signal output trace_length;
+
+    component eqs[num_lengths];
+
+    var selector_sum = 0;
+    var trace_length_lc = 0;
+    for (var j = 0; j < num_lengths; j++) {
+        if (num_lengths == 1) {
+            log_trace_length === min_log_trace_length;
+            selector[j] <== 1;
+        } else if (j % step == 0) {
+            eqs[j] = IsEqual();
+            eqs[j].in[0] <== log_trace_length;
+            eqs[j].in[1] <== min_log_trace_length + j;
+            selector[j] <== eqs[j].out;
+        } else {
+            selector[j] <== 0;
+        }
+
+        selector_sum += selector[j];
+        trace_length_lc += selector[j] * 2 ** (min_log_trace_length + j);
+    }
+
+    // exactly one of the supported trace lengths must be selected
+    selector_sum === 1;
+    trace_length <== trace_length_lc;
+}
+
+
 /**
  * Number of bits of an field element.
  */
@@ -104,6 +201,20 @@ function numbits(n) {
 }
 
 
+/**
+ * Number of FRI layers of a domain, folded until it is no larger than the
+ * maximum remainder size.
+ */
+function fri_num_layers(log_domain_size, log_folding_factor, log_max_remainder_size) {
+    var num_layers = 0;
+    while (log_domain_size > log_max_remainder_size) {
+        log_domain_size -= log_folding_factor;
+        num_layers += 1;
+    }
+    return num_layers;
+}
+
+
 /**
  * Convert a field element into binary representation.
  *
@@ -147,6 +258,63 @@ template Bits2Num(n) {
 }
 
 
+/**
+ * One-hot encoding of an integer.
+ *
+ * ARGUMENTS:
+ * - n: number of values, the input must be smaller than n
+ *
+ * INPUTS: in
+ * OUTPUTS: out[n]: binary array with a single 1, at index in
+ */
+template OneHot(n) {
+    signal input in;
+    signal output out[n];
+    var lc = 0;
+
+    for (var i = 0; i < n; i++) {
+        out[i] <-- in == i ? 1 : 0;
+        out[i] * (in - i) === 0;
+        lc += out[i];
+    }
+
+    lc === 1;
+}
+
+
+/**
+ * Keep the lower bits of a field element, given by a mask signal.
+ *
+ * ARGUMENTS:
+ * - n: number of bits of the input
+ *
+ * INPUTS:
+ * - in
+ * - mask[n]: binary array, 1 for the bits to keep
+ *
+ * OUTPUTS:
+ * - out: in, with the bits outside of the mask cleared
+ */
+template MaskBits(n) {
+    signal input in;
+    signal input mask[n];
+    signal output out;
+
+    signal bits[n];
+
+    component n2b = Num2Bits(n);
+    n2b.in <== in;
+
+    var lc = 0;
+    for (var i = 0; i < n; i++) {
+        bits[i] <== n2b.out[i] * mask[i];
+        lc += bits[i] * 2 ** i;
+    }
+
+    out <== lc;
+}
+
+
 /**
  * Add to a given array element, using a signal as index.
  */
@@ -314,6 +482,23 @@ template MultiplierN(N) {
     out <== inter[N - 2];
 }
 
+/**
+ * Logical OR of binary signals.
+ */
+template OrN(N) {
+    signal input in[N];
+    signal output out;
+
+    // none[i]: none of the first i + 1 inputs is 1
+    signal none[N];
+
+    none[0] <== 1 - in[0];
+    for (var i = 1; i < N; i++) {
+        none[i] <== none[i - 1] * (1 - in[i]);
+    }
+    out <== 1 - none[N - 1];
+}
+
 template IsZero() {
     signal input in;
     signal output out;
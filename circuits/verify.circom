@@ -8,15 +8,41 @@ include "public_coin.circom";
 /**
  * A circom verifier for STARKs.
  *
+ * The log2 of the trace length is an input of the circuit, between
+ * min_log_trace_length and max_log_trace_length, stepping by
+ * log2(merkle_arity). The domain generators, the depths of the Merkle trees,
+ * the number of FRI layers and the size of the FRI remainder are selected
+ * accordingly. The arrays of the proof are sized for the largest trace length,
+ * and padded with zeroes. A circuit for a single trace length is the special
+ * case min_log_trace_length == max_log_trace_length, for which the selection
+ * is constant.
+ *
+ * The OOD trace frame is checked by the OodFrameCheck template of the
+ * ood_frame/ file included by the main file. In zero-knowledge mode, the
+ * transition constraints are evaluated over the OOD trace frame in the
+ * circuit, which keeps it private along with the OOD frame constraint
+ * evaluations: the public inputs of the computation are then the only public
+ * signals, and the circuit verifies proofs of a single trace length.
+ *
  * ARGUMENTS:
+ * - addicity: log2 of the order of the largest power-of-2 subgroup of the field
  * - ce_blowup_factor: constraint evaluation domain blowup factor
  * - domain_offset: domain generator (7 for BLS12-381)
  * - folding_factor: FRI folding factor
+ * - fri_max_remainder_size: maximum size of the FRI remainder
+ * - fri_num_partitions: number of partitions of the FRI layers, whose Merkle
+     trees have their leaves ordered by partition
+ * - fri_remainder_coefficients: 1 if the FRI remainder is sent as the coefficients
+     of its polynomial, 0 if it is sent as its evaluations
+ * - grinding_factor: number of leading zeros of the proof of work
  * - lde_blowup_factor: Low Degree Extention blowup factor
+ * - max_log_trace_length: log2 of the largest supported trace length
+ * - merkle_arity: number of children of each node of the Merkle trees (2, 4 or 8)
+ * - min_log_trace_length: log2 of the smallest supported trace length
  * - num_assertions: number of assertions that will be turned into boundary constraints.
  * - num_draws: number of draws needed in order to have less than a 2**-128 probability
-     to not get enough distinct elements for your queries
- * - num_fri_layers: number of fri folds
+     to not get enough distinct elements for your queries, over the smallest
+     LDE domain
  * - num_pub_coin_seed: length of the serialized public inputs and context needed
      to initialize the public coin
  * - num_public_inputs: number of public inputs. Public inputs usually contain the
@@ -24,21 +50,34 @@ include "public_coin.circom";
  * - num_queries: number of decommitments for trace states and and constraint evaluations
      to be used in DEEP polynomial composition
  * - num_transition_constraints: number of transitions constraints defined in the AIR.
- * - trace_length: number of steps in the proven calculation
+ * - num_transition_exemptions: number of steps at the end of the trace on which
+     transition constraints do not apply.
  * - trace_width: number of registers need to prove the calculations
- * - tree_depth: trace and commitments tree depth log2(lde_domain_size)
+ *
+ * The trace and constraint commitment trees have depth
+ * log2(lde_domain_size) / log2(merkle_arity). The nodes of the
+ * merkle_top_depth(num_queries, depth, merkle_arity) top levels of the
+ * smallest depth are given once, and the authentication paths hold the
+ * merkle_arity - 1 siblings of each level below (see MerkleBatchVerify).
  *
  * INPUTS:
  * - constraint_commitment: root of the constraint merkle tree.
  * - constraint_evaluations: constraint polynomials evaluations
  * - constraint_query_proofs: merkle authentication paths to check consistency between
-     the commitment and the queries at pseudo-random position
- * - fri_commitments: the root of the evaluations merkle tree for each FRI layer
+     the commitment and the queries at pseudo-random position, below the top levels
+ * - constraint_top_nodes: nodes of the top levels of the constraint merkle tree
+ * - fri_commitments: the root of the evaluations merkle tree for each FRI layer,
+     followed by the remainder commitment and zero padding
  * - fri_layer_proofs: authentication paths of the aforementionned merkle tree at the
-     query_positions for each FRI layer
+     query_positions for each FRI layer, below the top levels and padded with zeroes,
+     at the indexes of their leaves in the partitioned tree
  * - fri_layer_queries: folded DEEP polynomial evaluations at the folded query positions
      for each FRI layer
- * - fri_remainder: complete evaluation of the FRI remainder over the LDE domain
+ * - fri_layer_top_nodes: nodes of the top levels of the merkle tree of each FRI
+     layer, padded with zeroes
+ * - fri_remainder: complete evaluation of the FRI remainder over its domain,
+     or the coefficients of the remainder polynomial, padded with zeroes
+ * - log_trace_length: log2 of the number of steps in the proven calculation
  * - ood_constraint_evaluations: constraint out of domain evaluations to be
      checked during the OOD consistency check
  * - ood_trace_frame: out of domain frame to evaluate constraints to check
@@ -49,37 +88,63 @@ include "public_coin.circom";
  * - trace_commitment: root of the trace merkle tree
  * - trace_evaluations: trace polynomial evaluations at the query positions
  * - trace_query_proofs: authentication paths of the aforementionned merkle tree at
-     the query positions
+     the query positions, below the top levels
+ * - trace_top_nodes: nodes of the top levels of the trace merkle tree
  */
 template Verify(
     addicity,
     ce_blowup_factor,
     domain_offset,
     folding_factor,
-    fri_tree_depths,
+    fri_max_remainder_size,
+    fri_num_partitions,
+    fri_remainder_coefficients,
     grinding_factor,
     lde_blowup_factor,
+    max_log_trace_length,
+    merkle_arity,
+    min_log_trace_length,
     num_assertions,
     num_draws,
-    num_fri_layers,
     num_pub_coin_seed,
     num_public_inputs,
     num_queries,
     num_transition_constraints,
-    trace_length,
-    trace_width,
-    tree_depth
+    num_transition_exemptions,
+    trace_width
 ) {
-    var remainder_size = (trace_length * lde_blowup_factor) \ (folding_factor ** num_fri_layers);
+    var log_blowup = numbits(lde_blowup_factor) - 1;
+    var log_folding_factor = numbits(folding_factor) - 1;
+    var log_max_remainder_size = numbits(fri_max_remainder_size) - 1;
+    var log_merkle_arity = numbits(merkle_arity) - 1;
+    var max_tree_depth = max_log_trace_length + log_blowup;
+    var min_merkle_depth = (min_log_trace_length + log_blowup) \ log_merkle_arity;
+    var max_merkle_depth = max_tree_depth \ log_merkle_arity;
+    var merkle_top_levels = merkle_top_depth(num_queries, min_merkle_depth, merkle_arity);
+    var merkle_path_length = (max_merkle_depth - merkle_top_levels) * (merkle_arity - 1);
+    var num_merkle_top_nodes = merkle_num_top_nodes(merkle_top_levels, merkle_arity);
+    var num_fri_layers = fri_num_layers(max_tree_depth, log_folding_factor, log_max_remainder_size);
+    var remainder_length = fri_max_remainder_length(
+        folding_factor,
+        fri_max_remainder_size,
+        fri_remainder_coefficients,
+        lde_blowup_factor,
+        max_log_trace_length,
+        merkle_arity,
+        min_log_trace_length
+    );
 
     signal input addicity_root;
     signal input constraint_commitment;
     signal input constraint_evaluations[num_queries][trace_width];
-    signal input constraint_query_proofs[num_queries][tree_depth];
+    signal input constraint_query_proofs[num_queries][merkle_path_length];
+    signal input constraint_top_nodes[num_merkle_top_nodes];
     signal input fri_commitments[num_fri_layers + 1];
-    signal input fri_layer_proofs[num_fri_layers][num_queries][tree_depth];
+    signal input fri_layer_proofs[num_fri_layers][num_queries][merkle_path_length];
     signal input fri_layer_queries[num_fri_layers][num_queries * folding_factor];
-    signal input fri_remainder[remainder_size];
+    signal input fri_layer_top_nodes[num_fri_layers][num_merkle_top_nodes];
+    signal input fri_remainder[remainder_length];
+    signal input log_trace_length;
     signal input ood_constraint_evaluations[trace_width];
     signal input ood_frame_constraint_evaluation[trace_width];
     signal input ood_trace_frame[2][trace_width];
@@ -88,7 +153,8 @@ template Verify(
     signal input pow_nonce;
     signal input trace_commitment;
     signal input trace_evaluations[num_queries][trace_width];
-    signal input trace_query_proofs[num_queries][tree_depth];
+    signal input trace_query_proofs[num_queries][merkle_path_length];
+    signal input trace_top_nodes[num_merkle_top_nodes];
 
     signal constraint_div[num_queries][trace_width];
     signal constraint_evalxcoeff[num_queries][trace_width];
@@ -96,60 +162,114 @@ template Verify(
     signal deep_deg_adjustment[num_queries];
     signal deep_evaluations[num_queries];
     signal deep_temp[num_queries][trace_width];
+    signal fri_commitment_mask[num_fri_layers + 1];
     signal g_lde;
     signal g_trace;
+    signal query_bit_mask[max_tree_depth];
     signal trace_deep_composition[num_queries][trace_width][2];
     signal trace_div[num_queries][trace_width][2];
+    signal tree_depth_selector[max_merkle_depth - min_merkle_depth + 1];
     signal x_coordinates[num_queries];
-    signal x_pow[trace_length * lde_blowup_factor];
 
     component addicity_pow[3];
     component constraintCommitmentVerifier;
     component fri;
+    component g_lde_square;
+    component g_trace_square;
+    component length;
     component ood;
+    component ood_frame;
     component pub_coin;
-    component multi_sel;
     component traceCommitmentVerifier;
-    component x_pow_domain_offset;
+    component x_pows[num_queries];
     component z_m;
 
 
+    // SELECT THE TRACE LENGTH
+    // ===========================================================================
+
+    assert(min_log_trace_length <= max_log_trace_length);
+    assert(max_tree_depth <= addicity);
+
+    // length.selector[j] = 1 if log_trace_length == min_log_trace_length + j,
+    // the supported lengths keeping the LDE domain size a power of merkle_arity
+    length = TraceLengthSelector(min_log_trace_length, max_log_trace_length, log_merkle_arity);
+    length.log_trace_length <== log_trace_length;
+    var num_lengths = max_log_trace_length - min_log_trace_length + 1;
+
+    // the LDE domain has 2**(log_trace_length + log_blowup) elements, the
+    // trace and constraint Merkle trees have log2(merkle_arity) times less levels
+    for (var d = 0; d < max_tree_depth; d++) {
+        var bit_mask = 0;
+        for (var j = 0; j < num_lengths; j += log_merkle_arity) {
+            if (d < min_log_trace_length + j + log_blowup) {
+                bit_mask += length.selector[j];
+            }
+        }
+        query_bit_mask[d] <== bit_mask;
+    }
+    for (var d = 0; d <= max_merkle_depth - min_merkle_depth; d++) {
+        tree_depth_selector[d] <== length.selector[d * log_merkle_arity];
+    }
+
+    // the public coin is reseeded with the commitments of the actual FRI
+    // layers, and with the remainder commitment which follows them
+    for (var i = 0; i < num_fri_layers + 1; i++) {
+        var commitment_mask = 0;
+        for (var j = 0; j < num_lengths; j += log_merkle_arity) {
+            if (i <= fri_num_layers(min_log_trace_length + j + log_blowup, log_folding_factor, log_max_remainder_size)) {
+                commitment_mask += length.selector[j];
+            }
+        }
+        fri_commitment_mask[i] <== commitment_mask;
+    }
+
+
     // CALCULATE TRACE DOMAIN AND LDE DOMAIN GENERATORS
+    // ===========================================================================
+
+    // the generators of the domains of the selected length are the generators
+    // of the domains of the maximum length, squared once per halving
     addicity_pow[0] = Pow(2 ** addicity);
     addicity_pow[0].in <== addicity_root;
     addicity_pow[0].out === 1;
 
-    var log2_trace_length = numbits(trace_length) - 1;
-    assert(log2_trace_length <= addicity);
-    addicity_pow[1] = Pow(2 ** (addicity - log2_trace_length));
+    addicity_pow[1] = Pow(2 ** (addicity - max_log_trace_length));
     addicity_pow[1].in <== addicity_root;
-    g_trace <== addicity_pow[1].out;
 
-    var log2_lde_domain_size = numbits(trace_length * lde_blowup_factor) - 1;
-    assert(log2_lde_domain_size <= addicity);
-    addicity_pow[2] = Pow(2 ** (addicity - log2_lde_domain_size));
+    addicity_pow[2] = Pow(2 ** (addicity - max_tree_depth));
     addicity_pow[2].in <== addicity_root;
-    g_lde <== addicity_pow[2].out;
+
+    g_trace_square = SelectSquare(num_lengths);
+    g_trace_square.in <== addicity_pow[1].out;
+    g_lde_square = SelectSquare(num_lengths);
+    g_lde_square.in <== addicity_pow[2].out;
+    for (var j = 0; j < num_lengths; j++) {
+        g_trace_square.selector[num_lengths - 1 - j] <== length.selector[j];
+        g_lde_square.selector[num_lengths - 1 - j] <== length.selector[j];
+    }
+    g_trace <== g_trace_square.out;
+    g_lde <== g_lde_square.out;
 
 
     // PUBLIC COIN INITIALIZATION
     pub_coin = PublicCoin(
         ce_blowup_factor,
         grinding_factor,
-        lde_blowup_factor,
+        max_tree_depth,
         num_assertions,
         num_draws,
         num_fri_layers,
         num_pub_coin_seed,
         num_queries,
         num_transition_constraints,
-        trace_length,
         trace_width
     );
 
     pub_coin.constraint_commitment <== constraint_commitment;
 
     for (var i = 0; i < num_fri_layers + 1; i++) {
+        pub_coin.fri_commitment_mask[i] <== fri_commitment_mask[i];
         pub_coin.fri_commitments[i] <== fri_commitments[i];
     }
 
@@ -168,6 +288,10 @@ template Verify(
         pub_coin.pub_coin_seed[i] <== pub_coin_seed[i];
     }
 
+    for (var d = 0; d < max_tree_depth; d++) {
+        pub_coin.query_bit_mask[d] <== query_bit_mask[d];
+    }
+
     pub_coin.trace_commitment <== trace_commitment;
 
 
@@ -177,13 +301,19 @@ template Verify(
     // Build random coefficients for the composition polynomial constraint coeffiscients
     ood = OodConsistencyCheck(
         ce_blowup_factor,
+        max_log_trace_length,
+        min_log_trace_length,
         num_assertions,
         num_public_inputs,
-        trace_length,
+        num_transition_exemptions,
         trace_width
     );
 
     ood.g_trace <== g_trace;
+    ood.trace_length <== length.trace_length;
+    for (var j = 0; j < num_lengths; j++) {
+        ood.length_selector[j] <== length.selector[j];
+    }
 
     for (var i = 0; i < num_transition_constraints; i++) {
         for (var j = 0; j < 2; j++) {
@@ -215,33 +345,54 @@ template Verify(
         ood.frame[1][i] <== ood_trace_frame[1][i];
     }
 
+    // in zero-knowledge mode, the OOD frame constraint evaluations are private
+    // and checked against the OOD trace frame here (see ood_frame/)
+    ood_frame = OodFrameCheck(trace_width);
+    for (var i = 0; i < trace_width; i++) {
+        ood_frame.frame[0][i] <== ood_trace_frame[0][i];
+        ood_frame.frame[1][i] <== ood_trace_frame[1][i];
+        ood_frame.ood_frame_constraint_evaluation[i] <== ood_frame_constraint_evaluation[i];
+    }
+
 
     // VERIFY TRACE AND CONSTRAINT COMMITMENTS
     // ===========================================================================
 
-    traceCommitmentVerifier = MerkleOpeningsVerify(num_queries, tree_depth, trace_width);
+    traceCommitmentVerifier = MerkleBatchVerify(num_queries, min_merkle_depth, max_merkle_depth, trace_width, merkle_arity);
+    for (var d = 0; d <= max_merkle_depth - min_merkle_depth; d++) {
+        traceCommitmentVerifier.depth_selector[d] <== tree_depth_selector[d];
+    }
     traceCommitmentVerifier.root <== trace_commitment;
     for (var i = 0; i < num_queries; i++) {
         traceCommitmentVerifier.indexes[i] <== pub_coin.query_positions[i];
         for (var j = 0; j < trace_width; j++) {
             traceCommitmentVerifier.leaves[i][j] <== trace_evaluations[i][j];
         }
-        for (var j = 0; j < tree_depth; j++) {
+        for (var j = 0; j < merkle_path_length; j++) {
             traceCommitmentVerifier.openings[i][j] <== trace_query_proofs[i][j];
         }
     }
+    for (var i = 0; i < num_merkle_top_nodes; i++) {
+        traceCommitmentVerifier.top_nodes[i] <== trace_top_nodes[i];
+    }
 
-    constraintCommitmentVerifier = MerkleOpeningsVerify(num_queries, tree_depth, trace_width);
+    constraintCommitmentVerifier = MerkleBatchVerify(num_queries, min_merkle_depth, max_merkle_depth, trace_width, merkle_arity);
+    for (var d = 0; d <= max_merkle_depth - min_merkle_depth; d++) {
+        constraintCommitmentVerifier.depth_selector[d] <== tree_depth_selector[d];
+    }
     constraintCommitmentVerifier.root <== constraint_commitment;
     for (var i = 0; i < num_queries; i++) {
         constraintCommitmentVerifier.indexes[i] <== pub_coin.query_positions[i];
         for (var j = 0; j < trace_width; j++) {
             constraintCommitmentVerifier.leaves[i][j] <== constraint_evaluations[i][j];
         }
-        for (var j = 0; j < tree_depth; j++) {
+        for (var j = 0; j < merkle_path_length; j++) {
             constraintCommitmentVerifier.openings[i][j] <== constraint_query_proofs[i][j];
         }
     }
+    for (var i = 0; i < num_merkle_top_nodes; i++) {
+        constraintCommitmentVerifier.top_nodes[i] <== constraint_top_nodes[i];
+    }
 
 
     // COMPUTE DEEP POLYNOMIAL EVALUATIONS at the query positions
@@ -250,27 +401,22 @@ template Verify(
     z_m = Pow(ce_blowup_factor);
     z_m.in <== pub_coin.z;
 
-    multi_sel = MultiSelector(trace_length * lde_blowup_factor, num_queries);
-
-    x_pow[0] <== 1;
-    multi_sel.in[0] <== 1;
-
-    for (var i = 1; i < trace_length * lde_blowup_factor; i++){
-        x_pow[i] <== x_pow[i-1] * g_lde;
-        multi_sel.in[i] <== x_pow[i] * domain_offset;
-    }
-
-    for(var i = 0; i < num_queries; i ++) {
-        multi_sel.indexes[i] <== pub_coin.query_positions[i];
+    // x coordinates of the query positions: domain_offset * g_lde^position,
+    // computed by square-and-multiply over the bits of each position
+    for (var i = 0; i < num_queries; i++) {
+        x_pows[i] = Pow_signal(max_tree_depth);
+        x_pows[i].in <== g_lde;
+        x_pows[i].exp <== pub_coin.query_positions[i];
+        x_coordinates[i] <== x_pows[i].out * domain_offset;
     }
 
     for (var i = 0; i < num_queries; i++) {
         for (var j = 0; j < trace_width; j++) {
             // DEEP trace composition
-            trace_div[i][j][0] <-- (trace_evaluations[i][j] - ood_trace_frame[0][j]) / (multi_sel.out[i] - pub_coin.z);
-            trace_div[i][j][0] * (multi_sel.out[i] - pub_coin.z) === trace_evaluations[i][j] - ood_trace_frame[0][j];
+            trace_div[i][j][0] <-- (trace_evaluations[i][j] - ood_trace_frame[0][j]) / (x_coordinates[i] - pub_coin.z);
+            trace_div[i][j][0] * (x_coordinates[i] - pub_coin.z) === trace_evaluations[i][j] - ood_trace_frame[0][j];
 
-            deep_temp[i][j] <== multi_sel.out[i] - pub_coin.z * g_trace;
+            deep_temp[i][j] <== x_coordinates[i] - pub_coin.z * g_trace;
             trace_div[i][j][1] <-- (trace_evaluations[i][j] - ood_trace_frame[1][j]) / deep_temp[i][j];
             trace_div[i][j][1] * deep_temp[i][j] === trace_evaluations[i][j] - ood_trace_frame[1][j];
 
@@ -280,14 +426,14 @@ template Verify(
             if (j == 0) {
                 trace_deep_composition[i][j][1] <== trace_deep_composition[i][j][0]+ pub_coin.deep_trace_coefficients[j][1] * trace_div[i][j][1];
 
-                constraint_div[i][j] <-- (constraint_evaluations[i][j] - ood_constraint_evaluations[j]) / (multi_sel.out[i] - z_m.out);
-                constraint_div[i][j]  * (multi_sel.out[i] - z_m.out) ===  constraint_evaluations[i][j] - ood_constraint_evaluations[j];
+                constraint_div[i][j] <-- (constraint_evaluations[i][j] - ood_constraint_evaluations[j]) / (x_coordinates[i] - z_m.out);
+                constraint_div[i][j]  * (x_coordinates[i] - z_m.out) ===  constraint_evaluations[i][j] - ood_constraint_evaluations[j];
                 constraint_evalxcoeff[i][j] <== constraint_div[i][j] * pub_coin.deep_constraint_coefficients[j];
             } else {
                 trace_deep_composition[i][j][1] <== trace_deep_composition[i][j-1][1] + trace_deep_composition[i][j][0]+ pub_coin.deep_trace_coefficients[j][1] * trace_div[i][j][1];
 
-                constraint_div[i][j] <-- (constraint_evaluations[i][j] - ood_constraint_evaluations[j]) / (multi_sel.out[i] - z_m.out);
-                (constraint_div[i][j])  * (multi_sel.out[i] - z_m.out) ===  constraint_evaluations[i][j] - ood_constraint_evaluations[j];
+                constraint_div[i][j] <-- (constraint_evaluations[i][j] - ood_constraint_evaluations[j]) / (x_coordinates[i] - z_m.out);
+                (constraint_div[i][j])  * (x_coordinates[i] - z_m.out) ===  constraint_evaluations[i][j] - ood_constraint_evaluations[j];
                 constraint_evalxcoeff[i][j] <== constraint_evalxcoeff[i][j-1] + constraint_div[i][j] * pub_coin.deep_constraint_coefficients[j];
             }
         }
@@ -295,7 +441,7 @@ template Verify(
         deep_composition[i] <== trace_deep_composition[i][trace_width -1][1] + constraint_evalxcoeff[i][trace_width -1];
 
         // final composition
-        deep_deg_adjustment[i] <== pub_coin.degree_adjustment_coefficients[0] + multi_sel.out[i] * pub_coin.degree_adjustment_coefficients[1];
+        deep_deg_adjustment[i] <== pub_coin.degree_adjustment_coefficients[0] + x_coordinates[i] * pub_coin.degree_adjustment_coefficients[1];
         deep_evaluations[i] <== deep_composition[i] * deep_deg_adjustment[i];
     }
 
@@ -307,22 +453,27 @@ template Verify(
         addicity,
         domain_offset,
         folding_factor,
-        fri_tree_depths,
+        fri_max_remainder_size,
+        fri_num_partitions,
+        fri_remainder_coefficients,
         lde_blowup_factor,
-        num_fri_layers,
-        num_queries,
-        trace_length,
-        tree_depth
+        max_log_trace_length,
+        merkle_arity,
+        min_log_trace_length,
+        num_queries
     );
 
     fri.addicity_root <== addicity_root;
     fri.g_lde <== g_lde;
 
+    for (var j = 0; j < num_lengths; j++) {
+        fri.length_selector[j] <== length.selector[j];
+    }
     for (var i = 0; i < num_queries; i++) {
         fri.deep_evaluations[i] <== deep_evaluations[i];
         fri.query_positions[i] <== pub_coin.query_positions[i];
     }
-    for (var i = 0; i < remainder_size; i++) {
+    for (var i = 0; i < remainder_length; i++) {
         fri.fri_remainder[i] <== fri_remainder[i];
     }
     for (var i = 0; i < num_fri_layers; i++) {
@@ -333,10 +484,206 @@ template Verify(
             for (var k = 0; k < folding_factor; k++) {
                 fri.fri_layer_queries[i][j * folding_factor + k] <== fri_layer_queries[i][j * folding_factor + k];
             }
-            for (var k = 0; k < tree_depth; k++) {
+            for (var k = 0; k < merkle_path_length; k++) {
                 fri.fri_layer_proofs[i][j][k] <== fri_layer_proofs[i][j][k];
             }
         }
+        for (var j = 0; j < num_merkle_top_nodes; j++) {
+            fri.fri_layer_top_nodes[i][j] <== fri_layer_top_nodes[i][j];
+        }
     }
     fri.fri_commitments[num_fri_layers] <== fri_commitments[num_fri_layers];
 }
+
+
+/**
+ * A circom verifier for STARKs, exposing a single public signal.
+ *
+ * The OOD trace frame, the OOD frame constraint evaluations and the public
+ * inputs are hashed together into a commitment, which is the only output of
+ * the circuit. The cost of verifying the Groth16 proof therefore does not
+ * depend on the trace width.
+ *
+ * The circuit verifies proofs of a single trace length, 2**max_log_trace_length.
+ *
+ * ARGUMENTS:
+ * - See Verify, min_log_trace_length must be max_log_trace_length
+ *
+ * INPUTS:
+ * - See Verify
+ *
+ * OUTPUTS:
+ * - public_signals_commitment: Poseidon hash of ood_trace_frame[0],
+     ood_trace_frame[1], ood_frame_constraint_evaluation and public_inputs,
+     in that order
+ */
+template VerifyCommitted(
+    addicity,
+    ce_blowup_factor,
+    domain_offset,
+    folding_factor,
+    fri_max_remainder_size,
+    fri_num_partitions,
+    fri_remainder_coefficients,
+    grinding_factor,
+    lde_blowup_factor,
+    max_log_trace_length,
+    merkle_arity,
+    min_log_trace_length,
+    num_assertions,
+    num_draws,
+    num_pub_coin_seed,
+    num_public_inputs,
+    num_queries,
+    num_transition_constraints,
+    num_transition_exemptions,
+    trace_width
+) {
+    var log_blowup = numbits(lde_blowup_factor) - 1;
+    var log_folding_factor = numbits(folding_factor) - 1;
+    var log_max_remainder_size = numbits(fri_max_remainder_size) - 1;
+    var log_merkle_arity = numbits(merkle_arity) - 1;
+    var max_tree_depth = max_log_trace_length + log_blowup;
+    var min_merkle_depth = (min_log_trace_length + log_blowup) \ log_merkle_arity;
+    var max_merkle_depth = max_tree_depth \ log_merkle_arity;
+    var merkle_top_levels = merkle_top_depth(num_queries, min_merkle_depth, merkle_arity);
+    var merkle_path_length = (max_merkle_depth - merkle_top_levels) * (merkle_arity - 1);
+    var num_merkle_top_nodes = merkle_num_top_nodes(merkle_top_levels, merkle_arity);
+    var num_fri_layers = fri_num_layers(max_tree_depth, log_folding_factor, log_max_remainder_size);
+    var remainder_length = fri_max_remainder_length(
+        folding_factor,
+        fri_max_remainder_size,
+        fri_remainder_coefficients,
+        lde_blowup_factor,
+        max_log_trace_length,
+        merkle_arity,
+        min_log_trace_length
+    );
+
+    signal input addicity_root;
+    signal input constraint_commitment;
+    signal input constraint_evaluations[num_queries][trace_width];
+    signal input constraint_query_proofs[num_queries][merkle_path_length];
+    signal input constraint_top_nodes[num_merkle_top_nodes];
+    signal input fri_commitments[num_fri_layers + 1];
+    signal input fri_layer_proofs[num_fri_layers][num_queries][merkle_path_length];
+    signal input fri_layer_queries[num_fri_layers][num_queries * folding_factor];
+    signal input fri_layer_top_nodes[num_fri_layers][num_merkle_top_nodes];
+    signal input fri_remainder[remainder_length];
+    signal input log_trace_length;
+    signal input ood_constraint_evaluations[trace_width];
+    signal input ood_frame_constraint_evaluation[trace_width];
+    signal input ood_trace_frame[2][trace_width];
+    signal input pub_coin_seed[num_pub_coin_seed];
+    signal input public_inputs[num_public_inputs];
+    signal input pow_nonce;
+    signal input trace_commitment;
+    signal input trace_evaluations[num_queries][trace_width];
+    signal input trace_query_proofs[num_queries][merkle_path_length];
+    signal input trace_top_nodes[num_merkle_top_nodes];
+
+    signal output public_signals_commitment;
+
+    component commitment;
+    component verifier;
+
+
+    // VERIFY THE STARK PROOF
+    // ===========================================================================
+
+    assert(min_log_trace_length == max_log_trace_length);
+
+    verifier = Verify(
+        addicity,
+        ce_blowup_factor,
+        domain_offset,
+        folding_factor,
+        fri_max_remainder_size,
+        fri_num_partitions,
+        fri_remainder_coefficients,
+        grinding_factor,
+        lde_blowup_factor,
+        max_log_trace_length,
+        merkle_arity,
+        min_log_trace_length,
+        num_assertions,
+        num_draws,
+        num_pub_coin_seed,
+        num_public_inputs,
+        num_queries,
+        num_transition_constraints,
+        num_transition_exemptions,
+        trace_width
+    );
+
+    verifier.addicity_root <== addicity_root;
+    verifier.constraint_commitment <== constraint_commitment;
+    verifier.log_trace_length <== log_trace_length;
+    verifier.pow_nonce <== pow_nonce;
+    verifier.trace_commitment <== trace_commitment;
+
+    for (var i = 0; i < num_queries; i++) {
+        for (var j = 0; j < trace_width; j++) {
+            verifier.constraint_evaluations[i][j] <== constraint_evaluations[i][j];
+            verifier.trace_evaluations[i][j] <== trace_evaluations[i][j];
+        }
+        for (var j = 0; j < merkle_path_length; j++) {
+            verifier.constraint_query_proofs[i][j] <== constraint_query_proofs[i][j];
+            verifier.trace_query_proofs[i][j] <== trace_query_proofs[i][j];
+        }
+    }
+    for (var i = 0; i < num_merkle_top_nodes; i++) {
+        verifier.constraint_top_nodes[i] <== constraint_top_nodes[i];
+        verifier.trace_top_nodes[i] <== trace_top_nodes[i];
+    }
+
+    for (var i = 0; i < num_fri_layers + 1; i++) {
+        verifier.fri_commitments[i] <== fri_commitments[i];
+    }
+    for (var i = 0; i < num_fri_layers; i++) {
+        for (var j = 0; j < num_queries; j++) {
+            for (var k = 0; k < merkle_path_length; k++) {
+                verifier.fri_layer_proofs[i][j][k] <== fri_layer_proofs[i][j][k];
+            }
+        }
+        for (var j = 0; j < num_queries * folding_factor; j++) {
+            verifier.fri_layer_queries[i][j] <== fri_layer_queries[i][j];
+        }
+        for (var j = 0; j < num_merkle_top_nodes; j++) {
+            verifier.fri_layer_top_nodes[i][j] <== fri_layer_top_nodes[i][j];
+        }
+    }
+    for (var i = 0; i < remainder_length; i++) {
+        verifier.fri_remainder[i] <== fri_remainder[i];
+    }
+
+    for (var i = 0; i < trace_width; i++) {
+        verifier.ood_constraint_evaluations[i] <== ood_constraint_evaluations[i];
+        verifier.ood_frame_constraint_evaluation[i] <== ood_frame_constraint_evaluation[i];
+        verifier.ood_trace_frame[0][i] <== ood_trace_frame[0][i];
+        verifier.ood_trace_frame[1][i] <== ood_trace_frame[1][i];
+    }
+
+    for (var i = 0; i < num_pub_coin_seed; i++) {
+        verifier.pub_coin_seed[i] <== pub_coin_seed[i];
+    }
+    for (var i = 0; i < num_public_inputs; i++) {
+        verifier.public_inputs[i] <== public_inputs[i];
+    }
+
+
+    // COMMIT TO THE PUBLIC SIGNALS
+    // ===========================================================================
+
+    commitment = Poseidon(3 * trace_width + num_public_inputs);
+    for (var i = 0; i < trace_width; i++) {
+        commitment.in[i] <== ood_trace_frame[0][i];
+        commitment.in[trace_width + i] <== ood_trace_frame[1][i];
+        commitment.in[2 * trace_width + i] <== ood_frame_constraint_evaluation[i];
+    }
+    for (var i = 0; i < num_public_inputs; i++) {
+        commitment.in[3 * trace_width + i] <== public_inputs[i];
+    }
+
+    public_signals_commitment <== commitment.out;
+}
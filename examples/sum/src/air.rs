@@ -1,49 +1,33 @@
-use serde::{ser::SerializeTuple, Serialize};
 use winter_circom_prover::{winterfell::{
     math::{fields::f256::BaseElement, FieldElement},
-    Air, AirContext, Assertion, ByteWriter, EvaluationFrame, FieldExtension, HashFunction,
-    ProofOptions, Serializable, TraceInfo,
-}, WinterCircomProofOptions};
+    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
+}, CircomAir, WinterCircomProofOptions};
 use winter_circom_prover::WinterPublicInputs;
 
-pub(crate) const PROOF_OPTIONS: WinterCircomProofOptions<2> =
-    WinterCircomProofOptions::new(128, 2, 3, [1, 1], 32, 8, 0, 8, 128);
+pub const PROOF_OPTIONS: WinterCircomProofOptions<2> =
+    WinterCircomProofOptions::new(128, 2, 2, 3, [1, 1], 32, 8, 0, 8, 128);
 
-#[derive(Clone, Default)]
+#[derive(Clone, Default, WinterPublicInputs)]
+#[winter_circom(options = PROOF_OPTIONS)]
 pub struct PublicInputs {
     pub start: BaseElement,
     pub result: BaseElement,
 }
 
-impl WinterPublicInputs for PublicInputs {
-    const NUM_PUB_INPUTS: usize = 2;
-}
-
-impl Serialize for PublicInputs {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        let mut state = serializer.serialize_tuple(2)?;
-        state.serialize_element(&self.start)?;
-        state.serialize_element(&self.result)?;
-        state.end()
-    }
-}
-
-impl Serializable for PublicInputs {
-    fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        target.write(self.start);
-        target.write(self.result);
-    }
-}
-
 pub struct WorkAir {
     context: AirContext<BaseElement>,
     start: BaseElement,
     result: BaseElement,
 }
 
+impl CircomAir<2> for WorkAir {
+    const CIRCUIT_NAME: &'static str = "sum";
+
+    const PROOF_OPTIONS: WinterCircomProofOptions<2> = PROOF_OPTIONS;
+
+    const AIR_TEMPLATES: &'static str = include_str!("../../../circuits/air/sum.circom");
+}
+
 impl Air for WorkAir {
     type BaseField = BaseElement;
     type PublicInputs = PublicInputs;
@@ -54,7 +38,8 @@ impl Air for WorkAir {
         let num_assertions = PROOF_OPTIONS.num_assertions();
 
         WorkAir {
-            context: AirContext::new(trace_info, degrees, num_assertions, options),
+            context: AirContext::new(trace_info, degrees, num_assertions, options)
+                .set_num_transition_exemptions(PROOF_OPTIONS.num_transition_exemptions()),
             start: pub_inputs.start,
             result: pub_inputs.result,
         }
@@ -74,7 +59,8 @@ impl Air for WorkAir {
     }
 
     fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
-        let last_step = self.trace_length() - 1;
+        // the blinding rows of the zero-knowledge mode must be left unconstrained
+        let last_step = self.trace_length() - PROOF_OPTIONS.num_transition_exemptions();
         vec![
             Assertion::single(0, 0, self.start),
             Assertion::single(1, 0, self.start),
@@ -86,21 +72,3 @@ impl Air for WorkAir {
         &self.context
     }
 }
-
-impl Default for WorkAir {
-    fn default() -> Self {
-        WorkAir::new(
-            TraceInfo::new(2, 8),
-            PublicInputs::default(),
-            ProofOptions::new(
-                32,
-                8,
-                0,
-                HashFunction::Poseidon,
-                FieldExtension::None,
-                8,
-                128,
-            ),
-        )
-    }
-}
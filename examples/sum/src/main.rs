@@ -0,0 +1,65 @@
+use serde_json::Value;
+use winter_circom_prover::{
+    cli::{self, CircomProject},
+    utils::WinterCircomError,
+    winterfell::{
+        math::{fields::f256::BaseElement, FieldElement},
+        ProofOptions, TraceTable,
+    },
+};
+
+mod air;
+use air::{PublicInputs, PROOF_OPTIONS};
+
+mod prover;
+use prover::WorkProver;
+
+struct Sum;
+
+impl CircomProject<2> for Sum {
+    type Prover = WorkProver;
+
+    fn prover(options: ProofOptions) -> WorkProver {
+        WorkProver::new(options)
+    }
+
+    fn build_trace(
+        prover: &WorkProver,
+        trace_length: usize,
+        inputs: &Value,
+    ) -> Result<TraceTable<BaseElement>, WinterCircomError> {
+        let mut trace = prover.build_trace(start(inputs)?, trace_length);
+
+        // only needed in zero-knowledge mode
+        PROOF_OPTIONS.blind_trace(&mut trace)?;
+
+        Ok(trace)
+    }
+
+    fn public_inputs(trace_length: usize, inputs: &Value) -> Result<PublicInputs, WinterCircomError> {
+        // the result is the sum of the integers from start to start + last_step,
+        // plus start, the last step preceding the blinding rows
+        let start = start(inputs)?;
+        let last_step =
+            BaseElement::from((trace_length - PROOF_OPTIONS.num_transition_exemptions()) as u64);
+        let result = start
+            + last_step * start
+            + last_step * (last_step + BaseElement::ONE) / BaseElement::from(2u64);
+
+        Ok(PublicInputs { start, result })
+    }
+}
+
+fn start(inputs: &Value) -> Result<BaseElement, WinterCircomError> {
+    inputs["start"]
+        .as_u64()
+        .map(BaseElement::from)
+        .ok_or_else(|| WinterCircomError::ParsingError {
+            file: String::from("winter-circom.toml"),
+            comment: Some(String::from("missing integer input start")),
+        })
+}
+
+fn main() -> Result<(), WinterCircomError> {
+    cli::run::<Sum, 2>()
+}
@@ -38,7 +38,7 @@ impl Prover for WorkProver {
     type Trace = TraceTable<Self::BaseField>;
 
     fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
-        let last_step = trace.length() - 1;
+        let last_step = trace.length() - PROOF_OPTIONS.num_transition_exemptions();
         PublicInputs {
             start: trace.get(0, 0),
             result: trace.get(1, last_step),
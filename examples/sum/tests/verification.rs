@@ -0,0 +1,84 @@
+//! Runs the `verify` command of the `sum` example against a corrupted
+//! verification key, which must fail instead of reporting an invalid proof.
+//!
+//! This test requires the `compile`, `setup` and `prove` commands of the `sum`
+//! example to have been run beforehand:
+//!
+//! ```bash
+//! cargo run --release -p example-sum -- -c examples/sum/winter-circom.toml compile
+//! cargo run --release -p example-sum -- -c examples/sum/winter-circom.toml setup
+//! cargo run --release -p example-sum -- -c examples/sum/winter-circom.toml prove
+//! cargo test --release -p example-sum --test verification -- --ignored --nocapture
+//! ```
+
+use std::{fs, path::Path, process::Command};
+
+const VERIFICATION_KEY: &str = "target/circom/sum/verification_key.json";
+
+#[test]
+#[ignore]
+fn corrupted_verification_key() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let key_path = root.join(VERIFICATION_KEY);
+
+    // the valid proof is accepted
+    assert!(verify(&root).0, "valid proof rejected");
+
+    // the verification key is restored even if the test fails
+    let key = KeyBackup::new(&key_path);
+    fs::write(&key_path, r#"{"protocol": "groth16", "curve": "bn128"}"#).unwrap();
+    let (success, output) = verify(&root);
+    drop(key);
+
+    assert!(!success, "proof verified with a corrupted verification key");
+    assert!(
+        output.contains("snarkjs exited with code"),
+        "SnarkJS failure not reported:\n{}",
+        output
+    );
+    assert!(
+        !output.contains("Invalid proof"),
+        "SnarkJS failure reported as an invalid proof:\n{}",
+        output
+    );
+}
+
+// HELPER FUNCTIONS
+// ===========================================================================
+
+/// Run the `verify` command of the `sum` example, and return whether it
+/// succeeded along with its output.
+fn verify(root: &Path) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_sum"))
+        .args(["-c", "examples/sum/winter-circom.toml", "verify"])
+        .current_dir(root)
+        .output()
+        .unwrap();
+    let text = [output.stdout, output.stderr].concat();
+
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&text).into_owned(),
+    )
+}
+
+/// Content of a file, written back to it when dropped.
+struct KeyBackup<'a> {
+    path: &'a Path,
+    content: Vec<u8>,
+}
+
+impl<'a> KeyBackup<'a> {
+    fn new(path: &'a Path) -> Self {
+        Self {
+            path,
+            content: fs::read(path).unwrap(),
+        }
+    }
+}
+
+impl Drop for KeyBackup<'_> {
+    fn drop(&mut self) {
+        fs::write(self.path, &self.content).unwrap();
+    }
+}
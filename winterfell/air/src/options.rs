@@ -38,6 +38,21 @@ pub enum HashFunction {
     /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
     Poseidon = 4,
 
+    /// Poseidon hash function with 256 bit output, building 4-ary Merkle trees.
+    ///
+    /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
+    Poseidon4 = 5,
+
+    /// Poseidon hash function with 256 bit output, building 8-ary Merkle trees.
+    ///
+    /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
+    Poseidon8 = 6,
+
+    /// Poseidon2 hash function with 256 bit output.
+    ///
+    /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
+    Poseidon2 = 7,
+
 
 }
 
@@ -85,6 +100,11 @@ pub enum FieldExtension {
 /// 5. Grinding factor - higher values increase proof soundness, but also may increase proof
 ///    generation time. More precisely, proof soundness is bounded by
 ///    `num_queries * log2(blowup_factor) + grinding_factor`.
+///
+/// The FRI remainder is sent as evaluations by default; it can instead be sent as the coefficients
+/// of the remainder polynomial with [ProofOptions::with_fri_remainder_coefficients()]. Similarly,
+/// the FRI layers are committed to in a single partition by default, and in several partitions
+/// with [ProofOptions::with_fri_partitions()].
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ProofOptions {
     num_queries: u8,
@@ -94,6 +114,8 @@ pub struct ProofOptions {
     field_extension: FieldExtension,
     fri_folding_factor: u8,
     fri_max_remainder_size: u8, // stored as power of 2
+    fri_remainder_coefficients: bool,
+    fri_num_partitions: u8, // stored as power of 2
 }
 
 // PROOF OPTIONS IMPLEMENTATION
@@ -157,9 +179,44 @@ impl ProofOptions {
             field_extension,
             fri_folding_factor: fri_folding_factor as u8,
             fri_max_remainder_size: fri_max_remainder_size.trailing_zeros() as u8,
+            fri_remainder_coefficients: false,
+            fri_num_partitions: 0,
         }
     }
 
+    /// Returns these proof options with the FRI remainder sent as the coefficients of the
+    /// remainder polynomial rather than its evaluations.
+    ///
+    /// The verifier then evaluates the remainder polynomial at the queried points instead of
+    /// interpolating the remainder evaluations, and the remainder commitment is the hash of the
+    /// coefficients instead of the root of a Merkle tree.
+    pub fn with_fri_remainder_coefficients(mut self) -> ProofOptions {
+        self.fri_remainder_coefficients = true;
+        self
+    }
+
+    /// Returns these proof options with the FRI layers committed to in `num_partitions`
+    /// partitions.
+    ///
+    /// The leaves of the commitment tree of each FRI layer, except for the remainder, are then
+    /// ordered by partition, the partition of a position being its remainder modulo
+    /// `num_partitions`. Each layer must have at least `num_partitions` rows.
+    ///
+    /// # Panics
+    /// Panics if `num_partitions` is zero, greater than 1024, or is not a power of two.
+    pub fn with_fri_partitions(mut self, num_partitions: usize) -> ProofOptions {
+        assert!(
+            num_partitions.is_power_of_two(),
+            "FRI number of partitions must be a power of 2"
+        );
+        assert!(
+            num_partitions <= 1024,
+            "FRI number of partitions cannot be greater than 1024"
+        );
+        self.fri_num_partitions = num_partitions.trailing_zeros() as u8;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -220,16 +277,36 @@ impl ProofOptions {
         B::GENERATOR
     }
 
+    /// Returns true if the FRI remainder is sent as the coefficients of the remainder polynomial
+    /// rather than its evaluations.
+    pub fn fri_remainder_coefficients(&self) -> bool {
+        self.fri_remainder_coefficients
+    }
+
+    /// Returns the number of partitions in which the FRI layers are committed to.
+    pub fn fri_num_partitions(&self) -> usize {
+        2usize.pow(self.fri_num_partitions as u32)
+    }
+
     /// Returns options for FRI protocol instantiated with parameters from this proof options.
     pub fn to_fri_options(&self) -> FriOptions {
         let folding_factor = self.fri_folding_factor as usize;
         let max_remainder_size = 2usize.pow(self.fri_max_remainder_size as u32);
-        FriOptions::new(self.blowup_factor(), folding_factor, max_remainder_size)
+        let options = FriOptions::new(self.blowup_factor(), folding_factor, max_remainder_size)
+            .with_partitions(self.fri_num_partitions());
+        if self.fri_remainder_coefficients {
+            options.with_remainder_coefficients()
+        } else {
+            options
+        }
     }
 }
 
 impl Serializable for ProofOptions {
     /// Serializes `self` and writes the resulting bytes into the `target`.
+    ///
+    /// The FRI remainder format and the number of FRI partitions are written in two final bytes,
+    /// which proof options serialized by earlier versions do not have.
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         target.write_u8(self.num_queries);
         target.write_u8(self.blowup_factor);
@@ -238,6 +315,8 @@ impl Serializable for ProofOptions {
         target.write(self.field_extension);
         target.write_u8(self.fri_folding_factor);
         target.write_u8(self.fri_max_remainder_size);
+        target.write_u8(self.fri_remainder_coefficients as u8);
+        target.write_u8(self.fri_num_partitions);
     }
 }
 
@@ -247,7 +326,7 @@ impl Deserializable for ProofOptions {
     /// # Errors
     /// Returns an error of a valid proof options could not be read from the specified `source`.
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        Ok(ProofOptions::new(
+        let options = ProofOptions::new(
             source.read_u8()? as usize,
             source.read_u8()? as usize,
             source.read_u8()? as u32,
@@ -255,7 +334,24 @@ impl Deserializable for ProofOptions {
             FieldExtension::read_from(source)?,
             source.read_u8()? as usize,
             2usize.pow(source.read_u8()? as u32),
-        ))
+        );
+        let options = match source.read_u8()? {
+            0 => options,
+            1 => options.with_fri_remainder_coefficients(),
+            value => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "value {} cannot be deserialized as a FRI remainder format",
+                    value
+                )))
+            }
+        };
+        match source.read_u8()? {
+            value if value <= 10 => Ok(options.with_fri_partitions(2usize.pow(value as u32))),
+            value => Err(DeserializationError::InvalidValue(format!(
+                "value {} cannot be deserialized as a FRI number of partitions",
+                value
+            ))),
+        }
     }
 }
 
@@ -311,6 +407,9 @@ impl HashFunction {
             Self::Blake3_256 => 128,
             Self::Sha3_256 => 128,
             Self::Poseidon => 128,
+            Self::Poseidon4 => 128,
+            Self::Poseidon8 => 128,
+            Self::Poseidon2 => 128,
         }
     }
 }
@@ -330,6 +429,9 @@ impl Deserializable for HashFunction {
             2 => Ok(HashFunction::Blake3_256),
             3 => Ok(HashFunction::Sha3_256),
             4 => Ok(HashFunction::Poseidon),
+            5 => Ok(HashFunction::Poseidon4),
+            6 => Ok(HashFunction::Poseidon8),
+            7 => Ok(HashFunction::Poseidon2),
             value => Err(DeserializationError::InvalidValue(format!(
                 "value {} cannot be deserialized as HashFunction enum",
                 value
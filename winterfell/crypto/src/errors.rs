@@ -11,10 +11,12 @@ use core::fmt;
 /// Defines errors which can occur when using Merkle trees.
 #[derive(Debug, PartialEq)]
 pub enum MerkleTreeError {
-    /// Fewer than two leaves were used to construct a Merkle tree.
+    /// Fewer leaves than the arity of the tree were used to construct a Merkle tree.
     TooFewLeaves(usize, usize),
     /// Number of leaves for a Merkle tree was not a power of two.
     NumberOfLeavesNotPowerOfTwo(usize),
+    /// Number of leaves for a Merkle tree was not a power of the arity of the tree.
+    NumberOfLeavesNotPowerOfArity(usize, usize),
     /// A leaf index was greater than or equal to the number of leaves in the tree.
     LeafIndexOutOfBounds(usize, usize),
     /// A leaf index was included more than once in the list of indexes for a batch proof.
@@ -44,6 +46,13 @@ impl fmt::Display for MerkleTreeError {
                     num_leaves
                 )
             }
+            Self::NumberOfLeavesNotPowerOfArity(arity, num_leaves) => {
+                write!(
+                    f,
+                    "number of leaves must be a power of {}, but {} were provided",
+                    arity, num_leaves
+                )
+            }
             Self::LeafIndexOutOfBounds(expected, actual) => {
                 write!(
                     f,
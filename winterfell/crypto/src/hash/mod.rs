@@ -17,7 +17,10 @@ mod rescue;
 pub use rescue::{Rp62_248, Rp64_256};
 
 mod poseidon;
-pub use poseidon::Poseidon;
+pub use poseidon::{ElementDigest as PoseidonDigest, Poseidon};
+
+mod poseidon2;
+pub use poseidon2::Poseidon2;
 // HASHER TRAITS
 // ================================================================================================
 
@@ -25,12 +28,18 @@ pub use poseidon::Poseidon;
 ///
 /// This trait defined hash procedures for the following inputs:
 /// * A sequence of bytes.
-/// * Two digests - this is intended for use in Merkle tree constructions.
+/// * Two digests, or [MERKLE_ARITY](Hasher::MERKLE_ARITY) digests - this is intended for use in
+///   Merkle tree constructions.
 /// * A digests and a u64 value - this intended for use in PRNG or PoW contexts.
 pub trait Hasher {
     /// Specifies a digest type returned by this hasher.
     type Digest: Digest;
 
+    /// Number of children of the nodes of the Merkle trees built with this hasher. The nodes are
+    /// hashed with [merge_many](Hasher::merge_many), which is the same as [merge](Hasher::merge)
+    /// for binary trees.
+    const MERKLE_ARITY: usize = 2;
+
     /// Returns a hash of the provided sequence of bytes.
     fn hash(bytes: &[u8]) -> Self::Digest;
 
@@ -38,6 +47,17 @@ pub trait Hasher {
     /// Merkle trees.
     fn merge(values: &[Self::Digest; 2]) -> Self::Digest;
 
+    /// Returns a hash of [MERKLE_ARITY](Hasher::MERKLE_ARITY) digests. This method is intended
+    /// for use in construction of Merkle trees, and defaults to [merge](Hasher::merge) for
+    /// binary trees.
+    ///
+    /// # Panics
+    /// Panics if the number of digests is not supported by the hasher.
+    fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        assert_eq!(values.len(), 2, "only two digests can be merged");
+        Self::merge(&[values[0], values[1]])
+    }
+
     /// Returns hash(`seed` || `value`). This method is intended for use in PRNG and PoW contexts.
     fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest;
 }
@@ -0,0 +1,56 @@
+use super::Digest;
+use math::fields::f256::BaseElement;
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// DIGEST TRAIT IMPLEMENTATIONS
+// ================================================================================================
+
+/// Digest of the [Poseidon](super::Poseidon) hash function, made of a single f256 field element.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct ElementDigest(BaseElement);
+
+impl ElementDigest {
+    pub fn new(value: BaseElement) -> Self {
+        Self(value)
+    }
+
+    pub fn as_element(&self) -> BaseElement {
+        self.0
+    }
+}
+
+impl Digest for ElementDigest {
+    fn as_bytes(&self) -> [u8; 32] {
+        self.0.to_le_bytes()
+    }
+}
+
+impl Serializable for ElementDigest {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.0.write_into(target);
+    }
+}
+
+impl Deserializable for ElementDigest {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self(BaseElement::read_from(source)?))
+    }
+}
+
+impl From<BaseElement> for ElementDigest {
+    fn from(value: BaseElement) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ElementDigest> for BaseElement {
+    fn from(value: ElementDigest) -> Self {
+        value.0
+    }
+}
+
+impl From<ElementDigest> for [u8; 32] {
+    fn from(value: ElementDigest) -> Self {
+        value.as_bytes()
+    }
+}
@@ -1,52 +1,127 @@
 mod param;
+mod param_t10;
+mod param_t6;
 use core::marker::PhantomData;
 
 // Optimized version of poseidon with same output as the basic permutation
 mod poseidon;
+pub(crate) use poseidon::bytes_to_elements;
+use poseidon::{PARAMETERS, PARAMETERS_T10, PARAMETERS_T6};
 
 #[cfg(test)]
 mod tests;
 
-use super::{ByteDigest, ElementHasher, Hasher};
-use math::{FieldElement, StarkField};
+use super::{Digest, ElementHasher, Hasher};
+use math::{fields::f256::BaseElement, FieldElement, StarkField};
+use utils::collections::Vec;
+
+mod digest;
+pub use digest::ElementDigest;
 
 // POSEIDON WITH 256-BIT OUTPUT
 // ===============================================================================================
 /// Implementation of the [Hasher](super::Hasher) trait for POSEIDON hash function with 256-bit
 /// output.
+///
+/// The input is absorbed by a sponge of width 4 and rate 3, after being padded with a single one
+/// and zeros up to a multiple of the rate. Any number of elements can therefore be hashed, as by
+/// the `Poseidon` template of the Circom verifier.
+///
+/// The digest is a single f256 field element. The elements of the base field are absorbed as f256
+/// field elements, without being serialized, so that the f256 elements are hashed as they are. The permutation, the sponge and the 2-to-1 compression are also
+/// exposed over f256 field elements, see [apply_permutation](Poseidon::apply_permutation),
+/// [hash_field_elements](Poseidon::hash_field_elements) and
+/// [compress](Poseidon::compress).
+///
+/// `ARITY` is the number of children of the nodes of the Merkle trees built with this hasher,
+/// which can be 2, 4 or 8. The children of a node are hashed with a single permutation of width
+/// `ARITY + 2`, i.e. by a sponge of rate `ARITY + 1`, the permutations of width 6 and 10 having
+/// their own parameters. All the other hashes are the same for every arity.
+pub struct Poseidon<B: StarkField, const ARITY: usize = 2>(PhantomData<B>);
+
+/// Converts the base elements of the given elements into f256 field elements, which can be
+/// absorbed as they are by the sponges of [Poseidon] and [Poseidon2](super::Poseidon2).
+pub(crate) fn as_f256_elements<B, E>(elements: &[E]) -> Vec<BaseElement>
+where
+    B: StarkField + Into<BaseElement>,
+    E: FieldElement<BaseField = B>,
+{
+    E::as_base_elements(elements)
+        .iter()
+        .map(|&element| element.into())
+        .collect()
+}
 
-pub struct Poseidon<B: StarkField>(PhantomData<B>);
+impl<B: StarkField, const ARITY: usize> Hasher for Poseidon<B, ARITY> {
+    type Digest = ElementDigest;
 
-impl<B:StarkField> Hasher for Poseidon<B> {
-    type Digest = ByteDigest<32>;
+    const MERKLE_ARITY: usize = ARITY;
 
     fn hash(bytes: &[u8]) -> Self::Digest {
-        // return the first [RATE] elements of the state as hash result
-        poseidon::digest(bytes)
+        ElementDigest::new(poseidon::digest(bytes))
     }
 
     fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
-        let mut data = [0; 64];
-        data[..32].copy_from_slice(values[0].0.as_slice());
-        data[32..].copy_from_slice(values[1].0.as_slice());
-        poseidon::digest(&data)
+        Self::merge_many(values)
+    }
+
+    fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        let mut elements = [BaseElement::ZERO; 8];
+        for (element, value) in elements.iter_mut().zip(values) {
+            *element = value.as_element();
+        }
+
+        ElementDigest::new(match values.len() {
+            2 => poseidon::compress(&PARAMETERS, &elements[..2]),
+            4 => poseidon::compress(&PARAMETERS_T6, &elements[..4]),
+            8 => poseidon::compress(&PARAMETERS_T10, &elements[..8]),
+            n => panic!("{} digests cannot be merged with Poseidon", n),
+        })
     }
 
     fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
-        let mut data = [0; 40];
-        data[..32].copy_from_slice(&seed.0);
-        data[32..].copy_from_slice(&value.to_le_bytes());
-        poseidon::digest(&data)
+        ElementDigest::new(poseidon::sponge(&[seed.as_element(), BaseElement::from(value)]))
     }
 }
 
-impl<B: StarkField> ElementHasher for Poseidon<B> {
+impl<B, const ARITY: usize> ElementHasher for Poseidon<B, ARITY>
+where
+    B: StarkField + Into<BaseElement>,
+{
     type BaseField = B;
 
     fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
-        assert!(B::IS_CANONICAL);
+        ElementDigest::new(poseidon::sponge(&as_f256_elements(elements)))
+    }
+}
+
+impl<B: StarkField, const ARITY: usize> Poseidon<B, ARITY> {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The number of field elements in the state.
+    pub const STATE_WIDTH: usize = param::T;
+
+    /// The number of field elements absorbed by the sponge before every permutation.
+    pub const RATE: usize = param::RATE;
+
+    // POSEIDON PERMUTATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies the Poseidon permutation to the provided state.
+    pub fn apply_permutation(state: &mut [BaseElement; param::T]) {
+        poseidon::permutation(&PARAMETERS, state);
+    }
+
+    /// Returns the first element of the state after absorbing the provided elements, padded
+    /// with a single one and zeros up to a multiple of the rate.
+    pub fn hash_field_elements(elements: &[BaseElement]) -> BaseElement {
+        poseidon::sponge(elements)
+    }
 
-        let bytes = E::elements_as_bytes(elements);
-        poseidon::digest(bytes)
+    /// Returns the hash of two field elements, with a single permutation. This is the same as
+    /// hashing them with [hash_field_elements](Poseidon::hash_field_elements).
+    pub fn compress(values: [BaseElement; 2]) -> BaseElement {
+        poseidon::compress(&PARAMETERS, &values)
     }
 }
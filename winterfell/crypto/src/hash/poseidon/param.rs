@@ -1,23 +1,219 @@
-use math::fields::f256::{BaseElement,U256};
-pub const RATE : usize =  3 ;
+use math::fields::f256::{BaseElement, U256};
 
-pub const DIGEST_SIZE : usize =  1 ;
+pub const RATE: usize = 3;
 
-pub const ALPHA : u32 =  5 ;
+pub const ALPHA: u32 = 5;
 
-pub const T : usize =  4 ;
+pub const T: usize = 4;
 
-pub const R_F : usize =  8 ;
-pub const R_P : usize =  58 ;
-pub const  MDS : [[BaseElement;  4 ]; 4 ]  =  [[BaseElement(U256([11557540151452292560, 8580869191601757519, 16566669086158367688, 2179673490660469815])), BaseElement(U256([13031886910778927445, 12517346533326592180, 8144662933818254154, 3155541716498785429])), BaseElement(U256([14189530783040002151, 18218565360323348296, 3974404712423739249, 1351078737644213301])), BaseElement(U256([15072872047116996556, 4583778191081507759, 9100592999991931277, 1945960437328820976]))],[BaseElement(U256([10941747238552008901, 13917433085688009879, 18429125221935012787, 148573241837877961])), BaseElement(U256([14833457472706932029, 17376244793194161256, 4318903170693421826, 1907818439872168705])), BaseElement(U256([2578775578517621238, 15979909381944677817, 16963080297589112229, 397793632377573080])), BaseElement(U256([4035459788380089600, 9310937769484475175, 1920293183608216888, 2422001753557881401]))],[BaseElement(U256([12015390429502188382, 490017637854563851, 5641951190726829053, 2512159935613548232])), BaseElement(U256([17963464505268955112, 17366094587733461002, 18346437632530179535, 1779949670952991881])), BaseElement(U256([18289887257615766211, 12126550366825507558, 3407969366260969768, 131235062736737183])), BaseElement(U256([5962045621121956216, 2611576512346162556, 14475353656292887519, 2369499439322552761]))],[BaseElement(U256([10017877014241760235, 4767170841415302052, 1565852728614419264, 317543777188080027])), BaseElement(U256([17794144202408502557, 7320179953762676365, 16347680615354673526, 85859515244870373])), BaseElement(U256([18180676498265108532, 9106259687937889321, 13516252613638935252, 2958714769292372324])), BaseElement(U256([17629117353726663513, 5953206725623950188, 10725954485752126580, 2315205801693892659]))] ];
+pub const R_F: usize = 8;
 
-pub const  MP : [[BaseElement;  4 ]; 4 ]  =  [[BaseElement(U256([1, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([18139096066774338104, 18013873105891597885, 11036113443566421725, 1046201057379916159])), BaseElement(U256([10739605862608695695, 6747488092389615344, 14631347176196046954, 2502905458352159103])), BaseElement(U256([6509373079643224663, 10372676599364194260, 4403931691958292192, 755213710773610489]))],[BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([18314324405484079486, 3019334403703906280, 12919026387198286079, 3261068935569778162])), BaseElement(U256([14443605992302112408, 7951568039635577074, 2299583978554597147, 2559183394922001017])), BaseElement(U256([3094033928434627938, 14644423912550997677, 9880969448101566094, 2203895394762525707]))],[BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([17703149867319221291, 15291054497614211638, 10848929926982181787, 3031382205584990258])), BaseElement(U256([8728993177976332897, 6097667064558029827, 15382158139916380502, 3301429834354211487])), BaseElement(U256([16358731610097609529, 16569235536815563956, 6247050473604356112, 853817085748817667]))] ];
+pub const R_P: usize = 58;
 
-pub const  V_COLLECTION : [[BaseElement;  3 ]; 58 ]  =  [[BaseElement(U256([10941747238552008901, 13917433085688009879, 18429125221935012787, 148573241837877961])), BaseElement(U256([12015390429502188382, 490017637854563851, 5641951190726829053, 2512159935613548232])), BaseElement(U256([10017877014241760235, 4767170841415302052, 1565852728614419264, 317543777188080027]))],[BaseElement(U256([8729339334344483599, 10694030546169845825, 16348516748946068614, 2681661028176467985])), BaseElement(U256([14450662785594408692, 10800646240628449488, 12935795174830657408, 3165293488256186926])), BaseElement(U256([5150781828335116563, 16585375867934818453, 12034509162286190379, 1552147523614960206]))],[BaseElement(U256([10223409814197164222, 1447028383873348868, 9139786840154365923, 2197512243738162951])), BaseElement(U256([9718351001934292490, 14982285965808855617, 16649333171436933232, 1922122143887666431])), BaseElement(U256([13364384352521717341, 10704936320622715332, 7768563785421200926, 3277727507242837639]))],[BaseElement(U256([1578987815221240867, 233611318951386261, 18430004627766921623, 898074769236706524])), BaseElement(U256([4228474028839683560, 11587849508922602837, 172394069727972959, 2236246343622547238])), BaseElement(U256([1972409713200519663, 4278971720899830610, 17291155943176212016, 782695550709565328]))],[BaseElement(U256([4007213883892043409, 626133535631183268, 713847584715508496, 421048099383983564])), BaseElement(U256([12440032345727541128, 8011730838307221639, 4827500569141965459, 651985256744084390])), BaseElement(U256([17298161439635129755, 17252193006121973149, 3630743031949397967, 942943276369497072]))],[BaseElement(U256([6486925651752942405, 15307414336805181716, 3220495755543358133, 1326730895121499212])), BaseElement(U256([6430770467140184538, 18217833482459482717, 3734256847498167990, 3092116886151386719])), BaseElement(U256([1319156868974047576, 3509790608234546834, 10424333467602582877, 3199852221599447014]))],[BaseElement(U256([1287580054902630116, 15227664213757435267, 7559572599594121510, 2184335445487543914])), BaseElement(U256([11964370241698943024, 874836954947268128, 15505417972407454296, 1680604390139710734])), BaseElement(U256([3013325534563107293, 15938369554098127377, 14410060767125713406, 3480912593098533186]))],[BaseElement(U256([16817672599787892871, 7703539973760692507, 14202230216516715818, 2785026274160469861])), BaseElement(U256([11139032918995677472, 7570907885216212352, 4296676042522152107, 1602987723463893692])), BaseElement(U256([16306166695743329668, 17426480708855312374, 13939785746366927145, 1869908889418339355]))],[BaseElement(U256([2476259668962620475, 5827743639553158204, 12793287325675858682, 2454449681310269317])), BaseElement(U256([277394954050408592, 11944557141839096640, 10695900487671120415, 2566818543397544593])), BaseElement(U256([5972586320305577149, 16375522299076375922, 7972999556337711634, 1368882106793655867]))],[BaseElement(U256([8464742494533179000, 6474556975221041675, 3795365665074084413, 387534889015193674])), BaseElement(U256([3334763668609737053, 8435111132494618826, 5678486820421002492, 1903798254405511559])), BaseElement(U256([16802617979705269297, 11493776641190224208, 16705347197813823444, 3272704851610287135]))],[BaseElement(U256([17200827681856262859, 9898995979569163467, 10510175517857813633, 2484471709731113399])), BaseElement(U256([6195429109104516528, 17584104622687538179, 6860745397492099789, 2294568739266324804])), BaseElement(U256([3917949008223056292, 16000804373238466825, 6915966787820788764, 3198041490247582269]))],[BaseElement(U256([2837340507589286233, 10870881566565377922, 14379682057803990742, 1454754274894764293])), BaseElement(U256([2427518113088428917, 11122393803584554875, 12784912402815103807, 2028124327335472527])), BaseElement(U256([9718306882705293538, 13150576118962038550, 2834688354592044268, 3387080627323694837]))],[BaseElement(U256([18332607880956942721, 12827233007900004393, 5623059756714144591, 3283225612788563170])), BaseElement(U256([424130654482100797, 9124335506823002323, 12514764667558304983, 1140040668456082227])), BaseElement(U256([2911703074494327757, 7954083567061698967, 16383886261869920899, 2367693747029243517]))],[BaseElement(U256([5638403921604548396, 9182503458323631382, 17043764503649204760, 3435792955918899422])), BaseElement(U256([4573100187862574619, 6443458262577309482, 14478031497575876528, 1863342944621223879])), BaseElement(U256([4767186605541475864, 13993863042961918612, 18184093221062683635, 2140247845684615377]))],[BaseElement(U256([3104130805215161898, 10874926743688630418, 17400917842645252204, 2489025022827387610])), BaseElement(U256([13393290337547034642, 11000657555343736296, 4560978566489310662, 2677693921884768468])), BaseElement(U256([3742804283663802725, 14287733943324952506, 6330092652293767321, 874969511380892631]))],[BaseElement(U256([7174625506407746487, 1199932622993030816, 4931562522022919389, 1609881719953460394])), BaseElement(U256([10863175872792834191, 18439503868182111139, 8485226971187787104, 87323752013298322])), BaseElement(U256([3388933515836867755, 8910402622659317208, 5629145130040224147, 936324643729400594]))],[BaseElement(U256([201494718990296441, 3594376298999533997, 644642172680917042, 2154041813762973461])), BaseElement(U256([3223158225643807012, 1700506954539557466, 3788531375511684460, 2829874490701677327])), BaseElement(U256([7659749658641365242, 7647032403587038639, 4273387503655165096, 2984564705132648163]))],[BaseElement(U256([6124536572049599540, 2221478981765511011, 17243642038789625970, 3373332422747798831])), BaseElement(U256([8412215515183503859, 3920415591624602561, 17516159310549437802, 3086120770026312238])), BaseElement(U256([1650081082620045133, 10318480177213866558, 4897106854641077410, 727060548875393751]))],[BaseElement(U256([12716445055561104626, 4204966866064192688, 16492860226181044562, 555027418509092759])), BaseElement(U256([6295033505432614265, 623174753487565270, 14039426949302042445, 381154262491667055])), BaseElement(U256([3103784695827716284, 685051890072090725, 3535066838658730171, 2866071609213071785]))],[BaseElement(U256([5935978145403687933, 9693683714510409255, 17950592540266337924, 505743760795433317])), BaseElement(U256([13360841069567170569, 7503578916900545167, 9882708456126666358, 1283043126935385848])), BaseElement(U256([11514958274542661625, 12189578746444924198, 11631774721493971164, 2108079660363759153]))],[BaseElement(U256([462561113572397433, 17023100865024697784, 5772262022223961798, 3219727573305180445])), BaseElement(U256([1899834080971171807, 16987387039108528655, 17103426953060504730, 3240632056711104083])), BaseElement(U256([11867108953208814072, 7011120055889671313, 18066821740510640402, 1440901522406392617]))],[BaseElement(U256([7761772478871550411, 16633417311915955087, 16827206614501686170, 1394814101188650892])), BaseElement(U256([16484135004326498340, 1906820184544348481, 15107133411957455108, 2211291272188342897])), BaseElement(U256([4281281153160348254, 4685523114757919943, 10990378687455316593, 2139709511203147346]))],[BaseElement(U256([17772791868677614033, 11719620502310200443, 8830498881222247539, 88980321786514757])), BaseElement(U256([4489813211854135948, 12760694121914702074, 10223500569479798218, 1165960299786193869])), BaseElement(U256([1196959700913806718, 11578228083275557782, 11848079541564215621, 2759038361720921684]))],[BaseElement(U256([3777522281326483865, 1397358773840559006, 14156684266731142425, 2859906397060980910])), BaseElement(U256([6908668508714665827, 16885643961997649645, 407665259560892862, 1670020004730736770])), BaseElement(U256([5493451410960537151, 13351175936415910756, 4778690621955930511, 3247982365669876024]))],[BaseElement(U256([7128706082506255323, 11401172140223819017, 6924761617895935602, 1251212063735876549])), BaseElement(U256([5910904085129739124, 6078992542675446138, 1228115606060823828, 3384617132088827504])), BaseElement(U256([7276017777745889037, 5740498256895640170, 9196915620301300362, 3125010338029686898]))],[BaseElement(U256([13226399999332552084, 6478140461715465069, 2651272352437822511, 1460149184484809515])), BaseElement(U256([6338796547592982542, 8362151283165167408, 12921180205680372676, 147648423772104530])), BaseElement(U256([10859259368012844895, 6670840649483511671, 5586774554030893789, 1126382400143563118]))],[BaseElement(U256([10533232533271152727, 1768114307959817340, 16314445060036255515, 51574022221230787])), BaseElement(U256([1620599909331103414, 17276945695647307413, 18234413370820254698, 1302703988879914713])), BaseElement(U256([9126413136382296756, 7775975184932739104, 13878017195433980325, 901646588071672653]))],[BaseElement(U256([6179071472318079952, 2797528466512056216, 13822432032173704748, 3354107187148381163])), BaseElement(U256([12417470455291672400, 5600707999442407003, 17264693702804666531, 298096188090832861])), BaseElement(U256([2406721746027099990, 14766417453270012122, 15267821074648933197, 3087659811212057879]))],[BaseElement(U256([2334973850427300801, 17030921044839791530, 60229175111241753, 2673799919929251236])), BaseElement(U256([14105354271465440212, 3709222732670812016, 1599854264209632015, 949985003801214008])), BaseElement(U256([15123461278783927583, 17031588627932931853, 7095934933326204997, 826938506664083072]))],[BaseElement(U256([14956019744417965418, 1197692115961811561, 3927714308966464694, 2590381708672174134])), BaseElement(U256([6315946269200717500, 9217664943746830096, 8693606589964609740, 2807255033163349470])), BaseElement(U256([11288373663650877724, 17436438981834613871, 1652567545225395386, 366519151707537392]))],[BaseElement(U256([4881872682607233792, 4108895342007850536, 15769223676806331856, 1993154945933361290])), BaseElement(U256([13958759670746557732, 12149496834933887952, 4714566718354957897, 1242021501341379710])), BaseElement(U256([11710049780713090388, 10008515338464243366, 6366158669320955370, 649985593268340206]))],[BaseElement(U256([18330575717250145756, 9111282356121651500, 7861221651724430302, 1540244112462079199])), BaseElement(U256([150841268023205617, 3746737552561934606, 4011121921781064367, 2201299153331981539])), BaseElement(U256([224005162061151873, 14622392676804000858, 16334337458283418413, 2531245625339280940]))],[BaseElement(U256([1083434963400716405, 3715288962119511806, 18208411736718783339, 198015328899806600])), BaseElement(U256([15603337944976700823, 11883724371940807856, 16287728137291167951, 370730651925288587])), BaseElement(U256([7622664829432648542, 11028620477071326557, 7020577245133702221, 2071848554839232457]))],[BaseElement(U256([10414450535525582821, 11638573272352046475, 10222534531132169061, 175599936486303600])), BaseElement(U256([17006687505947196956, 11007789658318371538, 6725270814347643337, 519018717656400989])), BaseElement(U256([16000315608587429814, 2192876487312046610, 16447886706850668241, 1557404173584618068]))],[BaseElement(U256([3180873267427099395, 1305384804885108732, 1004379866378459994, 608181777381302827])), BaseElement(U256([7756730387687472417, 13496788330532541946, 7011345259067036973, 3454319165850858060])), BaseElement(U256([2426528536672392062, 15432440213756987240, 5968402068905735266, 3143363270162916348]))],[BaseElement(U256([3637264578140199880, 17088066737800268566, 13163554466191395746, 3074471974252467424])), BaseElement(U256([7693198832959154264, 11057815322304150056, 2783360976078870750, 2962735815391291813])), BaseElement(U256([11979762690658624207, 13503309405006537129, 104338238894440192, 2696539623472820808]))],[BaseElement(U256([17715136308469787971, 13005590124677102854, 629230518230452145, 2021375019911365277])), BaseElement(U256([2807111604401213474, 17218803048532000436, 14226123071681796563, 6528706148232969])), BaseElement(U256([18323098244093423965, 647442106201107500, 14201066926697482572, 2945068068092281919]))],[BaseElement(U256([8695465640838752560, 9041065413672958554, 7805962635972679959, 2723406151961206193])), BaseElement(U256([8907680396997643321, 1022758983829229468, 9661733357263473433, 3258453460018218748])), BaseElement(U256([8792158198700624368, 1896439155573536985, 4628380895670945130, 197696056154871860]))],[BaseElement(U256([5005662026090168100, 2449167437573618855, 16121883815553192297, 3070683609067785877])), BaseElement(U256([4608495297367863086, 14733759308358284424, 16272363009347829291, 889349555590129602])), BaseElement(U256([9976869981197172825, 1280613818020879568, 15434973865418499686, 3031618427148464197]))],[BaseElement(U256([8612584129148208945, 17332334900344348984, 2779066468666685803, 1609776803068113312])), BaseElement(U256([837499600823797024, 7270120641455856627, 5618822165481619561, 2346835733948605634])), BaseElement(U256([6014510675126077382, 4997256903119484504, 1200585715830639087, 3408061947834390664]))],[BaseElement(U256([12613589745162832379, 7040865230242656820, 1764186994313540750, 1076824306412944136])), BaseElement(U256([7115687600463084292, 372873819828678391, 3936653325352003639, 153181948640810386])), BaseElement(U256([2576417355511222642, 3189494082483479138, 4652688079025343423, 1631672033589107687]))],[BaseElement(U256([8129476175388283789, 10597224909624077358, 3294286180260314884, 600252484411628731])), BaseElement(U256([10822913782710209142, 16632683511407206455, 1202551450472924110, 2156884193595388778])), BaseElement(U256([1303826848750231003, 142326404452571305, 764484748056449490, 2047566094261374144]))],[BaseElement(U256([8258731233676696860, 14587883523265558338, 7190918046391670784, 340103746918589695])), BaseElement(U256([10701913256115125651, 11237180334310612288, 7127607748150703279, 675165233392684833])), BaseElement(U256([7787294519271594542, 10396444897835601049, 581842157025792515, 2488991633977430961]))],[BaseElement(U256([9239364338642503934, 5312648272937160106, 17066842770373010545, 2549235237575241015])), BaseElement(U256([14478846661236330374, 5766592602429336466, 17007644295068793295, 780102625867534658])), BaseElement(U256([17750163543695362738, 4726397295178599179, 3793256552278515786, 1630976150211367971]))],[BaseElement(U256([5378941873923217628, 1041449538583648320, 14483085512899303734, 3103691627956013951])), BaseElement(U256([124007326959757645, 9221949680722872634, 6357427235709161180, 2108421614815191794])), BaseElement(U256([11992155500887573946, 138263697606001287, 10918476179789536700, 632483956196237107]))],[BaseElement(U256([8650792350534524132, 2159717124081413206, 5680520646764199906, 843724135435860983])), BaseElement(U256([5830267779810106792, 17814513515511913517, 6187407470272143751, 1939149791222817064])), BaseElement(U256([42224122891294617, 4257508204267389246, 11621966750310666342, 2288150371563606216]))],[BaseElement(U256([18191195679931545274, 2856388434623953969, 17042334035640453664, 321853112442843881])), BaseElement(U256([1691547559973909782, 10892484385386489815, 2215641027338579146, 1891413487260881194])), BaseElement(U256([14587894527716915203, 3337544883200734966, 6892677884100210201, 697658885458116965]))],[BaseElement(U256([2333213475834876049, 11789129941488142258, 8630360983226584941, 2999847852304852702])), BaseElement(U256([13525543904616173249, 13803221882019408510, 723637224918094210, 817191357845641675])), BaseElement(U256([4350693629220334863, 3542713045989898711, 10607230158588743528, 2325420742490040816]))],[BaseElement(U256([14559615028616206057, 2263310517312117352, 13034843094766951094, 266799706851464433])), BaseElement(U256([3501233552234855445, 6432675727571204703, 1700255584010890268, 396259058703338419])), BaseElement(U256([18063153526890725383, 1011296055877453224, 15587919406127756490, 3151056501946607741]))],[BaseElement(U256([10685328238813168292, 15931298753744605966, 7835666018558873846, 3481922815247975302])), BaseElement(U256([4012229758796841166, 8690849696339941390, 2054868972369177516, 2339882958895912992])), BaseElement(U256([3454654923054819695, 3686391547017965051, 3975666303493663776, 3389102915033438511]))],[BaseElement(U256([9775567567576798933, 4050675367389817445, 9511686044118830754, 2961085592484852701])), BaseElement(U256([3045184122726370429, 3397896243573351858, 3653741533815686150, 3039012887799172315])), BaseElement(U256([3699865956648212769, 3174687988649027022, 16092868707360362965, 1018996877930105937]))],[BaseElement(U256([6074598360913797823, 16833550789831047116, 8731092853224331337, 1670481908198133843])), BaseElement(U256([16295389643252418080, 210062748221353740, 16263996434367646139, 1813887558953416752])), BaseElement(U256([1036543553178045869, 2222779552425000632, 9737976015156897620, 2031207030404165242]))],[BaseElement(U256([10029969222204456783, 7441171619092111216, 13212009717204286023, 2488320462059511073])), BaseElement(U256([12128622156091874419, 1388843243916284460, 16607236700000709106, 3423358834829229330])), BaseElement(U256([768882139142380234, 8735409689008785822, 16751031839900861939, 2809673820605065678]))],[BaseElement(U256([3788955638907415167, 13237384940347825476, 14162193263793646610, 3257840218636701833])), BaseElement(U256([6891605992943517375, 11939233485183081605, 16555407731895264960, 2877145293541917662])), BaseElement(U256([3965297071777483436, 8770692333850633308, 17058793548840106895, 564810097934656210]))],[BaseElement(U256([2621068676293783194, 14271604182636863889, 11664702205850839198, 1961172570743284708])), BaseElement(U256([13380320036542512992, 15613273644521518823, 9987715510744121993, 3005242561813064788])), BaseElement(U256([5545175446006106558, 6819110982028750692, 7203274646046923935, 2730064748002526698]))],[BaseElement(U256([51458107208466983, 5150344880246986824, 14668304169610855996, 3334348982947834889])), BaseElement(U256([13132881093120985552, 4504383398629980564, 11399944327291758312, 355470074807653489])), BaseElement(U256([14072520105214300279, 7581530906182923952, 16455007422818270551, 3141935363670096444]))],[BaseElement(U256([10140719936049325486, 3733584696998271965, 3811317022052960785, 1938830626230172347])), BaseElement(U256([11877578527787394708, 9181683063274417980, 1670183035289498136, 3203908814717044251])), BaseElement(U256([11399482754836070861, 10425122121296114460, 11298216652935750073, 452823017842297358]))],[BaseElement(U256([11499661583727414324, 5424724619846624788, 14053299783848846231, 211358669336455509])), BaseElement(U256([3499285947480816385, 15901018715488781839, 10543657834924935150, 728898128145205818])), BaseElement(U256([15379233948305102741, 5990341292864155043, 8394712500341961749, 2124276229622728624]))] ];
+pub const MDS: [[BaseElement; 4]; 4] = [
+    [BaseElement(U256([11557540151452292560, 8580869191601757519, 16566669086158367688, 2179673490660469815])), BaseElement(U256([13031886910778927445, 12517346533326592180, 8144662933818254154, 3155541716498785429])), BaseElement(U256([14189530783040002151, 18218565360323348296, 3974404712423739249, 1351078737644213301])), BaseElement(U256([15072872047116996556, 4583778191081507759, 9100592999991931277, 1945960437328820976]))],
+    [BaseElement(U256([10941747238552008901, 13917433085688009879, 18429125221935012787, 148573241837877961])), BaseElement(U256([14833457472706932029, 17376244793194161256, 4318903170693421826, 1907818439872168705])), BaseElement(U256([2578775578517621238, 15979909381944677817, 16963080297589112229, 397793632377573080])), BaseElement(U256([4035459788380089600, 9310937769484475175, 1920293183608216888, 2422001753557881401]))],
+    [BaseElement(U256([12015390429502188382, 490017637854563851, 5641951190726829053, 2512159935613548232])), BaseElement(U256([17963464505268955112, 17366094587733461002, 18346437632530179535, 1779949670952991881])), BaseElement(U256([18289887257615766211, 12126550366825507558, 3407969366260969768, 131235062736737183])), BaseElement(U256([5962045621121956216, 2611576512346162556, 14475353656292887519, 2369499439322552761]))],
+    [BaseElement(U256([10017877014241760235, 4767170841415302052, 1565852728614419264, 317543777188080027])), BaseElement(U256([17794144202408502557, 7320179953762676365, 16347680615354673526, 85859515244870373])), BaseElement(U256([18180676498265108532, 9106259687937889321, 13516252613638935252, 2958714769292372324])), BaseElement(U256([17629117353726663513, 5953206725623950188, 10725954485752126580, 2315205801693892659]))],
+];
 
-pub const  W_HAT_COLLECTION : [[BaseElement;  3 ]; 58 ]  =  [[BaseElement(U256([15529021202379022096, 14717964552548395616, 12792354688081637733, 2333337416135751763])), BaseElement(U256([562130324814097531, 11987833540188177895, 117605057803565444, 2798385838808966986])), BaseElement(U256([5687198887218666062, 9219588085303879638, 13852260212603814662, 3346300624956183516]))],[BaseElement(U256([2335304889361488291, 10739582730810598971, 5120742467251193124, 2007518727652303365])), BaseElement(U256([18186480754785816346, 3195242845168647077, 1919080175096102852, 2576216678645085839])), BaseElement(U256([7391029068772035432, 9999657359166108291, 17444986997088645880, 1573345873387832387]))],[BaseElement(U256([16809909032926776448, 10553469824149182432, 13862823736376996776, 3075979423303695484])), BaseElement(U256([5644761702174094511, 2128538628890756160, 1437697349222469914, 1934784066381379896])), BaseElement(U256([890758193286699591, 15811324928930531217, 2711222513232509629, 3059168068988270127]))],[BaseElement(U256([17116653705415004475, 3561577834483880991, 7567748308373530664, 2759142520564346157])), BaseElement(U256([12665455655191244417, 8632871715475646757, 5381548842472814198, 3185463061456444916])), BaseElement(U256([9267941685837879692, 4564738270881013535, 3170160341352348109, 3236514158915563065]))],[BaseElement(U256([10823217352100727193, 17391466782776515815, 10153642315326186450, 2629675307752113728])), BaseElement(U256([1647757371310099754, 18276994362261194246, 15942626838086540115, 2633446208899325814])), BaseElement(U256([6937403202023270774, 11481091877472797034, 13980429478671236437, 2761960317817964411]))],[BaseElement(U256([10378121346347223442, 16906485374628052967, 7231609530892151685, 722425172977594969])), BaseElement(U256([9509740831044906381, 8094803161493594083, 7625635291797372992, 1681331526891234233])), BaseElement(U256([10006076530691748601, 2227587054448464652, 9305210764083648308, 1113903881305005594]))],[BaseElement(U256([4393540828176473121, 2872086692572768128, 14670818603988089213, 1108586724917889636])), BaseElement(U256([6455758211143915059, 13032067831865464639, 5502830463330272879, 114602479550778112])), BaseElement(U256([2741477040251663866, 12623804846357020617, 12643786417733161935, 2110013587192725672]))],[BaseElement(U256([6729828930226037869, 11984756605643451930, 8280313638735447648, 1311469219270367933])), BaseElement(U256([14659110477222005171, 4237012552249214635, 502399676757102302, 1048610870145038287])), BaseElement(U256([3639724249134379029, 13786630474419075864, 4992993072737404363, 409990343712679323]))],[BaseElement(U256([8022654511082352990, 11169044569102912845, 14533568348991092368, 566701020927408962])), BaseElement(U256([14545999350277243539, 10276310456075689442, 15139745195040217603, 2695864605768903592])), BaseElement(U256([431784277661467986, 6942825542630708926, 2112642700274232843, 731642898818248421]))],[BaseElement(U256([7064570281598858497, 9376547131110619813, 9545392799251931710, 49866499752266515])), BaseElement(U256([1206898109174305036, 6620720236338121218, 3225202744288894948, 345577437491820995])), BaseElement(U256([1741359255677301348, 15250116815881296288, 7369983510869009698, 1606526934697928966]))],[BaseElement(U256([7143818965811313888, 3680202767321725919, 16858735652018658141, 3395107725053101883])), BaseElement(U256([5466858465986714438, 11400407226345296690, 4326282001079320007, 894157420401517420])), BaseElement(U256([15391895767053707971, 15160878867033081288, 13027605510698950244, 3137122663916199440]))],[BaseElement(U256([14039375620083278375, 15857330350157935730, 13708218051153451288, 1473814561434245725])), BaseElement(U256([16628566645438959291, 14231804406036534556, 14495973350241592949, 3019217447320938570])), BaseElement(U256([292044270599222459, 16868113093765217143, 3405344842614793907, 1217921875555040333]))],[BaseElement(U256([5790736186290985927, 18206537365106586609, 11640517535459369088, 2789321997618099702])), BaseElement(U256([2587908794332204439, 14514796700313989323, 15865391253700950281, 1608057521743473685])), BaseElement(U256([2907268453184592805, 14681092405726262338, 6759995822546487220, 2276017934471185668]))],[BaseElement(U256([16015404539627164280, 6841215887560609861, 3734981668683202148, 2003925617920069700])), BaseElement(U256([6222332819836315821, 12729380098533250360, 10995502009754486806, 1207616271785944356])), BaseElement(U256([14548347527693587961, 17925332818058428675, 14056474592356506316, 1920516963670864097]))],[BaseElement(U256([3172216928582687354, 6526234656839419139, 8689681207958365175, 166295834722914005])), BaseElement(U256([13559894805195289769, 6449554171599114299, 2816342112433532339, 90435622428411929])), BaseElement(U256([14782962950577701584, 9692754820473329307, 12139601204813599793, 68619008096655238]))],[BaseElement(U256([15812039581684886670, 7133188256215198568, 2931469703391819212, 2751358669607144335])), BaseElement(U256([8366017517603994716, 9336784918575208586, 13121105574468034158, 616927494310810425])), BaseElement(U256([11418218164611628863, 12197906613710155790, 14582267434381280540, 794650737984631448]))],[BaseElement(U256([11385802637634267128, 6926278242091836707, 608201347403033368, 2888168785631193063])), BaseElement(U256([1552512090161435431, 7036989654157655127, 14262056994881352946, 973473043026840073])), BaseElement(U256([7990003107685010306, 12084213483161233575, 15341962068192137825, 218254289035520832]))],[BaseElement(U256([4997157629739015028, 14805178974380408870, 2802620730186130611, 3179857057140564603])), BaseElement(U256([10405117660409948980, 3040139821239415470, 12560645606826722659, 3185785563185253641])), BaseElement(U256([12612367114016547205, 17619470025868214963, 17943015915951517978, 672048047797332601]))],[BaseElement(U256([9899465196022476599, 1611152304652373797, 6305470765435931726, 2885924565045663482])), BaseElement(U256([4069763703625750416, 5630776272194589814, 8478743924149910181, 1448834603862665525])), BaseElement(U256([17943389653773746072, 12018839444175469347, 10500144596611752490, 1305687272115242883]))],[BaseElement(U256([14540955676390248035, 863145518693061016, 13093074154958439079, 3008904890806653690])), BaseElement(U256([10912928296522826146, 14327084275581562999, 13161659899503474709, 2993336817272219903])), BaseElement(U256([14040798609925879362, 3406285445488544747, 10942778080087862605, 2045167856283009071]))],[BaseElement(U256([3667245534845116535, 10165406102873646929, 2155080797265542353, 2065529158191483318])), BaseElement(U256([14521406443138685257, 1137810469136659449, 2824023061227950655, 2290040765372610885])), BaseElement(U256([6367923100242914117, 12781308971662195193, 11782470956202085833, 884083090552009527]))],[BaseElement(U256([16241721655385791891, 14862738216616210032, 307992382190702287, 2100022195626405773])), BaseElement(U256([13763975947239465322, 9107567291727053309, 2819287609820517591, 2936615741101414798])), BaseElement(U256([8517406077027454884, 11427525641073340385, 10029186752090971480, 1035536493752739835]))],[BaseElement(U256([10549041678615700787, 17359136863697513605, 17680585498412889794, 3210718325321768596])), BaseElement(U256([1497800953316070426, 3758559845704086567, 1959707253988868517, 1507205260422862754])), BaseElement(U256([15479646577178036143, 4324056848245425345, 8068974902804933442, 2438900722844748179]))],[BaseElement(U256([17468062658374715834, 11011815945574987741, 12824479678263763333, 1095641101643349594])), BaseElement(U256([13957204042003082785, 1616490484994408440, 608261446108921533, 2409102795842348403])), BaseElement(U256([6949148314391568558, 3673610729855972918, 17792956561864143731, 3438818195008284826]))],[BaseElement(U256([5635433233825754097, 7341044307594610295, 15301826006767638181, 1821587117735532904])), BaseElement(U256([6071090917746219121, 17004661290281273221, 16043674639105694623, 1461862420663493755])), BaseElement(U256([10164541397139130975, 9478518866927832941, 6787611694250309198, 1592772957115257178]))],[BaseElement(U256([15969224283734354633, 14291573249647112320, 5190474078200831052, 1104331341040099471])), BaseElement(U256([2384144334848869608, 13777148618090742338, 5615067447986129014, 3283508830958139486])), BaseElement(U256([4891555054976555471, 2174270348295160730, 2913261549213380279, 932315739539130127]))],[BaseElement(U256([10932629492260566916, 4416265643634880586, 16660091017186881493, 1961767230010419700])), BaseElement(U256([5672938687255381913, 12036364773154780405, 18261415430359560897, 2269746015985210641])), BaseElement(U256([9098002264109654518, 9278516325394572415, 13782140090891478983, 2276402905000797419]))],[BaseElement(U256([18285274678835041740, 176104926535518095, 9444212668439053977, 859390063058134229])), BaseElement(U256([4979110871721795354, 7661409163265844935, 6131289112999419406, 2634746398815530917])), BaseElement(U256([2518442405094887848, 1084652804139487568, 5152373459347065800, 2690945517856600055]))],[BaseElement(U256([16709547037664498998, 3422212413683465918, 16623913649328524235, 3177741369194419513])), BaseElement(U256([12637710857393994018, 15137090733275619870, 8176730727941368543, 1876251650152283020])), BaseElement(U256([13041949835074947509, 16377888659072762128, 8021729957366541146, 2234478746683741086]))],[BaseElement(U256([14603597686838050785, 6675217371583490333, 1651597940222712794, 1655404756283724626])), BaseElement(U256([2622062181213652640, 17378800544549005620, 8821187884997318154, 869922185736184907])), BaseElement(U256([12811854448962668868, 565035324489427216, 3396924312715298566, 46072010344444691]))],[BaseElement(U256([1089150207478816254, 10870354059357307612, 6975634339007632587, 371225025988767458])), BaseElement(U256([3748371261931322728, 17259482024854750343, 18055955159101447431, 1995328540868616116])), BaseElement(U256([14242412134800155743, 15538300296063562193, 12796193215910772891, 3160252089986178660]))],[BaseElement(U256([2286912205890811801, 10135874160542051917, 17941605161776572192, 408057103313617914])), BaseElement(U256([12202818709869608529, 17253670102458615250, 11493376788870551887, 3146526798926680282])), BaseElement(U256([9412329280982441181, 17127334047875583532, 15556031646833596222, 66622579224637787]))],[BaseElement(U256([1583980558268572020, 13414810283093594461, 6893088541683257903, 3455862208335846898])), BaseElement(U256([7574983518946370279, 7430850755521474588, 4566300910410958407, 2602307057269358953])), BaseElement(U256([2975347522792448097, 14209780979576330045, 6322714656033183708, 3485541044520734255]))],[BaseElement(U256([15640437289106620215, 7373312234158437107, 6644141975907293600, 315326484842760130])), BaseElement(U256([2010856641167080321, 7358983062854781760, 6941844079923082035, 2742212684788401426])), BaseElement(U256([11509921517428629818, 1095496649074822291, 10934213235390766405, 2951634164415601461]))],[BaseElement(U256([861907144947913835, 2601234222370706028, 3048132881025156431, 2981729116233633067])), BaseElement(U256([11394466997179863476, 498908378798426463, 1906430301968413839, 209275509046924390])), BaseElement(U256([3468949848600195709, 5147005729865918621, 3951056435357966519, 1165002769608372074]))],[BaseElement(U256([2437409648340687325, 12253444067169590806, 3791613858865178492, 3426229187912628753])), BaseElement(U256([3204714103431770575, 17568252952490068237, 8079045047339476735, 799364911637616923])), BaseElement(U256([14484828984265377509, 17020048997646194813, 6790349725547151938, 2924647371719625124]))],[BaseElement(U256([4075060278561455703, 11475764887359120028, 15627920573725266272, 1303881189169734457])), BaseElement(U256([1569893470347573899, 122304631048962185, 5129709571895132453, 1774606118707194475])), BaseElement(U256([12058377046988224429, 11984141601769639177, 8454522103522318184, 3216745778406378532]))],[BaseElement(U256([13182994254593626062, 9842918860517515082, 8125923148720372473, 270145290592020450])), BaseElement(U256([10139909966202167066, 13072056514697553699, 175826003107660253, 1439724994359000254])), BaseElement(U256([16590544346459777191, 12261358966137063343, 12101569102621195508, 846398109511802070]))],[BaseElement(U256([13592884026812165725, 12022012843500456025, 14797463521570947884, 2792715218437667434])), BaseElement(U256([7356245263383747557, 13758919145240210000, 11229864867210672681, 1165946902098226914])), BaseElement(U256([14103972652536449038, 3897088390511505719, 8519309111147319133, 237303003062987657]))],[BaseElement(U256([8814222969417516675, 18324292604080462620, 2364080189531701658, 1691739292547151573])), BaseElement(U256([17525152212351166379, 3665199398142286255, 1580147529832376302, 1542257760526038768])), BaseElement(U256([8070473706133599199, 14172979939023123344, 17414133656135684869, 1032357995724314582]))],[BaseElement(U256([2228055192450623529, 8436727057747644910, 16421586492432747356, 772067218412362799])), BaseElement(U256([17775548787768057704, 15126398296893084862, 2640315120953306788, 3156830006039756791])), BaseElement(U256([810018810284536190, 7118107416165713503, 6594958368391683644, 2910224724814639674]))],[BaseElement(U256([17809691596665737518, 1583815180832690795, 810407931950412260, 2376609778291647916])), BaseElement(U256([7687544809520658500, 4677358970700391023, 7516041731877175407, 2497427388371257579])), BaseElement(U256([5934370933478418915, 9378888845237245062, 9943674607180525635, 773684564351766435]))],[BaseElement(U256([16460951441604818944, 7163286345539126269, 13353053370604935158, 2663207936516232887])), BaseElement(U256([16918675850015262511, 7688615787185192628, 315318932558234014, 1875265131477518954])), BaseElement(U256([17504535741146918016, 13436848812301406003, 13802945903375411375, 1957522069102193246]))],[BaseElement(U256([17129417940940067947, 4840263348030663633, 18382195907825023875, 2148522111631033029])), BaseElement(U256([15031098516347341112, 9417605861424331285, 7387778655627589597, 724379705028985475])), BaseElement(U256([16330537961764219245, 4669575254612123987, 740530155325947109, 1215374097073437297]))],[BaseElement(U256([5705863437106564444, 15012958680850064252, 10403963106912931966, 3210578006790472034])), BaseElement(U256([9175681498074927579, 81406665680739950, 3713803203368054808, 2350474804137967043])), BaseElement(U256([2091025900680259641, 3252304095483488212, 12728371182101563878, 959357626856268553]))],[BaseElement(U256([7735520294070996124, 16518069796208763844, 3137908989223431114, 1359013391323180885])), BaseElement(U256([14370306436148504087, 2310656084979278394, 16915280335561058390, 2241488929479060653])), BaseElement(U256([15613464128279448288, 4540703381704505060, 4219182009623753747, 3387688538556967216]))],[BaseElement(U256([7731877518557284188, 7859227197712735442, 4544363355137572345, 1252033325276640145])), BaseElement(U256([9854052196397996231, 16483105228099740288, 14079712757233333178, 1416304608343368592])), BaseElement(U256([15796643333700237240, 15986693368933141603, 7791786533084386568, 263395809153204335]))],[BaseElement(U256([6301254373551156776, 14896750475628240427, 14037398619156593861, 1987270873397021560])), BaseElement(U256([7282455458129496126, 15823248592364427535, 13027183556158171548, 1388168778122577737])), BaseElement(U256([7970855594498914865, 16287883500356216028, 16108958047376547227, 2464289387668546232]))],[BaseElement(U256([2654992408607244210, 12662892292146795620, 5704673122934503568, 2846393780567982023])), BaseElement(U256([8753089067694762850, 14885924086799321701, 3269126319464062123, 1252808803034750604])), BaseElement(U256([7065007681099333819, 3258016403834160724, 9159532042900605064, 1199434477687315201]))],[BaseElement(U256([16749941075620252400, 12606642165243408784, 1749598233741055233, 565785147689153123])), BaseElement(U256([3336486993482431666, 7471133204096093230, 10682169260224830031, 3417031866406624212])), BaseElement(U256([16046120210712522008, 15227802304889939216, 3806410428130273322, 803952284595167033]))],[BaseElement(U256([12990478153313514236, 6390899040569698377, 13809897282768246456, 2287965629592206512])), BaseElement(U256([10203028317002107721, 17651158674181526437, 15121055368549879670, 774478464983626101])), BaseElement(U256([2279224338853708311, 2761083207077165577, 11936425502538819558, 1267195362786820372]))],[BaseElement(U256([13898895430210011348, 8166419127744694704, 5907743557331042246, 1217391793213821417])), BaseElement(U256([6922615037470909046, 10762323285893578929, 16489932203267776934, 1438948282285040184])), BaseElement(U256([12404049180778764510, 8525087638356299467, 815664607847014879, 2421416835283163527]))],[BaseElement(U256([16309997205047350135, 13454409761885811791, 218670335291085145, 508861013199246019])), BaseElement(U256([2913067395099704390, 4734260230703447070, 11767763170871901387, 2352390316104628283])), BaseElement(U256([12757811960325287262, 2906250592386931262, 9907458151053775388, 1822854985056846051]))],[BaseElement(U256([15276834829376514305, 12079145336959813667, 14480840677915246891, 798652498498272685])), BaseElement(U256([14402177705479741734, 7809223623320270564, 10498816522004602411, 3370726818609101913])), BaseElement(U256([6598857276499647372, 4634014088271792732, 8894300149557561545, 1187450479164099448]))],[BaseElement(U256([3340690100686710097, 9818760560675848344, 7121810734771127259, 1110557253990837038])), BaseElement(U256([4559159410095673383, 10164506339966069913, 13897333691900966297, 503645058121469166])), BaseElement(U256([6064358287569769327, 13057298630819419307, 3144314480379305294, 3278494971255536150]))],[BaseElement(U256([13866302187564891343, 1003353829001492463, 12147772301927763518, 2226831082893706598])), BaseElement(U256([3446099805234998207, 11294040615007483147, 2231080252247862123, 163763549323279703])), BaseElement(U256([3526929542883464296, 15571264774125404125, 7329522667157136977, 732729799147683897]))],[BaseElement(U256([373745146275499746, 5582050733603985284, 8121481683211633620, 2766380968140574810])), BaseElement(U256([4047395980047099552, 1513195693684510573, 14378592246239157264, 2776617604019211471])), BaseElement(U256([13795082024521096417, 6839518798098655448, 12945878380099430417, 1711750316236550963]))],[BaseElement(U256([12260583404923551433, 1905334461239807654, 6538671926716756949, 3039523257926055870])), BaseElement(U256([15353005206527029828, 4272621483362207836, 12968510640362370405, 2049130487970392244])), BaseElement(U256([15395857371291867529, 6821770082368768866, 15608764650389753287, 1890232088505963202]))] ];
+pub const MP: [[BaseElement; 4]; 4] = [
+    [BaseElement(U256([1, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([18139096066774338104, 18013873105891597885, 11036113443566421725, 1046201057379916159])), BaseElement(U256([10739605862608695695, 6747488092389615344, 14631347176196046954, 2502905458352159103])), BaseElement(U256([6509373079643224663, 10372676599364194260, 4403931691958292192, 755213710773610489]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([18314324405484079486, 3019334403703906280, 12919026387198286079, 3261068935569778162])), BaseElement(U256([14443605992302112408, 7951568039635577074, 2299583978554597147, 2559183394922001017])), BaseElement(U256([3094033928434627938, 14644423912550997677, 9880969448101566094, 2203895394762525707]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([17703149867319221291, 15291054497614211638, 10848929926982181787, 3031382205584990258])), BaseElement(U256([8728993177976332897, 6097667064558029827, 15382158139916380502, 3301429834354211487])), BaseElement(U256([16358731610097609529, 16569235536815563956, 6247050473604356112, 853817085748817667]))],
+];
 
-pub const M_0_0: BaseElement =  BaseElement(U256([11557540151452292560, 8580869191601757519, 16566669086158367688, 2179673490660469815])) ;
+pub static V_COLLECTION: [[BaseElement; 3]; 58] = [
+    [BaseElement(U256([10941747238552008901, 13917433085688009879, 18429125221935012787, 148573241837877961])), BaseElement(U256([12015390429502188382, 490017637854563851, 5641951190726829053, 2512159935613548232])), BaseElement(U256([10017877014241760235, 4767170841415302052, 1565852728614419264, 317543777188080027]))],
+    [BaseElement(U256([8729339334344483599, 10694030546169845825, 16348516748946068614, 2681661028176467985])), BaseElement(U256([14450662785594408692, 10800646240628449488, 12935795174830657408, 3165293488256186926])), BaseElement(U256([5150781828335116563, 16585375867934818453, 12034509162286190379, 1552147523614960206]))],
+    [BaseElement(U256([10223409814197164222, 1447028383873348868, 9139786840154365923, 2197512243738162951])), BaseElement(U256([9718351001934292490, 14982285965808855617, 16649333171436933232, 1922122143887666431])), BaseElement(U256([13364384352521717341, 10704936320622715332, 7768563785421200926, 3277727507242837639]))],
+    [BaseElement(U256([1578987815221240867, 233611318951386261, 18430004627766921623, 898074769236706524])), BaseElement(U256([4228474028839683560, 11587849508922602837, 172394069727972959, 2236246343622547238])), BaseElement(U256([1972409713200519663, 4278971720899830610, 17291155943176212016, 782695550709565328]))],
+    [BaseElement(U256([4007213883892043409, 626133535631183268, 713847584715508496, 421048099383983564])), BaseElement(U256([12440032345727541128, 8011730838307221639, 4827500569141965459, 651985256744084390])), BaseElement(U256([17298161439635129755, 17252193006121973149, 3630743031949397967, 942943276369497072]))],
+    [BaseElement(U256([6486925651752942405, 15307414336805181716, 3220495755543358133, 1326730895121499212])), BaseElement(U256([6430770467140184538, 18217833482459482717, 3734256847498167990, 3092116886151386719])), BaseElement(U256([1319156868974047576, 3509790608234546834, 10424333467602582877, 3199852221599447014]))],
+    [BaseElement(U256([1287580054902630116, 15227664213757435267, 7559572599594121510, 2184335445487543914])), BaseElement(U256([11964370241698943024, 874836954947268128, 15505417972407454296, 1680604390139710734])), BaseElement(U256([3013325534563107293, 15938369554098127377, 14410060767125713406, 3480912593098533186]))],
+    [BaseElement(U256([16817672599787892871, 7703539973760692507, 14202230216516715818, 2785026274160469861])), BaseElement(U256([11139032918995677472, 7570907885216212352, 4296676042522152107, 1602987723463893692])), BaseElement(U256([16306166695743329668, 17426480708855312374, 13939785746366927145, 1869908889418339355]))],
+    [BaseElement(U256([2476259668962620475, 5827743639553158204, 12793287325675858682, 2454449681310269317])), BaseElement(U256([277394954050408592, 11944557141839096640, 10695900487671120415, 2566818543397544593])), BaseElement(U256([5972586320305577149, 16375522299076375922, 7972999556337711634, 1368882106793655867]))],
+    [BaseElement(U256([8464742494533179000, 6474556975221041675, 3795365665074084413, 387534889015193674])), BaseElement(U256([3334763668609737053, 8435111132494618826, 5678486820421002492, 1903798254405511559])), BaseElement(U256([16802617979705269297, 11493776641190224208, 16705347197813823444, 3272704851610287135]))],
+    [BaseElement(U256([17200827681856262859, 9898995979569163467, 10510175517857813633, 2484471709731113399])), BaseElement(U256([6195429109104516528, 17584104622687538179, 6860745397492099789, 2294568739266324804])), BaseElement(U256([3917949008223056292, 16000804373238466825, 6915966787820788764, 3198041490247582269]))],
+    [BaseElement(U256([2837340507589286233, 10870881566565377922, 14379682057803990742, 1454754274894764293])), BaseElement(U256([2427518113088428917, 11122393803584554875, 12784912402815103807, 2028124327335472527])), BaseElement(U256([9718306882705293538, 13150576118962038550, 2834688354592044268, 3387080627323694837]))],
+    [BaseElement(U256([18332607880956942721, 12827233007900004393, 5623059756714144591, 3283225612788563170])), BaseElement(U256([424130654482100797, 9124335506823002323, 12514764667558304983, 1140040668456082227])), BaseElement(U256([2911703074494327757, 7954083567061698967, 16383886261869920899, 2367693747029243517]))],
+    [BaseElement(U256([5638403921604548396, 9182503458323631382, 17043764503649204760, 3435792955918899422])), BaseElement(U256([4573100187862574619, 6443458262577309482, 14478031497575876528, 1863342944621223879])), BaseElement(U256([4767186605541475864, 13993863042961918612, 18184093221062683635, 2140247845684615377]))],
+    [BaseElement(U256([3104130805215161898, 10874926743688630418, 17400917842645252204, 2489025022827387610])), BaseElement(U256([13393290337547034642, 11000657555343736296, 4560978566489310662, 2677693921884768468])), BaseElement(U256([3742804283663802725, 14287733943324952506, 6330092652293767321, 874969511380892631]))],
+    [BaseElement(U256([7174625506407746487, 1199932622993030816, 4931562522022919389, 1609881719953460394])), BaseElement(U256([10863175872792834191, 18439503868182111139, 8485226971187787104, 87323752013298322])), BaseElement(U256([3388933515836867755, 8910402622659317208, 5629145130040224147, 936324643729400594]))],
+    [BaseElement(U256([201494718990296441, 3594376298999533997, 644642172680917042, 2154041813762973461])), BaseElement(U256([3223158225643807012, 1700506954539557466, 3788531375511684460, 2829874490701677327])), BaseElement(U256([7659749658641365242, 7647032403587038639, 4273387503655165096, 2984564705132648163]))],
+    [BaseElement(U256([6124536572049599540, 2221478981765511011, 17243642038789625970, 3373332422747798831])), BaseElement(U256([8412215515183503859, 3920415591624602561, 17516159310549437802, 3086120770026312238])), BaseElement(U256([1650081082620045133, 10318480177213866558, 4897106854641077410, 727060548875393751]))],
+    [BaseElement(U256([12716445055561104626, 4204966866064192688, 16492860226181044562, 555027418509092759])), BaseElement(U256([6295033505432614265, 623174753487565270, 14039426949302042445, 381154262491667055])), BaseElement(U256([3103784695827716284, 685051890072090725, 3535066838658730171, 2866071609213071785]))],
+    [BaseElement(U256([5935978145403687933, 9693683714510409255, 17950592540266337924, 505743760795433317])), BaseElement(U256([13360841069567170569, 7503578916900545167, 9882708456126666358, 1283043126935385848])), BaseElement(U256([11514958274542661625, 12189578746444924198, 11631774721493971164, 2108079660363759153]))],
+    [BaseElement(U256([462561113572397433, 17023100865024697784, 5772262022223961798, 3219727573305180445])), BaseElement(U256([1899834080971171807, 16987387039108528655, 17103426953060504730, 3240632056711104083])), BaseElement(U256([11867108953208814072, 7011120055889671313, 18066821740510640402, 1440901522406392617]))],
+    [BaseElement(U256([7761772478871550411, 16633417311915955087, 16827206614501686170, 1394814101188650892])), BaseElement(U256([16484135004326498340, 1906820184544348481, 15107133411957455108, 2211291272188342897])), BaseElement(U256([4281281153160348254, 4685523114757919943, 10990378687455316593, 2139709511203147346]))],
+    [BaseElement(U256([17772791868677614033, 11719620502310200443, 8830498881222247539, 88980321786514757])), BaseElement(U256([4489813211854135948, 12760694121914702074, 10223500569479798218, 1165960299786193869])), BaseElement(U256([1196959700913806718, 11578228083275557782, 11848079541564215621, 2759038361720921684]))],
+    [BaseElement(U256([3777522281326483865, 1397358773840559006, 14156684266731142425, 2859906397060980910])), BaseElement(U256([6908668508714665827, 16885643961997649645, 407665259560892862, 1670020004730736770])), BaseElement(U256([5493451410960537151, 13351175936415910756, 4778690621955930511, 3247982365669876024]))],
+    [BaseElement(U256([7128706082506255323, 11401172140223819017, 6924761617895935602, 1251212063735876549])), BaseElement(U256([5910904085129739124, 6078992542675446138, 1228115606060823828, 3384617132088827504])), BaseElement(U256([7276017777745889037, 5740498256895640170, 9196915620301300362, 3125010338029686898]))],
+    [BaseElement(U256([13226399999332552084, 6478140461715465069, 2651272352437822511, 1460149184484809515])), BaseElement(U256([6338796547592982542, 8362151283165167408, 12921180205680372676, 147648423772104530])), BaseElement(U256([10859259368012844895, 6670840649483511671, 5586774554030893789, 1126382400143563118]))],
+    [BaseElement(U256([10533232533271152727, 1768114307959817340, 16314445060036255515, 51574022221230787])), BaseElement(U256([1620599909331103414, 17276945695647307413, 18234413370820254698, 1302703988879914713])), BaseElement(U256([9126413136382296756, 7775975184932739104, 13878017195433980325, 901646588071672653]))],
+    [BaseElement(U256([6179071472318079952, 2797528466512056216, 13822432032173704748, 3354107187148381163])), BaseElement(U256([12417470455291672400, 5600707999442407003, 17264693702804666531, 298096188090832861])), BaseElement(U256([2406721746027099990, 14766417453270012122, 15267821074648933197, 3087659811212057879]))],
+    [BaseElement(U256([2334973850427300801, 17030921044839791530, 60229175111241753, 2673799919929251236])), BaseElement(U256([14105354271465440212, 3709222732670812016, 1599854264209632015, 949985003801214008])), BaseElement(U256([15123461278783927583, 17031588627932931853, 7095934933326204997, 826938506664083072]))],
+    [BaseElement(U256([14956019744417965418, 1197692115961811561, 3927714308966464694, 2590381708672174134])), BaseElement(U256([6315946269200717500, 9217664943746830096, 8693606589964609740, 2807255033163349470])), BaseElement(U256([11288373663650877724, 17436438981834613871, 1652567545225395386, 366519151707537392]))],
+    [BaseElement(U256([4881872682607233792, 4108895342007850536, 15769223676806331856, 1993154945933361290])), BaseElement(U256([13958759670746557732, 12149496834933887952, 4714566718354957897, 1242021501341379710])), BaseElement(U256([11710049780713090388, 10008515338464243366, 6366158669320955370, 649985593268340206]))],
+    [BaseElement(U256([18330575717250145756, 9111282356121651500, 7861221651724430302, 1540244112462079199])), BaseElement(U256([150841268023205617, 3746737552561934606, 4011121921781064367, 2201299153331981539])), BaseElement(U256([224005162061151873, 14622392676804000858, 16334337458283418413, 2531245625339280940]))],
+    [BaseElement(U256([1083434963400716405, 3715288962119511806, 18208411736718783339, 198015328899806600])), BaseElement(U256([15603337944976700823, 11883724371940807856, 16287728137291167951, 370730651925288587])), BaseElement(U256([7622664829432648542, 11028620477071326557, 7020577245133702221, 2071848554839232457]))],
+    [BaseElement(U256([10414450535525582821, 11638573272352046475, 10222534531132169061, 175599936486303600])), BaseElement(U256([17006687505947196956, 11007789658318371538, 6725270814347643337, 519018717656400989])), BaseElement(U256([16000315608587429814, 2192876487312046610, 16447886706850668241, 1557404173584618068]))],
+    [BaseElement(U256([3180873267427099395, 1305384804885108732, 1004379866378459994, 608181777381302827])), BaseElement(U256([7756730387687472417, 13496788330532541946, 7011345259067036973, 3454319165850858060])), BaseElement(U256([2426528536672392062, 15432440213756987240, 5968402068905735266, 3143363270162916348]))],
+    [BaseElement(U256([3637264578140199880, 17088066737800268566, 13163554466191395746, 3074471974252467424])), BaseElement(U256([7693198832959154264, 11057815322304150056, 2783360976078870750, 2962735815391291813])), BaseElement(U256([11979762690658624207, 13503309405006537129, 104338238894440192, 2696539623472820808]))],
+    [BaseElement(U256([17715136308469787971, 13005590124677102854, 629230518230452145, 2021375019911365277])), BaseElement(U256([2807111604401213474, 17218803048532000436, 14226123071681796563, 6528706148232969])), BaseElement(U256([18323098244093423965, 647442106201107500, 14201066926697482572, 2945068068092281919]))],
+    [BaseElement(U256([8695465640838752560, 9041065413672958554, 7805962635972679959, 2723406151961206193])), BaseElement(U256([8907680396997643321, 1022758983829229468, 9661733357263473433, 3258453460018218748])), BaseElement(U256([8792158198700624368, 1896439155573536985, 4628380895670945130, 197696056154871860]))],
+    [BaseElement(U256([5005662026090168100, 2449167437573618855, 16121883815553192297, 3070683609067785877])), BaseElement(U256([4608495297367863086, 14733759308358284424, 16272363009347829291, 889349555590129602])), BaseElement(U256([9976869981197172825, 1280613818020879568, 15434973865418499686, 3031618427148464197]))],
+    [BaseElement(U256([8612584129148208945, 17332334900344348984, 2779066468666685803, 1609776803068113312])), BaseElement(U256([837499600823797024, 7270120641455856627, 5618822165481619561, 2346835733948605634])), BaseElement(U256([6014510675126077382, 4997256903119484504, 1200585715830639087, 3408061947834390664]))],
+    [BaseElement(U256([12613589745162832379, 7040865230242656820, 1764186994313540750, 1076824306412944136])), BaseElement(U256([7115687600463084292, 372873819828678391, 3936653325352003639, 153181948640810386])), BaseElement(U256([2576417355511222642, 3189494082483479138, 4652688079025343423, 1631672033589107687]))],
+    [BaseElement(U256([8129476175388283789, 10597224909624077358, 3294286180260314884, 600252484411628731])), BaseElement(U256([10822913782710209142, 16632683511407206455, 1202551450472924110, 2156884193595388778])), BaseElement(U256([1303826848750231003, 142326404452571305, 764484748056449490, 2047566094261374144]))],
+    [BaseElement(U256([8258731233676696860, 14587883523265558338, 7190918046391670784, 340103746918589695])), BaseElement(U256([10701913256115125651, 11237180334310612288, 7127607748150703279, 675165233392684833])), BaseElement(U256([7787294519271594542, 10396444897835601049, 581842157025792515, 2488991633977430961]))],
+    [BaseElement(U256([9239364338642503934, 5312648272937160106, 17066842770373010545, 2549235237575241015])), BaseElement(U256([14478846661236330374, 5766592602429336466, 17007644295068793295, 780102625867534658])), BaseElement(U256([17750163543695362738, 4726397295178599179, 3793256552278515786, 1630976150211367971]))],
+    [BaseElement(U256([5378941873923217628, 1041449538583648320, 14483085512899303734, 3103691627956013951])), BaseElement(U256([124007326959757645, 9221949680722872634, 6357427235709161180, 2108421614815191794])), BaseElement(U256([11992155500887573946, 138263697606001287, 10918476179789536700, 632483956196237107]))],
+    [BaseElement(U256([8650792350534524132, 2159717124081413206, 5680520646764199906, 843724135435860983])), BaseElement(U256([5830267779810106792, 17814513515511913517, 6187407470272143751, 1939149791222817064])), BaseElement(U256([42224122891294617, 4257508204267389246, 11621966750310666342, 2288150371563606216]))],
+    [BaseElement(U256([18191195679931545274, 2856388434623953969, 17042334035640453664, 321853112442843881])), BaseElement(U256([1691547559973909782, 10892484385386489815, 2215641027338579146, 1891413487260881194])), BaseElement(U256([14587894527716915203, 3337544883200734966, 6892677884100210201, 697658885458116965]))],
+    [BaseElement(U256([2333213475834876049, 11789129941488142258, 8630360983226584941, 2999847852304852702])), BaseElement(U256([13525543904616173249, 13803221882019408510, 723637224918094210, 817191357845641675])), BaseElement(U256([4350693629220334863, 3542713045989898711, 10607230158588743528, 2325420742490040816]))],
+    [BaseElement(U256([14559615028616206057, 2263310517312117352, 13034843094766951094, 266799706851464433])), BaseElement(U256([3501233552234855445, 6432675727571204703, 1700255584010890268, 396259058703338419])), BaseElement(U256([18063153526890725383, 1011296055877453224, 15587919406127756490, 3151056501946607741]))],
+    [BaseElement(U256([10685328238813168292, 15931298753744605966, 7835666018558873846, 3481922815247975302])), BaseElement(U256([4012229758796841166, 8690849696339941390, 2054868972369177516, 2339882958895912992])), BaseElement(U256([3454654923054819695, 3686391547017965051, 3975666303493663776, 3389102915033438511]))],
+    [BaseElement(U256([9775567567576798933, 4050675367389817445, 9511686044118830754, 2961085592484852701])), BaseElement(U256([3045184122726370429, 3397896243573351858, 3653741533815686150, 3039012887799172315])), BaseElement(U256([3699865956648212769, 3174687988649027022, 16092868707360362965, 1018996877930105937]))],
+    [BaseElement(U256([6074598360913797823, 16833550789831047116, 8731092853224331337, 1670481908198133843])), BaseElement(U256([16295389643252418080, 210062748221353740, 16263996434367646139, 1813887558953416752])), BaseElement(U256([1036543553178045869, 2222779552425000632, 9737976015156897620, 2031207030404165242]))],
+    [BaseElement(U256([10029969222204456783, 7441171619092111216, 13212009717204286023, 2488320462059511073])), BaseElement(U256([12128622156091874419, 1388843243916284460, 16607236700000709106, 3423358834829229330])), BaseElement(U256([768882139142380234, 8735409689008785822, 16751031839900861939, 2809673820605065678]))],
+    [BaseElement(U256([3788955638907415167, 13237384940347825476, 14162193263793646610, 3257840218636701833])), BaseElement(U256([6891605992943517375, 11939233485183081605, 16555407731895264960, 2877145293541917662])), BaseElement(U256([3965297071777483436, 8770692333850633308, 17058793548840106895, 564810097934656210]))],
+    [BaseElement(U256([2621068676293783194, 14271604182636863889, 11664702205850839198, 1961172570743284708])), BaseElement(U256([13380320036542512992, 15613273644521518823, 9987715510744121993, 3005242561813064788])), BaseElement(U256([5545175446006106558, 6819110982028750692, 7203274646046923935, 2730064748002526698]))],
+    [BaseElement(U256([51458107208466983, 5150344880246986824, 14668304169610855996, 3334348982947834889])), BaseElement(U256([13132881093120985552, 4504383398629980564, 11399944327291758312, 355470074807653489])), BaseElement(U256([14072520105214300279, 7581530906182923952, 16455007422818270551, 3141935363670096444]))],
+    [BaseElement(U256([10140719936049325486, 3733584696998271965, 3811317022052960785, 1938830626230172347])), BaseElement(U256([11877578527787394708, 9181683063274417980, 1670183035289498136, 3203908814717044251])), BaseElement(U256([11399482754836070861, 10425122121296114460, 11298216652935750073, 452823017842297358]))],
+    [BaseElement(U256([11499661583727414324, 5424724619846624788, 14053299783848846231, 211358669336455509])), BaseElement(U256([3499285947480816385, 15901018715488781839, 10543657834924935150, 728898128145205818])), BaseElement(U256([15379233948305102741, 5990341292864155043, 8394712500341961749, 2124276229622728624]))],
+];
 
-pub const  ROUND_CONSTANTS_OPTI : [[BaseElement;  4 ]; 66 ]  =  [[BaseElement(U256([4883224509544257960, 10294250702647188098, 13812652228131053665, 1489486978621741877])), BaseElement(U256([2051771277834103960, 8259167859906635434, 12413551415661793035, 3242815666112425170])), BaseElement(U256([2749193412415434487, 16619992502796972979, 1541504423508272868, 615558647478695540])), BaseElement(U256([2202610339749210646, 14590033123816308324, 7531768188061079944, 187923596438589980]))],[BaseElement(U256([12013822207865431924, 15862433016970535069, 17565577368278771394, 2844914407947638936])), BaseElement(U256([11754076989277134933, 6433830874845809185, 9588821972479041858, 95294193623336772])), BaseElement(U256([8415604812825408154, 6652321530001040360, 2547503334005344225, 1639001997265947979])), BaseElement(U256([16537180755974727721, 7038380527437244106, 11647109298171512274, 2235699787421759317]))],[BaseElement(U256([13163666223170610282, 10360337634083438640, 15499031385062742639, 701470322818021890])), BaseElement(U256([3745984203522884803, 5208314475719647079, 17862981097046841440, 3121901627827364635])), BaseElement(U256([13308586914265739875, 491638379001090274, 72498404946618309, 1364427480385344415])), BaseElement(U256([1709206971889937041, 13446112229142835583, 15668949568290620913, 2333121298576105734]))],[BaseElement(U256([393510679579464737, 6278634506426338755, 559649849068558978, 845300170976509185])), BaseElement(U256([17561294551121804683, 11264993937789989719, 5717380516324271385, 3007737636166644684])), BaseElement(U256([17324933860346480497, 12679843683657500848, 7279914650861175078, 530138844894835832])), BaseElement(U256([3469110145402836392, 18338773566319292165, 14250214542717871332, 2683197772235531821]))],[BaseElement(U256([6814709600947422231, 7376678752930342819, 17214123703788030583, 3045359388980353657])), BaseElement(U256([14557914110675118083, 2605261118068220946, 3787209126404928782, 407241446912237829])), BaseElement(U256([10083369221647639742, 6286273438450211125, 14487566855274674241, 1695825623746905576])), BaseElement(U256([7762465633450496501, 8989366662136822422, 7546665968871694779, 501231994026955687]))],[BaseElement(U256([7597777515002386110, 18349700309135028412, 121860958294361508, 1879773462311401035])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([1941988088353647977, 12145177474232149398, 239145673865057182, 802634960672786224])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([15547457227135888361, 17732950823924805495, 15484496928216946711, 1297948444541321096])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([1105426006801186345, 3019240822044374527, 7626471878182450149, 3155216140214560636])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([16043453585680886058, 5695854634322682778, 16186215635238249876, 450601180557612685])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([12907615864588042745, 7960357036139259066, 17505240698153288847, 3444208308071048505])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([8736071949451590572, 1061099380471615288, 12763753258571464231, 1538560603961328078])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([1884181321274827012, 7911617571204839048, 10757697910277400203, 2294997667310168315])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([4851091508237092523, 14546613915056214852, 7091672929235062133, 1282451375089131820])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([10437089180209377258, 3137829410399064730, 5915755701591116694, 579053828463210280])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([14219802550658025731, 15725522730163513732, 210941975963853702, 2276449425401697845])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([2708490081887850026, 6151836861794061284, 4954917524936727759, 3155693884887778398])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([16803314500448744435, 62935615191417267, 10102563778521422897, 139481581038628647])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([4984969340761865728, 14309276984666334371, 16739631507893285333, 23861761416341088])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([10978292048325547052, 7257146090874001208, 1914235808901381555, 370537218689200213])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([12826041589879244998, 11712670086311292021, 15204894983586836465, 1495560641434922419])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([13259214121274819382, 12035500720569855382, 5888353173246404795, 2332854462665624243])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([9421485503721044640, 16890122343846931884, 11516461574670652577, 1902754037412392722])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([14532127783192759068, 296045612495653551, 15907486169026744221, 3077076569924607436])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([12747635127478396329, 6562048064175864042, 18322720707711935265, 323923734977005031])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([12015800988371723774, 13203790345788926274, 5900208782042022472, 3348227111666379659])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([16129591818616956735, 10414345911712239486, 4798351502848264766, 2500493984799798138])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([12674768166420525806, 11644469910922213978, 4430557491145767432, 1633610573129228740])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([368628563461677969, 4428063254887370522, 454627129918303976, 2048353442501661038])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([16984699233163420167, 7125141691675357663, 17392071601619236626, 1862288523074377643])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([14001711897649905146, 4668770484928592456, 12691502722233126241, 896997717726982397])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([14612997442734106648, 16098992497601580805, 16252813609486286641, 1029491301428113113])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([15764522521100543862, 5399954592783985001, 9209848613853629641, 3207767007416053627])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([15187070852270221440, 16784924434455477957, 15300729176944633068, 240016720866684278])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([5124868444805357340, 9073838599375001171, 5095511886882708464, 2472290248252709908])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([818000558074870638, 6176466798531626985, 13506680461065955550, 3138043617970155082])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([8781190472669907190, 734649405530183675, 17333405819500172599, 31556970748507867])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([8158638308930965234, 18379403528976736518, 12692507438963537289, 2412106612154007757])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([6051409152120511571, 3215238445680400365, 8506705184786637445, 1030229686743090897])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([13341453686711549575, 11373836868422960612, 11839126904120686719, 833322139716403827])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([12747166021141738706, 12901502222350420545, 11915956865823650450, 2624737066686650581])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([17214267934671142706, 5175951310374535014, 2839940837235785405, 50144675399606541])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([7589079173469001310, 16882574300502134813, 16375917326963407431, 3149167705914552092])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([4248268766674610491, 13048608341593698674, 16801429734819928922, 845744706296813273])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([16403305247851626518, 3124077287790936964, 9733198718029198009, 2858579382822250802])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([10901119729115662355, 8291738141041996506, 2245186154405108264, 737301019822996368])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([4760135447469618121, 11967937062990394268, 13981462829071192034, 1492943159693883994])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([5874021875707914741, 7568794932951556392, 9974087930297794720, 446795249709364429])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([7243380842018167423, 9104814941543161411, 17206847727191045167, 1040906577624769540])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([4342915504265501199, 2660026047903060990, 11258577696244685123, 1414353702456948865])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([11044069783555449955, 13516208511146931367, 16504346313357349553, 319731839180621450])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([6695600247343434312, 4001788682850403273, 5540849699768120149, 1204964445302196619])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([8857704457170593269, 717367507594168067, 13586342971463491556, 2834611419542004181])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([10876378652026540883, 16200253500405462477, 11305081456352806243, 2463204422383790894])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([5943690019497091266, 9706489948919359586, 2708790793239650644, 1853881456574014324])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([16165820924010075070, 17238184523182137537, 15641595534554773992, 524218291737708574])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([17109555495040932090, 3354318784808545818, 3861958023046757858, 1520358155483917672])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([18378448507449423823, 5221318546724384521, 13310656917138527012, 2507841555408063115])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([7357599549944052115, 7440947860774104798, 3346297736920341690, 1845986991487462946])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([5699668193985464192, 2027884521476292920, 8871877670376964279, 2722585339757551675])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([10251222089887561613, 3859531975990021577, 3755918687785907600, 3357663776131999420])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([12487916590719953160, 17509455141510473447, 13989567227135374646, 467863141939884717])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],[BaseElement(U256([2981024797620009844, 6313524115750594456, 8785393495077303857, 602463280705498605])), BaseElement(U256([5966458694021400340, 11633762470123441294, 10707737764556089682, 617675319052731477])), BaseElement(U256([10587930402493866158, 7920806329525676493, 1994928203899279558, 2913570554789918978])), BaseElement(U256([6374360576755502466, 13032406500736980551, 10579210934773869559, 2468562939780521694]))],[BaseElement(U256([8105738535616942374, 15110007884824388077, 6382789434778123365, 2408615374958205049])), BaseElement(U256([8508080404579498758, 920960632064729237, 16583750083276734807, 1802651493205300815])), BaseElement(U256([15651610314982899733, 16648104100991402942, 12352206934922887193, 725618168976161431])), BaseElement(U256([614864971951770609, 4703550992485099385, 17062594477845225265, 1197813552638379191]))],[BaseElement(U256([11369601821696797163, 16283204231725409737, 179446577952076441, 3389143775014780088])), BaseElement(U256([3306566605422542805, 11948616708906630041, 1881773065092033570, 2032520933609900381])), BaseElement(U256([8743044067110811247, 5420226930886079079, 1300615806708915134, 2879586967043950542])), BaseElement(U256([12253806716448529009, 1252103417529824009, 1323330768256980179, 76354858153319112]))],[BaseElement(U256([2089073626764152874, 1128172732362094121, 18340021536518497642, 2488903300362531427])), BaseElement(U256([6602873240559008112, 9559510187158228186, 16647105303778797765, 1243905194368611144])), BaseElement(U256([15375457600104963671, 5209132332669050452, 2702966373015440372, 2726558967557263893])), BaseElement(U256([16380534596269340631, 7144074723509891344, 1898173492678832940, 2904903418137332168]))] ];
+pub static W_HAT_COLLECTION: [[BaseElement; 3]; 58] = [
+    [BaseElement(U256([15529021202379022096, 14717964552548395616, 12792354688081637733, 2333337416135751763])), BaseElement(U256([562130324814097531, 11987833540188177895, 117605057803565444, 2798385838808966986])), BaseElement(U256([5687198887218666062, 9219588085303879638, 13852260212603814662, 3346300624956183516]))],
+    [BaseElement(U256([2335304889361488291, 10739582730810598971, 5120742467251193124, 2007518727652303365])), BaseElement(U256([18186480754785816346, 3195242845168647077, 1919080175096102852, 2576216678645085839])), BaseElement(U256([7391029068772035432, 9999657359166108291, 17444986997088645880, 1573345873387832387]))],
+    [BaseElement(U256([16809909032926776448, 10553469824149182432, 13862823736376996776, 3075979423303695484])), BaseElement(U256([5644761702174094511, 2128538628890756160, 1437697349222469914, 1934784066381379896])), BaseElement(U256([890758193286699591, 15811324928930531217, 2711222513232509629, 3059168068988270127]))],
+    [BaseElement(U256([17116653705415004475, 3561577834483880991, 7567748308373530664, 2759142520564346157])), BaseElement(U256([12665455655191244417, 8632871715475646757, 5381548842472814198, 3185463061456444916])), BaseElement(U256([9267941685837879692, 4564738270881013535, 3170160341352348109, 3236514158915563065]))],
+    [BaseElement(U256([10823217352100727193, 17391466782776515815, 10153642315326186450, 2629675307752113728])), BaseElement(U256([1647757371310099754, 18276994362261194246, 15942626838086540115, 2633446208899325814])), BaseElement(U256([6937403202023270774, 11481091877472797034, 13980429478671236437, 2761960317817964411]))],
+    [BaseElement(U256([10378121346347223442, 16906485374628052967, 7231609530892151685, 722425172977594969])), BaseElement(U256([9509740831044906381, 8094803161493594083, 7625635291797372992, 1681331526891234233])), BaseElement(U256([10006076530691748601, 2227587054448464652, 9305210764083648308, 1113903881305005594]))],
+    [BaseElement(U256([4393540828176473121, 2872086692572768128, 14670818603988089213, 1108586724917889636])), BaseElement(U256([6455758211143915059, 13032067831865464639, 5502830463330272879, 114602479550778112])), BaseElement(U256([2741477040251663866, 12623804846357020617, 12643786417733161935, 2110013587192725672]))],
+    [BaseElement(U256([6729828930226037869, 11984756605643451930, 8280313638735447648, 1311469219270367933])), BaseElement(U256([14659110477222005171, 4237012552249214635, 502399676757102302, 1048610870145038287])), BaseElement(U256([3639724249134379029, 13786630474419075864, 4992993072737404363, 409990343712679323]))],
+    [BaseElement(U256([8022654511082352990, 11169044569102912845, 14533568348991092368, 566701020927408962])), BaseElement(U256([14545999350277243539, 10276310456075689442, 15139745195040217603, 2695864605768903592])), BaseElement(U256([431784277661467986, 6942825542630708926, 2112642700274232843, 731642898818248421]))],
+    [BaseElement(U256([7064570281598858497, 9376547131110619813, 9545392799251931710, 49866499752266515])), BaseElement(U256([1206898109174305036, 6620720236338121218, 3225202744288894948, 345577437491820995])), BaseElement(U256([1741359255677301348, 15250116815881296288, 7369983510869009698, 1606526934697928966]))],
+    [BaseElement(U256([7143818965811313888, 3680202767321725919, 16858735652018658141, 3395107725053101883])), BaseElement(U256([5466858465986714438, 11400407226345296690, 4326282001079320007, 894157420401517420])), BaseElement(U256([15391895767053707971, 15160878867033081288, 13027605510698950244, 3137122663916199440]))],
+    [BaseElement(U256([14039375620083278375, 15857330350157935730, 13708218051153451288, 1473814561434245725])), BaseElement(U256([16628566645438959291, 14231804406036534556, 14495973350241592949, 3019217447320938570])), BaseElement(U256([292044270599222459, 16868113093765217143, 3405344842614793907, 1217921875555040333]))],
+    [BaseElement(U256([5790736186290985927, 18206537365106586609, 11640517535459369088, 2789321997618099702])), BaseElement(U256([2587908794332204439, 14514796700313989323, 15865391253700950281, 1608057521743473685])), BaseElement(U256([2907268453184592805, 14681092405726262338, 6759995822546487220, 2276017934471185668]))],
+    [BaseElement(U256([16015404539627164280, 6841215887560609861, 3734981668683202148, 2003925617920069700])), BaseElement(U256([6222332819836315821, 12729380098533250360, 10995502009754486806, 1207616271785944356])), BaseElement(U256([14548347527693587961, 17925332818058428675, 14056474592356506316, 1920516963670864097]))],
+    [BaseElement(U256([3172216928582687354, 6526234656839419139, 8689681207958365175, 166295834722914005])), BaseElement(U256([13559894805195289769, 6449554171599114299, 2816342112433532339, 90435622428411929])), BaseElement(U256([14782962950577701584, 9692754820473329307, 12139601204813599793, 68619008096655238]))],
+    [BaseElement(U256([15812039581684886670, 7133188256215198568, 2931469703391819212, 2751358669607144335])), BaseElement(U256([8366017517603994716, 9336784918575208586, 13121105574468034158, 616927494310810425])), BaseElement(U256([11418218164611628863, 12197906613710155790, 14582267434381280540, 794650737984631448]))],
+    [BaseElement(U256([11385802637634267128, 6926278242091836707, 608201347403033368, 2888168785631193063])), BaseElement(U256([1552512090161435431, 7036989654157655127, 14262056994881352946, 973473043026840073])), BaseElement(U256([7990003107685010306, 12084213483161233575, 15341962068192137825, 218254289035520832]))],
+    [BaseElement(U256([4997157629739015028, 14805178974380408870, 2802620730186130611, 3179857057140564603])), BaseElement(U256([10405117660409948980, 3040139821239415470, 12560645606826722659, 3185785563185253641])), BaseElement(U256([12612367114016547205, 17619470025868214963, 17943015915951517978, 672048047797332601]))],
+    [BaseElement(U256([9899465196022476599, 1611152304652373797, 6305470765435931726, 2885924565045663482])), BaseElement(U256([4069763703625750416, 5630776272194589814, 8478743924149910181, 1448834603862665525])), BaseElement(U256([17943389653773746072, 12018839444175469347, 10500144596611752490, 1305687272115242883]))],
+    [BaseElement(U256([14540955676390248035, 863145518693061016, 13093074154958439079, 3008904890806653690])), BaseElement(U256([10912928296522826146, 14327084275581562999, 13161659899503474709, 2993336817272219903])), BaseElement(U256([14040798609925879362, 3406285445488544747, 10942778080087862605, 2045167856283009071]))],
+    [BaseElement(U256([3667245534845116535, 10165406102873646929, 2155080797265542353, 2065529158191483318])), BaseElement(U256([14521406443138685257, 1137810469136659449, 2824023061227950655, 2290040765372610885])), BaseElement(U256([6367923100242914117, 12781308971662195193, 11782470956202085833, 884083090552009527]))],
+    [BaseElement(U256([16241721655385791891, 14862738216616210032, 307992382190702287, 2100022195626405773])), BaseElement(U256([13763975947239465322, 9107567291727053309, 2819287609820517591, 2936615741101414798])), BaseElement(U256([8517406077027454884, 11427525641073340385, 10029186752090971480, 1035536493752739835]))],
+    [BaseElement(U256([10549041678615700787, 17359136863697513605, 17680585498412889794, 3210718325321768596])), BaseElement(U256([1497800953316070426, 3758559845704086567, 1959707253988868517, 1507205260422862754])), BaseElement(U256([15479646577178036143, 4324056848245425345, 8068974902804933442, 2438900722844748179]))],
+    [BaseElement(U256([17468062658374715834, 11011815945574987741, 12824479678263763333, 1095641101643349594])), BaseElement(U256([13957204042003082785, 1616490484994408440, 608261446108921533, 2409102795842348403])), BaseElement(U256([6949148314391568558, 3673610729855972918, 17792956561864143731, 3438818195008284826]))],
+    [BaseElement(U256([5635433233825754097, 7341044307594610295, 15301826006767638181, 1821587117735532904])), BaseElement(U256([6071090917746219121, 17004661290281273221, 16043674639105694623, 1461862420663493755])), BaseElement(U256([10164541397139130975, 9478518866927832941, 6787611694250309198, 1592772957115257178]))],
+    [BaseElement(U256([15969224283734354633, 14291573249647112320, 5190474078200831052, 1104331341040099471])), BaseElement(U256([2384144334848869608, 13777148618090742338, 5615067447986129014, 3283508830958139486])), BaseElement(U256([4891555054976555471, 2174270348295160730, 2913261549213380279, 932315739539130127]))],
+    [BaseElement(U256([10932629492260566916, 4416265643634880586, 16660091017186881493, 1961767230010419700])), BaseElement(U256([5672938687255381913, 12036364773154780405, 18261415430359560897, 2269746015985210641])), BaseElement(U256([9098002264109654518, 9278516325394572415, 13782140090891478983, 2276402905000797419]))],
+    [BaseElement(U256([18285274678835041740, 176104926535518095, 9444212668439053977, 859390063058134229])), BaseElement(U256([4979110871721795354, 7661409163265844935, 6131289112999419406, 2634746398815530917])), BaseElement(U256([2518442405094887848, 1084652804139487568, 5152373459347065800, 2690945517856600055]))],
+    [BaseElement(U256([16709547037664498998, 3422212413683465918, 16623913649328524235, 3177741369194419513])), BaseElement(U256([12637710857393994018, 15137090733275619870, 8176730727941368543, 1876251650152283020])), BaseElement(U256([13041949835074947509, 16377888659072762128, 8021729957366541146, 2234478746683741086]))],
+    [BaseElement(U256([14603597686838050785, 6675217371583490333, 1651597940222712794, 1655404756283724626])), BaseElement(U256([2622062181213652640, 17378800544549005620, 8821187884997318154, 869922185736184907])), BaseElement(U256([12811854448962668868, 565035324489427216, 3396924312715298566, 46072010344444691]))],
+    [BaseElement(U256([1089150207478816254, 10870354059357307612, 6975634339007632587, 371225025988767458])), BaseElement(U256([3748371261931322728, 17259482024854750343, 18055955159101447431, 1995328540868616116])), BaseElement(U256([14242412134800155743, 15538300296063562193, 12796193215910772891, 3160252089986178660]))],
+    [BaseElement(U256([2286912205890811801, 10135874160542051917, 17941605161776572192, 408057103313617914])), BaseElement(U256([12202818709869608529, 17253670102458615250, 11493376788870551887, 3146526798926680282])), BaseElement(U256([9412329280982441181, 17127334047875583532, 15556031646833596222, 66622579224637787]))],
+    [BaseElement(U256([1583980558268572020, 13414810283093594461, 6893088541683257903, 3455862208335846898])), BaseElement(U256([7574983518946370279, 7430850755521474588, 4566300910410958407, 2602307057269358953])), BaseElement(U256([2975347522792448097, 14209780979576330045, 6322714656033183708, 3485541044520734255]))],
+    [BaseElement(U256([15640437289106620215, 7373312234158437107, 6644141975907293600, 315326484842760130])), BaseElement(U256([2010856641167080321, 7358983062854781760, 6941844079923082035, 2742212684788401426])), BaseElement(U256([11509921517428629818, 1095496649074822291, 10934213235390766405, 2951634164415601461]))],
+    [BaseElement(U256([861907144947913835, 2601234222370706028, 3048132881025156431, 2981729116233633067])), BaseElement(U256([11394466997179863476, 498908378798426463, 1906430301968413839, 209275509046924390])), BaseElement(U256([3468949848600195709, 5147005729865918621, 3951056435357966519, 1165002769608372074]))],
+    [BaseElement(U256([2437409648340687325, 12253444067169590806, 3791613858865178492, 3426229187912628753])), BaseElement(U256([3204714103431770575, 17568252952490068237, 8079045047339476735, 799364911637616923])), BaseElement(U256([14484828984265377509, 17020048997646194813, 6790349725547151938, 2924647371719625124]))],
+    [BaseElement(U256([4075060278561455703, 11475764887359120028, 15627920573725266272, 1303881189169734457])), BaseElement(U256([1569893470347573899, 122304631048962185, 5129709571895132453, 1774606118707194475])), BaseElement(U256([12058377046988224429, 11984141601769639177, 8454522103522318184, 3216745778406378532]))],
+    [BaseElement(U256([13182994254593626062, 9842918860517515082, 8125923148720372473, 270145290592020450])), BaseElement(U256([10139909966202167066, 13072056514697553699, 175826003107660253, 1439724994359000254])), BaseElement(U256([16590544346459777191, 12261358966137063343, 12101569102621195508, 846398109511802070]))],
+    [BaseElement(U256([13592884026812165725, 12022012843500456025, 14797463521570947884, 2792715218437667434])), BaseElement(U256([7356245263383747557, 13758919145240210000, 11229864867210672681, 1165946902098226914])), BaseElement(U256([14103972652536449038, 3897088390511505719, 8519309111147319133, 237303003062987657]))],
+    [BaseElement(U256([8814222969417516675, 18324292604080462620, 2364080189531701658, 1691739292547151573])), BaseElement(U256([17525152212351166379, 3665199398142286255, 1580147529832376302, 1542257760526038768])), BaseElement(U256([8070473706133599199, 14172979939023123344, 17414133656135684869, 1032357995724314582]))],
+    [BaseElement(U256([2228055192450623529, 8436727057747644910, 16421586492432747356, 772067218412362799])), BaseElement(U256([17775548787768057704, 15126398296893084862, 2640315120953306788, 3156830006039756791])), BaseElement(U256([810018810284536190, 7118107416165713503, 6594958368391683644, 2910224724814639674]))],
+    [BaseElement(U256([17809691596665737518, 1583815180832690795, 810407931950412260, 2376609778291647916])), BaseElement(U256([7687544809520658500, 4677358970700391023, 7516041731877175407, 2497427388371257579])), BaseElement(U256([5934370933478418915, 9378888845237245062, 9943674607180525635, 773684564351766435]))],
+    [BaseElement(U256([16460951441604818944, 7163286345539126269, 13353053370604935158, 2663207936516232887])), BaseElement(U256([16918675850015262511, 7688615787185192628, 315318932558234014, 1875265131477518954])), BaseElement(U256([17504535741146918016, 13436848812301406003, 13802945903375411375, 1957522069102193246]))],
+    [BaseElement(U256([17129417940940067947, 4840263348030663633, 18382195907825023875, 2148522111631033029])), BaseElement(U256([15031098516347341112, 9417605861424331285, 7387778655627589597, 724379705028985475])), BaseElement(U256([16330537961764219245, 4669575254612123987, 740530155325947109, 1215374097073437297]))],
+    [BaseElement(U256([5705863437106564444, 15012958680850064252, 10403963106912931966, 3210578006790472034])), BaseElement(U256([9175681498074927579, 81406665680739950, 3713803203368054808, 2350474804137967043])), BaseElement(U256([2091025900680259641, 3252304095483488212, 12728371182101563878, 959357626856268553]))],
+    [BaseElement(U256([7735520294070996124, 16518069796208763844, 3137908989223431114, 1359013391323180885])), BaseElement(U256([14370306436148504087, 2310656084979278394, 16915280335561058390, 2241488929479060653])), BaseElement(U256([15613464128279448288, 4540703381704505060, 4219182009623753747, 3387688538556967216]))],
+    [BaseElement(U256([7731877518557284188, 7859227197712735442, 4544363355137572345, 1252033325276640145])), BaseElement(U256([9854052196397996231, 16483105228099740288, 14079712757233333178, 1416304608343368592])), BaseElement(U256([15796643333700237240, 15986693368933141603, 7791786533084386568, 263395809153204335]))],
+    [BaseElement(U256([6301254373551156776, 14896750475628240427, 14037398619156593861, 1987270873397021560])), BaseElement(U256([7282455458129496126, 15823248592364427535, 13027183556158171548, 1388168778122577737])), BaseElement(U256([7970855594498914865, 16287883500356216028, 16108958047376547227, 2464289387668546232]))],
+    [BaseElement(U256([2654992408607244210, 12662892292146795620, 5704673122934503568, 2846393780567982023])), BaseElement(U256([8753089067694762850, 14885924086799321701, 3269126319464062123, 1252808803034750604])), BaseElement(U256([7065007681099333819, 3258016403834160724, 9159532042900605064, 1199434477687315201]))],
+    [BaseElement(U256([16749941075620252400, 12606642165243408784, 1749598233741055233, 565785147689153123])), BaseElement(U256([3336486993482431666, 7471133204096093230, 10682169260224830031, 3417031866406624212])), BaseElement(U256([16046120210712522008, 15227802304889939216, 3806410428130273322, 803952284595167033]))],
+    [BaseElement(U256([12990478153313514236, 6390899040569698377, 13809897282768246456, 2287965629592206512])), BaseElement(U256([10203028317002107721, 17651158674181526437, 15121055368549879670, 774478464983626101])), BaseElement(U256([2279224338853708311, 2761083207077165577, 11936425502538819558, 1267195362786820372]))],
+    [BaseElement(U256([13898895430210011348, 8166419127744694704, 5907743557331042246, 1217391793213821417])), BaseElement(U256([6922615037470909046, 10762323285893578929, 16489932203267776934, 1438948282285040184])), BaseElement(U256([12404049180778764510, 8525087638356299467, 815664607847014879, 2421416835283163527]))],
+    [BaseElement(U256([16309997205047350135, 13454409761885811791, 218670335291085145, 508861013199246019])), BaseElement(U256([2913067395099704390, 4734260230703447070, 11767763170871901387, 2352390316104628283])), BaseElement(U256([12757811960325287262, 2906250592386931262, 9907458151053775388, 1822854985056846051]))],
+    [BaseElement(U256([15276834829376514305, 12079145336959813667, 14480840677915246891, 798652498498272685])), BaseElement(U256([14402177705479741734, 7809223623320270564, 10498816522004602411, 3370726818609101913])), BaseElement(U256([6598857276499647372, 4634014088271792732, 8894300149557561545, 1187450479164099448]))],
+    [BaseElement(U256([3340690100686710097, 9818760560675848344, 7121810734771127259, 1110557253990837038])), BaseElement(U256([4559159410095673383, 10164506339966069913, 13897333691900966297, 503645058121469166])), BaseElement(U256([6064358287569769327, 13057298630819419307, 3144314480379305294, 3278494971255536150]))],
+    [BaseElement(U256([13866302187564891343, 1003353829001492463, 12147772301927763518, 2226831082893706598])), BaseElement(U256([3446099805234998207, 11294040615007483147, 2231080252247862123, 163763549323279703])), BaseElement(U256([3526929542883464296, 15571264774125404125, 7329522667157136977, 732729799147683897]))],
+    [BaseElement(U256([373745146275499746, 5582050733603985284, 8121481683211633620, 2766380968140574810])), BaseElement(U256([4047395980047099552, 1513195693684510573, 14378592246239157264, 2776617604019211471])), BaseElement(U256([13795082024521096417, 6839518798098655448, 12945878380099430417, 1711750316236550963]))],
+    [BaseElement(U256([12260583404923551433, 1905334461239807654, 6538671926716756949, 3039523257926055870])), BaseElement(U256([15353005206527029828, 4272621483362207836, 12968510640362370405, 2049130487970392244])), BaseElement(U256([15395857371291867529, 6821770082368768866, 15608764650389753287, 1890232088505963202]))],
+];
+
+pub const M_0_0: BaseElement = BaseElement(U256([11557540151452292560, 8580869191601757519, 16566669086158367688, 2179673490660469815]));
+
+pub static ROUND_CONSTANTS_OPTI: [[BaseElement; 4]; 66] = [
+    [BaseElement(U256([4883224509544257960, 10294250702647188098, 13812652228131053665, 1489486978621741877])), BaseElement(U256([2051771277834103960, 8259167859906635434, 12413551415661793035, 3242815666112425170])), BaseElement(U256([2749193412415434487, 16619992502796972979, 1541504423508272868, 615558647478695540])), BaseElement(U256([2202610339749210646, 14590033123816308324, 7531768188061079944, 187923596438589980]))],
+    [BaseElement(U256([12013822207865431924, 15862433016970535069, 17565577368278771394, 2844914407947638936])), BaseElement(U256([11754076989277134933, 6433830874845809185, 9588821972479041858, 95294193623336772])), BaseElement(U256([8415604812825408154, 6652321530001040360, 2547503334005344225, 1639001997265947979])), BaseElement(U256([16537180755974727721, 7038380527437244106, 11647109298171512274, 2235699787421759317]))],
+    [BaseElement(U256([13163666223170610282, 10360337634083438640, 15499031385062742639, 701470322818021890])), BaseElement(U256([3745984203522884803, 5208314475719647079, 17862981097046841440, 3121901627827364635])), BaseElement(U256([13308586914265739875, 491638379001090274, 72498404946618309, 1364427480385344415])), BaseElement(U256([1709206971889937041, 13446112229142835583, 15668949568290620913, 2333121298576105734]))],
+    [BaseElement(U256([393510679579464737, 6278634506426338755, 559649849068558978, 845300170976509185])), BaseElement(U256([17561294551121804683, 11264993937789989719, 5717380516324271385, 3007737636166644684])), BaseElement(U256([17324933860346480497, 12679843683657500848, 7279914650861175078, 530138844894835832])), BaseElement(U256([3469110145402836392, 18338773566319292165, 14250214542717871332, 2683197772235531821]))],
+    [BaseElement(U256([6814709600947422231, 7376678752930342819, 17214123703788030583, 3045359388980353657])), BaseElement(U256([14557914110675118083, 2605261118068220946, 3787209126404928782, 407241446912237829])), BaseElement(U256([10083369221647639742, 6286273438450211125, 14487566855274674241, 1695825623746905576])), BaseElement(U256([7762465633450496501, 8989366662136822422, 7546665968871694779, 501231994026955687]))],
+    [BaseElement(U256([7597777515002386110, 18349700309135028412, 121860958294361508, 1879773462311401035])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([1941988088353647977, 12145177474232149398, 239145673865057182, 802634960672786224])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([15547457227135888361, 17732950823924805495, 15484496928216946711, 1297948444541321096])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([1105426006801186345, 3019240822044374527, 7626471878182450149, 3155216140214560636])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([16043453585680886058, 5695854634322682778, 16186215635238249876, 450601180557612685])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([12907615864588042745, 7960357036139259066, 17505240698153288847, 3444208308071048505])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([8736071949451590572, 1061099380471615288, 12763753258571464231, 1538560603961328078])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([1884181321274827012, 7911617571204839048, 10757697910277400203, 2294997667310168315])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([4851091508237092523, 14546613915056214852, 7091672929235062133, 1282451375089131820])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([10437089180209377258, 3137829410399064730, 5915755701591116694, 579053828463210280])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([14219802550658025731, 15725522730163513732, 210941975963853702, 2276449425401697845])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([2708490081887850026, 6151836861794061284, 4954917524936727759, 3155693884887778398])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([16803314500448744435, 62935615191417267, 10102563778521422897, 139481581038628647])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([4984969340761865728, 14309276984666334371, 16739631507893285333, 23861761416341088])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([10978292048325547052, 7257146090874001208, 1914235808901381555, 370537218689200213])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([12826041589879244998, 11712670086311292021, 15204894983586836465, 1495560641434922419])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([13259214121274819382, 12035500720569855382, 5888353173246404795, 2332854462665624243])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([9421485503721044640, 16890122343846931884, 11516461574670652577, 1902754037412392722])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([14532127783192759068, 296045612495653551, 15907486169026744221, 3077076569924607436])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([12747635127478396329, 6562048064175864042, 18322720707711935265, 323923734977005031])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([12015800988371723774, 13203790345788926274, 5900208782042022472, 3348227111666379659])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([16129591818616956735, 10414345911712239486, 4798351502848264766, 2500493984799798138])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([12674768166420525806, 11644469910922213978, 4430557491145767432, 1633610573129228740])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([368628563461677969, 4428063254887370522, 454627129918303976, 2048353442501661038])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([16984699233163420167, 7125141691675357663, 17392071601619236626, 1862288523074377643])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([14001711897649905146, 4668770484928592456, 12691502722233126241, 896997717726982397])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([14612997442734106648, 16098992497601580805, 16252813609486286641, 1029491301428113113])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([15764522521100543862, 5399954592783985001, 9209848613853629641, 3207767007416053627])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([15187070852270221440, 16784924434455477957, 15300729176944633068, 240016720866684278])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([5124868444805357340, 9073838599375001171, 5095511886882708464, 2472290248252709908])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([818000558074870638, 6176466798531626985, 13506680461065955550, 3138043617970155082])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([8781190472669907190, 734649405530183675, 17333405819500172599, 31556970748507867])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([8158638308930965234, 18379403528976736518, 12692507438963537289, 2412106612154007757])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([6051409152120511571, 3215238445680400365, 8506705184786637445, 1030229686743090897])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([13341453686711549575, 11373836868422960612, 11839126904120686719, 833322139716403827])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([12747166021141738706, 12901502222350420545, 11915956865823650450, 2624737066686650581])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([17214267934671142706, 5175951310374535014, 2839940837235785405, 50144675399606541])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([7589079173469001310, 16882574300502134813, 16375917326963407431, 3149167705914552092])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([4248268766674610491, 13048608341593698674, 16801429734819928922, 845744706296813273])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([16403305247851626518, 3124077287790936964, 9733198718029198009, 2858579382822250802])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([10901119729115662355, 8291738141041996506, 2245186154405108264, 737301019822996368])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([4760135447469618121, 11967937062990394268, 13981462829071192034, 1492943159693883994])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([5874021875707914741, 7568794932951556392, 9974087930297794720, 446795249709364429])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([7243380842018167423, 9104814941543161411, 17206847727191045167, 1040906577624769540])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([4342915504265501199, 2660026047903060990, 11258577696244685123, 1414353702456948865])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([11044069783555449955, 13516208511146931367, 16504346313357349553, 319731839180621450])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([6695600247343434312, 4001788682850403273, 5540849699768120149, 1204964445302196619])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([8857704457170593269, 717367507594168067, 13586342971463491556, 2834611419542004181])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([10876378652026540883, 16200253500405462477, 11305081456352806243, 2463204422383790894])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([5943690019497091266, 9706489948919359586, 2708790793239650644, 1853881456574014324])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([16165820924010075070, 17238184523182137537, 15641595534554773992, 524218291737708574])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([17109555495040932090, 3354318784808545818, 3861958023046757858, 1520358155483917672])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([18378448507449423823, 5221318546724384521, 13310656917138527012, 2507841555408063115])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([7357599549944052115, 7440947860774104798, 3346297736920341690, 1845986991487462946])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([5699668193985464192, 2027884521476292920, 8871877670376964279, 2722585339757551675])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([10251222089887561613, 3859531975990021577, 3755918687785907600, 3357663776131999420])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([12487916590719953160, 17509455141510473447, 13989567227135374646, 467863141939884717])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([2981024797620009844, 6313524115750594456, 8785393495077303857, 602463280705498605])), BaseElement(U256([5966458694021400340, 11633762470123441294, 10707737764556089682, 617675319052731477])), BaseElement(U256([10587930402493866158, 7920806329525676493, 1994928203899279558, 2913570554789918978])), BaseElement(U256([6374360576755502466, 13032406500736980551, 10579210934773869559, 2468562939780521694]))],
+    [BaseElement(U256([8105738535616942374, 15110007884824388077, 6382789434778123365, 2408615374958205049])), BaseElement(U256([8508080404579498758, 920960632064729237, 16583750083276734807, 1802651493205300815])), BaseElement(U256([15651610314982899733, 16648104100991402942, 12352206934922887193, 725618168976161431])), BaseElement(U256([614864971951770609, 4703550992485099385, 17062594477845225265, 1197813552638379191]))],
+    [BaseElement(U256([11369601821696797163, 16283204231725409737, 179446577952076441, 3389143775014780088])), BaseElement(U256([3306566605422542805, 11948616708906630041, 1881773065092033570, 2032520933609900381])), BaseElement(U256([8743044067110811247, 5420226930886079079, 1300615806708915134, 2879586967043950542])), BaseElement(U256([12253806716448529009, 1252103417529824009, 1323330768256980179, 76354858153319112]))],
+    [BaseElement(U256([2089073626764152874, 1128172732362094121, 18340021536518497642, 2488903300362531427])), BaseElement(U256([6602873240559008112, 9559510187158228186, 16647105303778797765, 1243905194368611144])), BaseElement(U256([15375457600104963671, 5209132332669050452, 2702966373015440372, 2726558967557263893])), BaseElement(U256([16380534596269340631, 7144074723509891344, 1898173492678832940, 2904903418137332168]))],
+];
 
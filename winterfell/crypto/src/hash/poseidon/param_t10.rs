@@ -0,0 +1,228 @@
+use math::fields::f256::{BaseElement, U256};
+
+pub const RATE: usize = 9;
+
+pub const ALPHA: u32 = 5;
+
+pub const T: usize = 10;
+
+pub const R_F: usize = 8;
+
+pub const R_P: usize = 57;
+
+pub const MDS: [[BaseElement; 10]; 10] = [
+    [BaseElement(U256([13169651040791848764, 17681121639116810758, 9996279995255410100, 378411308405933301])), BaseElement(U256([16449384940117845471, 1168287013056029648, 4199929300841000646, 3138408793718600279])), BaseElement(U256([16412392485132386078, 4797491823570341340, 9467921622615050726, 248895057536250578])), BaseElement(U256([6928248326960209019, 12696855194672491938, 18217469964026081459, 3208428325561937035])), BaseElement(U256([15822294869789939335, 14147125668685472421, 3091169659588112640, 2984089538939760935])), BaseElement(U256([12077094674865702455, 3162909807584736754, 10772067241103038337, 58842067302668789])), BaseElement(U256([13491524442438628179, 294535257200601157, 6904817864590817886, 97252859746082230])), BaseElement(U256([11465988848106260512, 9943262602061062810, 16421479383011463506, 628721937176115798])), BaseElement(U256([12724956358701017432, 4750492682157458092, 6988138572429808527, 2084933831851765567])), BaseElement(U256([14987266269994402115, 18336738593146723220, 13559188321164104424, 213771037804609167]))],
+    [BaseElement(U256([6937160341144590235, 14128635412644992906, 17504837050601326421, 1485573108176367906])), BaseElement(U256([6587060215144605135, 5742603723820136869, 13723601151624223539, 1129626691387462294])), BaseElement(U256([11517147034772465563, 6640044249432860496, 8573473528133789528, 820771413814091613])), BaseElement(U256([1963897405200680574, 3472215205233468527, 2455773513414154475, 2449619971658317602])), BaseElement(U256([11063178513000541854, 8746729881535503215, 7977863980488035924, 2546369041853283325])), BaseElement(U256([7491749694405228691, 11084030235822834542, 4652122910190354317, 608733056014455898])), BaseElement(U256([9763377565560792917, 13666751633831300245, 3453469814656447530, 559208061539276535])), BaseElement(U256([5218053440979004585, 3786754682109865720, 7478809736971599159, 3092421611336601336])), BaseElement(U256([9424221569337726239, 15732406399373227420, 2159281349792979486, 1859810208021530862])), BaseElement(U256([13724089854924642536, 16273402453075386697, 4109923561948187741, 1418778210548895501]))],
+    [BaseElement(U256([15501223288847007874, 14275183025781138540, 2178710775620689155, 2591214915842715043])), BaseElement(U256([16707786228742680482, 4816222009012658128, 13958260187197516175, 1218478751239450719])), BaseElement(U256([384767896962787465, 398749110577105955, 9776043516005882689, 1201981941341037894])), BaseElement(U256([17707753456187225268, 17652953538910730971, 8932039425678820838, 2405980936974723918])), BaseElement(U256([7745758647606297757, 9410529552044656349, 9702624761176930986, 2438105096982894603])), BaseElement(U256([10241231373800940606, 7611776803681478774, 14911822487898080230, 227033295915494242])), BaseElement(U256([12307927587371677541, 1769520454035103537, 1004452936374380650, 3375139311944612480])), BaseElement(U256([15226045369898351232, 2635454439815764588, 7042903821430939205, 2940668484204648881])), BaseElement(U256([18356010175588936664, 2927304316351534643, 64664415348038373, 2334974410645847827])), BaseElement(U256([12268462020688856981, 8889589640378690816, 13701702263689814568, 712218321132236352]))],
+    [BaseElement(U256([16858629069011970515, 17967184193160767619, 6230228611449506788, 182954966499048864])), BaseElement(U256([17591505004625691918, 5025279223290707918, 1641531726930694908, 246895618955321368])), BaseElement(U256([5990661707768340442, 957843854097059785, 6907398880150913454, 2860414807616211487])), BaseElement(U256([17658810306833475789, 5339121590475305167, 18337868862985931614, 2350695976690710829])), BaseElement(U256([1669130224990012965, 2715311491972303131, 7430051047259893936, 3371034112659093863])), BaseElement(U256([3533853212395371576, 1863056657019878439, 1849053040602185721, 2693678851662578177])), BaseElement(U256([8335754372062955616, 4330517437073785431, 5229039151734109744, 2001084788030071385])), BaseElement(U256([9453043756846078957, 11363116222621282164, 18118221371274941501, 359893856820214391])), BaseElement(U256([15064745294071153338, 17554081954014275484, 12672394312782793777, 2577096772494936590])), BaseElement(U256([12967758800864038208, 2801143041343965674, 17663163601953950286, 678534261317531799]))],
+    [BaseElement(U256([18061543706908889051, 1370867151470009184, 14552054965503584255, 2510745721503435027])), BaseElement(U256([7984820222478039657, 15333388790574130302, 11729590091243375860, 2356167168595732174])), BaseElement(U256([11396792239060870251, 10953224102014457827, 7324082876399612942, 646549040818253568])), BaseElement(U256([12575730570818344047, 18220588936169884402, 6652706444678483610, 1263761735155034388])), BaseElement(U256([5357902387654975126, 16595198310117422957, 10202478820867512411, 3238995833195196294])), BaseElement(U256([1855214588023406593, 12470751965951131883, 14756455070455726373, 859873408301803860])), BaseElement(U256([457822181823198683, 10524281452711096867, 15416446538378616483, 1950387345296301773])), BaseElement(U256([4257355077516669329, 5631157421886582539, 13355526145220611593, 1029250321729052490])), BaseElement(U256([10184093855481214076, 10843142552847664871, 17593996433164336968, 391690220442559618])), BaseElement(U256([6760147175626760697, 17049167729260365286, 2877288291588407376, 3474378873471272507]))],
+    [BaseElement(U256([6243666022290260057, 18260229542191188846, 10557280124121356815, 1000601895275295834])), BaseElement(U256([3005169457293466855, 13243070816588176652, 15297779814575418200, 3470018795826301173])), BaseElement(U256([9066128422169244208, 10957789595626316272, 5475136704613425015, 1946567965420992906])), BaseElement(U256([3356878972899456192, 10206975192939487169, 18002487315182088879, 2258345276978633738])), BaseElement(U256([13438587314778649209, 13776825468945596323, 360544516840266147, 2981159989737366994])), BaseElement(U256([17625631778046593997, 3142904972004639815, 17815605826038608608, 138895691267312029])), BaseElement(U256([849242225634319451, 3785908915165057223, 7749931990434104247, 239416141500363218])), BaseElement(U256([8427991199061438281, 11836683663641815567, 6208727066005207412, 1316069934545823957])), BaseElement(U256([3404956756631355438, 12603977380566041430, 5977442314664342967, 3045111035493381624])), BaseElement(U256([9723966317843061162, 5383695966894510937, 1899619119635922846, 3030031763720565130]))],
+    [BaseElement(U256([10371767627165795552, 7027917844786669699, 12961785828478122474, 3362136255364674472])), BaseElement(U256([9874026601471384537, 3619434928187179812, 15692008280962720320, 2148685373422438169])), BaseElement(U256([11074211288294912777, 11106623238889248212, 3433181850248523177, 3333368178851951319])), BaseElement(U256([9676107081146593807, 10707644253183233557, 17046490141222122153, 1467063583981634194])), BaseElement(U256([15008501296336368519, 10577269428895405647, 18436845087136400074, 1395409101572474273])), BaseElement(U256([3517115013362308656, 1541013323236767153, 14553248236344106821, 88015083790267112])), BaseElement(U256([10353536159372892722, 7936440003236932601, 10636617200048015435, 1764655105939975201])), BaseElement(U256([5564782809951508579, 12269101184189501726, 5325386558640187373, 2131055314356415474])), BaseElement(U256([8632038107371232152, 1954754797546817509, 15886083955431571305, 1162503597242125280])), BaseElement(U256([7412720982994919479, 3075262347975489278, 18364242961962976939, 2501326417655259093]))],
+    [BaseElement(U256([1518015917076559380, 6196506591429141521, 2476576165071926139, 2039503408600235870])), BaseElement(U256([17633050363786263093, 12627915088924342079, 8941977283125767097, 2374840430125463968])), BaseElement(U256([5775279909253854149, 3916764440736869228, 2456697696296844642, 2021309347059614047])), BaseElement(U256([8905090384850775983, 16789530080340027606, 5927407711217168234, 2619497888947054511])), BaseElement(U256([589748989957081580, 11804731675522710919, 8707523905748713234, 3163975971488737503])), BaseElement(U256([12897284757915465768, 8232260063314489623, 1304433266433504220, 1217763638273440669])), BaseElement(U256([264011789513104110, 4997489537311538600, 364945752597717742, 2304604872170741521])), BaseElement(U256([16777036120867276069, 2517339657511040755, 6372637844691952338, 632854166692706191])), BaseElement(U256([653131190691635480, 10633087391312319618, 5425877660860362462, 2773358166611359059])), BaseElement(U256([8212898137676158749, 10214599584776705134, 15147724646227213755, 460937173741437632]))],
+    [BaseElement(U256([15605638334491447737, 2428671317732189329, 11819214518575612629, 1286437000431040581])), BaseElement(U256([8303120491873423223, 18152281300983326062, 9656748325205191170, 795686192627995425])), BaseElement(U256([9307155292618536813, 17016922598617905238, 3953070811542425415, 1599874842673907032])), BaseElement(U256([14755238202675844542, 16568143548765550432, 10253753394333561809, 2064745361617787582])), BaseElement(U256([16196674259787681895, 13227659274499198542, 17835865915992053877, 2334625528716928364])), BaseElement(U256([18166690409665431809, 9026415304760287232, 14067863837620925946, 2971225740048256705])), BaseElement(U256([15134088195582442222, 17188923165127164699, 2955721274008498216, 1333433045495071137])), BaseElement(U256([3107847042384129212, 16527678966314980092, 5580055607579946040, 3282354104686586764])), BaseElement(U256([8878454776437443294, 2188312405251739876, 3339556729548804416, 1180661076748650007])), BaseElement(U256([14417570102423332417, 5565478444490171355, 592662020381043761, 3362631706580899230]))],
+    [BaseElement(U256([7353264554279929700, 2655325404696331825, 12251853035826420948, 2038332201462537499])), BaseElement(U256([5649279179950250581, 18042265066129086585, 7419056503342314230, 991384562728032404])), BaseElement(U256([18232063919053221916, 9977892501104248959, 5700984127454243230, 321856075729021455])), BaseElement(U256([18411615240475218429, 10232774854052701048, 9691987514816049897, 3138768148019982973])), BaseElement(U256([8358381532893915243, 9931011937719105985, 651016094407483471, 1441165549971574117])), BaseElement(U256([14537435669543248539, 8100578277345655073, 1364824949756436273, 2778582571440202754])), BaseElement(U256([3400112904412597116, 10564192632026512445, 6460735026227119645, 3192136068434122878])), BaseElement(U256([103191710098737588, 16089295939739547403, 13468864426465645252, 3448608969941260664])), BaseElement(U256([6293164341794068198, 6637188600818348219, 6954663735651447762, 3150139093078242800])), BaseElement(U256([2711455743162766210, 11097028127369122780, 12459254064317952663, 1204985723854364094]))],
+];
+
+pub const MP: [[BaseElement; 10]; 10] = [
+    [BaseElement(U256([1, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([11516322878874062883, 11435469091204589971, 3324372302314918840, 663459260731362009])), BaseElement(U256([9747079119060033194, 11240778892757296624, 10586255927947689261, 1884505196278660160])), BaseElement(U256([10040666211040381665, 9374933317880434340, 15621412181980769410, 2906805554112972927])), BaseElement(U256([3890201684614908795, 4543024847290338235, 8111149636410741727, 2589187498251115953])), BaseElement(U256([12092452385119411189, 941277115027254781, 13152116186528946270, 391899864736264153])), BaseElement(U256([5273264744054281918, 8111719966845070475, 2821473324426030435, 3408909900053209120])), BaseElement(U256([245016975123584373, 4234560696687743599, 3491052956852275290, 21863059548378872])), BaseElement(U256([1955474352698732071, 12024160371803652045, 12373678051912026239, 3394514159566953267])), BaseElement(U256([13274063152702948334, 5831433920422951734, 17386135590402093254, 299230485114808813]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([11427587683204179061, 12335793773133897082, 11853605172774448827, 2687395174477563109])), BaseElement(U256([2359988060197683400, 11079049246256241949, 1547678777177419462, 1180172949050435970])), BaseElement(U256([13388221063639062143, 8655265391156200638, 9986939612627052428, 1405301643918475982])), BaseElement(U256([7831651985839381808, 10456247475239671459, 10780752060616583386, 2744700464369669130])), BaseElement(U256([14436933288031826492, 9002650867401679454, 1628298084922881315, 1039080000435834017])), BaseElement(U256([15136917632536541499, 2641175159633787493, 5393770752575818261, 665048942524676042])), BaseElement(U256([17939021285082390195, 197276681497759796, 3243532870241365970, 1961257385457103673])), BaseElement(U256([1082809910800254185, 5022398735991555310, 16431259724987505816, 1320582154129994394])), BaseElement(U256([17696791844318510005, 11762537763020199762, 13989009824755547663, 2919595888872597860]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([1332971012576232301, 271536733147126104, 860167146301347930, 2459196720327175224])), BaseElement(U256([8399541550536186107, 16520152013662090973, 1943073972465816572, 1868322928664465179])), BaseElement(U256([25354461223369163, 12720573445381175055, 14487644257496435793, 1664923711270484976])), BaseElement(U256([1209269499028901599, 11455352694066288556, 17408371060509663614, 2542542141999832200])), BaseElement(U256([17928224073786982345, 6370834233162865875, 16995161877458418622, 1668859559068054973])), BaseElement(U256([1087432959428513325, 4406661969761179216, 16357369904984999436, 2949152509264211197])), BaseElement(U256([8712521160171166699, 8555568577330749309, 13195372478586748344, 1773408101927461931])), BaseElement(U256([1626849924673904926, 4740284630547824029, 2389852616957207040, 3138074193501496916])), BaseElement(U256([18117955700534430642, 13560899460095414323, 8216468875833718277, 2726046140748548042]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([8807042067300673836, 5060763521123979898, 14134078075388096406, 1876392120572512798])), BaseElement(U256([12361113173366657618, 18364195879943212702, 9888526163656553059, 2163184784967591753])), BaseElement(U256([6278592625483531537, 13573387292998044539, 16289393987765607144, 1218063806860167160])), BaseElement(U256([11951410584384629090, 13035076788627261927, 7497770692714772850, 1765329083642840237])), BaseElement(U256([3939592157918556537, 17264665303670164000, 6869076468492810958, 777889429030049151])), BaseElement(U256([11206418804955258975, 415585512101024739, 2638442486415314650, 2568001177197070525])), BaseElement(U256([10458010177127135515, 8619214414511264499, 3511694703513515938, 2156983871708484424])), BaseElement(U256([18165182826050847661, 6710848305254711313, 13628468770327465239, 2902792850074567684])), BaseElement(U256([16042981747003369737, 10176062441242057933, 18269997578670882946, 2493876571224875078]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([587677302066443768, 9924290405154267736, 9128232543918369656, 2180719624983425207])), BaseElement(U256([12262135713153810274, 12382608260479091653, 15522548765874661564, 6093960465414033])), BaseElement(U256([6069617330095659644, 10563110123603345243, 7016821299203852945, 623779397070564292])), BaseElement(U256([5683632722797353814, 7594079620266554648, 16911004081516089174, 3258705794620745249])), BaseElement(U256([16265518904043442643, 11040666810288736098, 8328539875751002493, 3371566125013055898])), BaseElement(U256([15525777011138134707, 10328132145728846603, 6529036552032430253, 730663172136991150])), BaseElement(U256([10039189765607604597, 9684334529970149796, 765630644633300753, 3063102807372142873])), BaseElement(U256([12591104431342126728, 509471574329035449, 15786389988838858530, 1646647462604721413])), BaseElement(U256([1042347119918419108, 3428219031782547988, 13783996028879306473, 2774610022276781174]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([5324636199424907731, 18428611683786068469, 14320148160960341695, 835078011331090495])), BaseElement(U256([15221992942917696914, 11308635695964910672, 5718166404376675931, 872307789410189214])), BaseElement(U256([335797756660012905, 11255852709908501837, 5309684169121165964, 1899916521877214512])), BaseElement(U256([12935439963243841623, 3796570770434691927, 13322796801308879320, 218756475252379958])), BaseElement(U256([6674052216974124516, 15083758081091319619, 16433556548133225002, 2400694847006144924])), BaseElement(U256([16557854553383108523, 1132367544325719230, 5650617123632480802, 495329433653789425])), BaseElement(U256([2158589770738730223, 4403743619950664121, 5950614967450412381, 2194609101035013778])), BaseElement(U256([13640245383336893467, 8107979726748995177, 252637843848356450, 912744817011516496])), BaseElement(U256([4667988542642527962, 11375195832217296094, 17180210322046623920, 294030517935031816]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([11560382134132453243, 1639058856076718155, 478534588213716897, 977534118331482278])), BaseElement(U256([8466734430958006971, 10546631827964897394, 14197914937270707148, 1539217389226502637])), BaseElement(U256([814055820606318838, 8215953152996257071, 3730119998875128423, 668009459390846080])), BaseElement(U256([11172385420098742005, 1020474125288117235, 8032863366451673874, 2305228143509690066])), BaseElement(U256([2033069807572495289, 18273218350629022889, 3572141082528294609, 1414265357360628389])), BaseElement(U256([6087601339055888066, 16577647637396882926, 15649753831297238606, 536377439841396796])), BaseElement(U256([1099887508602222623, 12585407524054958184, 14119713649952279611, 272991423209227314])), BaseElement(U256([9841953139011529566, 5086084167366277832, 9356897213578349990, 3005399419431448994])), BaseElement(U256([607081904456987824, 5426974563585161979, 5328886823885846868, 2759196225155267449]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([3271549259517888777, 15022024997702927922, 4562213893885254793, 3472189730461352906])), BaseElement(U256([5500263899352899189, 13991422297626924578, 955839682761206776, 228982246284020124])), BaseElement(U256([7425812383041917807, 7469148583798347479, 665852924960628689, 3069829494929573126])), BaseElement(U256([17558820667766285316, 3886094279522656666, 10271283746821503794, 3257733250153523279])), BaseElement(U256([5269444970710413408, 3267985956004538774, 16902808905696066555, 374685207421907794])), BaseElement(U256([14791763775451604922, 4210475157273717937, 2266665343288653574, 553432257137391635])), BaseElement(U256([10658635474992067669, 10643979594957795710, 10213026858724409135, 2731857345772503858])), BaseElement(U256([18156315398307105910, 8806186038163658344, 15086275446858580472, 690923875387475633])), BaseElement(U256([3262828910700756318, 17717112601682818979, 9720445849379808489, 1817338344018219415]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([13890651854956184236, 15632814288664285852, 15393533684091931651, 2929477116378455692])), BaseElement(U256([14351373824396646133, 7004386160692914374, 5484812729052431502, 3096782881390911727])), BaseElement(U256([6430188956138679514, 3472998402881976855, 16209091088496341577, 2876374785159427028])), BaseElement(U256([2452995125695702878, 9801052512560080130, 17527721842064146820, 2906252302468809478])), BaseElement(U256([7238419299232418615, 15629867073841202570, 375716700392832698, 625892288026859956])), BaseElement(U256([15810283564957247806, 13747869368878174959, 7588669776882075057, 277026080250112886])), BaseElement(U256([5807701891823132233, 5611003589200416292, 6407981769670173442, 673698711558717292])), BaseElement(U256([6684543124093946423, 3257513678499276663, 12992616524078908729, 2374151342170275896])), BaseElement(U256([17040894923506817419, 17264400305141287804, 13486101387480081323, 3019289548238073598]))],
+];
+
+pub static V_COLLECTION: [[BaseElement; 9]; 57] = [
+    [BaseElement(U256([6937160341144590235, 14128635412644992906, 17504837050601326421, 1485573108176367906])), BaseElement(U256([15501223288847007874, 14275183025781138540, 2178710775620689155, 2591214915842715043])), BaseElement(U256([16858629069011970515, 17967184193160767619, 6230228611449506788, 182954966499048864])), BaseElement(U256([18061543706908889051, 1370867151470009184, 14552054965503584255, 2510745721503435027])), BaseElement(U256([6243666022290260057, 18260229542191188846, 10557280124121356815, 1000601895275295834])), BaseElement(U256([10371767627165795552, 7027917844786669699, 12961785828478122474, 3362136255364674472])), BaseElement(U256([1518015917076559380, 6196506591429141521, 2476576165071926139, 2039503408600235870])), BaseElement(U256([15605638334491447737, 2428671317732189329, 11819214518575612629, 1286437000431040581])), BaseElement(U256([7353264554279929700, 2655325404696331825, 12251853035826420948, 2038332201462537499]))],
+    [BaseElement(U256([2621301004274001361, 5977740901164830013, 3124578415791972182, 577197801247313834])), BaseElement(U256([10295659357791224515, 10718136547533479129, 16185290812226322981, 2667955317109902893])), BaseElement(U256([14873028301052298373, 3873370135443159603, 5184040684476937139, 2650175755167885052])), BaseElement(U256([3793507190265198861, 5064408989624839196, 14836670768093381723, 2717076509977650099])), BaseElement(U256([9079992999302130232, 13178014298946447356, 1571643271346217711, 3020883258052771380])), BaseElement(U256([2284266009766845363, 17646046103349774234, 9251530265551942885, 2326532479636714529])), BaseElement(U256([15065311007366740301, 15295921122225492920, 4333264609895254877, 3478877594550960013])), BaseElement(U256([3736944102877226687, 14363987707163218906, 12766421040428342226, 1897138618330684791])), BaseElement(U256([1235985651282628572, 16950714227312893499, 920303264215767682, 801748595778950509]))],
+    [BaseElement(U256([2798765791726298114, 17915275461777603036, 178646132734937534, 2425888353699144124])), BaseElement(U256([14886749837470313782, 15653622869678530050, 14882495857624612446, 3125667445544433923])), BaseElement(U256([15650294880489659363, 8531651719432426718, 6230155548916278557, 3320180982993715726])), BaseElement(U256([5197130802815402185, 13027209217590276877, 10583790740720657393, 610444112584360543])), BaseElement(U256([11339014209766163610, 14284763085166918218, 9676739141954208140, 1646420509579262335])), BaseElement(U256([17144077459737623815, 9118050435293634478, 8696188667919590483, 3253387436475896063])), BaseElement(U256([4205015183054879849, 3818505293449767695, 6412979991349043111, 1421315443647147434])), BaseElement(U256([1590876172323227119, 977699646242444635, 9960817930163294179, 1484453672883366128])), BaseElement(U256([1717519120966884180, 14231377147912282440, 1696043139527865939, 352256911220935649]))],
+    [BaseElement(U256([9296494204560913629, 17204524403976421064, 9543362551789426824, 1724037306673963138])), BaseElement(U256([882001880077489772, 8712743423478007688, 9120616042791674032, 1531240925834249051])), BaseElement(U256([18364318104379697788, 18020054211489104195, 10125207670184899668, 3014843776863950048])), BaseElement(U256([9010234569449904377, 16941861577509829975, 9621173559084814519, 227006201220666433])), BaseElement(U256([18438240830169210160, 12962112018919075751, 1505721600192342135, 528666254529286652])), BaseElement(U256([3336040910741188712, 9376518706035409664, 7711168819410893139, 3105735094984740056])), BaseElement(U256([4471487348010583946, 4267007325447215118, 8782972465565793930, 2371780699508638364])), BaseElement(U256([5979784002405143860, 15643074298130968598, 14436253722988324953, 909846212762257309])), BaseElement(U256([14009883336500188716, 1410325294841045270, 16099832527966570944, 1375177031364281833]))],
+    [BaseElement(U256([9689156540989443807, 12130796422069377912, 16104928869843709304, 384500921766669070])), BaseElement(U256([1465243205449147273, 4454637693197575356, 7639939926895870572, 2968585877051573392])), BaseElement(U256([6800697305719015489, 13739533522482055333, 1352224063751628451, 3206606345361747845])), BaseElement(U256([10423929760454259093, 9851943974086251242, 2901936228493228031, 621807482250072247])), BaseElement(U256([3627158753432659135, 2759623305899872627, 12447530482556091378, 1050839743841702892])), BaseElement(U256([5606923332745882441, 17196216542041171723, 11514012222825036698, 2349256409337446683])), BaseElement(U256([7105656995642170184, 10215676910954236211, 17331022919386683845, 2448688889759367482])), BaseElement(U256([7006928753178153020, 2139731850714273407, 2971165235393550826, 440838787340995626])), BaseElement(U256([5980631027751082148, 6741739923943601073, 2767920974214741769, 775415658092143356]))],
+    [BaseElement(U256([2399637482166355161, 6190730215408521092, 4762630005446808342, 2641231803055438757])), BaseElement(U256([5917000545897174930, 6138681337784275887, 17788527041572705265, 1141248145823866632])), BaseElement(U256([14839258733552195173, 8371618404575691340, 5079213006782595615, 1014442180701403491])), BaseElement(U256([15435656422553405379, 9040193476414804931, 9091067612715048615, 2032222968872811091])), BaseElement(U256([10883147942127445856, 5596640585158742757, 14665105823401295669, 1782053068726011225])), BaseElement(U256([2408339661145230045, 15800996572623049318, 10909788223311108336, 1293254560315272778])), BaseElement(U256([16780001017073831268, 18092248127819752379, 5462586195579459301, 2027323358414364609])), BaseElement(U256([3796436379291292442, 8362350640585543707, 10266891768208253319, 605772014354731176])), BaseElement(U256([8668618383651825730, 5538793131338605361, 159128337979654560, 1203495368861809923]))],
+    [BaseElement(U256([16990851967280963985, 7207601427786610170, 13205378793280242297, 2403455008865313713])), BaseElement(U256([17233114767787502311, 3883202682886547360, 15887004742416183118, 668177186868297812])), BaseElement(U256([3190601734087437730, 6779306422726199169, 7903715345958762020, 1298013434425490441])), BaseElement(U256([10115352143570704743, 14252687528782790232, 3657438187436377455, 2039603678235360036])), BaseElement(U256([8454321582259821483, 8129050398976167126, 7957289187242768130, 1516776797576929196])), BaseElement(U256([5516453798536404934, 4484619045212460650, 12594834955488311975, 693583475486609615])), BaseElement(U256([17666110633192135614, 7619268981855581121, 1954079205256378272, 687795965517500398])), BaseElement(U256([4372310543326892956, 18217474491281518547, 15874070190595462496, 1192734198653119205])), BaseElement(U256([16866647787150587178, 1199907447449710799, 14759929505218918881, 1117592765076940549]))],
+    [BaseElement(U256([8668877844424983211, 10988472033369466488, 8343997649189351763, 2631326057009092185])), BaseElement(U256([10895756404400780697, 1151662672853673970, 4226067592568779981, 158391356901983128])), BaseElement(U256([13124018904766079306, 4407325984534095153, 5704715787958131419, 1651924695340192052])), BaseElement(U256([6975594637209288504, 7110641413934823028, 12206197673628940092, 1137009285300474878])), BaseElement(U256([6554795791842014849, 6227889541629432365, 2481557805327075984, 1406179083088244316])), BaseElement(U256([3441565260504145590, 12223662341300331101, 9511266353533989333, 262715993777552878])), BaseElement(U256([3370070501754040365, 13863784746920902929, 17939993904410130372, 2590657032250054117])), BaseElement(U256([15826500679633167669, 10705336596169950633, 7510512011229414723, 797524402382549785])), BaseElement(U256([2999298739389329056, 13407579598925822271, 16940132396453663563, 697808591282686698]))],
+    [BaseElement(U256([18073795983286818410, 2814395633295039511, 14405295962526858625, 3357887510577138441])), BaseElement(U256([15614937858620808558, 5947902147706920260, 17441619958822820073, 475799252751739759])), BaseElement(U256([9064202404846837207, 18141014946650132534, 4736090489817906923, 2355984811231119798])), BaseElement(U256([7730807299875192855, 6974213978367765114, 15439894201691183228, 1079755840170114965])), BaseElement(U256([16249196262931959337, 559919653303105776, 6322100300460224839, 659433356661203325])), BaseElement(U256([6097753308122193993, 16475434281186183153, 752775571988453295, 978035769270917325])), BaseElement(U256([7255977865332715120, 14470678648714142791, 12439926641626806907, 706287556385498822])), BaseElement(U256([16815190627003006952, 1352443471533769364, 7767296628535517059, 229573371900308067])), BaseElement(U256([11523398179849943280, 4513043536694754827, 15355801366654168339, 2579214270733320794]))],
+    [BaseElement(U256([8222292359403968369, 8576285715725702234, 10965747989226605960, 1868115156115861233])), BaseElement(U256([11302558247543542279, 3760274705901230859, 994983672077500733, 2570393698851501820])), BaseElement(U256([16443648209757335989, 15572591427626078099, 9125945662561125646, 2092127616741648036])), BaseElement(U256([16536971779251992598, 1606897133876522680, 14398659211531419952, 1900043205015820594])), BaseElement(U256([16395312174179829052, 4046109148634023612, 3179799668822868751, 2025814124196439481])), BaseElement(U256([4322240215094897579, 7586674608176864278, 15983733261980461887, 702103904435815208])), BaseElement(U256([13863727731563264104, 10229372495746502583, 13348958497211233826, 3085152870300442251])), BaseElement(U256([5551363402970091554, 12341968444600881720, 13374928977037776956, 3327680743596914635])), BaseElement(U256([17510843871914167682, 12976321021279241928, 1933582966948984816, 3420588554367434092]))],
+    [BaseElement(U256([5109219658682258965, 1159128779692137915, 18423190234449427251, 498227495253493597])), BaseElement(U256([14297840279314553194, 11623186426071236764, 17437672844692677767, 552075302967892102])), BaseElement(U256([7747852214751183459, 10779357368737316322, 12084210782976864524, 1043052659664495176])), BaseElement(U256([11489991230377088694, 9618482444213330531, 16087868931373362990, 2128029851924722666])), BaseElement(U256([17501017473209426210, 11105488951831424477, 12912245628138424597, 1986623597705839807])), BaseElement(U256([15267220764292161372, 6763793984559410680, 11086064133197493078, 2684344868907105637])), BaseElement(U256([6569921034275110160, 4163661285207981078, 17484911383122515329, 3391922502779999358])), BaseElement(U256([16529689704463213075, 9945315569686986215, 654767619751406311, 2117462975138731447])), BaseElement(U256([14108430352673899594, 9844892902528902719, 17923771797387710568, 1989106254601321742]))],
+    [BaseElement(U256([13779273299921474655, 13953084625425215628, 16816801852912711742, 2732040615214643421])), BaseElement(U256([9171032278824469316, 15729499342841579213, 1908439950394113581, 1639306779736245055])), BaseElement(U256([13728487013548252454, 17679251487665152391, 11486777053069576883, 1570194875904298779])), BaseElement(U256([12803454462985592986, 35346418916786045, 13695610529992950881, 826612616332023875])), BaseElement(U256([8176833299734046298, 11939825123551987394, 4500305597093984034, 1254344319089452256])), BaseElement(U256([13269515982937796843, 16280890503815832540, 9485700290301983902, 1492247626283491674])), BaseElement(U256([17523801735077467216, 9303766425983766736, 8231162189208986656, 1502816756300629110])), BaseElement(U256([1532311417877822531, 8035847337815352231, 12028232505094301648, 333958172811395119])), BaseElement(U256([8601433978253296512, 16574706411390313990, 14280561029579948822, 203306765395320511]))],
+    [BaseElement(U256([12569325066254821876, 1715047904781247553, 5858506988621708922, 2993902638297184887])), BaseElement(U256([18201584355423067765, 1956474260702395351, 2921146446844655593, 2304898895979491356])), BaseElement(U256([9411111549047188226, 956169121491943575, 3712884054927694176, 2790794420553369382])), BaseElement(U256([4146517379786645377, 6817345742933831132, 6351007116034210453, 39937067843114586])), BaseElement(U256([10169135595908226194, 3231713785289612799, 1960112199512380182, 689535220785083129])), BaseElement(U256([5548297106285163903, 12995903069508241743, 11964671434215260788, 1154649418990579149])), BaseElement(U256([13912557446393640575, 6320818503973530460, 661978869019964493, 213200654214067126])), BaseElement(U256([11835739566225515472, 9771766188764844756, 12047800023244453517, 3239127327755729072])), BaseElement(U256([12175116855384797395, 15842720132707129745, 16713925414055948679, 1897814021801607173]))],
+    [BaseElement(U256([3775101569358691589, 11994500678564621436, 3495035211676331624, 2690924131782972804])), BaseElement(U256([5563688329190703462, 16383514626484711720, 5449465181609776740, 55344447271166304])), BaseElement(U256([4904181512466706421, 12048083052242951594, 8444633948228408180, 1290520431167549432])), BaseElement(U256([17884461524768932104, 4502829128098863191, 12576464944725010602, 1099071039637929497])), BaseElement(U256([540633926167810447, 13434499919971666173, 2203066301498909843, 2835535952515625108])), BaseElement(U256([9075985794838239196, 3197080168239399827, 15620511875840834013, 1249802302022460391])), BaseElement(U256([7286331156631817581, 14093445587770315718, 12138856936887659086, 393098735507408980])), BaseElement(U256([14394637280805000349, 17529050678971394854, 17631218437211043376, 1488653891251207564])), BaseElement(U256([7501904478509555643, 13298110875233897738, 15180986344554636166, 3407458542989208196]))],
+    [BaseElement(U256([12556312736065160894, 13683203987813238984, 1744074425216955804, 1687174145781330628])), BaseElement(U256([6693733106773189023, 8627197227253468295, 12237219681529008043, 2386097052028265784])), BaseElement(U256([13653319488141546960, 4548419871193253986, 15943433811163666538, 1482949052412109848])), BaseElement(U256([3377391770095924787, 11919635765929582672, 15960092341980410656, 3277662164627903360])), BaseElement(U256([6823633173825778667, 6662012792607950477, 17985311252603265602, 3057244799145011105])), BaseElement(U256([15058307740501769182, 5013748408940858820, 3792253287447451751, 2363091937205244705])), BaseElement(U256([10714006467884716904, 17082434127078414546, 772692385319683413, 2527439317072873276])), BaseElement(U256([12649217581718238339, 14764126795502549390, 13512549965017407589, 1270783592288126452])), BaseElement(U256([6604389689597594107, 8157957955118146707, 7783195165676155509, 3265535157349145214]))],
+    [BaseElement(U256([9937984935364745597, 9659857834248298774, 8927132474062766926, 2395152803780167963])), BaseElement(U256([15402698165979300903, 6162733622332384799, 6848907705772572789, 3384006335733664356])), BaseElement(U256([6471687516992605571, 5220093203471254987, 6292471771526512160, 3051062628167579086])), BaseElement(U256([7304259764541609324, 12789771260485829950, 9858853774070603842, 1606680721921779544])), BaseElement(U256([10968388526459723623, 16383104629082188577, 15092680900635816533, 2871872835382189627])), BaseElement(U256([16477804541620922636, 9597320372614359815, 13080304082861446716, 787332093711077008])), BaseElement(U256([12784608829885235480, 3369412906373121030, 12129375569815382093, 3269756388998353393])), BaseElement(U256([13417568172265219795, 2601914481063535559, 1384491181892492521, 2332578038565125623])), BaseElement(U256([12474674049501586398, 16803385477253502681, 2553628614818472218, 3075695782815884943]))],
+    [BaseElement(U256([4448810831667814217, 5490086063810391733, 17026538497333266549, 158609927556276678])), BaseElement(U256([9447657348021048128, 3176515197254848258, 3493586614059949743, 3445454007418352956])), BaseElement(U256([3430902898881510162, 11579594407089019608, 3046191136913432632, 1318498587572097577])), BaseElement(U256([16895600334650083062, 9580754728984364504, 14900629781928405207, 1501146578041393675])), BaseElement(U256([8725487247512321265, 4678859898705575061, 7006373726474133756, 1560093180034604439])), BaseElement(U256([7016633222185497637, 13257867114625115273, 5872636234087199868, 909588857815516694])), BaseElement(U256([15770877148822781247, 1524297466117284271, 9030405607487667276, 253849424764644165])), BaseElement(U256([7024384767788809938, 6611124774340844193, 14599506719485486421, 1049460978584898560])), BaseElement(U256([3447397307605950322, 395330226331198446, 12396971866617593524, 1296161559204034646]))],
+    [BaseElement(U256([4152926164399305122, 2406365805598670031, 10337395044048917578, 122512462627885774])), BaseElement(U256([703588980176815845, 16437881533287295929, 4257436523040176372, 3377390979767993776])), BaseElement(U256([5552997276138277511, 4763394194338876330, 4385193426066516695, 3079405788622656252])), BaseElement(U256([12385180953781868127, 15878538622711540090, 15993608536868006978, 1493907590965677080])), BaseElement(U256([74537527083318093, 12854984564312380722, 7144288640309858963, 174562218713676083])), BaseElement(U256([10394413250666010347, 13541781018016461264, 13729057354440941631, 942270689105556912])), BaseElement(U256([8867604092495201737, 14080770725929155703, 5729907694667559662, 2741693797752137041])), BaseElement(U256([10536889805939767906, 15847239586700215189, 9019881367709100433, 2631012629790479185])), BaseElement(U256([9140068303282272362, 9918201413320375993, 6209697134947359341, 972678485088492931]))],
+    [BaseElement(U256([1030905621007647984, 12041628643246471775, 11789548553373313229, 3076571002361901228])), BaseElement(U256([12727604358537334662, 16866295218508754048, 1183597078532546476, 34142994686755225])), BaseElement(U256([1328520504585097660, 11595691758432646775, 12274861604178736871, 2225357326737305207])), BaseElement(U256([4055807081283999340, 18093510439854251971, 239242821389639554, 1639056165355756512])), BaseElement(U256([16569177096379606848, 3016925684246768806, 5723594979170920105, 2847601186930012494])), BaseElement(U256([19369040201171451, 15961918350186669311, 4878288928571127798, 1421825153233368580])), BaseElement(U256([13917632595426554152, 14599301303815447513, 13202415030718624057, 393211789576714777])), BaseElement(U256([14104316581564982928, 1252523271752290432, 14283001156357440241, 340633150526443775])), BaseElement(U256([5525150460550778687, 14336857492603744463, 2160766751227687420, 1108551221012130212]))],
+    [BaseElement(U256([16602734792410582739, 10615113577328315196, 4553448704845555815, 1192781515759749242])), BaseElement(U256([5464825883530778405, 5587728562899834884, 11221514245523148193, 2359787850165892972])), BaseElement(U256([8158711141440959814, 13456930577548352460, 5492821209286106961, 2956076452286099209])), BaseElement(U256([16594967394584027264, 480444555827082549, 13739073903287278080, 1447512297291162020])), BaseElement(U256([4241053545872893049, 10898431720648988995, 6219296877822550350, 1131593144315059788])), BaseElement(U256([5379776952614177760, 6549652937329182179, 14176986244155791225, 2560593753704588655])), BaseElement(U256([18223742949393104154, 1919029641269747460, 8410008390278606804, 3134112962124390008])), BaseElement(U256([3569706684102428915, 15179786751907239165, 13766628343410475457, 2033947610717396710])), BaseElement(U256([1475498618595447867, 17432552843180512227, 17997397548321189016, 2584605983223651769]))],
+    [BaseElement(U256([7098601676089547670, 5521949303822443681, 5208113427518025328, 977693280141027366])), BaseElement(U256([6901620058731371195, 5199407708659629867, 4938867627749075857, 243016210772021016])), BaseElement(U256([6021410085055475380, 8463280582141272373, 12761089109883155564, 2388910095709708200])), BaseElement(U256([15129461320121750010, 4351200683793285556, 17051205999030672405, 3059419362032483100])), BaseElement(U256([693166413136724497, 17497592054229433081, 5758533208557655309, 2633584568128154916])), BaseElement(U256([16019989996255077422, 4974955202484350098, 10094875310446678008, 2441827723405253236])), BaseElement(U256([3952884773414650745, 10978006431413572658, 748946681475177796, 1424908487808711997])), BaseElement(U256([18011705366582503008, 4442424018938832829, 5398915997717107871, 2478284201977167144])), BaseElement(U256([15731036572517040337, 5945092496332966895, 14219063660041933538, 278846074213660615]))],
+    [BaseElement(U256([15636395151033945169, 13796770589755021235, 2475808744332969327, 2548947319897243679])), BaseElement(U256([3664388067738690999, 14021933294848161204, 16254892411717229772, 733710058178001124])), BaseElement(U256([13334466099373540023, 11579666730441589046, 11246108567098411709, 2655423561165736860])), BaseElement(U256([16081126960356016224, 15403166810528108000, 6509705653356537743, 1637436877864078048])), BaseElement(U256([9800611756998028504, 17720305782612276955, 9888762900987408469, 903815143076977044])), BaseElement(U256([4730596429070394860, 6900323036490064755, 93340309846711409, 1619694877469426027])), BaseElement(U256([5661569964060330212, 18025885769472080175, 14404658735177179684, 359456386174234127])), BaseElement(U256([14858936078314926969, 7574329001527718765, 1149904913228093775, 728691542429539236])), BaseElement(U256([16944779019007967624, 11237473380545179689, 13051152212489828869, 190340738738474001]))],
+    [BaseElement(U256([2054709027878893109, 3095870252551720056, 5579980739110293514, 2513245556874879064])), BaseElement(U256([4537527141867013396, 537233520202586897, 6814464849244150940, 3116994873066437406])), BaseElement(U256([9752158831071444101, 7575014438818363894, 17652045773916056716, 2066080036887008175])), BaseElement(U256([4081130849712815409, 10453374904501461148, 11595085017564365393, 739117070922992400])), BaseElement(U256([4721273196308721122, 15469991024271299911, 4369359450371866580, 2065510738792794256])), BaseElement(U256([2173064138250859571, 16755267277899912003, 3459472813089445735, 2436994576440271478])), BaseElement(U256([11658030317930765009, 9135910926875480646, 9642834238988476163, 3380114774980730883])), BaseElement(U256([2717799574151270080, 13663083796117377167, 2780879402627259543, 712046464810153856])), BaseElement(U256([4111332630068242905, 11499596327396040374, 3178961570218808236, 1677693030681959338]))],
+    [BaseElement(U256([16587188912173588130, 6125523910500670312, 2423298401506985683, 1172928047269418113])), BaseElement(U256([941258650841284527, 11906554583817601724, 8248027151087269033, 576965765122134840])), BaseElement(U256([14956532609189088061, 17146523153775648753, 3135335279767303742, 2257729007836114444])), BaseElement(U256([1150068390937837112, 943813709881208332, 10446813409832236035, 426999107614972358])), BaseElement(U256([13018278315918944879, 14441427757439925392, 11194471816861236336, 3486890359251081757])), BaseElement(U256([3444219240673840453, 5306270289075957113, 15887402986899774991, 3071843121045927234])), BaseElement(U256([15590691352133725138, 4648782864572312308, 15675883392726843530, 2206035257463616575])), BaseElement(U256([10181194037690012624, 12397144915693968181, 6471124321580918097, 2579750518125103162])), BaseElement(U256([14485676993186154599, 18366783352805346117, 2039365642244085708, 926167359851090501]))],
+    [BaseElement(U256([9511270341815967756, 16099313418144408887, 12699843103621466500, 396054946812199712])), BaseElement(U256([858946843455445263, 1610531458802970341, 9700971848308158001, 3117234750244420642])), BaseElement(U256([2947394714112357278, 12141267197446323564, 1996368057976876696, 830770654700637493])), BaseElement(U256([3256066785837091677, 1416915498496512541, 1009829941740780299, 2716527019280553408])), BaseElement(U256([4783837898354975989, 7727245756447515556, 8401133278705996032, 1090086581962994997])), BaseElement(U256([4344596007674126992, 10667424778824041516, 16486715922583276545, 3462944542412428552])), BaseElement(U256([845239560078514750, 6372073206821556760, 2935882497277006994, 3350596831397002910])), BaseElement(U256([4780226620375629749, 4181433892224095530, 13687741247078774873, 430749767335358637])), BaseElement(U256([440165704137701480, 8291028792864400326, 11957992508327216658, 324920806952085686]))],
+    [BaseElement(U256([14172735044049228772, 2037167871060764353, 9869635184562089689, 1606691356895720863])), BaseElement(U256([13306995662183748978, 8158627335179587206, 347787492397175358, 462035898685686843])), BaseElement(U256([5880163078251698499, 11758481376897621315, 16490444022729363233, 3035817116274904637])), BaseElement(U256([7601216997499130801, 4982079708836396154, 8285144750349446354, 2079628468687001186])), BaseElement(U256([642694544107644235, 13137586925846272104, 17274588608555601024, 570068710383969893])), BaseElement(U256([8094085907954665885, 10085895786720728937, 18350317414842479846, 3013588654229592754])), BaseElement(U256([13051598195811133718, 9332291280147325936, 162902390991690136, 1785527355406846801])), BaseElement(U256([7997445391599000964, 13761493074168631349, 14614002368112272177, 1362590316481124771])), BaseElement(U256([18327144190718329443, 13952354108059949579, 4206227372137556381, 1530023016140077542]))],
+    [BaseElement(U256([13251341390185565992, 17362028446140774445, 4496867909417019319, 108708080404443484])), BaseElement(U256([1591185052648749786, 10842571640016771219, 3464731212606944993, 2093395019085217151])), BaseElement(U256([55539682883531361, 3804341830218499712, 13239176433747562129, 3469936637231628233])), BaseElement(U256([3034526966493798963, 874749849163107347, 11084914208075518019, 64968883662484803])), BaseElement(U256([6009329381373296281, 3520595368561544205, 12203751853973702320, 1794970008093327572])), BaseElement(U256([1998431692334736549, 18156215269359637419, 4246773875863340236, 1821458276906410759])), BaseElement(U256([2487842091433730058, 15897787455643211637, 13688125476574657566, 2136129549651899869])), BaseElement(U256([6576628478051210259, 6000518615200402730, 17378852993181100158, 2692254911520366498])), BaseElement(U256([18393897908055012510, 11740781831801751278, 3012472766448386377, 234382705732866787]))],
+    [BaseElement(U256([7343752218434131818, 9422026184474912269, 4525922809150864952, 422120709057445536])), BaseElement(U256([15933892991488053547, 17326528287271250524, 15147824110577749585, 2240001088565068427])), BaseElement(U256([17376377462416253904, 2351156386607283720, 9540004853000076072, 2567806974343468174])), BaseElement(U256([3528727601188900399, 533542108308829810, 1149990260879137080, 1036957137384536735])), BaseElement(U256([4915338753009205844, 11205214744817114875, 15887209651997229077, 1416560651939668922])), BaseElement(U256([12148865155989969205, 10596625863540126030, 5304250762924169590, 1929954648007503948])), BaseElement(U256([6416983404761099931, 17268630879731387336, 6624124062884013240, 2059592797437036485])), BaseElement(U256([16548383873878104575, 4738990534927964130, 10072336558362216777, 1132769240351106340])), BaseElement(U256([7882112005042707222, 16741956382710634382, 16419572081263687229, 1409332586919514002]))],
+    [BaseElement(U256([14302088133466766643, 14819324793507391187, 5184972180453912005, 246457648239629501])), BaseElement(U256([4818018145436268779, 12768962191572516948, 4260195458033840463, 3294035799689331567])), BaseElement(U256([4585454999724566919, 603804137804012282, 876569636951629154, 2607390446917315197])), BaseElement(U256([8044467095712598985, 502489167269680560, 14368969363396274096, 541368098018194916])), BaseElement(U256([813081767562397204, 6790971577422632855, 8340820816990733276, 1607386851133600655])), BaseElement(U256([6798354205204667563, 8784830559789740679, 12829531554223585656, 162994933362861568])), BaseElement(U256([15148105591639441927, 4857043161952738260, 11062187378787680372, 1484184022623494041])), BaseElement(U256([1715181382103103258, 5479535565407559949, 6797298192799136117, 1265306333776101046])), BaseElement(U256([12135421074503859320, 14222896190906095430, 11846884004761818775, 859709562541248322]))],
+    [BaseElement(U256([14288102150813495884, 12105583405613162181, 16946653055401352253, 2604389275550258729])), BaseElement(U256([4857464644142255481, 5734652581408418625, 5145834846954130663, 3469027405951131370])), BaseElement(U256([15707314201986387980, 1085146157754412560, 15229449081895676889, 2030531098822921541])), BaseElement(U256([1239687869895430344, 3885329676505188444, 12929784633008575172, 3807653750302390])), BaseElement(U256([13605622184604719552, 6594598068564105746, 1968132046856685707, 2208364879020619137])), BaseElement(U256([3631268642663758737, 9225728231189792125, 8438069905322840975, 1532556271495851544])), BaseElement(U256([13666616999713511009, 14490708762791132870, 14041335524948355124, 588082762352889389])), BaseElement(U256([11129265188712750597, 7660917076205819865, 18333108041835182647, 2117961317270082234])), BaseElement(U256([4980168213269187008, 14727039904721964058, 5767990812619499978, 68494831441354351]))],
+    [BaseElement(U256([16089222791792583573, 17275274219929298952, 6460861947932073886, 521384487726095350])), BaseElement(U256([9942805533353979560, 7550581040792895195, 3779701420067666832, 1219201231197382512])), BaseElement(U256([5837330729309687340, 6967278936701312421, 1583922121875971919, 2294136555046055304])), BaseElement(U256([15077875262376367106, 8916931663623939021, 16891356710088309056, 2595448173699223091])), BaseElement(U256([15004426036806393862, 15299866841759891672, 18247950834100998068, 408510856329235048])), BaseElement(U256([14960840792357620094, 6721853500601559922, 16588742083537700784, 2420411876706648501])), BaseElement(U256([7789744565915381068, 3103211219122867408, 8221052202772958013, 2135359582859513012])), BaseElement(U256([3663387382530892596, 7168332635422124941, 7638599806726671893, 2095875967292874428])), BaseElement(U256([4014375386091053163, 2324396526073212731, 2745898710452222558, 2012358890221533546]))],
+    [BaseElement(U256([7571085803123760967, 1756080493034574192, 10856397812177062001, 1786469036723865658])), BaseElement(U256([7436892946293390900, 13527354421515943782, 14290741602910124813, 176554662021778025])), BaseElement(U256([591849370156574719, 8007695404358339366, 3923261319146109017, 2665048918553332101])), BaseElement(U256([2272441836502332671, 3203907104051235305, 792505681486155963, 326361906649866667])), BaseElement(U256([3702992164008878111, 4921873801383485801, 11679252868645152528, 93301067815096984])), BaseElement(U256([12929626809909844959, 9224358244240576137, 11530024361121286797, 909936276782255016])), BaseElement(U256([244586857443792334, 18209719566999131815, 11674159845059414850, 3419124848844916970])), BaseElement(U256([7252015012015616035, 15119711783404042606, 6621610310417510096, 1603268093063957702])), BaseElement(U256([978428607365877134, 14197300917091456201, 16725232085593286326, 575810009653828023]))],
+    [BaseElement(U256([7245751362022231131, 16467885936703660665, 8667461089544468930, 955909557033697380])), BaseElement(U256([4742176187359324637, 13525184673355701450, 1152958020979372290, 1084295129361393863])), BaseElement(U256([14995444762487409179, 4382705332712515148, 347686061909178700, 653936095518891995])), BaseElement(U256([16892029944742078593, 15982557560861545898, 12148605290623337876, 672574280975058914])), BaseElement(U256([14407131180657293838, 9540425805809669528, 17168422107447067843, 2983545921300988463])), BaseElement(U256([14050783553162018890, 14652342938271496704, 12777935545396344782, 971427096290565028])), BaseElement(U256([16065003656737384523, 13193455058067168567, 14088757683479198724, 2308935815809292337])), BaseElement(U256([9362506700396465823, 11209094632268925068, 1250686904390492284, 938036300080734215])), BaseElement(U256([6586722992235918746, 2121274457325437275, 15818551367832734476, 2229211894388686658]))],
+    [BaseElement(U256([4757090102808604232, 17931505377861014510, 13233453385803801459, 759425967479899400])), BaseElement(U256([17160048482382550086, 8766276156799803773, 2517952310608197572, 1004753605477743881])), BaseElement(U256([11610873440457026214, 11383192182280679912, 14996582953867995675, 774289310161739021])), BaseElement(U256([13604291292607143993, 17999652817254124567, 14706477958775967882, 2002028822757735572])), BaseElement(U256([13535286010306816683, 12181773082865979616, 15135669115169710212, 3062881351950916671])), BaseElement(U256([7904339726816627812, 1441970157523668338, 16675648950716151956, 2759631711207150075])), BaseElement(U256([17103752511390455655, 10474652973947839956, 15509428458806901434, 1151468631236681991])), BaseElement(U256([14633360267119674476, 816521753089597267, 14216905588230409718, 2478147160012862239])), BaseElement(U256([641093417045641381, 14931532350393053946, 12672757056504267353, 2823622329024143648]))],
+    [BaseElement(U256([4047938934067201334, 17335150590590887295, 4377370611180731992, 2132360285490773918])), BaseElement(U256([15121308066348803581, 13788850457200140153, 6131907225697658905, 2557505018213203697])), BaseElement(U256([17285615263916651420, 15900718696964750357, 10106616908872324393, 1671485835589664499])), BaseElement(U256([8050683277034021205, 400149354448688013, 9043297928331221238, 880494057804908882])), BaseElement(U256([11007135964433922792, 18090645494683651954, 13533198229567680421, 2776954131336329334])), BaseElement(U256([15785877432123304795, 14830772450154812124, 7277047633944954756, 2102251345891264348])), BaseElement(U256([9828674286736413341, 13275994320829280460, 17571634231417301492, 2281552027750232099])), BaseElement(U256([4053026113565297075, 3880419312735601315, 4067588538934441293, 2189919726866348045])), BaseElement(U256([15095987229519267168, 202016168525274874, 3767890712396954856, 2267755609627976213]))],
+    [BaseElement(U256([11422827302525962584, 4580709715316025412, 8024333239721727302, 263960395023917102])), BaseElement(U256([6711697292446621295, 3708072628902119858, 1014730208628128129, 782705607612051113])), BaseElement(U256([16963946256466955723, 180960027197855848, 11511944205861968710, 2275920815512949472])), BaseElement(U256([4502755985878187388, 5862590517065526245, 4719689159935367786, 2993774866530905773])), BaseElement(U256([10102903437745146728, 15441697658234984882, 17040104365372927855, 73537645920954586])), BaseElement(U256([12546746137277681791, 17860987000674191487, 7297127535329394017, 997398303565505029])), BaseElement(U256([13082219852545211725, 14796777185070436057, 13182846264101080565, 1757442619319890266])), BaseElement(U256([11871715011790552802, 4571427214966801897, 1515749675828293163, 3129884516687964334])), BaseElement(U256([7366506118249751804, 3375216248443417555, 4568768423185461750, 2562777685316406310]))],
+    [BaseElement(U256([1525981003902747574, 16059942627786973758, 16360915439646163969, 2013958835164903119])), BaseElement(U256([4857998499339643499, 10901653141495271282, 6541167285743872979, 625536142718872002])), BaseElement(U256([15699024646417153069, 9138306580170092668, 13863250598524374210, 3444621280973802843])), BaseElement(U256([16364687936817330981, 9815921277125654577, 10237513052534068911, 2252686033066389045])), BaseElement(U256([5910887034635538805, 10338591116608268363, 4412088772713660795, 2711943144791946797])), BaseElement(U256([3969476561258570461, 1120877370872504882, 3942192307198432753, 1487326764350382295])), BaseElement(U256([12546039431407574464, 10284026045541077922, 13292549687403098900, 2984839443361224537])), BaseElement(U256([12249345629612066098, 8753381556101616504, 9608086031627022873, 1446792764738018694])), BaseElement(U256([8878018256169114456, 4277498767911890521, 10583477658992421696, 1153178092756830419]))],
+    [BaseElement(U256([4188571747866386321, 4195984190942842748, 13235864824843173277, 61456667776024514])), BaseElement(U256([5740433007167244765, 3491161301610755674, 4148320997426342341, 3119784078419433505])), BaseElement(U256([1468313429273208720, 15952879685569242993, 12363642930227796357, 1827435473796306394])), BaseElement(U256([11684884704098387580, 10353565927802637763, 17843457733490502735, 655710796001448716])), BaseElement(U256([8924067616515692819, 8071433930565951952, 4462702075093529386, 1110881617756049816])), BaseElement(U256([13169859555419467973, 13882159412906127649, 6118853353497371635, 1297061948842348289])), BaseElement(U256([2861089109156291505, 6333416385825218799, 9334548823866810930, 1943188045179786183])), BaseElement(U256([11902365656269879194, 6496151204884655939, 17916719229231723445, 3317792390962570955])), BaseElement(U256([10021113923378240711, 2319073173594425278, 14502737966537463869, 1602079586373895862]))],
+    [BaseElement(U256([10588926212707461000, 14326079067682527135, 7201948966082422536, 1026202004098164192])), BaseElement(U256([8569255392359460553, 6174649226525808587, 8889666165088071656, 1750079307866877089])), BaseElement(U256([8830211612337529135, 7106789774136797121, 7643680633290703758, 3223238915245993587])), BaseElement(U256([5753750563734580791, 1874571861872696663, 10246928638737384777, 284647049720820180])), BaseElement(U256([12316122280493868287, 12688695111087272201, 15310549180979028574, 2439656056189149054])), BaseElement(U256([560438568688048991, 16777851752730396800, 11242294887119339982, 1584558204581774363])), BaseElement(U256([6741258813025302897, 7526209442062594433, 13673360477240060968, 1692137019655918171])), BaseElement(U256([11864909704633114665, 8460394822531191027, 869500365166095011, 2513236121299514504])), BaseElement(U256([10380422130252268612, 16469622917981922394, 7917599010920373498, 2791106949640943731]))],
+    [BaseElement(U256([4254307454704715137, 6234713750989462469, 12285542820061186112, 3130668042736175473])), BaseElement(U256([9634304390613429142, 1904627850478703995, 14479713433055651090, 2783180579643380514])), BaseElement(U256([6956490861609675431, 6670487589278977001, 3403661799436157215, 1844271251792247837])), BaseElement(U256([13016705953545138596, 17661532050235768579, 15376696999925771432, 3340579511679077358])), BaseElement(U256([16078532671519638458, 1141169082480615007, 14864744339790556117, 2140708376565388566])), BaseElement(U256([12105491597858963455, 16103334645009757334, 12624060867862498597, 591897034789325003])), BaseElement(U256([10165318122931161173, 9953096632919272829, 3808158904440260461, 333800147822048225])), BaseElement(U256([11475852684539129270, 11421012035166709776, 663403088746844232, 793401435875375946])), BaseElement(U256([3347803145246116316, 1366688400069814991, 6684695000310914322, 340979658538014227]))],
+    [BaseElement(U256([5374929395811666790, 17432831441988845734, 12235281932953684593, 37748707857542017])), BaseElement(U256([11368846305639639033, 7631388256323977646, 2093910812909170555, 3034130541545890510])), BaseElement(U256([7644662593113576490, 16480277199753685689, 11392768069647758626, 85106623846003057])), BaseElement(U256([4178416100246178407, 12558483828127513646, 13260817807951541483, 1656671193710261570])), BaseElement(U256([7793292684054745869, 7071799397331620483, 8437765731698383013, 280421765887799056])), BaseElement(U256([1965933958344387039, 1417842792683466579, 9923540829578843698, 1059610143755303759])), BaseElement(U256([10085935937080904327, 5830488568960907911, 14730708814774970742, 3400572709421401666])), BaseElement(U256([5295488835779746967, 1468036606062328408, 7231118080006317489, 207961447489837767])), BaseElement(U256([9967893996184327787, 12574153655881991382, 17562590851099945433, 1829171960702458469]))],
+    [BaseElement(U256([13008400934910787323, 17138517403732021432, 304893506164786882, 1641925919980336562])), BaseElement(U256([11411181984914537884, 3398178126832779977, 13611053765198401322, 2088301792426159708])), BaseElement(U256([4952391068970070061, 5700728946979579308, 15267228580225532522, 1914440128355919271])), BaseElement(U256([3492505969393013755, 5751811825563712874, 9528049837847248142, 2700593003226716938])), BaseElement(U256([8931001083953977763, 9014431758306482819, 635673292509861728, 1287315875835414606])), BaseElement(U256([8961340115151407642, 13808987027546961990, 4705256876401904599, 642603458557681508])), BaseElement(U256([7068988002719293945, 6709714587483596404, 3898511107774278851, 2514822650871764701])), BaseElement(U256([16251121149820732186, 1911347828307632398, 5389454063023610438, 121617747761262385])), BaseElement(U256([7371902897222717169, 16688616810703180921, 1653781019762124143, 604616057555261560]))],
+    [BaseElement(U256([4834494698660890708, 10398088664648255489, 12185685858976831718, 112963346479198304])), BaseElement(U256([16322094914484272229, 5295202809733556338, 3145940636213692273, 3287092989336235650])), BaseElement(U256([14987821155124592232, 18398636884254096963, 5479223763947387373, 235962158875515699])), BaseElement(U256([16881423677964693639, 5701453139185088498, 8801224198170759292, 202949154031095792])), BaseElement(U256([13974297359663951002, 8989226568324108510, 12409016106506487772, 321264424280796783])), BaseElement(U256([12518943070217649064, 8367513036328295724, 1159482094607542067, 2455598779302322372])), BaseElement(U256([17506400419935398810, 17106519426336660108, 4805403493795316378, 224622713613255590])), BaseElement(U256([3554362118513131733, 8144935047741541565, 12808325364805783696, 2118151388811449391])), BaseElement(U256([13379597716162267876, 17750256990098865576, 13578173940309096240, 739107232739966504]))],
+    [BaseElement(U256([8177628468544376572, 7090800144256227048, 8022974722781792120, 1967309407054816987])), BaseElement(U256([10086203375970967036, 13211211141041397218, 463019194394224620, 681998561455758109])), BaseElement(U256([12015025596308486415, 4953787670217270067, 13699141237861525994, 970829923895752195])), BaseElement(U256([14995385570420844436, 17814880884704225449, 965451801527250311, 1028450687413330787])), BaseElement(U256([16412375806761170602, 995685748936114116, 17298385922972427332, 1714471016287623658])), BaseElement(U256([16416334348156843739, 15197152563092327757, 10925932834540801899, 3455497989977506687])), BaseElement(U256([8855414927978951300, 3104335043823426114, 2716654196717471834, 170880850182372240])), BaseElement(U256([18097234757970272841, 7598279432917155224, 8551402714843650831, 369238810351265147])), BaseElement(U256([15102134987066762713, 15160800693831934874, 305912679361790672, 3083024633647038883]))],
+    [BaseElement(U256([5917585080168909103, 9270017003189408996, 1176749460360807531, 2979797540967259971])), BaseElement(U256([5790170108881735871, 8217296249310712141, 3302665845553638759, 425340788927372914])), BaseElement(U256([17167843346441169649, 18326819860582038154, 2183634625123636609, 3341961264518410772])), BaseElement(U256([6900072840376614940, 16317747302394249024, 15592221991113341568, 432491167522243132])), BaseElement(U256([14806350529615003194, 12037444314033413133, 18249368732262347968, 2927313624018001326])), BaseElement(U256([15661117809296934589, 9101215501262017096, 761590367741635792, 966440976157866411])), BaseElement(U256([14497145443429596123, 17006649432205128620, 4232731890715043302, 1296163060821009059])), BaseElement(U256([1878278135908142601, 12410027191614546527, 2327662291896817864, 1213644287149568901])), BaseElement(U256([6749244861919707040, 1043060846929478364, 3395680914383884762, 2707116683173629172]))],
+    [BaseElement(U256([5003992738507928318, 3729608773244407466, 11752132078319150828, 2907095321381690281])), BaseElement(U256([18445606233440114088, 993173048217253384, 4588850257702308253, 1309347021459127651])), BaseElement(U256([16360023375566202183, 13330465355488998103, 12862857345126167099, 3131342909984574703])), BaseElement(U256([3673770604837527303, 8007475533397354487, 11242646754136712254, 2951456382606559084])), BaseElement(U256([11858046694617654765, 2385820651797949876, 9708029947880132829, 2812155075412396970])), BaseElement(U256([4358200755821063857, 13702386190167040366, 4637124156650341490, 2596633191089586789])), BaseElement(U256([6741845954119802761, 13217169922508512732, 6313652951157854201, 1446884835066708821])), BaseElement(U256([1693227617381051554, 978405594282168847, 8055613877545957782, 1917969756538927986])), BaseElement(U256([1413881391775839730, 15254749650073381984, 83639325590663821, 172567934000373160]))],
+    [BaseElement(U256([14152442374663970425, 12893564005759890334, 15446886582021211822, 2291502769271087426])), BaseElement(U256([1904111757621514596, 7635586878077194655, 2471904243529633231, 2869337484336330546])), BaseElement(U256([11148298819459161110, 18410476961325476717, 17619382595456660782, 1697954808274402935])), BaseElement(U256([2925773355580155530, 17381127975429633961, 11268444376615265987, 3137141899690890679])), BaseElement(U256([5766149791262499022, 17132098745258678060, 347139348081158993, 668741252362370120])), BaseElement(U256([15475058093216579067, 4276962720386224636, 14878821880627455410, 1568807387899627760])), BaseElement(U256([12905503076048334262, 12417529091242062280, 13182746036984412719, 1165148273749802215])), BaseElement(U256([7098625484031128372, 751124536014386245, 15761393316762114794, 1197511413752496971])), BaseElement(U256([9065056433519809684, 4117272117887969550, 16029304076610861851, 542668598288065858]))],
+    [BaseElement(U256([12231735364035530417, 2549802993546654990, 13972890570138689747, 1180812774370686104])), BaseElement(U256([13557162668521571187, 6887870402904682577, 13014189830282622285, 832764117567297091])), BaseElement(U256([893152137645464721, 204280906283164431, 4230337221828567572, 1354003663104300873])), BaseElement(U256([6323673780710618845, 1965752311241310960, 11419435222723403492, 2999198414976463450])), BaseElement(U256([2111036746175489177, 4058465970602363275, 9794895257734180928, 2451229957583599397])), BaseElement(U256([17755753428561735456, 5503288793344581594, 3875493900428500099, 300618615685258242])), BaseElement(U256([11373038111508546451, 1207900355432840001, 15191520011685437094, 3077170351804407647])), BaseElement(U256([15939931275755706293, 2881749041711911826, 15447601184923628896, 2362210104196626819])), BaseElement(U256([16942293639174445399, 10496165929596887376, 10998152544181472558, 1729746168781343741]))],
+    [BaseElement(U256([14397347603896994169, 1730030088938024497, 7777130663175826956, 1452961072743302411])), BaseElement(U256([6727462232590671938, 4094890411561075798, 2649710724214308822, 3100966532756602860])), BaseElement(U256([9358758005860463719, 11225014129080246125, 1796815661103368033, 444703880724252767])), BaseElement(U256([11569633207010448275, 12858944985805781305, 6148406900627039225, 643294297707713501])), BaseElement(U256([8460026830491811729, 18015063596926071122, 4717457848573863719, 1636163803433325741])), BaseElement(U256([8053511211415143124, 17530642802973816138, 8374247585942683941, 3178420961796193451])), BaseElement(U256([2448295306825983552, 3777940545605276884, 2242689621624531788, 1094845913027829430])), BaseElement(U256([11182402219229401460, 3117390901359515109, 17358011794926401530, 23169306937543561])), BaseElement(U256([2456192129235120710, 12290197175478284822, 7927252573725793776, 2136966925854665232]))],
+    [BaseElement(U256([11843918988345357142, 2339054376040820093, 9163238065720791266, 268441247572620470])), BaseElement(U256([17522493735142450544, 2473225952850764812, 7014695429242222725, 2698639842143278308])), BaseElement(U256([13587580851038404095, 4746604010189654965, 7459302121930480745, 3273045665114687382])), BaseElement(U256([6883270747551236944, 1469938246460840863, 16434322413253811199, 191475315186959445])), BaseElement(U256([11655553339044458336, 4408303104969279863, 5626830272363677096, 47047702391562266])), BaseElement(U256([18083021142627892591, 561817653443856503, 17913026540559298354, 1719195446329565723])), BaseElement(U256([2958997713373208840, 6877154533275280818, 9151685510926102967, 907739163353178800])), BaseElement(U256([11793354194713508648, 8437227776006572200, 3581091609096471071, 2986704609342630217])), BaseElement(U256([15448836486182151090, 13932846094850825894, 17711181330476349942, 2626481519481474922]))],
+    [BaseElement(U256([12388311300627301719, 17822816404764674937, 12882631823077022276, 432294069581596335])), BaseElement(U256([7066925252217667246, 8694829987469720948, 503829497380472351, 1184662701415182693])), BaseElement(U256([14062056064837499578, 16172083502872980668, 1114776079787423697, 1075188056578352729])), BaseElement(U256([17902736358401821941, 14414799082848482390, 10886020076067034500, 2501980791270999294])), BaseElement(U256([1218545924802129451, 2428218400441642035, 12115729721750920690, 2490042099627662904])), BaseElement(U256([4881830538573212882, 11555430221730276947, 4294356683397676110, 1887388399888514458])), BaseElement(U256([3300178019700879775, 17746146712761549765, 10584087521445562708, 3216144670442384413])), BaseElement(U256([11138844478409973598, 6217385203464925449, 12676522324781455543, 2492533771157639930])), BaseElement(U256([8425152746362220871, 15449125076884951142, 10398914609017001470, 2080432747818069415]))],
+    [BaseElement(U256([5141812026146728215, 3418443653222936879, 11371788066641481892, 1963888977166430934])), BaseElement(U256([1600146052053734148, 9740422849016482163, 16559670241718791198, 2702763148693107102])), BaseElement(U256([13850474482419864775, 15727308875124351340, 11340571854787870421, 673196098332140415])), BaseElement(U256([12832120046605904351, 11451886829119261920, 4022229904278124145, 1743216046344339945])), BaseElement(U256([1139045128578847876, 5621513401518592420, 983284953956392024, 2974827886098954560])), BaseElement(U256([12729225934701344386, 9993845911401210077, 10419420526161954006, 1161777144097521053])), BaseElement(U256([15769264708187814734, 4289967216271925065, 11394757096638754160, 535176849396416807])), BaseElement(U256([11139159624886593140, 809764213415589227, 4817276490554300778, 1323061378792555077])), BaseElement(U256([11542986769225757370, 11926028300372950389, 7724250761115887543, 2535676773525856286]))],
+    [BaseElement(U256([9838914610283500526, 16207085948651250321, 12268417983101997134, 107043972174895538])), BaseElement(U256([3351159335968067837, 10271980251717874214, 8869816517874090105, 1306186580720941819])), BaseElement(U256([12574113324126203399, 18048366363414885009, 4764852335696860741, 1311822539139491704])), BaseElement(U256([15384369690940460136, 9175114073870860329, 7274469820648536481, 1336644804140488579])), BaseElement(U256([8615630450611872072, 10794014418102843691, 8121018179486284495, 643018204182626435])), BaseElement(U256([17512421624953648044, 7292112346541034449, 12639565090228686797, 2174199725684644331])), BaseElement(U256([13858702635324983178, 14767211370826286199, 14690897221952786093, 2973130815581874020])), BaseElement(U256([10657850643548534185, 14717951342335476057, 17765328160486004040, 1719149376465122841])), BaseElement(U256([16061844544944758193, 8388128877019027840, 16632870876292768002, 820880028126420234]))],
+    [BaseElement(U256([10466162018687022871, 16616512620123296880, 17861296782448556998, 2366282834372645445])), BaseElement(U256([16077052747388600925, 4883395891349572826, 3903470215241552023, 1788489871811794956])), BaseElement(U256([7206340200150461808, 10135409642468067685, 8059931229363556646, 642100709521505674])), BaseElement(U256([2375403416192408575, 14068251077884509063, 15338603213467257134, 2813230273133668839])), BaseElement(U256([425217946028412450, 10259157427427311798, 1731609140082974774, 380062587293110462])), BaseElement(U256([13035400274759408606, 9663452087969495595, 1805100425858711255, 681415838598965551])), BaseElement(U256([15080289833856955116, 2162443661075544746, 17034806255844985391, 2193307321336756070])), BaseElement(U256([8077016572982151439, 6565170380271313496, 11314352911053966426, 258744166853463996])), BaseElement(U256([9975854687708163561, 4124027610822437535, 9173156842885000418, 14245246297343182]))],
+    [BaseElement(U256([13193428722796257866, 1790944882221003893, 3664387790083621083, 2174866348956614901])), BaseElement(U256([8759231999285205337, 2729750784742070777, 11049790604616659178, 2234488611392593048])), BaseElement(U256([913730254540023483, 2845179769406753544, 5424685410157684692, 1269362069455664541])), BaseElement(U256([11662148348486001813, 4896972402177281541, 16121238739656318188, 1464630303466503606])), BaseElement(U256([12181041871196270911, 301705956352563542, 13463339402350123762, 1790382521717855802])), BaseElement(U256([556440909589609961, 7883390718241056836, 10271590762024237660, 2008026350563247368])), BaseElement(U256([993551542394326399, 17002933957667278479, 15152122715831604756, 437379012534664742])), BaseElement(U256([17009717276346329239, 3070708095308377043, 2619203911571560892, 331486282084707551])), BaseElement(U256([1123423366664829522, 3636032962637369190, 2439655716361441394, 2550298902794775889]))],
+    [BaseElement(U256([10209482661139513827, 10043166170127345965, 12460099801415880918, 1411744022560143564])), BaseElement(U256([15467481784155319171, 13811496165931841696, 5747025732668834761, 706498870871253063])), BaseElement(U256([10008494495639292121, 10603916861495756242, 10309914690088394215, 1865247900526129808])), BaseElement(U256([13735549817456983710, 10845851205949133589, 14847427540828960208, 2248904622990247021])), BaseElement(U256([13506691770890492766, 5640866158795691982, 14487676219135576996, 1452317193842956707])), BaseElement(U256([7964730837011991416, 14379224811197784162, 10851680319960001657, 1151867142320933917])), BaseElement(U256([9620133035556214643, 1758613432143727746, 10392405359142192548, 3244549350176892620])), BaseElement(U256([10326317305591322985, 12240625907777026690, 6992363250624954297, 2508952920783887649])), BaseElement(U256([7325904845109774050, 10784547110436999882, 8376607474365428004, 1772564666934636747]))],
+    [BaseElement(U256([14708254383262985965, 6326747368736190933, 12924813605346505157, 2330900383514076574])), BaseElement(U256([14808093273582627308, 14948834354397707673, 900636078637500392, 1471719959562537971])), BaseElement(U256([8774501564520734843, 11909513314652844665, 18307817739305408264, 2197844846930992447])), BaseElement(U256([15732600915129759587, 17020194659609720870, 10764393804829929550, 3198683857423760125])), BaseElement(U256([98191163005687388, 4378093913543427634, 7254589921261893116, 594942113318126982])), BaseElement(U256([14816900506549907259, 11880968736590472036, 16520334065797920218, 1313265693156020271])), BaseElement(U256([1648371767882536876, 9655320952761420148, 10657216249045144049, 93115671117843230])), BaseElement(U256([5700255692222581754, 13041978617412603992, 6225530861036929365, 3148101759721595870])), BaseElement(U256([13081110748804987083, 15385551544362006094, 11257773876540525132, 2919051936011300337]))],
+];
+
+pub static W_HAT_COLLECTION: [[BaseElement; 9]; 57] = [
+    [BaseElement(U256([17969608682636498833, 6325000101106672208, 9709166915019382291, 1439911355024459888])), BaseElement(U256([6313807457712556963, 15175098713954262108, 2874687056989359247, 3401973425808429486])), BaseElement(U256([9791090377465902553, 10844225261041631892, 9908315445136979633, 554598449427324573])), BaseElement(U256([314237480860131177, 11272221901827637568, 11883875272487088869, 389740336831945245])), BaseElement(U256([6286315360546372796, 7436169690561060561, 3302188220774834694, 1244486914647644359])), BaseElement(U256([6614641065872479555, 5417507418397885770, 6980001038127026590, 1530120157128651088])), BaseElement(U256([10070373382338896494, 14870248749192857609, 9369309078486761671, 366616926927274383])), BaseElement(U256([15510667879405977833, 13666144610949931918, 10454380545070192605, 2964579232856339674])), BaseElement(U256([10089448661734630304, 11843796517208302917, 5759924494001591494, 2037590259004066355]))],
+    [BaseElement(U256([17752893803426913876, 4457824631709457680, 8561564993498894892, 751572622294738847])), BaseElement(U256([18067968257658977498, 4958966799817987799, 16187679363071109796, 730483111456237322])), BaseElement(U256([2718170869129365484, 13075228190395691038, 11541081600389609212, 216266250550978104])), BaseElement(U256([4477546256386837184, 4880083690169370457, 11915498110358875165, 2406694600951038753])), BaseElement(U256([5761146728298303760, 13088577922559732516, 1867581504068910848, 543351286464226514])), BaseElement(U256([11786423919957947836, 3508765314127687092, 3639053508164675483, 3296146957063393227])), BaseElement(U256([3555509536186723929, 16242868200247302328, 10220607384720511336, 1120455496325372756])), BaseElement(U256([11081843396013279187, 15811041116373375336, 3987774353238977496, 1937886216096978132])), BaseElement(U256([6283531737450428878, 15354080553680450808, 14975533515579593022, 2465191473156027539]))],
+    [BaseElement(U256([820954649193278946, 7703380975264577021, 560038160828364258, 136809763844402190])), BaseElement(U256([8340241295709721065, 4180549224228918166, 12441025739149772555, 3065196473211907015])), BaseElement(U256([11932736070645470118, 7195304518787056095, 9617370872174964010, 1440003090017771607])), BaseElement(U256([6439681767681654404, 17561981835655332770, 770687168202688295, 1217299762633633112])), BaseElement(U256([10417853108999407940, 9962150196196824179, 6453971986516691988, 3469322286851943011])), BaseElement(U256([4033969954366997238, 7942419252791391899, 15103881491074003332, 794541181085333977])), BaseElement(U256([15970833807918954042, 13915455929317757750, 14624708331656371817, 120756576051489124])), BaseElement(U256([11934876677159736310, 15026656504702026982, 2747638296989839476, 2169619463425920541])), BaseElement(U256([5919369416646947970, 8300433397761189517, 16442058943693629006, 378759983928285919]))],
+    [BaseElement(U256([9491558270237217202, 739226912064347854, 4242083165075669252, 2027739137125509345])), BaseElement(U256([5268419046532646575, 640718881034494306, 3589365393983326249, 2448383332724526202])), BaseElement(U256([2507512155486931638, 14913930180422818181, 3438980327364633004, 2844043888497460004])), BaseElement(U256([11178339504032932331, 14600917626252215242, 3553547349641595575, 2194945488326292211])), BaseElement(U256([10747253325611380361, 1139834591696183098, 13489490888533817551, 2337511773059705494])), BaseElement(U256([12349306468058512759, 10247059875717196343, 4176694789142382692, 3136177840530603523])), BaseElement(U256([17477912721382246851, 187842467601605114, 2417128070802631010, 61312980351600918])), BaseElement(U256([2102681702748233634, 2029980526062297465, 771200582175358355, 2146929557220530586])), BaseElement(U256([2265690149737010232, 3804188809597705254, 3564279907866833705, 1946697405097010859]))],
+    [BaseElement(U256([14475279108025054008, 13879741036690539020, 15926089588978081809, 2558038340549641293])), BaseElement(U256([6859060926437629917, 4241475527488272591, 17778774186884046848, 886899342364588013])), BaseElement(U256([14840880605802487113, 11727670935933660456, 1487552098404818146, 780127988094287334])), BaseElement(U256([4224941794185061136, 3178895112960410263, 8328529610302897143, 1547502056947348378])), BaseElement(U256([17014111059730332185, 1207366123234272391, 13013985635844058501, 2133071420014157183])), BaseElement(U256([17528277202871568284, 11434241438821817184, 11594044044356550169, 1560078483464268360])), BaseElement(U256([12620596539059196463, 9031880843316150010, 4754773811377220864, 2705135820749626796])), BaseElement(U256([10874230440613702074, 5165011643590655924, 355233276615922665, 1014212572684918943])), BaseElement(U256([1837843802263681617, 4369676967362092413, 12681278841223156442, 3094523686893754174]))],
+    [BaseElement(U256([3904176013108888598, 842759175225336514, 10720116259422394322, 334310944924865483])), BaseElement(U256([564869027460180043, 6558696351684760322, 4880921061499036421, 2777692732183708637])), BaseElement(U256([13938611579713309469, 16789895714059359327, 11456927869071233528, 2629196393358257980])), BaseElement(U256([245877593560333253, 10031996765556872560, 3922766562965960356, 3380334854193299611])), BaseElement(U256([3151447009385216976, 11141354507143237886, 16461134556756002067, 1177718870171049621])), BaseElement(U256([5534323348354262937, 5653436970563739447, 16432076592830599654, 404175294879973610])), BaseElement(U256([11797258920207424789, 12002937749576731161, 9441936244945023568, 802340958173750036])), BaseElement(U256([16037841829316102697, 8168328825876221292, 11190586533941390180, 1422461202572328785])), BaseElement(U256([11364105118040045549, 12394327568754338181, 9703187303390145504, 564251407909251991]))],
+    [BaseElement(U256([6444339639594348713, 2485472986835669460, 6305029646254347755, 2428019128969177296])), BaseElement(U256([17613346564837636443, 6870925335661885298, 7740132960275112438, 3299104059436391532])), BaseElement(U256([14238422561440164023, 490031201912834440, 4220058429366353859, 209874010492950068])), BaseElement(U256([3232152649379694826, 5401331160318094515, 5683748685049215261, 1951045855883236600])), BaseElement(U256([17674302616932731521, 12036340138586889270, 14424661033677521084, 645887981036612253])), BaseElement(U256([13159450904673346727, 4232285181018358230, 2428625289697206745, 1579913474423354819])), BaseElement(U256([10267313062809447437, 2348724984852437192, 11153539529428037612, 1759917943392308102])), BaseElement(U256([11117707893040070975, 338518023423457894, 11774803977114161118, 2251600223773997297])), BaseElement(U256([13000134783053412413, 638514077827711314, 2927807680692303048, 3294349453358629973]))],
+    [BaseElement(U256([15356308672213969297, 12347132663471340417, 8638991103949261768, 2507830354462171772])), BaseElement(U256([15039374783042425436, 9850197303811838319, 7630765941196755466, 2064890635832858344])), BaseElement(U256([13306612529001775501, 10773172322935576845, 6651187109816802563, 2201367698560050923])), BaseElement(U256([8830308038069534490, 14827438712769136501, 13198705283549770610, 3000530608535720145])), BaseElement(U256([13300006473162935505, 17997136392093611706, 16976197841629354766, 2913678444719717025])), BaseElement(U256([12141126719900583902, 17338890289558344322, 5770407139424133391, 3240338856420193905])), BaseElement(U256([5166776775983418367, 8815963646364949679, 16427356730791236401, 1170687648728518264])), BaseElement(U256([17928229172763830295, 8803923622069256156, 13759976839485604140, 914404942933283092])), BaseElement(U256([3483668292413287311, 5423952390194886697, 12795789398596819368, 2260560285368100318]))],
+    [BaseElement(U256([2323245683394901024, 7577829594884785989, 4100521513779986424, 1677170595019712992])), BaseElement(U256([13653172007080493927, 4846772190386564570, 4753327665472451498, 1295274309475566148])), BaseElement(U256([4152852187755113418, 10760398837216717235, 13090214361747124617, 935638154906366655])), BaseElement(U256([16884018381349846031, 13166774241449325203, 9139668253566823596, 2847654521698028895])), BaseElement(U256([6734017343767535310, 13797464161266354195, 4015761431130260505, 2168954451495297646])), BaseElement(U256([5675060144798053215, 16062724719347658944, 15964015328136889569, 733932779238323110])), BaseElement(U256([15067543880087405548, 17668040785901444904, 14346198681556302336, 2313358337941083574])), BaseElement(U256([8450035674707552761, 963918789524323916, 17239706712425441286, 197986942544148624])), BaseElement(U256([9227480652616532278, 8640792810694896845, 14029405207669442194, 2882471541280905189]))],
+    [BaseElement(U256([12354397734963226527, 15560501121583469257, 2464463514153762162, 1104012648905737810])), BaseElement(U256([9529530884885563191, 6770422988207381165, 15289012964880794286, 731168711292662349])), BaseElement(U256([13606109006362878296, 16664762400781834269, 4495352541842400461, 2782645867879093509])), BaseElement(U256([2381314892207443944, 10528130131711852898, 16647089251820492445, 2936345865864481957])), BaseElement(U256([1904941071821344261, 675665850188658697, 16649394407424235015, 1390662177020745904])), BaseElement(U256([15512821042312963176, 17031264492555558672, 17023230719426810187, 2259720230477682806])), BaseElement(U256([7691734287510919722, 4521301396925869184, 9729308038470574438, 2838710048299603226])), BaseElement(U256([15416060803130910955, 2232855623765537398, 6940021552209231688, 1726777908640754066])), BaseElement(U256([12377355740920831123, 11757634387627735745, 4683875073538466714, 3122871692021136356]))],
+    [BaseElement(U256([7820204802047275268, 1217971231328540052, 7767503212913422239, 263432289532741843])), BaseElement(U256([4406467778720413822, 10518283316040536296, 9766141724299660782, 2928650739224893560])), BaseElement(U256([2768400392165123700, 1211891010377364565, 17159432911859679967, 1806931879964159244])), BaseElement(U256([3720704240576507048, 1764387069498664404, 4954666566995418928, 3186758740376914833])), BaseElement(U256([12189127277343792287, 6236837782286363297, 3852264267729502474, 1009898881139474020])), BaseElement(U256([11763776132579999594, 13435292676419440554, 15523793897642546771, 674175550562679301])), BaseElement(U256([10228526273072267250, 6582044113896124141, 15416274875457670191, 155149279008594498])), BaseElement(U256([7298186350299721449, 15168982505360230313, 6561264475893643844, 1165791500057837164])), BaseElement(U256([12822579316091544386, 9283960585123298107, 16414067932237889989, 1968758519933166304]))],
+    [BaseElement(U256([6968137030690049794, 10634337159482619900, 15959453688656579105, 2105524339648814054])), BaseElement(U256([9913619878224037363, 14146692361071823955, 11786936531477565958, 1849398524921393635])), BaseElement(U256([90938098300303305, 18158536862175022893, 2530288304594603314, 1790723497050809568])), BaseElement(U256([487204409662907831, 9102949253583085338, 17770954045507653868, 2169743826318295342])), BaseElement(U256([16058484955168075098, 11580021623435407427, 13702884982352063586, 2122549800222268047])), BaseElement(U256([17526993786002559353, 5004530360610342462, 11704943538931564381, 305677094834067734])), BaseElement(U256([16423374607027041841, 7032754554381221375, 16649360748250306284, 746029049536523330])), BaseElement(U256([1716902063384013525, 4927876736907761379, 15964394248313906373, 2147334288830799525])), BaseElement(U256([15674133571097181949, 10722476322732051296, 6339356035135780677, 2175494678126452258]))],
+    [BaseElement(U256([8333182278727276098, 13642059104082575220, 10043472872115367417, 2963914578002518276])), BaseElement(U256([15452487244213244130, 2394664915630858294, 17024557869470495357, 60616775068862147])), BaseElement(U256([12802658336433368705, 10605245314853824032, 11100381399025162408, 1250483651379294400])), BaseElement(U256([13486678335203958295, 1235900354647790393, 2981740342283348325, 2560153359355329062])), BaseElement(U256([10382067898354466202, 18371643931056397565, 548836731244546730, 1493455667552874236])), BaseElement(U256([2703476059259419489, 13540071536144696837, 17368643054615923427, 1138637499868000330])), BaseElement(U256([18265631740495983661, 7827972465076372572, 8862491895732067173, 1160752580806087510])), BaseElement(U256([14335802357239359079, 6609845208853744617, 10948293700276931852, 1249359079056352518])), BaseElement(U256([12626129603417739843, 8222583672849858805, 17773389907816735482, 896922108302434381]))],
+    [BaseElement(U256([12861304809486572540, 12893880927112442196, 6718850143316436553, 1571945283091052033])), BaseElement(U256([11478500890208018128, 11879923272644221230, 13254338213778106473, 3250817067531365103])), BaseElement(U256([15603988865764347152, 7242949444843080198, 6739895749928788318, 2105735090124627430])), BaseElement(U256([6438056271889512472, 10577092955905010525, 7767592881770889640, 2471296949391763259])), BaseElement(U256([8597987982718417338, 17934638816392744595, 16723468982821233057, 1070388484577071972])), BaseElement(U256([12151449896292018620, 4217990563735117360, 13252660768311744740, 3336342014061018731])), BaseElement(U256([10184768263322960357, 13170814909901215329, 3912091195650482765, 3290582213166915655])), BaseElement(U256([1035700757965626646, 6426938649675626405, 15842126901957596498, 566582058023568723])), BaseElement(U256([15207202911449056545, 9363099724799825469, 4747055582728757212, 1616375523966737164]))],
+    [BaseElement(U256([10863135229377030755, 11164854804436173301, 2736208484669929737, 791147552195800195])), BaseElement(U256([10714681691266313997, 17317800739015190580, 4763651032740556704, 795650776102360497])), BaseElement(U256([16737573999220505261, 7102755740213692284, 7930191801289211005, 772156241978416317])), BaseElement(U256([4205525003105048251, 11134438792405673507, 11713302403032872191, 1336051925665874347])), BaseElement(U256([4480790085174201639, 17861573078102773462, 8357970827216831768, 12223711802704606])), BaseElement(U256([12562969863416554527, 13062807674418673925, 4716344749594173175, 2306238486013221383])), BaseElement(U256([5426183028921949963, 15681019599991754637, 7694286296320931848, 1091743460461990681])), BaseElement(U256([14428195229991371041, 13173851248957829662, 5556590860841586393, 2066485495559043229])), BaseElement(U256([16856894305526053546, 11766987643909600652, 17267485840313085516, 1390656088159773070]))],
+    [BaseElement(U256([162924201752081702, 15770581558876249214, 14831531799508054392, 3024864865262527383])), BaseElement(U256([17069033001374839496, 4310119951612203781, 6815600389968608995, 1565573317175076719])), BaseElement(U256([11274799573536136597, 14736272876157088926, 8249896205593860888, 1667709500937744290])), BaseElement(U256([15779624588906764237, 18107048838778043706, 14305124189678375754, 476909439452841908])), BaseElement(U256([2061382055248887045, 17783256193086669406, 1746820236395174315, 3307465265944096248])), BaseElement(U256([7754115425307776629, 2059885560388789331, 11785325929117841368, 877443257849593590])), BaseElement(U256([6906051368433687983, 10739947362681101106, 7859047022455991905, 60001262942179889])), BaseElement(U256([8250307400010404537, 12962878516336306680, 5231317335274682083, 1542171521148692645])), BaseElement(U256([12365106679626475206, 12649497823201025978, 11695510097795664854, 1969852924766698126]))],
+    [BaseElement(U256([10158817833374405555, 4062910508750093253, 12226265111734117492, 1128727391902759995])), BaseElement(U256([7985706985289022518, 4718749290300929148, 17956304582018559148, 1932900820448498737])), BaseElement(U256([10504699179981438383, 14876369468033811751, 6845752741201934194, 1508910262868509039])), BaseElement(U256([752155196096990193, 18084854375810112346, 14514484296221491953, 2225544498557290111])), BaseElement(U256([5713860550429242252, 1184639320070881986, 4569893660430971047, 3245047723452760640])), BaseElement(U256([2112459260917778, 5180274737452996554, 8275476577049429234, 278805283586728083])), BaseElement(U256([11138070112364064740, 8927049641969006110, 2648118390810551341, 2084200251682808107])), BaseElement(U256([7025106466545083241, 4878154672405088765, 7744115270681556418, 552439055248850250])), BaseElement(U256([8261237844544671756, 15565156621352708767, 6656732346745848856, 462358789123417170]))],
+    [BaseElement(U256([13035751103508615924, 15777318214234573470, 16650475879056447339, 2621480724008189131])), BaseElement(U256([15301572086851963061, 6183789191279133247, 16642197017962882328, 3003907859136452266])), BaseElement(U256([4754856331879536195, 12267581475327105798, 12589068082626770661, 406047766732725774])), BaseElement(U256([15632396612198464308, 13683771887687013910, 8738193477755309662, 2927801448072786757])), BaseElement(U256([7599364792101874726, 10714140277970292215, 7079287600781122119, 2505398438608222636])), BaseElement(U256([12711580528140364082, 15104963717765835360, 15770019593943860706, 372401416210292181])), BaseElement(U256([17284918914434401838, 852580852026318023, 3891431423083363340, 2418219528702754001])), BaseElement(U256([2403765063572681799, 11767884252935146745, 9035672358641589790, 3227408673099322792])), BaseElement(U256([17540444779527749249, 1695935246248820787, 8668759011382145096, 1041871446109255555]))],
+    [BaseElement(U256([3333379491654279505, 872898536544515158, 12152080326256712593, 1915776286743101318])), BaseElement(U256([10167201847733650747, 6376259676049941484, 16156040366528132028, 1361947214612336104])), BaseElement(U256([342321876327019182, 915161170082900098, 1377462753231126555, 3023033814840142492])), BaseElement(U256([10612201847790350569, 17710601101562136446, 13935103640596939819, 2702874960495646186])), BaseElement(U256([611350896531270836, 16493637146686217060, 9846535556715299743, 2511832311186944318])), BaseElement(U256([11916794958726505474, 3872451678936994406, 5639887119742555532, 2677819052435561670])), BaseElement(U256([7976959475807276185, 10188601593534696767, 7656797670592947482, 2953148519541848742])), BaseElement(U256([17545935114828856180, 11744251075733796726, 9741345618203737702, 2295015973608045768])), BaseElement(U256([9791612363416382884, 12268464280768825457, 18436602936931813596, 2430139175047889877]))],
+    [BaseElement(U256([4348773868921349990, 4200057244322546713, 846661772633998503, 3259188957170610076])), BaseElement(U256([1138874059176055688, 7641309620224083787, 13912524176163407282, 2960450049011295221])), BaseElement(U256([13880491622298463002, 10483860279227591944, 1085386645962046030, 1685590814995549951])), BaseElement(U256([9384253775563350470, 7118761566086428491, 17610535623228629969, 3367425082772927612])), BaseElement(U256([12825678106197778210, 13451869262718005284, 8345325377521871394, 630774723344232320])), BaseElement(U256([2147855147945758328, 12407029546145759180, 17778760512911889581, 3124583793699558136])), BaseElement(U256([18420690591072706650, 11972738873171086797, 3926781947191409832, 3175526731099440764])), BaseElement(U256([823221283657078935, 7358450757515558847, 4617359532651326251, 1153785963223052877])), BaseElement(U256([9714084795727979217, 5253599655211609123, 6828971394363942487, 1556840791756172550]))],
+    [BaseElement(U256([9184801862960151673, 12895459329771176654, 9303338075368264713, 893359079320063510])), BaseElement(U256([595129060686841861, 12095802561736736040, 6096078606306777190, 461227122418571297])), BaseElement(U256([14044684664313977884, 15650553027183810836, 18366830456913769575, 3370024012174359620])), BaseElement(U256([4621151640166948676, 5927301588826819159, 15790338478514973102, 2945331811252285980])), BaseElement(U256([462706402133055541, 5861846711618321836, 7961408148080632061, 3217967593872843842])), BaseElement(U256([16345954597003636379, 7670164462747019065, 13330809811824537796, 1128821310635288623])), BaseElement(U256([312755877944401290, 13220645865687565490, 9729595647218297180, 2566452597097911748])), BaseElement(U256([2465627693730709845, 4036062029745938957, 7311166364981139348, 1239547971817151058])), BaseElement(U256([4250082213403336114, 7507358308406440598, 10640660817333927963, 3052543399518978159]))],
+    [BaseElement(U256([3534185656636240486, 7512093588881767949, 12722160500138483378, 1696915570503680473])), BaseElement(U256([12905233392534552550, 13480184260018904604, 6583586007529156420, 3013487318541880231])), BaseElement(U256([18058257422246342938, 5537539948411918654, 15477358814577532284, 641051736073677965])), BaseElement(U256([11522449535849953312, 6415855059820581895, 6027390591991858885, 2242101703062255739])), BaseElement(U256([13568797994611716557, 3743871034533531718, 9217970826112254496, 1661363111726518070])), BaseElement(U256([922519243790796043, 3102497163523901623, 12795515260228803420, 1928689610034622709])), BaseElement(U256([16779542290549386965, 18069541062285931725, 9204934119846828263, 1775208234284420558])), BaseElement(U256([127760599250083941, 12268073842577758930, 10745010867395082864, 2029773316122852985])), BaseElement(U256([12833795200687414957, 14397922680700768471, 16851470798783598599, 3307576254325958448]))],
+    [BaseElement(U256([86424970392415740, 7255039149491438887, 1280165400366657432, 1763735347672725696])), BaseElement(U256([8382426691805343568, 2365461743734770918, 5450230540507801262, 2171257870255655650])), BaseElement(U256([5080686474614106704, 7928406171354010268, 8478178646806007546, 180560681630116420])), BaseElement(U256([9680172743167872631, 14477682952646479627, 13566559783161514669, 1623217885731828156])), BaseElement(U256([6284331680189661587, 12807326877071509341, 822085632102669702, 3060843502224766526])), BaseElement(U256([15442829855123975264, 8562931029508105688, 6490446042649919691, 3073596900335460190])), BaseElement(U256([2431459462986939902, 8699304378375819764, 4709001655703137173, 98358185543953889])), BaseElement(U256([12196442874349180527, 12151966271652358867, 9433027618377288640, 2459667959291718823])), BaseElement(U256([12440667464077854260, 15489183179157255612, 11967337182961852432, 211565478388396293]))],
+    [BaseElement(U256([18394166889754251790, 4206933661665359195, 7696050528065972851, 1673217556386868416])), BaseElement(U256([1422004648771316772, 5963578578550806662, 13812629930069870104, 2390695722670885254])), BaseElement(U256([16443698646041485651, 17151261070668596535, 15882806689462159696, 1594667610296794501])), BaseElement(U256([2786480965073496494, 2472898324766076349, 3694753698433991689, 1304098864077702925])), BaseElement(U256([11167106765870014130, 5979267500980978004, 16749828846876031978, 1903641420500343853])), BaseElement(U256([8685167107288429618, 16920753123436901285, 13287760474587881171, 2037159760385026309])), BaseElement(U256([16215380724658325368, 16638062264810252162, 17655525159535244227, 3396432170014374226])), BaseElement(U256([10642636690462782514, 3006805760862023919, 8167719988167312945, 2509623805948497753])), BaseElement(U256([5396308233800113258, 13084276067524545118, 11405895617769813177, 2980828217320777246]))],
+    [BaseElement(U256([17709548150309774411, 15734883098218318447, 13194285445819984401, 32559312510615312])), BaseElement(U256([17799642186941032285, 6394469025427922002, 1456549805776584693, 1772267105459960735])), BaseElement(U256([4393624159965453955, 4686632898210461276, 583596274680432977, 246507017915727746])), BaseElement(U256([8876184878955185676, 8426541542300827530, 9685118670468653795, 830035695296573262])), BaseElement(U256([12320871676988277381, 13951396205143123559, 10838373346577051047, 1863741689989869987])), BaseElement(U256([2968678789998870380, 8874906803691480520, 9166147135943674438, 2747964727182547243])), BaseElement(U256([15887942989416348150, 15522525270194482005, 13447669857358460217, 1179840993172173697])), BaseElement(U256([4274105345966227507, 11273711055417979084, 10180705786895246891, 957595300093438620])), BaseElement(U256([85094163744424353, 9747342096745223945, 17719925668327726535, 2316808833364192611]))],
+    [BaseElement(U256([8759750651218527441, 11850734130560257980, 17796826960604509972, 2792223278462810528])), BaseElement(U256([14633741220006391902, 6327076483957951161, 2157188176095420520, 2312875465496750209])), BaseElement(U256([931109103909068982, 4887927362198705326, 2848477906444198883, 122072203920498965])), BaseElement(U256([16490140601328087999, 15466324534346827401, 3305121024372619446, 2833284609203954928])), BaseElement(U256([16964703063218258095, 5989751597843988060, 15051930708941305242, 1310794327789910695])), BaseElement(U256([14628678884956339643, 7342779484306757442, 8114766055628983308, 908408461417750074])), BaseElement(U256([1930864009912816724, 13977086566519619425, 725316009280552593, 3086281254182002953])), BaseElement(U256([18107745781741551758, 283345534294269799, 8119230634485608284, 1264004605526218396])), BaseElement(U256([408710858941100323, 12640271256741056025, 18337356623976182607, 2969170744639742967]))],
+    [BaseElement(U256([7695693260812460187, 13665113874345280545, 7135369855462899276, 2394022660522039315])), BaseElement(U256([1071816389555913378, 16876134648419712063, 9167409501937999562, 1740040150818262423])), BaseElement(U256([594488508287212585, 5064879622737004753, 12204035322953015271, 1876614208216708451])), BaseElement(U256([412932624895455214, 10699361426054275703, 13449515177374846235, 3001694222816393680])), BaseElement(U256([7515132819928933512, 8781025836362622794, 75442349956751580, 86133588258296641])), BaseElement(U256([2096203424237710165, 5554282995552543677, 16529512493389415079, 412661346954990983])), BaseElement(U256([4372886719494531464, 10551410802841127600, 16338537244701867032, 2561986892411537025])), BaseElement(U256([9985186371003031891, 2057013547176943069, 7869459966115321913, 406658763516092945])), BaseElement(U256([15571903310633854436, 11950966462901059463, 15969941147938036002, 2930557651637389231]))],
+    [BaseElement(U256([13220008042845679321, 18004866630299393787, 9952135877472975828, 3395378669222932709])), BaseElement(U256([13167968379370581440, 12795001027021710207, 13411170287421782673, 424750053022985994])), BaseElement(U256([8149134965875179807, 17114846347369028638, 10655722655858283902, 3298394029913093774])), BaseElement(U256([11511385432371467031, 16603177077973669762, 9664760189736778471, 3077891019633868282])), BaseElement(U256([14878274812248290676, 8402709634639466825, 1942562906119474506, 2667531280162269299])), BaseElement(U256([3822061671270921242, 4548346768850018650, 7257259882824209014, 2802750649224352198])), BaseElement(U256([4433125933826941283, 14403090333587601843, 3259378159525815113, 828303593663460594])), BaseElement(U256([1968839050141657560, 8739552840602079947, 2945650502743767189, 2723453857828915430])), BaseElement(U256([2887276539080910571, 1623562592374704547, 14441427001623711843, 1082957308850501260]))],
+    [BaseElement(U256([6891521369787486005, 10859551349459338585, 5116436695935139465, 2907870872004304141])), BaseElement(U256([10674907413898053242, 9022788790525122351, 10028055233409778961, 268584232141445290])), BaseElement(U256([13580163826584961398, 15028821560896758253, 590574806457159125, 1139716365564371253])), BaseElement(U256([10530860733962726074, 1456006112093974817, 9834636314180261844, 2310931183609624750])), BaseElement(U256([17933722663995336197, 227508575480019153, 12683808888320612719, 3102930072867097038])), BaseElement(U256([10931291708202509622, 13583978211045798445, 8606586367110545210, 2737592511534176757])), BaseElement(U256([17767239349034895550, 9271135032149539005, 13267151991997113958, 1668275366097741479])), BaseElement(U256([12132634653868350436, 10845802404973744870, 133184722735948709, 2658074577376153471])), BaseElement(U256([8560286372914369350, 7045030311988531209, 3024191315000002508, 620564212054426393]))],
+    [BaseElement(U256([6335352225388225233, 5782850189308376625, 6868518538094725033, 2840447952303848757])), BaseElement(U256([18342035750534656115, 151291257274699179, 14717738496142637394, 202590207626930174])), BaseElement(U256([5916195562179918672, 8672675871336187162, 9085533023108279547, 3448987844953681826])), BaseElement(U256([931156506397787674, 2860062745696516104, 7062420929250710020, 1569750409727435356])), BaseElement(U256([3220157294064022857, 10163240530797631589, 12995618807453204846, 2809098899405830957])), BaseElement(U256([14224357686806717041, 14244081873746789721, 13037328869100481053, 1051359679310563649])), BaseElement(U256([4637464004881454260, 384952299489332854, 10707751671502838333, 1442844534563059430])), BaseElement(U256([17666531129137392652, 9835278636781357588, 4671348526087639291, 3149049540861881772])), BaseElement(U256([12885749824246730169, 13954408001805941044, 8560537386822492237, 1469633848207089531]))],
+    [BaseElement(U256([9239509518798194550, 15179033947141075098, 5497329119578480961, 1159244013562270119])), BaseElement(U256([7279353272760764755, 2609791474177856134, 9798463976947788985, 3418777998838752051])), BaseElement(U256([11706102833848140778, 7724158375520941206, 3489696803447375629, 1204287980633536751])), BaseElement(U256([9747630029801550337, 8477807624480488268, 13138922465501339710, 314848345486336856])), BaseElement(U256([16646933707864590023, 1866786196280494072, 17382926017606448195, 423500793918806661])), BaseElement(U256([17757527937035913742, 16098184214168927489, 6610212074068820893, 2943548020432858522])), BaseElement(U256([16338156802572241660, 15544306432209314256, 13984779516889026934, 3253840097616535775])), BaseElement(U256([15103974794741641418, 841201734350280939, 13878493931716884051, 1038775010795941834])), BaseElement(U256([10110018652377519571, 16871996490770644415, 12366736375836850021, 2636653474953288273]))],
+    [BaseElement(U256([7038622524485543781, 12196149150240858097, 16215563814974443802, 2189919511746528618])), BaseElement(U256([15605711330330803976, 263488676640482926, 11648163695368183061, 2263559337568286979])), BaseElement(U256([7795906205001699846, 11419190726236973357, 5228746221601227687, 2518737159049032513])), BaseElement(U256([3062170540114425965, 15060177420675096124, 903708502752440167, 2225732216000764532])), BaseElement(U256([6351268008697400621, 14454785804740744142, 12116618029223467823, 963781300708578657])), BaseElement(U256([7900738386540532397, 3922341941808299769, 8712718419962313543, 246586359324657197])), BaseElement(U256([748994077916044677, 9296799135641159549, 16627862319630970196, 3063504809483739469])), BaseElement(U256([14964670112168512103, 16840187362498783286, 42202195296286035, 1691498577874541585])), BaseElement(U256([10200562679195510243, 653342304326209110, 13523049108645643133, 317056341609969129]))],
+    [BaseElement(U256([1280973250357847120, 6010115823780281524, 733094095809529742, 1011877178413685100])), BaseElement(U256([13078599305723567570, 10759514303717038818, 6562783048049171330, 382915222360747421])), BaseElement(U256([13116130825722126077, 12128866161575741434, 333378483648146123, 253581068357269039])), BaseElement(U256([15623693409933649794, 13111425422838550518, 1883282454477280340, 2714349902497123606])), BaseElement(U256([12134933112555008704, 3100658744921834610, 13519191876485497266, 1707397093894782722])), BaseElement(U256([15473944446864689175, 15045792962542495724, 11878255776268715859, 2308114559073555611])), BaseElement(U256([7915976097708127196, 17549715388143885177, 6895068971767721188, 2401281601921463124])), BaseElement(U256([14107097474041232504, 6650484049208910551, 16838478730359423694, 2009891111111808542])), BaseElement(U256([7060586462282788145, 5036446653026581805, 1447804546753687801, 1479060415815007118]))],
+    [BaseElement(U256([15883080316952287728, 15216694833218894188, 7291990158548675338, 1527913877945680353])), BaseElement(U256([11835389501000034955, 3650030203143692123, 13251428175638807433, 1879878420297753646])), BaseElement(U256([4833089792629317275, 15177895523395367763, 5400341015371704096, 1572397044427002825])), BaseElement(U256([7439525966488983234, 18226238356474099274, 5529635653579055893, 1100844289653516828])), BaseElement(U256([8218667300549445256, 17338503151183919037, 8145589879383019165, 1298045756871642330])), BaseElement(U256([11381061444539548802, 7043496027527748556, 16003288432831402725, 2380817885519540270])), BaseElement(U256([14209140536417033632, 10607530340216407081, 14722097067133558842, 2644521068774944968])), BaseElement(U256([3512085483713031256, 16835163370093903121, 17121791501757230798, 1745195194875585245])), BaseElement(U256([5886455399913700296, 3516314815836994565, 7186768246796133419, 446881876498010417]))],
+    [BaseElement(U256([623029568804260929, 8615324162700860952, 6706044568754976413, 1946661780640139647])), BaseElement(U256([6147517466111876596, 8453463825893128444, 9059259884324362114, 1925650944886484626])), BaseElement(U256([2115114877234646211, 10038741405785466300, 1563268002564058200, 3153016121728193644])), BaseElement(U256([10365754172634491748, 2607095421068413391, 5202995402827510264, 2787495321755455513])), BaseElement(U256([11890056984063475366, 10087538379166210576, 9328777317008618021, 567597837514873526])), BaseElement(U256([15850621022290650171, 1850611921595605575, 11951760524786118302, 3111173615165699245])), BaseElement(U256([15844384851475263524, 14839815533373848034, 8643820271368423313, 1606725969900621043])), BaseElement(U256([5671965995627973362, 4739425552878535836, 8295647176766524530, 1994675952445992192])), BaseElement(U256([6676504761760880750, 10621762875759951622, 14976585558320911294, 95460343543187033]))],
+    [BaseElement(U256([14651403196738943156, 6662739695008797138, 119064216767089501, 985031547234565934])), BaseElement(U256([2069238279885558900, 16308033067377627109, 11768915727651476696, 2539806712350195654])), BaseElement(U256([8438140186939261236, 6381619961892330558, 5077586209749763422, 2405901203138426038])), BaseElement(U256([7737207368101035410, 5535753436238933830, 1048706048574139640, 3124163890809131510])), BaseElement(U256([17711605640024093886, 2107420593393022744, 12006947697576017866, 2791293557743146041])), BaseElement(U256([2634875502631350150, 1184848876878857939, 2305078293183478647, 1991011776801369969])), BaseElement(U256([7051680622466790372, 7283192579959550607, 8201720456414782028, 1128760958897444519])), BaseElement(U256([9807186052623986883, 14174782875048531236, 14777769778193906329, 806267628982422454])), BaseElement(U256([34567717996411291, 5032801425441700335, 17123358943031480789, 2092275639007939330]))],
+    [BaseElement(U256([9522410967481838324, 16940523905449413579, 12997803258918632024, 336813789015495576])), BaseElement(U256([6777718311591670933, 18230882682876737582, 3784308471507068525, 2392637871542895063])), BaseElement(U256([888421839220111926, 526367205054078153, 5436470968279896198, 459637457164652489])), BaseElement(U256([2945392118504383312, 2972393574549012549, 14926383430642051556, 1094973192127152785])), BaseElement(U256([15450734525285835113, 6384053871318135865, 14788773833223027145, 2409273802006259387])), BaseElement(U256([7265403193662466410, 16675023661154135583, 7784261588428084923, 435900252127719582])), BaseElement(U256([6833168267627065594, 7550609746302663610, 9839926686864663670, 2375841391157144611])), BaseElement(U256([2212849260394003420, 6196473318826004637, 16057723913187442385, 1147565541823902519])), BaseElement(U256([17146097817728569484, 1938400554776015589, 7351753423762831561, 591298952431074553]))],
+    [BaseElement(U256([4894451673747526024, 12121944649587305074, 5424157773594925467, 2420192054535225845])), BaseElement(U256([13693687738456836090, 6084385524019073921, 7319103898358088907, 641258066511126050])), BaseElement(U256([7384308347060100202, 15619278438769498749, 12473134638910039427, 2816627948968409435])), BaseElement(U256([10533298562762200645, 17734986863237576773, 8193466600739430122, 2674178335095726789])), BaseElement(U256([13171998896882244092, 16752998719235871413, 2452538606091370068, 2653492298997927130])), BaseElement(U256([12541919256339786866, 4262433649194776113, 2989446499223686008, 224883508911071625])), BaseElement(U256([8601253191880803064, 3258097930413691488, 1264269755600512249, 1581933633576872415])), BaseElement(U256([12955268047259455910, 15614283233845185888, 4818225369805615779, 1925766088177317502])), BaseElement(U256([16869448188175355869, 1245240828795151652, 3703022173186377277, 3125353209139911096]))],
+    [BaseElement(U256([4059207025735410302, 14181542096648054651, 13302816737084584068, 2252536079892794099])), BaseElement(U256([12066045742751890709, 11227077518886521913, 12192470521712012991, 1722047209534085718])), BaseElement(U256([3960984844759202636, 10469115756169147749, 6197074504833490037, 3064347623463509691])), BaseElement(U256([18202956710660557587, 643236299740573750, 10697497247069554407, 1540154147249211824])), BaseElement(U256([6493959185881603984, 4704977818300187521, 13457952968286831026, 2451067097294145124])), BaseElement(U256([9372412208885565818, 69909779708779132, 1563783466045855218, 3326045977133936345])), BaseElement(U256([15830596141128936964, 17775639884314205795, 14599401287538857353, 2437988209181933439])), BaseElement(U256([5571114935745103492, 5994715326318041160, 15456952971528962428, 3190168426058356567])), BaseElement(U256([14404785524017831477, 7090973256525766917, 11057744902721363424, 1697636341685371391]))],
+    [BaseElement(U256([16274224697224053656, 9608291415295094329, 9095580892206093552, 1199064838674902602])), BaseElement(U256([6152806386665291324, 642787157675755346, 8851052394027875412, 2940116568171087190])), BaseElement(U256([4406207475559895654, 1554323936040159866, 4622394704345994030, 930239607097928439])), BaseElement(U256([12864929544345667832, 18055504419497275114, 13846913113238069830, 1220284716486438512])), BaseElement(U256([12119445407684663620, 5990347236062724781, 10116463906700425472, 3312407149945121486])), BaseElement(U256([2863487322577346000, 11619561424947603520, 10209473421383347884, 575273233784729507])), BaseElement(U256([11169314436972546503, 8646871703356034381, 6979115403690133989, 875503793301736916])), BaseElement(U256([6839749738469061228, 16247921978334710134, 5502684752888756589, 1451075850251488738])), BaseElement(U256([5165797622805169361, 6819508229633054520, 13028507288993776229, 2202882734406350565]))],
+    [BaseElement(U256([13042131137207446491, 2647059435005734030, 5208236944795313344, 794470024654128828])), BaseElement(U256([8402830115357950284, 8867353963487639491, 15175949510512010611, 3377771724369824827])), BaseElement(U256([15192797472532368241, 12184694872383364909, 3857097844113420557, 437585034698108783])), BaseElement(U256([16812965878506873499, 3456654133623986430, 7801172581047340749, 2923635707846010653])), BaseElement(U256([13209570985287178179, 10536667942979062282, 15197641798637393203, 2180873760730344109])), BaseElement(U256([10137613402645842579, 3822587409502702403, 10328683712614300374, 2075457691233136949])), BaseElement(U256([15237355331958671950, 2432156658930945841, 6350855150498514638, 1974249605070370807])), BaseElement(U256([7845361751782881864, 3856311227287598885, 13390084664112360643, 269311887984490083])), BaseElement(U256([14400094868968794247, 7331515010799935406, 7539711294063426058, 3316719917841818902]))],
+    [BaseElement(U256([3385033386979951203, 7421464603652980628, 14366017593159289330, 1120278410818307524])), BaseElement(U256([14894532011007005187, 109431846925699341, 6791810271719583753, 3128891926038320999])), BaseElement(U256([4482760622860719636, 14009661985439381627, 16648350888999043934, 818597732178101102])), BaseElement(U256([8980982065539576659, 15159684938933758751, 17937320682539129649, 824287139236213193])), BaseElement(U256([3854352589755267322, 14135200452679572930, 2262881283818861127, 2221933046475219912])), BaseElement(U256([17959318876862532951, 12776423211814585202, 11502393867443434030, 1949632218648839994])), BaseElement(U256([9282095614962655893, 909096569157598076, 16755034432776795714, 1723020223575422212])), BaseElement(U256([9399110814366684556, 11309580003563522574, 842521406979902922, 2378665915184010248])), BaseElement(U256([15501986115022021161, 14480918799040715812, 808739088107805006, 1046964651813725443]))],
+    [BaseElement(U256([8202729880085767786, 14294822685114627786, 11928238427695256354, 17454609180535771])), BaseElement(U256([11329965121510017494, 12422837738263966221, 7523388644403801871, 1356975413103875796])), BaseElement(U256([1922373997859032911, 17499723474318550970, 8926730768056196115, 191320613090314180])), BaseElement(U256([2131742906864825121, 7952118461515375361, 11474279202225005280, 1137180321496210288])), BaseElement(U256([4203375411867700792, 13842072859193017168, 4981616828110272665, 1536406044059985128])), BaseElement(U256([15151490656651557580, 10772943277336459871, 5187687894967739133, 1869307624523108125])), BaseElement(U256([7039813673983100766, 5873679985603119870, 18210067032491763518, 169694315495661798])), BaseElement(U256([13448867872630633156, 17129420813110237567, 4714964400449631601, 1057248885101816374])), BaseElement(U256([16672006232175680387, 15075438219852831659, 8005037895475420626, 1010797017920701828]))],
+    [BaseElement(U256([13752466280981569193, 10901441924407972635, 12578430377596623429, 316640565350182189])), BaseElement(U256([8033313169197473905, 1867614523288817559, 290470037550383466, 2976885955684917396])), BaseElement(U256([14264698516610044780, 12255708385647883214, 4392566590538727401, 2632356267522043785])), BaseElement(U256([15242779126587032547, 2311790843756856447, 17322049755134226237, 2225755060816301885])), BaseElement(U256([10208360295602949940, 11165126309418253443, 10332749252055382122, 2752255290830294978])), BaseElement(U256([8459508206445686512, 5916009287253260145, 13223804156831446250, 947535897198800306])), BaseElement(U256([10039595575908867888, 16441484553195170072, 15347366379489714793, 88948276533675785])), BaseElement(U256([14569313579567462143, 14186856453340654494, 261605896574565794, 307405198476280554])), BaseElement(U256([17648626030459509806, 8497825444065999017, 5231864569722597331, 1585816310446957831]))],
+    [BaseElement(U256([12337743130153587358, 14472097052572457331, 17317574219328302409, 1164815913993337948])), BaseElement(U256([15411773622978040328, 14243047887008981150, 5914591145656657827, 297603850777517331])), BaseElement(U256([2172632918270579727, 2111673980353827980, 17388553353737518452, 2127631851233420289])), BaseElement(U256([6644223456230147691, 17424908408711687523, 10501044326997997417, 1807070903757888170])), BaseElement(U256([10116030335766301208, 13116441442096607574, 11347038956823835894, 547346242459153777])), BaseElement(U256([4684723416710170108, 16721792492787340788, 4672360136462087288, 519518106863396727])), BaseElement(U256([7540184935758503903, 18379931959345658496, 6285027778582822530, 3459806406459948723])), BaseElement(U256([8460816154325269951, 14453673253449029544, 9887133908333374695, 985714818578998213])), BaseElement(U256([5723951318295654303, 12949282891591022399, 9554810724914850832, 2921441979091458161]))],
+    [BaseElement(U256([17949184473789745841, 15594482732648380031, 7780544683588527570, 327860165334126349])), BaseElement(U256([17679483111395430744, 10246663833515372955, 14230369354470971907, 2332371061791542255])), BaseElement(U256([11140807154084045091, 7407026518181453901, 927734891891342367, 1041157828301119837])), BaseElement(U256([16998279192465646254, 17276708272581785277, 6116670296187059722, 2415212611793788532])), BaseElement(U256([9062215769223496987, 2331079191915509167, 16443997816586143281, 2882890519039954433])), BaseElement(U256([7698418902613375300, 14314274534891078281, 15078511360247061955, 776052994648206455])), BaseElement(U256([10044554622652379390, 16825798571821276388, 17122197023459322620, 28367212350148591])), BaseElement(U256([2673878566936526705, 15965356556727135933, 6643120196443868630, 2930741517667667842])), BaseElement(U256([56951930074914275, 1767526576641007396, 18196947160177261093, 745241244771080237]))],
+    [BaseElement(U256([7192910783641231449, 7452661206296475785, 8341080697295061216, 121881436889686878])), BaseElement(U256([11805344685365406409, 17954444900508635958, 17417856829530625222, 17003195673217575])), BaseElement(U256([3079997197941213510, 7608071167976271697, 15578748291699391161, 1683186975562733469])), BaseElement(U256([9802513041406551069, 6103971341506459082, 7701527322135199316, 3309763340030981082])), BaseElement(U256([17920658248670040991, 8023580091189361580, 5148465063122938628, 1993691786490261444])), BaseElement(U256([10436217029215471905, 13711034200658938668, 15664794102571792311, 2962957293600700150])), BaseElement(U256([9084879524544399204, 9221147694267377502, 1376828066285809773, 930399061691179472])), BaseElement(U256([15024306650611902739, 3101057931364901259, 8176618929743198599, 1346043268528834948])), BaseElement(U256([2335419032365101654, 6937903212787644895, 11064482709306974381, 783029416262904466]))],
+    [BaseElement(U256([15878716590469876058, 3010673226166869026, 17697091478919462311, 3366913737119976323])), BaseElement(U256([3572843592665759072, 1986103201728437502, 7612875721638055400, 2193064580566531875])), BaseElement(U256([15848525098559693538, 7503985473430897657, 7709178177641356649, 2767694150561603127])), BaseElement(U256([532771064980438991, 10031885204310836504, 17502678214079544332, 1812314220540821295])), BaseElement(U256([3728117287700128851, 9843680630919225646, 16097347409908188695, 57924430540141548])), BaseElement(U256([6440164109045364671, 8055737507247620801, 325305796457566949, 272802735298172420])), BaseElement(U256([10735270483146190177, 2329072376590024382, 1758381337545712347, 3269402506684782787])), BaseElement(U256([12247421945275075730, 1932105266379594276, 17572566348054762916, 241224000709541192])), BaseElement(U256([10023792150905921397, 6784764650341963859, 6484219884791063599, 329907099912692124]))],
+    [BaseElement(U256([10391702880936940787, 13731813043767384866, 8432189875924313511, 262128329100568513])), BaseElement(U256([15568881034199293570, 323268655242225026, 8571188921894016229, 759399065734918401])), BaseElement(U256([16154518111950043483, 9490267386527061320, 7860380853014634762, 2432131786448213687])), BaseElement(U256([6200459700971510300, 1452271122263068848, 12143155583322066863, 3364106012110682544])), BaseElement(U256([17854226504119402791, 8341020409068553478, 17331920930883280446, 3470016002943755373])), BaseElement(U256([8923824002262542373, 8413152091059146893, 15177272256823971792, 740894497782630751])), BaseElement(U256([2181968822914632504, 4929586104823599623, 1647981944002429597, 3185897483314969158])), BaseElement(U256([18281309265932642237, 14079957331452386641, 15079830080253977383, 1264612426460472358])), BaseElement(U256([8284818624686186474, 13618937322918339526, 3209465477468211015, 807408167090933656]))],
+    [BaseElement(U256([7518432900522780618, 16209571140345332382, 3938888933147797814, 2587732622415792310])), BaseElement(U256([12390830918237510770, 13150538077101157220, 11740192802441981856, 1611936402670958383])), BaseElement(U256([74567896630903740, 3039151684077332509, 2378979089798011771, 2916563500181656696])), BaseElement(U256([1679390088962014907, 15239726942882611861, 477046173472759502, 2228487460216438563])), BaseElement(U256([14847365819840095738, 4003368463536439197, 13775087884508392199, 1925133098991580883])), BaseElement(U256([14217722693525587336, 11315293322982812967, 1173356909692151340, 974773870039817872])), BaseElement(U256([2437869845452911989, 12396148486159700999, 986361085525240975, 2221566783129215409])), BaseElement(U256([9563486693572825708, 6087708678702895482, 13682676829918228436, 1272746028989466782])), BaseElement(U256([10395708850842305925, 6226616776234402111, 18221304026965977836, 2379659028427960584]))],
+    [BaseElement(U256([12690070782384107963, 93831973998450938, 10073879090633755276, 2111638141401076607])), BaseElement(U256([8666344551069800949, 7110678372985248004, 11139458696588329429, 1904615253358454642])), BaseElement(U256([13248234528072824059, 16848593342156160410, 2743998318015893495, 3136964037592617663])), BaseElement(U256([3051714934255542939, 664523610994852623, 12782844595145531956, 719101009056412873])), BaseElement(U256([1951234181560730911, 11392757865686442674, 276134288431690947, 186590728505449480])), BaseElement(U256([6054553955834197094, 738802806978458184, 6239853152954928554, 23377060665023950])), BaseElement(U256([17303983282089773731, 15005528533584041870, 17183471081070651694, 2266006683174157])), BaseElement(U256([1110667112707030275, 7027757139597102938, 5037203865749416792, 1486565236025446552])), BaseElement(U256([4669425450793900969, 5306624015871722134, 6682338587492785930, 3095072354979207156]))],
+    [BaseElement(U256([8034207899818978098, 16798996645008963507, 17236700775683837712, 3342347137517119850])), BaseElement(U256([11491838976348145859, 17384149059429330873, 12517454393860238616, 2518282029287587512])), BaseElement(U256([12632009213281302296, 17794731929203578910, 13690241433840268742, 2549681128634350114])), BaseElement(U256([3380471475368921776, 17110397142010308281, 6599708646628317935, 2278789381194081107])), BaseElement(U256([9855454125601033709, 11976869824151851728, 16629711694839621048, 5563501518140851])), BaseElement(U256([14400337259027575439, 4922655811597535057, 7125755036017460150, 1091014731503244568])), BaseElement(U256([5885175732430656301, 10853395962161852727, 6466667319893806985, 2460372286820213849])), BaseElement(U256([12730712284991671046, 11505950106460550636, 17346171915713964902, 1207254594020559407])), BaseElement(U256([2050972956037278871, 7454616986729720066, 6514551375336777035, 2540504775879817585]))],
+    [BaseElement(U256([6070919989340166688, 262335921446574366, 4057445574875102460, 685539956577570442])), BaseElement(U256([669730207259069347, 13058154559688660496, 649349642028302686, 2362892093150685175])), BaseElement(U256([12813676726780605825, 8628761002678842698, 5698688199028175394, 1252844469316166895])), BaseElement(U256([17266492425597313434, 18055935278149516206, 10197395660346414292, 2488894203591136232])), BaseElement(U256([3003730876597509065, 15749039437346230299, 16062315975083224642, 789241075716868285])), BaseElement(U256([18242652411417429927, 5624654885700669933, 15693622293686814167, 3354371895866583442])), BaseElement(U256([13897945355350133453, 16251400327982530608, 18289873422463252653, 2521061010023811393])), BaseElement(U256([7862791999795229198, 15380839018740459944, 7162044277912584718, 1565000318384683838])), BaseElement(U256([90293584380209206, 4458957693729496997, 10762817990578591537, 2363071584112530340]))],
+    [BaseElement(U256([6336201387161673364, 9965767355006686192, 16165326400627233398, 1643878366822173915])), BaseElement(U256([1783608440393617050, 13567009800488002079, 5346147940373544213, 2878887113853720409])), BaseElement(U256([2005516690676177218, 302729612267714406, 8032805974939902595, 206292649231593388])), BaseElement(U256([16442491517015592536, 18423266084160223009, 5094602610906373226, 2129521012434460817])), BaseElement(U256([5405319586203048575, 16513361388238110954, 13051780810316948905, 245915521211314537])), BaseElement(U256([16429751561199838380, 9820321319921504427, 3073064678198285935, 3139751380365607053])), BaseElement(U256([6056426339042883425, 1890175087152263446, 449922669279764792, 2534009773321382907])), BaseElement(U256([13960942131210719433, 16353295947120109505, 1657511785018366633, 1299885695303511123])), BaseElement(U256([15711028937822737624, 9830072157962966319, 5248754664471198484, 3421598616638923967]))],
+    [BaseElement(U256([5914113316235183947, 6329382569465706424, 7692918008966278457, 1387659332041001793])), BaseElement(U256([18154648729239525966, 6035428729018871841, 5823602679106002451, 1903985862602891760])), BaseElement(U256([5444229263604306845, 18049341580301883810, 5318227837220003690, 2807064775469369768])), BaseElement(U256([12338136246596929821, 17248515402187158983, 14404638275476536724, 2041904896559128945])), BaseElement(U256([16303656713202810437, 11540602077210040607, 9076812528669846142, 1134253973001547860])), BaseElement(U256([16402489335891999993, 2278400155760677563, 7741277580286011110, 46440903077894941])), BaseElement(U256([9564202385615171489, 361350395967059416, 4038087650721202207, 2214248960913967665])), BaseElement(U256([15098604862443328633, 12320998418942328453, 4412015979595022118, 1214039655784736403])), BaseElement(U256([6858821068391259420, 8849986473154446294, 11217876901247365, 1464785425858558342]))],
+    [BaseElement(U256([15343037852676820132, 11137769761547938003, 14987406877885873738, 2017777351074557023])), BaseElement(U256([2923025477571658641, 17263413553618150357, 10377953692989794158, 224579719167231976])), BaseElement(U256([1342823098137625579, 17026574153059994107, 5701558310618207944, 681340479078630675])), BaseElement(U256([4160574414712148983, 10010230574290472789, 6512400513027005816, 3148487507335325370])), BaseElement(U256([8994761013279899775, 15071512536726045660, 7543971535485455355, 489112871739857854])), BaseElement(U256([16634029851254243060, 8392263790041295506, 13238277811266531608, 2930943404790021428])), BaseElement(U256([1396768441884626900, 12714127465235167671, 11707783433965430820, 471425190043471468])), BaseElement(U256([8490458593561065960, 3241717416164394104, 3890980005929846267, 177725268332012742])), BaseElement(U256([7402854041909718208, 13790770246045529999, 9856429262326360602, 3254531002856220281]))],
+    [BaseElement(U256([6105744448111969001, 1353180257037437020, 13975152236129067927, 758035534692104342])), BaseElement(U256([5682170124811994638, 6106927890520600514, 10258923077199263713, 1114441046134345205])), BaseElement(U256([5177981454219108435, 18217201084466554909, 10605311854898767360, 2096667267285053313])), BaseElement(U256([5235760937283057044, 8200964771881837007, 11775650660005110812, 2019192243434764138])), BaseElement(U256([3189732451736229948, 5788523873273707318, 16516049631086575804, 3092802852632198733])), BaseElement(U256([6484058028192778353, 12544613423013644454, 11249973380016947514, 2671618065803644929])), BaseElement(U256([4438737441225654581, 2613269100043611819, 17388287402567756840, 1669929129902448829])), BaseElement(U256([7460905271061550050, 15313880550651125929, 16794163659078129176, 2775353484550688031])), BaseElement(U256([8852965122552121817, 3280645199462878259, 5948528479512072306, 2900284471785937643]))],
+];
+
+pub const M_0_0: BaseElement = BaseElement(U256([13169651040791848764, 17681121639116810758, 9996279995255410100, 378411308405933301]));
+
+pub static ROUND_CONSTANTS_OPTI: [[BaseElement; 10]; 65] = [
+    [BaseElement(U256([10533917777261574232, 6722035816409078270, 14869399744884457147, 916319336267391461])), BaseElement(U256([10600580955023511463, 814202216355698301, 14337743273667443888, 274523252165020978])), BaseElement(U256([14873931304846405374, 2150945907496066706, 11436057656703009238, 2045366006618483005])), BaseElement(U256([17175383898763905590, 4192309531700143386, 12325544734408991960, 646614336886828101])), BaseElement(U256([12836668885554906938, 11772865024483286421, 12758570063426505205, 864086790953674701])), BaseElement(U256([10853623603947555374, 1540098189833736363, 15220533558070995352, 1552250921860277672])), BaseElement(U256([15053561074392096057, 2620031334372829665, 4251510196592823925, 2176591642317590853])), BaseElement(U256([5921497310523782764, 11229195499086324858, 8457620414052985453, 1904406261715027442])), BaseElement(U256([5236660594652909951, 1798965078442566518, 15734763669934195193, 2114646709209981158])), BaseElement(U256([5067564979085575868, 17913520926773275027, 5697037022604735476, 1261884529444083204]))],
+    [BaseElement(U256([5335860278970712847, 5057677415609492904, 13582222504020248910, 3254232546534046644])), BaseElement(U256([16222395840682637698, 14733348625845794265, 10619175355409179536, 2682664551983898613])), BaseElement(U256([8860009698177196302, 173570499639113971, 6963513296380398821, 2991178547076704113])), BaseElement(U256([10227682587369609701, 14087651805976931172, 3640643310625311642, 2767332216421842057])), BaseElement(U256([9019402023385079622, 10481563974880488309, 9614360307163864665, 1674506555250116178])), BaseElement(U256([7563589968201462299, 17206261066347798564, 18400254255495805733, 2007145847532902480])), BaseElement(U256([16048939918617461749, 7422958456990278258, 12580090608058256112, 74866969838081956])), BaseElement(U256([1448140962405447371, 1378215837168844426, 11337843404989862734, 2319393562930815301])), BaseElement(U256([735489685344042990, 5886599984729381574, 12870530852908442580, 1292861771430845269])), BaseElement(U256([5363888704161644552, 343987760747263756, 702172466130267770, 170057538292301827]))],
+    [BaseElement(U256([18190523058393771415, 6617252247072661094, 11475585684185076648, 1147236341998051247])), BaseElement(U256([13729527410411049734, 14686970460994810627, 12861893465108866025, 2694647285705837508])), BaseElement(U256([13329737756162898082, 15266258676060094019, 11088884500550185032, 2126593311647715892])), BaseElement(U256([1524665045608701073, 16863680115293361255, 15948105528962119520, 538468660004176276])), BaseElement(U256([10531174863105220578, 17247556652227406092, 17125151851327405108, 1800791131783224282])), BaseElement(U256([8980837180626174463, 5640746836053044636, 128126821066535823, 203650745041710135])), BaseElement(U256([17074960586864891099, 18132251709151700646, 8411968969806327485, 493435873263645579])), BaseElement(U256([848958977953250386, 184366591127754707, 13805364451758101356, 1349503406069413293])), BaseElement(U256([14779253916392754210, 3361150621728963214, 1144955161308285881, 414919569811662566])), BaseElement(U256([1083015078326013706, 6257067488190233708, 5521233387069151701, 2988764193723745279]))],
+    [BaseElement(U256([966782512037154181, 7997262850419324939, 7479950119816585432, 2080502341465800473])), BaseElement(U256([8501758130574476127, 17568187282322251132, 13866204589618528878, 546225541119523556])), BaseElement(U256([14254664457175324581, 13682093177523486929, 5966376944062536029, 1644354041569648978])), BaseElement(U256([12980324192233479274, 6389040068976825299, 7046572360715875504, 2785835974187650615])), BaseElement(U256([10280604989097819736, 17440862667822683217, 18250038275725969548, 855914576373678433])), BaseElement(U256([9518032213692776165, 1438815228782659358, 4170273032902954395, 2887586936000452826])), BaseElement(U256([6127343941123136976, 11485091614975421144, 10461962550816844875, 2865931056031488772])), BaseElement(U256([13632361403202592979, 2701293946573990026, 10014752564857087061, 2102376958303762144])), BaseElement(U256([5324092523955238954, 1195585322177108010, 10930520416849984913, 2200254013218557083])), BaseElement(U256([14073528345993579081, 8753232620806576436, 17391151174691715852, 2671641358661843882]))],
+    [BaseElement(U256([9695785931014018320, 17773991695925098632, 14633218646129159927, 572932940837622425])), BaseElement(U256([8252727693203881898, 5431081986834620729, 11285283709300837253, 575439921849747599])), BaseElement(U256([12415083224097309958, 15080190659846324029, 6298935490502133860, 345861442886655850])), BaseElement(U256([11560613965522052562, 16682372906544653573, 6216743503053300922, 2845602243009779856])), BaseElement(U256([15238869608104305130, 5847395468198255156, 17053165315211904024, 1209665130946844644])), BaseElement(U256([12215223773509868701, 9475101745247594865, 4059409961502354797, 3366061273379244771])), BaseElement(U256([16428724514994227215, 17695604650199730450, 6655125800658240810, 711865536849144469])), BaseElement(U256([13298042784412015055, 3448333850741471440, 5938946358254407755, 2996461687384924548])), BaseElement(U256([18187429440817318725, 18417122486632357706, 5081285848396938952, 3121276360879595298])), BaseElement(U256([2034714423346305389, 4543959394499800181, 15319098256446364871, 2426587143716715372]))],
+    [BaseElement(U256([15421644400806135652, 721913186584167941, 7478854794669346344, 2495944069627804521])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([17877467228341826793, 2113762913493440995, 14009271878707830581, 900571748225000626])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([11484278059501827358, 18250542337133166778, 4523609806010335777, 122006389780603128])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([13124470713602306602, 6889026506910508786, 17584200368848777636, 2840934088989954736])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([6562697632786516054, 12149985439677530937, 15545082215891426690, 1250891343617166943])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([463015826093625904, 17378802415088983787, 6650990137234179928, 463590890796480349])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([13468857160220198766, 10390616335763002940, 372116349189708172, 2796566323041171159])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([10416669880077525169, 13321327781691532797, 17120507980651513794, 1706743337159040148])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([2895109021272083910, 17929478833720386763, 2324158921940115632, 1175941168413835647])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([285038397640669394, 15640841063871144379, 12059777485346615731, 189713356687879510])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([17474562800337469954, 16871718988173634837, 17243523589584567938, 3041662776259996893])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([2732668220094128568, 5264539518828639438, 1465461728134309074, 2410621233114206036])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([1440708063758957645, 16643557987823708815, 12065815891020790441, 2318602514136693920])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([1699086242582262583, 15715378465850774519, 17262821427286597161, 907060034830157239])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([4839019349418367871, 14580077192370652456, 4881161262089795858, 3432322958417605379])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([661028398921254321, 5852755751930042942, 10488432136781675106, 2652338809560784950])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([15501364163779573430, 3992040972321496405, 7719737873607775460, 746258574050911647])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([10971064972062144417, 160278426479328566, 7691949690144951019, 2901265714156919572])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([2200270442964927069, 1665668999160989594, 10455317747225809007, 2707831004413570265])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([8506980330342279395, 8911198717490891993, 15485130755626758638, 1122540077680891134])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([1206957354467053838, 7227467374721102153, 3505184379285862994, 3391235249667030636])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([15624072054082716948, 15138076699969783252, 5154602155038543085, 1024522038646029383])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([11952297971423902550, 7872525514754796601, 5773328772878098821, 2679581612089050543])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([18300502965243533712, 13017018296109422237, 14571233776218499544, 2470513549425035744])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([3087766850740737306, 4593834218653604824, 13577304787660318805, 905801009250260208])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([10539772805926696713, 2789213196275961951, 18302778989112491577, 1161579344496048712])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([7848435018415652242, 17493982788727334600, 3523187931120554397, 2197881213361116242])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([12336916419793967706, 827781246715304217, 17926359494795650704, 837622542348212927])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([4454906443655734194, 4037988388290697943, 10103251663724335696, 883821969550311997])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([5397633038644069297, 8887135373546977122, 8169275347538666313, 171797249553411757])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([2168858449235642803, 14130393719171063358, 17952515765708642597, 1848546992332205397])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([15627619397143686176, 17517937310490654539, 8981806833379169807, 1852814136558468720])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([13228057235545409975, 18020685428136779643, 16596973692542820629, 137456614181846980])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([1709197383311837316, 15266753387507652968, 18220834116963623184, 1715046741283789004])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([14425277113871529558, 13241582562923293154, 8252549298608265155, 918685193548168468])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([8887418180400298892, 11788262873325791152, 2807263556608691309, 673818023767987887])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([4083814807602960203, 8829420551026442583, 18077729170059347051, 2182336213200204011])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([8175872252209074855, 4272815681455078893, 9915230646081525126, 175701913102014539])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([10747031258595883664, 18251397812595229600, 10660958846044433564, 2471218547012595670])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([1568745354691093432, 254781286238389726, 16201962342855937490, 1281069437194045812])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([3578910873087178846, 15742309230820091677, 13097329881867070994, 1830282265145992570])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([13324495041420519479, 12724447724759253763, 14436055584792395289, 2571141523656804480])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([3953303599636093156, 12229934946076693578, 7819014768617060798, 2564411468761153612])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([8431474482654183774, 2294546483405151151, 2526261187754515572, 983642120024884412])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([17449398151174475968, 12384680455310861940, 9414655818790962909, 2660142765356327758])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([4041238388465410997, 3272162722665405436, 2959102998245452968, 623894094397113675])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([8878864441444421487, 7454400335550418790, 8073650610777247984, 2377200029520382714])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([17265327783375091875, 1542142545254889392, 4715664399533166871, 22288249923413492])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([574349695454456345, 17120923279885390725, 10714961482397028734, 437242398345387716])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([2779543220581600442, 16874592596308896455, 5404917915518996939, 1915412679043507980])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([17869176740920643893, 16805030240885205142, 12142086496809461784, 3386377581361346915])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([3288208440976329785, 6517766740730231979, 7125990639427720491, 2611106488553366766])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([7509520252694983041, 12432626737805264996, 4049484801880687577, 3233047016258860431])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([12534242164860636908, 2380492060042623130, 4035654840739091446, 1860586871118293165])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([10825637790201473974, 16097115166510677629, 10112144523212429938, 971868603365675367])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([9941676200206075239, 7342745291379215998, 16825548022321553613, 661534005862860601])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([15915479714499867402, 17352996776308181877, 10954687353539242334, 1311170761248191235])), BaseElement(U256([940583523534673614, 617538113025849909, 2616852229884454142, 2354773093618494713])), BaseElement(U256([6725964615317734097, 525493000408898878, 11008575499142105546, 679315460174085963])), BaseElement(U256([6445039148376852980, 6872611559879685348, 5462947729701967701, 2215117443216470525])), BaseElement(U256([16965973732985264556, 7400836638532444, 16396607247843945930, 295175364336686716])), BaseElement(U256([11441159654713680296, 5610075882698540251, 18101445883492756228, 907330378987983015])), BaseElement(U256([591087358330060732, 1514326107853063851, 18335432046654774534, 3393208063274593496])), BaseElement(U256([5281272537271555996, 16951450321326860718, 7067331329946136435, 2115648329010772143])), BaseElement(U256([7051634167809055080, 2392603103553849178, 3606434263436741294, 2905656750863405425])), BaseElement(U256([633485577958758, 4092976996676693756, 11096291549499276857, 976616442588448139]))],
+    [BaseElement(U256([15343810824971387986, 8647692351383337301, 16430475580237739317, 679526789039368868])), BaseElement(U256([9407219193851257181, 8865838323805512097, 1765742610111393502, 362211405308136552])), BaseElement(U256([7102382367733951591, 7051145130670169798, 17277797374893915271, 1272985623426272480])), BaseElement(U256([10860395176619903925, 11132042527099333316, 2646356464527304711, 2633809842054565155])), BaseElement(U256([14486425510420199982, 15000308477688736931, 4191252811410751537, 1173710093700559377])), BaseElement(U256([16200229874679018083, 9915804745304017531, 8310937525067191231, 233989877327605873])), BaseElement(U256([6343203978834258130, 18412883228795834648, 2093957724936625960, 796838670175830065])), BaseElement(U256([1485929641315944738, 545153168905405875, 940404373616726740, 3473357975822478152])), BaseElement(U256([11678847556095373270, 12737494540273881451, 4409423351907796072, 32647549134969898])), BaseElement(U256([3223502234139182583, 15574143450092372291, 12484230136459125204, 2413244073267668840]))],
+    [BaseElement(U256([9128227406435444530, 7872481979164750747, 3226796040916487666, 2308917523285369650])), BaseElement(U256([18078168166794356467, 2083360188633001112, 10043290711167907371, 2519353773537600718])), BaseElement(U256([13412281551215087695, 100449109822355098, 4574009861445680693, 3159102878910751466])), BaseElement(U256([16009215213585498607, 17354182891536638528, 6293415623188925114, 1924882607784463706])), BaseElement(U256([1064511965626030926, 14109387419193753979, 10278129956498356575, 1152087190099383011])), BaseElement(U256([14126194690979123446, 12537456873520799266, 1862126867090188107, 2969722510505136772])), BaseElement(U256([9021567775697811215, 4905166975027407015, 14101829291319412719, 2406757065361067629])), BaseElement(U256([9367066616291582302, 8736444198448016799, 13595705155584956437, 2635133080437813266])), BaseElement(U256([8943899397075627553, 9746370648821588174, 4130237714974959829, 695986478715442008])), BaseElement(U256([6729368318835747193, 16736837699562656218, 8870289811101045000, 1007600798679125406]))],
+    [BaseElement(U256([10798277669535059797, 1211123866986308376, 9499642003622901946, 1107676571523869860])), BaseElement(U256([15895416972808049757, 17419957673126056379, 68447080604026669, 2304701893171099690])), BaseElement(U256([6005604301725818512, 16160108634405560878, 12991093065221644758, 3461331795608955982])), BaseElement(U256([6900733922247171888, 6834779117881158158, 11271426765592350660, 1743168281391763907])), BaseElement(U256([5839647621612673020, 2936751965958113751, 1190327286626026990, 3204421240355999167])), BaseElement(U256([839048865787810418, 8686306996512196346, 9745174920653482419, 3103120290192156410])), BaseElement(U256([8892188082768977926, 18043100272490231770, 12500422439740535929, 2682676175325306408])), BaseElement(U256([18255357234924412557, 8372334673134124441, 7340212663099926310, 594637323434065893])), BaseElement(U256([1044148511691566115, 11470771447212152363, 11794719118043236373, 1245244705052723219])), BaseElement(U256([7021333657846356056, 6257645215599658185, 1334585180270698783, 2223231478307315223]))],
+];
+
@@ -0,0 +1,220 @@
+use math::fields::f256::{BaseElement, U256};
+
+pub const RATE: usize = 5;
+
+pub const ALPHA: u32 = 5;
+
+pub const T: usize = 6;
+
+pub const R_F: usize = 8;
+
+pub const R_P: usize = 57;
+
+pub const MDS: [[BaseElement; 6]; 6] = [
+    [BaseElement(U256([9788408700862316784, 16565367001237555719, 7204362579762655067, 2963914115853885205])), BaseElement(U256([10152837159485849966, 12589097562077831700, 9569187696533452075, 2225466742208364767])), BaseElement(U256([12820268960584612139, 280354524351484518, 6307166378406689227, 807729545738104712])), BaseElement(U256([15096858175676458503, 15396928848161878581, 6679511715977330263, 922202727179928189])), BaseElement(U256([15718761605122060937, 4730824173400999620, 1369915610791367485, 786205078853446297])), BaseElement(U256([14804580181700730990, 18207418833520676084, 5151077732622174568, 1575288407669720088]))],
+    [BaseElement(U256([11003798992603659444, 15967051805416470143, 6226834343284135550, 2584194657544508242])), BaseElement(U256([7796944799266275941, 156344192749013984, 14693235873631737130, 925760008468646368])), BaseElement(U256([4467895588369234593, 5570263751350562160, 3745533098960625818, 2978902115464189534])), BaseElement(U256([998540991102605370, 14971215602339577580, 10727093967858720683, 3392554284243808877])), BaseElement(U256([10143124661573333129, 10837501342647423467, 9900590438867083147, 2945793297075802018])), BaseElement(U256([14455968475208918669, 10382017294628076782, 10211348585905109709, 270820466593118351]))],
+    [BaseElement(U256([16506178596875105159, 13687528456312123053, 7475448131479278114, 1492397091113714052])), BaseElement(U256([15213568528458253376, 9772881401731579430, 13449512769337497088, 3481133310152733585])), BaseElement(U256([10890842256610290263, 7656717968108244887, 6904215592042406394, 2754673599882151603])), BaseElement(U256([16501327655608334975, 2441665701627434782, 7838342809386007910, 985664553494145724])), BaseElement(U256([11568399470710224604, 8357207092647310274, 4094439718302300784, 1458173760229155552])), BaseElement(U256([94337139016241113, 9186787285943224425, 2748615947070208157, 66676822830991795]))],
+    [BaseElement(U256([9649184358182689273, 2887669414954078759, 16357185580885564757, 3284336457429222765])), BaseElement(U256([1381091124544737780, 2524356119752219431, 12327605611223090308, 436144400844296492])), BaseElement(U256([12604188768310418090, 927935778723703490, 141962384576291299, 1374035317651702644])), BaseElement(U256([14012655588362937839, 6606901810656755616, 2402377601393044258, 1223544799510230612])), BaseElement(U256([1763733620982220607, 6003164190816911495, 694175197815380098, 95628367678971426])), BaseElement(U256([953522774759849989, 13828456299247183355, 1787132254943644804, 2983231850597911144]))],
+    [BaseElement(U256([8587881481332623146, 6766016866392039101, 6253418789581942813, 2359243023472969358])), BaseElement(U256([12299214323899815456, 16440733401293928881, 12700272247944169913, 1793465821521845536])), BaseElement(U256([13122500224959475482, 9772724432928750103, 9194659152398122944, 3225652718125410512])), BaseElement(U256([8259754749246609630, 4680930571446564877, 4586014933135053274, 1000377818574554187])), BaseElement(U256([11622297802707703281, 14291816415397108152, 913505834296236768, 834930263911162993])), BaseElement(U256([5185424610922583817, 962841986983740202, 14175075752988650977, 2364563256335593816]))],
+    [BaseElement(U256([9111225464966089596, 14375948357149373265, 2500600431331901996, 1921438924699325696])), BaseElement(U256([14286928058833971369, 15162584518654226120, 15280699331569983362, 421169953524290472])), BaseElement(U256([17360998033991669135, 7264615127356127680, 238310490764759531, 3282428618947322068])), BaseElement(U256([13670812194109302646, 635639470087416917, 17470193666466105011, 3472520210587220313])), BaseElement(U256([15130866646198236768, 13900017972881345591, 6559914358334989070, 1328072114988828612])), BaseElement(U256([10965908362201059803, 4788927936044510185, 4312898118210176195, 913261360430066064]))],
+];
+
+pub const MP: [[BaseElement; 6]; 6] = [
+    [BaseElement(U256([1, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([3011551754716887868, 12324477538724635217, 14291627909175826887, 1773794164514657339])), BaseElement(U256([3569972366105951582, 14460067753461790247, 18206407771876796940, 613680475676248133])), BaseElement(U256([3277447937689381159, 17057340646023968114, 4077094368702392889, 3000565484480006727])), BaseElement(U256([1262419873762775975, 885271163397693045, 8909713118971371788, 1464858692946753627])), BaseElement(U256([4195475226127908122, 9691676311989618845, 17179355338828235815, 1775371447174192720]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([5737401358212320215, 15994176409209636729, 8480457375321884753, 3295926403017607630])), BaseElement(U256([13106012197771551738, 13159085119000869689, 11982866485165051658, 1990374273391029709])), BaseElement(U256([2314543380260364871, 691984200615513508, 9427089432335926453, 2335422385750837846])), BaseElement(U256([18180578756144797871, 12013621370883695215, 1848716001858927793, 3394176247817902619])), BaseElement(U256([2063155783254765805, 47374672679009139, 3213310649855373023, 1621407067828695125]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([10004176112363050618, 4269164500398908386, 64923225821472403, 845434075051409879])), BaseElement(U256([6536784928715733848, 16306376796387216270, 18438435549596845626, 1782015677161829596])), BaseElement(U256([10701534407752148444, 3041935355464481128, 6577512827263178125, 313255636692510463])), BaseElement(U256([4703205986249263105, 11139896514680288796, 17223395323920094396, 3328665001758579455])), BaseElement(U256([2269466449053771835, 9912627135788729947, 2698029411506767813, 3282438521026143022]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([17116892714671250734, 8407720053744756461, 2193329221510015885, 1873727056956777585])), BaseElement(U256([8781880524324453210, 12224864529564278905, 4467206047625470193, 1661583089824073583])), BaseElement(U256([11876730553934454962, 7161181272014290968, 9518002569804252017, 2532781650261303561])), BaseElement(U256([8820304472349296126, 8164891087865162730, 14100053207742593893, 428056589088759658])), BaseElement(U256([3997044249667399451, 6795373317648123548, 14928967685735608205, 2336735540529619902]))],
+    [BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([14604547518095242592, 11277310607679663840, 10677624329336696441, 733951676497567736])), BaseElement(U256([5116331946773513342, 14116381718023301324, 6159786288764294386, 1064051865320684843])), BaseElement(U256([11320113868884898837, 1092047312118797585, 7769375593677441976, 402797507829613430])), BaseElement(U256([13223181151761578745, 6179305629978135276, 17254444258582513992, 735461228527307008])), BaseElement(U256([4084294643553245407, 5608087766850535656, 13775195447613409903, 2014006835123395395]))],
+];
+
+pub static V_COLLECTION: [[BaseElement; 5]; 57] = [
+    [BaseElement(U256([11003798992603659444, 15967051805416470143, 6226834343284135550, 2584194657544508242])), BaseElement(U256([16506178596875105159, 13687528456312123053, 7475448131479278114, 1492397091113714052])), BaseElement(U256([9649184358182689273, 2887669414954078759, 16357185580885564757, 3284336457429222765])), BaseElement(U256([8587881481332623146, 6766016866392039101, 6253418789581942813, 2359243023472969358])), BaseElement(U256([9111225464966089596, 14375948357149373265, 2500600431331901996, 1921438924699325696]))],
+    [BaseElement(U256([10660638796866518831, 12145674804883872592, 15732194754873476901, 2764499549682379282])), BaseElement(U256([12790692633625995602, 12281084266304213754, 6821901029678145543, 1148944122615479372])), BaseElement(U256([2469187325946214196, 2937308115827011760, 506411929241129425, 332443574174547098])), BaseElement(U256([12374435142428959967, 14918091310166480771, 2025387602833983659, 2882458379975938357])), BaseElement(U256([15322210753784598131, 3409264999793934144, 14406898125100090173, 2464582304150335575]))],
+    [BaseElement(U256([17635063794842909317, 12265196973674914449, 7961336981008857336, 2406466925970751136])), BaseElement(U256([1733908606851346332, 10790298886105458624, 6103509747801738350, 483615034995563156])), BaseElement(U256([1058215091070278673, 2906045836387891669, 385018755951958908, 368190214816654098])), BaseElement(U256([13541219237415506965, 656604499549500137, 9080923293390697541, 2999927850200415249])), BaseElement(U256([10638228230937731970, 16823022745890820671, 7460121443401994474, 1961383147328063846]))],
+    [BaseElement(U256([8412062499307847216, 12907273880592178481, 15438459745479065161, 1474614845371203759])), BaseElement(U256([14101292723571253285, 17612752155918049551, 6216097806333549457, 1137062108528134086])), BaseElement(U256([7385896797804085953, 5362447195833126191, 12614615343669760437, 1972296439485832482])), BaseElement(U256([9475933751869312656, 277108741545639229, 7591934618336622452, 1649025377554467321])), BaseElement(U256([12194083650441947330, 6241931719872834018, 7769983623362424425, 311774053496661395]))],
+    [BaseElement(U256([16364029781544580588, 1805978657128404765, 10834564629586600865, 3074156402542187399])), BaseElement(U256([9249191466291758707, 14217791403993646573, 9592620831243152736, 663319716100019647])), BaseElement(U256([11243147741442870123, 11460977095017182291, 8861317728371011675, 3002627144054777988])), BaseElement(U256([11235148402273722669, 7786362911896113137, 7487919228242907832, 919234095079810591])), BaseElement(U256([4125318794312409848, 12696087670108551691, 3833138039189991202, 2582339666990354691]))],
+    [BaseElement(U256([7837335260698427771, 3224383092931139558, 7764035187653478630, 2219116031795432145])), BaseElement(U256([1287825868518336297, 17648422492169101425, 7723723037484267819, 1629690265034222986])), BaseElement(U256([13834126953182408353, 1946979877384034964, 9772311867803319063, 2395740307435344239])), BaseElement(U256([17038684644654036307, 10876138019171981735, 3196345884165008233, 719504126127950302])), BaseElement(U256([11965892084814739032, 2525887976424523029, 8513706234147451083, 466595765814807120]))],
+    [BaseElement(U256([4190634488768692357, 9139547294607550231, 1820929585840657287, 756369114896728633])), BaseElement(U256([4556150621292492344, 4524947603626406138, 8366235020137466603, 1349197805278517275])), BaseElement(U256([10652654057992241427, 9662338410956834833, 7351945940692236019, 840263028561234455])), BaseElement(U256([17577834663695335629, 9050885790899192906, 1887777544679259613, 2437848817552360744])), BaseElement(U256([6665968539153784339, 1139279572448243110, 3165858437333677923, 1179148905683739592]))],
+    [BaseElement(U256([11382377533986351651, 1811689826687197351, 6551973430629278383, 1052645522630909156])), BaseElement(U256([7407962265177427977, 11043349018950386075, 18248173814811614809, 186832443639296341])), BaseElement(U256([9707547248781731556, 12364733285276975817, 15571310937058969925, 1441959501756814277])), BaseElement(U256([15722780498983019392, 12554404221721907072, 7159545134426503654, 2488738218547287182])), BaseElement(U256([15048995753702306814, 13031909138299709787, 1760626324860016972, 1486261924341089331]))],
+    [BaseElement(U256([5421693928040516350, 1576339855980961638, 5408286393721733432, 1088327603846463703])), BaseElement(U256([7927224983375636839, 15121713686598652499, 11041933586500827253, 1296545047808675324])), BaseElement(U256([18383119445161669706, 9299947604808668792, 3055754532532938767, 324175332603282798])), BaseElement(U256([16989358758480746178, 6189007654356946968, 14499324154815769085, 418403020226828456])), BaseElement(U256([14855529314240529487, 2655920587829004575, 975857510189334403, 2999238874404333859]))],
+    [BaseElement(U256([18086610534934060923, 124730334670944635, 5947972751774604959, 36044001383830773])), BaseElement(U256([4228915381821709582, 2610900510424520659, 9834033312428625233, 2562771836424917834])), BaseElement(U256([3545529541689486894, 6130398483690495657, 8537029840419138230, 2241085271822049469])), BaseElement(U256([13581201151155218490, 15908247581744354430, 14600737610536437545, 1787868131646319147])), BaseElement(U256([16636157657532070441, 10114920699886608252, 277685211005151206, 1740069892598504310]))],
+    [BaseElement(U256([3265190568284218823, 12794017072253835903, 14705720205696761763, 2877565907871151503])), BaseElement(U256([10003324184817895320, 2975030867122623930, 16240029431518938539, 955314343896954167])), BaseElement(U256([1105925950409885997, 12698122619666636979, 6957975669486534454, 2326258980544393446])), BaseElement(U256([3893687330214329124, 4371927929881081446, 5536898403213200099, 3313227495391142386])), BaseElement(U256([11986311483331190070, 12542281436302080785, 552470792437969821, 1952323542115784377]))],
+    [BaseElement(U256([13277676072969682982, 12906905975510732044, 15359693409224543112, 2699253445283964584])), BaseElement(U256([4135389250735381330, 5379927452809999116, 11877803364667274289, 3333386373538436270])), BaseElement(U256([6261244966299012934, 8652153123988356121, 14961382400439123249, 1852142516584056313])), BaseElement(U256([2668949222799268556, 13352565641272430425, 5249081711841332244, 359283572170819451])), BaseElement(U256([14909090556088723444, 17485432788880138268, 17528910851713832511, 1581093296913919456]))],
+    [BaseElement(U256([3214257309512244428, 3731889442225071944, 11978677253493015289, 2790681020364176828])), BaseElement(U256([6517754031839696368, 8040901854049162753, 4743303455322465889, 499861141840869498])), BaseElement(U256([16330603241340221258, 8209330114577518063, 3971151040054632601, 2968198335328342887])), BaseElement(U256([12380786000897431466, 11786252076971856977, 15137570170723516781, 1370337363136461002])), BaseElement(U256([10126745782302681734, 16115980520442983515, 15581714859462826266, 165735050736405910]))],
+    [BaseElement(U256([15847829025809728453, 17706159325484093056, 13266565612036775932, 603761575880587437])), BaseElement(U256([14267002562095574823, 367502165692260453, 9387786744883654421, 1167559588597544033])), BaseElement(U256([17964123992289501271, 18078729168489405269, 9454061115644979716, 868292450236033694])), BaseElement(U256([10646325781215834253, 9843190478614626194, 11044024297062429275, 2842883436246423090])), BaseElement(U256([18169085164608390666, 1310978132240022255, 454449671339256059, 2514576992315030310]))],
+    [BaseElement(U256([6188136799863779999, 13695053154366396895, 8132101772601531645, 661774399267885633])), BaseElement(U256([16504484959260332604, 9267598927651053762, 9159450401402791332, 1934396856657279373])), BaseElement(U256([12560919662525957698, 17453773502523750745, 11740797117279405106, 2289688638442902709])), BaseElement(U256([11485420465604572217, 9212231745306998152, 13099081585123403589, 3052428299682559597])), BaseElement(U256([17196337949531774079, 3197420389309171217, 8776473207644895408, 627087681655111266]))],
+    [BaseElement(U256([17682792111875546078, 9477873667890780295, 1915257186224653709, 46891329784284918])), BaseElement(U256([9180585758686787459, 10565200291745513789, 8900396233758037454, 899468994890097093])), BaseElement(U256([9855046408474673003, 17052980779000047256, 9639962494841416452, 2086420704640169183])), BaseElement(U256([5910056843759065942, 5977972210498872668, 979254275138748720, 589687907575250491])), BaseElement(U256([2824196611451285091, 10153481971461979167, 16319865744892996835, 1346868894962811121]))],
+    [BaseElement(U256([380998555520603256, 15693317502518474612, 5642351789501047091, 2497791863944970053])), BaseElement(U256([17645308011323482971, 16472829213764811435, 11007877429141077799, 59941803770864449])), BaseElement(U256([7435605927711330380, 8128496676086724863, 4210387610072700324, 1077150011034838410])), BaseElement(U256([12407500768485093454, 5670815460050531577, 4212262958505035485, 1908557587936280936])), BaseElement(U256([5561155493685657517, 11997159740038895187, 15712775590679407773, 1855882749931636657]))],
+    [BaseElement(U256([17130631208957661189, 1592053721039850725, 14448726606689134620, 2141288846878026728])), BaseElement(U256([6522214025599365860, 11844801191011350597, 3337921689481920406, 348861899132676705])), BaseElement(U256([14719151435886503175, 13335959472203403198, 13627930206932280142, 2952898825335129279])), BaseElement(U256([5157200509943272650, 17697171044523208862, 11702607511008601423, 1494608070304823247])), BaseElement(U256([14199711337409161338, 14071431558289263685, 7382303262945917303, 922292494845740820]))],
+    [BaseElement(U256([7905570788039374491, 948599384217173085, 5126162403415533382, 595300808007467734])), BaseElement(U256([5052257287686044553, 7605455479414291930, 17690262274413102528, 2963107839079416633])), BaseElement(U256([12372450590780811823, 8500009619792362529, 382549475006694590, 1948340922736541912])), BaseElement(U256([7977635223226316118, 5749263780400914615, 8869662030188919794, 1630730262427867552])), BaseElement(U256([14840194750097844078, 7960347071739510695, 17800768008628123826, 3394240709464455983]))],
+    [BaseElement(U256([15737441688865173466, 9835708332918149290, 4719758917072663225, 65746428862094401])), BaseElement(U256([17717221683311581055, 11847291334884705466, 14414139724097244039, 1738316308005643607])), BaseElement(U256([9032565937654958481, 3616803616611502258, 3824485986015763559, 212474624862789868])), BaseElement(U256([15525325502555539852, 14838360105456060758, 10409575496412019021, 2288349651482807433])), BaseElement(U256([4566762344626693446, 7931910737783002569, 8192081526726292440, 1710780332226307537]))],
+    [BaseElement(U256([17296114179785363589, 2354362850080968077, 8244437568228343899, 3264355360586762259])), BaseElement(U256([15032297012374361589, 7298343128128407782, 6226198218987396824, 965699219849953208])), BaseElement(U256([693009791587506423, 1582524368888147248, 3312285329778328354, 2351399740404815671])), BaseElement(U256([12333535346438727397, 9983253242380921172, 12867130617114348276, 1027728608589789223])), BaseElement(U256([155997449681171617, 12936333142314626165, 3888096168725056877, 1185164346536542468]))],
+    [BaseElement(U256([9649145804800946150, 605169989888718948, 17946786696000553199, 2147808544475769933])), BaseElement(U256([10604465604111535543, 17669331498492208798, 6795667904426308772, 639871448897653721])), BaseElement(U256([2536901763514968527, 10674279767923775748, 3772781766033449986, 901752681065891617])), BaseElement(U256([7677105401577942625, 14043000268285254716, 18085791483324365846, 1794947063818071682])), BaseElement(U256([6538369494417854741, 2763188391062925264, 1555776030498955700, 3384065883272241787]))],
+    [BaseElement(U256([970343109178572337, 2969086609064298734, 668677731650515941, 286768676988090877])), BaseElement(U256([485809746201347090, 860115851633716580, 4395459484246665112, 2630037726871913170])), BaseElement(U256([16471816445034936308, 13696462928347538542, 9060127965354419954, 1987836871711844821])), BaseElement(U256([17570761766657925090, 17623954111114725398, 6184519490496401679, 1118104122427616999])), BaseElement(U256([385325111165049202, 16301941299239888679, 1289091348407690038, 845827013477144281]))],
+    [BaseElement(U256([16243676309170154140, 9085041493700308156, 17462688442437621285, 1098516009094333999])), BaseElement(U256([13223758710304643996, 4636298391661259958, 3845543791421663717, 1589847325287448605])), BaseElement(U256([13054259691221242801, 15038085140162764118, 12493580679422769434, 215117233415181006])), BaseElement(U256([12948636484072264854, 11886567838697613767, 15532316751058260995, 282624714828881679])), BaseElement(U256([15076330971107676302, 10144209222029714326, 14788858913274606106, 2971672601090525049]))],
+    [BaseElement(U256([5434655819989880658, 1156352407879885176, 11304866199683471326, 1694178889836117608])), BaseElement(U256([11098526828511307805, 17295285556155313792, 3737167707150352514, 491981886828440866])), BaseElement(U256([8851871187221565806, 9534418022324620652, 709431623554058075, 2977736104540660116])), BaseElement(U256([4094891275427802772, 2598151344639296587, 6322623386844372892, 1603528767417599530])), BaseElement(U256([11802937397264647642, 5797193257350747231, 11373250507001525089, 425343687620066807]))],
+    [BaseElement(U256([14029568543747775959, 16279315065542812437, 9812757776229847104, 1430771587398129549])), BaseElement(U256([12201927497731563932, 7987843370321092199, 5548204766209865812, 1773384959142819110])), BaseElement(U256([1487053918445330106, 14064395433796833926, 5582149137621708788, 3378944477316508629])), BaseElement(U256([1503358866781157693, 17464819352519957539, 17092418914753997879, 2771280439816224099])), BaseElement(U256([12580943895849810165, 8349293095937292460, 10854317478100636449, 1144143796759799476]))],
+    [BaseElement(U256([7674537964015143093, 1333985328546483148, 3844670039755141184, 2149920554307387857])), BaseElement(U256([17896759486584055245, 11248651052446236311, 5717464779002548239, 1444280008420209163])), BaseElement(U256([2654908965163667426, 862475877310901351, 14160813021750602872, 1761628705704114689])), BaseElement(U256([6980330035105860703, 11699487089119591608, 2771933160003483951, 1987105356359864873])), BaseElement(U256([11039217736718635484, 10649335310096692817, 886425211199706698, 854647374962160101]))],
+    [BaseElement(U256([16082100538622746666, 14025881863174844798, 13441864273961410451, 2832449197178017219])), BaseElement(U256([10407253567560622967, 8932727609222011863, 2732298915655530313, 1876036832638372118])), BaseElement(U256([1589337836547237272, 14240043727727357507, 2344986587506206702, 959807446331822505])), BaseElement(U256([12592611172231796285, 1689282741691197069, 18342815861469112651, 631984985014243495])), BaseElement(U256([4293655560953848998, 5141125172641987201, 16821828021506945635, 1911766120108654201]))],
+    [BaseElement(U256([17654530143459846934, 2730998500606216272, 1584040407836171804, 1463234852447740152])), BaseElement(U256([16163400801471246176, 2537259478373095236, 2284413755206811893, 2477334429396924928])), BaseElement(U256([12211325589195028725, 9914442180649253775, 2374896528882477626, 982005890893932256])), BaseElement(U256([12995123927552123870, 3453582280722496473, 14619117148003896081, 1431764898855378772])), BaseElement(U256([4970985343640587977, 12189341974240348642, 14523843958358059539, 2907407224114556673]))],
+    [BaseElement(U256([15852954070819352227, 12981836737511112465, 16114803222429130786, 2575091909052241694])), BaseElement(U256([3810080141866403905, 792413956424407676, 3508727416823997492, 1703685657183107750])), BaseElement(U256([350991181105765040, 1893146416970103124, 54367534578640430, 3107994209171406413])), BaseElement(U256([16473692634635076951, 2790705371320593809, 12410386440196311857, 344100627609792832])), BaseElement(U256([15142692768681350275, 17286750969774319980, 17122568444514400946, 308059517945975203]))],
+    [BaseElement(U256([17024917741630140712, 6054249615351672737, 1379065606714130943, 716119290095352864])), BaseElement(U256([13006779028564196059, 13791960501480115795, 9966934838744902914, 2311855972859873708])), BaseElement(U256([9392718090939578003, 3873055499822396116, 12831444135200028112, 2023158717167102040])), BaseElement(U256([18270739724227465356, 14382428293858437673, 5344596953501317670, 2508570697020175297])), BaseElement(U256([3431440742318507836, 1424523215186026043, 11710703881401063526, 2131347706625761715]))],
+    [BaseElement(U256([9582593970110303887, 6097997343549099886, 3541337512777842224, 1551487104223758933])), BaseElement(U256([11629049006461538095, 11516098956546947612, 3119708652292038676, 1587340238385355945])), BaseElement(U256([9159769197441477631, 2424936738546044996, 6626335069078661763, 1505342364128011852])), BaseElement(U256([7544713738983851120, 15986029307487156543, 1104000410003301785, 2990121865665142103])), BaseElement(U256([9786342009034101480, 3534389130343053585, 5695723211968935343, 1368993514651581174]))],
+    [BaseElement(U256([8718509470083666902, 13724894091414182097, 15386894258442547126, 698804691227857198])), BaseElement(U256([15489027929804047755, 15751240497675998733, 2897828409341951620, 2657137042718882603])), BaseElement(U256([15949899589578393135, 688606293196775240, 17947854569721731886, 2504847977189665228])), BaseElement(U256([8959044088286589621, 5436709190993769130, 753159657379722622, 2153293383073408221])), BaseElement(U256([1211897095568962127, 3301988945643421798, 6484249813748432620, 207450033592952621]))],
+    [BaseElement(U256([7882103102521612486, 12217424947116136404, 1708754073026297359, 901606716978697725])), BaseElement(U256([294300974412656207, 7008092475501801973, 766692389917662906, 2392130664051783832])), BaseElement(U256([11296553338823260672, 8292535923136171525, 14656116309076378141, 3252365287176977233])), BaseElement(U256([14250365544798591123, 10008737090250421923, 3661649197933382915, 3472069871965441078])), BaseElement(U256([15362248314655323006, 8349272093438478605, 5506516974546539514, 2740770442764268624]))],
+    [BaseElement(U256([10801166172900000018, 6883139460401496115, 3315218449526764962, 1919859883221317963])), BaseElement(U256([6142006944735112315, 3690741509395170526, 14321155910930579982, 843720748057905608])), BaseElement(U256([7824324952748371710, 5103607838069967652, 7235669756393005773, 2446353465934021643])), BaseElement(U256([3752031622719700590, 9612534053500513706, 14805271457070502141, 1297977983786778190])), BaseElement(U256([10316067935436965698, 13565970873238840071, 4771620824163043351, 2895735238048415845]))],
+    [BaseElement(U256([12031986857634883075, 1544698853939233476, 18236977496085294465, 1645485293560930066])), BaseElement(U256([4170916566379946808, 11844911048149402604, 4898572488815749530, 2065720139891768318])), BaseElement(U256([2312214749362674215, 3759784560085169598, 7921232583516882457, 3187896879811489222])), BaseElement(U256([3513925148208755545, 15040319316948672530, 10288622874252314313, 2164952938052983943])), BaseElement(U256([14965849288854511564, 13113419131316407466, 17634904460243591589, 147655778664294887]))],
+    [BaseElement(U256([8675018442012861017, 16052131826454998663, 2083284622939699925, 2046276101170700613])), BaseElement(U256([2825616147963924346, 12421917233522557600, 11898427768523396087, 1016622168341481000])), BaseElement(U256([3093048433482345163, 4983314090786497619, 87701181174892100, 1528554517175459858])), BaseElement(U256([623677478088457795, 13729249820539631677, 4419937945224665759, 3368861535107056061])), BaseElement(U256([15703694725367153690, 15659490200737785474, 4280336994538677682, 837265804434472821]))],
+    [BaseElement(U256([2073149610726287892, 4441519652004406816, 14310860927524493245, 357760251704985174])), BaseElement(U256([14506990912719564406, 5866888028118547301, 9218765136552828303, 1650206493695676241])), BaseElement(U256([3766619303980402643, 10991989169810508545, 8196116039646833431, 2290302472603202199])), BaseElement(U256([9161183816361163829, 5085017365105520793, 16774795197115288920, 1912031594788862048])), BaseElement(U256([7971866019693841809, 11250323114050736791, 7604299509614323424, 931966722686819900]))],
+    [BaseElement(U256([13567945365636165559, 4331194634865726031, 12564377373959738183, 1333828584838473230])), BaseElement(U256([10661219301244629870, 10636250781395217782, 17432730325401248572, 1496147085706679720])), BaseElement(U256([9595984336716863339, 17269902474834520374, 16592925649979733942, 91161253555565386])), BaseElement(U256([1400844496420356885, 15902145347721821226, 1582402399254815511, 95701074493892465])), BaseElement(U256([2514920423293021994, 5047743622721761898, 6465582676826450246, 1348745513075564863]))],
+    [BaseElement(U256([10980947763632414867, 1963828814220922874, 15394334480530536255, 870257473013811883])), BaseElement(U256([18393872256963647658, 3053769391838770234, 2408026214147374769, 3009866078467951075])), BaseElement(U256([14290673631485291602, 9020519454249559112, 4414455758699471465, 2258173932308182994])), BaseElement(U256([13363227789940329343, 13346304833752652538, 4085498627943928619, 724797551417071480])), BaseElement(U256([13870981821558891148, 2048068978099917921, 3803440825153030365, 2385934775702468416]))],
+    [BaseElement(U256([4963299610655227270, 597398879760734234, 15164036655312146159, 3354115683745875618])), BaseElement(U256([2143585132587236729, 3514946715391786659, 11628511010219597771, 975046428383787049])), BaseElement(U256([4882816110379603286, 7918155955122890232, 13222283091225923888, 2448786407184176013])), BaseElement(U256([8185846745694788397, 6340092077349001658, 10666643852355156513, 956442210866554724])), BaseElement(U256([10753671606791476026, 7451444787556091890, 9016764534243855170, 588053038257883700]))],
+    [BaseElement(U256([3430651002939770805, 9937056837526191090, 13016632497977078393, 911013214937187709])), BaseElement(U256([16794685246177201510, 693387642076463140, 7901656226818923085, 1992811099258709920])), BaseElement(U256([959491425168758020, 10208121784534094185, 5415200219734476031, 518469311955220640])), BaseElement(U256([5045253399161410095, 4674275971855587299, 10496134043010343434, 186499814671694392])), BaseElement(U256([4590886926361519422, 6730520548314583275, 9149922500522278181, 3098921866884422609]))],
+    [BaseElement(U256([8869012317190361351, 16052990320828983787, 7687395119344427116, 3145063852866217795])), BaseElement(U256([11748788530671727643, 7113133553950132712, 8535799340521607947, 20228363517187239])), BaseElement(U256([1395352486858303602, 11943874985353913837, 6397943537926362843, 525749550662539865])), BaseElement(U256([3272492110441592226, 3994445996359996181, 13984889854372368315, 1382500754008391046])), BaseElement(U256([9165582904396972699, 2238246790305624246, 12652701502247575227, 1007826323797847090]))],
+    [BaseElement(U256([10041685829653372397, 653230901621650282, 11403308266834903100, 2710945100038875620])), BaseElement(U256([14637654639650572226, 7695282468884701567, 6379740198527503780, 1819170051884587800])), BaseElement(U256([3722215340594922682, 13016879831941324791, 12856706658055522572, 2038759496285798372])), BaseElement(U256([7675015909097712387, 5456986172225003663, 8237675950486393236, 2945670041941857570])), BaseElement(U256([875469682809480549, 2835216093051308702, 2592092763954045375, 2429305776045834548]))],
+    [BaseElement(U256([3127373415253484611, 6718051037270598719, 9000716038786002470, 1946744698630430560])), BaseElement(U256([12007755046844018295, 3012313038644384924, 3784448456136803813, 887615352296549815])), BaseElement(U256([8797747244099273776, 16738381741794105827, 16047535126796974732, 1379414898164325539])), BaseElement(U256([4997479750182342961, 8072076373198902249, 11478539207850620048, 576719283675961852])), BaseElement(U256([2929021919697300584, 17191867971240095333, 2897466616714070797, 3337312409392656027]))],
+    [BaseElement(U256([3375983183439023094, 12091839382400913506, 2233727504851057806, 161696057450883275])), BaseElement(U256([1614441703351253519, 17937335283624567206, 8131928003378345971, 222123593333473089])), BaseElement(U256([2779964796840175310, 12174982401305550225, 17397057055055952107, 1529589865051621916])), BaseElement(U256([15042211033910235072, 2853003374899608056, 5792501738400961249, 3455801034269791405])), BaseElement(U256([17105063718775594558, 15996351273458793322, 5038622138421521245, 3047763078026537992]))],
+    [BaseElement(U256([4149960757762979566, 17824251495275329088, 13258402694843291619, 4366678001975588])), BaseElement(U256([9947368617379977735, 10632026171163422611, 11350678746326840374, 3399496776948843339])), BaseElement(U256([16604842516406033832, 10369129925413969140, 2603985733867607149, 947372503932307584])), BaseElement(U256([17930962768409032803, 16893939004212172435, 13597590292103030194, 1066279865785801136])), BaseElement(U256([5454543470104964407, 8486960357701578638, 14836857659041520457, 2733493995573551186]))],
+    [BaseElement(U256([14901571907873756831, 13652838362210292241, 2951545876753958438, 1870214002486748552])), BaseElement(U256([6739590369608497265, 5010108746194403269, 12428336373314635848, 1769835157816683159])), BaseElement(U256([5808367553365516934, 17292992979791639344, 6353831020632388012, 3021311262973979182])), BaseElement(U256([17247607014841826796, 14804123754420187931, 7168733402368432764, 577457977276920097])), BaseElement(U256([14228428468489945529, 5082945083149876121, 4894376616534457347, 2463419536912479236]))],
+    [BaseElement(U256([7889590355429660079, 14482633450296700882, 8875413106948232085, 1171121263946957746])), BaseElement(U256([2770464103566492034, 8166668231468047773, 5595975835262683659, 438870496819153786])), BaseElement(U256([16043406375350603150, 42579515792231984, 3953958820055415297, 1980045016784439373])), BaseElement(U256([10832592241848726062, 7180533414583872974, 3008741489612351460, 2263540857036366967])), BaseElement(U256([14306508985908576195, 5937249874885050333, 13185166692839511597, 1092195456327036910]))],
+    [BaseElement(U256([1922054616221354921, 7049832559232855806, 6141518122488803690, 3409914370194975831])), BaseElement(U256([6656751503484265560, 11947064801469292584, 6215952146020329047, 1684725372675396935])), BaseElement(U256([12043121105056986021, 11750140722007490321, 2858256848767838006, 3426293510949204119])), BaseElement(U256([3690273632460126645, 5232282784057587198, 18096610173790919011, 1170693351740871884])), BaseElement(U256([10760064849301268469, 10623924731272123084, 7862504471663133497, 293069530559075925]))],
+    [BaseElement(U256([567592519602035483, 2854058544394188288, 3317755306468853929, 3354178142578422295])), BaseElement(U256([5267947160880616671, 4185290278809394104, 13151795121872315537, 2350188435737227469])), BaseElement(U256([10456771232871949694, 16690166167613441982, 14908889240562139026, 2881795215775614659])), BaseElement(U256([10030640566647492972, 4786166235342032817, 18279457722094273382, 2498391874281142918])), BaseElement(U256([16405598155416659093, 5343980073937782581, 3030111685622875948, 2041058439212005277]))],
+    [BaseElement(U256([2941141060217956462, 17555293290413485202, 860776929649666940, 1519447784362631651])), BaseElement(U256([2390651762808526201, 14200142451532584068, 6311376467403044165, 2620484296295257779])), BaseElement(U256([12051402778710647255, 1143574099137288880, 17803902359159801207, 3395676641933049088])), BaseElement(U256([13777781703213735546, 9441207764130755942, 8804601180459952053, 3464764150080726108])), BaseElement(U256([3338075891246171504, 8040668007816403791, 8056836167585414485, 3224408530679379221]))],
+    [BaseElement(U256([16465699458717323185, 4343878307002633859, 7462837317658956552, 1951198327115358202])), BaseElement(U256([14423808765140041104, 17592079397817820881, 15605657854330329676, 2299850525712470372])), BaseElement(U256([2602777026957769402, 17408644707783489559, 7379266208590759620, 466241586145387975])), BaseElement(U256([14532063628491715264, 13364677657366629140, 10570269540187956643, 1866317659598803879])), BaseElement(U256([5666117936828340774, 2253149477264529339, 8675578693223886946, 1300278720074794251]))],
+    [BaseElement(U256([3515167433810951958, 17300133513039440607, 11162128198735568823, 1421569260489486137])), BaseElement(U256([5385218609616301556, 1141283441812566313, 10846897192279155512, 156715424159622884])), BaseElement(U256([11017435494503084463, 14767058960023220367, 13373581396914134228, 2747739864686958578])), BaseElement(U256([13807291671368995449, 4140124203454013177, 7376143900229951864, 2006472991583894985])), BaseElement(U256([8324590783128806860, 8520663027639409825, 9041543124634971392, 662048849964167913]))],
+    [BaseElement(U256([107986829016991058, 15898483374550863188, 6966413980072014763, 431863230627506143])), BaseElement(U256([2437508862571381704, 2794915623946628425, 12381605954315344125, 1079639876499669767])), BaseElement(U256([6557866235633639313, 9470826398828955709, 12734356221120299063, 1935201156648348042])), BaseElement(U256([10313886943845372353, 13003654357350558861, 17206369139186504466, 2084377003536349638])), BaseElement(U256([4040715482512190689, 15051683687062032430, 8750993803102280266, 2304649181195944530]))],
+    [BaseElement(U256([9797607948400522804, 16011077923229367690, 5435830799085834327, 661608863124715851])), BaseElement(U256([1910261687558594136, 9863837971420364171, 5979859058624167520, 2044983172649754329])), BaseElement(U256([15333053881415359569, 13409152207030212769, 14246592638710287971, 158498333237806518])), BaseElement(U256([4385514736695264652, 9705979095318628511, 7898364328717496195, 3344927929288819148])), BaseElement(U256([10769405251320131304, 16666106947978258995, 12219426836682898628, 3214023379800092230]))],
+    [BaseElement(U256([12204507846141148122, 2090325657042904865, 9735965152131125035, 866263636915281947])), BaseElement(U256([12531478324320918935, 12748422489253146935, 6922790365026798419, 61574760101614750])), BaseElement(U256([14294797630310050017, 12243263034490246939, 9247495741135660845, 2169394538444515833])), BaseElement(U256([17705079005030526093, 15540394609735577283, 7693955376159979227, 1329283353549079883])), BaseElement(U256([13200531270066547253, 16635322778788844380, 6202524744974059281, 532503861663663218]))],
+];
+
+pub static W_HAT_COLLECTION: [[BaseElement; 5]; 57] = [
+    [BaseElement(U256([3395768083663354871, 13893925567458234004, 4738319273210040529, 1672062849506136411])), BaseElement(U256([11216688659735438933, 13804540533588287169, 11827437045495809994, 2558659949759197426])), BaseElement(U256([1532434717041878024, 6556302715237098156, 15337054758670928898, 242011780782581998])), BaseElement(U256([15826255618488914855, 519553130631672927, 13932479920798219738, 2485849180372692320])), BaseElement(U256([8479192026050492258, 986280636404748830, 3981201316765017506, 1664522306332847709]))],
+    [BaseElement(U256([1948353903618470260, 8479246076538597152, 3329996805626117706, 2020769996716981626])), BaseElement(U256([11272530262162720442, 12342906757285669781, 12341802793746258244, 1182747101547497859])), BaseElement(U256([5519394578546518252, 4305007070023733427, 3041556982052929364, 528811016592490303])), BaseElement(U256([523085953149422969, 97586691324227033, 3023665804604595745, 659280514665603696])), BaseElement(U256([4132786331685804195, 14291180576285343699, 1618621536009741673, 2421744428564414366]))],
+    [BaseElement(U256([15020209696799443379, 15213650966397208926, 9909414990367818428, 548638520006598682])), BaseElement(U256([12460647946842296241, 17988716444719911493, 12869075857468687492, 2133523901486503450])), BaseElement(U256([1823240622612966338, 5407050725371236463, 5913200584345822714, 1390681886733944230])), BaseElement(U256([1755779784756008424, 16976877126433635805, 13081844871769992343, 306859289100433621])), BaseElement(U256([10645571822355705302, 14601012241914139319, 4330965677935776007, 1849176153277383263]))],
+    [BaseElement(U256([14344998648108687438, 11694217526794024376, 14701961462217978203, 34163222675381690])), BaseElement(U256([17898412387298860492, 9119616810216868818, 8682586456839351780, 613718336318153634])), BaseElement(U256([7993084400416843701, 6156596607951211011, 16644457372263867158, 2775598537989804052])), BaseElement(U256([1320472517019691620, 6538043528929709629, 12841230880169360643, 1378123314210316271])), BaseElement(U256([13711907845192213514, 1614155361512735577, 16046932818992105455, 1329099988015034718]))],
+    [BaseElement(U256([8995138446941300728, 1353540892648411374, 8434919427404467638, 3083563216559407019])), BaseElement(U256([5556634288576270345, 12152542145612923104, 10376344835446345043, 1543973231132348316])), BaseElement(U256([14714498836151582, 1343795126691599685, 7241068992183241717, 835068716822668292])), BaseElement(U256([13235896790107994946, 1097629555002879394, 14635265182300114203, 819598361481231549])), BaseElement(U256([17847811720841948007, 9779521260348978216, 12886262760789695854, 3473399748924999105]))],
+    [BaseElement(U256([4273566969840619341, 1858004577877282240, 14685842939968348213, 840569213251406550])), BaseElement(U256([13232927423268979136, 804225185364871479, 15990602216785458329, 2460749599413074940])), BaseElement(U256([12821607609215673384, 13339085764326980315, 7531735561831144610, 3398680676079777589])), BaseElement(U256([16180592725785888692, 14019119476548052691, 7883616762154056636, 2388378254691399154])), BaseElement(U256([3183973567967718754, 6903254742237831242, 10812997045706021455, 970717123173054359]))],
+    [BaseElement(U256([12996903400288537894, 5034105842547083856, 1650004968391592879, 367877392374315893])), BaseElement(U256([8085662129514390739, 15012047841263357021, 1204770753016228440, 1376679700414488429])), BaseElement(U256([14174494548735268199, 11200539971936757173, 7591740710703810571, 308766395880165583])), BaseElement(U256([8946814677441929117, 211775900159278510, 4114169711894340024, 2930067473638137356])), BaseElement(U256([15101945713791870949, 18407177569796518146, 2631325179378521054, 124708002964836821]))],
+    [BaseElement(U256([3688562010396639080, 9168246621751632457, 14442777806150406949, 2301943645307732786])), BaseElement(U256([13760740950272629642, 5963223227355898408, 9637879378404736104, 637248326970805597])), BaseElement(U256([9943367004925659694, 6999869916382597116, 11802553428813942153, 1737324950124696634])), BaseElement(U256([18128493550481049453, 13601899403286090188, 13954445022090407544, 727407232717130111])), BaseElement(U256([229641904781308915, 13060766379882200778, 8498081013805619403, 616324552399778977]))],
+    [BaseElement(U256([16974790917592729129, 4142430865389315680, 12310674791747840988, 2708264136058070147])), BaseElement(U256([6002132540786866128, 1110403948266239028, 12938718687172384243, 168654126688406928])), BaseElement(U256([11630130237908750694, 9315784934633948332, 8976086736854224297, 964400565519942953])), BaseElement(U256([12920762911927833817, 8098022705850679527, 10950001523509854061, 3212185711795927544])), BaseElement(U256([2701957227674545511, 1132003497671295033, 7873248259975443834, 1434959069761457996]))],
+    [BaseElement(U256([5350599669643416600, 1914348849077954336, 4721623140377120824, 1812730171947225470])), BaseElement(U256([9678778306707793263, 14794024641409036650, 17071318430075236172, 499398715104114162])), BaseElement(U256([11034744365916067242, 3645420678273605006, 13880293857589414351, 1028738650084672164])), BaseElement(U256([1348613140294495742, 7311427759943234023, 7274768241170838884, 3328131090382108530])), BaseElement(U256([16567720225364596703, 9621091892571906087, 13519391989396203663, 258990677516301362]))],
+    [BaseElement(U256([12759686822038433444, 10918797693359682984, 5364538320105099571, 382127907043759276])), BaseElement(U256([10097681284833169967, 6880835010021676097, 13585617274299699859, 3010207317074933070])), BaseElement(U256([5785483425071683670, 14291897222350698221, 14169823414269024810, 1626479303351109430])), BaseElement(U256([15790192086411774902, 6715270126184958130, 8684562248148282338, 3260610261968940489])), BaseElement(U256([4848720179288813592, 8358132159391541483, 9366411762963370615, 1741178801430233447]))],
+    [BaseElement(U256([5201960653473053040, 18259018368897634503, 13730647295613317286, 2555203300223227351])), BaseElement(U256([5066698342479351917, 9730988339498091017, 9443173157446122754, 920985418020318016])), BaseElement(U256([5444904973646542419, 2413346133117139680, 1426201979511631407, 368069263468171066])), BaseElement(U256([9503126240802314661, 6378010647204064308, 3714399637325404196, 2539420247804527920])), BaseElement(U256([17753177545750562786, 12703800479148947144, 11204848948044065797, 2767012384863996072]))],
+    [BaseElement(U256([14018443533523403577, 1033669759287909484, 5677947027462645137, 61285644600179840])), BaseElement(U256([17299962323627138671, 17781336717201602995, 12152164042760181311, 1364829947725498955])), BaseElement(U256([17461854090276750264, 12693051185890983070, 7396196072098463486, 531686154277621170])), BaseElement(U256([14946539205890058402, 17770079196528598054, 14325927238127237975, 288115154216380555])), BaseElement(U256([11408859349014515208, 8804462452489262339, 1398636906555262958, 3471656425846766544]))],
+    [BaseElement(U256([7328635713923297950, 16956380246027366653, 17568509056421572811, 1310934909974984188])), BaseElement(U256([11542370573592638813, 8599522244325488863, 12470234009642927326, 513090456377723895])), BaseElement(U256([14496377444565821228, 2551519556317453989, 8155502154032882518, 3255696644724417221])), BaseElement(U256([12913664041407530810, 18184195253280162623, 18380525007498425974, 1891025843865917177])), BaseElement(U256([13669019744395893860, 1098638860018320761, 7030048837496159954, 3381297293783030967]))],
+    [BaseElement(U256([6754125319923917172, 5779604903781546808, 17313440624835174150, 1214603452611984271])), BaseElement(U256([14294743054063952465, 15390778374042375002, 9752341364736247169, 1939539827391132738])), BaseElement(U256([13061327012553122361, 15231084173192598239, 8020398180688581582, 1120391784796963953])), BaseElement(U256([14557779495062049241, 7889464252682381417, 5108103034143704463, 1615592068467163343])), BaseElement(U256([16582810242439769370, 11676573376722111396, 11537375460437099850, 1464383006593042911]))],
+    [BaseElement(U256([8282103750492465709, 17748150810078958407, 13871478318477697315, 740500290160612585])), BaseElement(U256([15130464041000304515, 7963367664550836741, 762063162858783297, 2352913777610813805])), BaseElement(U256([7001415376184801900, 8252837411501193153, 7979245515520082299, 348607153912944594])), BaseElement(U256([3944938771130351298, 8276588856509647828, 9653253813853801708, 668940402861158760])), BaseElement(U256([5075180684470641253, 12319567066992783769, 12957737599404861432, 748544712551900068]))],
+    [BaseElement(U256([2750020951604856339, 8213112798190882568, 17051534346827265514, 822126557634637822])), BaseElement(U256([5615439844423802186, 8597662343243038209, 14426164693246765892, 641342125471053225])), BaseElement(U256([3901764743416994646, 3679546812886476376, 8120903694492060107, 2352486418721362431])), BaseElement(U256([10227830467312047180, 13742795966763434220, 15088825663291602064, 2285895811625650377])), BaseElement(U256([9482715029561877753, 5279720937741016823, 14295721496030627462, 2173204172863816194]))],
+    [BaseElement(U256([13938851955518808460, 10340314372736051144, 4820523561446681476, 184353161599424687])), BaseElement(U256([13146211260129201298, 1864320785499536299, 13862988600154122986, 1252894586213778497])), BaseElement(U256([1693105753434384559, 3005613683062554798, 14816632656904113110, 38043682928854289])), BaseElement(U256([13930802506098407736, 4058928336598864795, 9963799002233629970, 2606305474244731561])), BaseElement(U256([11321118968516645347, 2507256250293933452, 924170046784944432, 3099100886781698947]))],
+    [BaseElement(U256([5675376184981265198, 12036330305282831072, 7800580534425044034, 1620879596438030128])), BaseElement(U256([15988575679798338167, 4883926149920917306, 10298205003372435505, 3037895650808653999])), BaseElement(U256([6148758083168377014, 5707161926614778505, 7584985463338323634, 1884765473317223523])), BaseElement(U256([4602452949975827089, 10540262438334539860, 17078973272928981573, 2589749960034494746])), BaseElement(U256([593449755266619011, 545053209563615129, 5133042647557973563, 938190888161162990]))],
+    [BaseElement(U256([17398305246112290363, 15044283796879976446, 11033541279332867233, 67107407645286173])), BaseElement(U256([14835356769478391386, 12388810445930714728, 1289104168411634977, 1553993739083734381])), BaseElement(U256([637530504330965539, 6560141021442105876, 17099812995161657174, 802644917907688006])), BaseElement(U256([10834601260833786312, 2259707105185577233, 8473285756227310583, 396457512469017011])), BaseElement(U256([1834261169694722741, 16594608107509608529, 12949355250828928707, 759664881574615022]))],
+    [BaseElement(U256([4568127748808483150, 9355387689904590091, 10571317514762590211, 1701187050146702157])), BaseElement(U256([12165247823621250355, 2594342793097704665, 18148024203144659001, 3195606489579173401])), BaseElement(U256([1813662580636038785, 11303437124425400021, 15312930253884560292, 852788172350384617])), BaseElement(U256([8747302266639087045, 7978675073840554921, 5761937293196092011, 3244129488794831213])), BaseElement(U256([10233528743888672955, 8141693156401962324, 12593077903673850338, 2373620707481468728]))],
+    [BaseElement(U256([6627848195095778872, 2971889342783685102, 15400568952973822536, 1102739494598149750])), BaseElement(U256([7690442709215578298, 13447111235476018617, 14249085578028064005, 29247361284301267])), BaseElement(U256([7683441161433347566, 15328794141049948195, 13612086727571076181, 2154320839026544983])), BaseElement(U256([15117278438605171356, 8722340232108608559, 15734625308770540934, 2627703807128163075])), BaseElement(U256([16898401288490652366, 10768221957026030684, 9592623243898151202, 2820169939620274082]))],
+    [BaseElement(U256([2560605225293336464, 7035523729857475614, 5400447730952880739, 786396413515061382])), BaseElement(U256([2135982656014621787, 6183576186962709950, 5834934926794675824, 2603555514925826568])), BaseElement(U256([174263707461043524, 16571215217155374861, 771112219915701275, 1616854727895201312])), BaseElement(U256([8215756343488994123, 11089853369921054250, 11471729763046124993, 2539039594142308926])), BaseElement(U256([14841002223096749491, 5012291736158437823, 10753713942826617726, 3353391016487431309]))],
+    [BaseElement(U256([11162846471602868327, 18005585705462570386, 2552264791415769684, 2130100431128102665])), BaseElement(U256([11042545061416477871, 5572993152149709602, 8052526959366654678, 2763061532725930847])), BaseElement(U256([1372192104720801105, 16182463224253780443, 10688336237867770824, 1483811831060054938])), BaseElement(U256([10484106471131539009, 16086551163624698924, 4370725336186368051, 1038203368339767705])), BaseElement(U256([8378159894838706617, 11466142506314134094, 1366543846960451416, 607220944589789182]))],
+    [BaseElement(U256([7764669875400530902, 5971584750872523060, 7969451275575202793, 1823813879274653743])), BaseElement(U256([5700933671490933233, 5755116587388804767, 11920536698076942042, 994250572096731081])), BaseElement(U256([5206740317844341759, 14765476650662227931, 755187077116263995, 1039184025195591866])), BaseElement(U256([15125693666781902480, 18061524417466906206, 12129385054545742398, 1623297783746531823])), BaseElement(U256([402573524612221842, 19450925943902540, 5184207505245141906, 2142368933673139474]))],
+    [BaseElement(U256([4920350075122713148, 17363446560637671886, 13092377884074294951, 601275358668918523])), BaseElement(U256([14127155433279917946, 17114825704772158746, 886258727619985092, 1499389520830800953])), BaseElement(U256([172546136830688303, 15170924443286230044, 8320794204414478486, 1837695633316433553])), BaseElement(U256([14101103367153851213, 5587614976925914776, 14597931980082975782, 2495265605063648993])), BaseElement(U256([8300715563359264661, 3273061031096596835, 5063221992609444024, 2960122624054115302]))],
+    [BaseElement(U256([11192148420767809614, 6329383443255215750, 478700218997044176, 823111933583679678])), BaseElement(U256([1592791602447113229, 17915202437146800500, 11004890408277719811, 2002119667007742865])), BaseElement(U256([8509127244955898322, 3459591106731818214, 2884810675286737315, 57820882559270177])), BaseElement(U256([3284174663095098281, 14936910580978570084, 13898049116609184052, 1240043755402525513])), BaseElement(U256([15258197071051076356, 1567626779724969401, 15103732933503426765, 2508458463688958686]))],
+    [BaseElement(U256([17040725736582558512, 3687355177724120613, 4530425680150612899, 2798040531765602814])), BaseElement(U256([16782984844615180061, 619268434650371798, 3567782540130639035, 2012377168901069653])), BaseElement(U256([7836275137307659083, 11984018752643024965, 3709580004566649045, 3041545417680220968])), BaseElement(U256([12997097202264931976, 8392221963997026702, 6781016144009260396, 166877267739163311])), BaseElement(U256([7772055251890542237, 11163384542716794296, 15438592468579119646, 3425105548344764352]))],
+    [BaseElement(U256([15207000607267702277, 16909814054586809220, 11649666215241979318, 1128493859163254941])), BaseElement(U256([17577088943233001135, 10553151221454038945, 2375783305379062303, 1305933886501066411])), BaseElement(U256([17883262909729083311, 1733613075358753164, 5855840731676478105, 592732727758449960])), BaseElement(U256([11916111318502555175, 13069802455727220889, 5102960025897072553, 1870596218567014799])), BaseElement(U256([15757041625026328449, 2017495865233429836, 4283494589822572214, 2731074140712017037]))],
+    [BaseElement(U256([6315978655824200088, 1604697676241447368, 13560835671240766677, 674854504985169003])), BaseElement(U256([1967233958920899754, 4985545765367431326, 4323289501362705846, 2674768033167972633])), BaseElement(U256([14042144756296320125, 2345580436789281677, 17485991771448636765, 178373444417024988])), BaseElement(U256([2995697540625611239, 11763736678364143338, 7225732269145321584, 2973880380403616293])), BaseElement(U256([1529897669961076511, 3583040301467370240, 4413534661311291211, 1337617805357281568]))],
+    [BaseElement(U256([16408923603713789734, 9262099675023233086, 14315909810107382914, 50413680583256719])), BaseElement(U256([13129764740537092903, 13690392003395903263, 7776518047015931299, 1052104712096690129])), BaseElement(U256([2907813846006094700, 5045330289683290830, 2589115130733370197, 619157476506980846])), BaseElement(U256([15545926748519766619, 10914977365344378726, 7910713871189007157, 1548004678026032792])), BaseElement(U256([16719908804889214517, 18254539667034030218, 17071635639295463242, 770767680887051069]))],
+    [BaseElement(U256([14382712496762768958, 8131881327641011506, 17652736241118858068, 3422262625292666142])), BaseElement(U256([10293862868262171348, 8118642186172086354, 17813056343242283561, 2783419260003588235])), BaseElement(U256([14154484143667487670, 9159365398538581329, 11896980139965817517, 247951552203401893])), BaseElement(U256([10319197803787987620, 15918804478025647128, 8560403069978196302, 1995667130006199179])), BaseElement(U256([4673802848943005526, 11428631345464813230, 15248306081178699066, 222040549318356666]))],
+    [BaseElement(U256([15282509111473534142, 18049799965526213581, 9225393862401737489, 3390674295313847041])), BaseElement(U256([8982449812286174800, 2472510162440629029, 1148073128991478069, 1749121779423102896])), BaseElement(U256([10365427068133515612, 1419395445482582770, 11735072071364433920, 3347325309990554398])), BaseElement(U256([7398777499483207734, 17485461716480534331, 7558030057781765370, 2107978374768892327])), BaseElement(U256([10633876786165501860, 14548193312532177049, 9047318496506664946, 2181592252825978393]))],
+    [BaseElement(U256([2920160567323127675, 11844621450509360340, 12784621189977936256, 3143812194109511021])), BaseElement(U256([11237524287255299722, 14255084452730354800, 10096925904057557269, 1313211308927188037])), BaseElement(U256([1306211444080054930, 11626882916797028745, 10481292234509440843, 3132299905605489028])), BaseElement(U256([4691776633523400407, 9360266884425496257, 16810008958887605774, 616488222828365087])), BaseElement(U256([4957760313528337569, 3052859033080327165, 4888062722850386331, 2047561041824776512]))],
+    [BaseElement(U256([552659737128418050, 15252799729536183319, 3790311377030964778, 2231050778335069617])), BaseElement(U256([9372831601417444458, 3586730784669326298, 7525857096307707294, 2950014995914860346])), BaseElement(U256([9030412176293058179, 14384096986379866894, 3407334877078076717, 2012771239386200237])), BaseElement(U256([3032907929483131976, 11518780480247601824, 12298540625497630775, 1178175174156440180])), BaseElement(U256([7522599979893346090, 15324011156219398509, 12650864052870998461, 1837632485513906172]))],
+    [BaseElement(U256([311920465995771466, 2448815358512116213, 4175522872135020986, 1604645910257248595])), BaseElement(U256([13016506063300724739, 12910243003036604700, 7425096466009614439, 1046874865512400703])), BaseElement(U256([5426501012669070749, 2933896308494281504, 15689865700096683219, 3233634378394596066])), BaseElement(U256([944201319858610923, 10747684877374893823, 7723613091722589500, 205067133817989871])), BaseElement(U256([11796715168656210972, 9112746393641745231, 12741994899076718610, 2340153687434161818]))],
+    [BaseElement(U256([1655108349938563411, 12754779801155393902, 3238944038568560171, 2497974015503036855])), BaseElement(U256([4976033691537408220, 11853554030090092882, 3216392784293866897, 2348737267424860929])), BaseElement(U256([4739194499543469998, 15014957199891718088, 13105644150241677902, 2166824114813520745])), BaseElement(U256([12048347185403148384, 3177261455700405423, 705510453618472484, 1827307375322944127])), BaseElement(U256([12292130374133779973, 4696252587200951106, 16765826408111629446, 2201959680463040625]))],
+    [BaseElement(U256([5854767571165715726, 4212019089075968858, 9867759493839071559, 1123111934081063643])), BaseElement(U256([15461706018280627217, 12478772560098870427, 14786520956672956536, 534947950248580190])), BaseElement(U256([7853673074607274080, 2855578027938336638, 2526599510618993943, 860795390983808501])), BaseElement(U256([8757609982337490221, 5487968249940933459, 15754296299819157715, 1599879072192417688])), BaseElement(U256([888524426180274674, 15332167021493210118, 3795228580017771393, 2996482337775061392]))],
+    [BaseElement(U256([10726741335777756367, 4443443224452928955, 738965784977654399, 3073045662731990012])), BaseElement(U256([4576132762091364265, 12135466782711154841, 15914937061904795923, 200811055289400653])), BaseElement(U256([9214800878929177775, 16265012866919501050, 15660806895966682587, 1432488606997577079])), BaseElement(U256([5830034835058437391, 12695332414874394455, 5811512013729523542, 2351710051269063267])), BaseElement(U256([15059351679695236035, 7730235259813613163, 9133124037909179633, 1552405335845667856]))],
+    [BaseElement(U256([14731897498993234821, 5214479951763222154, 4260666081440200359, 1049886719076622442])), BaseElement(U256([8284124810789331950, 3904623402153842148, 4845404244690950313, 324314059596465193])), BaseElement(U256([12210545073594713818, 12892894332504137888, 8100205495420439430, 109014012842583663])), BaseElement(U256([16765449605414639817, 7156104826857039834, 10714170558198052736, 609682120423212480])), BaseElement(U256([16958520960224198060, 5160200791134432626, 15085717461293382414, 1250322804302141174]))],
+    [BaseElement(U256([2999554850716098465, 6444866370279640265, 13413667757931582044, 1307176170012679400])), BaseElement(U256([7865567997435981323, 12134793021711142451, 12361033231994361792, 876889442011121754])), BaseElement(U256([7912656310391283003, 2972635716356976126, 14409028120850852386, 2431293498971479769])), BaseElement(U256([10991729605966834584, 3674829293099218357, 11009315086642317779, 1870714246537395954])), BaseElement(U256([6245270376276921812, 14445910087408251916, 824636857512690937, 2431434404055312833]))],
+    [BaseElement(U256([4086483258494582810, 188689287394691252, 2073855180650497556, 23675056861467259])), BaseElement(U256([5875879579034020844, 591584519942339345, 16320954683778927013, 320929795230272927])), BaseElement(U256([10083725840669430289, 6622616878505123421, 15589269702640877343, 2793818487431952533])), BaseElement(U256([12937965969877799471, 7889314448420049293, 4533966851813292283, 1085480723045835186])), BaseElement(U256([6820004960420226035, 17622446351344465114, 5002713565695400906, 3343415168190641530]))],
+    [BaseElement(U256([16021832950443203906, 5643734199810841256, 14569187954807594609, 1346716921508191270])), BaseElement(U256([13692776214482858424, 746371788204424587, 16376143685300534302, 3057226104576989100])), BaseElement(U256([15456609355445637087, 7888908135169291832, 14073288657750008518, 525229032155264080])), BaseElement(U256([5552386206563954805, 11668051929716079713, 717485974091615934, 2640818449328050516])), BaseElement(U256([13390624528317073261, 430159462225724385, 16637370148812215925, 704880355037714184]))],
+    [BaseElement(U256([979524443095819179, 8125157278177788304, 17670201827973736585, 985190343606776275])), BaseElement(U256([8325661217316356800, 7171121215267169304, 17583984740282193551, 584662941373759060])), BaseElement(U256([6896090117271489867, 10134695347928432823, 18073958050006957408, 2933423262295750543])), BaseElement(U256([10038556692909058138, 11630229319776979081, 15182621289253070431, 2531591187459515569])), BaseElement(U256([11792653300375767317, 6973822607722082488, 10884966393542950799, 1610604812399295884]))],
+    [BaseElement(U256([13960636560631854041, 3085415225902553886, 16879159536193088165, 2430863344071731980])), BaseElement(U256([13935755477686416176, 18093863805038511256, 14870023862714267304, 1377067460991150262])), BaseElement(U256([2015127048696428500, 16084009051645419509, 16221535360321743398, 3036084172949613861])), BaseElement(U256([14359653778090550431, 15519069878705274952, 15359697674679683248, 582464064104912597])), BaseElement(U256([11566566769800486042, 2871121894377116983, 2368379380771854411, 872801492899828667]))],
+    [BaseElement(U256([10352997203981174773, 10945809268867834786, 13575290958876535199, 951417975245134115])), BaseElement(U256([15071887335330815486, 828590168955926239, 4793739748833065759, 2497720133073642886])), BaseElement(U256([14307791330165958809, 6152334371985739215, 14759842408533367304, 974182484708147650])), BaseElement(U256([7591800777522141601, 17486441756594739333, 14360703243176273811, 2507603745292343639])), BaseElement(U256([16869870380697801867, 12557072139023638562, 8921566896327162847, 1397050053868745685]))],
+    [BaseElement(U256([10725541650681818305, 17670418121211499450, 9940534850686255257, 698941056388509659])), BaseElement(U256([8065025920678673532, 2230255160005443809, 1249483376468946300, 483206066405849770])), BaseElement(U256([13285786636005207580, 18322700549533321921, 5293572064303505603, 1020464134333202513])), BaseElement(U256([16960890882088616893, 9912193767351172382, 3476166387657081288, 2973237556035735466])), BaseElement(U256([17813339275335327047, 15808025509148920489, 16723773597488475543, 1632471627676334567]))],
+    [BaseElement(U256([6358588587014870404, 15223896053353806716, 8473294082968954225, 536708283732791507])), BaseElement(U256([6395686503072924076, 4738893400430072457, 8854338375730792011, 2262164007044549118])), BaseElement(U256([16002134011979521477, 8301997546563979891, 10673536776923984460, 2679793969413355232])), BaseElement(U256([340703880492414860, 13671339799674697193, 12007131133086745033, 3103124882091844730])), BaseElement(U256([9894070283620616687, 612947807614525389, 11261788054445530568, 3327149707556500023]))],
+    [BaseElement(U256([7932663382578663897, 11601344657641810912, 8270413466496033179, 2101774377648216681])), BaseElement(U256([2550355602273700205, 1967145273955966751, 14048245950321725218, 1928104028005414609])), BaseElement(U256([5244119549053057891, 1019354113694079337, 14293247536627491986, 2654106291139262975])), BaseElement(U256([1178082545157414614, 14128231158879370336, 6709310514935465405, 3258516383673021624])), BaseElement(U256([5326612435571754876, 3292039723320210636, 3157643625055224980, 1996542764598392077]))],
+    [BaseElement(U256([10953264597319178253, 16878611970929016737, 1958403540417103597, 788742722776736159])), BaseElement(U256([12158192730071228730, 11886854677777022101, 8879168134860507324, 3282963556924701962])), BaseElement(U256([13093881716652819402, 1038649264532175077, 241109203639860760, 676266360103372442])), BaseElement(U256([915899168954676270, 4845527096948798581, 13245007553436233439, 3010395701360538702])), BaseElement(U256([7609846649639970141, 8560775933474968566, 5799078503524843614, 202659095825864827]))],
+    [BaseElement(U256([7914393880475717530, 7842325645028460263, 10771302399288560878, 902770114634426815])), BaseElement(U256([17776370071351483016, 6905489132274786868, 16150356131087426003, 3414947572443360861])), BaseElement(U256([6960708385521498232, 15100444758715439065, 10872660925920721989, 2177215634310970414])), BaseElement(U256([9313953465269803429, 41370794908689987, 4370102989172554621, 989054451011022145])), BaseElement(U256([14463817706007094750, 17287408181340802265, 15696794767119944472, 1864274348169964886]))],
+    [BaseElement(U256([5246380274446787345, 464814489189661367, 14205683901261567697, 919532318658533681])), BaseElement(U256([11338598804567343961, 10289665070709684567, 3810616532270532179, 3438394806944127184])), BaseElement(U256([8659816647297161972, 17584199394339965190, 784884349906946963, 502989703992030131])), BaseElement(U256([8018657286635912359, 235241470241108603, 7616090617779465889, 3343259394680491924])), BaseElement(U256([13607806550618628951, 14785332422010625142, 16048261297011341702, 1321507785247272638]))],
+    [BaseElement(U256([16286133810072075259, 11427975980757010977, 15767006663751459705, 154979285898248604])), BaseElement(U256([16967308077753076283, 2489243315653284254, 16564245725079760413, 1576993392624669499])), BaseElement(U256([10794094178121580927, 2686652507131516122, 17211810736714923635, 131504772520046483])), BaseElement(U256([234753098502404974, 8159230482768638078, 9381428717159180363, 2270401227202634915])), BaseElement(U256([14619149663771624577, 4288248661005590166, 5831868325049904496, 1782791347924888569]))],
+    [BaseElement(U256([8916210673555425042, 18333639587124625255, 14090382826103943980, 3375919732188349483])), BaseElement(U256([17864914865248082561, 13413576699182535445, 901545497969010366, 3394067231076403097])), BaseElement(U256([12330071289182905744, 7425168948368777305, 294832230760117972, 3099130494973199540])), BaseElement(U256([8095606119222068824, 8936298824912867028, 12097775099124712864, 3061922752893275095])), BaseElement(U256([17470760312977359048, 18158880046679533359, 4562274851466155119, 363894131359680317]))],
+    [BaseElement(U256([14766002353723612446, 3303620057531747714, 1122328659560620317, 439040673066052954])), BaseElement(U256([1198028610105458002, 14115156408667823708, 372506498712960797, 1845563252428195173])), BaseElement(U256([12056275027805304767, 13345557121649821600, 16261357659630571736, 2677081003941176612])), BaseElement(U256([2501695074245356811, 11140654411116623642, 9520369821862443083, 2994716602993200286])), BaseElement(U256([1811798198537864766, 11856281564377977192, 1325309690758342787, 272880584906027198]))],
+    [BaseElement(U256([3915050721883999283, 15576363050229855422, 8976652994594359620, 2973297406624923839])), BaseElement(U256([12848521189488397118, 15654072736723097589, 9042635738471667582, 752710337781625469])), BaseElement(U256([159561309054850587, 15093651614977751710, 10271603623893301913, 2314136870742353804])), BaseElement(U256([15788049094064462618, 12846420710533391187, 4812092750801852858, 305468889512595659])), BaseElement(U256([6008568652277007755, 16019635718565147450, 9974625495252612193, 2435144726719755889]))],
+    [BaseElement(U256([11709732888931524877, 5219389782620722901, 9560889098517889113, 1826128793096633774])), BaseElement(U256([8446321431118587172, 12607711703401778129, 10491430919066663689, 952536304453340128])), BaseElement(U256([687747013195761266, 8573073587345296440, 14630388839186657805, 1472940205710355816])), BaseElement(U256([4825906330806401545, 13762330026118317669, 16440900693335656457, 417383906698466030])), BaseElement(U256([2976950342957024622, 17866403083034178902, 2427360578766799039, 1590297928303189080]))],
+];
+
+pub const M_0_0: BaseElement = BaseElement(U256([9788408700862316784, 16565367001237555719, 7204362579762655067, 2963914115853885205]));
+
+pub static ROUND_CONSTANTS_OPTI: [[BaseElement; 6]; 65] = [
+    [BaseElement(U256([10459503811226335567, 14230253750685106293, 9336793203172286999, 1715984875808249622])), BaseElement(U256([13843931689457706560, 14301585504375398248, 9658456709992437648, 1629234957123081844])), BaseElement(U256([6446387601635992385, 4528519713071616554, 1477454365053016520, 2605768361401565437])), BaseElement(U256([3475997442834799776, 18208681867573731606, 14330406757906256628, 1531962156390387097])), BaseElement(U256([10701674841452297541, 7548143708019431487, 6943273732587889727, 1959016823698019078])), BaseElement(U256([7821895561953147328, 12305699988430712159, 4925662082590293456, 2921590315435745167]))],
+    [BaseElement(U256([11461919574408067233, 16467774279707448181, 507052456766742508, 1180026963844331792])), BaseElement(U256([12969150145014635402, 14510416771642588961, 3318378868142705490, 2626245944578381994])), BaseElement(U256([15466942969765470268, 12087532487085011216, 378086000842361583, 502312184218527550])), BaseElement(U256([9211502767346749984, 1729678274073894344, 7130250730105552991, 1956841074975258114])), BaseElement(U256([13820952345163586292, 8214599083521298015, 15580387376259606815, 1198290661751982004])), BaseElement(U256([10125111801567536180, 18280946245034345916, 11754024003656637889, 3242032582281650994]))],
+    [BaseElement(U256([16282844779274002652, 1022623689936855302, 12537561184027371457, 849850199656710311])), BaseElement(U256([9448353199145878965, 9844602341815833121, 9286769886465299423, 2220765007393497632])), BaseElement(U256([12249938222571747881, 5615696844724876064, 15245098653379468957, 1367381292253034508])), BaseElement(U256([6016603399851625797, 6106322441243088293, 6809392982251879096, 1378382238659942868])), BaseElement(U256([2985735936364150708, 11596416767090390179, 7451727769366520470, 2733099268317247677])), BaseElement(U256([17862651180515974923, 15118149544017098804, 2746833114134418182, 328111717547499207]))],
+    [BaseElement(U256([16273220833976926773, 4288777129645678500, 1010744189204116778, 2997584320700630650])), BaseElement(U256([17907782393017193514, 8302189280926510126, 7831014449214868783, 1254662188099200087])), BaseElement(U256([14252899634340293461, 363399240480492543, 6537483424522216022, 3273424765242594893])), BaseElement(U256([16576518404319180295, 5732995179925897496, 9447081839068946668, 2339103337359849435])), BaseElement(U256([8509009835286681389, 9548187653484083043, 4397833440564866188, 1328563692297918289])), BaseElement(U256([3157214397365984849, 15784484253618615213, 18161686358726752723, 1071320268382985630]))],
+    [BaseElement(U256([2013402965548774691, 5421032890051871314, 9331559201974746559, 1104058824286574316])), BaseElement(U256([1636625849623489596, 11438239416032011882, 13620730527974531341, 1307447685599877631])), BaseElement(U256([3713367341023480725, 10640408500646728795, 8507054671323398364, 2591541832514744020])), BaseElement(U256([10063613087050159741, 12071066915723979479, 13024848674251017004, 1829004565852704155])), BaseElement(U256([13302898780759969600, 11039103526111653233, 7878842534238111927, 1425389804012182466])), BaseElement(U256([4314754885271328366, 12048264131646779943, 12220471992809822692, 1775846150065339795]))],
+    [BaseElement(U256([17918727837680433990, 6307437603621592721, 3801654677356978008, 1567913145345551779])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([13392678846246512187, 7468564619467771443, 13907311880671789774, 1001794669701374711])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([6550372114386620291, 17734634024463137978, 10632623819273981389, 1438108652593754051])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([12286490666476828172, 15443841872282879764, 12969715298301688433, 1348513298379512191])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([3767829461047425707, 13921811975028384389, 6191766407031005321, 2254193363314772073])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([3030107194093612334, 16718691208862746468, 6525408987691693265, 2071755026620073953])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([111006654894439188, 5526233646315004931, 10739755991219107468, 2794464919102116453])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([12656383897474873706, 10848480384094182697, 16917236132767389343, 1513624593787378759])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([2073972677381694284, 5226900399916990112, 6518611221590604188, 494144961986062362])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([15872938610943563851, 6037072589267719531, 7014696867270031953, 1419832963382968625])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([13765894535551586045, 6762476300808013612, 15165681469711307396, 1218141431170162616])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([16213269643645560215, 16803195662218311273, 12676947459825012221, 1504097486371289200])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([17178509324496254988, 11403908388376367067, 2033397178085455659, 353856673199325507])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([24803623864191394, 16941072297813389512, 7770490584956850295, 835477758771193038])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([11631473223351387529, 7321540087608196076, 10769225070485229558, 3252466678936829506])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([16354838680612332329, 18003943450680486477, 7351432361095321425, 497158368767164607])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([13656588593621719952, 15492551185708320568, 10697144052626968423, 1283640251854716745])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([13207147274373552388, 12077407099009509866, 12448839196331263244, 1337448028707353604])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([7123171164833814604, 6670974069646435903, 6757146556455478930, 2717943439372935387])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([4367630344786248153, 6451523390866283295, 13627607927006075973, 1692312636001633617])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([10067019548696138639, 4194886782955312349, 7111924633027295027, 11649071907602373])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([7444482862126549344, 5052682607068408888, 8703874214173149915, 138516432412901172])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([17635506116931540011, 14162304376220432706, 4766990305016039536, 2829336166028899221])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([5792571796247656616, 11985694288217003019, 13813621358057602573, 3152940318346771131])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([1453599227525073602, 13817919732318783486, 8895622486711560460, 1905751106514264382])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([8111213606869015810, 3267685323025082330, 10432221724188998705, 1014220376420966254])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([17199556976182403745, 10791228715071775154, 5295485496778501558, 2285122464130974931])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([14285552872729786641, 2912630714266884648, 6125186961364696770, 2945782181480632797])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([2935889017206522084, 8415824205370619242, 8168014142552790068, 1108474349649599783])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([5104758835539717715, 577546095867806023, 10307193704052568925, 2566030618601161893])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([11523940739401614047, 14786222554616951791, 6220726366038488455, 1866476077653197622])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([1851892770410628741, 11834653085271738342, 9375593248054917681, 3221146952600967499])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([16586302637432168771, 9738605540347119233, 16296520006978355233, 490285603229628163])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([14437317836476243386, 12643766037508848507, 1341328037041478674, 2813172490588845833])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([16356198753973039438, 1928841744664024444, 16362866588682756733, 2769531148144942372])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([15761338583083255439, 4367067989505107718, 15871117020534555518, 1093399933049325979])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([7745675587554995620, 941440190294762148, 9207821325085550916, 1679046684650018732])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([696237407264928263, 11451015973650020904, 10022764790852651977, 3257874239744126909])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([1140280909332804056, 16691919975682980903, 7967865282185065570, 2381497138409658351])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([1633593619300940669, 4444614763143102386, 1102492875794408186, 3429090226248229331])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([15299517081086297794, 7379086319650324829, 13945286823929113384, 359225571845418210])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([12671521657216709551, 560497253388010990, 12611532805198722196, 740970685543671567])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([677146103298523197, 11124998092548282897, 3349409236997657638, 392612370571707029])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([1623422055735010326, 5341112471719897826, 12504613112331709362, 3055391711421987566])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([8093168008797001322, 9568629175611276139, 14142975675565689564, 412378740501678955])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([1271256036497563502, 4193657274545698140, 1596250359539282165, 1196891800748331809])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([18046412438398738158, 12511780006405819667, 16427506032349172587, 3460478525652644906])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([7988283064609291704, 6922767525102071020, 571323541166718423, 2738552614574590161])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([3111828393147695535, 11568636137717060657, 4520430426055613859, 1201679889424889300])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([17931775088973281944, 5993984351458009846, 5832328842571953006, 2339295510660694479])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([17551838948865166643, 13470320669829635643, 8584980302519916360, 1452390339026762178])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([138696212836984412, 6285488164283928882, 738663135129187529, 56007178370240387])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([9002968995043712977, 17797488065097933458, 9664513644055074626, 2375347055020522339])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([15458795254903143540, 10193704293465902663, 16364662108804993632, 1867021173705352717])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([14369695371045763487, 11225779248008825078, 7212211545350407778, 887197810555452627])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([2727592977611881121, 10339237575214618257, 9881959483620564633, 2329357637838931589])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0])), BaseElement(U256([0, 0, 0, 0]))],
+    [BaseElement(U256([10816119844867297037, 15830631829264487095, 11593144195149888655, 1367137224377463010])), BaseElement(U256([18392163191389339975, 14900242480596197717, 18266303765768712629, 188092217182888269])), BaseElement(U256([2888574549514430153, 16950538430489864093, 9095228303667566385, 2365987655994260824])), BaseElement(U256([3345310120488208494, 7038624493256447203, 3859397687977595747, 237115562819089917])), BaseElement(U256([3227300162637583289, 16129587443712708707, 16769393254161771508, 3455127980440511894])), BaseElement(U256([5187571989495815708, 9705951878564730047, 3853513089426348043, 1680664807824653900]))],
+    [BaseElement(U256([1057981247053808009, 3983427318919861134, 13367590751512779259, 2639544567278532750])), BaseElement(U256([14515542926855058741, 228134197113256904, 14113164811722581699, 3246786154110621189])), BaseElement(U256([2104541704213418299, 9735885606344608342, 6328998869388409039, 780812441836371473])), BaseElement(U256([636198105920687179, 15519124972712934656, 2676125527547435791, 2997890396999118582])), BaseElement(U256([7481387057167089479, 2879415634934524222, 14226860036423012801, 3102161018384175863])), BaseElement(U256([1487950439004053509, 1282201097115286614, 9890196199428694163, 711459532047793548]))],
+    [BaseElement(U256([9267899412811874475, 14842205993736765020, 5074704116694102427, 2854376725169414494])), BaseElement(U256([18098260899286064797, 10445278736095663111, 12004552100935076162, 882202404797875269])), BaseElement(U256([18061413995263467140, 8277465074545745437, 10219200582173298502, 1276418356082699019])), BaseElement(U256([8880678520814666548, 7679235142910163178, 18109623899297807829, 1367885359217945139])), BaseElement(U256([2060036360057749578, 15249500108911226672, 18179424740178844657, 2248528589978296795])), BaseElement(U256([4109768137128325810, 408828172548480953, 6549670106317527173, 2508959745291655866]))],
+    [BaseElement(U256([8139167740767730038, 12610677560383459512, 17284232976616326579, 99542968207377122])), BaseElement(U256([1512406163399458488, 9508790312374861867, 3052036175026504818, 140288587652561898])), BaseElement(U256([7082096206331923687, 1630210248469785787, 7785208541107731761, 3121187647297142245])), BaseElement(U256([10355836975786254683, 5633000752145223389, 5569775892992658995, 3050389736294435207])), BaseElement(U256([3930417354515800968, 12840704174484398138, 10782596246751444118, 3451465587516047745])), BaseElement(U256([7449861012138331953, 12179633797193843872, 3799916787799094181, 871798323864410205]))],
+];
+
@@ -1,105 +1,169 @@
-use crate::hash::ByteDigest;
-
-//padding with 0s and a single one
-use super::param::*;
+use super::{param, param_t10, param_t6};
 use math::{fields::f256::BaseElement, FieldElement};
 use std::vec::Vec;
 
-pub(crate) fn digest(input: &[u8]) -> ByteDigest<32> {
-    let mut formatted_input: Vec<BaseElement> = vec![];
+/// Parameters of the Poseidon permutation over a state of `T` elements, `RATE` being `T - 1`.
+pub(crate) struct Parameters<const T: usize, const RATE: usize> {
+    pub(crate) alpha: u32,
+    pub(crate) r_f: usize,
+    pub(crate) r_p: usize,
+    pub(crate) mds: [[BaseElement; T]; T],
+    pub(crate) mp: [[BaseElement; T]; T],
+    pub(crate) v_collection: &'static [[BaseElement; RATE]],
+    pub(crate) w_hat_collection: &'static [[BaseElement; RATE]],
+    pub(crate) m_0_0: BaseElement,
+    pub(crate) round_constants: &'static [[BaseElement; T]],
+}
 
-    for chunk in input.chunks(32) {
-        formatted_input.push(BaseElement::from_le_bytes(chunk));
-    }
+macro_rules! parameters {
+    ($param:ident) => {
+        Parameters {
+            alpha: $param::ALPHA,
+            r_f: $param::R_F,
+            r_p: $param::R_P,
+            mds: $param::MDS,
+            mp: $param::MP,
+            v_collection: &$param::V_COLLECTION,
+            w_hat_collection: &$param::W_HAT_COLLECTION,
+            m_0_0: $param::M_0_0,
+            round_constants: &$param::ROUND_CONSTANTS_OPTI,
+        }
+    };
+}
 
-    let mut output = formatted_input.clone();
+/// Parameters of the width 4 permutation, used for hashing.
+pub(crate) static PARAMETERS: Parameters<{ param::T }, { param::RATE }> = parameters!(param);
 
-    padder(&mut output);
+/// Parameters of the width 6 permutation, used to merge 4 digests.
+pub(crate) static PARAMETERS_T6: Parameters<{ param_t6::T }, { param_t6::RATE }> =
+    parameters!(param_t6);
 
-    ByteDigest(hash(&mut output))
-}
+/// Parameters of the width 10 permutation, used to merge 8 digests.
+pub(crate) static PARAMETERS_T10: Parameters<{ param_t10::T }, { param_t10::RATE }> =
+    parameters!(param_t10);
 
-pub(crate) fn padder(input: &mut Vec<BaseElement>) {
-    let l = input.len();
-    let padded_length = (l / RATE + 1) * RATE;
+/// Hash a sequence of bytes, interpreting every 32 bytes as a field element.
+pub(crate) fn digest(input: &[u8]) -> BaseElement {
+    sponge(&bytes_to_elements(input))
+}
 
-    input.push(BaseElement::ONE);
-    for _i in l + 1..padded_length {
-        input.push(BaseElement::ZERO)
-    }
+/// Interpret every 32 bytes of the input as a field element in little-endian order, so that the
+/// bytes of f256 field elements are read back as the same elements.
+pub(crate) fn bytes_to_elements(input: &[u8]) -> Vec<BaseElement> {
+    input.chunks(32).map(BaseElement::from_le_bytes).collect()
 }
 
-pub(crate) fn hash(input: &mut Vec<BaseElement>) -> [u8; 32 * DIGEST_SIZE] {
-    let ref mut state = [BaseElement::ZERO; T].to_vec();
+/// Absorb the input, padded with a single one and zeros up to a multiple of
+/// the rate of [PARAMETERS], and return the first element of the state.
+pub(crate) fn sponge(input: &[BaseElement]) -> BaseElement {
+    let mut state = [BaseElement::ZERO; param::T];
 
-    for i in 0..input.len() / RATE {
+    let mut i = 0;
+    for &element in input.iter().chain([BaseElement::ONE].iter()) {
         //absorbtion
-        for j in 0..RATE {
-            state[j] += input[i * RATE + j]
+        state[i] += element;
+        i += 1;
+        if i == param::RATE {
+            permutation(&PARAMETERS, &mut state);
+            i = 0;
         }
-        permutation(state);
     }
 
-    let mut output = [0_u8; 32 * DIGEST_SIZE];
-    for i in 0..DIGEST_SIZE {
-        output[i..i + 32].copy_from_slice(&state[i].to_le_bytes())
+    // the remaining rate elements are padded with zeros
+    if i > 0 {
+        permutation(&PARAMETERS, &mut state);
     }
 
-    output
+    state[0]
 }
 
-pub(crate) fn permutation(input: &mut Vec<BaseElement>) {
-    let ref mut state = input.clone()[..T].to_vec();
+/// Hash `T - 2` elements with a single permutation of width `T`. The padded
+/// input fits in the rate, so this gives the same result as a sponge of rate
+/// `T - 1`, and as [sponge] for two elements.
+pub(crate) fn compress<const T: usize, const RATE: usize>(
+    params: &Parameters<T, RATE>,
+    input: &[BaseElement],
+) -> BaseElement {
+    assert_eq!(input.len(), T - 2);
+    let mut state = [BaseElement::ZERO; T];
+    state[..T - 2].copy_from_slice(input);
+    state[T - 2] = BaseElement::ONE;
+
+    permutation(params, &mut state);
+
+    state[0]
+}
 
-    for j in 0..R_F / 2 {
-        full_round(state, j);
+pub(crate) fn permutation<const T: usize, const RATE: usize>(
+    params: &Parameters<T, RATE>,
+    state: &mut [BaseElement],
+) {
+    for j in 0..params.r_f / 2 {
+        full_round(params, state, j);
     }
 
-    for j in 0..R_P {
-        partial_round(state, j + R_F / 2);
+    for j in 0..params.r_p {
+        partial_round(params, state, j + params.r_f / 2);
     }
 
-    for j in 0..R_F / 2 {
-        full_round(state, j + R_F / 2 + R_P);
+    for j in 0..params.r_f / 2 {
+        full_round(params, state, j + params.r_f / 2 + params.r_p);
     }
-
-    input[..T].copy_from_slice(&state);
 }
 
-pub(crate) fn full_round(state: &mut Vec<BaseElement>, i: usize) {
-    add_constants(state, i);
-    apply_sbox(state);
-    apply_mds(state);
+pub(crate) fn full_round<const T: usize, const RATE: usize>(
+    params: &Parameters<T, RATE>,
+    state: &mut [BaseElement],
+    i: usize,
+) {
+    add_constants(params, state, i);
+    apply_sbox(params, state);
+    apply_mds(params, state);
 }
 
-pub(crate) fn partial_round(state: &mut Vec<BaseElement>, i: usize) {
-    if i == R_F / 2 {
-        add_constants(state, i);
-        matrix_mul(state, MP);
+pub(crate) fn partial_round<const T: usize, const RATE: usize>(
+    params: &Parameters<T, RATE>,
+    state: &mut [BaseElement],
+    i: usize,
+) {
+    let (r_f, r_p) = (params.r_f, params.r_p);
+    if i == r_f / 2 {
+        add_constants(params, state, i);
+        matrix_mul(state, params.mp);
     }
 
-    state[0] = state[0].exp(ALPHA.into());
+    state[0] = state[0].exp(params.alpha.into());
 
-    if i < R_F / 2 + R_P - 1 {
-        state[0] += ROUND_CONSTANTS_OPTI[i+1][0];
+    if i < r_f / 2 + r_p - 1 {
+        state[0] += params.round_constants[i + 1][0];
     }
 
-    sparse_matrix(state, R_P - 1 - (i - R_F / 2));
+    sparse_matrix(params, state, r_p - 1 - (i - r_f / 2));
 }
 
-pub(crate) fn add_constants(state: &mut [BaseElement], round: usize) {
-    for i in 0..T {
-        state[i] += ROUND_CONSTANTS_OPTI[round][i];
+pub(crate) fn add_constants<const T: usize, const RATE: usize>(
+    params: &Parameters<T, RATE>,
+    state: &mut [BaseElement],
+    round: usize,
+) {
+    for (element, constant) in state.iter_mut().zip(params.round_constants[round].iter()) {
+        *element += *constant;
     }
 }
 
-pub(crate) fn apply_sbox<E: FieldElement>(state: &mut [E]) {
+pub(crate) fn apply_sbox<E: FieldElement, const T: usize, const RATE: usize>(
+    params: &Parameters<T, RATE>,
+    state: &mut [E],
+) {
     for i in 0..T {
-        state[i] = state[i].exp(ALPHA.into());
+        state[i] = state[i].exp(params.alpha.into());
     }
 }
 
-pub(crate) fn matrix_mul<E: FieldElement + From<BaseElement>>(state: &mut [E],m: [[BaseElement; T]; T] ) {
+pub(crate) fn matrix_mul<E: FieldElement + From<BaseElement>, const T: usize>(
+    state: &mut [E],
+    m: [[BaseElement; T]; T],
+) {
     let mut result = [E::ZERO; T];
     let mut temp = [E::ZERO; T];
     for i in 0..T {
@@ -114,32 +178,37 @@ pub(crate) fn matrix_mul<E: FieldElement + From<BaseElement>>(state: &mut [E],m:
     state.copy_from_slice(&result);
 }
 
-pub(crate) fn sparse_matrix(state: &mut [BaseElement],i: usize) {
-    let _v = V_COLLECTION[i];
-    let _w = W_HAT_COLLECTION[i];
+pub(crate) fn sparse_matrix<const T: usize, const RATE: usize>(
+    params: &Parameters<T, RATE>,
+    state: &mut [BaseElement],
+    i: usize,
+) {
     let s0 = state[0];
 
-    state[0] *= M_0_0;
+    state[0] *= params.m_0_0;
 
     for j in 1..T {
-        state[0] += W_HAT_COLLECTION[i][j - 1] * state[j];
+        state[0] += params.w_hat_collection[i][j - 1] * state[j];
     }
 
-    for j in 1..T {
-        state[j] += s0 * V_COLLECTION[i][j - 1];
+    for (element, v) in state[1..].iter_mut().zip(params.v_collection[i].iter()) {
+        *element += s0 * *v;
     }
 }
 
-pub(crate) fn apply_mds<E: FieldElement + From<BaseElement>>(state: &mut [E]) {
+pub(crate) fn apply_mds<E: FieldElement + From<BaseElement>, const T: usize, const RATE: usize>(
+    params: &Parameters<T, RATE>,
+    state: &mut [E],
+) {
     let mut result = [E::ZERO; T];
     let mut temp = [E::ZERO; T];
-    for i in 0..T {
+    for (row, sum) in params.mds.iter().zip(result.iter_mut()) {
         for j in 0..T {
-            temp[j] = E::from(MDS[i][j]) * state[j];
+            temp[j] = E::from(row[j]) * state[j];
         }
 
         for j in 0..T {
-            result[i] += temp[j];
+            *sum += temp[j];
         }
     }
     state.copy_from_slice(&result);
@@ -1,8 +1,13 @@
 use super::param::*;
-use super::poseidon;
-use math::fields::f256::{BaseElement, U256};
-use math::FieldElement;
-use rand_utils::rand_array;
+use super::{poseidon, poseidon::PARAMETERS, ElementDigest, Poseidon};
+use crate::hash::{Digest, ElementHasher, Hasher};
+use math::fields::{
+    f128,
+    f256::{BaseElement, U256},
+};
+use math::{FieldElement, StarkField};
+use rand_utils::{rand_array, rand_value};
+use utils::{Deserializable, Serializable, SliceReader};
 
 #[test]
 fn test_sbox() {
@@ -12,7 +17,7 @@ fn test_sbox() {
     expected.iter_mut().for_each(|v| *v = v.exp(ALPHA.into()));
 
     let mut actual = state;
-    poseidon::apply_sbox(&mut actual);
+    poseidon::apply_sbox(&PARAMETERS, &mut actual);
     println!("{:?}", actual);
     assert_eq!(expected, actual);
 }
@@ -21,7 +26,7 @@ fn test_sbox() {
 fn test_mds() {
     let mut state = element_vec(T, &|i| i);
 
-    poseidon::apply_mds(&mut state);
+    poseidon::apply_mds(&PARAMETERS, &mut state);
 
     // expected values are obtained by executing sage reference implementation code
 
@@ -49,7 +54,7 @@ fn test_mds() {
 fn test_constants() {
     let mut state = element_vec(T, &|i| i);
 
-    poseidon::add_constants(&mut state, 0);
+    poseidon::add_constants(&PARAMETERS, &mut state, 0);
 
     // expected values are obtained by executing sage reference implementation code
     let expected = [
@@ -95,18 +100,16 @@ fn test_permutation() {
             "0x3ff622da276830b9451b88b85e6184fd6ae15c8ab3ee25a5667be8592cce3b1",
         )),
     ];
-    poseidon::permutation(&mut state);
+    poseidon::permutation(&PARAMETERS, &mut state);
     dbg!(&state);
     assert_eq!(state, expected);
 }
 
 #[test]
 fn test_hash() {
-    let mut state = element_vec(T, &|i| i);
-
-    poseidon::padder(&mut state);
+    let state = element_vec(T, &|i| i);
 
-    let output = poseidon::hash(&mut state);
+    let output = poseidon::sponge(&state).to_le_bytes();
 
     // expected values are obtained by executing sage reference implementation code
     let expected: [u8; 32] = [
@@ -116,6 +119,112 @@ fn test_hash() {
     assert_eq!(expected, output);
 }
 
+#[test]
+fn hash_elements_matches_hash() {
+    let elements: [BaseElement; 7] = rand_array();
+
+    let expected = Poseidon::<BaseElement>::hash(BaseElement::elements_as_bytes(&elements));
+    assert_eq!(expected, Poseidon::<BaseElement>::hash_elements(&elements));
+    assert_eq!(
+        expected.as_element(),
+        Poseidon::<BaseElement>::hash_field_elements(&elements)
+    );
+}
+
+#[test]
+fn hash_elements_absorbs_elements() {
+    // the elements are absorbed by the sponge as they are, without being serialized
+    let elements: [BaseElement; 7] = rand_array();
+    assert_eq!(
+        Poseidon::<BaseElement>::hash_elements(&elements).as_element(),
+        poseidon::sponge(&elements)
+    );
+
+    // the elements of smaller fields are absorbed with their value
+    let elements: [f128::BaseElement; 5] = rand_array();
+    let values: Vec<BaseElement> = elements
+        .iter()
+        .map(|element| BaseElement::from(element.as_int()))
+        .collect();
+    assert_eq!(
+        Poseidon::<f128::BaseElement>::hash_elements(&elements).as_element(),
+        poseidon::sponge(&values)
+    );
+}
+
+#[test]
+fn merge_matches_hash_elements() {
+    let digests = [
+        ElementDigest::new(rand_value()),
+        ElementDigest::new(rand_value()),
+    ];
+    let elements = [digests[0].as_element(), digests[1].as_element()];
+
+    let expected = Poseidon::<BaseElement>::hash_elements(&elements);
+    assert_eq!(expected, Poseidon::<BaseElement>::merge(&digests));
+    assert_eq!(
+        expected.as_element(),
+        Poseidon::<BaseElement>::compress(elements)
+    );
+
+    let value = rand_value::<u64>();
+    assert_eq!(
+        Poseidon::<BaseElement>::hash_elements(&[elements[0], BaseElement::from(value)]),
+        Poseidon::<BaseElement>::merge_with_int(digests[0], value)
+    );
+}
+
+#[test]
+fn merge_many() {
+    let digests: Vec<ElementDigest> = (0..8).map(|_| ElementDigest::new(rand_value())).collect();
+
+    // two digests are merged as by merge, whatever the arity
+    let expected = Poseidon::<BaseElement>::merge(&[digests[0], digests[1]]);
+    assert_eq!(expected, Poseidon::<BaseElement>::merge_many(&digests[..2]));
+    assert_eq!(
+        expected,
+        Poseidon::<BaseElement, 4>::merge(&[digests[0], digests[1]])
+    );
+    assert_eq!(
+        expected,
+        Poseidon::<BaseElement, 8>::merge_many(&digests[..2])
+    );
+
+    // the digests of the 4-ary and 8-ary trees depend on all the children
+    for arity in [4, 8] {
+        let digest = Poseidon::<BaseElement, 4>::merge_many(&digests[..arity]);
+        for i in 0..arity {
+            let mut children = digests[..arity].to_vec();
+            children[i] = ElementDigest::new(rand_value());
+            assert_ne!(digest, Poseidon::<BaseElement, 4>::merge_many(&children));
+        }
+    }
+    assert_eq!(Poseidon::<BaseElement, 4>::MERKLE_ARITY, 4);
+    assert_eq!(Poseidon::<BaseElement>::MERKLE_ARITY, 2);
+}
+
+#[test]
+#[should_panic]
+fn merge_many_unsupported_length() {
+    let digests = [ElementDigest::new(rand_value()); 3];
+    Poseidon::<BaseElement, 4>::merge_many(&digests);
+}
+
+#[test]
+fn digest_serialization() {
+    let d1 = ElementDigest::new(rand_value());
+
+    let mut bytes = vec![];
+    d1.write_into(&mut bytes);
+    assert_eq!(32, bytes.len());
+    assert_eq!(d1.as_bytes().to_vec(), bytes);
+
+    let mut reader = SliceReader::new(&bytes);
+    let d2 = ElementDigest::read_from(&mut reader).unwrap();
+
+    assert_eq!(d1, d2);
+}
+
 //HELPER FUNCTION
 
 fn element_vec(n: usize, f: &dyn Fn(usize) -> usize) -> Vec<BaseElement> {
@@ -0,0 +1,91 @@
+mod param;
+use core::marker::PhantomData;
+
+mod permutation;
+
+#[cfg(test)]
+mod tests;
+
+use super::{
+    poseidon::{as_f256_elements, ElementDigest},
+    ElementHasher, Hasher,
+};
+use math::{fields::f256::BaseElement, FieldElement, StarkField};
+
+// POSEIDON2 WITH 256-BIT OUTPUT
+// ===============================================================================================
+/// Implementation of the [Hasher](super::Hasher) trait for the POSEIDON2 hash function with
+/// 256-bit output.
+///
+/// The permutation has a state of width 3, with the round constants of the reference
+/// implementation over the f256 field. The input is absorbed by a sponge of rate 2, whose
+/// capacity element is initialized to the number of input elements, the last chunk of the input
+/// being padded with zeros. Two elements are therefore hashed with a single permutation, which
+/// is the compression of the Merkle trees, and the `HashElements` template of the Circom
+/// verifier hashes the same way.
+///
+/// The digest is a single f256 field element, the same as the one of
+/// [Poseidon](super::Poseidon), and the elements of the base field are absorbed as f256 field
+/// elements, without being serialized. Only binary Merkle trees are supported.
+pub struct Poseidon2<B: StarkField>(PhantomData<B>);
+
+impl<B: StarkField> Hasher for Poseidon2<B> {
+    type Digest = ElementDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        ElementDigest::new(permutation::digest(bytes))
+    }
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        ElementDigest::new(permutation::compress([
+            values[0].as_element(),
+            values[1].as_element(),
+        ]))
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        ElementDigest::new(permutation::compress([
+            seed.as_element(),
+            BaseElement::from(value),
+        ]))
+    }
+}
+
+impl<B: StarkField + Into<BaseElement>> ElementHasher for Poseidon2<B> {
+    type BaseField = B;
+
+    fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        ElementDigest::new(permutation::sponge(&as_f256_elements(elements)))
+    }
+}
+
+impl<B: StarkField> Poseidon2<B> {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The number of field elements in the state.
+    pub const STATE_WIDTH: usize = param::T;
+
+    /// The number of field elements absorbed by the sponge before every permutation.
+    pub const RATE: usize = param::RATE;
+
+    // POSEIDON2 PERMUTATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies the Poseidon2 permutation to the provided state.
+    pub fn apply_permutation(state: &mut [BaseElement; param::T]) {
+        permutation::permutation(state);
+    }
+
+    /// Returns the first element of the state after absorbing the provided elements, the
+    /// capacity element being initialized to their number.
+    pub fn hash_field_elements(elements: &[BaseElement]) -> BaseElement {
+        permutation::sponge(elements)
+    }
+
+    /// Returns the hash of two field elements, with a single permutation. This is the same as
+    /// hashing them with [hash_field_elements](Poseidon2::hash_field_elements).
+    pub fn compress(values: [BaseElement; 2]) -> BaseElement {
+        permutation::compress(values)
+    }
+}
@@ -0,0 +1,95 @@
+use super::param::{ALPHA, EXTERNAL_ROUND_CONSTANTS, INTERNAL_ROUND_CONSTANTS, RATE, R_F, R_P, T};
+use crate::hash::poseidon::bytes_to_elements;
+use math::{fields::f256::BaseElement, FieldElement};
+
+/// Diagonal of the internal matrix `1 + diag(1, 1, 2)` of width 3, where `1` is the matrix of
+/// ones.
+const INTERNAL_DIAGONAL: [u64; T] = [1, 1, 2];
+
+/// Hash a sequence of bytes, interpreting every 32 bytes as a field element.
+pub(crate) fn digest(input: &[u8]) -> BaseElement {
+    sponge(&bytes_to_elements(input))
+}
+
+/// Absorb the input, the capacity element being initialized to its length and its last chunk
+/// being padded with zeros, and return the first element of the state.
+pub(crate) fn sponge(input: &[BaseElement]) -> BaseElement {
+    let mut state = [BaseElement::ZERO; T];
+    state[RATE] = BaseElement::from(input.len() as u64);
+
+    for chunk in input.chunks(RATE) {
+        for (element, value) in state.iter_mut().zip(chunk) {
+            *element += *value;
+        }
+        permutation(&mut state);
+    }
+
+    // the empty input is hashed with a single permutation as well
+    if input.is_empty() {
+        permutation(&mut state);
+    }
+
+    state[0]
+}
+
+/// Hash two elements with a single permutation, which gives the same result as [sponge].
+pub(crate) fn compress(input: [BaseElement; 2]) -> BaseElement {
+    let mut state = [input[0], input[1], BaseElement::from(2u64)];
+
+    permutation(&mut state);
+
+    state[0]
+}
+
+pub(crate) fn permutation(state: &mut [BaseElement; T]) {
+    external_matrix(state);
+
+    for round in 0..R_F / 2 {
+        full_round(state, round);
+    }
+
+    for constant in INTERNAL_ROUND_CONSTANTS[..R_P].iter() {
+        state[0] += *constant;
+        state[0] = state[0].exp(ALPHA.into());
+        internal_matrix(state);
+    }
+
+    for round in R_F / 2..R_F {
+        full_round(state, round);
+    }
+}
+
+pub(crate) fn full_round(state: &mut [BaseElement; T], round: usize) {
+    for (element, constant) in state.iter_mut().zip(EXTERNAL_ROUND_CONSTANTS[round].iter()) {
+        *element += *constant;
+    }
+    apply_sbox(state);
+    external_matrix(state);
+}
+
+pub(crate) fn apply_sbox(state: &mut [BaseElement; T]) {
+    for element in state.iter_mut() {
+        *element = element.exp(ALPHA.into());
+    }
+}
+
+/// Multiply the state by the external matrix `circ(2, 1, 1)`, i.e. add the sum of the state to
+/// each element.
+pub(crate) fn external_matrix(state: &mut [BaseElement; T]) {
+    let sum = state
+        .iter()
+        .fold(BaseElement::ZERO, |sum, &element| sum + element);
+    for element in state.iter_mut() {
+        *element += sum;
+    }
+}
+
+/// Multiply the state by the internal matrix `1 + diag(1, 1, 2)`.
+pub(crate) fn internal_matrix(state: &mut [BaseElement; T]) {
+    let sum = state
+        .iter()
+        .fold(BaseElement::ZERO, |sum, &element| sum + element);
+    for (element, diagonal) in state.iter_mut().zip(INTERNAL_DIAGONAL) {
+        *element = *element * BaseElement::from(diagonal) + sum;
+    }
+}
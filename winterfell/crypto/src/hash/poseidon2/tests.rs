@@ -0,0 +1,94 @@
+use super::{permutation, ElementDigest, Poseidon2};
+use crate::hash::{ElementHasher, Hasher};
+use math::fields::f256::{BaseElement, U256};
+use math::FieldElement;
+use rand_utils::{rand_array, rand_value};
+
+#[test]
+fn test_permutation() {
+    let mut state = [BaseElement::ZERO, BaseElement::ONE, BaseElement::from(2u64)];
+
+    Poseidon2::<BaseElement>::apply_permutation(&mut state);
+
+    // expected values are the test vector of the reference implementation for BN254, t = 3
+    let expected = [
+        BaseElement(U256::from(
+            "0x0bb61d24daca55eebcb1929a82650f328134334da98ea4f847f760054f4a3033",
+        )),
+        BaseElement(U256::from(
+            "0x303b6f7c86d043bfcbcc80214f26a30277a15d3f74ca654992defe7ff8d03570",
+        )),
+        BaseElement(U256::from(
+            "0x1ed25194542b12eef8617361c3ba7c52e660b145994427cc86296242cf766ec8",
+        )),
+    ];
+    assert_eq!(state, expected);
+}
+
+#[test]
+fn test_sponge() {
+    // the capacity element is initialized to the number of elements
+    let expected = [
+        "0x2ed1da00b14d635bd35b88ab49390d5c13c90da7e9e3a5f1ea69cd87a0aa3e82",
+        "0x004ac84817ce56f90be91879987055fa1f3aa48e277cfaaeba165dc2b50e83a8",
+        "0x17e23ca37a1710c6d2c2b26f2578d2729717d8a2cb77d6670398b5a537775b1a",
+        "0x13848d1d008e9cf1cd33caadf7cf1d592b11f212f6d8f799d5793a72ae967732",
+    ];
+    for (length, expected) in [0, 1, 2, 5].into_iter().zip(expected) {
+        let elements: Vec<BaseElement> = (1..=length as u64).map(BaseElement::from).collect();
+        assert_eq!(
+            permutation::sponge(&elements),
+            BaseElement(U256::from(expected))
+        );
+    }
+}
+
+#[test]
+fn hash_elements_matches_hash() {
+    let elements: [BaseElement; 7] = rand_array();
+
+    let expected = Poseidon2::<BaseElement>::hash(BaseElement::elements_as_bytes(&elements));
+    assert_eq!(expected, Poseidon2::<BaseElement>::hash_elements(&elements));
+    assert_eq!(
+        expected.as_element(),
+        Poseidon2::<BaseElement>::hash_field_elements(&elements)
+    );
+}
+
+#[test]
+fn hash_elements_absorbs_elements() {
+    // two elements are absorbed as they are by a single permutation, the capacity element being
+    // their number, as by the HashElements template of the circuit
+    let elements: [BaseElement; 2] = rand_array();
+    let mut state = [elements[0], elements[1], BaseElement::from(2u64)];
+    Poseidon2::<BaseElement>::apply_permutation(&mut state);
+    assert_eq!(
+        Poseidon2::<BaseElement>::hash_elements(&elements).as_element(),
+        state[0]
+    );
+
+    let elements: [BaseElement; 7] = rand_array();
+    assert_eq!(
+        Poseidon2::<BaseElement>::hash_elements(&elements).as_element(),
+        permutation::sponge(&elements)
+    );
+}
+
+#[test]
+fn merge_matches_hash_elements() {
+    let values: [BaseElement; 2] = rand_array();
+    let digests = values.map(ElementDigest::new);
+
+    let expected = Poseidon2::<BaseElement>::hash_elements(&values);
+    assert_eq!(expected, Poseidon2::<BaseElement>::merge(&digests));
+    assert_eq!(
+        expected.as_element(),
+        Poseidon2::<BaseElement>::compress(values)
+    );
+
+    let value: u64 = rand_value();
+    assert_eq!(
+        Poseidon2::<BaseElement>::merge_with_int(digests[0], value),
+        Poseidon2::<BaseElement>::hash_elements(&[values[0], BaseElement::from(value)])
+    );
+}
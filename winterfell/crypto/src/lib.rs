@@ -33,6 +33,8 @@ pub mod hashers {
     pub use super::hash::Rp64_256;
     pub use super::hash::Sha3_256;
     pub use super::hash::Poseidon;
+    pub use super::hash::Poseidon2;
+    pub use super::hash::PoseidonDigest;
 }
 
 mod merkle;
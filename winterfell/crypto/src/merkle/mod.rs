@@ -4,9 +4,8 @@
 // LICENSE file in the root directory of this source tree.
 
 use crate::{errors::MerkleTreeError, hash::Hasher};
-use core::slice;
 use math::log2;
-use utils::collections::{BTreeMap, BTreeSet, Vec};
+use utils::collections::{BTreeMap, Vec};
 
 mod proofs;
 pub use proofs::BatchMerkleProof;
@@ -45,9 +44,15 @@ mod tests;
 /// The depth of a tree is zero-based. Thus, a tree with two leaves has depth 1, a tree with four
 /// leaves has depth 2 etc.
 ///
+/// Every internal node has [MERKLE_ARITY](Hasher::MERKLE_ARITY) children, which are hashed with
+/// [merge_many](Hasher::merge_many). For arities other than two, the number of leaves must be a
+/// power of the arity, and the depth is the number of levels of internal nodes, e.g. a 4-ary tree
+/// with 16 leaves has depth 2. A Merkle path then contains `arity - 1` siblings per level.
+///
 /// When the crate is compiled with `concurrent` feature enabled, tree construction will be
 /// performed in multiple threads (usually, as many threads as there are logical cores on the
-/// machine). The number of threads can be configured via `RAYON_NUM_THREADS` environment variable.
+/// machine) for binary trees. The number of threads can be configured via `RAYON_NUM_THREADS`
+/// environment variable.
 ///
 /// To generate an inclusion proof for a given leaf, [MerkleTree::prove()] method can be used.
 /// You can also use [MerkleTree::prove_batch()] method to generate inclusion proofs for multiple
@@ -103,21 +108,29 @@ impl<H: Hasher> MerkleTree<H> {
     ///
     /// # Errors
     /// Returns an error if:
-    /// * Fewer than two leaves were provided.
+    /// * Fewer leaves than the arity of the tree were provided.
     /// * Number of leaves is not a power of two.
+    /// * Number of leaves is not a power of the arity of the tree.
     pub fn new(leaves: Vec<H::Digest>) -> Result<Self, MerkleTreeError> {
-        if leaves.len() < 2 {
-            return Err(MerkleTreeError::TooFewLeaves(2, leaves.len()));
+        let arity = H::MERKLE_ARITY;
+        if leaves.len() < arity {
+            return Err(MerkleTreeError::TooFewLeaves(arity, leaves.len()));
         }
         if !leaves.len().is_power_of_two() {
             return Err(MerkleTreeError::NumberOfLeavesNotPowerOfTwo(leaves.len()));
         }
+        if log2(leaves.len()) % log2(arity) != 0 {
+            return Err(MerkleTreeError::NumberOfLeavesNotPowerOfArity(
+                arity,
+                leaves.len(),
+            ));
+        }
 
         #[cfg(not(feature = "concurrent"))]
         let nodes = build_merkle_nodes::<H>(&leaves);
 
         #[cfg(feature = "concurrent")]
-        let nodes = if leaves.len() <= concurrent::MIN_CONCURRENT_LEAVES {
+        let nodes = if arity != 2 || leaves.len() <= concurrent::MIN_CONCURRENT_LEAVES {
             build_merkle_nodes::<H>(&leaves)
         } else {
             concurrent::build_merkle_nodes::<H>(&leaves)
@@ -137,9 +150,10 @@ impl<H: Hasher> MerkleTree<H> {
     /// Returns depth of the tree.
     ///
     /// The depth of a tree is zero-based. Thus, a tree with two leaves has depth 1, a tree with
-    /// four leaves has depth 2 etc.
+    /// four leaves has depth 2 etc. For other arities, the depth is the number of levels of
+    /// internal nodes.
     pub fn depth(&self) -> usize {
-        log2(self.leaves.len()) as usize
+        (log2(self.leaves.len()) / log2(H::MERKLE_ARITY)) as usize
     }
 
     /// Returns leaf nodes of the tree.
@@ -152,7 +166,8 @@ impl<H: Hasher> MerkleTree<H> {
 
     /// Returns a Merkle path to a leaf at the specified `index`.
     ///
-    /// The leaf itself will be the first element in the path.
+    /// The leaf itself will be the first element in the path, followed by its `arity - 1` siblings
+    /// and by the siblings of its ancestors, in the order of the nodes.
     ///
     /// # Errors
     /// Returns an error if the specified index is greater than or equal to the number of leaves
@@ -165,12 +180,16 @@ impl<H: Hasher> MerkleTree<H> {
             ));
         }
 
-        let mut proof = vec![self.leaves[index], self.leaves[index ^ 1]];
+        let arity = H::MERKLE_ARITY;
+        let mut proof = vec![self.leaves[index]];
 
-        let mut index = (index + self.nodes.len()) >> 1;
-        while index > 1 {
-            proof.push(self.nodes[index ^ 1]);
-            index >>= 1;
+        let mut position = index + self.nodes.len();
+        while position > 1 {
+            let parent = parent_position(position, arity);
+            for sibling in child_positions(parent, arity).filter(|&sibling| sibling != position) {
+                proof.push(self.node(sibling));
+            }
+            position = parent;
         }
 
         Ok(proof)
@@ -196,51 +215,20 @@ impl<H: Hasher> MerkleTree<H> {
             ));
         }
 
-        let index_map = map_indexes(indexes, self.depth())?;
-        let indexes = normalize_indexes(indexes);
+        let index_map = map_indexes(indexes, self.leaves.len())?;
         let mut leaves = vec![H::Digest::default(); index_map.len()];
-        let mut nodes: Vec<Vec<H::Digest>> = Vec::with_capacity(indexes.len());
-
-        // populate the proof with leaf node values
-        let n = self.leaves.len();
-        let mut next_indexes: Vec<usize> = Vec::new();
-        for index in indexes {
-            let missing: Vec<H::Digest> = (index..index + 2)
-                .flat_map(|i| {
-                    let v = self.leaves[i];
-                    if let Some(idx) = index_map.get(&i) {
-                        leaves[*idx] = v;
-                        None
-                    } else {
-                        Some(v)
-                    }
-                })
-                .collect();
-            nodes.push(missing);
-
-            next_indexes.push((index + n) >> 1);
+        for (&index, &i) in index_map.iter() {
+            leaves[i] = self.leaves[index];
         }
 
-        // add required internal nodes to the proof, skipping redundancies
-        for _ in 1..self.depth() {
-            let indexes = next_indexes.clone();
-            next_indexes.truncate(0);
-
-            let mut i = 0;
-            while i < indexes.len() {
-                let sibling_index = indexes[i] ^ 1;
-                if i + 1 < indexes.len() && indexes[i + 1] == sibling_index {
-                    i += 1;
-                } else {
-                    nodes[i].push(self.nodes[sibling_index]);
-                }
-
-                // add parent index to the set of next indexes
-                next_indexes.push(sibling_index >> 1);
-
-                i += 1;
-            }
-        }
+        // add the siblings of the leaves and of their ancestors to the proof, skipping redundancies
+        let positions = index_map.keys().map(|index| index + self.nodes.len());
+        let nodes = batch_nodes(
+            positions.collect(),
+            H::MERKLE_ARITY,
+            self.depth(),
+            |position| self.node(position),
+        );
 
         Ok(BatchMerkleProof {
             leaves,
@@ -262,17 +250,24 @@ impl<H: Hasher> MerkleTree<H> {
         index: usize,
         proof: &[H::Digest],
     ) -> Result<(), MerkleTreeError> {
-        let r = index & 1;
-        let mut v = H::merge(&[proof[r], proof[1 - r]]);
+        let arity = H::MERKLE_ARITY;
+        if proof.is_empty() || (proof.len() - 1) % (arity - 1) != 0 {
+            return Err(MerkleTreeError::InvalidProof);
+        }
 
-        let mut index = (index + 2usize.pow((proof.len() - 1) as u32)) >> 1;
-        for &p in proof.iter().skip(2) {
-            v = if index & 1 == 0 {
-                H::merge(&[v, p])
-            } else {
-                H::merge(&[p, v])
-            };
-            index >>= 1;
+        let mut v = proof[0];
+        let mut index = index;
+        let mut children = Vec::with_capacity(arity);
+        for siblings in proof[1..].chunks(arity - 1) {
+            // insert the node among its siblings
+            let i = index % arity;
+            children.clear();
+            children.extend_from_slice(&siblings[..i]);
+            children.push(v);
+            children.extend_from_slice(&siblings[i..]);
+
+            v = H::merge_many(&children);
+            index /= arity;
         }
 
         if v != root {
@@ -301,6 +296,18 @@ impl<H: Hasher> MerkleTree<H> {
         }
         Ok(())
     }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the node at the specified position, the leaves following the internal nodes.
+    fn node(&self, position: usize) -> H::Digest {
+        if position < self.nodes.len() {
+            self.nodes[position]
+        } else {
+            self.leaves[position - self.nodes.len()]
+        }
+    }
 }
 
 // HELPER FUNCTIONS
@@ -309,41 +316,91 @@ impl<H: Hasher> MerkleTree<H> {
 /// Returns the internal nodes of a Merkle tree defined by the specified leaves.
 ///
 /// The internal nodes are turned as a vector where the root is stored at position 1, its children
-/// are stored at positions 2, 3, their children are stored at positions 4, 5, 6, 7 etc.
+/// are stored at positions 2, 3, their children are stored at positions 4, 5, 6, 7 etc. For
+/// arities other than two, the children of the node at position `i` are stored at positions
+/// `arity * (i - 1) + 2` to `arity * (i - 1) + arity + 1`.
 ///
 /// This function is exposed primarily for benchmarking purposes. It is not intended to be used
 /// directly by the end users of the crate.
 pub fn build_merkle_nodes<H: Hasher>(leaves: &[H::Digest]) -> Vec<H::Digest> {
-    let n = leaves.len() / 2;
+    let arity = H::MERKLE_ARITY;
+    let n = (leaves.len() - 1) / (arity - 1);
 
     // create un-initialized array to hold all intermediate nodes
-    let mut nodes = unsafe { utils::uninit_vector::<H::Digest>(2 * n) };
+    let mut nodes = unsafe { utils::uninit_vector::<H::Digest>(n + 1) };
     nodes[0] = H::Digest::default();
 
-    // re-interpret leaves as an array of two leaves fused together
-    let two_leaves = unsafe { slice::from_raw_parts(leaves.as_ptr() as *const [H::Digest; 2], n) };
-
     // build first row of internal nodes (parents of leaves)
-    for (i, j) in (0..n).zip(n..nodes.len()) {
-        nodes[j] = H::merge(&two_leaves[i]);
+    let first_parent = n + 1 - leaves.len() / arity;
+    for (i, children) in (first_parent..=n).zip(leaves.chunks(arity)) {
+        nodes[i] = H::merge_many(children);
     }
 
-    // re-interpret nodes as an array of two nodes fused together
-    let two_nodes = unsafe { slice::from_raw_parts(nodes.as_ptr() as *const [H::Digest; 2], n) };
-
     // calculate all other tree nodes
-    for i in (1..n).rev() {
-        nodes[i] = H::merge(&two_nodes[i]);
+    for i in (1..first_parent).rev() {
+        let first_child = arity * (i - 1) + 2;
+        nodes[i] = H::merge_many(&nodes[first_child..first_child + arity]);
     }
 
     nodes
 }
 
+/// Returns the position of the parent of the node at the specified position.
+fn parent_position(position: usize, arity: usize) -> usize {
+    (position - 2) / arity + 1
+}
+
+/// Returns the positions of the children of the node at the specified position.
+fn child_positions(position: usize, arity: usize) -> core::ops::Range<usize> {
+    let first_child = arity * (position - 1) + 2;
+    first_child..first_child + arity
+}
+
+/// Returns the nodes of a batch proof for the nodes at the specified positions, which must be
+/// sorted in ascending order.
+///
+/// At the first level, the siblings of every group of leaves having the same parent are added to
+/// a new node vector. At the following levels, the siblings of every group of nodes are appended
+/// to the node vector of the index of the first node of the group among the nodes of this level.
+fn batch_nodes<D: Copy>(
+    mut positions: Vec<usize>,
+    arity: usize,
+    depth: usize,
+    get_node: impl Fn(usize) -> D,
+) -> Vec<Vec<D>> {
+    let mut nodes: Vec<Vec<D>> = Vec::new();
+    for level in 0..depth {
+        let mut parents = Vec::new();
+        let mut i = 0;
+        while i < positions.len() {
+            let parent = parent_position(positions[i], arity);
+            let mut missing = Vec::new();
+            let mut j = i;
+            for child in child_positions(parent, arity) {
+                if j < positions.len() && positions[j] == child {
+                    j += 1;
+                } else {
+                    missing.push(get_node(child));
+                }
+            }
+
+            if level == 0 {
+                nodes.push(missing);
+            } else {
+                nodes[i].append(&mut missing);
+            }
+            parents.push(parent);
+            i = j;
+        }
+        positions = parents;
+    }
+    nodes
+}
+
 fn map_indexes(
     indexes: &[usize],
-    tree_depth: usize,
+    num_leaves: usize,
 ) -> Result<BTreeMap<usize, usize>, MerkleTreeError> {
-    let num_leaves = 2usize.pow(tree_depth as u32);
     let mut map = BTreeMap::new();
     for (i, index) in indexes.iter().cloned().enumerate() {
         map.insert(index, i);
@@ -358,11 +415,3 @@ fn map_indexes(
 
     Ok(map)
 }
-
-fn normalize_indexes(indexes: &[usize]) -> Vec<usize> {
-    let mut set = BTreeSet::new();
-    for &index in indexes {
-        set.insert(index - (index & 1));
-    }
-    set.into_iter().collect()
-}
@@ -5,7 +5,7 @@
 
 use crate::{errors::MerkleTreeError, Hasher};
 use utils::{
-    collections::{BTreeMap, BTreeSet, Vec},
+    collections::{BTreeMap, Vec},
     string::ToString,
     ByteReader, Deserializable, DeserializationError, Serializable,
 };
@@ -37,14 +37,17 @@ pub struct BatchMerkleProof<H: Hasher> {
 impl<H: Hasher> BatchMerkleProof<H> {
     /// Constructs a batch Merkle proof from individual Merkle authentication paths.
     ///
+    /// The paths are those of [MerkleTree::prove()](super::MerkleTree::prove), i.e. they start
+    /// with the leaf.
+    ///
     /// # Panics
     /// Panics if:
     /// * No paths have been provided (i.e., `paths` is an empty slice).
     /// * More than 255 paths have been provided.
     /// * Number of paths is not equal to the number of indexes.
     /// * Not all paths have the same length.
+    /// * The length of the paths is not that of a tree of [MERKLE_ARITY](Hasher::MERKLE_ARITY).
     pub fn from_paths(paths: &[Vec<H::Digest>], indexes: &[usize]) -> BatchMerkleProof<H> {
-        // TODO: optimize this to reduce amount of vector cloning.
         assert!(!paths.is_empty(), "at least one path must be provided");
         assert!(
             paths.len() <= MAX_PATHS,
@@ -57,61 +60,37 @@ impl<H: Hasher> BatchMerkleProof<H> {
             "number of paths must equal number of indexes"
         );
 
-        let depth = paths[0].len();
+        let arity = H::MERKLE_ARITY;
+        let path_length = paths[0].len();
+        assert!(
+            path_length > 1 && (path_length - 1) % (arity - 1) == 0,
+            "paths must contain a leaf and {} siblings per level",
+            arity - 1
+        );
+        let depth = (path_length - 1) / (arity - 1);
+        let offset = leaf_offset(depth, arity);
 
-        // sort indexes in ascending order, and also re-arrange paths accordingly
+        // sort indexes in ascending order, and collect the nodes of the paths by position
         let mut path_map = BTreeMap::new();
-        for (&index, path) in indexes.iter().zip(paths.iter().cloned()) {
-            assert_eq!(depth, path.len(), "not all paths have the same length");
-            path_map.insert(index, path);
+        let mut node_map = BTreeMap::new();
+        for (&index, path) in indexes.iter().zip(paths.iter()) {
+            assert_eq!(
+                path_length,
+                path.len(),
+                "not all paths have the same length"
+            );
+            path_map.insert(index, path[0]);
+            insert_path_nodes(&mut node_map, index + offset, path, arity);
         }
-        let indexes = path_map.keys().cloned().collect::<Vec<_>>();
-        let paths = path_map.values().cloned().collect::<Vec<_>>();
-        path_map.clear();
-
-        let mut leaves = vec![H::Digest::default(); indexes.len()];
-        let mut nodes: Vec<Vec<H::Digest>> = Vec::with_capacity(indexes.len());
-
-        // populate values and the first layer of proof nodes
-        let mut i = 0;
-        while i < indexes.len() {
-            leaves[i] = paths[i][0];
-            if indexes.len() > i + 1 && are_siblings(indexes[i], indexes[i + 1]) {
-                leaves[i + 1] = paths[i][1];
-                nodes.push(vec![]);
-                i += 1;
-            } else {
-                nodes.push(vec![paths[i][1]]);
-            }
-            path_map.insert(indexes[i] >> 1, paths[i].clone());
-            i += 1;
-        }
-
-        // populate all remaining layers of proof nodes
-        for d in 2..depth {
-            let indexes = path_map.keys().cloned().collect::<Vec<_>>();
-            let mut next_path_map = BTreeMap::new();
 
-            let mut i = 0;
-            while i < indexes.len() {
-                let index = indexes[i];
-                let path = path_map.get(&index).unwrap();
-                if indexes.len() > i + 1 && are_siblings(index, indexes[i + 1]) {
-                    i += 1;
-                } else {
-                    nodes[i].push(path[d]);
-                }
-                next_path_map.insert(index >> 1, path.clone());
-                i += 1;
-            }
-
-            core::mem::swap(&mut path_map, &mut next_path_map);
-        }
+        let leaves = path_map.values().cloned().collect();
+        let positions = path_map.keys().map(|index| index + offset).collect();
+        let nodes = super::batch_nodes(positions, arity, depth, |position| node_map[&position]);
 
         BatchMerkleProof {
             leaves,
             nodes,
-            depth: (depth - 1) as u8,
+            depth: depth as u8,
         }
     }
 
@@ -126,136 +105,74 @@ impl<H: Hasher> BatchMerkleProof<H> {
     /// * List of indexes contains duplicates.
     /// * The proof does not resolve to a single root.
     pub fn get_root(&self, indexes: &[usize]) -> Result<H::Digest, MerkleTreeError> {
-        if indexes.is_empty() {
-            return Err(MerkleTreeError::TooFewLeafIndexes);
-        }
-        if indexes.len() > MAX_PATHS {
-            return Err(MerkleTreeError::TooManyLeafIndexes(
-                MAX_PATHS,
-                indexes.len(),
-            ));
-        }
-
-        let mut buf = [H::Digest::default(); 2];
-        let mut v = BTreeMap::new();
+        self.get_nodes(indexes)?
+            .remove(&1)
+            .ok_or(MerkleTreeError::InvalidProof)
+    }
 
-        // replace odd indexes, offset, and sort in ascending order
-        let index_map = super::map_indexes(indexes, self.depth as usize)?;
-        let indexes = super::normalize_indexes(indexes);
-        if indexes.len() != self.nodes.len() {
-            return Err(MerkleTreeError::InvalidProof);
-        }
+    /// Computes the Merkle paths aggregated in this proof, for the specified `indexes`.
+    ///
+    /// Contrary to the paths of [MerkleTree::prove()](super::MerkleTree::prove), the paths do not
+    /// contain the leaves, and are made of the `arity - 1` siblings of every level only.
+    ///
+    /// # Errors
+    /// Returns an error for the same reasons as [get_root()](BatchMerkleProof::get_root).
+    pub fn to_paths(&self, indexes: &[usize]) -> Result<Vec<Vec<H::Digest>>, MerkleTreeError> {
+        let arity = H::MERKLE_ARITY;
+        let offset = leaf_offset(self.depth as usize, arity);
+        let nodes = self.get_nodes(indexes)?;
 
-        // for each index use values to compute parent nodes
-        let offset = 2usize.pow(self.depth as u32);
-        let mut next_indexes: Vec<usize> = Vec::new();
-        let mut proof_pointers: Vec<usize> = Vec::with_capacity(indexes.len());
-        for (i, index) in indexes.into_iter().enumerate() {
-            // copy values of leaf sibling leaf nodes into the buffer
-            match index_map.get(&index) {
-                Some(&index1) => {
-                    if self.leaves.len() <= index1 {
-                        return Err(MerkleTreeError::InvalidProof);
-                    }
-                    buf[0] = self.leaves[index1];
-                    match index_map.get(&(index + 1)) {
-                        Some(&index2) => {
-                            if self.leaves.len() <= index2 {
-                                return Err(MerkleTreeError::InvalidProof);
-                            }
-                            buf[1] = self.leaves[index2];
-                            proof_pointers.push(0);
-                        }
-                        None => {
-                            if self.nodes[i].is_empty() {
-                                return Err(MerkleTreeError::InvalidProof);
-                            }
-                            buf[1] = self.nodes[i][0];
-                            proof_pointers.push(1);
-                        }
-                    }
-                }
-                None => {
-                    if self.nodes[i].is_empty() {
-                        return Err(MerkleTreeError::InvalidProof);
-                    }
-                    buf[0] = self.nodes[i][0];
-                    match index_map.get(&(index + 1)) {
-                        Some(&index2) => {
-                            if self.leaves.len() <= index2 {
-                                return Err(MerkleTreeError::InvalidProof);
-                            }
-                            buf[1] = self.leaves[index2];
+        // build and return authentication paths
+        indexes
+            .iter()
+            .map(|&index| {
+                let mut path = Vec::with_capacity(self.depth as usize * (arity - 1));
+                let mut position = index + offset;
+                while position > 1 {
+                    let parent = super::parent_position(position, arity);
+                    for sibling in super::child_positions(parent, arity) {
+                        if sibling != position {
+                            path.push(*nodes.get(&sibling).ok_or(MerkleTreeError::InvalidProof)?);
                         }
-                        None => return Err(MerkleTreeError::InvalidProof),
-                    }
-                    proof_pointers.push(1);
-                }
-            }
-
-            // hash sibling nodes into their parent
-            let parent = H::merge(&buf);
-
-            let parent_index = (offset + index) >> 1;
-            v.insert(parent_index, parent);
-            next_indexes.push(parent_index);
-        }
-
-        // iteratively move up, until we get to the root
-        for _ in 1..self.depth {
-            let indexes = next_indexes.clone();
-            next_indexes.truncate(0);
-
-            let mut i = 0;
-            while i < indexes.len() {
-                let node_index = indexes[i];
-                let sibling_index = node_index ^ 1;
-
-                // determine the sibling
-                let sibling: H::Digest;
-                if i + 1 < indexes.len() && indexes[i + 1] == sibling_index {
-                    sibling = match v.get(&sibling_index) {
-                        Some(sibling) => *sibling,
-                        None => return Err(MerkleTreeError::InvalidProof),
-                    };
-                    i += 1;
-                } else {
-                    let pointer = proof_pointers[i];
-                    if self.nodes[i].len() <= pointer {
-                        return Err(MerkleTreeError::InvalidProof);
                     }
-                    sibling = self.nodes[i][pointer];
-                    proof_pointers[i] += 1;
+                    position = parent;
                 }
+                Ok(path)
+            })
+            .collect()
+    }
 
-                // get the node from the map of hashed nodes
-                let node = match v.get(&node_index) {
-                    Some(node) => node,
-                    None => return Err(MerkleTreeError::InvalidProof),
-                };
-
-                // compute parent node from node and sibling
-                if node_index & 1 != 0 {
-                    buf[0] = sibling;
-                    buf[1] = *node;
-                } else {
-                    buf[0] = *node;
-                    buf[1] = sibling;
-                }
-                let parent = H::merge(&buf);
-
-                // add the parent node to the next set of nodes
-                let parent_index = node_index >> 1;
-                v.insert(parent_index, parent);
-                next_indexes.push(parent_index);
-
-                i += 1;
-            }
-        }
-        v.remove(&1).ok_or(MerkleTreeError::InvalidProof)
+    /// Computes the nodes of the `num_levels` levels below the root which are known from this
+    /// proof, for the specified `indexes`.
+    ///
+    /// The `l`-th level of the result holds the `arity^(l + 1)` nodes at depth `l + 1` of the
+    /// tree, in order, the nodes which are neither on the Merkle paths of the `indexes` nor
+    /// siblings of such nodes being `None`. At most `depth` levels are returned, the last one
+    /// holding the leaves.
+    ///
+    /// # Errors
+    /// Returns an error for the same reasons as [get_root()](BatchMerkleProof::get_root).
+    pub fn to_top_levels(
+        &self,
+        indexes: &[usize],
+        num_levels: usize,
+    ) -> Result<Vec<Vec<Option<H::Digest>>>, MerkleTreeError> {
+        let arity = H::MERKLE_ARITY;
+        let nodes = self.get_nodes(indexes)?;
+
+        Ok((1..=num_levels.min(self.depth as usize))
+            .map(|depth| {
+                let offset = leaf_offset(depth, arity);
+                (offset..offset + arity.pow(depth as u32))
+                    .map(|position| nodes.get(&position).copied())
+                    .collect()
+            })
+            .collect())
     }
 
-    pub fn to_paths(&self, indexes: &[usize]) -> Result<Vec<Vec<H::Digest>>, MerkleTreeError> {
+    /// Returns the nodes of the tree which can be computed from this proof, by position in the
+    /// tree, the leaves following the internal nodes.
+    fn get_nodes(&self, indexes: &[usize]) -> Result<BTreeMap<usize, H::Digest>, MerkleTreeError> {
         if indexes.is_empty() {
             return Err(MerkleTreeError::TooFewLeafIndexes);
         }
@@ -266,106 +183,63 @@ impl<H: Hasher> BatchMerkleProof<H> {
             ));
         }
 
-        // create un-initialized array to hold all intermediate nodes
-        let n = 2usize.pow(self.depth as u32);
-        let mut leaves = unsafe { utils::uninit_vector::<H::Digest>(n) };
-        let mut nodes = unsafe { utils::uninit_vector::<H::Digest>(n) };
-        nodes[0] = H::Digest::default();
-
-        // reinterpret leaves and nodes as an array of two leaves fused together
-        let two_leaves =
-            unsafe { std::slice::from_raw_parts(leaves.as_ptr() as *const [H::Digest; 2], n >> 1) };
-        let two_nodes =
-            unsafe { std::slice::from_raw_parts(nodes.as_ptr() as *const [H::Digest; 2], n >> 1) };
-
-        // sort indexes in ascending order
-        let original_indexes = indexes;
-        let index_map = super::map_indexes(indexes, self.depth as usize)?;
-        let indexes = index_map.keys().cloned().collect::<Vec<_>>();
-
-        // populate the leaves and the first layer of nodes
-        let mut i = 0;
-        let mut j = 0;
-        let mut nodes_indexes: Vec<usize> = Vec::with_capacity(indexes.len());
-        let mut next_index_map = BTreeSet::new();
-        while i < indexes.len() {
-            let index = indexes[i];
-            leaves[index] = if let Some(idx) = index_map.get(&index) {
-                self.leaves[*idx]
-            } else {
-                return Err(MerkleTreeError::InvalidProof);
-            };
-            if indexes.len() > i + 1 && are_siblings(index, indexes[i + 1]) {
-                leaves[indexes[i + 1]] = if let Some(idx) = index_map.get(&indexes[i + 1]) {
-                    self.leaves[*idx]
-                } else {
-                    return Err(MerkleTreeError::InvalidProof);
-                };
-                nodes_indexes.push(0);
-                i += 1;
-            } else {
-                leaves[index ^ 1] = self.nodes[j][0];
-                nodes_indexes.push(1);
-            }
-            nodes[(index + n) >> 1] = H::merge(&two_leaves[index >> 1]);
-            next_index_map.insert((index + n) >> 1);
-            i += 1;
-            j += 1;
-        }
+        let arity = H::MERKLE_ARITY;
+        let depth = self.depth as usize;
+        let offset = leaf_offset(depth, arity);
+        let index_map = super::map_indexes(indexes, arity.pow(depth as u32))?;
 
-        // populate the remaining layers of nodes. after this loop,
-        // the leaves and the nodes will contain all the required nodes
-        // to build the required authentication paths
-        for _ in 1..self.depth {
-            let indexes = next_index_map.iter().cloned().collect::<Vec<_>>();
-            next_index_map.clear();
+        // the leaves are ordered as the specified indexes
+        let mut v = BTreeMap::new();
+        for (&index, &i) in index_map.iter() {
+            let leaf = self.leaves.get(i).ok_or(MerkleTreeError::InvalidProof)?;
+            v.insert(index + offset, *leaf);
+        }
 
+        // iteratively move up, until we get to the root
+        let mut positions: Vec<usize> = index_map.keys().map(|index| index + offset).collect();
+        let mut proof_pointers: Vec<usize> = Vec::new();
+        let mut children = Vec::with_capacity(arity);
+        for level in 0..depth {
+            let mut parents = Vec::new();
             let mut i = 0;
-            while i < indexes.len() {
-                let index = indexes[i];
-                if indexes.len() > i + 1 && are_siblings(index, indexes[i + 1]) {
-                    i += 1;
-                } else {
-                    nodes[index ^ 1] = self.nodes[i][nodes_indexes[i]];
-                    nodes_indexes[i] += 1;
+            while i < positions.len() {
+                // the first level has a node vector per group of siblings, the following ones
+                // use the node vector of the index of the first node of the group
+                let pointer = if level == 0 { parents.len() } else { i };
+                if level == 0 {
+                    proof_pointers.push(0);
+                }
+                let nodes = self
+                    .nodes
+                    .get(pointer)
+                    .ok_or(MerkleTreeError::InvalidProof)?;
+
+                let parent = super::parent_position(positions[i], arity);
+                children.clear();
+                for child in super::child_positions(parent, arity) {
+                    if i < positions.len() && positions[i] == child {
+                        children.push(v[&child]);
+                        i += 1;
+                    } else {
+                        let node = nodes
+                            .get(proof_pointers[pointer])
+                            .ok_or(MerkleTreeError::InvalidProof)?;
+                        children.push(*node);
+                        v.insert(child, *node);
+                        proof_pointers[pointer] += 1;
+                    }
                 }
-                let index = index >> 1;
-                nodes[index] = H::merge(&&two_nodes[index]);
-                next_index_map.insert(index);
-                i += 1;
+
+                v.insert(parent, H::merge_many(&children));
+                parents.push(parent);
             }
+            positions = parents;
         }
 
-        // the only remaining node should be the root
-        if next_index_map.len() > 1 || !next_index_map.contains(&1) {
+        if proof_pointers.len() != self.nodes.len() {
             return Err(MerkleTreeError::InvalidProof);
         }
-
-        // build and return authentication paths
-        original_indexes
-            .iter()
-            .cloned()
-            .map(|mut index| {
-                let mut path: Vec<H::Digest> = Vec::with_capacity(self.depth as usize + 1);
-
-                // add sibling leaf
-                path.push(leaves[index ^ 1]);
-                index = (index + n) >> 1;
-
-                // add nodes
-                for _ in 1..self.depth {
-                    path.push(nodes[index ^ 1]);
-                    index >>= 1;
-                }
-
-                if index != 1 {
-                    // index must be root
-                    return Err(MerkleTreeError::InvalidProof);
-                }
-
-                Ok(path)
-            })
-            .collect::<Result<Vec<Vec<_>>, MerkleTreeError>>()
+        Ok(v)
     }
 
     // SERIALIZATION / DESERIALIZATION
@@ -451,8 +325,27 @@ impl<H: Hasher> BatchMerkleProof<H> {
 // HELPER FUNCTIONS
 // ================================================================================================
 
-/// Two nodes are siblings if index of the left node is even and right node
-/// immediately follows the left node.
-fn are_siblings(left: usize, right: usize) -> bool {
-    left & 1 == 0 && right - 1 == left
+/// Returns the position of the first leaf of a tree of the specified depth and arity.
+fn leaf_offset(depth: usize, arity: usize) -> usize {
+    (arity.pow(depth as u32) - 1) / (arity - 1) + 1
+}
+
+/// Inserts the nodes of the Merkle path of the leaf at the specified position into `nodes`.
+fn insert_path_nodes<D: Copy>(
+    nodes: &mut BTreeMap<usize, D>,
+    mut position: usize,
+    path: &[D],
+    arity: usize,
+) {
+    nodes.insert(position, path[0]);
+    for siblings in path[1..].chunks(arity - 1) {
+        let parent = super::parent_position(position, arity);
+        let mut siblings = siblings.iter();
+        for sibling in super::child_positions(parent, arity) {
+            if sibling != position {
+                nodes.insert(sibling, *siblings.next().unwrap());
+            }
+        }
+        position = parent;
+    }
 }
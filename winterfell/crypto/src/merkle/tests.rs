@@ -6,12 +6,15 @@
 use std::collections::HashSet;
 
 use super::*;
-use math::fields::f128::BaseElement;
+use math::fields::{f128::BaseElement, f256};
 use proptest::prelude::*;
 use rand_utils::rand_array;
 
 type Digest256 = crate::hash::ByteDigest<32>;
 type Blake3_256 = crate::hash::Blake3_256<BaseElement>;
+type PoseidonDigest = crate::hash::PoseidonDigest;
+type Poseidon4 = crate::hash::Poseidon<f256::BaseElement, 4>;
+type Poseidon8 = crate::hash::Poseidon<f256::BaseElement, 8>;
 
 static LEAVES4: [[u8; 32]; 4] = [
     [
@@ -274,6 +277,85 @@ fn to_paths() {
     }
 }
 
+#[test]
+fn new_tree_4ary() {
+    let leaves = poseidon_leaves(16);
+    let tree = MerkleTree::<Poseidon4>::new(leaves.clone()).unwrap();
+    assert_eq!(2, tree.depth());
+    let nodes: Vec<_> = leaves.chunks(4).map(Poseidon4::merge_many).collect();
+    assert_eq!(&Poseidon4::merge_many(&nodes), tree.root());
+
+    assert_eq!(
+        Err(MerkleTreeError::TooFewLeaves(4, 2)),
+        MerkleTree::<Poseidon4>::new(poseidon_leaves(2)).map(|_| ())
+    );
+    assert_eq!(
+        Err(MerkleTreeError::NumberOfLeavesNotPowerOfArity(4, 8)),
+        MerkleTree::<Poseidon4>::new(poseidon_leaves(8)).map(|_| ())
+    );
+}
+
+#[test]
+fn prove_n_verify_4ary() {
+    let leaves = poseidon_leaves(16);
+    let tree = MerkleTree::<Poseidon4>::new(leaves.clone()).unwrap();
+
+    let proof = tree.prove(6).unwrap();
+    let expected = vec![
+        leaves[6],
+        leaves[4],
+        leaves[5],
+        leaves[7],
+        Poseidon4::merge_many(&leaves[..4]),
+        Poseidon4::merge_many(&leaves[8..12]),
+        Poseidon4::merge_many(&leaves[12..]),
+    ];
+    assert_eq!(expected, proof);
+    assert!(MerkleTree::<Poseidon4>::verify(*tree.root(), 6, &proof).is_ok());
+    assert!(MerkleTree::<Poseidon4>::verify(*tree.root(), 5, &proof).is_err());
+    assert!(MerkleTree::<Poseidon4>::verify(*tree.root(), 6, &proof[..6]).is_err());
+
+    for index in 0..16 {
+        let proof = tree.prove(index).unwrap();
+        assert!(MerkleTree::<Poseidon4>::verify(*tree.root(), index, &proof).is_ok());
+    }
+}
+
+#[test]
+fn prove_batch_4ary() {
+    let tree = MerkleTree::<Poseidon4>::new(poseidon_leaves(64)).unwrap();
+    check_batch_proofs(
+        &tree,
+        &[&[5], &[5, 6], &[7, 1, 63, 40, 41, 6], &[32, 0, 17]],
+    );
+    let all_indexes: Vec<usize> = (0..64).rev().collect();
+    check_batch_proofs(&tree, &[&all_indexes]);
+
+    // the leaves of a group of siblings are followed by the siblings of their ancestors
+    let proof = tree.prove_batch(&[1, 2]).unwrap();
+    let leaves = tree.leaves();
+    assert_eq!(1, proof.nodes.len());
+    assert_eq!(&[leaves[0], leaves[3]], &proof.nodes[0][..2]);
+    assert_eq!(8, proof.nodes[0].len());
+    assert!(MerkleTree::verify_batch(tree.root(), &[1, 3], &proof).is_err());
+    assert!(MerkleTree::verify_batch(tree.root(), &[1], &proof).is_err());
+}
+
+#[test]
+fn prove_batch_8ary() {
+    let tree = MerkleTree::<Poseidon8>::new(poseidon_leaves(64)).unwrap();
+    assert_eq!(2, tree.depth());
+    for index in [0, 9, 63] {
+        let proof = tree.prove(index).unwrap();
+        assert_eq!(15, proof.len());
+        assert!(MerkleTree::<Poseidon8>::verify(*tree.root(), index, &proof).is_ok());
+    }
+    check_batch_proofs(
+        &tree,
+        &[&[5], &[9, 5, 6, 63], &[0, 8, 16, 24, 32, 40, 48, 56]],
+    );
+}
+
 proptest! {
     #[test]
     fn prove_n_verify(tree in random_blake3_merkle_tree(128),
@@ -315,6 +397,77 @@ proptest! {
 
 // HELPER FUNCTIONS
 // --------------------------------------------------------------------------------------------
+/// Checks the batch proofs of the tree for every set of indexes, built from the tree and from
+/// individual paths, as well as their conversion to paths and their serialization.
+fn check_batch_proofs<H: Hasher>(tree: &MerkleTree<H>, index_sets: &[&[usize]]) {
+    for &indexes in index_sets {
+        let proof = tree.prove_batch(indexes).unwrap();
+        assert_eq!(tree.depth(), proof.depth as usize);
+        assert_eq!(
+            Ok(()),
+            MerkleTree::verify_batch(tree.root(), indexes, &proof)
+        );
+
+        let paths: Vec<_> = indexes.iter().map(|&i| tree.prove(i).unwrap()).collect();
+        let expected: Vec<_> = paths.iter().map(|path| path[1..].to_vec()).collect();
+        assert_eq!(expected, proof.to_paths(indexes).unwrap());
+
+        // the known nodes of the top levels are those of the tree, and include the siblings of
+        // the nodes on the paths
+        let arity = H::MERKLE_ARITY;
+        let top_levels = proof.to_top_levels(indexes, tree.depth() + 1).unwrap();
+        assert_eq!(tree.depth(), top_levels.len());
+        for (level, nodes) in top_levels.iter().enumerate() {
+            let offset = (arity.pow(level as u32 + 1) - 1) / (arity - 1) + 1;
+            for (position, node) in (offset..).zip(nodes) {
+                let tree_node = if level + 1 == tree.depth() {
+                    tree.leaves[position - offset]
+                } else {
+                    tree.nodes[position]
+                };
+                assert!(node.map_or(true, |node| node == tree_node));
+            }
+            for path in expected.iter() {
+                let siblings = &path[(tree.depth() - level - 1) * (arity - 1)..][..arity - 1];
+                for sibling in siblings {
+                    assert!(nodes.contains(&Some(*sibling)));
+                }
+            }
+        }
+
+        let mut sorted_indexes = indexes.to_vec();
+        sorted_indexes.sort_unstable();
+        let sorted_paths: Vec<_> = sorted_indexes
+            .iter()
+            .map(|&i| tree.prove(i).unwrap())
+            .collect();
+        let sorted_proof = tree.prove_batch(&sorted_indexes).unwrap();
+        let proof_from_paths = BatchMerkleProof::<H>::from_paths(&sorted_paths, &sorted_indexes);
+        assert_eq!(sorted_proof.leaves, proof_from_paths.leaves);
+        assert_eq!(sorted_proof.nodes, proof_from_paths.nodes);
+        assert_eq!(sorted_proof.depth, proof_from_paths.depth);
+        assert_eq!(
+            *tree.root(),
+            BatchMerkleProof::<H>::from_paths(&paths, indexes)
+                .get_root(&sorted_indexes)
+                .unwrap()
+        );
+
+        let bytes = proof.serialize_nodes();
+        let mut reader = utils::SliceReader::new(&bytes);
+        let deserialized =
+            BatchMerkleProof::<H>::deserialize(&mut reader, proof.leaves.clone(), proof.depth)
+                .unwrap();
+        assert_eq!(proof.nodes, deserialized.nodes);
+    }
+}
+
+fn poseidon_leaves(num_leaves: usize) -> Vec<PoseidonDigest> {
+    (0..num_leaves)
+        .map(|i| Poseidon4::hash(&i.to_le_bytes()))
+        .collect()
+}
+
 fn hash_2x1(v1: Digest256, v2: Digest256) -> Digest256 {
     Blake3_256::merge(&[v1, v2])
 }
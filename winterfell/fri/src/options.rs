@@ -14,6 +14,8 @@ pub struct FriOptions {
     folding_factor: usize,
     max_remainder_size: usize,
     blowup_factor: usize,
+    remainder_coefficients: bool,
+    num_partitions: usize,
 }
 
 impl FriOptions {
@@ -46,9 +48,23 @@ impl FriOptions {
             folding_factor,
             max_remainder_size,
             blowup_factor,
+            remainder_coefficients: false,
+            num_partitions: 1,
         }
     }
 
+    /// Returns these options with the remainder sent as polynomial coefficients.
+    ///
+    /// By default, the remainder (the last FRI layer) is sent as evaluations over its domain,
+    /// committed to with a Merkle tree, and the verifier interpolates it to check its degree.
+    /// With this option, the prover sends the coefficients of the remainder polynomial instead,
+    /// committed to by hashing them together; the verifier then evaluates this polynomial at the
+    /// queried points, and the degree bound follows from the number of coefficients.
+    pub fn with_remainder_coefficients(mut self) -> Self {
+        self.remainder_coefficients = true;
+        self
+    }
+
     /// Returns the offset by which the evaluation domain is shifted.
     ///
     /// The domain is shifted by multiplying every element in the domain by this offset.
@@ -84,6 +100,38 @@ impl FriOptions {
         self.blowup_factor
     }
 
+    /// Returns these options with the FRI layers committed to in `num_partitions` partitions.
+    ///
+    /// The evaluations of each layer, except for the remainder, are split into `num_partitions`
+    /// partitions, such that the partition of a position in the folded domain is its remainder
+    /// modulo `num_partitions`. The leaves of the layer commitment tree are ordered by partition,
+    /// which allows the trees of the partitions to be built separately. The smallest partitioned
+    /// layer has as many rows as the remainder, so `num_partitions` must not exceed the
+    /// [remainder size](FriOptions::fri_remainder_size) of the domain.
+    ///
+    /// # Panics
+    /// Panics if `num_partitions` is zero or is not a power of two.
+    pub fn with_partitions(mut self, num_partitions: usize) -> Self {
+        assert!(
+            num_partitions.is_power_of_two(),
+            "number of partitions must be a power of two, but was {}",
+            num_partitions
+        );
+        self.num_partitions = num_partitions;
+        self
+    }
+
+    /// Returns true if the remainder (the last FRI layer) is sent as polynomial coefficients
+    /// rather than evaluations.
+    pub fn remainder_coefficients(&self) -> bool {
+        self.remainder_coefficients
+    }
+
+    /// Returns the number of partitions in which the FRI layers are committed to.
+    pub fn num_partitions(&self) -> usize {
+        self.num_partitions
+    }
+
     /// Computes and return the number of FRI layers required for a domain of the specified size.
     ///
     /// The remainder layer (the last FRI layer) is not included in the returned value.
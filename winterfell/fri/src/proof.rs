@@ -155,11 +155,12 @@ impl FriProof {
             layer_queries.push(qv);
         }
 
-        // make sure the remaining domain size matches remainder length
-        if domain_size != num_remainder_elements {
+        // make sure the remainder does not exceed the remaining domain size; the remainder is
+        // shorter than its domain when it is sent as polynomial coefficients
+        if num_remainder_elements > domain_size {
             return Err(DeserializationError::InvalidValue(format!(
-                "FRI remainder domain size must be {}, but was {}",
-                num_remainder_elements, domain_size,
+                "FRI remainder cannot have more than {} elements, but had {}",
+                domain_size, num_remainder_elements,
             )));
         }
 
@@ -333,7 +334,7 @@ impl FriProofLayer {
 
         // build batch Merkle proof
         let mut reader = SliceReader::new(&self.paths);
-        let tree_depth = log2(domain_size) as u8;
+        let tree_depth = (log2(domain_size) / log2(H::MERKLE_ARITY)) as u8;
         let merkle_proof = BatchMerkleProof::deserialize(&mut reader, hashed_queries, tree_depth)?;
         if reader.has_more_bytes() {
             return Err(DeserializationError::UnconsumedBytes);
@@ -6,12 +6,12 @@
 use crate::{
     folding::{apply_drp, fold_positions},
     proof::{FriProof, FriProofLayer},
-    utils::hash_values,
+    utils::{hash_values, map_positions_to_indexes, partition_leaves},
     FriOptions,
 };
 use core::marker::PhantomData;
 use crypto::{ElementHasher, Hasher, MerkleTree};
-use math::{FieldElement, StarkField};
+use math::{fft, FieldElement, StarkField};
 use utils::{collections::Vec, flatten_vector_elements, group_slice_elements, transpose_slice};
 
 mod channel;
@@ -66,6 +66,15 @@ mod tests;
 /// The prover keeps all FRI layers (consisting of evaluations and corresponding Merkle trees) in
 /// its internal state.
 ///
+/// If the options require the remainder to be sent as polynomial coefficients (see
+/// [FriOptions::with_remainder_coefficients()]), the last layer is not committed to with a Merkle
+/// tree. Instead, the prover interpolates the remaining evaluations into a polynomial, and commits
+/// to its coefficients by hashing them together.
+///
+/// If the options split the FRI layers into several partitions (see
+/// [FriOptions::with_partitions()]), the leaves of the commitment tree of each layer, except for
+/// the remainder layer, are ordered by partition.
+///
 /// # Query phase
 /// In the query phase, which is executed via [build_proof()](FriProver::build_proof()) function,
 /// the prover receives a set of positions in the domain *D* from the verifier. The prover then
@@ -94,6 +103,7 @@ where
 {
     options: FriOptions,
     layers: Vec<FriLayer<B, E, H>>,
+    remainder_coefficients: Vec<E>,
     _channel: PhantomData<C>,
 }
 
@@ -120,6 +130,7 @@ where
         FriProver {
             options,
             layers: Vec::new(),
+            remainder_coefficients: Vec::new(),
             _channel: PhantomData,
         }
     }
@@ -138,14 +149,19 @@ where
     }
 
     /// Returns number of FRI layers computed during the last execution of the
-    /// [build_layers()](FriProver::build_layers()) method.
+    /// [build_layers()](FriProver::build_layers()) method, including the remainder layer.
     pub fn num_layers(&self) -> usize {
-        self.layers.len()
+        if self.remainder_coefficients.is_empty() {
+            self.layers.len()
+        } else {
+            self.layers.len() + 1
+        }
     }
 
     /// Clears a vector of internally stored layers.
     pub fn reset(&mut self) {
         self.layers.clear();
+        self.remainder_coefficients.clear();
     }
 
     // COMMIT PHASE
@@ -162,7 +178,10 @@ where
     /// of the DRP.
     ///
     /// # Panics
-    /// Panics if the prover state is dirty (the vector of layers is not empty).
+    /// Panics if:
+    /// * The prover state is dirty (the vector of layers is not empty).
+    /// * The number of partitions is greater than the number of rows of a partitioned layer,
+    ///   i.e. than the size of the remainder.
     pub fn build_layers(&mut self, channel: &mut C, mut evaluations: Vec<E>) {
         assert!(
             self.layers.is_empty(),
@@ -170,16 +189,33 @@ where
         );
 
         // reduce the degree by folding_factor at each iteration until the remaining polynomial
-        // is small enough; + 1 is for the remainder
-        for _ in 0..self.options.num_fri_layers(evaluations.len()) + 1 {
+        // is small enough; + 1 is for the remainder, unless it is sent as coefficients
+        let num_fri_layers = self.options.num_fri_layers(evaluations.len());
+        let num_layers = if self.options.remainder_coefficients() {
+            num_fri_layers
+        } else {
+            num_fri_layers + 1
+        };
+        for depth in 0..num_layers {
+            // the remainder layer is never partitioned
+            let num_partitions = if depth < num_fri_layers {
+                self.options.num_partitions()
+            } else {
+                1
+            };
             match self.folding_factor() {
-                4 => self.build_layer::<4>(channel, &mut evaluations),
-                8 => self.build_layer::<8>(channel, &mut evaluations),
-                16 => self.build_layer::<16>(channel, &mut evaluations),
+                4 => self.build_layer::<4>(channel, &mut evaluations, num_partitions),
+                8 => self.build_layer::<8>(channel, &mut evaluations, num_partitions),
+                16 => self.build_layer::<16>(channel, &mut evaluations, num_partitions),
                 _ => unimplemented!("folding factor {} is not supported", self.folding_factor()),
             }
         }
 
+        if self.options.remainder_coefficients() {
+            self.build_remainder_coefficients(channel, evaluations);
+            return;
+        }
+
         // make sure remainder length does not exceed max allowed value
         let last_layer = &self.layers[self.layers.len() - 1];
         let remainder_size = last_layer.evaluations.len();
@@ -193,13 +229,30 @@ where
 
     /// Builds a single FRI layer by first committing to the `evaluations`, then drawing a random
     /// alpha from the channel and use it to perform degree-respecting projection.
-    fn build_layer<const N: usize>(&mut self, channel: &mut C, evaluations: &mut Vec<E>) {
+    ///
+    /// The leaves of the commitment tree are ordered by partition when `num_partitions` is
+    /// greater than one.
+    fn build_layer<const N: usize>(
+        &mut self,
+        channel: &mut C,
+        evaluations: &mut Vec<E>,
+        num_partitions: usize,
+    ) {
         // commit to the evaluations at the current layer; we do this by first transposing the
         // evaluations into a matrix of N columns, and then building a Merkle tree from the
         // rows of this matrix; we do this so that we could de-commit to N values with a single
         // Merkle authentication path.
         let transposed_evaluations = transpose_slice(evaluations);
-        let hashed_evaluations = hash_values::<H, E, N>(&transposed_evaluations);
+        assert!(
+            transposed_evaluations.len() >= num_partitions,
+            "FRI layer of {} rows cannot be split into {} partitions",
+            transposed_evaluations.len(),
+            num_partitions
+        );
+        let hashed_evaluations = partition_leaves(
+            hash_values::<H, E, N>(&transposed_evaluations),
+            num_partitions,
+        );
         let evaluation_tree =
             MerkleTree::<H>::new(hashed_evaluations).expect("failed to construct FRI layer tree");
         channel.commit_fri_layer(*evaluation_tree.root());
@@ -216,6 +269,29 @@ where
         });
     }
 
+    /// Interpolates the remainder `evaluations` into a polynomial, commits to its coefficients by
+    /// hashing them together, and draws a random alpha from the channel so that the transcript has
+    /// the same shape as when the remainder is sent as evaluations.
+    fn build_remainder_coefficients(&mut self, channel: &mut C, mut evaluations: Vec<E>) {
+        debug_assert!(
+            evaluations.len() <= self.options.max_remainder_size(),
+            "last FRI layer cannot exceed {} elements, but was {} elements",
+            self.options.max_remainder_size(),
+            evaluations.len()
+        );
+
+        // the remainder polynomial has degree smaller than its domain size divided by the
+        // blowup factor; the higher coefficients are all zeros and are not sent
+        let inv_twiddles = fft::get_inv_twiddles::<B>(evaluations.len());
+        fft::interpolate_poly_with_offset(&mut evaluations, &inv_twiddles, self.domain_offset());
+        evaluations.truncate(evaluations.len() / self.options.blowup_factor());
+
+        channel.commit_fri_layer(H::hash_elements(&evaluations));
+        channel.draw_fri_alpha();
+
+        self.remainder_coefficients = evaluations;
+    }
+
     // QUERY PHASE
     // --------------------------------------------------------------------------------------------
     /// Executes query phase of FRI protocol.
@@ -223,30 +299,46 @@ where
     /// For each of the provided `positions`, corresponding evaluations from each of the layers
     /// (excluding the remainder layer) are recorded into the proof together with Merkle
     /// authentication paths from the root of layer commitment trees. For the remainder, we include
-    /// the whole set of evaluations into the proof.
+    /// the whole set of evaluations into the proof, or the coefficients of the remainder
+    /// polynomial if the options require them.
     ///
     /// # Panics
     /// Panics is the prover state is clean (no FRI layers have been build yet).
     pub fn build_proof(&mut self, positions: &[usize]) -> FriProof {
-        assert!(
-            !self.layers.is_empty(),
-            "FRI layers have not been built yet"
-        );
+        assert!(self.num_layers() > 0, "FRI layers have not been built yet");
+        let remainder_coefficients = self.options.remainder_coefficients();
         let mut positions = positions.to_vec();
-        let mut domain_size = self.layers[0].evaluations.len();
+        // when the domain is not larger than the maximum remainder size, its evaluations are
+        // all in the remainder, which is then the only layer
+        let mut domain_size = match self.layers.first() {
+            Some(layer) => layer.evaluations.len(),
+            None => self.remainder_coefficients.len() * self.options.blowup_factor(),
+        };
         let folding_factor = self.options.folding_factor();
 
-        // for all FRI layers, except the last one, record tree root, determine a set of query
-        // positions, and query the layer at these positions.
-        let mut layers = Vec::with_capacity(self.layers.len());
-        for i in 0..self.layers.len() - 1 {
+        // for all FRI layers, except the remainder layer, record tree root, determine a set of
+        // query positions, and query the layer at these positions.
+        let num_layers = if remainder_coefficients {
+            self.layers.len()
+        } else {
+            self.layers.len() - 1
+        };
+        let mut layers = Vec::with_capacity(num_layers);
+        for i in 0..num_layers {
             positions = fold_positions(&positions, domain_size, folding_factor);
+            // determine where the evaluations at these positions are in the commitment tree
+            let indexes = map_positions_to_indexes(
+                &positions,
+                domain_size,
+                folding_factor,
+                self.options.num_partitions(),
+            );
 
             // sort of a static dispatch for folding_factor parameter
             let proof_layer = match folding_factor {
-                4 => query_layer::<B, E, H, 4>(&self.layers[i], &positions),
-                8 => query_layer::<B, E, H, 8>(&self.layers[i], &positions),
-                16 => query_layer::<B, E, H, 16>(&self.layers[i], &positions),
+                4 => query_layer::<B, E, H, 4>(&self.layers[i], &positions, &indexes),
+                8 => query_layer::<B, E, H, 8>(&self.layers[i], &positions, &indexes),
+                16 => query_layer::<B, E, H, 16>(&self.layers[i], &positions, &indexes),
                 _ => unimplemented!("folding factor {} is not supported", folding_factor),
             };
 
@@ -254,21 +346,27 @@ where
             domain_size /= folding_factor;
         }
 
-        // use the remaining polynomial values directly as proof; last layer values contain
-        // remainder in transposed form - so, we un-transpose it first
-        let last_values = &self.layers[self.layers.len() - 1].evaluations;
-        let mut remainder = E::zeroed_vector(last_values.len());
-        let n = last_values.len() / folding_factor;
-        for i in 0..n {
-            for j in 0..folding_factor {
-                remainder[i + n * j] = last_values[i * folding_factor + j];
+        let remainder = if remainder_coefficients {
+            // use the coefficients of the remaining polynomial directly as proof
+            self.remainder_coefficients.clone()
+        } else {
+            // use the remaining polynomial values directly as proof; last layer values contain
+            // remainder in transposed form - so, we un-transpose it first
+            let last_values = &self.layers[self.layers.len() - 1].evaluations;
+            let mut remainder = E::zeroed_vector(last_values.len());
+            let n = last_values.len() / folding_factor;
+            for i in 0..n {
+                for j in 0..folding_factor {
+                    remainder[i + n * j] = last_values[i * folding_factor + j];
+                }
             }
-        }
+            remainder
+        };
 
         // clear layers so that another proof can be generated
         self.reset();
 
-        FriProof::new(layers, remainder, 1)
+        FriProof::new(layers, remainder, self.options.num_partitions())
     }
 }
 
@@ -276,15 +374,16 @@ where
 // ================================================================================================
 
 /// Builds a single proof layer by querying the evaluations of the passed in FRI layer at the
-/// specified positions.
+/// specified positions, whose leaves are at the specified `indexes` of the commitment tree.
 fn query_layer<B: StarkField, E: FieldElement<BaseField = B>, H: Hasher, const N: usize>(
     layer: &FriLayer<B, E, H>,
     positions: &[usize],
+    indexes: &[usize],
 ) -> FriProofLayer {
     // build Merkle authentication paths for all query positions
     let proof = layer
         .tree
-        .prove_batch(positions)
+        .prove_batch(indexes)
         .expect("failed to generate a Merkle proof for FRI layer queries");
 
     // build a list of polynomial evaluations at each position; since evaluations in FRI layers
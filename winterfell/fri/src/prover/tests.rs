@@ -59,6 +59,154 @@ fn fri_prove_verify() {
     assert!(result.is_err());
 }
 
+#[test]
+fn fri_prove_verify_remainder_coefficients() {
+    let trace_length = 4096;
+    let lde_blowup = 8;
+
+    let options = FriOptions::new(lde_blowup, 4, 256).with_remainder_coefficients();
+    let mut channel = build_prover_channel(trace_length, &options);
+    let evaluations = build_evaluations(trace_length, lde_blowup);
+
+    // instantiate the prover and generate the proof
+    let mut prover = FriProver::new(options.clone());
+    prover.build_layers(&mut channel, evaluations.clone());
+    let positions = channel.draw_query_positions();
+    let proof = prover.build_proof(&positions);
+
+    // the remainder only contains the coefficients of a polynomial of the expected degree
+    let remainder_size = options.fri_remainder_size(trace_length * lde_blowup);
+    assert_eq!(
+        remainder_size / lde_blowup,
+        proof.num_remainder_elements::<BaseElement>()
+    );
+
+    // make sure the proof can be verified
+    let commitments = channel.layer_commitments().to_vec();
+    let max_degree = trace_length - 1;
+    let result = verify_proof(
+        proof.clone(),
+        commitments.clone(),
+        &evaluations,
+        max_degree,
+        trace_length * lde_blowup,
+        &positions,
+        &options,
+    );
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+
+    // make sure proof fails for invalid degree
+    let result = verify_proof(
+        proof.clone(),
+        commitments.clone(),
+        &evaluations,
+        max_degree - 256,
+        trace_length * lde_blowup,
+        &positions,
+        &options,
+    );
+    assert!(result.is_err());
+
+    // make sure proof fails when the remainder is sent as evaluations
+    let result = verify_proof(
+        proof,
+        commitments,
+        &evaluations,
+        max_degree,
+        trace_length * lde_blowup,
+        &positions,
+        &FriOptions::new(lde_blowup, 4, 256),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn fri_prove_verify_remainder_coefficients_without_layers() {
+    let trace_length = 16;
+    let lde_blowup = 4;
+
+    // the domain of 64 elements is not folded, the remainder is the only layer
+    let options = FriOptions::new(lde_blowup, 4, 64).with_remainder_coefficients();
+    assert_eq!(0, options.num_fri_layers(trace_length * lde_blowup));
+    let mut channel = build_prover_channel(trace_length, &options);
+    let evaluations = build_evaluations(trace_length, lde_blowup);
+
+    // instantiate the prover and generate the proof
+    let mut prover = FriProver::new(options.clone());
+    prover.build_layers(&mut channel, evaluations.clone());
+    let positions = channel.draw_query_positions();
+    let proof = prover.build_proof(&positions);
+    assert_eq!(
+        trace_length,
+        proof.num_remainder_elements::<BaseElement>()
+    );
+
+    // make sure the proof can be verified
+    let commitments = channel.layer_commitments().to_vec();
+    let max_degree = trace_length - 1;
+    let result = verify_proof(
+        proof.clone(),
+        commitments.clone(),
+        &evaluations,
+        max_degree,
+        trace_length * lde_blowup,
+        &positions,
+        &options,
+    );
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+
+    // make sure proof fails for invalid degree
+    let result = verify_proof(
+        proof,
+        commitments,
+        &evaluations,
+        max_degree - 1,
+        trace_length * lde_blowup,
+        &positions,
+        &options,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn fri_prove_verify_partitions() {
+    let trace_length = 4096;
+    let lde_blowup = 8;
+
+    for options in [
+        FriOptions::new(lde_blowup, 4, 256).with_partitions(4),
+        FriOptions::new(lde_blowup, 4, 256)
+            .with_partitions(8)
+            .with_remainder_coefficients(),
+        // the smallest partitioned layer has as many rows as the remainder of 128 elements
+        FriOptions::new(lde_blowup, 4, 256).with_partitions(128),
+    ] {
+        let mut channel = build_prover_channel(trace_length, &options);
+        let evaluations = build_evaluations(trace_length, lde_blowup);
+
+        // instantiate the prover and generate the proof
+        let mut prover = FriProver::new(options.clone());
+        prover.build_layers(&mut channel, evaluations.clone());
+        let positions = channel.draw_query_positions();
+        let proof = prover.build_proof(&positions);
+        assert_eq!(options.num_partitions(), proof.num_partitions());
+
+        // make sure the proof can be verified
+        let commitments = channel.layer_commitments().to_vec();
+        let max_degree = trace_length - 1;
+        let result = verify_proof(
+            proof,
+            commitments,
+            &evaluations,
+            max_degree,
+            trace_length * lde_blowup,
+            &positions,
+            &options,
+        );
+        assert!(result.is_ok(), "{:}", result.err().unwrap());
+    }
+}
+
 // TEST UTILS
 // ================================================================================================
 
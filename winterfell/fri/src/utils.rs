@@ -37,6 +37,24 @@ pub fn map_positions_to_indexes(
     result
 }
 
+/// Reorders the leaves of a layer commitment tree by partition, so that the leaf of each position
+/// in the folded domain is at the index given by [map_positions_to_indexes()].
+pub fn partition_leaves<T: Copy>(leaves: Vec<T>, num_partitions: usize) -> Vec<T> {
+    if num_partitions == 1 {
+        return leaves;
+    }
+
+    let partition_size = leaves.len() / num_partitions;
+    let mut result = Vec::with_capacity(leaves.len());
+    for partition_idx in 0..num_partitions {
+        for local_idx in 0..partition_size {
+            result.push(leaves[local_idx * num_partitions + partition_idx]);
+        }
+    }
+
+    result
+}
+
 /// Hashes each of the arrays in the provided slice and returns a vector of resulting hashes.
 pub fn hash_values<H, E, const N: usize>(values: &[[E; N]]) -> Vec<H::Digest>
 where
@@ -119,6 +119,27 @@ pub trait VerifierChannel<E: FieldElement> {
 
         Ok(remainder)
     }
+
+    /// Returns FRI remainder polynomial coefficients read from this channel.
+    ///
+    /// This also checks whether the coefficients are valid against the provided commitment,
+    /// which is the hash of all the coefficients.
+    ///
+    /// # Errors
+    /// Returns an error if the hash of the coefficients read from the channel does not match the
+    /// specified `commitment`.
+    fn read_remainder_coefficients(
+        &mut self,
+        commitment: &<<Self as VerifierChannel<E>>::Hasher as Hasher>::Digest,
+    ) -> Result<Vec<E>, VerifierError> {
+        let remainder = self.take_fri_remainder();
+
+        if *commitment != Self::Hasher::hash_elements(&remainder) {
+            return Err(VerifierError::RemainderCommitmentMismatch);
+        }
+
+        Ok(remainder)
+    }
 }
 
 // DEFAULT VERIFIER CHANNEL IMPLEMENTATION
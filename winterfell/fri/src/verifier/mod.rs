@@ -304,10 +304,24 @@ where
 
         // 2 ----- verify the remainder of the FRI proof ----------------------------------------------
 
+        let remainder_commitment = self.layer_commitments.last().unwrap();
+        if self.options.remainder_coefficients() {
+            return verify_remainder_coefficients(
+                channel.read_remainder_coefficients(remainder_commitment)?,
+                &positions,
+                &evaluations,
+                domain_generator,
+                self.options.domain_offset(),
+                max_degree_plus_1 - 1,
+            );
+        }
+
         // read the remainder from the channel and make sure it matches with the columns
         // of the previous layer
-        let remainder_commitment = self.layer_commitments.last().unwrap();
         let remainder = channel.read_remainder::<N>(remainder_commitment)?;
+        if remainder.len() != domain_size {
+            return Err(VerifierError::InvalidRemainderFolding);
+        }
         for (&position, evaluation) in positions.iter().zip(evaluations) {
             if remainder[position] != evaluation {
                 return Err(VerifierError::InvalidRemainderFolding);
@@ -346,6 +360,32 @@ fn verify_remainder<B: StarkField, E: FieldElement<BaseField = B>>(
     }
 }
 
+/// Returns Ok(()) if the polynomial with coefficients `remainder` has degree <= `max_degree`, and
+/// if its evaluations at the x coordinates specified by `positions` in the remainder domain are
+/// equal to `evaluations`.
+fn verify_remainder_coefficients<B: StarkField, E: FieldElement<BaseField = B>>(
+    remainder: Vec<E>,
+    positions: &[usize],
+    evaluations: &[E],
+    domain_generator: B,
+    domain_offset: B,
+    max_degree: usize,
+) -> Result<(), VerifierError> {
+    // the degree is bounded by the number of coefficients
+    if remainder.len() > max_degree + 1 {
+        return Err(VerifierError::RemainderDegreeMismatch(max_degree));
+    }
+
+    for (&position, &evaluation) in positions.iter().zip(evaluations) {
+        let x = domain_generator.exp((position as u64).into()) * domain_offset;
+        if polynom::eval(&remainder, E::from(x)) != evaluation {
+            return Err(VerifierError::InvalidRemainderFolding);
+        }
+    }
+
+    Ok(())
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 fn get_query_values<E: FieldElement, const N: usize>(
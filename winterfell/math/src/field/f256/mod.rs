@@ -318,6 +318,28 @@ impl From<u8> for BaseElement {
     }
 }
 
+impl From<super::f128::BaseElement> for BaseElement {
+    /// Converts an element of the 128-bit field into a field element with the same value, which
+    /// is smaller than the field modulus.
+    fn from(value: super::f128::BaseElement) -> Self {
+        BaseElement::from(value.as_int())
+    }
+}
+
+impl From<super::f64::BaseElement> for BaseElement {
+    /// Converts an element of the 64-bit field into a field element with the same value.
+    fn from(value: super::f64::BaseElement) -> Self {
+        BaseElement::from(value.as_int())
+    }
+}
+
+impl From<super::f62::BaseElement> for BaseElement {
+    /// Converts an element of the 62-bit field into a field element with the same value.
+    fn from(value: super::f62::BaseElement) -> Self {
+        BaseElement::from(value.as_int())
+    }
+}
+
 impl From<[u8; 32]> for BaseElement {
     /// Converts the value encoded in an array of 32 bytes into a field element. The bytes
     /// are assumed to be in little-endian byte order. If the value is greater than or equal
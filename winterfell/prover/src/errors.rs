@@ -21,6 +21,9 @@ pub enum ProverError {
     /// This error occurs when the base field specified by the AIR does not support field extension
     /// of degree specified by proof options.
     UnsupportedFieldExtension(usize),
+    /// This error occurs when the FRI layers cannot be split into the number of partitions
+    /// specified by proof options, because the smallest partitioned layer has fewer rows.
+    TooManyFriPartitions(usize, usize),
 }
 
 impl fmt::Display for ProverError {
@@ -36,6 +39,9 @@ impl fmt::Display for ProverError {
             Self::UnsupportedFieldExtension(degree) => {
                 write!(f, "field extension of degree {} is not supported for the specified base field", degree)
             }
+            Self::TooManyFriPartitions(num_partitions, max_partitions) => {
+                write!(f, "FRI layers cannot be split into {} partitions; at most {} partitions are supported for the LDE domain", num_partitions, max_partitions)
+            }
         }
     }
 }
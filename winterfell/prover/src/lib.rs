@@ -60,13 +60,13 @@ use utils::collections::Vec;
 pub use math;
 use math::{
     fft::infer_degree,
-    fields::{CubeExtension, QuadExtension},
+    fields::{f256, CubeExtension, QuadExtension},
     ExtensibleField, FieldElement, StarkField,
 };
 
 pub use crypto;
 use crypto::{
-    hashers::{Blake3_192, Blake3_256, Sha3_256,Poseidon},
+    hashers::{Blake3_192, Blake3_256, Sha3_256,Poseidon,Poseidon2},
     ElementHasher, MerkleTree,
 };
 
@@ -156,8 +156,14 @@ pub trait Prover {
     /// the computation described by [Self::Air](Prover::Air) and generated using some set of
     /// secret and public inputs. Public inputs must match the value returned from
     /// [Self::get_pub_inputs()](Prover::get_pub_inputs) for the provided trace.
+    ///
+    /// The base field must convert into the f256 field, whose elements are absorbed by the
+    /// Poseidon hash functions. This is the case of all the fields of the math crate.
     #[rustfmt::skip]
-    fn prove(&self, trace: Self::Trace) -> Result<StarkProof, ProverError> {
+    fn prove(&self, trace: Self::Trace) -> Result<StarkProof, ProverError>
+    where
+        Self::BaseField: Into<f256::BaseElement>,
+    {
         // figure out which version of the generic proof generation procedure to run. this is a sort
         // of static dispatch for selecting two generic parameter: extension field and hash function.
         match self.options().field_extension() {
@@ -165,8 +171,10 @@ pub trait Prover {
                 HashFunction::Blake3_256 => self.generate_proof::<Self::BaseField, Blake3_256<Self::BaseField>>(trace),
                 HashFunction::Blake3_192 => self.generate_proof::<Self::BaseField, Blake3_192<Self::BaseField>>(trace),
                 HashFunction::Sha3_256 => self.generate_proof::<Self::BaseField, Sha3_256<Self::BaseField>>(trace),
-                //FIXME: <Basefield> ??
                 HashFunction::Poseidon => self.generate_proof::<Self::BaseField, Poseidon<Self::BaseField>>(trace),
+                HashFunction::Poseidon4 => self.generate_proof::<Self::BaseField, Poseidon<Self::BaseField, 4>>(trace),
+                HashFunction::Poseidon8 => self.generate_proof::<Self::BaseField, Poseidon<Self::BaseField, 8>>(trace),
+                HashFunction::Poseidon2 => self.generate_proof::<Self::BaseField, Poseidon2<Self::BaseField>>(trace),
             },
             FieldExtension::Quadratic => {
                 if !<QuadExtension<Self::BaseField>>::is_supported() {
@@ -177,6 +185,9 @@ pub trait Prover {
                     HashFunction::Blake3_192 => self.generate_proof::<QuadExtension<Self::BaseField>, Blake3_192<Self::BaseField>>(trace),
                     HashFunction::Sha3_256 => self.generate_proof::<QuadExtension<Self::BaseField>, Sha3_256<Self::BaseField>>(trace),
                     HashFunction::Poseidon => self.generate_proof::<QuadExtension<Self::BaseField>, Poseidon<Self::BaseField>>(trace),
+                    HashFunction::Poseidon4 => self.generate_proof::<QuadExtension<Self::BaseField>, Poseidon<Self::BaseField, 4>>(trace),
+                    HashFunction::Poseidon8 => self.generate_proof::<QuadExtension<Self::BaseField>, Poseidon<Self::BaseField, 8>>(trace),
+                    HashFunction::Poseidon2 => self.generate_proof::<QuadExtension<Self::BaseField>, Poseidon2<Self::BaseField>>(trace),
                 }
             }
             FieldExtension::Cubic => {
@@ -188,6 +199,9 @@ pub trait Prover {
                     HashFunction::Blake3_192 => self.generate_proof::<CubeExtension<Self::BaseField>, Blake3_192<Self::BaseField>>(trace),
                     HashFunction::Sha3_256 => self.generate_proof::<CubeExtension<Self::BaseField>, Sha3_256<Self::BaseField>>(trace),
                     HashFunction::Poseidon => self.generate_proof::<CubeExtension<Self::BaseField>, Poseidon<Self::BaseField>>(trace),
+                    HashFunction::Poseidon4 => self.generate_proof::<CubeExtension<Self::BaseField>, Poseidon<Self::BaseField, 4>>(trace),
+                    HashFunction::Poseidon8 => self.generate_proof::<CubeExtension<Self::BaseField>, Poseidon<Self::BaseField, 8>>(trace),
+                    HashFunction::Poseidon2 => self.generate_proof::<CubeExtension<Self::BaseField>, Poseidon2<Self::BaseField>>(trace),
                 }
             }
         }
@@ -217,6 +231,17 @@ pub trait Prover {
         // execution of the computation for the provided public inputs.
         let air = Self::Air::new(trace.get_info(), pub_inputs, self.options().clone());
 
+        // the smallest partitioned FRI layer has as many rows as the remainder, and must have at
+        // least one row per partition
+        let fri_options = air.options().to_fri_options();
+        let max_fri_partitions = fri_options.fri_remainder_size(air.lde_domain_size());
+        if fri_options.num_partitions() > max_fri_partitions {
+            return Err(ProverError::TooManyFriPartitions(
+                fri_options.num_partitions(),
+                max_fri_partitions,
+            ));
+        }
+
         // create a channel which is used to simulate interaction between the prover and the
         // verifier; the channel will be used to commit to values and to draw randomness that
         // should come from the verifier.
@@ -3,7 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use crate::TraceTable;
+use crate::{Prover, ProverError, TraceTable};
 use air::{
     Air, AirContext, Assertion, EvaluationFrame, FieldExtension, HashFunction, ProofOptions,
     TraceInfo, TransitionConstraintDegree,
@@ -98,8 +98,8 @@ impl Air for MockAir {
     type BaseField = BaseElement;
     type PublicInputs = ();
 
-    fn new(trace_info: TraceInfo, _pub_inputs: (), _options: ProofOptions) -> Self {
-        let context = build_context(trace_info, 8, 1);
+    fn new(trace_info: TraceInfo, _pub_inputs: (), options: ProofOptions) -> Self {
+        let context = build_context(trace_info, options, 1);
         MockAir {
             context,
             assertions: Vec::new(),
@@ -128,23 +128,62 @@ impl Air for MockAir {
     }
 }
 
-// HELPER FUNCTIONS
+// MOCK PROVER
 // ================================================================================================
 
-fn build_context<B: StarkField>(
-    trace_info: TraceInfo,
-    blowup_factor: usize,
-    num_assertions: usize,
-) -> AirContext<B> {
+pub struct MockProver {
+    options: ProofOptions,
+}
+
+impl MockProver {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl Prover for MockProver {
+    type BaseField = BaseElement;
+    type Air = MockAir;
+    type Trace = TraceTable<BaseElement>;
+
+    fn get_pub_inputs(&self, _trace: &Self::Trace) {}
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
+
+// PROVER TESTS
+// ================================================================================================
+
+#[test]
+fn prove_too_many_fri_partitions() {
+    // the LDE domain of 2^13 elements is folded 3 times into a remainder of 128 elements, which
+    // is the number of rows of the smallest partitioned FRI layer
     let options = ProofOptions::new(
         32,
-        blowup_factor,
+        8,
         0,
         HashFunction::Blake3_256,
         FieldExtension::None,
         4,
         256,
     );
+    let prover = MockProver::new(options.with_fri_partitions(256));
+    assert_eq!(
+        Err(ProverError::TooManyFriPartitions(256, 128)),
+        prover.prove(build_fib_trace(2048))
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_context<B: StarkField>(
+    trace_info: TraceInfo,
+    options: ProofOptions,
+    num_assertions: usize,
+) -> AirContext<B> {
     let t_degrees = vec![TransitionConstraintDegree::new(2)];
     AirContext::new(trace_info, t_degrees, num_assertions, options)
 }
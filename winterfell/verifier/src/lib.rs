@@ -41,7 +41,7 @@ pub use air::{
 
 pub use math;
 use math::{
-    fields::{CubeExtension, QuadExtension},
+    fields::{f256, CubeExtension, QuadExtension},
     FieldElement,
 };
 
@@ -52,7 +52,7 @@ pub use utils::{
 
 pub use crypto;
 use crypto::{
-    hashers::{Blake3_192, Blake3_256, Sha3_256,Poseidon},
+    hashers::{Blake3_192, Blake3_256, Sha3_256,Poseidon,Poseidon2},
     ElementHasher, RandomCoin,
 };
 
@@ -83,11 +83,17 @@ pub use errors::VerifierError;
 /// a correct execution of the computation. This could happen for many various reasons, including:
 /// - The specified proof was generated for a different computation.
 /// - The specified proof was generated for this computation but for different public inputs.
+///
+/// The base field of the AIR must convert into the f256 field, whose elements are absorbed by
+/// the Poseidon hash functions. This is the case of all the fields of the math crate.
 #[rustfmt::skip]
 pub fn verify<AIR: Air>(
     proof: StarkProof,
     pub_inputs: AIR::PublicInputs,
-) -> Result<(), VerifierError> {
+) -> Result<(), VerifierError>
+where
+    AIR::BaseField: Into<f256::BaseElement>,
+{
     // build a seed for the public coin; the initial seed is the hash of public inputs and proof
     // context, but as the protocol progresses, the coin will be reseeded with the info received
     // from the prover
@@ -123,6 +129,21 @@ pub fn verify<AIR: Air>(
                 let channel = VerifierChannel::new(&air, proof)?;
                 perform_verification::<AIR, AIR::BaseField, Poseidon<AIR::BaseField>>(air, channel, public_coin)
             }
+            HashFunction::Poseidon4 => {
+                let public_coin = RandomCoin::new(&public_coin_seed);
+                let channel = VerifierChannel::new(&air, proof)?;
+                perform_verification::<AIR, AIR::BaseField, Poseidon<AIR::BaseField, 4>>(air, channel, public_coin)
+            }
+            HashFunction::Poseidon8 => {
+                let public_coin = RandomCoin::new(&public_coin_seed);
+                let channel = VerifierChannel::new(&air, proof)?;
+                perform_verification::<AIR, AIR::BaseField, Poseidon<AIR::BaseField, 8>>(air, channel, public_coin)
+            }
+            HashFunction::Poseidon2 => {
+                let public_coin = RandomCoin::new(&public_coin_seed);
+                let channel = VerifierChannel::new(&air, proof)?;
+                perform_verification::<AIR, AIR::BaseField, Poseidon2<AIR::BaseField>>(air, channel, public_coin)
+            }
         },
         FieldExtension::Quadratic => {
             if !<QuadExtension<AIR::BaseField>>::is_supported() {
@@ -150,6 +171,21 @@ pub fn verify<AIR: Air>(
                     let channel = VerifierChannel::new(&air, proof)?;
                     perform_verification::<AIR, QuadExtension<AIR::BaseField>, Poseidon<AIR::BaseField>>(air, channel, public_coin)
                 }
+                HashFunction::Poseidon4 => {
+                    let public_coin = RandomCoin::new(&public_coin_seed);
+                    let channel = VerifierChannel::new(&air, proof)?;
+                    perform_verification::<AIR, QuadExtension<AIR::BaseField>, Poseidon<AIR::BaseField, 4>>(air, channel, public_coin)
+                }
+                HashFunction::Poseidon8 => {
+                    let public_coin = RandomCoin::new(&public_coin_seed);
+                    let channel = VerifierChannel::new(&air, proof)?;
+                    perform_verification::<AIR, QuadExtension<AIR::BaseField>, Poseidon<AIR::BaseField, 8>>(air, channel, public_coin)
+                }
+                HashFunction::Poseidon2 => {
+                    let public_coin = RandomCoin::new(&public_coin_seed);
+                    let channel = VerifierChannel::new(&air, proof)?;
+                    perform_verification::<AIR, QuadExtension<AIR::BaseField>, Poseidon2<AIR::BaseField>>(air, channel, public_coin)
+                }
             }
         },
         FieldExtension::Cubic => {
@@ -177,6 +213,21 @@ pub fn verify<AIR: Air>(
                     let channel = VerifierChannel::new(&air, proof)?;
                     perform_verification::<AIR, CubeExtension<AIR::BaseField>, Poseidon<AIR::BaseField>>(air, channel, public_coin)
                 }
+                HashFunction::Poseidon4 => {
+                    let public_coin = RandomCoin::new(&public_coin_seed);
+                    let channel = VerifierChannel::new(&air, proof)?;
+                    perform_verification::<AIR, CubeExtension<AIR::BaseField>, Poseidon<AIR::BaseField, 4>>(air, channel, public_coin)
+                }
+                HashFunction::Poseidon8 => {
+                    let public_coin = RandomCoin::new(&public_coin_seed);
+                    let channel = VerifierChannel::new(&air, proof)?;
+                    perform_verification::<AIR, CubeExtension<AIR::BaseField>, Poseidon<AIR::BaseField, 8>>(air, channel, public_coin)
+                }
+                HashFunction::Poseidon2 => {
+                    let public_coin = RandomCoin::new(&public_coin_seed);
+                    let channel = VerifierChannel::new(&air, proof)?;
+                    perform_verification::<AIR, CubeExtension<AIR::BaseField>, Poseidon2<AIR::BaseField>>(air, channel, public_coin)
+                }
             }
         },
     }